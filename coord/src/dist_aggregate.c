@@ -413,6 +413,13 @@ void RSExecDistAggregate(RedisModuleCtx *ctx, RedisModuleString **argv, int argc
   int rc = AREQ_Compile(r, argv + 2 + profileArgs, argc - 2 - profileArgs, &status);
   if (rc != REDISMODULE_OK) goto err;
 
+  // The shards would only fuse their own results, which are not ranked like the results of a
+  // single index
+  if (r->searchopts.fusion.method != FUSION_NONE) {
+    QueryError_SetError(&status, QUERY_EAGGPLAN, "FUSION is not supported by FT.AGGREGATE on a cluster");
+    goto err;
+  }
+
   rc = AGGPLN_Distribute(&r->ap, &status);
   if (rc != REDISMODULE_OK) goto err;
 
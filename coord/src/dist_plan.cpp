@@ -2,6 +2,7 @@
 #include "aggregate/aggregate.h"
 #include "aggregate/aggregate_plan.h"
 #include "aggregate/reducer.h"
+#include "extension.h"
 #include "util/arr.h"
 #include "dist_plan.h"
 
@@ -48,11 +49,9 @@ struct ReducerDistCtx {
     return args;
   }
 
-  template <typename... T>
   bool add(PLN_GroupStep *gstp, const char *name, const char **alias, QueryError *status,
-           T... uargs) {
-    ArgsCursorCXX args(uargs...);
-    ArgsCursor *cargs = copyArgs(&args);
+           ArgsCursor *args) {
+    ArgsCursor *cargs = copyArgs(args);
     if (PLNGroupStep_AddReducer(gstp, name, cargs, status) != REDISMODULE_OK) {
       return false;
     }
@@ -62,6 +61,13 @@ struct ReducerDistCtx {
     return true;
   }
 
+  template <typename... T>
+  bool add(PLN_GroupStep *gstp, const char *name, const char **alias, QueryError *status,
+           T... uargs) {
+    ArgsCursorCXX args(uargs...);
+    return add(gstp, name, alias, status, static_cast<ArgsCursor *>(&args));
+  }
+
   template <typename... T>
   bool addLocal(const char *name, QueryError *status, T... uargs) {
     return add(localGroup, name, NULL, status, uargs...);
@@ -71,6 +77,23 @@ struct ReducerDistCtx {
     return add(remoteGroup, name, alias, status, uargs...);
   }
 
+  // Format an arguments count, for reducers whose number of arguments isn't fixed
+  const char *formatCount(size_t n) {
+    char *s = (char *)BlkAlloc_Alloc(alloc, 32, 32);
+    snprintf(s, 32, "%zu", n);
+    return s;
+  }
+
+  // Add the source reducer itself to the remote group, with all of its arguments
+  bool addRemoteSelf(const char **alias, QueryError *status) {
+    size_t nargs = srcReducer->args.argc;
+    ArgsCursorCXX args(formatCount(nargs));
+    for (size_t ii = 0; ii < nargs; ++ii) {
+      args.append((void *)srcarg(ii));
+    }
+    return add(remoteGroup, srcReducer->name, alias, status, static_cast<ArgsCursor *>(&args));
+  }
+
   const char *srcarg(size_t n) const {
     auto *s = (const char *)srcReducer->args.objs[n];
     return stripAtPrefix(s);
@@ -191,6 +214,58 @@ static RLookup *distStepGetLookup(PLN_BaseStep *bstp) {
   return &((PLN_DistributeStep *)bstp)->lk;
 }
 
+/**
+ * Checks whether every facet can run its first GROUPBY on the shards: only
+ * APPLY steps may precede it, and all of its reducers must be distributable.
+ */
+static bool canDistributeFacets(const PLN_FacetStep *fstp) {
+  for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+    const AGGPlan *plan = fstp->facets[ii].plan;
+    // Every facet has a GROUPBY, so this never reaches the end of the plan
+    const PLN_BaseStep *stp = PLN_NEXT_STEP(AGPLN_FindStep(plan, NULL, NULL, PLN_T_ROOT));
+    while (stp->type == PLN_T_APPLY) {
+      stp = PLN_NEXT_STEP(stp);
+    }
+    if (stp->type != PLN_T_GROUP) {
+      return false;
+    }
+    const PLN_GroupStep *gstp = (const PLN_GroupStep *)stp;
+    for (size_t jj = 0; jj < array_len(gstp->reducers); ++jj) {
+      if (!getDistributionFunc(gstp->reducers[jj].name)) {
+        return false;
+      }
+    }
+  }
+  return true;
+}
+
+/**
+ * Distributes a FACET step: the leading APPLY steps and the first GROUPBY of
+ * every facet run on the shards, as part of a remote FACET step. The local
+ * facets then merge the facet rows sent by the shards.
+ */
+static int distributeFacetStep(AGGPlan *remote, PLN_FacetStep *fstp, PLN_DistributeStep *dstp,
+                               QueryError *status) {
+  PLN_FacetStep *remoteFstp = PLNFacetStep_New();
+  AGPLN_AddStep(remote, &remoteFstp->base);
+
+  for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+    AGGPlan *plan = fstp->facets[ii].plan;
+    AGGPlan *remotePlan = PLNFacetStep_AddFacet(remoteFstp, fstp->facets[ii].name);
+    auto current = const_cast<PLN_BaseStep *>(AGPLN_FindStep(plan, NULL, NULL, PLN_T_ROOT));
+    current = PLN_NEXT_STEP(current);
+    while (current->type == PLN_T_APPLY) {
+      current = moveStep(remotePlan, plan, current);
+    }
+    int cont = 1;
+    if (!distributeGroupStep(plan, remotePlan, current, dstp, &cont, status)) {
+      return REDISMODULE_ERR;
+    }
+  }
+  fstp->isMerge = 1;
+  return REDISMODULE_OK;
+}
+
 #define CHECK_ARG_COUNT(N)                                                               \
   if (src->args.argc != N) {                                                             \
     QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid arguments for reducer %s", \
@@ -198,6 +273,13 @@ static RLookup *distStepGetLookup(PLN_BaseStep *bstp) {
     return REDISMODULE_ERR;                                                              \
   }
 
+#define CHECK_ARG_COUNT_RANGE(MIN, MAX)                                                  \
+  if (src->args.argc < MIN || src->args.argc > MAX) {                                    \
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid arguments for reducer %s", \
+                           src->name);                                                   \
+    return REDISMODULE_ERR;                                                              \
+  }
+
 /* Distribute COUNT into remote count and local SUM */
 static int distributeCount(ReducerDistCtx *rdctx, QueryError *status) {
   if (rdctx->srcReducer->args.argc != 0) {
@@ -237,21 +319,179 @@ static int distributeSingleArgSelf(ReducerDistCtx *rdctx, QueryError *status) {
 #define STRINGIFY__(a) #a
 #define RANDOM_SAMPLE_SIZE_STR STRINGIFY_(RANDOM_SAMPLE_SIZE)
 
-/* Distribute QUANTILE into remote RANDOM_SAMPLE and local QUANTILE */
+/* Add a remote QUANTILE_SKETCH of the first argument, with the resolution at `resArg` if given */
+static int addRemoteQuantileSketch(ReducerDistCtx *rdctx, size_t resArg, const char **alias,
+                                   QueryError *status) {
+  if (rdctx->srcReducer->args.argc > resArg) {
+    return rdctx->addRemote("QUANTILE_SKETCH", alias, status, "2", rdctx->srcarg(0),
+                            rdctx->srcarg(resArg));
+  }
+  return rdctx->addRemote("QUANTILE_SKETCH", alias, status, "1", rdctx->srcarg(0));
+}
+
+/* Distribute QUANTILE into remote QUANTILE_SKETCH and local QUANTILE_MERGE */
 static int distributeQuantile(ReducerDistCtx *rdctx, QueryError *status) {
   PLN_Reducer *src = rdctx->srcReducer;
-  CHECK_ARG_COUNT(2);
+  CHECK_ARG_COUNT_RANGE(2, 3);
   const char *alias = NULL;
 
-  if (!rdctx->addRemote("RANDOM_SAMPLE", &alias, status, "2", rdctx->srcarg(0),
-                        RANDOM_SAMPLE_SIZE_STR)) {
+  if (!addRemoteQuantileSketch(rdctx, 2, &alias, status)) {
+    return REDISMODULE_ERR;
+  }
+
+  // The sketches are merged with the same resolution they were made with
+  if (src->args.argc > 2) {
+    if (!rdctx->addLocal("QUANTILE_MERGE", status, "3", alias, rdctx->srcarg(1),
+                         rdctx->srcarg(2), "AS", src->alias)) {
+      return REDISMODULE_ERR;
+    }
+  } else if (!rdctx->addLocal("QUANTILE_MERGE", status, "2", alias, rdctx->srcarg(1), "AS",
+                              src->alias)) {
+    return REDISMODULE_ERR;
+  }
+
+  return REDISMODULE_OK;
+}
+
+/* Distribute MEDIAN into remote QUANTILE_SKETCH and local QUANTILE_MERGE */
+static int distributeMedian(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  CHECK_ARG_COUNT_RANGE(1, 2);
+  const char *alias = NULL;
+
+  if (!addRemoteQuantileSketch(rdctx, 1, &alias, status)) {
+    return REDISMODULE_ERR;
+  }
+
+  if (src->args.argc > 1) {
+    if (!rdctx->addLocal("QUANTILE_MERGE", status, "3", alias, "0.5", rdctx->srcarg(1), "AS",
+                         src->alias)) {
+      return REDISMODULE_ERR;
+    }
+  } else if (!rdctx->addLocal("QUANTILE_MERGE", status, "2", alias, "0.5", "AS", src->alias)) {
+    return REDISMODULE_ERR;
+  }
+
+  return REDISMODULE_OK;
+}
+
+/* Distribute PERCENTILES into remote QUANTILE_SKETCH and local PERCENTILES_MERGE */
+static int distributePercentiles(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  if (src->args.argc < 2) {
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid arguments for reducer %s",
+                           src->name);
+    return REDISMODULE_ERR;
+  }
+  const char *alias = NULL;
+
+  if (!rdctx->addRemote("QUANTILE_SKETCH", &alias, status, "1", rdctx->srcarg(0))) {
+    return REDISMODULE_ERR;
+  }
+
+  // The sketch replaces the property, followed by the same quantiles
+  size_t nargs = src->args.argc;
+  ArgsCursorCXX args(rdctx->formatCount(nargs), alias);
+  for (size_t ii = 1; ii < nargs; ++ii) {
+    args.append((void *)rdctx->srcarg(ii));
+  }
+  args.append((void *)"AS");
+  args.append((void *)src->alias);
+  if (!rdctx->add(rdctx->localGroup, "PERCENTILES_MERGE", NULL, status,
+                  static_cast<ArgsCursor *>(&args))) {
+    return REDISMODULE_ERR;
+  }
+
+  return REDISMODULE_OK;
+}
+
+/* Distribute MODE into remote MODE_COUNTS and local MODE_MERGE */
+static int distributeMode(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  CHECK_ARG_COUNT(1);
+  const char *alias;
+  if (!rdctx->addRemote("MODE_COUNTS", &alias, status, "1", rdctx->srcarg(0))) {
+    return REDISMODULE_ERR;
+  }
+  if (!rdctx->addLocal("MODE_MERGE", status, "1", alias, "AS", src->alias)) {
+    return REDISMODULE_ERR;
+  }
+  return REDISMODULE_OK;
+}
+
+/* Distribute HISTOGRAM into remote HISTOGRAM and local HISTOGRAM_MERGE. Shards bucket their
+ * rows the same way, so their bucket counts can just be summed */
+static int distributeHistogram(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  const char *alias;
+  if (!rdctx->addRemoteSelf(&alias, status)) {
+    return REDISMODULE_ERR;
+  }
+  if (!rdctx->addLocal("HISTOGRAM_MERGE", status, "1", alias, "AS", src->alias)) {
+    return REDISMODULE_ERR;
+  }
+  return REDISMODULE_OK;
+}
+
+/* Distribute TOP_HITS into remote TOP_HITS and local TOP_HITS_MERGE. The shard hits contain their
+ * SORTBY properties, so the merge can pick the best ones by the same order */
+static int distributeTopHits(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  size_t nargs = src->args.argc;
+  if (nargs < 1) {
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid arguments for reducer %s",
+                           src->name);
+    return REDISMODULE_ERR;
+  }
+  const char *alias;
+  if (!rdctx->addRemoteSelf(&alias, status)) {
     return REDISMODULE_ERR;
   }
 
-  if (!rdctx->addLocal("QUANTILE", status, "2", alias, rdctx->srcarg(1), "AS", src->alias)) {
+  // The merge takes the shard hits, followed by the count and the SORTBY arguments
+  size_t nmerge = 1;
+  if (nargs > 2 && !strcasecmp((const char *)src->args.objs[1], "SORTBY")) {
+    nmerge = std::min(nargs, 3 + (size_t)atoi((const char *)src->args.objs[2]));
+  }
+  ArgsCursorCXX args(rdctx->formatCount(nmerge + 1), alias);
+  for (size_t ii = 0; ii < nmerge; ++ii) {
+    args.append(src->args.objs[ii]);
+  }
+  args.append((void *)"AS");
+  args.append((void *)src->alias);
+  if (!rdctx->add(rdctx->localGroup, "TOP_HITS_MERGE", NULL, status,
+                  static_cast<ArgsCursor *>(&args))) {
+    return REDISMODULE_ERR;
+  }
+  return REDISMODULE_OK;
+}
+
+/* Distribute GEO_BOUNDS into remote GEO_BOUNDS and local GEO_BOUNDS_MERGE */
+static int distributeGeoBounds(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  CHECK_ARG_COUNT(1);
+  const char *alias;
+  if (!rdctx->addRemoteSelf(&alias, status)) {
+    return REDISMODULE_ERR;
+  }
+  if (!rdctx->addLocal("GEO_BOUNDS_MERGE", status, "1", alias, "AS", src->alias)) {
     return REDISMODULE_ERR;
   }
+  return REDISMODULE_OK;
+}
 
+/* Distribute GEO_CENTROID into remote GEO_CENTROID_SUMS and local GEO_CENTROID_MERGE. The
+ * centroids of the shards can't be averaged, but the sums of their points can be added */
+static int distributeGeoCentroid(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  CHECK_ARG_COUNT(1);
+  const char *alias;
+  if (!rdctx->addRemote("GEO_CENTROID_SUMS", &alias, status, "1", rdctx->srcarg(0))) {
+    return REDISMODULE_ERR;
+  }
+  if (!rdctx->addLocal("GEO_CENTROID_MERGE", status, "1", alias, "AS", src->alias)) {
+    return REDISMODULE_ERR;
+  }
   return REDISMODULE_OK;
 }
 
@@ -284,6 +524,28 @@ static int distributeCountDistinctish(ReducerDistCtx *rdctx, QueryError *status)
   return REDISMODULE_OK;
 }
 
+/* A reducer registered by an extension runs on the shards, and its declared merge reducer
+ * combines the shard results */
+static int distributeExtReducer(ReducerDistCtx *rdctx, QueryError *status) {
+  PLN_Reducer *src = rdctx->srcReducer;
+  const ExtReducerCtx *ext = Extensions_GetReducer(src->name);
+  CHECK_ARG_COUNT((size_t)ext->nargs);
+  if (strcasecmp(ext->mergeReducer, ext->name) &&
+      !Extensions_IsValidMergeReducer(ext->mergeReducer)) {
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid merge reducer %s for reducer %s",
+                           ext->mergeReducer, ext->name);
+    return REDISMODULE_ERR;
+  }
+  const char *alias;
+  if (!rdctx->addRemoteSelf(&alias, status)) {
+    return REDISMODULE_ERR;
+  }
+  if (!rdctx->addLocal(ext->mergeReducer, status, "1", alias, "AS", src->alias)) {
+    return REDISMODULE_ERR;
+  }
+  return REDISMODULE_OK;
+}
+
 static int distributeAvg(ReducerDistCtx *rdctx, QueryError *status) {
   PLN_Reducer *src = rdctx->srcReducer;
   PLN_GroupStep *local = rdctx->localGroup, *remote = rdctx->remoteGroup;
@@ -336,6 +598,13 @@ static struct {
     {"STDDEV", distributeStdDev},
     {"COUNT_DISTINCTISH", distributeCountDistinctish},
     {"QUANTILE", distributeQuantile},
+    {"MEDIAN", distributeMedian},
+    {"PERCENTILES", distributePercentiles},
+    {"MODE", distributeMode},
+    {"HISTOGRAM", distributeHistogram},
+    {"GEO_BOUNDS", distributeGeoBounds},
+    {"GEO_CENTROID", distributeGeoCentroid},
+    {"TOP_HITS", distributeTopHits},
 
     {NULL, NULL}  // sentinel value
 
@@ -348,9 +617,27 @@ reducerDistributionFunc getDistributionFunc(const char *key) {
     }
   }
 
+  // Reducers registered by extensions are distributed only if they declare a merge reducer
+  const ExtReducerCtx *ext = Extensions_GetReducer(key);
+  if (ext && ext->mergeReducer) {
+    return distributeExtReducer;
+  }
   return NULL;
 }
 
+// Register the keys output by a remote group
+static void registerGroupKeys(RLookup *lookup, const PLN_GroupStep *gstp) {
+  for (size_t ii = 0; ii < gstp->nproperties; ++ii) {
+    const char *propname = stripAtPrefix(gstp->properties[ii]);
+    RLookup_GetKey(lookup, propname, RLOOKUP_F_OCREAT);
+  }
+  for (size_t ii = 0; ii < array_len(gstp->reducers); ++ii) {
+    PLN_Reducer *r = gstp->reducers + ii;
+    // Register the aliases they are registered under as well
+    RLookup_GetKey(lookup, r->alias, RLOOKUP_F_OCREAT);
+  }
+}
+
 int AGGPLN_Distribute(AGGPlan *src, QueryError *status) {
   AGGPlan *remote = (AGGPlan *)rm_malloc(sizeof(*remote));
   AGPLN_Init(remote);
@@ -398,6 +685,20 @@ int AGGPLN_Distribute(AGGPlan *src, QueryError *status) {
           return REDISMODULE_ERR;
         }
         break;
+      case PLN_T_FACET: {
+        // Facets which cannot be distributed run entirely on the coordinator
+        PLN_FacetStep *fstp = (PLN_FacetStep *)current;
+        if (!canDistributeFacets(fstp)) {
+          cont = 0;
+          break;
+        }
+        if (distributeFacetStep(remote, fstp, dstp, status) != REDISMODULE_OK) {
+          freeDistStep((PLN_BaseStep *)dstp);
+          return REDISMODULE_ERR;
+        }
+        current = PLN_NEXT_STEP(current);
+        break;
+      }
       default:
         cont = 0;
         break;
@@ -435,16 +736,7 @@ int AGGPLN_Distribute(AGGPlan *src, QueryError *status) {
         break;
       }
       case PLN_T_GROUP: {
-        PLN_GroupStep *gstp = (PLN_GroupStep *)cur;
-        for (size_t ii = 0; ii < gstp->nproperties; ++ii) {
-          const char *propname = stripAtPrefix(gstp->properties[ii]);
-          RLookup_GetKey(lookup, propname, RLOOKUP_F_OCREAT);
-        }
-        for (size_t ii = 0; ii < array_len(gstp->reducers); ++ii) {
-          PLN_Reducer *r = gstp->reducers + ii;
-          // Register the aliases they are registered under as well
-          RLookup_GetKey(lookup, r->alias, RLOOKUP_F_OCREAT);
-        }
+        registerGroupKeys(lookup, (PLN_GroupStep *)cur);
         break;
       }
       case PLN_T_APPLY: {
@@ -460,6 +752,17 @@ int AGGPLN_Distribute(AGGPlan *src, QueryError *status) {
     }
   }
 
+  // Facet rows sent by the shards carry the facet name, and the output of the
+  // remote facet groups
+  auto rfstp = (const PLN_FacetStep *)AGPLN_FindStep(remote, NULL, NULL, PLN_T_FACET);
+  if (rfstp) {
+    RLookup_GetKey(lookup, PLN_FACET_NAME_FIELD, RLOOKUP_F_OCREAT);
+    for (size_t ii = 0; ii < array_len(rfstp->facets); ++ii) {
+      auto gstp = (PLN_GroupStep *)AGPLN_FindStep(rfstp->facets[ii].plan, NULL, NULL, PLN_T_GROUP);
+      registerGroupKeys(lookup, gstp);
+    }
+  }
+
   AGPLN_PopStep(src, &src->firstStep_s.base);
   AGPLN_Prepend(src, &dstp->base);
   auto tmp = (char **)AGPLN_Serialize(dstp->plan);
@@ -28,6 +28,7 @@ static InfoFieldSpec toplevelSpecs_g[] = {
     {.name = "inverted_sz_mb", .type = InfoField_DoubleSum},
     {.name = "total_inverted_index_blocks", .type = InfoField_WholeSum},
     {.name = "vector_index_sz_mb", .type = InfoField_DoubleSum},
+    {.name = "vector_index_raw_sz_mb", .type = InfoField_DoubleSum},
     {.name = "offset_vectors_sz_mb", .type = InfoField_DoubleSum},
     {.name = "doc_table_size_mb", .type = InfoField_DoubleSum},
     {.name = "sortable_values_size_mb", .type = InfoField_DoubleSum},
@@ -40,6 +40,7 @@
 #include <pthread.h>
 #include <stdbool.h>
 #include "query.h"
+#include "hybrid_fusion.h"
 
 #define CLUSTERDOWN_ERR "ERRCLUSTER Uninitialized cluster state, could not perform command"
 
@@ -330,12 +331,14 @@ typedef struct {
 typedef enum {
   SPECIAL_CASE_NONE,
   SPECIAL_CASE_KNN,
-  SPECIAL_CASE_SORTBY
+  SPECIAL_CASE_SORTBY,
+  SPECIAL_CASE_FUSION
 } searchRequestSpecialCase;
 
 
 typedef struct {
       size_t k;               // K value
+      double maxDistance;     // Distance cutoff (INFINITY if not set)
       const char* fieldName;  // Field name
       bool shouldSort;        // Should run presort before the coordinator sort
       size_t offset;          // Reply offset
@@ -343,6 +346,16 @@ typedef struct {
       QueryNode* queryNode;   // Query node
 } knnContext;
 
+typedef struct {
+      FusionOptions opts;          // FUSION options, with the windows resolved against K
+      const char* fieldName;       // Vector distance field name
+      size_t offset;               // Distance reply offset
+      size_t lexicalOffset;        // Lexical score reply offset
+      searchResult **results;      // All the results received from the shards
+      FusionCandidate *cands;      // The fusion candidate of each result
+      QueryNode* queryNode;        // Query node
+} fusionContext;
+
 typedef struct {
   const char* sortKey;  // SortKey name;
   bool asc;             // Sort order ASC/DESC
@@ -353,6 +366,7 @@ typedef struct {
   union {
     knnContext knn;
     sortbyContext sortby;
+    fusionContext fusion;
   };
   searchRequestSpecialCase specialCaseType;
 } specialCaseCtx;
@@ -426,6 +440,8 @@ void searchRequestCtx_Free(searchRequestCtx *r) {
       specialCaseCtx* ctx = r->specialCases[i];
       if(ctx->specialCaseType == SPECIAL_CASE_KNN) {
         QueryNode_Free(ctx->knn.queryNode);
+      } else if(ctx->specialCaseType == SPECIAL_CASE_FUSION) {
+        QueryNode_Free(ctx->fusion.queryNode);
       }
       SpecialCaseCtx_Free(ctx);
     }
@@ -455,6 +471,34 @@ static int rscParseProfile(searchRequestCtx *req, RedisModuleString **argv) {
   return REDISMODULE_OK;
 }
 
+// Prepare a FUSION special case.
+// Each shard fuses its own results, but the ranks within each branch are only known over the
+// results of all the shards, so the coordinator collects them and fuses them again.
+void prepareFusionCase(searchRequestCtx *req, RedisModuleString **argv, int argc, int fusionIndex,
+                       QueryNode *queryNode, QueryError *status) {
+  specialCaseCtx *ctx = SpecialCaseCtx_New();
+  ctx->specialCaseType = SPECIAL_CASE_FUSION;
+  ctx->fusion.queryNode = queryNode;
+  ctx->fusion.fieldName = queryNode->opts.distField ? queryNode->opts.distField : queryNode->vn.vq->scoreField;
+  if(!req->specialCases) {
+    req->specialCases = array_new(specialCaseCtx*, 1);
+  }
+  req->specialCases = array_append(req->specialCases, ctx);
+
+  ArgsCursor ac;
+  ArgsCursor_InitRString(&ac, argv + fusionIndex + 1, argc - fusionIndex - 1);
+  if (FusionOptions_Parse(&ctx->fusion.opts, &ac, status) != REDISMODULE_OK) {
+    return;
+  }
+  size_t k = queryNode->vn.vq->knn.k;
+  if (!ctx->fusion.opts.lexicalWindow) {
+    ctx->fusion.opts.lexicalWindow = k;
+  }
+  if (!ctx->fusion.opts.vectorWindow) {
+    ctx->fusion.opts.vectorWindow = k;
+  }
+}
+
 // Prepare a TOPK special case.
 void prepareOptionalTopKCase(searchRequestCtx *req, RedisModuleString **argv, int argc, QueryError *status) {
 
@@ -498,11 +542,15 @@ void prepareOptionalTopKCase(searchRequestCtx *req, RedisModuleString **argv, in
     Param_DictFree(params);
   }
 
-  if(queryNode!= NULL && queryNode->type == QN_VECTOR) {
+  int fusionIndex = RMUtil_ArgIndex("FUSION", argv, argc);
+  if(queryNode!= NULL && queryNode->type == QN_VECTOR && fusionIndex > 2) {
+    prepareFusionCase(req, argv, argc, fusionIndex, queryNode, status);
+  } else if(queryNode!= NULL && queryNode->type == QN_VECTOR) {
     QueryVectorNode queryVectorNode = queryNode->vn;
     size_t k = queryVectorNode.vq->knn.k;
     specialCaseCtx *ctx = SpecialCaseCtx_New();
     ctx->knn.k = k;
+    ctx->knn.maxDistance = queryVectorNode.vq->maxDistance;
     ctx->knn.fieldName = queryNode->opts.distField ? queryNode->opts.distField : queryVectorNode.vq->scoreField;
     ctx->knn.pq = NULL;
     ctx->knn.queryNode = queryNode;
@@ -834,6 +882,12 @@ static void getReplyOffsets(const searchRequestCtx *ctx, searchReplyOffsets *off
         specialCasesMaxOffset = MAX(specialCasesMaxOffset, ctx->specialCases[i]->sortby.offset);
         break;
       }
+      case SPECIAL_CASE_FUSION: {
+        ctx->specialCases[i]->fusion.offset += specialCaseStartOffset;
+        ctx->specialCases[i]->fusion.lexicalOffset += specialCaseStartOffset;
+        specialCasesMaxOffset = MAX(specialCasesMaxOffset, ctx->specialCases[i]->fusion.lexicalOffset);
+        break;
+      }
       case SPECIAL_CASE_NONE:
       default:
         break;
@@ -920,6 +974,12 @@ static void proccessKNNSearchReply(MRReply *arr, searchReducerCtx *rCtx, RedisMo
     double d = strtod(score + 1, &eptr);
     RedisModule_Assert(eptr != res->sortKey + 1 && *eptr == 0);
 
+    // Results beyond the distance cutoff are dropped, as they are by the shards
+    if (d > reduceSpecialCaseCtx->knn.maxDistance) {
+      rCtx->cachedResult = res;
+      continue;
+    }
+
     // As long as we don't have k results, keep insert
     if (heap_count(reduceSpecialCaseCtx->knn.pq) < reduceSpecialCaseCtx->knn.k) {
       scoredSearchResultWrapper* resWrapper = rm_malloc(sizeof(scoredSearchResultWrapper));
@@ -949,6 +1009,65 @@ static void proccessKNNSearchReply(MRReply *arr, searchReducerCtx *rCtx, RedisMo
   }
 }
 
+// Parse a numeric field sent by the shard as "#<number>". Returns false if the document has no
+// value for this field.
+static bool parseNumericField(MRReply *reply, double *d) {
+  if (MRReply_Type(reply) != MR_REPLY_STRING) {
+    return false;
+  }
+  const char *s = MRReply_String(reply, NULL);
+  if (!s || s[0] != '#') {
+    return false;
+  }
+  char *eptr;
+  *d = strtod(s + 1, &eptr);
+  return eptr != s + 1 && *eptr == 0;
+}
+
+static void proccessFusionSearchReply(MRReply *arr, searchReducerCtx *rCtx, RedisModuleCtx *ctx) {
+  if (arr == NULL) {
+    return;
+  }
+  if (MRReply_Type(arr) == MR_REPLY_ERROR) {
+    rCtx->lastError = arr;
+    return;
+  }
+  if (MRReply_Type(arr) != MR_REPLY_ARRAY || MRReply_Length(arr) == 0) {
+    // Empty reply??
+    return;
+  }
+
+  size_t len = MRReply_Length(arr);
+
+  int step = rCtx->offsets.step;
+  fusionContext *fusion = &rCtx->reduceSpecialCaseCtx->fusion;
+  for (int j = 1; j < len; j += step) {
+    if (j + step > len) {
+      RedisModule_Log(
+          ctx, "warning",
+          "got a bad reply from redisearch, reply contains less parameters then expected");
+      rCtx->errorOccured = true;
+      break;
+    }
+    searchResult *res = newResult(rCtx->cachedResult, arr, j, &rCtx->offsets , rCtx->searchCtx->withExplainScores);
+    if (!res || !res->id) {
+      RedisModule_Log(ctx, "warning", "got an unexpected argument when parsing redisearch results");
+      rCtx->errorOccured = true;
+      rCtx->cachedResult = res;
+      break;
+    } else {
+      rCtx->cachedResult = NULL;
+    }
+
+    // Every result is kept, since the ranks are only known once all the replies are in.
+    FusionCandidate *cand = array_ensure_tail(&fusion->cands, FusionCandidate);
+    *cand = (FusionCandidate){0};
+    cand->hasVector = parseNumericField(MRReply_ArrayElement(arr, j + fusion->offset), &cand->distance);
+    cand->hasLexical = parseNumericField(MRReply_ArrayElement(arr, j + fusion->lexicalOffset), &cand->lexicalScore);
+    fusion->results = array_append(fusion->results, res);
+  }
+}
+
 static void processSearchReply(MRReply *arr, searchReducerCtx *rCtx, RedisModuleCtx *ctx) {
   if (arr == NULL) {
     return;
@@ -1055,6 +1174,39 @@ static void knnPostProcess(searchReducerCtx *rCtx) {
 
 }
 
+static void fusionPostProcess(searchReducerCtx *rCtx) {
+  specialCaseCtx* reducerSpecialCaseCtx = rCtx->reduceSpecialCaseCtx;
+  RedisModule_Assert(reducerSpecialCaseCtx->specialCaseType == SPECIAL_CASE_FUSION);
+  fusionContext *fusion = &reducerSpecialCaseCtx->fusion;
+  size_t numberOfResults = array_len(fusion->results);
+  HybridFusion_Score(&fusion->opts, fusion->cands, numberOfResults);
+
+  rCtx->totalReplies = 0;
+  for (size_t i = 0; i < numberOfResults; i++) {
+    searchResult* res = fusion->results[i];
+    if (!fusion->cands[i].fused) {
+      rm_free(res);
+      continue;
+    }
+    res->score = fusion->cands[i].score;
+    rCtx->totalReplies++;
+    if(heap_count(rCtx->pq) < heap_size(rCtx->pq)) {
+      heap_offerx(rCtx->pq, res);
+    } else {
+      searchResult *smallest = heap_peek(rCtx->pq);
+      int c = cmp_results(res, smallest, rCtx->searchCtx);
+      if (c < 0) {
+        smallest = heap_poll(rCtx->pq);
+        heap_offerx(rCtx->pq, res);
+        rm_free(smallest);
+      } else {
+        rm_free(res);
+      }
+    }
+  }
+  array_clear(fusion->results);
+}
+
 static void sendSearchResults(RedisModuleCtx *ctx, searchReducerCtx *rCtx) {
   // Reverse the top N results
 
@@ -1235,6 +1387,14 @@ static int searchResultReducer(struct MRCtx *mc, int count, MRReply **replies) {
           rCtx.reduceSpecialCaseCtx = knnCtx;
           break;
         }
+      } else if(req->specialCases[i]->specialCaseType == SPECIAL_CASE_FUSION) {
+        specialCaseCtx* fusionCtx = req->specialCases[i];
+        fusionCtx->fusion.results = array_new(searchResult*, num);
+        fusionCtx->fusion.cands = array_new(FusionCandidate, num);
+        rCtx.processReply = (void (*)(struct redisReply *, struct searchReducerCtx *, RedisModuleCtx *))proccessFusionSearchReply;
+        rCtx.postProcess = (void (*)(struct searchReducerCtx *))fusionPostProcess;
+        rCtx.reduceSpecialCaseCtx = fusionCtx;
+        break;
       }
     }
   }
@@ -1274,6 +1434,11 @@ cleanup:
       rCtx.reduceSpecialCaseCtx->knn.pq) {
     heap_destroy(rCtx.reduceSpecialCaseCtx->knn.pq);
   }
+  if (rCtx.reduceSpecialCaseCtx &&
+      rCtx.reduceSpecialCaseCtx->specialCaseType == SPECIAL_CASE_FUSION) {
+    array_free_ex(rCtx.reduceSpecialCaseCtx->fusion.results, rm_free(*(void **)ptr));
+    array_free(rCtx.reduceSpecialCaseCtx->fusion.cands);
+  }
 
   searchRequestCtx_Free(req);
   RS_CHECK_FUNC(RedisModule_BlockedClientMeasureTimeEnd, bc);
@@ -1652,6 +1817,22 @@ void sendRequiredFields(searchRequestCtx *req, MRCommand *cmd) {
         ctx->knn.offset = offset++;
         break;
       }
+      case SPECIAL_CASE_FUSION: {
+        if(req->requiredFields == NULL) {
+          req->requiredFields = array_new(const char*, 2);
+        }
+        // The distance field may already be requested as the sortkey.
+        if(i > 0 && req->specialCases[0]->specialCaseType == SPECIAL_CASE_SORTBY &&
+           strcmp(req->specialCases[0]->sortby.sortKey, ctx->fusion.fieldName) == 0) {
+          ctx->fusion.offset = 0;
+        } else {
+          req->requiredFields = array_append(req->requiredFields, ctx->fusion.fieldName);
+          ctx->fusion.offset = offset++;
+        }
+        req->requiredFields = array_append(req->requiredFields, FUSION_LEXICAL_SCORE_FIELD);
+        ctx->fusion.lexicalOffset = offset++;
+        break;
+      }
       default:
         break;
     }
@@ -1669,6 +1850,15 @@ void sendRequiredFields(searchRequestCtx *req, MRCommand *cmd) {
   }
 }
 
+static const specialCaseCtx *getFusionCase(const searchRequestCtx *req) {
+  for(size_t i = 0; i < array_len(req->specialCases); i++) {
+    if(req->specialCases[i]->specialCaseType == SPECIAL_CASE_FUSION) {
+      return req->specialCases[i];
+    }
+  }
+  return NULL;
+}
+
 int FlatSearchCommandHandler(RedisModuleBlockedClient *bc, RedisModuleString **argv, int argc) {
   QueryError status = {0};
   searchRequestCtx *req = rscParseRequest(argv, argc, &status);
@@ -1687,7 +1877,20 @@ int FlatSearchCommandHandler(RedisModuleBlockedClient *bc, RedisModuleString **a
 
   // replace the LIMIT {offset} {limit} with LIMIT 0 {limit}, because we need all top N to merge
   int limitIndex = RMUtil_ArgExists("LIMIT", argv, argc, 3);
-  if (limitIndex && req->limit > 0 && limitIndex < argc - 2) {
+  const specialCaseCtx *fusionCtx = getFusionCase(req);
+  if (fusionCtx) {
+    // A fused query needs all the results within the fusion windows of every shard.
+    char buf[32];
+    snprintf(buf, sizeof(buf), "%zu", fusionCtx->fusion.opts.lexicalWindow + fusionCtx->fusion.opts.vectorWindow);
+    if (limitIndex && limitIndex < argc - 2) {
+      MRCommand_ReplaceArg(&cmd, limitIndex + 1, "0", 1);
+      MRCommand_ReplaceArg(&cmd, limitIndex + 2, buf, strlen(buf));
+    } else {
+      MRCommand_Append(&cmd, "LIMIT", strlen("LIMIT"));
+      MRCommand_Append(&cmd, "0", 1);
+      MRCommand_Append(&cmd, buf, strlen(buf));
+    }
+  } else if (limitIndex && req->limit > 0 && limitIndex < argc - 2) {
     size_t k =0;
     MRCommand_ReplaceArg(&cmd, limitIndex + 1, "0", 1);
     char buf[32];
@@ -2141,6 +2344,7 @@ RedisModule_OnLoad(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
     RM_TRY(RedisModule_CreateCommand(ctx, "FT.CURSOR", SafeCmd(CursorCommand), "readonly", 0, 0, -1));
   }
   RM_TRY(RedisModule_CreateCommand(ctx, "FT.SYNDUMP", SafeCmd(FirstShardCommandHandler), "readonly", 0, 0, -1));
+  RM_TRY(RedisModule_CreateCommand(ctx, "FT.STEMDUMP", SafeCmd(FirstShardCommandHandler), "readonly", 0, 0, -1));
   RM_TRY(RedisModule_CreateCommand(ctx, "FT._LIST", SafeCmd(FirstShardCommandHandler), "readonly",0, 0, -1));
   RM_TRY(RedisModule_CreateCommand(ctx, "FT.DICTDUMP", SafeCmd(FirstShardCommandHandler), "readonly", 0, 0, -1));
   RM_TRY(RedisModule_CreateCommand(ctx, "FT.SPELLCHECK", SafeCmd(SpellCheckCommandHandler), "readonly", 0, 0, -1));
@@ -2171,6 +2375,8 @@ RedisModule_OnLoad(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
     RM_TRY(RedisModule_CreateCommand(ctx, "FT.SYNADD", SafeCmd(SynAddCommandHandler), "readonly", 0, 0, -1));
     RM_TRY(RedisModule_CreateCommand(ctx, "FT.SYNUPDATE", SafeCmd(MastersFanoutCommandHandler),"readonly", 0, 0, -1));
     RM_TRY(RedisModule_CreateCommand(ctx, "FT.SYNFORCEUPDATE", SafeCmd(MastersFanoutCommandHandler),"readonly", 0, 0, -1));
+    RM_TRY(RedisModule_CreateCommand(ctx, "FT.STEMADD", SafeCmd(MastersFanoutCommandHandler),"readonly", 0, 0, -1));
+    RM_TRY(RedisModule_CreateCommand(ctx, "FT.STEMDEL", SafeCmd(MastersFanoutCommandHandler),"readonly", 0, 0, -1));
   }
 
   // cluster set commands
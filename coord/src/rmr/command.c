@@ -70,6 +70,11 @@ struct mrCommandConf __commandConfig[] = {
     {"_FT.SYNUPDATE", MRCommand_Write | MRCommand_NoKey, 1, -1, NULL},
     {"_FT.SYNFORCEUPDATE", MRCommand_Write | MRCommand_NoKey, 1, -1, NULL},
 
+    // Stemming exceptions commands
+    {"_FT.STEMADD", MRCommand_Write | MRCommand_NoKey, 1, -1, NULL},
+    {"_FT.STEMDEL", MRCommand_Write | MRCommand_NoKey, 1, -1, NULL},
+    {"_FT.STEMDUMP", MRCommand_Write | MRCommand_NoKey, 1, -1, NULL},
+
     // Coordination commands - they are all read commands since they can be triggered from slaves
     {"FT.ADD", MRCommand_Read | MRCommand_Coordination, -1, 2, NULL},
     {"FT.SEARCH", MRCommand_Read | MRCommand_Coordination, -1, 1, NULL},
@@ -22,6 +22,7 @@ extern "C" {
 #define DEFAULT_LIMIT 10
 
 typedef struct Grouper Grouper;
+typedef struct RPFacets RPFacets;
 struct QOptimizer;
 
 typedef enum {
@@ -80,6 +81,9 @@ typedef enum {
 typedef enum {
   /* Received EOF from iterator */
   QEXEC_S_ITERDONE = 0x02,
+
+  /* Received EOF from the main pipeline, now replying with the facet rows */
+  QEXEC_S_FACETS = 0x04,
 } QEStateFlags;
 
 typedef struct {
@@ -148,6 +152,9 @@ typedef struct {
   const char** requiredFields;
 
   struct QOptimizer *optimizer;        // Hold parameters for query optimizer
+
+  /** FACET processor of the pipeline, if any. Owned by the pipeline */
+  RPFacets *facets;
   
   // Currently we need both because maxSearchResults limits the OFFSET also in
   // FT.AGGREGATE execution.
@@ -280,6 +287,45 @@ ResultProcessor *Grouper_GetRP(Grouper *gr);
  */
 void Grouper_AddReducer(Grouper *g, Reducer *r, RLookupKey *dst);
 
+/******************************************************************************
+ ******************************************************************************
+ ** Facets Functions                                                         **
+ ******************************************************************************
+ ******************************************************************************/
+
+/**
+ * Creates the processor of a FACET step. Every row passing through it is fed
+ * to each of its facets, and then passed on to the next processor.
+ *
+ * `srclookup` is the lookup of the incoming rows. If `mergeKey` is set
+ * (coordinator), rows having a value for it are facet rows sent by the shards:
+ * they are only fed to the facet of that name, and are not passed on.
+ */
+RPFacets *RPFacets_New(const RLookup *srclookup, const RLookupKey *mergeKey);
+
+ResultProcessor *RPFacets_GetRP(RPFacets *rpf);
+
+/**
+ * Adds a facet. The processors of the facet are then added, in order, with
+ * RPFacets_PushFacetRP(). The first grouper added is the one accumulating the
+ * rows, and no other processor may come before it but projectors and filters.
+ */
+void RPFacets_AddFacet(RPFacets *rpf, const char *name);
+void RPFacets_PushFacetRP(RPFacets *rpf, ResultProcessor *rp);
+
+/**
+ * Sets the lookup of the rows output by the last added facet. The facet name is
+ * written to these rows under PLN_FACET_NAME_FIELD.
+ */
+void RPFacets_SetFacetLookup(RPFacets *rpf, RLookup *lookup);
+
+/**
+ * Gets the next facet row, once the main pipeline is done. Any row not pulled
+ * yet by the main pipeline is consumed first. `lookup` is set to the lookup of
+ * the returned row.
+ */
+int RPFacets_Next(RPFacets *rpf, SearchResult *r, RLookup **lookup);
+
 void AREQ_Execute(AREQ *req, RedisModuleCtx *outctx);
 int prepareExecutionPlan(AREQ *req, int pipeline_options, QueryError *status);
 void sendChunk(AREQ *req, RedisModuleCtx *outctx, size_t limit);
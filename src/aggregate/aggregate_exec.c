@@ -237,6 +237,88 @@ static size_t getResultsFactor(AREQ *req) {
   return count;
 }
 
+/**
+ * Gets the next row to reply with: the rows of the main pipeline, followed by
+ * the rows of the facets, if any. The lookup of the row is set in `cv`.
+ */
+static int getNextResult(AREQ *req, SearchResult *r, cachedVars *cv) {
+  if (!(req->stateflags & QEXEC_S_FACETS)) {
+    ResultProcessor *rp = req->qiter.endProc;
+    int rc = rp->Next(rp, r);
+    if (rc != RS_RESULT_EOF || !req->facets) {
+      return rc;
+    }
+    req->stateflags |= QEXEC_S_FACETS;
+    SearchResult_Clear(r);
+  }
+  return RPFacets_Next(req->facets, r, &cv->lastLk);
+}
+
+/**
+ * Sends a chunk of <n> rows of a request with facets. The facet rows are
+ * counted in the total, so the rows are fetched before the preamble is sent.
+ */
+static void sendFacetsChunk(AREQ *req, RedisModuleCtx *outctx, size_t limit) {
+  SearchResult *rows = array_new(SearchResult, 8);
+  RLookup **lookups = array_new(RLookup *, 8);
+  size_t numFacetRows = 0;
+  SearchResult r = {0};
+  int rc = RS_RESULT_OK;
+
+  cachedVars cv = {0};
+  cv.lastLk = AGPLN_GetLookup(&req->ap, NULL, AGPLN_GETLOOKUP_LAST);
+  cv.lastAstp = AGPLN_GetArrangeStep(&req->ap);
+
+  while (array_len(rows) < limit && (rc = getNextResult(req, &r, &cv)) == RS_RESULT_OK) {
+    if (req->stateflags & QEXEC_S_FACETS) {
+      numFacetRows++;
+    }
+    // The rows outlive the iterators, like the rows buffered by the sorter
+    r.indexResult = NULL;
+    rows = array_append(rows, r);
+    lookups = array_append(lookups, cv.lastLk);
+    memset(&r, 0, sizeof(r));
+  }
+
+  OPTMZ(QOptimizer_UpdateTotalResults(req));
+
+  if (rc == RS_RESULT_TIMEDOUT && !(req->reqflags & QEXEC_F_IS_CURSOR) && !IsProfile(req) &&
+      req->reqConfig.timeoutPolicy == TimeoutPolicy_Fail) {
+    RedisModule_ReplyWithArray(outctx, 1);
+    RedisModule_ReplyWithSimpleString(outctx, "Timeout limit was reached");
+  } else if (rc == RS_RESULT_ERROR) {
+    RedisModule_ReplyWithArray(outctx, 2);
+    RedisModule_ReplyWithLongLong(outctx, req->qiter.totalResults + numFacetRows);
+    RedisModule_ReplyWithArray(outctx, 1);
+    QueryError_ReplyAndClear(outctx, req->qiter.err);
+  } else {
+    if (rc == RS_RESULT_TIMEDOUT) {
+      rc = RS_RESULT_OK;
+    }
+    size_t nelem = 1;
+    RedisModule_ReplyWithArray(outctx, REDISMODULE_POSTPONED_ARRAY_LEN);
+    RedisModule_ReplyWithLongLong(outctx, req->qiter.totalResults + numFacetRows);
+    for (size_t ii = 0; ii < array_len(rows) && !(req->reqflags & QEXEC_F_NOROWS); ++ii) {
+      cv.lastLk = lookups[ii];
+      nelem += serializeResult(req, outctx, rows + ii, &cv);
+    }
+    RedisModule_ReplySetArrayLength(outctx, nelem);
+  }
+
+  for (size_t ii = 0; ii < array_len(rows); ++ii) {
+    SearchResult_Destroy(rows + ii);
+  }
+  SearchResult_Destroy(&r);
+  array_free(rows);
+  array_free(lookups);
+  if (rc != RS_RESULT_OK) {
+    req->stateflags |= QEXEC_S_ITERDONE;
+  }
+
+  // Reset the total results length:
+  req->qiter.totalResults = 0;
+}
+
 /**
  * Sends a chunk of <n> rows, optionally also sending the preamble
  */
@@ -245,17 +327,20 @@ void sendChunk(AREQ *req, RedisModuleCtx *outctx, size_t limit) {
   size_t nelem = 0;
   SearchResult r = {0};
   int rc = RS_RESULT_EOF;
-  ResultProcessor *rp = req->qiter.endProc;
 
   if (!(req->reqflags & QEXEC_F_IS_CURSOR) && !(req->reqflags & QEXEC_F_IS_SEARCH)) {
     limit = req->maxAggregateResults;
   }
+  if (req->facets) {
+    sendFacetsChunk(req, outctx, limit);
+    return;
+  }
 
   cachedVars cv = {0};
   cv.lastLk = AGPLN_GetLookup(&req->ap, NULL, AGPLN_GETLOOKUP_LAST);
   cv.lastAstp = AGPLN_GetArrangeStep(&req->ap);
 
-  rc = rp->Next(rp, &r);
+  rc = getNextResult(req, &r, &cv);
   long resultsLen = REDISMODULE_POSTPONED_ARRAY_LEN;
   if (rc == RS_RESULT_TIMEDOUT && !(req->reqflags & QEXEC_F_IS_CURSOR) && !IsProfile(req) &&
       req->reqConfig.timeoutPolicy == TimeoutPolicy_Fail) {
@@ -306,7 +391,7 @@ void sendChunk(AREQ *req, RedisModuleCtx *outctx, size_t limit) {
     goto done;
   }
 
-  while (nrows++ < limit && (rc = rp->Next(rp, &r)) == RS_RESULT_OK) {
+  while (nrows++ < limit && (rc = getNextResult(req, &r, &cv)) == RS_RESULT_OK) {
     if (!(req->reqflags & QEXEC_F_NOROWS)) {
       nelem += serializeResult(req, outctx, &r, &cv);
     }
@@ -26,6 +26,8 @@ static const char *steptypeToString(PLN_StepType type) {
       return "LOAD";
     case PLN_T_DISTRIBUTE:
       return "DISTRIBUTE";
+    case PLN_T_FACET:
+      return "FACET";
     case PLN_T_INVALID:
     default:
       return "<UNKNOWN>";
@@ -193,6 +195,41 @@ RLookup *AGPLN_GetLookup(const AGGPlan *pln, const PLN_BaseStep *bstp, AGPLNGetL
   return NULL;
 }
 
+static void facetStepDtor(PLN_BaseStep *bstp) {
+  PLN_FacetStep *fstp = (PLN_FacetStep *)bstp;
+  for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+    AGPLN_FreeSteps(fstp->facets[ii].plan);
+    rm_free(fstp->facets[ii].plan);
+  }
+  array_free(fstp->facets);
+  rm_free(fstp);
+}
+
+PLN_FacetStep *PLNFacetStep_New(void) {
+  PLN_FacetStep *fstp = rm_calloc(1, sizeof(*fstp));
+  fstp->base.type = PLN_T_FACET;
+  fstp->base.dtor = facetStepDtor;
+  fstp->facets = array_new(PLN_Facet, 4);
+  return fstp;
+}
+
+AGGPlan *PLNFacetStep_AddFacet(PLN_FacetStep *fstp, const char *name) {
+  PLN_Facet *facet = array_ensure_tail(&fstp->facets, PLN_Facet);
+  facet->name = name;
+  facet->plan = rm_malloc(sizeof(*facet->plan));
+  AGPLN_Init(facet->plan);
+  return facet->plan;
+}
+
+PLN_Facet *PLNFacetStep_GetFacet(PLN_FacetStep *fstp, const char *name) {
+  for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+    if (!strcmp(fstp->facets[ii].name, name)) {
+      return fstp->facets + ii;
+    }
+  }
+  return NULL;
+}
+
 void AGPLN_FreeSteps(AGGPlan *pln) {
   DLLIST_node *nn = pln->steps.next;
   while (nn && nn != &pln->steps) {
@@ -265,6 +302,14 @@ void AGPLN_Dump(const AGGPlan *pln) {
         }
         break;
       }
+      case PLN_T_FACET: {
+        const PLN_FacetStep *fstp = (PLN_FacetStep *)stp;
+        for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+          printf("  FACET: %s%s\n", fstp->facets[ii].name, fstp->isMerge ? " (MERGE)" : "");
+          AGPLN_Dump(fstp->facets[ii].plan);
+        }
+        break;
+      }
       case PLN_T_ROOT:
       case PLN_T_DISTRIBUTE:
       case PLN_T_INVALID:
@@ -362,6 +407,21 @@ static void serializeGroup(myArgArray_t *arr, const PLN_BaseStep *stp) {
   }
 }
 
+static void serializeFacets(myArgArray_t *arr, const PLN_BaseStep *stp) {
+  const PLN_FacetStep *fstp = (PLN_FacetStep *)stp;
+  for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+    char **facetArgs = AGPLN_Serialize(fstp->facets[ii].plan);
+    append_string(arr, "FACET");
+    append_string(arr, fstp->facets[ii].name);
+    append_uint(arr, array_len(facetArgs));
+    // The facet arguments are already allocated, so they are moved as they are
+    for (size_t jj = 0; jj < array_len(facetArgs); ++jj) {
+      *arr = array_append(*arr, facetArgs[jj]);
+    }
+    array_free(facetArgs);
+  }
+}
+
 array_t AGPLN_Serialize(const AGGPlan *pln) {
   char **arr = array_new(char *, 1);
   for (const DLLIST_node *nn = pln->steps.next; nn != &pln->steps; nn = nn->next) {
@@ -380,6 +440,9 @@ array_t AGPLN_Serialize(const AGGPlan *pln) {
       case PLN_T_GROUP:
         serializeGroup(&arr, stp);
         break;
+      case PLN_T_FACET:
+        serializeFacets(&arr, stp);
+        break;
       case PLN_T_INVALID:
       case PLN_T_ROOT:
       case PLN_T_DISTRIBUTE:
@@ -27,6 +27,7 @@ typedef enum {
   PLN_T_APPLY,
   PLN_T_ARRANGE,
   PLN_T_LOAD,
+  PLN_T_FACET,
   PLN_T__MAX
 } PLN_StepType;
 
@@ -142,6 +143,44 @@ int PLNGroupStep_AddReducer(PLN_GroupStep *gstp, const char *name, ArgsCursor *a
 
 PLN_MapFilterStep *PLNMapFilterStep_New(const char *expr, int mode);
 
+/* The name of the field holding the facet name of every facet row */
+#define PLN_FACET_NAME_FIELD "__facet"
+
+/**
+ * Facet step - several independent pipelines (each with at least one GROUPBY)
+ * fed by the rows reaching this step. The rows themselves are passed on as-is,
+ * and the rows of every facet are returned after them.
+ */
+typedef struct {
+  PLN_BaseStep base;
+
+  struct PLN_Facet {
+    const char *name;
+    AGGPlan *plan;  // Steps of the facet. Its root step is unused
+  } * facets;              // array_*
+
+  // Set on the coordinator: the facets merge the facet rows received from the shards, instead of
+  // the rows reaching this step
+  int isMerge;
+} PLN_FacetStep;
+
+#ifdef __cplusplus
+typedef PLN_FacetStep::PLN_Facet PLN_Facet;
+#else
+typedef struct PLN_Facet PLN_Facet;
+#endif
+
+PLN_FacetStep *PLNFacetStep_New(void);
+
+/**
+ * Adds an empty facet to the facet step, returning the plan to which its steps
+ * should be added
+ */
+AGGPlan *PLNFacetStep_AddFacet(PLN_FacetStep *fstp, const char *name);
+
+/** Gets a facet by its name, or NULL if there is no such facet */
+PLN_Facet *PLNFacetStep_GetFacet(PLN_FacetStep *fstp, const char *name);
+
 #ifdef __cplusplus
 typedef PLN_GroupStep::PLN_Reducer PLN_Reducer;
 #else
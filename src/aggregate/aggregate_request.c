@@ -18,6 +18,8 @@
 #include "config.h"
 #include "util/timeout.h"
 #include "query_optimizer.h"
+#include "hybrid_fusion.h"
+#include "vector_compression.h"
 
 extern RSConfig RSGlobalConfig;
 
@@ -252,6 +254,10 @@ static int handleCommonArgs(AREQ *req, ArgsCursor *ac, QueryError *status, int a
     if (parseDialect(&req->reqConfig.dialectVersion, ac, status) != REDISMODULE_OK) {
       return ARG_ERROR;
     }
+  } else if (AC_AdvanceIfMatch(ac, "FUSION")) {
+    if (FusionOptions_Parse(&req->searchopts.fusion, ac, status) != REDISMODULE_OK) {
+      return ARG_ERROR;
+    }
   } else {
     return ARG_UNKNOWN;
   }
@@ -610,7 +616,7 @@ PLN_GroupStep *PLNGroupStep_New(const char **properties, size_t nproperties) {
   return gstp;
 }
 
-static int parseGroupby(AREQ *req, ArgsCursor *ac, QueryError *status) {
+static int parseGroupby(AGGPlan *plan, ArgsCursor *ac, QueryError *status) {
   ArgsCursor groupArgs = {0};
   const char *s;
   AC_GetString(ac, &s, NULL, AC_F_NOADVANCE);
@@ -622,7 +628,7 @@ static int parseGroupby(AREQ *req, ArgsCursor *ac, QueryError *status) {
 
   // Number of fields.. now let's see the reducers
   PLN_GroupStep *gstp = PLNGroupStep_New((const char **)groupArgs.objs, groupArgs.argc);
-  AGPLN_AddStep(&req->ap, &gstp->base);
+  AGPLN_AddStep(plan, &gstp->base);
 
   while (AC_AdvanceIfMatch(ac, "REDUCE")) {
     const char *name;
@@ -660,7 +666,7 @@ PLN_MapFilterStep *PLNMapFilterStep_New(const char *expr, int mode) {
   return stp;
 }
 
-static int handleApplyOrFilter(AREQ *req, ArgsCursor *ac, QueryError *status, int isApply) {
+static int handleApplyOrFilter(AGGPlan *plan, ArgsCursor *ac, QueryError *status, int isApply) {
   // Parse filters!
   const char *expr = NULL;
   int rv = AC_GetString(ac, &expr, NULL, 0);
@@ -669,8 +675,16 @@ static int handleApplyOrFilter(AREQ *req, ArgsCursor *ac, QueryError *status, in
     return REDISMODULE_ERR;
   }
 
+  // Parse the expression right away, so unknown functions and invalid arguments counts are
+  // reported when the request is compiled
+  RSExpr *parsedExpr = ExprAST_Parse(expr, strlen(expr), status);
+  if (!parsedExpr) {
+    return REDISMODULE_ERR;
+  }
+
   PLN_MapFilterStep *stp = PLNMapFilterStep_New(expr, isApply ? PLN_T_APPLY : PLN_T_FILTER);
-  AGPLN_AddStep(&req->ap, &stp->base);
+  stp->parsedExpr = parsedExpr;
+  AGPLN_AddStep(plan, &stp->base);
 
   if (isApply) {
     if (AC_AdvanceIfMatch(ac, "AS")) {
@@ -688,12 +702,114 @@ static int handleApplyOrFilter(AREQ *req, ArgsCursor *ac, QueryError *status, in
 
 error:
   if (stp) {
-    AGPLN_PopStep(&req->ap, &stp->base);
+    AGPLN_PopStep(plan, &stp->base);
     stp->base.dtor(&stp->base);
   }
   return REDISMODULE_ERR;
 }
 
+static int parseFacetLimit(AREQ *req, PLN_ArrangeStep *arng, ArgsCursor *ac, QueryError *status) {
+  if (AC_NumRemaining(ac) < 2) {
+    QueryError_SetError(status, QUERY_EPARSEARGS, "LIMIT requires two arguments");
+    return REDISMODULE_ERR;
+  }
+  if (AC_GetU64(ac, &arng->offset, 0) != AC_OK || AC_GetU64(ac, &arng->limit, 0) != AC_OK) {
+    QueryError_SetError(status, QUERY_EPARSEARGS, "LIMIT needs two numeric arguments");
+    return REDISMODULE_ERR;
+  }
+  if (arng->limit > req->maxAggregateResults) {
+    QueryError_SetErrorFmt(status, QUERY_ELIMIT, "LIMIT exceeds maximum of %llu",
+                           req->maxAggregateResults);
+    return REDISMODULE_ERR;
+  }
+  arng->isLimited = 1;
+  return REDISMODULE_OK;
+}
+
+static PLN_ArrangeStep *getFacetArrangeStep(AGGPlan *plan, const char *name, QueryError *status) {
+  // Rows are accumulated by the first GROUPBY, so nothing but APPLY and FILTER
+  // may come before it
+  if (!AGPLN_HasStep(plan, PLN_T_GROUP)) {
+    QERR_MKBADARGS_FMT(status, "FACET `%s` must have a GROUPBY before SORTBY or LIMIT", name);
+    return NULL;
+  }
+  return AGPLN_GetOrCreateArrangeStep(plan);
+}
+
+/**
+ * FACET <name> <nargs> <steps...>
+ * Each facet is a small pipeline of APPLY/FILTER/GROUPBY/SORTBY/LIMIT steps, fed
+ * by the rows reaching the FACET step. Consecutive FACET clauses share a single
+ * plan step.
+ */
+static int handleFacet(AREQ *req, ArgsCursor *ac, QueryError *status) {
+  const char *name;
+  ArgsCursor facetArgs = {0};
+  int rv = AC_GetString(ac, &name, NULL, 0);
+  if (rv != AC_OK) {
+    QERR_MKBADARGS_AC(status, "FACET", rv);
+    return REDISMODULE_ERR;
+  }
+  rv = AC_GetVarArgs(ac, &facetArgs);
+  if (rv != AC_OK) {
+    QERR_MKBADARGS_AC(status, "FACET", rv);
+    return REDISMODULE_ERR;
+  }
+
+  PLN_FacetStep *fstp = NULL;
+  PLN_BaseStep *last = DLLIST_ITEM(req->ap.steps.prev, PLN_BaseStep, llnodePln);
+  if (last->type == PLN_T_FACET) {
+    fstp = (PLN_FacetStep *)last;
+  } else if (AGPLN_HasStep(&req->ap, PLN_T_FACET)) {
+    QERR_MKBADARGS_FMT(status, "All FACET clauses must be adjacent");
+    return REDISMODULE_ERR;
+  } else {
+    fstp = PLNFacetStep_New();
+    AGPLN_AddStep(&req->ap, &fstp->base);
+  }
+
+  if (!strcmp(name, PLN_FACET_NAME_FIELD) || PLNFacetStep_GetFacet(fstp, name)) {
+    QERR_MKBADARGS_FMT(status, "Duplicate FACET name `%s`", name);
+    return REDISMODULE_ERR;
+  }
+  AGGPlan *plan = PLNFacetStep_AddFacet(fstp, name);
+
+  while (!AC_IsAtEnd(&facetArgs)) {
+    if (AC_AdvanceIfMatch(&facetArgs, "GROUPBY")) {
+      if (parseGroupby(plan, &facetArgs, status) != REDISMODULE_OK) {
+        return REDISMODULE_ERR;
+      }
+    } else if (AC_AdvanceIfMatch(&facetArgs, "APPLY")) {
+      if (handleApplyOrFilter(plan, &facetArgs, status, 1) != REDISMODULE_OK) {
+        return REDISMODULE_ERR;
+      }
+    } else if (AC_AdvanceIfMatch(&facetArgs, "FILTER")) {
+      if (handleApplyOrFilter(plan, &facetArgs, status, 0) != REDISMODULE_OK) {
+        return REDISMODULE_ERR;
+      }
+    } else if (AC_AdvanceIfMatch(&facetArgs, "SORTBY")) {
+      PLN_ArrangeStep *arng = getFacetArrangeStep(plan, name, status);
+      if (!arng || parseSortby(arng, &facetArgs, status, 0) != REDISMODULE_OK) {
+        return REDISMODULE_ERR;
+      }
+    } else if (AC_AdvanceIfMatch(&facetArgs, "LIMIT")) {
+      PLN_ArrangeStep *arng = getFacetArrangeStep(plan, name, status);
+      if (!arng || parseFacetLimit(req, arng, &facetArgs, status) != REDISMODULE_OK) {
+        return REDISMODULE_ERR;
+      }
+    } else {
+      QueryError_FmtUnknownArg(status, &facetArgs, "FACET");
+      return REDISMODULE_ERR;
+    }
+  }
+
+  if (!AGPLN_HasStep(plan, PLN_T_GROUP)) {
+    QERR_MKBADARGS_FMT(status, "FACET `%s` requires a GROUPBY step", name);
+    return REDISMODULE_ERR;
+  }
+  return REDISMODULE_OK;
+}
+
 static void loadDtor(PLN_BaseStep *bstp) {
   PLN_LoadStep *lstp = (PLN_LoadStep *)bstp;
   rm_free(lstp->keys);
@@ -790,11 +906,11 @@ int AREQ_Compile(AREQ *req, RedisModuleString **argv, int argc, QueryError *stat
       if (!ensureExtendedMode(req, "GROUPBY", status)) {
         goto error;
       }
-      if (parseGroupby(req, &ac, status) != REDISMODULE_OK) {
+      if (parseGroupby(&req->ap, &ac, status) != REDISMODULE_OK) {
         goto error;
       }
     } else if (AC_AdvanceIfMatch(&ac, "APPLY")) {
-      if (handleApplyOrFilter(req, &ac, status, 1) != REDISMODULE_OK) {
+      if (handleApplyOrFilter(&req->ap, &ac, status, 1) != REDISMODULE_OK) {
         goto error;
       }
     } else if (AC_AdvanceIfMatch(&ac, "LOAD")) {
@@ -802,7 +918,14 @@ int AREQ_Compile(AREQ *req, RedisModuleString **argv, int argc, QueryError *stat
         goto error;
       }
     } else if (AC_AdvanceIfMatch(&ac, "FILTER")) {
-      if (handleApplyOrFilter(req, &ac, status, 0) != REDISMODULE_OK) {
+      if (handleApplyOrFilter(&req->ap, &ac, status, 0) != REDISMODULE_OK) {
+        goto error;
+      }
+    } else if (AC_AdvanceIfMatch(&ac, "FACET")) {
+      if (!ensureExtendedMode(req, "FACET", status)) {
+        goto error;
+      }
+      if (handleFacet(req, &ac, status) != REDISMODULE_OK) {
         goto error;
       }
     } else {
@@ -845,6 +968,26 @@ static void applyGlobalFilters(RSSearchOptions *opts, QueryAST *ast, const Redis
   }
 }
 
+// FUSION combines the two branches of a hybrid KNN query, which must be the root of the query.
+// The windows default to the K of the query.
+static int applyFusionOptions(const QueryAST *ast, FusionOptions *fusion, QueryError *status) {
+  const QueryNode *root = ast->root;
+  if (!root || root->type != QN_VECTOR || root->vn.vq->type != VECSIM_QT_KNN ||
+      QueryNode_NumChildren(root) != 1) {
+    QueryError_SetError(status, QUERY_EINVAL,
+                        "FUSION requires a hybrid KNN query: `(<query>)=>[KNN ...]`");
+    return REDISMODULE_ERR;
+  }
+  size_t k = root->vn.vq->knn.k;
+  if (!fusion->lexicalWindow) {
+    fusion->lexicalWindow = k;
+  }
+  if (!fusion->vectorWindow) {
+    fusion->vectorWindow = k;
+  }
+  return REDISMODULE_OK;
+}
+
 int AREQ_ApplyContext(AREQ *req, RedisSearchCtx *sctx, QueryError *status) {
   // Sort through the applicable options:
   IndexSpec *index = sctx->spec;
@@ -897,6 +1040,13 @@ int AREQ_ApplyContext(AREQ *req, RedisSearchCtx *sctx, QueryError *status) {
     return REDISMODULE_ERR;
   }
 
+  QAST_Analyze(ast, sctx->spec, opts);
+
+  if (opts->fusion.method != FUSION_NONE &&
+      applyFusionOptions(ast, &opts->fusion, status) != REDISMODULE_OK) {
+    return REDISMODULE_ERR;
+  }
+
   if (!(opts->flags & Search_Verbatim)) {
     if (QAST_Expand(ast, opts->expanderName, opts, sctx, status) != REDISMODULE_OK) {
       return REDISMODULE_ERR;
@@ -1112,11 +1262,137 @@ static ResultProcessor *getArrangeRP(AREQ *req, AGGPlan *pln, const PLN_BaseStep
   return rp;
 }
 
+/**
+ * Builds the processors of a single facet. Steps are chained like in the main
+ * pipeline, but are owned by the facet and read the rows of `lookup`.
+ */
+static int buildFacetPipeline(RPFacets *rpf, AGGPlan *pln, RLookup *lookup, QueryError *status) {
+  for (const DLLIST_node *nn = pln->steps.next; nn != &pln->steps; nn = nn->next) {
+    PLN_BaseStep *stp = DLLIST_ITEM(nn, PLN_BaseStep, llnodePln);
+
+    switch (stp->type) {
+      case PLN_T_GROUP: {
+        PLN_GroupStep *gstp = (PLN_GroupStep *)stp;
+        ResultProcessor *rp = buildGroupRP(gstp, lookup, status);
+        if (!rp) {
+          return REDISMODULE_ERR;
+        }
+        RPFacets_PushFacetRP(rpf, rp);
+        lookup = &gstp->lookup;
+        break;
+      }
+
+      case PLN_T_APPLY:
+      case PLN_T_FILTER: {
+        PLN_MapFilterStep *mstp = (PLN_MapFilterStep *)stp;
+        if (!mstp->parsedExpr) {
+          mstp->parsedExpr = ExprAST_Parse(mstp->rawExpr, strlen(mstp->rawExpr), status);
+          if (!mstp->parsedExpr) {
+            return REDISMODULE_ERR;
+          }
+        }
+        if (!ExprAST_GetLookupKeys(mstp->parsedExpr, lookup, status)) {
+          return REDISMODULE_ERR;
+        }
+        if (stp->type == PLN_T_APPLY) {
+          RLookupKey *dstkey = RLookup_GetKey(lookup, stp->alias, RLOOKUP_F_OCREAT);
+          RPFacets_PushFacetRP(rpf, RPEvaluator_NewProjector(mstp->parsedExpr, lookup, dstkey));
+        } else {
+          RPFacets_PushFacetRP(rpf, RPEvaluator_NewFilter(mstp->parsedExpr, lookup));
+        }
+        break;
+      }
+
+      case PLN_T_ARRANGE: {
+        PLN_ArrangeStep *astp = (PLN_ArrangeStep *)stp;
+        size_t limit = astp->offset + astp->limit;
+        if (!limit) {
+          limit = DEFAULT_LIMIT;
+        }
+        if (astp->sortKeys) {
+          size_t nkeys = array_len(astp->sortKeys);
+          astp->sortkeysLK = rm_malloc(sizeof(*astp->sortKeys) * nkeys);
+          for (size_t ii = 0; ii < nkeys; ++ii) {
+            const char *keystr = astp->sortKeys[ii];
+            astp->sortkeysLK[ii] = RLookup_GetKey(lookup, keystr, RLOOKUP_F_NOFLAGS);
+            if (!astp->sortkeysLK[ii]) {
+              QueryError_SetErrorFmt(status, QUERY_ENOPROPKEY, "Property `%s` not loaded nor in schema", keystr);
+              return REDISMODULE_ERR;
+            }
+          }
+          // Facet rows are not documents, so there is nothing to load
+          RPFacets_PushFacetRP(rpf, RPSorter_NewByFields(limit, astp->sortkeysLK, nkeys, NULL, 0,
+                                                         astp->sortAscMap, false));
+        }
+        if (astp->offset || (astp->limit && !astp->sortKeys)) {
+          RPFacets_PushFacetRP(rpf, RPPager_New(astp->offset, astp->limit));
+        }
+        break;
+      }
+
+      case PLN_T_ROOT:
+        // Placeholder step of the facet plan
+        break;
+      default:
+        RS_LOG_ASSERT(0, "Unexpected step in FACET");
+    }
+  }
+
+  RPFacets_SetFacetLookup(rpf, lookup);
+  return REDISMODULE_OK;
+}
+
+static ResultProcessor *getFacetsRP(AREQ *req, PLN_FacetStep *fstp, ResultProcessor *rpUpstream,
+                                    QueryError *status) {
+  AGGPlan *pln = &req->ap;
+  RLookup *lookup = AGPLN_GetLookup(pln, &fstp->base, AGPLN_GETLOOKUP_PREV);
+  const RLookupKey *mergeKey = NULL;
+  if (fstp->isMerge) {
+    mergeKey = RLookup_GetKey(lookup, PLN_FACET_NAME_FIELD, RLOOKUP_F_OCREAT);
+  }
+
+  RPFacets *rpf = RPFacets_New(lookup, mergeKey);
+  RLookupKey *lastKey = lookup->tail;
+  for (size_t ii = 0; ii < array_len(fstp->facets); ++ii) {
+    RPFacets_AddFacet(rpf, fstp->facets[ii].name);
+    if (buildFacetPipeline(rpf, fstp->facets[ii].plan, lookup, status) != REDISMODULE_OK) {
+      RPFacets_GetRP(rpf)->Free(RPFacets_GetRP(rpf));
+      return NULL;
+    }
+  }
+
+  // Fields only used by the facets are not part of the main rows
+  for (RLookupKey *kk = lastKey ? lastKey->next : lookup->head; kk; kk = kk->next) {
+    kk->flags |= RLOOKUP_F_HIDDEN;
+  }
+
+  // Same as for GROUPBY: load the fields the facets need, if nothing did yet
+  RLookup *firstLk = AGPLN_GetLookup(pln, &fstp->base, AGPLN_GETLOOKUP_FIRST);
+  if (firstLk == lookup) {
+    const RLookupKey **kklist = NULL;
+    for (RLookupKey *kk = firstLk->head; kk; kk = kk->next) {
+      if ((kk->flags & RLOOKUP_F_SCHEMASRC) && (!(kk->flags & RLOOKUP_F_SVSRC))) {
+        *array_ensure_tail(&kklist, const RLookupKey *) = kk;
+      }
+    }
+    if (kklist != NULL) {
+      ResultProcessor *rpLoader = RPLoader_New(firstLk, kklist, array_len(kklist));
+      array_free(kklist);
+      RS_LOG_ASSERT(rpLoader, "RPLoader_New failed");
+      rpUpstream = pushRP(req, rpLoader, rpUpstream);
+    }
+  }
+
+  req->facets = rpf;
+  return pushRP(req, RPFacets_GetRP(rpf), rpUpstream);
+}
+
 // Assumes that the spec is locked
 static ResultProcessor *getScorerRP(AREQ *req) {
   const char *scorer = req->searchopts.scorerName;
   if (!scorer) {
-    scorer = DEFAULT_SCORER_NAME;
+    // The lexical branch of a fused query is ranked by BM25, unless asked otherwise
+    scorer = req->searchopts.fusion.method != FUSION_NONE ? BM25_SCORER_NAME : DEFAULT_SCORER_NAME;
   }
   ScoringFunctionArgs scargs = {0};
   if (req->reqflags & QEXEC_F_SEND_SCOREEXPLAIN) {
@@ -1131,6 +1407,48 @@ static ResultProcessor *getScorerRP(AREQ *req) {
   return rp;
 }
 
+static const FieldSpec *getCompressedVectorField(IndexSpec *spec, const VectorQuery *vq) {
+  const FieldSpec *fs = IndexSpec_GetField(spec, vq->property, strlen(vq->property));
+  return fs && FIELD_IS(fs, INDEXFLD_T_VECTOR) && FieldSpec_IsVectorCompressed(fs) ? fs : NULL;
+}
+
+static int isRootOrUncompressedVectorNode(QueryNode *node, QueryNode *root, void *ctx) {
+  return node == root || node->type != QN_VECTOR || !getCompressedVectorField(ctx, node->vn.vq);
+}
+
+/**
+ * The results of a query over a compressed vector field are reranked by the vector loader, which
+ * reads their full precision vectors once they are scored. It drops the results whose full
+ * precision distance doesn't qualify, so the vector query must be the root of the query.
+ * The vector loader also yields the matched vector of a KNN query, which is always the root.
+ * Returns NULL if the query doesn't need it, or on error.
+ */
+static ResultProcessor *getVectorLoaderRP(AREQ *req, RLookup *rl, QueryError *status) {
+  QueryNode *root = req->ast.root;
+  IndexSpec *spec = req->sctx->spec;
+  if (!root) {
+    return NULL;
+  }
+  if (!QueryNode_ForEach(root, isRootOrUncompressedVectorNode, spec, 0)) {
+    QueryError_SetError(status, QUERY_EINVAL,
+                        "A query over a compressed vector field must be the root of the query");
+    return NULL;
+  }
+  if (root->type != QN_VECTOR) {
+    return NULL;
+  }
+  const VectorQuery *vq = root->vn.vq;
+  const FieldSpec *fs = IndexSpec_GetField(spec, vq->property, strlen(vq->property));
+  if (!fs || !FIELD_IS(fs, INDEXFLD_T_VECTOR) || (!FieldSpec_IsVectorCompressed(fs) && !vq->matchField)) {
+    return NULL;
+  }
+  const RLookupKey *distKey =
+      vq->scoreField ? RLookup_GetKey(rl, vq->scoreField, RLOOKUP_F_NOFLAGS) : NULL;
+  const RLookupKey *matchKey =
+      vq->matchField ? RLookup_GetKey(rl, vq->matchField, RLOOKUP_F_NOFLAGS) : NULL;
+  return RPVectorLoader_New(fs, vq, distKey, matchKey);
+}
+
 static int hasQuerySortby(const AGGPlan *pln) {
   const PLN_BaseStep *bstp = AGPLN_FindStep(pln, NULL, NULL, PLN_T_GROUP);
   if (bstp != NULL) {
@@ -1183,12 +1501,32 @@ static void buildImplicitPipeline(AREQ *req, QueryError *Status) {
 
   /** Create a scorer if:
    *  * WITHSCORES is defined
-   *  * there is no subsequent sorter within this grouping */
-  if ((req->reqflags & QEXEC_F_SEND_SCORES) ||
+   *  * there is no subsequent sorter within this grouping
+   *  * the results are fused, which needs the lexical score */
+  int fusion = req->searchopts.fusion.method != FUSION_NONE;
+  if (fusion || (req->reqflags & QEXEC_F_SEND_SCORES) ||
       (!hasQuerySortby(&req->ap) && IsSearch(req) && !IsCount(req))) {
     rp = getScorerRP(req);
     PUSH_RP();
   }
+
+  if (fusion) {
+    RLookupKey *scoreKey = RLookup_GetKey(first, FUSION_SCORE_FIELD, RLOOKUP_F_OCREAT | RLOOKUP_F_HIDDEN);
+    RLookupKey *lexicalKey =
+        RLookup_GetKey(first, FUSION_LEXICAL_SCORE_FIELD, RLOOKUP_F_OCREAT | RLOOKUP_F_HIDDEN);
+    scoreKey->flags |= RLOOKUP_F_ISLOADED;
+    lexicalKey->flags |= RLOOKUP_F_ISLOADED;
+    rp = RPHybridFusion_New(&req->searchopts.fusion, scoreKey, lexicalKey);
+    PUSH_RP();
+  }
+
+  // Rerank the results of a query over a compressed vector field, and yield the matched vectors
+  rp = getVectorLoaderRP(req, first, Status);
+  if (rp) {
+    PUSH_RP();
+  } else if (QueryError_HasError(Status)) {
+    return;
+  }
 }
 
 /**
@@ -1380,9 +1718,11 @@ int AREQ_BuildPipeline(AREQ *req, int options, QueryError *status) {
         PLN_MapFilterStep *mstp = (PLN_MapFilterStep *)stp;
         // Ensure the lookups can actually find what they need
         RLookup *curLookup = AGPLN_GetLookup(pln, stp, AGPLN_GETLOOKUP_PREV);
-        mstp->parsedExpr = ExprAST_Parse(mstp->rawExpr, strlen(mstp->rawExpr), status);
         if (!mstp->parsedExpr) {
-          goto error;
+          mstp->parsedExpr = ExprAST_Parse(mstp->rawExpr, strlen(mstp->rawExpr), status);
+          if (!mstp->parsedExpr) {
+            goto error;
+          }
         }
 
         if (!ExprAST_GetLookupKeys(mstp->parsedExpr, curLookup, status)) {
@@ -1448,6 +1788,13 @@ int AREQ_BuildPipeline(AREQ *req, int options, QueryError *status) {
         }
         break;
       }
+      case PLN_T_FACET: {
+        rpUpstream = getFacetsRP(req, (PLN_FacetStep *)stp, rpUpstream, status);
+        if (!rpUpstream) {
+          goto error;
+        }
+        break;
+      }
       case PLN_T_ROOT:
         // Placeholder step for initial lookup
         break;
@@ -90,11 +90,12 @@ RSExpr *RS_NewPredicate(RSCondition cond, RSExpr *left, RSExpr *right) {
   return e;
 }
 
-RSExpr *RS_NewFunc(const char *str, size_t len, RSArgList *args, RSFunction cb) {
+RSExpr *RS_NewFunc(const char *str, size_t len, RSArgList *args, const RSFunctionInfo *info) {
   RSExpr *e = newExpr(RSExpr_Function);
   e->func.args = args;
   e->func.name = rm_strndup(str, len);
-  e->func.Call = cb;
+  e->func.Call = info->f;
+  e->func.ext = info->ext;
   return e;
 }
 
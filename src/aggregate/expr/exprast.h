@@ -22,7 +22,7 @@ RSExpr *RS_NewStringLiteral(const char *str, size_t len);
 RSExpr *RS_NewNullLiteral();
 RSExpr *RS_NewNumberLiteral(double n);
 RSExpr *RS_NewOp(unsigned char op, RSExpr *left, RSExpr *right);
-RSExpr *RS_NewFunc(const char *str, size_t len, RSArgList *args, RSFunction cb);
+RSExpr *RS_NewFunc(const char *str, size_t len, RSArgList *args, const RSFunctionInfo *info);
 RSExpr *RS_NewProp(const char *str, size_t len);
 RSExpr *RS_NewPredicate(RSCondition cond, RSExpr *left, RSExpr *right);
 RSExpr *RS_NewInverted(RSExpr *child);
@@ -41,7 +41,11 @@ static int evalFunc(ExprEval *eval, const RSFunctionExpr *f, RSValue *result) {
   }
 
   /** We pass an RSValue**, not an RSValue*, as the arguments */
-  rc = f->Call(eval, result, argspp, nargs, eval->err);
+  if (f->ext) {
+    rc = Extensions_CallExprFunction(f->ext, result, argspp, nargs, eval->err);
+  } else {
+    rc = f->Call(eval, result, argspp, nargs, eval->err);
+  }
 
 cleanup:
   for (size_t ii = 0; ii < nusedargs; ii++) {
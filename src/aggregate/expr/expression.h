@@ -93,6 +93,7 @@ typedef struct {
   const char *name;
   RSArgList *args;
   RSFunction Call;
+  const ExtExprFunctionCtx *ext;  // Set instead of Call for functions registered by extensions
 } RSFunctionExpr;
 
 typedef struct {
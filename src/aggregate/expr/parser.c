@@ -1054,13 +1054,19 @@ static YYACTIONTYPE yy_reduce(
         break;
       case 22: /* expr ::= SYMBOL LP arglist RP */
 {
-    RSFunction cb = RSFunctionRegistry_Get(yymsp[-3].minor.yy0.s, yymsp[-3].minor.yy0.len);
-    if (!cb) {
+    const RSFunctionInfo *info = RSFunctionRegistry_Get(yymsp[-3].minor.yy0.s, yymsp[-3].minor.yy0.len);
+    if (!info) {
         rm_asprintf(&ctx->errorMsg, "Unknown function name '%.*s'", yymsp[-3].minor.yy0.len, yymsp[-3].minor.yy0.s);
         ctx->ok = 0;
+        RSArgList_Free(yymsp[-1].minor.yy46);
         yylhsminor.yy19 = NULL; 
+    } else if (yymsp[-1].minor.yy46->len < info->minargs || (info->maxargs >= 0 && yymsp[-1].minor.yy46->len > info->maxargs)) {
+        rm_asprintf(&ctx->errorMsg, "Invalid number of arguments for function '%.*s'", yymsp[-3].minor.yy0.len, yymsp[-3].minor.yy0.s);
+        ctx->ok = 0;
+        RSArgList_Free(yymsp[-1].minor.yy46);
+        yylhsminor.yy19 = NULL;
     } else {
-         yylhsminor.yy19 = RS_NewFunc(yymsp[-3].minor.yy0.s, yymsp[-3].minor.yy0.len, yymsp[-1].minor.yy46, cb);
+         yylhsminor.yy19 = RS_NewFunc(yymsp[-3].minor.yy0.s, yymsp[-3].minor.yy0.len, yymsp[-1].minor.yy46, info);
     }
 }
   yymsp[-3].minor.yy19 = yylhsminor.yy19;
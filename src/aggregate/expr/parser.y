@@ -78,13 +78,19 @@ number(A) ::= MINUS NUMBER(B). { A = -B.numval; }
 
 expr(A) ::= PROPERTY(B). { A = RS_NewProp(B.s, B.len); }
 expr(A) ::= SYMBOL(B) LP arglist(C) RP. {
-    RSFunction cb = RSFunctionRegistry_Get(B.s, B.len);
-    if (!cb) {
+    const RSFunctionInfo *info = RSFunctionRegistry_Get(B.s, B.len);
+    if (!info) {
         rm_asprintf(&ctx->errorMsg, "Unknown function name '%.*s'", B.len, B.s);
         ctx->ok = 0;
+        RSArgList_Free(C);
         A = NULL; 
+    } else if (C->len < info->minargs || (info->maxargs >= 0 && C->len > info->maxargs)) {
+        rm_asprintf(&ctx->errorMsg, "Invalid number of arguments for function '%.*s'", B.len, B.s);
+        ctx->ok = 0;
+        RSArgList_Free(C);
+        A = NULL;
     } else {
-         A = RS_NewFunc(B.s, B.len, C, cb);
+         A = RS_NewFunc(B.s, B.len, C, info);
     }
 }
 
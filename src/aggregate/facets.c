@@ -0,0 +1,260 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "aggregate.h"
+#include "rmalloc.h"
+#include "util/arr.h"
+
+/**
+ * Facets run several aggregation pipelines over the rows of a single query.
+ *
+ * The `RPFacets` processor is placed in the main chain, where the FACET step
+ * appears. Every row pulled through it is copied into each facet pipeline and
+ * then passed on unchanged. The facet pipelines are regular result processor
+ * chains, whose first processor is a `FacetSource`: it yields the row that is
+ * currently being fed and returns RS_RESULT_PAUSED when there is none, so the
+ * grouper of the facet accumulates the row and hands control back to us.
+ *
+ * Once the main chain is exhausted, the facet pipelines are read one after
+ * the other by RPFacets_Next().
+ *
+ * On the coordinator, the facets merge the facet rows sent by the shards
+ * instead: these are recognized by their facet name, and are not passed on.
+ */
+
+typedef struct {
+  ResultProcessor base;
+  const RLookup *lookup;  // lookup of the rows fed to the facet
+  SearchResult *pending;  // row waiting to be consumed
+  int done;
+} FacetSource;
+
+typedef struct {
+  const char *name;
+  RSValue *nameValue;
+  FacetSource *source;
+
+  // First grouper of the facet, which accumulates the rows
+  ResultProcessor *accum;
+  // Last processor of the facet
+  ResultProcessor *end;
+
+  // Lookup of the rows yielded by `end`, and the key holding the facet name
+  RLookup *lookup;
+  const RLookupKey *nameKey;
+} Facet;
+
+struct RPFacets {
+  ResultProcessor base;
+  const RLookup *srclookup;
+  Facet *facets;  // array_*
+
+  // Coordinator only: key holding the facet name of the rows sent by the shards.
+  // These rows are routed to their facet only.
+  const RLookupKey *mergeKey;
+
+  // Facet pipelines must not touch the state of the main query (spec lock,
+  // total results), so they get their own iterator context. Only the error
+  // object is shared, and it may change between cursor reads.
+  QueryIterator qiter;
+
+  SearchResult scratch;
+  int upstreamDone;
+  size_t curFacet;
+};
+
+static int facetSourceNext(ResultProcessor *base, SearchResult *r) {
+  FacetSource *src = (FacetSource *)base;
+  if (src->done) {
+    return RS_RESULT_EOF;
+  }
+  if (!src->pending) {
+    return RS_RESULT_PAUSED;
+  }
+
+  const SearchResult *in = src->pending;
+  src->pending = NULL;
+
+  r->docId = in->docId;
+  r->score = in->score;
+  r->rowdata.sv = in->rowdata.sv;
+  for (const RLookupKey *kk = src->lookup->head; kk; kk = kk->next) {
+    if (in->rowdata.dyn && array_len(in->rowdata.dyn) > kk->dstidx && in->rowdata.dyn[kk->dstidx]) {
+      RLookup_WriteKey(kk, &r->rowdata, in->rowdata.dyn[kk->dstidx]);
+    }
+  }
+  return RS_RESULT_OK;
+}
+
+static void facetSourceFree(ResultProcessor *base) {
+  rm_free(base);
+}
+
+static void syncIterator(RPFacets *self) {
+  self->qiter.err = self->base.parent->err;
+  self->qiter.timeoutPolicy = self->base.parent->timeoutPolicy;
+}
+
+/* Hands a row to the facet and lets its accumulating processor consume it */
+static int feedFacet(RPFacets *self, Facet *f, SearchResult *r) {
+  syncIterator(self);
+  f->source->pending = r;
+  int rc = f->accum->Next(f->accum, &self->scratch);
+  f->source->pending = NULL;
+  SearchResult_Clear(&self->scratch);
+  return rc == RS_RESULT_PAUSED ? RS_RESULT_OK : rc;
+}
+
+static Facet *getFacetByName(RPFacets *self, const RSValue *name) {
+  size_t n;
+  const char *s = RSValue_StringPtrLen(name, &n);
+  if (!s) {
+    return NULL;
+  }
+  for (size_t ii = 0; ii < array_len(self->facets); ++ii) {
+    if (strlen(self->facets[ii].name) == n && !strncmp(self->facets[ii].name, s, n)) {
+      return self->facets + ii;
+    }
+  }
+  return NULL;
+}
+
+static int rpfacetsNext(ResultProcessor *base, SearchResult *r) {
+  RPFacets *self = (RPFacets *)base;
+  if (self->upstreamDone) {
+    return RS_RESULT_EOF;
+  }
+
+  int rc;
+  while ((rc = base->upstream->Next(base->upstream, r)) == RS_RESULT_OK) {
+    if (!self->mergeKey) {
+      for (size_t ii = 0; ii < array_len(self->facets); ++ii) {
+        rc = feedFacet(self, self->facets + ii, r);
+        if (rc != RS_RESULT_OK) {
+          return rc;
+        }
+      }
+      return RS_RESULT_OK;
+    }
+
+    // Merging: only the facet rows of the shards are fed to the facets
+    RSValue *name = RLookup_GetItem(self->mergeKey, &r->rowdata);
+    if (!name || RSValue_IsNull(name)) {
+      return RS_RESULT_OK;
+    }
+    // The shard counted the row in its total, but it isn't passed on. The total
+    // may have been sent already with a previous cursor chunk.
+    if (base->parent->totalResults) {
+      base->parent->totalResults--;
+    }
+    Facet *f = getFacetByName(self, name);
+    rc = f ? feedFacet(self, f, r) : RS_RESULT_OK;
+    SearchResult_Clear(r);
+    if (rc != RS_RESULT_OK) {
+      return rc;
+    }
+  }
+
+  if (rc == RS_RESULT_EOF) {
+    self->upstreamDone = 1;
+  }
+  return rc;
+}
+
+static void freeFacet(Facet *f) {
+  ResultProcessor *rp = f->end;
+  while (rp) {
+    ResultProcessor *next = rp->upstream;
+    rp->Free(rp);
+    rp = next;
+  }
+  RSValue_Decref(f->nameValue);
+}
+
+static void rpfacetsFree(ResultProcessor *base) {
+  RPFacets *self = (RPFacets *)base;
+  for (size_t ii = 0; ii < array_len(self->facets); ++ii) {
+    freeFacet(self->facets + ii);
+  }
+  array_free(self->facets);
+  SearchResult_Destroy(&self->scratch);
+  rm_free(self);
+}
+
+RPFacets *RPFacets_New(const RLookup *srclookup, const RLookupKey *mergeKey) {
+  RPFacets *ret = rm_calloc(1, sizeof(*ret));
+  ret->srclookup = srclookup;
+  ret->mergeKey = mergeKey;
+  ret->facets = array_new(Facet, 4);
+
+  ret->base.type = RP_FACETS;
+  ret->base.Next = rpfacetsNext;
+  ret->base.Free = rpfacetsFree;
+  return ret;
+}
+
+ResultProcessor *RPFacets_GetRP(RPFacets *rpf) {
+  return &rpf->base;
+}
+
+void RPFacets_AddFacet(RPFacets *rpf, const char *name) {
+  FacetSource *src = rm_calloc(1, sizeof(*src));
+  src->lookup = rpf->srclookup;
+  src->base.type = RP_FACETS;
+  src->base.Next = facetSourceNext;
+  src->base.Free = facetSourceFree;
+  src->base.parent = &rpf->qiter;
+
+  Facet f = {.name = name,
+             .nameValue = RS_NewCopiedString(name, strlen(name)),
+             .source = src,
+             .end = &src->base};
+  rpf->facets = array_append(rpf->facets, f);
+}
+
+void RPFacets_PushFacetRP(RPFacets *rpf, ResultProcessor *rp) {
+  Facet *f = &array_tail(rpf->facets);
+  rp->upstream = f->end;
+  rp->parent = &rpf->qiter;
+  f->end = rp;
+  if (!f->accum && rp->type == RP_GROUP) {
+    f->accum = rp;
+  }
+}
+
+void RPFacets_SetFacetLookup(RPFacets *rpf, RLookup *lookup) {
+  Facet *f = &array_tail(rpf->facets);
+  f->lookup = lookup;
+  f->nameKey = RLookup_GetKey(lookup, PLN_FACET_NAME_FIELD, RLOOKUP_F_OCREAT);
+}
+
+int RPFacets_Next(RPFacets *rpf, SearchResult *r, RLookup **lookup) {
+  // The main chain may stop pulling before its upstream is exhausted (e.g. LIMIT),
+  // but the facets must still see every row of the query.
+  while (!rpf->upstreamDone) {
+    int rc = rpfacetsNext(&rpf->base, r);
+    SearchResult_Clear(r);
+    if (rc != RS_RESULT_OK && rc != RS_RESULT_EOF) {
+      return rc;
+    }
+  }
+
+  syncIterator(rpf);
+  while (rpf->curFacet < array_len(rpf->facets)) {
+    Facet *f = rpf->facets + rpf->curFacet;
+    f->source->done = 1;
+    int rc = f->end->Next(f->end, r);
+    if (rc == RS_RESULT_OK) {
+      RLookup_WriteKey(f->nameKey, &r->rowdata, f->nameValue);
+      *lookup = f->lookup;
+      return RS_RESULT_OK;
+    } else if (rc != RS_RESULT_EOF) {
+      return rc;
+    }
+    rpf->curFacet++;
+  }
+  return RS_RESULT_EOF;
+}
@@ -66,9 +66,12 @@ time_t fast_timegm(const struct tm *ltm) {
   long tyears, tdays, leaps, utc_hrs;
 
   tyears = ltm->tm_year - 70;  // tm->tm_year is from 1900.
-  leaps = (tyears + 2) / 4;    // no of next two lines until year 2100.
+  leaps = (tyears + 1) / 4;    // leap days of the previous years, valid until year 2100.
   // i = (ltm->tm_year – 100) / 100;
   // leaps -= ( (i/4)*3 + i%4 );
+  if ((tyears + 2) % 4 == 0 && ltm->tm_mon > 1) {
+    leaps++;  // the leap day of the current year has passed
+  }
   tdays = mon_days[ltm->tm_mon];
 
   tdays += ltm->tm_mday - 1;  // days of month passed.
@@ -77,21 +80,59 @@ time_t fast_timegm(const struct tm *ltm) {
   return (tdays * 86400) + (ltm->tm_hour * 3600) + (ltm->tm_min * 60) + ltm->tm_sec;
 }
 
+static const char *dateUnitNames_g[] = {
+    [RSDateUnit_Minute] = "minute", [RSDateUnit_Hour] = "hour",   [RSDateUnit_Day] = "day",
+    [RSDateUnit_Month] = "month",   [RSDateUnit_Year] = "year",
+};
+
+int RSDate_ParseUnit(const char *s, size_t len, RSDateUnit *unit) {
+  for (size_t ii = 0; ii < sizeof(dateUnitNames_g) / sizeof(dateUnitNames_g[0]); ++ii) {
+    if (strlen(dateUnitNames_g[ii]) == len && !strncasecmp(dateUnitNames_g[ii], s, len)) {
+      *unit = ii;
+      return 1;
+    }
+  }
+  return 0;
+}
+
+int RSDate_Truncate(double d, RSDateUnit unit, double *out) {
+  if (d < 0) {
+    return 0;
+  }
+  if (unit == RSDateUnit_Minute) {
+    *out = floor(d - fmod(d, 60));
+    return 1;
+  }
+
+  time_t ts = (time_t)d;
+  struct tm tmm;
+  gmtime_r(&ts, &tmm);
+  switch (unit) {
+    case RSDateUnit_Year:
+      tmm.tm_mon = 0;
+      // fall through
+    case RSDateUnit_Month:
+      tmm.tm_mday = 1;
+      // fall through
+    case RSDateUnit_Day:
+      tmm.tm_hour = 0;
+      // fall through
+    default:
+      tmm.tm_min = 0;
+      tmm.tm_sec = 0;
+  }
+  *out = (double)fast_timegm(&tmm);
+  return 1;
+}
+
 static int func_hour(ExprEval *ctx, RSValue *result, RSValue **argv, size_t argc, QueryError *err) {
   VALIDATE_ARGS("hour", 1, 1, err);
 
   double d;
-  if (!RSValue_ToNumber(argv[0], &d) || d < 0) {
+  if (!RSValue_ToNumber(argv[0], &d) || !RSDate_Truncate(d, RSDateUnit_Hour, &d)) {
     goto err;
   }
-  time_t ts = (time_t)d;
-  struct tm tmm;
-
-  gmtime_r(&ts, &tmm);
-  tmm.tm_sec = 0;
-  tmm.tm_min = 0;
-  ts = fast_timegm(&tmm);
-  RSValue_SetNumber(result, (double)ts);
+  RSValue_SetNumber(result, d);
 
   return EXPR_EVAL_OK;
 err:
@@ -106,10 +147,10 @@ static int func_minute(ExprEval *ctx, RSValue *result, RSValue **argv, size_t ar
   VALIDATE_ARGS("minute", 1, 1, err);
 
   double d;
-  if (!RSValue_ToNumber(argv[0], &d) || d < 0) {
+  if (!RSValue_ToNumber(argv[0], &d) || !RSDate_Truncate(d, RSDateUnit_Minute, &d)) {
     goto err;
   }
-  RSValue_SetNumber(result, floor(d - fmod(d, 60)));
+  RSValue_SetNumber(result, d);
   return EXPR_EVAL_OK;
 err:
   // on runtime error (bad formatting, etc) we just set the result to null
@@ -123,18 +164,10 @@ static int func_day(ExprEval *ctx, RSValue *result, RSValue **argv, size_t argc,
   VALIDATE_ARGS("day", 1, 1, err);
 
   double d;
-  if (!RSValue_ToNumber(argv[0], &d) || d < 0) {
+  if (!RSValue_ToNumber(argv[0], &d) || !RSDate_Truncate(d, RSDateUnit_Day, &d)) {
     goto err;
   }
-  time_t ts = (time_t)d;
-  struct tm tmm;
-
-  gmtime_r(&ts, &tmm);
-  tmm.tm_sec = 0;
-  tmm.tm_hour = 0;
-  tmm.tm_min = 0;
-  ts = fast_timegm(&tmm);
-  RSValue_SetNumber(result, (double)ts);
+  RSValue_SetNumber(result, d);
   return EXPR_EVAL_OK;
 err:
   // on runtime error (bad formatting, etc) we just set the result to null
@@ -231,18 +264,10 @@ static int func_month(ExprEval *ctx, RSValue *result, RSValue **argv, size_t arg
   VALIDATE_ARGS("month", 1, 1, err);
 
   double d;
-  if (!RSValue_ToNumber(argv[0], &d) || d < 0) {
+  if (!RSValue_ToNumber(argv[0], &d) || !RSDate_Truncate(d, RSDateUnit_Month, &d)) {
     goto err;
   }
-  time_t ts = (time_t)d;
-  struct tm tmm;
-  gmtime_r(&ts, &tmm);
-  tmm.tm_sec = 0;
-  tmm.tm_hour = 0;
-  tmm.tm_min = 0;
-  tmm.tm_mday = 1;
-  ts = fast_timegm(&tmm);
-  RSValue_SetNumber(result, (double)ts);
+  RSValue_SetNumber(result, d);
   return EXPR_EVAL_OK;
 err:
   // on runtime error (bad formatting, etc) we just set the result to null
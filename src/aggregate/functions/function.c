@@ -9,29 +9,69 @@
 
 static RSFunctionRegistry functions_g = {0};
 
-RSFunction RSFunctionRegistry_Get(const char *name, size_t len) {
+const RSFunctionInfo *RSFunctionRegistry_Get(const char *name, size_t len) {
 
   for (size_t i = 0; i < functions_g.len; i++) {
     if (len == strlen(functions_g.funcs[i].name) &&
         !strncasecmp(functions_g.funcs[i].name, name, len)) {
-      return functions_g.funcs[i].f;
+      return &functions_g.funcs[i];
     }
   }
   return NULL;
 }
 
-int RSFunctionRegistry_RegisterFunction(const char *name, RSFunction f, RSValueType retType) {
+static RSFunctionInfo *registryAppend(const char *name) {
   if (functions_g.len + 1 >= functions_g.cap) {
     functions_g.cap += functions_g.cap ? functions_g.cap : 2;
     functions_g.funcs = rm_realloc(functions_g.funcs, functions_g.cap * sizeof(*functions_g.funcs));
   }
-  functions_g.funcs[functions_g.len].f = f;
-  functions_g.funcs[functions_g.len].name = name;
-  functions_g.funcs[functions_g.len].retType = retType;
-  functions_g.len++;
+  RSFunctionInfo *info = &functions_g.funcs[functions_g.len++];
+  memset(info, 0, sizeof(*info));
+  info->name = name;
+  info->maxargs = -1;
+  return info;
+}
+
+int RSFunctionRegistry_RegisterFunction(const char *name, RSFunction f, RSValueType retType) {
+  RSFunctionInfo *info = registryAppend(name);
+  info->f = f;
+  info->retType = retType;
+  return 1;
+}
+
+int RSFunctionRegistry_RegisterExtFunction(const ExtExprFunctionCtx *ext, unsigned minargs,
+                                           int maxargs) {
+  if (RSFunctionRegistry_Get(ext->name, strlen(ext->name))) {
+    return 0;
+  }
+  RSFunctionInfo *info = registryAppend(ext->name);
+  info->ext = ext;
+  info->minargs = minargs;
+  info->maxargs = maxargs;
+  switch (ext->retType) {
+    case RSExprValue_Number:
+      info->retType = RSValue_Number;
+      break;
+    case RSExprValue_String:
+      info->retType = RSValue_String;
+      break;
+    default:
+      info->retType = RSValue_Null;
+      break;
+  }
   return 1;
 }
 
+void RSFunctionRegistry_RemoveExtFunctions() {
+  size_t n = 0;
+  for (size_t i = 0; i < functions_g.len; i++) {
+    if (!functions_g.funcs[i].ext) {
+      functions_g.funcs[n++] = functions_g.funcs[i];
+    }
+  }
+  functions_g.len = n;
+}
+
 void RegisterAllFunctions() {
   RegisterMathFunctions();
   RegisterDateFunctions();
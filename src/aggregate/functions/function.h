@@ -11,6 +11,7 @@
 #include <util/block_alloc.h>
 #include <result_processor.h>
 #include <query_error.h>
+#include <extension.h>
 #ifdef __cplusplus
 extern "C" {
 #endif
@@ -68,16 +69,51 @@ typedef struct {
     const char *name;
     RSValueType retType;
     unsigned minargs;
-    int maxargs;
+    int maxargs;  // -1 if unbounded
+    const ExtExprFunctionCtx *ext;  // Set for functions registered by extensions
   } * funcs;
 } RSFunctionRegistry;
 
 typedef struct RSFunctionInfo RSFunctionInfo;
 
-RSFunction RSFunctionRegistry_Get(const char *name, size_t len);
+const RSFunctionInfo *RSFunctionRegistry_Get(const char *name, size_t len);
 
 int RSFunctionRegistry_RegisterFunction(const char *name, RSFunction f, RSValueType retType);
 
+/* Register a function implemented by an extension. Its arguments count is validated when the
+ * expression is parsed. Returns 0 if a function with the same name already exists */
+int RSFunctionRegistry_RegisterExtFunction(const ExtExprFunctionCtx *ext, unsigned minargs,
+                                           int maxargs);
+
+/* Remove the functions registered by extensions, before their contexts are freed */
+void RSFunctionRegistry_RemoveExtFunctions();
+
+/* Calendar units for rounding timestamps */
+typedef enum {
+  RSDateUnit_Minute,
+  RSDateUnit_Hour,
+  RSDateUnit_Day,
+  RSDateUnit_Month,
+  RSDateUnit_Year,
+} RSDateUnit;
+
+/* Parse a calendar unit name (minute, hour, day, month or year). Returns 0 if it is unknown */
+int RSDate_ParseUnit(const char *s, size_t len, RSDateUnit *unit);
+
+/* Round a timestamp down to the beginning of its calendar unit. Returns 0 if the timestamp is
+ * invalid */
+int RSDate_Truncate(double ts, RSDateUnit unit, double *out);
+
+/* Parse a geo value, either a "lon,lat" string or an encoded geo sort key, into `geo` as
+ * {lon, lat}. Returns REDISMODULE_OK or REDISMODULE_ERR */
+int RSGeo_ParseValue(RSValue *v, double *geo);
+
+#define RSGEO_GEOHASH_MAX_PRECISION 12
+
+/* Write the base32 geohash of a point, `precision` characters long, into `out`. Returns 0 if the
+ * coordinates are out of range */
+int RSGeo_Geohash(double lon, double lat, int precision, char *out);
+
 void RegisterMathFunctions();
 void RegisterStringFunctions();
 void RegisterDateFunctions();
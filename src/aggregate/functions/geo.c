@@ -10,9 +10,10 @@
 #include "rs_geo.h"
 
 #include <err.h>
+#include <math.h>
 
 // parse "x,y"
-static int parseField(RSValue *argv, double *geo) {
+int RSGeo_ParseValue(RSValue *argv, double *geo) {
   int rv = REDISMODULE_OK;
   RSValue *val = RSValue_Dereference(argv);
 
@@ -54,7 +55,7 @@ static int geofunc_distance(ExprEval *ctx, RSValue *result,
       rv = parseLonLat(argv[j], argv[j + 1], geo[i]);
       j += 2;
     } else {
-      rv = parseField(argv[j], geo[i]);
+      rv = RSGeo_ParseValue(argv[j], geo[i]);
       j += 1;
     }
     if (rv != REDISMODULE_OK) goto error;
@@ -70,6 +71,59 @@ error:
   return EXPR_EVAL_OK;
 }
 
+static const char geohashAlphabet[] = "0123456789bcdefghjkmnpqrstuvwxyz";
+
+int RSGeo_Geohash(double lon, double lat, int precision, char *out) {
+  if (!(lon >= -180 && lon <= 180 && lat >= -90 && lat <= 90)) {
+    return 0;
+  }
+  double lonRange[2] = {-180, 180}, latRange[2] = {-90, 90};
+  // Every character encodes 5 bits, which alternately halve the longitude and latitude ranges
+  int isLon = 1;
+  for (int i = 0; i < precision; ++i) {
+    int idx = 0;
+    for (int bit = 0; bit < 5; ++bit, isLon = !isLon) {
+      double *range = isLon ? lonRange : latRange;
+      double val = isLon ? lon : lat;
+      double mid = (range[0] + range[1]) / 2;
+      idx <<= 1;
+      if (val >= mid) {
+        idx |= 1;
+        range[0] = mid;
+      } else {
+        range[1] = mid;
+      }
+    }
+    out[i] = geohashAlphabet[idx];
+  }
+  out[precision] = '\0';
+  return 1;
+}
+
+/* geohash_grid(@loc, precision): the geohash cell of a point, for clustering points on a map */
+static int geofunc_geohash_grid(ExprEval *ctx, RSValue *result,
+                                RSValue **argv, size_t argc, QueryError *err) {
+  VALIDATE_ARGS("geohash_grid", 2, 2, err);
+  double geo[2], precision;
+  if (!RSValue_ToNumber(argv[1], &precision) || precision != floor(precision) || precision < 1 ||
+      precision > RSGEO_GEOHASH_MAX_PRECISION) {
+    QueryError_SetErrorFmt(err, QUERY_EPARSEARGS,
+                           "geohash_grid precision must be an integer between 1 and %d",
+                           RSGEO_GEOHASH_MAX_PRECISION);
+    return EXPR_EVAL_ERR;
+  }
+
+  char *hash = ExprEval_UnalignedAlloc(ctx, RSGEO_GEOHASH_MAX_PRECISION + 1);
+  if (RSGeo_ParseValue(argv[0], geo) != REDISMODULE_OK ||
+      !RSGeo_Geohash(geo[0], geo[1], precision, hash)) {
+    RSValue_MakeReference(result, RS_NullVal());
+    return EXPR_EVAL_OK;
+  }
+  RSValue_SetConstString(result, hash, precision);
+  return EXPR_EVAL_OK;
+}
+
 void RegisterGeoFunctions() {
   RSFunctionRegistry_RegisterFunction("geodistance", geofunc_distance, RSValue_String);
+  RSFunctionRegistry_RegisterFunction("geohash_grid", geofunc_geohash_grid, RSValue_String);
 }
\ No newline at end of file
@@ -34,6 +34,23 @@ NUMERIC_SIMPLE_FUNCTION(sqrt);
 NUMERIC_SIMPLE_FUNCTION(log2);
 NUMERIC_SIMPLE_FUNCTION(exp);
 
+/* bucket(x, interval): the lower bound of the fixed-width bucket of x, same as HISTOGRAM INTERVAL */
+static int mathfunc_bucket(ExprEval *ctx, RSValue *result, RSValue **argv, size_t argc,
+                           QueryError *error) {
+  if (argc != 2) {
+    QueryError_SetErrorFmt(error, QUERY_EPARSEARGS, "Invalid number of arguments for bucket");
+    return EXPR_EVAL_ERR;
+  }
+  double d, interval;
+  if (!RSValue_ToNumber(argv[0], &d) || !RSValue_ToNumber(argv[1], &interval) ||
+      !(interval > 0)) {
+    RSValue_SetNumber(result, NAN);
+    return EXPR_EVAL_OK;
+  }
+  RSValue_SetNumber(result, floor(d / interval) * interval);
+  return EXPR_EVAL_OK;
+}
+
 #define REGISTER_MATHFUNC(name, f) \
   RSFunctionRegistry_RegisterFunction(name, mathfunc_##f, RSValue_Number);
 
@@ -45,4 +62,5 @@ void RegisterMathFunctions() {
   REGISTER_MATHFUNC("sqrt", sqrt);
   REGISTER_MATHFUNC("log2", log2);
   REGISTER_MATHFUNC("exp", exp);
+  REGISTER_MATHFUNC("bucket", bucket);
 }
\ No newline at end of file
@@ -19,6 +19,18 @@ void RDCR_RegisterFactory(const char *name, ReducerFactory factory) {
   *tail = ent;
 }
 
+void RDCR_RemoveFactory(ReducerFactory factory) {
+  size_t n = 0;
+  for (size_t ii = 0; ii < array_len(globalRegistry); ++ii) {
+    if (globalRegistry[ii].fn != factory) {
+      globalRegistry[n++] = globalRegistry[ii];
+    }
+  }
+  if (globalRegistry) {
+    globalRegistry = array_trimm_len(globalRegistry, array_len(globalRegistry) - n);
+  }
+}
+
 static int isBuiltinsRegistered = 0;
 
 ReducerFactory RDCR_GetFactory(const char *name) {
@@ -35,20 +47,37 @@ ReducerFactory RDCR_GetFactory(const char *name) {
   return NULL;
 }
 
-#define RDCR_XBUILTIN(X)                           \
-  X(RDCRCount_New, "COUNT")                        \
-  X(RDCRSum_New, "SUM")                            \
-  X(RDCRToList_New, "TOLIST")                      \
-  X(RDCRMin_New, "MIN")                            \
-  X(RDCRMax_New, "MAX")                            \
-  X(RDCRAvg_New, "AVG")                            \
-  X(RDCRCountDistinct_New, "COUNT_DISTINCT")       \
-  X(RDCRCountDistinctish_New, "COUNT_DISTINCTISH") \
-  X(RDCRQuantile_New, "QUANTILE")                  \
-  X(RDCRStdDev_New, "STDDEV")                      \
-  X(RDCRFirstValue_New, "FIRST_VALUE")             \
-  X(RDCRRandomSample_New, "RANDOM_SAMPLE")         \
-  X(RDCRHLL_New, "HLL")                            \
+#define RDCR_XBUILTIN(X)                            \
+  X(RDCRCount_New, "COUNT")                         \
+  X(RDCRSum_New, "SUM")                             \
+  X(RDCRToList_New, "TOLIST")                       \
+  X(RDCRMin_New, "MIN")                             \
+  X(RDCRMax_New, "MAX")                             \
+  X(RDCRAvg_New, "AVG")                             \
+  X(RDCRCountDistinct_New, "COUNT_DISTINCT")        \
+  X(RDCRCountDistinctish_New, "COUNT_DISTINCTISH")  \
+  X(RDCRQuantile_New, "QUANTILE")                   \
+  X(RDCRMedian_New, "MEDIAN")                       \
+  X(RDCRPercentiles_New, "PERCENTILES")             \
+  X(RDCRQuantileSketch_New, "QUANTILE_SKETCH")      \
+  X(RDCRQuantileMerge_New, "QUANTILE_MERGE")        \
+  X(RDCRPercentilesMerge_New, "PERCENTILES_MERGE")  \
+  X(RDCRMode_New, "MODE")                           \
+  X(RDCRModeCounts_New, "MODE_COUNTS")              \
+  X(RDCRModeMerge_New, "MODE_MERGE")                \
+  X(RDCRHistogram_New, "HISTOGRAM")                 \
+  X(RDCRHistogramMerge_New, "HISTOGRAM_MERGE")      \
+  X(RDCRGeoBounds_New, "GEO_BOUNDS")                \
+  X(RDCRGeoBoundsMerge_New, "GEO_BOUNDS_MERGE")     \
+  X(RDCRGeoCentroid_New, "GEO_CENTROID")            \
+  X(RDCRGeoCentroidSums_New, "GEO_CENTROID_SUMS")   \
+  X(RDCRGeoCentroidMerge_New, "GEO_CENTROID_MERGE") \
+  X(RDCRStdDev_New, "STDDEV")                       \
+  X(RDCRFirstValue_New, "FIRST_VALUE")              \
+  X(RDCRRandomSample_New, "RANDOM_SAMPLE")          \
+  X(RDCRTopHits_New, "TOP_HITS")                    \
+  X(RDCRTopHitsMerge_New, "TOP_HITS_MERGE")         \
+  X(RDCRHLL_New, "HLL")                             \
   X(RDCRHLLSum_New, "HLL_SUM")
 
 void RDCR_RegisterBuiltins(void) {
@@ -38,6 +38,9 @@ typedef enum {
 /* Maximum possible value to random sample group size */
 #define MAX_SAMPLE_SIZE 1000
 
+/* Maximum number of rows returned by TOP_HITS for each group */
+#define MAX_TOP_HITS_SIZE 1000
+
 typedef struct Reducer {
   /**
    * Most reducers only operate on a single source key. This can be used to
@@ -155,15 +158,34 @@ Reducer *RDCRAvg_New(const ReducerOptions *);
 Reducer *RDCRCountDistinct_New(const ReducerOptions *);
 Reducer *RDCRCountDistinctish_New(const ReducerOptions *);
 Reducer *RDCRQuantile_New(const ReducerOptions *);
+Reducer *RDCRMedian_New(const ReducerOptions *);
+Reducer *RDCRPercentiles_New(const ReducerOptions *);
+Reducer *RDCRQuantileSketch_New(const ReducerOptions *);
+Reducer *RDCRQuantileMerge_New(const ReducerOptions *);
+Reducer *RDCRPercentilesMerge_New(const ReducerOptions *);
+Reducer *RDCRMode_New(const ReducerOptions *);
+Reducer *RDCRModeCounts_New(const ReducerOptions *);
+Reducer *RDCRModeMerge_New(const ReducerOptions *);
+Reducer *RDCRHistogram_New(const ReducerOptions *);
+Reducer *RDCRHistogramMerge_New(const ReducerOptions *);
+Reducer *RDCRGeoBounds_New(const ReducerOptions *);
+Reducer *RDCRGeoBoundsMerge_New(const ReducerOptions *);
+Reducer *RDCRGeoCentroid_New(const ReducerOptions *);
+Reducer *RDCRGeoCentroidSums_New(const ReducerOptions *);
+Reducer *RDCRGeoCentroidMerge_New(const ReducerOptions *);
 Reducer *RDCRStdDev_New(const ReducerOptions *);
 Reducer *RDCRFirstValue_New(const ReducerOptions *);
 Reducer *RDCRRandomSample_New(const ReducerOptions *);
+Reducer *RDCRTopHits_New(const ReducerOptions *);
+Reducer *RDCRTopHitsMerge_New(const ReducerOptions *);
 Reducer *RDCRHLL_New(const ReducerOptions *);
 Reducer *RDCRHLLSum_New(const ReducerOptions *);
 
 typedef Reducer *(*ReducerFactory)(const ReducerOptions *);
 ReducerFactory RDCR_GetFactory(const char *name);
 void RDCR_RegisterFactory(const char *name, ReducerFactory factory);
+/* Remove the reducers registered with `factory`, before their names are freed */
+void RDCR_RemoveFactory(ReducerFactory factory);
 void RDCR_RegisterBuiltins(void);
 
 #ifdef __cplusplus
@@ -0,0 +1,230 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "aggregate/reducer.h"
+#include "aggregate/functions/function.h"
+#include <math.h>
+
+typedef enum {
+  GEO_INPUT_POINTS,  // Geo values, "lon,lat" strings or encoded geo sort keys
+  GEO_INPUT_MERGE,   // Partial results of the shards, in a cluster
+} GeoInput;
+
+typedef struct {
+  Reducer base;
+  GeoInput input;
+  // Output the sums of the unit vectors (GEO_CENTROID_SUMS) rather than the centroid
+  int outputSums;
+} GeoReducer;
+
+static int isValidPoint(double lon, double lat) {
+  return lon >= -180 && lon <= 180 && lat >= -90 && lat <= 90;
+}
+
+/* Call `f` on every valid point of a geo value, which may be an array for multi-value fields */
+static void forEachPoint(RSValue *v, void (*f)(void *, double, double), void *instance) {
+  v = RSValue_Dereference(v);
+  if (v->t == RSValue_Array) {
+    uint32_t len = RSValue_ArrayLen(v);
+    for (uint32_t i = 0; i < len; i++) {
+      forEachPoint(RSValue_ArrayItem(v, i), f, instance);
+    }
+    return;
+  }
+  double geo[2];
+  if (RSGeo_ParseValue(v, geo) == REDISMODULE_OK && isValidPoint(geo[0], geo[1])) {
+    f(instance, geo[0], geo[1]);
+  }
+}
+
+/* Read an array of `n` numbers, as returned by the shards. Returns 0 if it isn't one */
+static int getNumbers(RSValue *v, double *out, uint32_t n) {
+  v = RSValue_Dereference(v);
+  if (v->t != RSValue_Array || RSValue_ArrayLen(v) != n) {
+    return 0;
+  }
+  for (uint32_t i = 0; i < n; i++) {
+    if (!RSValue_ToNumber(RSValue_ArrayItem(v, i), out + i)) {
+      return 0;
+    }
+  }
+  return 1;
+}
+
+/***************************************************************************************************
+ * GEO_BOUNDS
+ **************************************************************************************************/
+
+typedef struct {
+  size_t count;
+  double minLon, minLat, maxLon, maxLat;
+} geoBoundsCtx;
+
+static void *boundsNewInstance(Reducer *r) {
+  geoBoundsCtx *ctx = BlkAlloc_Alloc(&r->alloc, sizeof(*ctx), 32 * sizeof(*ctx));
+  *ctx = (geoBoundsCtx){
+      .minLon = INFINITY, .minLat = INFINITY, .maxLon = -INFINITY, .maxLat = -INFINITY};
+  return ctx;
+}
+
+static void boundsAddPoint(void *instance, double lon, double lat) {
+  geoBoundsCtx *ctx = instance;
+  ctx->count++;
+  ctx->minLon = fmin(ctx->minLon, lon);
+  ctx->minLat = fmin(ctx->minLat, lat);
+  ctx->maxLon = fmax(ctx->maxLon, lon);
+  ctx->maxLat = fmax(ctx->maxLat, lat);
+}
+
+static int boundsAdd(Reducer *rbase, void *instance, const RLookupRow *srcrow) {
+  GeoReducer *r = (GeoReducer *)rbase;
+  RSValue *v = RLookup_GetItem(rbase->srckey, srcrow);
+  if (!v || v == RS_NullVal()) {
+    return 1;
+  }
+
+  if (r->input == GEO_INPUT_MERGE) {
+    // The bounds of a shard are the same as its two corners
+    double bounds[4];
+    if (getNumbers(v, bounds, 4)) {
+      boundsAddPoint(instance, bounds[0], bounds[1]);
+      boundsAddPoint(instance, bounds[2], bounds[3]);
+    }
+  } else {
+    forEachPoint(v, boundsAddPoint, instance);
+  }
+  return 1;
+}
+
+static RSValue *boundsFinalize(Reducer *rbase, void *instance) {
+  geoBoundsCtx *ctx = instance;
+  if (!ctx->count) {
+    return RS_NullVal();
+  }
+  RSValue **arr = rm_calloc(4, sizeof(*arr));
+  arr[0] = RS_NumVal(ctx->minLon);
+  arr[1] = RS_NumVal(ctx->minLat);
+  arr[2] = RS_NumVal(ctx->maxLon);
+  arr[3] = RS_NumVal(ctx->maxLat);
+  return RSValue_NewArrayEx(arr, 4, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+}
+
+/***************************************************************************************************
+ * GEO_CENTROID
+ **************************************************************************************************/
+
+// The points are averaged as unit vectors, so the centroid is also right across the antimeridian
+typedef struct {
+  double x, y, z;
+  size_t count;
+} geoCentroidCtx;
+
+static void *centroidNewInstance(Reducer *r) {
+  geoCentroidCtx *ctx = BlkAlloc_Alloc(&r->alloc, sizeof(*ctx), 32 * sizeof(*ctx));
+  *ctx = (geoCentroidCtx){0};
+  return ctx;
+}
+
+static void centroidAddPoint(void *instance, double lon, double lat) {
+  geoCentroidCtx *ctx = instance;
+  double lonRad = lon * M_PI / 180, latRad = lat * M_PI / 180;
+  ctx->x += cos(latRad) * cos(lonRad);
+  ctx->y += cos(latRad) * sin(lonRad);
+  ctx->z += sin(latRad);
+  ctx->count++;
+}
+
+static int centroidAdd(Reducer *rbase, void *instance, const RLookupRow *srcrow) {
+  GeoReducer *r = (GeoReducer *)rbase;
+  geoCentroidCtx *ctx = instance;
+  RSValue *v = RLookup_GetItem(rbase->srckey, srcrow);
+  if (!v || v == RS_NullVal()) {
+    return 1;
+  }
+
+  if (r->input == GEO_INPUT_MERGE) {
+    double sums[4];
+    if (getNumbers(v, sums, 4) && sums[3] > 0) {
+      ctx->x += sums[0];
+      ctx->y += sums[1];
+      ctx->z += sums[2];
+      ctx->count += sums[3];
+    }
+  } else {
+    forEachPoint(v, centroidAddPoint, instance);
+  }
+  return 1;
+}
+
+// Drop the rounding errors of the conversions, so that exact coordinates are returned as such
+static double roundCoordinate(double d) {
+  d = round(d * 1e9) / 1e9;
+  return d == 0 ? 0 : d;  // no -0
+}
+
+static RSValue *centroidFinalize(Reducer *rbase, void *instance) {
+  GeoReducer *r = (GeoReducer *)rbase;
+  geoCentroidCtx *ctx = instance;
+
+  if (r->outputSums) {
+    RSValue **arr = rm_calloc(4, sizeof(*arr));
+    arr[0] = RS_NumVal(ctx->x);
+    arr[1] = RS_NumVal(ctx->y);
+    arr[2] = RS_NumVal(ctx->z);
+    arr[3] = RS_NumVal(ctx->count);
+    return RSValue_NewArrayEx(arr, 4, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+  }
+
+  double norm = sqrt(ctx->x * ctx->x + ctx->y * ctx->y + ctx->z * ctx->z);
+  // No points, or points evenly spread around the globe, have no centroid
+  if (!ctx->count || norm < 1e-12 * ctx->count) {
+    return RS_NullVal();
+  }
+  double lon = atan2(ctx->y, ctx->x) * 180 / M_PI;
+  double lat = atan2(ctx->z, sqrt(ctx->x * ctx->x + ctx->y * ctx->y)) * 180 / M_PI;
+  // Same format as the values of geo fields
+  return RS_StringValFmt("%.12g,%.12g", roundCoordinate(lon), roundCoordinate(lat));
+}
+
+/**************************************************************************************************/
+
+static Reducer *newGeoReducerCommon(const ReducerOptions *options, GeoInput input, int isBounds) {
+  GeoReducer *r = rm_calloc(1, sizeof(*r));
+  if (!ReducerOpts_GetKey(options, &r->base.srckey) || !ReducerOpts_EnsureArgsConsumed(options)) {
+    rm_free(r);
+    return NULL;
+  }
+  r->input = input;
+  r->base.NewInstance = isBounds ? boundsNewInstance : centroidNewInstance;
+  r->base.Add = isBounds ? boundsAdd : centroidAdd;
+  r->base.Finalize = isBounds ? boundsFinalize : centroidFinalize;
+  r->base.Free = Reducer_GenericFree;
+  return &r->base;
+}
+
+Reducer *RDCRGeoBounds_New(const ReducerOptions *options) {
+  return newGeoReducerCommon(options, GEO_INPUT_POINTS, 1);
+}
+
+Reducer *RDCRGeoBoundsMerge_New(const ReducerOptions *options) {
+  return newGeoReducerCommon(options, GEO_INPUT_MERGE, 1);
+}
+
+Reducer *RDCRGeoCentroid_New(const ReducerOptions *options) {
+  return newGeoReducerCommon(options, GEO_INPUT_POINTS, 0);
+}
+
+Reducer *RDCRGeoCentroidSums_New(const ReducerOptions *options) {
+  Reducer *r = newGeoReducerCommon(options, GEO_INPUT_POINTS, 0);
+  if (r) {
+    ((GeoReducer *)r)->outputSums = 1;
+  }
+  return r;
+}
+
+Reducer *RDCRGeoCentroidMerge_New(const ReducerOptions *options) {
+  return newGeoReducerCommon(options, GEO_INPUT_MERGE, 0);
+}
@@ -0,0 +1,246 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "aggregate/reducer.h"
+#include "aggregate/functions/function.h"
+#include "util/khash.h"
+#include <math.h>
+
+static const int khid = 39;
+KHASH_MAP_INIT_INT64(khid, size_t);
+
+typedef enum {
+  HISTOGRAM_INTERVAL,  // Buckets of a fixed width
+  HISTOGRAM_RANGES,    // Buckets between explicit boundaries
+  HISTOGRAM_DATE,      // Calendar buckets of timestamps
+  HISTOGRAM_MERGE,     // Histograms returned by HISTOGRAM, in a cluster
+} HistogramMode;
+
+typedef struct {
+  Reducer base;
+  HistogramMode mode;
+  double interval;
+  double *ranges;  // Ordered boundaries, for HISTOGRAM_RANGES
+  size_t numRanges;
+  RSDateUnit dateUnit;
+} HistogramReducer;
+
+typedef struct {
+  double key;
+  size_t count;
+} histogramBucket;
+
+// Buckets are keyed by the bits of their lower bound
+static uint64_t bucketHashKey(double key) {
+  if (key == 0) {
+    key = 0;  // -0.0 and 0.0 are the same bucket
+  }
+  uint64_t bits;
+  memcpy(&bits, &key, sizeof(bits));
+  return bits;
+}
+
+static void histogramAddBucket(khash_t(khid) * buckets, double key, size_t count) {
+  int ret;
+  khiter_t k = kh_put(khid, buckets, bucketHashKey(key), &ret);
+  if (ret) {
+    kh_value(buckets, k) = 0;
+  }
+  kh_value(buckets, k) += count;
+}
+
+static void *histogramNewInstance(Reducer *rbase) {
+  HistogramReducer *r = (HistogramReducer *)rbase;
+  khash_t(khid) *buckets = kh_init(khid);
+  // Explicit ranges are always returned, even if empty
+  for (size_t ii = 0; r->mode == HISTOGRAM_RANGES && ii + 1 < r->numRanges; ++ii) {
+    histogramAddBucket(buckets, r->ranges[ii], 0);
+  }
+  return buckets;
+}
+
+// Find the bucket of a value. Returns 0 if the value doesn't belong to any bucket
+static int histogramGetKey(const HistogramReducer *r, double d, double *key) {
+  switch (r->mode) {
+    case HISTOGRAM_INTERVAL:
+      *key = floor(d / r->interval) * r->interval;
+      return isfinite(*key);
+    case HISTOGRAM_DATE:
+      return RSDate_Truncate(d, r->dateUnit, key);
+    case HISTOGRAM_RANGES: {
+      if (!(d >= r->ranges[0] && d < r->ranges[r->numRanges - 1])) {
+        return 0;
+      }
+      size_t lo = 0, hi = r->numRanges - 1;
+      while (hi - lo > 1) {
+        size_t mid = (lo + hi) / 2;
+        if (d < r->ranges[mid]) {
+          hi = mid;
+        } else {
+          lo = mid;
+        }
+      }
+      *key = r->ranges[lo];
+      return 1;
+    }
+    default:
+      return 0;
+  }
+}
+
+static void histogramAddValue(const HistogramReducer *r, khash_t(khid) * buckets,
+                              const RSValue *v) {
+  double d, key;
+  if (RSValue_ToNumber(v, &d) && histogramGetKey(r, d, &key)) {
+    histogramAddBucket(buckets, key, 1);
+  }
+}
+
+static int histogramAdd(Reducer *rbase, void *instance, const RLookupRow *srcrow) {
+  HistogramReducer *r = (HistogramReducer *)rbase;
+  khash_t(khid) *buckets = instance;
+  RSValue *v = RLookup_GetItem(rbase->srckey, srcrow);
+  if (!v) {
+    return 1;
+  }
+  v = RSValue_Dereference(v);
+
+  if (r->mode == HISTOGRAM_MERGE) {
+    uint32_t len = v->t == RSValue_Array ? RSValue_ArrayLen(v) : 0;
+    if (len % 2) {
+      return 0;
+    }
+    for (uint32_t i = 0; i < len; i += 2) {
+      double key, count;
+      if (RSValue_ToNumber(RSValue_ArrayItem(v, i), &key) &&
+          RSValue_ToNumber(RSValue_ArrayItem(v, i + 1), &count) && count >= 0) {
+        histogramAddBucket(buckets, key, count);
+      }
+    }
+  } else if (v->t == RSValue_Array) {
+    uint32_t len = RSValue_ArrayLen(v);
+    for (uint32_t i = 0; i < len; i++) {
+      histogramAddValue(r, buckets, RSValue_ArrayItem(v, i));
+    }
+  } else {
+    histogramAddValue(r, buckets, v);
+  }
+  return 1;
+}
+
+static int cmpBuckets(const void *a, const void *b) {
+  double ka = ((const histogramBucket *)a)->key, kb = ((const histogramBucket *)b)->key;
+  return ka < kb ? -1 : ka > kb ? 1 : 0;
+}
+
+static RSValue *histogramFinalize(Reducer *rbase, void *instance) {
+  khash_t(khid) *buckets = instance;
+  size_t n = 0;
+  histogramBucket *sorted = rm_malloc(sizeof(*sorted) * (kh_size(buckets) + 1));
+  for (khiter_t k = kh_begin(buckets); k != kh_end(buckets); ++k) {
+    if (kh_exist(buckets, k)) {
+      uint64_t bits = kh_key(buckets, k);
+      memcpy(&sorted[n].key, &bits, sizeof(bits));
+      sorted[n++].count = kh_value(buckets, k);
+    }
+  }
+  qsort(sorted, n, sizeof(*sorted), cmpBuckets);
+
+  // The buckets are returned as a flat list of key/count pairs, ordered by key
+  RSValue **arr = rm_calloc(n * 2, sizeof(*arr));
+  for (size_t ii = 0; ii < n; ++ii) {
+    arr[ii * 2] = RS_NumVal(sorted[ii].key);
+    arr[ii * 2 + 1] = RS_NumVal(sorted[ii].count);
+  }
+  rm_free(sorted);
+  return RSValue_NewArrayEx(arr, n * 2, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+}
+
+static void histogramFreeInstance(Reducer *r, void *instance) {
+  kh_destroy(khid, (khash_t(khid) *)instance);
+}
+
+static void histogramFree(Reducer *r) {
+  rm_free(((HistogramReducer *)r)->ranges);
+  Reducer_GenericFree(r);
+}
+
+static int parseHistogramMode(const ReducerOptions *options, HistogramReducer *r) {
+  ArgsCursor *ac = options->args;
+  int rv;
+  if (AC_AdvanceIfMatch(ac, "INTERVAL")) {
+    r->mode = HISTOGRAM_INTERVAL;
+    if ((rv = AC_GetDouble(ac, &r->interval, 0)) != AC_OK) {
+      QERR_MKBADARGS_AC(options->status, "INTERVAL", rv);
+      return 0;
+    }
+    if (!(r->interval > 0) || !isfinite(r->interval)) {
+      QERR_MKBADARGS_FMT(options->status, "INTERVAL must be a positive number");
+      return 0;
+    }
+  } else if (AC_AdvanceIfMatch(ac, "DATE_INTERVAL")) {
+    r->mode = HISTOGRAM_DATE;
+    const char *unit;
+    size_t len;
+    if ((rv = AC_GetString(ac, &unit, &len, 0)) != AC_OK) {
+      QERR_MKBADARGS_AC(options->status, "DATE_INTERVAL", rv);
+      return 0;
+    }
+    if (!RSDate_ParseUnit(unit, len, &r->dateUnit)) {
+      QERR_MKBADARGS_FMT(options->status, "Unknown DATE_INTERVAL `%.*s`", (int)len, unit);
+      return 0;
+    }
+  } else if (AC_AdvanceIfMatch(ac, "RANGES")) {
+    r->mode = HISTOGRAM_RANGES;
+    size_t n = AC_NumRemaining(ac);
+    if (n < 2) {
+      QERR_MKBADARGS_FMT(options->status, "RANGES requires at least two boundaries");
+      return 0;
+    }
+    r->ranges = rm_calloc(n, sizeof(*r->ranges));
+    for (r->numRanges = 0; r->numRanges < n; ++r->numRanges) {
+      double *boundary = r->ranges + r->numRanges;
+      if ((rv = AC_GetDouble(ac, boundary, 0)) != AC_OK) {
+        QERR_MKBADARGS_AC(options->status, "RANGES", rv);
+        return 0;
+      }
+      if (r->numRanges && !(*boundary > boundary[-1])) {
+        QERR_MKBADARGS_FMT(options->status, "RANGES boundaries must be in increasing order");
+        return 0;
+      }
+    }
+  } else {
+    QERR_MKBADARGS_FMT(options->status,
+                       "HISTOGRAM requires one of INTERVAL, DATE_INTERVAL or RANGES");
+    return 0;
+  }
+  return 1;
+}
+
+static Reducer *newHistogramCommon(const ReducerOptions *options, int isMerge) {
+  HistogramReducer *r = rm_calloc(1, sizeof(*r));
+  r->base.NewInstance = histogramNewInstance;
+  r->base.Add = histogramAdd;
+  r->base.Finalize = histogramFinalize;
+  r->base.FreeInstance = histogramFreeInstance;
+  r->base.Free = histogramFree;
+  r->mode = HISTOGRAM_MERGE;
+
+  if (!ReducerOpts_GetKey(options, &r->base.srckey) ||
+      (!isMerge && !parseHistogramMode(options, r)) || !ReducerOpts_EnsureArgsConsumed(options)) {
+    histogramFree(&r->base);
+    return NULL;
+  }
+  return &r->base;
+}
+
+Reducer *RDCRHistogram_New(const ReducerOptions *options) {
+  return newHistogramCommon(options, 0);
+}
+
+Reducer *RDCRHistogramMerge_New(const ReducerOptions *options) {
+  return newHistogramCommon(options, 1);
+}
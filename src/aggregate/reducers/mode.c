@@ -0,0 +1,140 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "aggregate/reducer.h"
+#include "util/khash.h"
+
+typedef struct {
+  RSValue *value;
+  size_t count;
+} modeEntry;
+
+static const int khid = 37;
+KHASH_MAP_INIT_INT64(khid, modeEntry);
+
+typedef struct {
+  Reducer base;
+  // Output the count of every value (MODE_COUNTS) rather than the most frequent one
+  int outputCounts;
+  // The source values are arrays of value/count pairs, as returned by MODE_COUNTS
+  int isMerge;
+} ModeReducer;
+
+static void *modeNewInstance(Reducer *r) {
+  return kh_init(khid);
+}
+
+static void modeAddValue(khash_t(khid) * counts, RSValue *v, size_t count) {
+  uint64_t hval = RSValue_Hash(v, 0);
+  int ret;
+  khiter_t k = kh_put(khid, counts, hval, &ret);
+  if (ret) {
+    kh_value(counts, k).value = RSValue_IncrRef(RSValue_MakePersistent(v));
+    kh_value(counts, k).count = 0;
+  }
+  kh_value(counts, k).count += count;
+}
+
+static int modeAdd(Reducer *rbase, void *instance, const RLookupRow *srcrow) {
+  ModeReducer *r = (ModeReducer *)rbase;
+  khash_t(khid) *counts = instance;
+  RSValue *v = RLookup_GetItem(rbase->srckey, srcrow);
+  if (!v || v == RS_NullVal()) {
+    return 1;
+  }
+  v = RSValue_Dereference(v);
+
+  if (r->isMerge) {
+    uint32_t len = v->t == RSValue_Array ? RSValue_ArrayLen(v) : 0;
+    if (len % 2) {
+      return 0;
+    }
+    for (uint32_t i = 0; i < len; i += 2) {
+      double count;
+      if (RSValue_ToNumber(RSValue_ArrayItem(v, i + 1), &count) && count > 0) {
+        modeAddValue(counts, RSValue_Dereference(RSValue_ArrayItem(v, i)), count);
+      }
+    }
+  } else if (v->t == RSValue_Array) {
+    // Every element of a multi-value counts as an occurrence
+    uint32_t len = RSValue_ArrayLen(v);
+    for (uint32_t i = 0; i < len; i++) {
+      modeAddValue(counts, RSValue_Dereference(RSValue_ArrayItem(v, i)), 1);
+    }
+  } else {
+    modeAddValue(counts, v, 1);
+  }
+  return 1;
+}
+
+static RSValue *modeFinalize(Reducer *rbase, void *instance) {
+  ModeReducer *r = (ModeReducer *)rbase;
+  khash_t(khid) *counts = instance;
+
+  if (r->outputCounts) {
+    RSValue **arr = rm_calloc(kh_size(counts) * 2, sizeof(*arr));
+    size_t n = 0;
+    for (khiter_t k = kh_begin(counts); k != kh_end(counts); ++k) {
+      if (kh_exist(counts, k)) {
+        arr[n++] = RSValue_IncrRef(kh_value(counts, k).value);
+        arr[n++] = RS_NumVal(kh_value(counts, k).count);
+      }
+    }
+    return RSValue_NewArrayEx(arr, n, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+  }
+
+  // Ties are broken by the lower value, so the result doesn't depend on the order of the rows
+  const modeEntry *best = NULL;
+  for (khiter_t k = kh_begin(counts); k != kh_end(counts); ++k) {
+    if (!kh_exist(counts, k)) {
+      continue;
+    }
+    const modeEntry *cur = &kh_value(counts, k);
+    if (!best || cur->count > best->count ||
+        (cur->count == best->count && RSValue_Cmp(cur->value, best->value, NULL) < 0)) {
+      best = cur;
+    }
+  }
+  return best ? RSValue_IncrRef(best->value) : RS_NullVal();
+}
+
+static void modeFreeInstance(Reducer *r, void *instance) {
+  khash_t(khid) *counts = instance;
+  for (khiter_t k = kh_begin(counts); k != kh_end(counts); ++k) {
+    if (kh_exist(counts, k)) {
+      RSValue_Decref(kh_value(counts, k).value);
+    }
+  }
+  kh_destroy(khid, counts);
+}
+
+static Reducer *newModeCommon(const ReducerOptions *options, int outputCounts, int isMerge) {
+  ModeReducer *r = rm_calloc(1, sizeof(*r));
+  if (!ReducerOpts_GetKey(options, &r->base.srckey) || !ReducerOpts_EnsureArgsConsumed(options)) {
+    rm_free(r);
+    return NULL;
+  }
+  r->outputCounts = outputCounts;
+  r->isMerge = isMerge;
+  r->base.NewInstance = modeNewInstance;
+  r->base.Add = modeAdd;
+  r->base.Finalize = modeFinalize;
+  r->base.FreeInstance = modeFreeInstance;
+  r->base.Free = Reducer_GenericFree;
+  return &r->base;
+}
+
+Reducer *RDCRMode_New(const ReducerOptions *options) {
+  return newModeCommon(options, 0, 0);
+}
+
+Reducer *RDCRModeCounts_New(const ReducerOptions *options) {
+  return newModeCommon(options, 1, 0);
+}
+
+Reducer *RDCRModeMerge_New(const ReducerOptions *options) {
+  return newModeCommon(options, 0, 1);
+}
@@ -7,15 +7,25 @@
 #include <aggregate/reducer.h>
 #include "util/quantile.h"
 
+typedef enum {
+  QTL_OUTPUT_NUMBER,  // The value of a single quantile
+  QTL_OUTPUT_ARRAY,   // The values of all of the quantiles, in the requested order
+  QTL_OUTPUT_SKETCH,  // The serialized stream, to be merged by QUANTILE_MERGE/PERCENTILES_MERGE
+} QTLOutput;
+
 typedef struct {
   Reducer base;
-  double pct;
+  double *pcts;
+  size_t numPcts;
   unsigned resolution;
+  QTLOutput output;
+  // The source values are serialized streams (from QUANTILE_SKETCH) rather than numbers
+  int isMerge;
 } QTLReducer;
 
 static void *quantileNewInstance(Reducer *parent) {
   QTLReducer *qt = (QTLReducer *)parent;
-  return NewQuantileStream(&qt->pct, 0, qt->resolution);
+  return NewQuantileStream(NULL, 0, qt->resolution);
 }
 
 static int quantileAdd(Reducer *rbase, void *ctx, const RLookupRow *row) {
@@ -27,6 +37,16 @@ static int quantileAdd(Reducer *rbase, void *ctx, const RLookupRow *row) {
     return 1;
   }
 
+  if (qt->isMerge) {
+    v = RSValue_Dereference(v);
+    if (!RSValue_IsString(v)) {
+      return 0;
+    }
+    size_t len;
+    const char *buf = RSValue_StringPtrLen(v, &len);
+    return QS_Merge(qs, buf, len);
+  }
+
   if (v->t != RSValue_Array) {
     if (RSValue_ToNumber(v, &d)) {
       QS_Insert(qs, d);
@@ -45,54 +65,149 @@ static int quantileAdd(Reducer *rbase, void *ctx, const RLookupRow *row) {
 static RSValue *quantileFinalize(Reducer *r, void *ctx) {
   QuantStream *qs = ctx;
   QTLReducer *qt = (QTLReducer *)r;
-  double value = QS_Query(qs, qt->pct);
-  return RS_NumVal(value);
+  switch (qt->output) {
+    case QTL_OUTPUT_SKETCH: {
+      size_t len;
+      char *buf = QS_Serialize(qs, &len);
+      return RS_StringVal(buf, len);
+    }
+    case QTL_OUTPUT_ARRAY: {
+      RSValue **arr = rm_calloc(qt->numPcts, sizeof(*arr));
+      for (size_t ii = 0; ii < qt->numPcts; ++ii) {
+        arr[ii] = RS_NumVal(QS_Query(qs, qt->pcts[ii]));
+      }
+      return RSValue_NewArrayEx(arr, qt->numPcts, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+    }
+    default:
+      return RS_NumVal(QS_Query(qs, qt->pcts[0]));
+  }
 }
 
 static void quantileFreeInstance(Reducer *unused, void *p) {
   QS_Free(p);
 }
 
-Reducer *RDCRQuantile_New(const ReducerOptions *options) {
-  QTLReducer *r = rm_calloc(1, sizeof(*r));
-  r->resolution = 500;  // Fixed, i guess?
+static void quantileFree(Reducer *r) {
+  rm_free(((QTLReducer *)r)->pcts);
+  Reducer_GenericFree(r);
+}
 
-  if (!ReducerOptions_GetKey(options, &r->base.srckey)) {
-    goto error;
-  }
-  int rv;
-  if ((rv = AC_GetDouble(options->args, &r->pct, 0)) != AC_OK) {
-    QERR_MKBADARGS_AC(options->status, options->name, rv);
-    goto error;
+// Read a single quantile, or all of the remaining arguments as quantiles
+static int parseQuantiles(const ReducerOptions *options, QTLReducer *r, int all) {
+  size_t n = all ? AC_NumRemaining(options->args) : 1;
+  if (n == 0) {
+    QERR_MKBADARGS_FMT(options->status, "Missing quantiles for %s", options->name);
+    return 0;
   }
-  if (!(r->pct >= 0 && r->pct <= 1.0)) {
-    QERR_MKBADARGS_FMT(options->status, "Percentage must be between 0.0 and 1.0");
-    goto error;
+  r->pcts = rm_calloc(n, sizeof(*r->pcts));
+  for (r->numPcts = 0; r->numPcts < n; ++r->numPcts) {
+    double *pct = r->pcts + r->numPcts;
+    int rv;
+    if ((rv = AC_GetDouble(options->args, pct, 0)) != AC_OK) {
+      QERR_MKBADARGS_AC(options->status, options->name, rv);
+      return 0;
+    }
+    if (!(*pct >= 0 && *pct <= 1.0)) {
+      QERR_MKBADARGS_FMT(options->status, "Percentage must be between 0.0 and 1.0");
+      return 0;
+    }
   }
+  return 1;
+}
 
+// Read the optional resolution, which must be the last argument
+static int parseResolution(const ReducerOptions *options, QTLReducer *r) {
   if (!AC_IsAtEnd(options->args)) {
+    int rv;
     if ((rv = AC_GetUnsigned(options->args, &r->resolution, 0)) != AC_OK) {
       QERR_MKBADARGS_AC(options->status, "<resolution>", rv);
-      goto error;
+      return 0;
     }
     if (r->resolution < 1 || r->resolution > MAX_SAMPLE_SIZE) {
       QERR_MKBADARGS_FMT(options->status, "Invalid resolution");
-      goto error;
+      return 0;
     }
   }
+  return 1;
+}
 
-  if (!ReducerOpts_EnsureArgsConsumed(options)) {
-    goto error;
-  }
-
+static QTLReducer *newQuantileCommon(const ReducerOptions *options, QTLOutput output,
+                                     int isMerge) {
+  QTLReducer *r = rm_calloc(1, sizeof(*r));
+  r->resolution = 500;  // Fixed, i guess?
+  r->output = output;
+  r->isMerge = isMerge;
   r->base.NewInstance = quantileNewInstance;
   r->base.Add = quantileAdd;
-  r->base.Free = Reducer_GenericFree;
+  r->base.Free = quantileFree;
   r->base.FreeInstance = quantileFreeInstance;
   r->base.Finalize = quantileFinalize;
+
+  if (!ReducerOptions_GetKey(options, &r->base.srckey)) {
+    quantileFree(&r->base);
+    return NULL;
+  }
+  return r;
+}
+
+static Reducer *finishQuantile(const ReducerOptions *options, QTLReducer *r, int ok) {
+  if (!ok || !ReducerOpts_EnsureArgsConsumed(options)) {
+    quantileFree(&r->base);
+    return NULL;
+  }
   return &r->base;
+}
+
+Reducer *RDCRQuantile_New(const ReducerOptions *options) {
+  QTLReducer *r = newQuantileCommon(options, QTL_OUTPUT_NUMBER, 0);
+  if (!r) {
+    return NULL;
+  }
+  return finishQuantile(options, r,
+                        parseQuantiles(options, r, 0) && parseResolution(options, r));
+}
+
+Reducer *RDCRMedian_New(const ReducerOptions *options) {
+  QTLReducer *r = newQuantileCommon(options, QTL_OUTPUT_NUMBER, 0);
+  if (!r) {
+    return NULL;
+  }
+  r->pcts = rm_malloc(sizeof(*r->pcts));
+  r->pcts[0] = 0.5;
+  r->numPcts = 1;
+  return finishQuantile(options, r, parseResolution(options, r));
+}
+
+Reducer *RDCRPercentiles_New(const ReducerOptions *options) {
+  QTLReducer *r = newQuantileCommon(options, QTL_OUTPUT_ARRAY, 0);
+  if (!r) {
+    return NULL;
+  }
+  return finishQuantile(options, r, parseQuantiles(options, r, 1));
+}
+
+Reducer *RDCRQuantileSketch_New(const ReducerOptions *options) {
+  QTLReducer *r = newQuantileCommon(options, QTL_OUTPUT_SKETCH, 0);
+  if (!r) {
+    return NULL;
+  }
+  return finishQuantile(options, r, parseResolution(options, r));
+}
+
+Reducer *RDCRQuantileMerge_New(const ReducerOptions *options) {
+  QTLReducer *r = newQuantileCommon(options, QTL_OUTPUT_NUMBER, 1);
+  if (!r) {
+    return NULL;
+  }
+  // The resolution of the merged stream is only given if the sketches were made with one
+  return finishQuantile(options, r,
+                        parseQuantiles(options, r, 0) && parseResolution(options, r));
+}
 
-error:
-  rm_free(r);
-  return NULL;
+Reducer *RDCRPercentilesMerge_New(const ReducerOptions *options) {
+  QTLReducer *r = newQuantileCommon(options, QTL_OUTPUT_ARRAY, 1);
+  if (!r) {
+    return NULL;
+  }
+  return finishQuantile(options, r, parseQuantiles(options, r, 1));
 }
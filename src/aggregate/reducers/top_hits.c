@@ -0,0 +1,372 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "aggregate/reducer.h"
+#include "util/minmax_heap.h"
+#include "util/arr.h"
+
+/**
+ * TOP_HITS keeps the best rows of every group, according to its own SORTBY.
+ * Each hit is returned as a flat list of property/value pairs, holding the
+ * SORTBY properties followed by the LOAD properties.
+ *
+ * TOP_HITS_MERGE combines the hits returned by the shards in a cluster. It
+ * finds the SORTBY properties by name within each hit.
+ */
+
+typedef struct {
+  Reducer base;
+  size_t len;  // Maximum number of hits per group
+  int isMerge;
+
+  const RLookupKey **sortKeys;  // array_*, unused when merging
+  RSValue **sortNames;          // array_*, only used when merging
+  uint64_t sortAscMap;
+
+  const RLookupKey **keys;  // array_*, properties of every hit
+  RSValue **names;          // array_*, names of `keys`
+} TopHitsReducer;
+
+typedef struct {
+  size_t seq;        // Arrival order, used to break ties
+  RSValue *fields;   // Property/value pairs of the hit
+  size_t nsort;
+  RSValue *sortvals[];
+} topHit;
+
+typedef struct {
+  heap_t *heap;
+  size_t seen;
+} topHitsCtx;
+
+static void topHitFree(void *p) {
+  topHit *h = p;
+  if (!h) {
+    return;
+  }
+  for (size_t ii = 0; ii < h->nsort; ++ii) {
+    RSVALUE_CLEARVAR(h->sortvals[ii]);
+  }
+  RSVALUE_CLEARVAR(h->fields);
+  rm_free(h);
+}
+
+// Shards send their numbers as strings, so values which can be read as numbers are always
+// compared as numbers. This way, the coordinator orders the hits like a single shard does.
+static int cmpSortValues(const RSValue *v1, const RSValue *v2) {
+  double d1, d2;
+  if (RSValue_ToNumber(v1, &d1) && RSValue_ToNumber(v2, &d2)) {
+    return d1 < d2 ? -1 : d1 > d2 ? 1 : 0;
+  }
+  return RSValue_Cmp(v1, v2, NULL);
+}
+
+/* Returns a positive value if `e1` is a better hit than `e2` */
+static int cmpHits(const void *e1, const void *e2, const void *udata) {
+  const TopHitsReducer *r = udata;
+  const topHit *h1 = e1, *h2 = e2;
+
+  for (size_t ii = 0; ii < h1->nsort; ++ii) {
+    const RSValue *v1 = h1->sortvals[ii], *v2 = h2->sortvals[ii];
+    // Hits without a sort value come last, regardless of the direction
+    if (!v1 || !v2) {
+      if (v1 || v2) {
+        return v1 ? 1 : -1;
+      }
+      continue;
+    }
+    int rc = cmpSortValues(v1, v2);
+    if (rc != 0) {
+      return SORTASCMAP_GETASC(r->sortAscMap, ii) ? -rc : rc;
+    }
+  }
+  return h1->seq < h2->seq ? 1 : -1;
+}
+
+static void *topHitsNewInstance(Reducer *rbase) {
+  TopHitsReducer *r = (TopHitsReducer *)rbase;
+  topHitsCtx *ctx = Reducer_BlkAlloc(rbase, sizeof(*ctx), 1024 * sizeof(*ctx));
+  ctx->heap = mmh_init_with_size(r->len + 1, cmpHits, r, topHitFree);
+  ctx->seen = 0;
+  return ctx;
+}
+
+static topHit *newHit(size_t nsort, size_t seq) {
+  topHit *h = rm_calloc(1, sizeof(*h) + nsort * sizeof(*h->sortvals));
+  h->seq = seq;
+  h->nsort = nsort;
+  return h;
+}
+
+// Builds the property/value pairs of a row. Missing properties are omitted
+static RSValue *hitFieldsFromRow(const TopHitsReducer *r, const RLookupRow *srcrow) {
+  size_t nkeys = array_len(r->keys), n = 0;
+  RSValue **arr = rm_calloc(nkeys * 2, sizeof(*arr));
+  for (size_t ii = 0; ii < nkeys; ++ii) {
+    RSValue *v = RLookup_GetItem(r->keys[ii], srcrow);
+    if (!v) {
+      continue;
+    }
+    arr[n++] = RSValue_IncrRef(r->names[ii]);
+    arr[n++] = RSValue_IncrRef(v);
+  }
+  return RSValue_NewArrayEx(arr, n, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+}
+
+// Finds the value of a property within the property/value pairs of a hit
+static RSValue *hitGetField(const RSValue *fields, const RSValue *name) {
+  size_t nlen;
+  const char *ns = RSValue_StringPtrLen(name, &nlen);
+  uint32_t len = RSValue_ArrayLen(fields);
+  for (uint32_t ii = 0; ii + 1 < len; ii += 2) {
+    size_t flen;
+    const char *fs = RSValue_StringPtrLen(RSValue_ArrayItem(fields, ii), &flen);
+    if (fs && flen == nlen && !strncmp(fs, ns, nlen)) {
+      return RSValue_ArrayItem(fields, ii + 1);
+    }
+  }
+  return NULL;
+}
+
+/* Inserts a hit if it is among the best ones seen so far. Takes ownership of the hit */
+static void topHitsInsert(TopHitsReducer *r, topHitsCtx *ctx, topHit *h) {
+  if (ctx->heap->count < r->len) {
+    mmh_insert(ctx->heap, h);
+    return;
+  }
+  topHit *worst = mmh_peek_min(ctx->heap);
+  if (cmpHits(h, worst, r) > 0) {
+    topHitFree(mmh_pop_min(ctx->heap));
+    mmh_insert(ctx->heap, h);
+  } else {
+    topHitFree(h);
+  }
+}
+
+static int topHitsAdd(Reducer *rbase, void *instance, const RLookupRow *srcrow) {
+  TopHitsReducer *r = (TopHitsReducer *)rbase;
+  topHitsCtx *ctx = instance;
+  size_t nsort = array_len(r->sortKeys);
+
+  topHit *h = newHit(nsort, ctx->seen++);
+  for (size_t ii = 0; ii < nsort; ++ii) {
+    RSValue *v = RLookup_GetItem(r->sortKeys[ii], srcrow);
+    h->sortvals[ii] = v ? RSValue_IncrRef(v) : NULL;
+  }
+
+  // Only build the hit once we know it is kept
+  if (ctx->heap->count >= r->len && cmpHits(h, mmh_peek_min(ctx->heap), r) <= 0) {
+    topHitFree(h);
+    return 1;
+  }
+  h->fields = hitFieldsFromRow(r, srcrow);
+  topHitsInsert(r, ctx, h);
+  return 1;
+}
+
+static int topHitsMergeAdd(Reducer *rbase, void *instance, const RLookupRow *srcrow) {
+  TopHitsReducer *r = (TopHitsReducer *)rbase;
+  topHitsCtx *ctx = instance;
+  RSValue *v = RLookup_GetItem(rbase->srckey, srcrow);
+  if (!v) {
+    return 1;
+  }
+  v = RSValue_Dereference(v);
+  if (v->t != RSValue_Array) {
+    return 1;
+  }
+
+  size_t nsort = array_len(r->sortNames);
+  uint32_t len = RSValue_ArrayLen(v);
+  for (uint32_t ii = 0; ii < len; ++ii) {
+    RSValue *fields = RSValue_Dereference(RSValue_ArrayItem(v, ii));
+    if (fields->t != RSValue_Array) {
+      continue;
+    }
+    topHit *h = newHit(nsort, ctx->seen++);
+    for (size_t jj = 0; jj < nsort; ++jj) {
+      RSValue *sv = hitGetField(fields, r->sortNames[jj]);
+      h->sortvals[jj] = sv ? RSValue_IncrRef(sv) : NULL;
+    }
+    h->fields = RSValue_IncrRef(fields);
+    topHitsInsert(r, ctx, h);
+  }
+  return 1;
+}
+
+static RSValue *topHitsFinalize(Reducer *rbase, void *instance) {
+  topHitsCtx *ctx = instance;
+  size_t n = ctx->heap->count;
+  RSValue **arr = rm_calloc(n, sizeof(*arr));
+  // Best hits first
+  for (size_t ii = 0; ii < n; ++ii) {
+    topHit *h = mmh_pop_max(ctx->heap);
+    arr[ii] = h->fields;
+    h->fields = NULL;
+    topHitFree(h);
+  }
+  return RSValue_NewArrayEx(arr, n, RSVAL_ARRAY_ALLOC | RSVAL_ARRAY_NOINCREF);
+}
+
+static void topHitsFreeInstance(Reducer *rbase, void *instance) {
+  topHitsCtx *ctx = instance;
+  mmh_free(ctx->heap);
+}
+
+static void freeValues(RSValue **values) {
+  for (size_t ii = 0; ii < array_len(values); ++ii) {
+    RSValue_Decref(values[ii]);
+  }
+  array_free(values);
+}
+
+static void topHitsFree(Reducer *rbase) {
+  TopHitsReducer *r = (TopHitsReducer *)rbase;
+  array_free(r->sortKeys);
+  array_free(r->keys);
+  freeValues(r->sortNames);
+  freeValues(r->names);
+  Reducer_GenericFree(rbase);
+}
+
+// Adds a property to the hits, unless it is already there
+static int addHitKey(TopHitsReducer *r, const RLookupKey *key) {
+  for (size_t ii = 0; ii < array_len(r->keys); ++ii) {
+    if (r->keys[ii] == key) {
+      return 0;
+    }
+  }
+  r->keys = array_append(r->keys, key);
+  r->names = array_append(r->names, RS_NewCopiedString(key->name, key->name_len));
+  return 1;
+}
+
+static int parseTopHitsSortby(const ReducerOptions *options, TopHitsReducer *r) {
+  ArgsCursor sub = {0};
+  int rv = AC_GetVarArgs(options->args, &sub);
+  if (rv != AC_OK) {
+    QERR_MKBADARGS_AC(options->status, "SORTBY", rv);
+    return 0;
+  }
+
+  size_t nsort = 0;
+  while (!AC_IsAtEnd(&sub)) {
+    const char *s = AC_GetStringNC(&sub, NULL);
+    if (*s == '@') {
+      if (nsort >= SORTASCMAP_MAXFIELDS) {
+        QERR_MKBADARGS_FMT(options->status, "Cannot sort by more than %lu fields",
+                           SORTASCMAP_MAXFIELDS);
+        return 0;
+      }
+      s++;
+      if (r->isMerge) {
+        r->sortNames = array_append(r->sortNames, RS_NewCopiedString(s, strlen(s)));
+      } else {
+        const RLookupKey *key = RLookup_GetKey(options->srclookup, s, RLOOKUP_F_HIDDEN);
+        if (!key) {
+          QueryError_SetErrorFmt(options->status, QUERY_ENOPROPKEY,
+                                 "Property `%s` not present in document or pipeline", s);
+          return 0;
+        }
+        r->sortKeys = array_append(r->sortKeys, key);
+        addHitKey(r, key);
+      }
+      nsort++;
+    } else if (nsort && !strcasecmp(s, "ASC")) {
+      SORTASCMAP_SETASC(r->sortAscMap, nsort - 1);
+    } else if (nsort && !strcasecmp(s, "DESC")) {
+      SORTASCMAP_SETDESC(r->sortAscMap, nsort - 1);
+    } else {
+      QERR_MKBADARGS_FMT(options->status, "MISSING ASC or DESC after sort field (%s)", s);
+      return 0;
+    }
+  }
+  return 1;
+}
+
+static int parseTopHitsLoad(const ReducerOptions *options, TopHitsReducer *r) {
+  ArgsCursor sub = {0};
+  int rv = AC_GetVarArgs(options->args, &sub);
+  if (rv != AC_OK) {
+    QERR_MKBADARGS_AC(options->status, "LOAD", rv);
+    return 0;
+  }
+
+  ReducerOptions subopts = *options;
+  subopts.args = &sub;
+  while (!AC_IsAtEnd(&sub)) {
+    const RLookupKey *key;
+    if (!ReducerOpts_GetKey(&subopts, &key)) {
+      return 0;
+    }
+    addHitKey(r, key);
+  }
+  return 1;
+}
+
+static int parseTopHits(const ReducerOptions *options, TopHitsReducer *r) {
+  if (r->isMerge && !ReducerOpts_GetKey(options, &r->base.srckey)) {
+    return 0;
+  }
+
+  unsigned len;
+  int rv = AC_GetUnsigned(options->args, &len, 0);
+  if (rv != AC_OK) {
+    QERR_MKBADARGS_AC(options->status, "<count>", rv);
+    return 0;
+  }
+  if (len == 0 || len > MAX_TOP_HITS_SIZE) {
+    QERR_MKBADARGS_FMT(options->status, "TOP_HITS count must be between 1 and %d",
+                       MAX_TOP_HITS_SIZE);
+    return 0;
+  }
+  r->len = len;
+
+  if (AC_AdvanceIfMatch(options->args, "SORTBY") && !parseTopHitsSortby(options, r)) {
+    return 0;
+  }
+  if (!r->isMerge) {
+    if (AC_AdvanceIfMatch(options->args, "LOAD") && !parseTopHitsLoad(options, r)) {
+      return 0;
+    }
+    if (!array_len(r->keys)) {
+      QERR_MKBADARGS_FMT(options->status, "TOP_HITS requires SORTBY or LOAD properties");
+      return 0;
+    }
+  }
+  return ReducerOpts_EnsureArgsConsumed(options);
+}
+
+static Reducer *newTopHitsCommon(const ReducerOptions *options, int isMerge) {
+  TopHitsReducer *r = rm_calloc(1, sizeof(*r));
+  r->isMerge = isMerge;
+  r->sortAscMap = SORTASCMAP_INIT;
+  r->sortKeys = array_new(const RLookupKey *, SORTASCMAP_MAXFIELDS);
+  r->sortNames = array_new(RSValue *, SORTASCMAP_MAXFIELDS);
+  r->keys = array_new(const RLookupKey *, 8);
+  r->names = array_new(RSValue *, 8);
+
+  Reducer *rbase = &r->base;
+  rbase->NewInstance = topHitsNewInstance;
+  rbase->Add = isMerge ? topHitsMergeAdd : topHitsAdd;
+  rbase->Finalize = topHitsFinalize;
+  rbase->FreeInstance = topHitsFreeInstance;
+  rbase->Free = topHitsFree;
+
+  if (!parseTopHits(options, r)) {
+    topHitsFree(rbase);
+    return NULL;
+  }
+  return rbase;
+}
+
+Reducer *RDCRTopHits_New(const ReducerOptions *options) {
+  return newTopHitsCommon(options, 0);
+}
+
+Reducer *RDCRTopHitsMerge_New(const ReducerOptions *options) {
+  return newTopHitsCommon(options, 1);
+}
@@ -0,0 +1,716 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "analyzer.h"
+#include "normalize.h"
+#include "toksep.h"
+#include "config.h"
+#include "rdb.h"
+#include "rmalloc.h"
+#include "phonetic_manager.h"
+#include "util/arr.h"
+#include "libnu/libnu.h"
+
+#include <ctype.h>
+#include <string.h>
+#include <sys/param.h>
+
+#define ANALYZER_HTML_STRIP_STR "HTML_STRIP"
+
+#define ANALYZER_STANDARD_STR "STANDARD"
+#define ANALYZER_WHITESPACE_STR "WHITESPACE"
+#define ANALYZER_KEYWORD_STR "KEYWORD"
+#define ANALYZER_NGRAM_STR "NGRAM"
+#define ANALYZER_EDGE_NGRAM_STR "EDGE_NGRAM"
+
+#define ANALYZER_LOWERCASE_STR "LOWERCASE"
+#define ANALYZER_ASCIIFOLDING_STR "ASCIIFOLDING"
+#define ANALYZER_LENGTH_STR "LENGTH"
+#define ANALYZER_STOP_STR "STOP"
+#define ANALYZER_STEM_STR "STEM"
+#define ANALYZER_LANGUAGE_STR "LANGUAGE"
+
+// Shortest word which is stemmed, as in the default tokenizer
+#define MIN_STEM_CANDIDATE_LEN 4
+
+/***************************************************************************************************
+ * Definition
+ **************************************************************************************************/
+
+static int parseFilter(ArgsCursor *ac, AnalyzerFilter *f, QueryError *status) {
+  int rc;
+  if (AC_AdvanceIfMatch(ac, ANALYZER_LOWERCASE_STR)) {
+    f->type = AnalyzerFilter_Lowercase;
+  } else if (AC_AdvanceIfMatch(ac, ANALYZER_ASCIIFOLDING_STR)) {
+    f->type = AnalyzerFilter_AsciiFolding;
+  } else if (AC_AdvanceIfMatch(ac, ANALYZER_LENGTH_STR)) {
+    f->type = AnalyzerFilter_Length;
+    if ((rc = AC_GetU32(ac, &f->length.min, 0)) != AC_OK ||
+        (rc = AC_GetU32(ac, &f->length.max, AC_F_GE1)) != AC_OK) {
+      QERR_MKBADARGS_AC(status, ANALYZER_LENGTH_STR " filter", rc);
+      return 0;
+    }
+    if (f->length.min > f->length.max) {
+      QueryError_SetError(status, QUERY_EPARSEARGS,
+                          ANALYZER_LENGTH_STR " filter minimum is greater than its maximum");
+      return 0;
+    }
+  } else if (AC_AdvanceIfMatch(ac, ANALYZER_STOP_STR)) {
+    f->type = AnalyzerFilter_Stop;
+  } else if (AC_AdvanceIfMatch(ac, ANALYZER_STEM_STR)) {
+    f->type = AnalyzerFilter_Stem;
+    f->stem.hasLanguage = false;
+    if (AC_AdvanceIfMatch(ac, ANALYZER_LANGUAGE_STR)) {
+      const char *lang = AC_IsAtEnd(ac) ? NULL : AC_GetStringNC(ac, NULL);
+      f->stem.language = lang ? RSLanguage_Find(lang, 0) : RS_LANG_UNSUPPORTED;
+      if (f->stem.language == RS_LANG_UNSUPPORTED) {
+        QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid language for " ANALYZER_STEM_STR
+                               " filter: `%s`", lang ? lang : "");
+        return 0;
+      }
+      f->stem.hasLanguage = true;
+    }
+  } else {
+    const char *name = AC_IsAtEnd(ac) ? "" : AC_GetStringNC(ac, NULL);
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Unknown analyzer filter `%s`", name);
+    return 0;
+  }
+  return 1;
+}
+
+static int parseNGram(ArgsCursor *ac, RSAnalyzer *analyzer, const char *name, QueryError *status) {
+  int rc;
+  if ((rc = AC_GetU32(ac, &analyzer->ngram.min, AC_F_GE1)) != AC_OK ||
+      (rc = AC_GetU32(ac, &analyzer->ngram.max, AC_F_GE1)) != AC_OK) {
+    QERR_MKBADARGS_AC(status, name, rc);
+    return 0;
+  }
+  if (analyzer->ngram.min > analyzer->ngram.max) {
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS,
+                           "%s tokenizer minimum is greater than its maximum", name);
+    return 0;
+  }
+  return 1;
+}
+
+static bool isNGramTokenizer(AnalyzerTokenizerType type) {
+  return type == AnalyzerTokenizer_NGram || type == AnalyzerTokenizer_EdgeNGram;
+}
+
+RSAnalyzer *Analyzer_New(const char **args, size_t nargs, QueryError *status) {
+  RSAnalyzer *analyzer = rm_calloc(1, sizeof(*analyzer));
+  analyzer->tokenizer = AnalyzerTokenizer_Standard;
+  analyzer->charFilters = array_new(AnalyzerCharFilterType, 1);
+  analyzer->filters = array_new(AnalyzerFilter, 4);
+  analyzer->args = array_new(char *, nargs);
+  for (size_t ii = 0; ii < nargs; ++ii) {
+    analyzer->args = array_append(analyzer->args, rm_strdup(args[ii]));
+  }
+
+  ArgsCursor ac;
+  ArgsCursor_InitCString(&ac, args, nargs);
+  bool hasTokenizer = false;
+  while (!AC_IsAtEnd(&ac)) {
+    if (AC_AdvanceIfMatch(&ac, ANALYZER_CHAR_FILTER_STR)) {
+      if (AC_AdvanceIfMatch(&ac, ANALYZER_HTML_STRIP_STR)) {
+        analyzer->charFilters = array_append(analyzer->charFilters, AnalyzerCharFilter_HtmlStrip);
+      } else {
+        const char *name = AC_IsAtEnd(&ac) ? "" : AC_GetStringNC(&ac, NULL);
+        QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Unknown analyzer char filter `%s`", name);
+        goto error;
+      }
+
+    } else if (AC_AdvanceIfMatch(&ac, ANALYZER_TOKENIZER_STR)) {
+      if (hasTokenizer) {
+        QueryError_SetError(status, QUERY_EPARSEARGS, "Analyzer has more than one tokenizer");
+        goto error;
+      }
+      hasTokenizer = true;
+      if (AC_AdvanceIfMatch(&ac, ANALYZER_STANDARD_STR)) {
+        analyzer->tokenizer = AnalyzerTokenizer_Standard;
+      } else if (AC_AdvanceIfMatch(&ac, ANALYZER_WHITESPACE_STR)) {
+        analyzer->tokenizer = AnalyzerTokenizer_Whitespace;
+      } else if (AC_AdvanceIfMatch(&ac, ANALYZER_KEYWORD_STR)) {
+        analyzer->tokenizer = AnalyzerTokenizer_Keyword;
+      } else if (AC_AdvanceIfMatch(&ac, ANALYZER_NGRAM_STR)) {
+        analyzer->tokenizer = AnalyzerTokenizer_NGram;
+        if (!parseNGram(&ac, analyzer, ANALYZER_NGRAM_STR, status)) {
+          goto error;
+        }
+      } else if (AC_AdvanceIfMatch(&ac, ANALYZER_EDGE_NGRAM_STR)) {
+        analyzer->tokenizer = AnalyzerTokenizer_EdgeNGram;
+        if (!parseNGram(&ac, analyzer, ANALYZER_EDGE_NGRAM_STR, status)) {
+          goto error;
+        }
+      } else {
+        const char *name = AC_IsAtEnd(&ac) ? "" : AC_GetStringNC(&ac, NULL);
+        QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Unknown analyzer tokenizer `%s`", name);
+        goto error;
+      }
+
+    } else if (AC_AdvanceIfMatch(&ac, ANALYZER_FILTER_STR)) {
+      AnalyzerFilter f = {0};
+      if (!parseFilter(&ac, &f, status)) {
+        goto error;
+      }
+      analyzer->filters = array_append(analyzer->filters, f);
+
+    } else {
+      QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Unknown analyzer argument `%s`",
+                             AC_GetStringNC(&ac, NULL));
+      goto error;
+    }
+  }
+
+  // Only whole words can be stemmed
+  if (isNGramTokenizer(analyzer->tokenizer) && Analyzer_HasFilter(analyzer, AnalyzerFilter_Stem)) {
+    QueryError_SetError(status, QUERY_EPARSEARGS,
+                        ANALYZER_STEM_STR " filter can't be used with n-gram tokenizers");
+    goto error;
+  }
+  return analyzer;
+
+error:
+  Analyzer_Free(analyzer);
+  return NULL;
+}
+
+RSAnalyzer *Analyzer_Parse(ArgsCursor *ac, QueryError *status) {
+  ArgsCursor sub;
+  int rc = AC_GetVarArgs(ac, &sub);
+  if (rc != AC_OK) {
+    QERR_MKBADARGS_AC(status, "ANALYZER", rc);
+    return NULL;
+  }
+  size_t nargs = AC_NumArgs(&sub);
+  const char **args = rm_malloc(sizeof(*args) * (nargs ? nargs : 1));
+  for (size_t ii = 0; ii < nargs; ++ii) {
+    args[ii] = AC_GetStringNC(&sub, NULL);
+  }
+  RSAnalyzer *analyzer = Analyzer_New(args, nargs, status);
+  rm_free(args);
+  return analyzer;
+}
+
+void Analyzer_Free(RSAnalyzer *analyzer) {
+  if (!analyzer) {
+    return;
+  }
+  array_free(analyzer->charFilters);
+  array_free(analyzer->filters);
+  array_free_ex(analyzer->args, rm_free(*(char **)ptr));
+  rm_free(analyzer);
+}
+
+bool Analyzer_HasFilter(const RSAnalyzer *analyzer, AnalyzerFilterType type) {
+  for (size_t ii = 0; ii < array_len(analyzer->filters); ++ii) {
+    if (analyzer->filters[ii].type == type) {
+      return true;
+    }
+  }
+  return false;
+}
+
+void Analyzer_RdbSave(RedisModuleIO *rdb, const RSAnalyzer *analyzer) {
+  RedisModule_SaveUnsigned(rdb, analyzer ? 1 : 0);
+  if (!analyzer) {
+    return;
+  }
+  RedisModule_SaveUnsigned(rdb, array_len(analyzer->args));
+  for (size_t ii = 0; ii < array_len(analyzer->args); ++ii) {
+    RedisModule_SaveStringBuffer(rdb, analyzer->args[ii], strlen(analyzer->args[ii]) + 1);
+  }
+}
+
+int Analyzer_RdbLoad(RedisModuleIO *rdb, RSAnalyzer **analyzer) {
+  *analyzer = NULL;
+  if (!LoadUnsigned_IOError(rdb, return REDISMODULE_ERR)) {
+    return REDISMODULE_OK;
+  }
+  size_t nargs = LoadUnsigned_IOError(rdb, return REDISMODULE_ERR);
+  char **args = rm_calloc(nargs ? nargs : 1, sizeof(*args));
+  for (size_t ii = 0; ii < nargs; ++ii) {
+    args[ii] = LoadStringBuffer_IOError(rdb, NULL, goto cleanup);
+  }
+
+  QueryError status = {0};
+  *analyzer = Analyzer_New((const char **)args, nargs, &status);
+  QueryError_ClearError(&status);
+
+cleanup:
+  for (size_t ii = 0; ii < nargs && args[ii]; ++ii) {
+    RedisModule_Free(args[ii]);
+  }
+  rm_free(args);
+  return *analyzer ? REDISMODULE_OK : REDISMODULE_ERR;
+}
+
+void Analyzer_Reply(RedisModuleCtx *ctx, const RSAnalyzer *analyzer) {
+  RedisModule_ReplyWithArray(ctx, array_len(analyzer->args));
+  for (size_t ii = 0; ii < array_len(analyzer->args); ++ii) {
+    RedisModule_ReplyWithCString(ctx, analyzer->args[ii]);
+  }
+}
+
+/***************************************************************************************************
+ * Char filters
+ **************************************************************************************************/
+
+/* Replace the tags, comments and character references with spaces. Text which only looks like the
+ * start of a tag, such as "a < b", is kept */
+static void htmlStrip(char *s, size_t len) {
+  char *end = s + len;
+  for (char *p = s; p < end; ++p) {
+    char *last = NULL;
+    if (*p == '<' && p + 1 < end) {
+      if (end - p >= 4 && !strncmp(p, "<!--", 4)) {
+        for (char *q = p + 4; q + 2 < end && !last; ++q) {
+          if (!strncmp(q, "-->", 3)) {
+            last = q + 2;
+          }
+        }
+      } else if (isalpha(p[1]) || p[1] == '/' || p[1] == '!' || p[1] == '?') {
+        last = memchr(p + 1, '>', end - p - 1);
+      }
+    } else if (*p == '&') {
+      char *q = p + 1;
+      if (q < end && *q == '#') {
+        ++q;
+        if (q < end && (*q == 'x' || *q == 'X')) {
+          ++q;
+        }
+        while (q < end && isxdigit(*q)) ++q;
+      } else {
+        while (q < end && isalnum(*q)) ++q;
+      }
+      if (q < end && *q == ';' && isalnum(q[-1])) {
+        last = q;
+      }
+    }
+    if (last) {
+      memset(p, ' ', last - p + 1);
+      p = last;
+    }
+  }
+}
+
+/***************************************************************************************************
+ * Token filters
+ **************************************************************************************************/
+
+/* Lowercase `len` bytes of `s` into `out`, which holds at least 3 * len bytes. Returns the length
+ * of the output */
+static size_t lowercase(const char *s, size_t len, char *out) {
+  const char *end = s + len;
+  char *o = out;
+  while (s < end) {
+    uint32_t cp;
+    const char *next = Normalize_ReadCodepoint(s, end, &cp);
+    if (!next) {
+      break;
+    }
+    const char *lower = nu_tolower(cp);
+    if (lower) {
+      uint32_t u = 0;
+      do {
+        lower = nu_casemap_read(lower, &u);
+        if (u) {
+          o = nu_utf8_write(u, o);
+        }
+      } while (u);
+    } else {
+      memcpy(o, s, next - s);
+      o += next - s;
+    }
+    s = next;
+  }
+  // A truncated sequence at the end is kept as is
+  memcpy(o, s, end - s);
+  o += end - s;
+  return o - out;
+}
+
+/* The number of characters of a UTF-8 string */
+static size_t utf8Length(const char *s, size_t len) {
+  size_t n = 0;
+  for (size_t ii = 0; ii < len; ++ii) {
+    n += ((unsigned char)s[ii] & 0xC0) != 0x80;
+  }
+  return n;
+}
+
+char *Analyzer_NormalizeTerm(const RSAnalyzer *analyzer, const char *s, size_t *len) {
+  size_t n = *len, cap = n + 1;
+  char *buf = rm_malloc(cap);
+  char *tmp = rm_malloc(cap);
+  memcpy(buf, s, n);
+  for (size_t ii = 0; ii < array_len(analyzer->filters); ++ii) {
+    AnalyzerFilterType type = analyzer->filters[ii].type;
+    if (type == AnalyzerFilter_Lowercase) {
+      if (3 * n + 1 > cap) {
+        cap = 3 * n + 1;
+        buf = rm_realloc(buf, cap);
+        tmp = rm_realloc(tmp, cap);
+      }
+      n = lowercase(buf, n, tmp);
+    } else if (type == AnalyzerFilter_AsciiFolding) {
+      n = Normalize_AsciiFold(buf, n, tmp);
+    } else {
+      continue;
+    }
+    char *swap = buf;
+    buf = tmp;
+    tmp = swap;
+  }
+  rm_free(tmp);
+  buf[n] = '\0';
+  *len = n;
+  return buf;
+}
+
+/***************************************************************************************************
+ * Tokenizer
+ **************************************************************************************************/
+
+typedef struct {
+  RSTokenizer base;
+  const RSAnalyzer *analyzer;
+  // Of the document or query, for the STEM filters without a language
+  Stemmer *stemmer;
+  // By language, the stemmers of the STEM filters with a language. They are created on first use
+  // and kept when the tokenizer is reset for another analyzer
+  Stemmer *stemmers[RS_LANG_UNSUPPORTED];
+  // The rest of the text to tokenize
+  char *pos;
+  char *end;
+  // Filters read the token from buf and write it to tmp, before they are swapped
+  char *buf;
+  char *tmp;
+  size_t cap;
+  size_t len;
+  // The token as present in the text
+  const char *raw;
+  size_t rawLen;
+  // For the n-gram tokenizers, the filtered word which is split into grams
+  char *word;
+  size_t wordLen;
+  // Byte offsets of the characters of the word, followed by its length
+  uint32_t *chars;
+  uint32_t numChars;
+  size_t wordCap;
+  // The next gram, and the longest one
+  uint32_t gramStart;
+  uint32_t gramLen;
+  uint32_t maxGramLen;
+  // Emit the whole word after its grams
+  bool wholeWord;
+} analyzerTokenizer;
+
+static void reserve(analyzerTokenizer *self, size_t len) {
+  if (len + 1 > self->cap) {
+    self->cap = len + 1;
+    self->buf = rm_realloc(self->buf, self->cap);
+    self->tmp = rm_realloc(self->tmp, self->cap);
+  }
+}
+
+static void swapBuffers(analyzerTokenizer *self, size_t len) {
+  char *tmp = self->buf;
+  self->buf = self->tmp;
+  self->tmp = tmp;
+  self->len = len;
+  self->buf[len] = '\0';
+}
+
+static void analyzerTokenizer_Start(RSTokenizer *base, char *text, size_t len, uint32_t options) {
+  analyzerTokenizer *self = (analyzerTokenizer *)base;
+  TokenizerCtx *ctx = &base->ctx;
+  ctx->text = text;
+  ctx->len = len;
+  ctx->options = options;
+  self->pos = text;
+  self->end = text + len;
+  // No grams left from the previous text
+  self->gramLen = 1;
+  self->maxGramLen = 0;
+  self->wholeWord = false;
+
+  if (options & TOKENIZE_SINGLE_TOKEN) {
+    return;
+  }
+  for (size_t ii = 0; ii < array_len(self->analyzer->charFilters); ++ii) {
+    switch (self->analyzer->charFilters[ii]) {
+      case AnalyzerCharFilter_HtmlStrip:
+        htmlStrip(text, len);
+        break;
+    }
+  }
+}
+
+/* Copy the next token of the text to the buffer. Returns 0 when there are no more tokens */
+static int nextToken(analyzerTokenizer *self) {
+  AnalyzerTokenizerType type = self->analyzer->tokenizer;
+  if (self->base.ctx.options & TOKENIZE_SINGLE_TOKEN) {
+    type = AnalyzerTokenizer_Keyword;
+  }
+
+  while (self->pos < self->end) {
+    char *start = self->pos, *p = start;
+    self->len = 0;
+    reserve(self, self->end - start);
+
+    switch (type) {
+      case AnalyzerTokenizer_Keyword:
+        memcpy(self->buf, start, self->end - start);
+        self->len = self->end - start;
+        p = self->end;
+        break;
+
+      case AnalyzerTokenizer_Whitespace:
+        for (; p < self->end && !isspace((unsigned char)*p); ++p) {
+          self->buf[self->len++] = *p;
+        }
+        break;
+
+      case AnalyzerTokenizer_Standard:
+      case AnalyzerTokenizer_NGram:
+      case AnalyzerTokenizer_EdgeNGram:
+        // A backslash escapes a separator, which is then part of the token
+        for (; p < self->end && !istoksep(*p) && !isspace((unsigned char)*p); ++p) {
+          if (*p == '\\' && p + 1 < self->end) {
+            ++p;
+          } else if (iscntrl((unsigned char)*p)) {
+            continue;
+          }
+          self->buf[self->len++] = *p;
+        }
+        break;
+    }
+
+    // Skip the separator
+    self->pos = p < self->end ? p + 1 : self->end;
+    if (self->len) {
+      self->buf[self->len] = '\0';
+      self->raw = start;
+      self->rawLen = p - start;
+      return 1;
+    }
+  }
+  return 0;
+}
+
+static Stemmer *languageStemmer(analyzerTokenizer *self, RSLanguage language) {
+  if (!self->stemmers[language]) {
+    self->stemmers[language] = NewStemmer(SnowballStemmer, language);
+  }
+  return self->stemmers[language];
+}
+
+/* Run the filters over the token in the buffer. Returns 0 if it was dropped */
+static int filterToken(analyzerTokenizer *self, const char **stem, size_t *stemLen) {
+  TokenizerCtx *ctx = &self->base.ctx;
+  const RSAnalyzer *analyzer = self->analyzer;
+  for (size_t ii = 0; ii < array_len(analyzer->filters); ++ii) {
+    const AnalyzerFilter *f = analyzer->filters + ii;
+    switch (f->type) {
+      case AnalyzerFilter_Lowercase:
+        reserve(self, 3 * self->len);
+        swapBuffers(self, lowercase(self->buf, self->len, self->tmp));
+        break;
+
+      case AnalyzerFilter_AsciiFolding:
+        swapBuffers(self, Normalize_AsciiFold(self->buf, self->len, self->tmp));
+        break;
+
+      case AnalyzerFilter_Length: {
+        size_t n = utf8Length(self->buf, self->len);
+        if (n < f->length.min || n > f->length.max) {
+          return 0;
+        }
+        break;
+      }
+
+      case AnalyzerFilter_Stop:
+        if (StopWordList_Contains(ctx->stopwords, self->buf, self->len)) {
+          return 0;
+        }
+        break;
+
+      case AnalyzerFilter_Stem: {
+        Stemmer *stemmer = f->stem.hasLanguage ? languageStemmer(self, f->stem.language)
+                                               : self->stemmer;
+        if (!(ctx->options & TOKENIZE_NOSTEM) && stemmer &&
+            !StemExceptions_Find(ctx->stemExceptions, self->buf, self->len, stem, stemLen) &&
+            self->len >= MIN_STEM_CANDIDATE_LEN) {
+          *stem = stemmer->Stem(stemmer->ctx, self->buf, self->len, stemLen);
+        }
+        break;
+      }
+    }
+    if (!self->len) {
+      return 0;
+    }
+  }
+  return 1;
+}
+
+/* Split the filtered token in the buffer into grams.
+ *
+ * In the documents, the grams are ordered by length, so that the longest grams of a word are at
+ * consecutive positions. A query term is then looked up as is if it isn't longer than the longest
+ * grams, and as the phrase of its longest grams otherwise. Words shorter than the shortest grams
+ * are kept whole, and so are the words longer than the longest prefixes of EDGE_NGRAM, which has
+ * no phrases */
+static void startGrams(analyzerTokenizer *self) {
+  const RSAnalyzer *analyzer = self->analyzer;
+  if (self->len + 1 > self->wordCap) {
+    self->wordCap = self->len + 1;
+    self->word = rm_realloc(self->word, self->wordCap);
+    self->chars = rm_realloc(self->chars, self->wordCap * sizeof(*self->chars));
+  }
+  memcpy(self->word, self->buf, self->len);
+  self->wordLen = self->len;
+  self->numChars = 0;
+  for (uint32_t ii = 0; ii < self->len; ++ii) {
+    if (((unsigned char)self->buf[ii] & 0xC0) != 0x80) {
+      self->chars[self->numChars++] = ii;
+    }
+  }
+  self->chars[self->numChars] = self->len;
+
+  uint32_t n = self->numChars, min = analyzer->ngram.min, max = analyzer->ngram.max;
+  bool edge = analyzer->tokenizer == AnalyzerTokenizer_EdgeNGram;
+  self->gramStart = 0;
+  if (n < min || ((self->base.ctx.options & TOKENIZE_SINGLE_TOKEN) && (edge || n <= max))) {
+    // No grams
+    self->gramLen = 1;
+    self->maxGramLen = 0;
+    self->wholeWord = true;
+  } else {
+    self->gramLen = (self->base.ctx.options & TOKENIZE_SINGLE_TOKEN) ? max : min;
+    self->maxGramLen = MIN(max, n);
+    self->wholeWord = edge && n > max;
+  }
+}
+
+/* Copy the next gram of the word to the buffer. Returns 0 when there are no more grams */
+static int nextGram(analyzerTokenizer *self) {
+  bool edge = self->analyzer->tokenizer == AnalyzerTokenizer_EdgeNGram;
+  for (; self->gramLen <= self->maxGramLen; ++self->gramLen, self->gramStart = 0) {
+    uint32_t lastStart = edge ? 0 : self->numChars - self->gramLen;
+    if (self->gramStart <= lastStart) {
+      uint32_t from = self->chars[self->gramStart];
+      uint32_t to = self->chars[self->gramStart + self->gramLen];
+      memcpy(self->buf, self->word + from, to - from);
+      self->len = to - from;
+      self->buf[self->len] = '\0';
+      ++self->gramStart;
+      return 1;
+    }
+  }
+  if (self->wholeWord) {
+    self->wholeWord = false;
+    memcpy(self->buf, self->word, self->wordLen);
+    self->len = self->wordLen;
+    self->buf[self->len] = '\0';
+    return 1;
+  }
+  return 0;
+}
+
+static uint32_t analyzerTokenizer_Next(RSTokenizer *base, Token *t) {
+  analyzerTokenizer *self = (analyzerTokenizer *)base;
+  TokenizerCtx *ctx = &base->ctx;
+  const char *stem = NULL;
+  size_t stemLen = 0;
+
+  if (isNGramTokenizer(self->analyzer->tokenizer)) {
+    while (!nextGram(self)) {
+      if (!nextToken(self)) {
+        return 0;
+      }
+      if (filterToken(self, &stem, &stemLen)) {
+        startGrams(self);
+      }
+    }
+  } else {
+    do {
+      if (!nextToken(self)) {
+        return 0;
+      }
+    } while (!filterToken(self, &stem, &stemLen));
+  }
+
+  *t = (Token){.tok = self->buf,
+               .tokLen = self->len,
+               .raw = self->raw,
+               .rawLen = self->rawLen,
+               .stem = stem,
+               .stemLen = stemLen,
+               .pos = ++ctx->lastOffset,
+               .flags = Token_CopyRaw | Token_CopyStem,
+               .phoneticsPrimary = t->phoneticsPrimary};
+
+  if ((ctx->options & TOKENIZE_PHONETICS) && self->len >= RSGlobalConfig.minPhoneticTermLen) {
+    if (t->phoneticsPrimary) {
+      rm_free(t->phoneticsPrimary);
+      t->phoneticsPrimary = NULL;
+    }
+    PhoneticManager_ExpandPhonetics(NULL, self->buf, self->len, &t->phoneticsPrimary, NULL);
+  }
+  return ctx->lastOffset;
+}
+
+static void analyzerTokenizer_Reset(RSTokenizer *base, Stemmer *stemmer, StopWordList *stopwords,
+                                    uint32_t opts) {
+  analyzerTokenizer *self = (analyzerTokenizer *)base;
+  if (stopwords) {
+    StopWordList_Ref(stopwords);
+  }
+  if (base->ctx.stopwords) {
+    StopWordList_Unref(base->ctx.stopwords);
+  }
+  self->stemmer = stemmer;
+  base->ctx.stopwords = stopwords;
+  base->ctx.stemExceptions = NULL;
+  base->ctx.options = opts;
+  base->ctx.lastOffset = 0;
+}
+
+static void analyzerTokenizer_Free(RSTokenizer *base) {
+  analyzerTokenizer *self = (analyzerTokenizer *)base;
+  for (size_t ii = 0; ii < RS_LANG_UNSUPPORTED; ++ii) {
+    if (self->stemmers[ii]) {
+      self->stemmers[ii]->Free(self->stemmers[ii]);
+    }
+  }
+  if (base->ctx.stopwords) {
+    StopWordList_Unref(base->ctx.stopwords);
+  }
+  rm_free(self->buf);
+  rm_free(self->tmp);
+  rm_free(self->word);
+  rm_free(self->chars);
+  rm_free(self);
+}
+
+RSTokenizer *NewAnalyzerTokenizer(const RSAnalyzer *analyzer, Stemmer *stemmer,
+                                  StopWordList *stopwords, uint32_t opts) {
+  analyzerTokenizer *self = rm_calloc(1, sizeof(*self));
+  self->analyzer = analyzer;
+  self->base.Next = analyzerTokenizer_Next;
+  self->base.Start = analyzerTokenizer_Start;
+  self->base.Free = analyzerTokenizer_Free;
+  self->base.Reset = analyzerTokenizer_Reset;
+  self->base.Reset(&self->base, stemmer, stopwords, opts);
+  return &self->base;
+}
+
+void AnalyzerTokenizer_Reset(RSTokenizer *tokenizer, const RSAnalyzer *analyzer, Stemmer *stemmer,
+                             StopWordList *stopwords, uint32_t opts) {
+  ((analyzerTokenizer *)tokenizer)->analyzer = analyzer;
+  tokenizer->Reset(tokenizer, stemmer, stopwords, opts);
+}
@@ -0,0 +1,130 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "tokenize.h"
+#include "language.h"
+#include "query_error.h"
+#include "redismodule.h"
+#include "rmutil/args.h"
+
+/*
+ * An analyzer replaces the default tokenization of a TEXT field with a chain of:
+ *
+ * - Char filters, which edit the text before it is split into tokens. They keep the length of the
+ *   text, so that the byte offsets of the tokens still point into the document.
+ * - A tokenizer, which splits the text into tokens.
+ * - Token filters, which are applied in order to every token, and may drop it.
+ *
+ * The same filters are applied to the terms of the queries over the field. Query terms were
+ * already tokenized by the query parser, so the char filters and the tokenizer are skipped.
+ *
+ * Definition, as given after ANALYZER {nargs} in the schema:
+ *   [CHAR_FILTER HTML_STRIP]...
+ *   [TOKENIZER STANDARD | WHITESPACE | KEYWORD | NGRAM {min} {max} | EDGE_NGRAM {min} {max}]
+ *   [FILTER LOWERCASE | ASCIIFOLDING | LENGTH {min} {max} | STOP | STEM [LANGUAGE {lang}]]...
+ */
+
+#define ANALYZER_CHAR_FILTER_STR "CHAR_FILTER"
+#define ANALYZER_TOKENIZER_STR "TOKENIZER"
+#define ANALYZER_FILTER_STR "FILTER"
+
+typedef enum {
+  AnalyzerCharFilter_HtmlStrip,  // Replace HTML tags, comments and entities with spaces
+} AnalyzerCharFilterType;
+
+typedef enum {
+  AnalyzerTokenizer_Standard,    // Split on punctuation and whitespace, like the default tokenizer
+  AnalyzerTokenizer_Whitespace,  // Split on whitespace only
+  AnalyzerTokenizer_Keyword,     // The whole text is a single token
+  AnalyzerTokenizer_NGram,       // Split like the standard tokenizer, then into the grams of the words
+  AnalyzerTokenizer_EdgeNGram,   // Split like the standard tokenizer, then into the prefixes of the words
+} AnalyzerTokenizerType;
+
+typedef enum {
+  AnalyzerFilter_Lowercase,     // Lowercase all the letters, not only the ASCII ones
+  AnalyzerFilter_AsciiFolding,  // Replace the latin letters with diacritics with their ASCII form
+  AnalyzerFilter_Length,        // Drop tokens which are too short or too long
+  AnalyzerFilter_Stop,          // Drop the stopwords of the index
+  AnalyzerFilter_Stem,          // Index the stem of the tokens along with them
+} AnalyzerFilterType;
+
+typedef struct {
+  AnalyzerFilterType type;
+  union {
+    struct {
+      // In characters, inclusive
+      uint32_t min;
+      uint32_t max;
+    } length;
+    struct {
+      // Stem in this language rather than in the language of the document or query
+      RSLanguage language;
+      bool hasLanguage;
+    } stem;
+  };
+} AnalyzerFilter;
+
+typedef struct RSAnalyzer {
+  AnalyzerCharFilterType *charFilters;  // array
+  AnalyzerTokenizerType tokenizer;
+  struct {
+    // Lengths of the grams in characters, inclusive
+    uint32_t min;
+    uint32_t max;
+  } ngram;
+  AnalyzerFilter *filters;  // array
+  // The definition, which is persisted and replied by FT.INFO
+  char **args;  // array
+} RSAnalyzer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Parse an analyzer definition from a list of arguments. Returns NULL and sets the error if it is
+ * invalid */
+RSAnalyzer *Analyzer_New(const char **args, size_t nargs, QueryError *status);
+
+/* Parse an analyzer definition prefixed with its number of arguments */
+RSAnalyzer *Analyzer_Parse(ArgsCursor *ac, QueryError *status);
+
+void Analyzer_Free(RSAnalyzer *analyzer);
+
+bool Analyzer_HasFilter(const RSAnalyzer *analyzer, AnalyzerFilterType type);
+
+void Analyzer_RdbSave(RedisModuleIO *rdb, const RSAnalyzer *analyzer);
+
+/* Load an analyzer saved by Analyzer_RdbSave. `*analyzer` is set to NULL if there is none.
+ * Returns REDISMODULE_ERR if the saved analyzer couldn't be read */
+int Analyzer_RdbLoad(RedisModuleIO *rdb, RSAnalyzer **analyzer);
+
+/* Reply with the definition of the analyzer, as a list of arguments */
+void Analyzer_Reply(RedisModuleCtx *ctx, const RSAnalyzer *analyzer);
+
+/* Create a tokenizer running the chain of the analyzer. The analyzer must outlive it.
+ * `stemmer` is used by the STEM filters without a language, and may be NULL. The text given to
+ * the tokenizer is modified in place, unless TOKENIZE_SINGLE_TOKEN is set.
+ *
+ * With TOKENIZE_SINGLE_TOKEN, the n-gram tokenizers split the term into the grams it is looked up
+ * with, which are consecutive in the documents containing it */
+RSTokenizer *NewAnalyzerTokenizer(const RSAnalyzer *analyzer, Stemmer *stemmer,
+                                  StopWordList *stopwords, uint32_t opts);
+
+/* Reset a tokenizer created by NewAnalyzerTokenizer to run the chain of another analyzer, so that
+ * it can be reused across fields and documents */
+void AnalyzerTokenizer_Reset(RSTokenizer *tokenizer, const RSAnalyzer *analyzer, Stemmer *stemmer,
+                             StopWordList *stopwords, uint32_t opts);
+
+/* Apply the LOWERCASE and ASCIIFOLDING filters of the analyzer to a term which isn't tokenized,
+ * such as the term of a prefix, fuzzy or lexical range query. Returns a new string and sets `len`
+ * to its length */
+char *Analyzer_NormalizeTerm(const RSAnalyzer *analyzer, const char *s, size_t *len);
+
+#ifdef __cplusplus
+}
+#endif
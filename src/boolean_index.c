@@ -0,0 +1,320 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "boolean_index.h"
+#include "index_result.h"
+#include "rmalloc.h"
+#include "spec.h"
+
+#include <string.h>
+#include <sys/types.h>
+
+#define BITMAP_BLOCK_DOCS 1024
+#define BITMAP_BLOCK_WORDS (BITMAP_BLOCK_DOCS / 64)
+
+typedef struct {
+  t_docId firstId;  // A multiple of BITMAP_BLOCK_DOCS
+  uint64_t words[BITMAP_BLOCK_WORDS];
+} BitmapBlock;
+
+// The IDs of the documents holding one of the values, with the blocks sorted by their first ID
+typedef struct {
+  BitmapBlock *blocks;
+  size_t numBlocks;
+  size_t numDocs;
+} DocIdBitmap;
+
+struct BooleanIndex {
+  DocIdBitmap values[2];
+};
+
+/* Find the block which may hold `docId`. If there is none, returns NULL and sets `pos` to the
+ * position the block should be inserted at */
+static BitmapBlock *bitmap_findBlock(const DocIdBitmap *bm, t_docId docId, size_t *pos) {
+  t_docId firstId = docId - docId % BITMAP_BLOCK_DOCS;
+  size_t bottom = 0, top = bm->numBlocks;
+  while (bottom < top) {
+    size_t mid = (bottom + top) / 2;
+    if (bm->blocks[mid].firstId < firstId) {
+      bottom = mid + 1;
+    } else {
+      top = mid;
+    }
+  }
+  *pos = bottom;
+  if (bottom < bm->numBlocks && bm->blocks[bottom].firstId == firstId) {
+    return bm->blocks + bottom;
+  }
+  return NULL;
+}
+
+static int bitmap_test(const DocIdBitmap *bm, t_docId docId) {
+  size_t pos;
+  const BitmapBlock *blk = bitmap_findBlock(bm, docId, &pos);
+  if (!blk) {
+    return 0;
+  }
+  size_t bit = docId - blk->firstId;
+  return !!(blk->words[bit / 64] & (1ULL << (bit % 64)));
+}
+
+// Returns the memory the bitmap grew by
+static size_t bitmap_set(DocIdBitmap *bm, t_docId docId) {
+  size_t pos, grew = 0;
+  BitmapBlock *blk = bitmap_findBlock(bm, docId, &pos);
+  if (!blk) {
+    // Documents are added in increasing ID order, so the block is almost always appended
+    bm->blocks = rm_realloc(bm->blocks, (bm->numBlocks + 1) * sizeof(*bm->blocks));
+    memmove(bm->blocks + pos + 1, bm->blocks + pos, (bm->numBlocks - pos) * sizeof(*bm->blocks));
+    bm->numBlocks++;
+    blk = bm->blocks + pos;
+    memset(blk, 0, sizeof(*blk));
+    blk->firstId = docId - docId % BITMAP_BLOCK_DOCS;
+    grew = sizeof(*blk);
+  }
+  size_t bit = docId - blk->firstId;
+  uint64_t mask = 1ULL << (bit % 64);
+  if (!(blk->words[bit / 64] & mask)) {
+    blk->words[bit / 64] |= mask;
+    bm->numDocs++;
+  }
+  return grew;
+}
+
+// Returns the memory which was freed, or -1 if the document wasn't in the bitmap
+static ssize_t bitmap_clear(DocIdBitmap *bm, t_docId docId) {
+  size_t pos;
+  BitmapBlock *blk = bitmap_findBlock(bm, docId, &pos);
+  if (!blk) {
+    return -1;
+  }
+  size_t bit = docId - blk->firstId;
+  uint64_t mask = 1ULL << (bit % 64);
+  if (!(blk->words[bit / 64] & mask)) {
+    return -1;
+  }
+  blk->words[bit / 64] &= ~mask;
+  bm->numDocs--;
+
+  for (size_t i = 0; i < BITMAP_BLOCK_WORDS; ++i) {
+    if (blk->words[i]) {
+      return 0;
+    }
+  }
+  // The block is empty
+  memmove(blk, blk + 1, (bm->numBlocks - pos - 1) * sizeof(*bm->blocks));
+  bm->numBlocks--;
+  return sizeof(*blk);
+}
+
+/* Find the first document of the bitmap whose ID is at least `docId`, starting at the block
+ * `*blockPos`. Returns 0 if there is none */
+static t_docId bitmap_next(const DocIdBitmap *bm, size_t *blockPos, t_docId docId) {
+  if (*blockPos >= bm->numBlocks || bm->blocks[*blockPos].firstId > docId) {
+    // Blocks may have been added or removed while a cursor was idle
+    bitmap_findBlock(bm, docId, blockPos);
+  }
+  for (; *blockPos < bm->numBlocks; ++*blockPos) {
+    const BitmapBlock *blk = bm->blocks + *blockPos;
+    if (docId >= blk->firstId + BITMAP_BLOCK_DOCS) {
+      continue;
+    }
+    size_t bit = docId > blk->firstId ? docId - blk->firstId : 0;
+    size_t w = bit / 64;
+    uint64_t word = blk->words[w] & (~0ULL << (bit % 64));
+    while (!word && ++w < BITMAP_BLOCK_WORDS) {
+      word = blk->words[w];
+    }
+    if (word) {
+      return blk->firstId + w * 64 + __builtin_ctzll(word);
+    }
+  }
+  return 0;
+}
+
+static BooleanIndex *NewBooleanIndex() {
+  return rm_calloc(1, sizeof(BooleanIndex));
+}
+
+static void BooleanIndex_Free(BooleanIndex *idx) {
+  rm_free(idx->values[0].blocks);
+  rm_free(idx->values[1].blocks);
+  rm_free(idx);
+}
+
+#define BOOLEANINDEX_KEY_FMT "bl:%s/%s"
+
+RedisModuleString *fmtRedisBooleanIndexKey(RedisSearchCtx *ctx, const char *field) {
+  return RedisModule_CreateStringPrintf(ctx->redisCtx, BOOLEANINDEX_KEY_FMT, ctx->spec->name,
+                                        field);
+}
+
+BooleanIndex *OpenBooleanIndex(IndexSpec *spec, const FieldSpec *fs, int write) {
+  RedisModuleString *keyName = IndexSpec_GetFormattedKey(spec, fs, INDEXFLD_T_BOOLEAN);
+  KeysDictValue *kdv = dictFetchValue(spec->keysDict, keyName);
+  if (kdv) {
+    return kdv->p;
+  }
+  if (!write) {
+    return NULL;
+  }
+  kdv = rm_calloc(1, sizeof(*kdv));
+  kdv->dtor = (void (*)(void *))BooleanIndex_Free;
+  kdv->p = NewBooleanIndex();
+  dictAdd(spec->keysDict, keyName, kdv);
+  return kdv->p;
+}
+
+size_t BooleanIndex_Index(BooleanIndex *idx, t_docId docId, int mask) {
+  size_t grew = 0;
+  for (int val = 0; val < 2; ++val) {
+    if (mask & BOOLEAN_VALUE_MASK(val)) {
+      grew += bitmap_set(&idx->values[val], docId);
+    }
+  }
+  return grew;
+}
+
+void BooleanIndex_RemoveId(IndexSpec *spec, t_docId docId) {
+  for (size_t i = 0; i < spec->numFields; ++i) {
+    const FieldSpec *fs = spec->fields + i;
+    if (!FIELD_IS(fs, INDEXFLD_T_BOOLEAN)) {
+      continue;
+    }
+    BooleanIndex *idx = OpenBooleanIndex(spec, fs, 0);
+    if (!idx) {
+      continue;
+    }
+    for (int val = 0; val < 2; ++val) {
+      ssize_t freed = bitmap_clear(&idx->values[val], docId);
+      if (freed >= 0) {
+        spec->stats.invertedSize -= freed;
+        spec->stats.numRecords--;
+      }
+    }
+  }
+}
+
+/******************************************************************************
+ * Iterator
+ ******************************************************************************/
+
+typedef struct {
+  IndexIterator base;
+  const DocIdBitmap *bitmap;
+  size_t blockPos;
+  t_docId lastDocId;
+} BooleanIterator;
+
+typedef struct {
+  IndexCriteriaTester base;
+  const DocIdBitmap *bitmap;
+} BooleanCriteriaTester;
+
+static int BI_Test(IndexCriteriaTester *ct, t_docId id) {
+  return bitmap_test(((BooleanCriteriaTester *)ct)->bitmap, id);
+}
+
+static void BI_TesterFree(IndexCriteriaTester *ct) {
+  rm_free(ct);
+}
+
+static IndexCriteriaTester *BI_GetCriteriaTester(void *ctx) {
+  BooleanIterator *it = ctx;
+  BooleanCriteriaTester *ct = rm_malloc(sizeof(*ct));
+  ct->bitmap = it->bitmap;
+  ct->base.Test = BI_Test;
+  ct->base.Free = BI_TesterFree;
+  return &ct->base;
+}
+
+static int BI_Read(void *ctx, RSIndexResult **hit) {
+  BooleanIterator *it = ctx;
+  if (!it->base.isValid) {
+    return INDEXREAD_EOF;
+  }
+  t_docId docId = bitmap_next(it->bitmap, &it->blockPos, it->lastDocId + 1);
+  if (!docId) {
+    it->base.isValid = 0;
+    return INDEXREAD_EOF;
+  }
+  it->lastDocId = it->base.current->docId = docId;
+  *hit = it->base.current;
+  return INDEXREAD_OK;
+}
+
+static int BI_SkipTo(void *ctx, t_docId docId, RSIndexResult **hit) {
+  BooleanIterator *it = ctx;
+  if (!it->base.isValid) {
+    return INDEXREAD_EOF;
+  }
+  if (docId <= it->lastDocId) {
+    docId = it->lastDocId + 1;
+  }
+  bitmap_findBlock(it->bitmap, docId, &it->blockPos);
+  t_docId found = bitmap_next(it->bitmap, &it->blockPos, docId);
+  if (!found) {
+    it->base.isValid = 0;
+    return INDEXREAD_EOF;
+  }
+  it->lastDocId = it->base.current->docId = found;
+  *hit = it->base.current;
+  return found == docId ? INDEXREAD_OK : INDEXREAD_NOTFOUND;
+}
+
+static t_docId BI_LastDocId(void *ctx) {
+  return ((BooleanIterator *)ctx)->lastDocId;
+}
+
+static size_t BI_Len(void *ctx) {
+  return ((BooleanIterator *)ctx)->bitmap->numDocs;
+}
+
+static void BI_Abort(void *ctx) {
+  ((BooleanIterator *)ctx)->base.isValid = 0;
+}
+
+static void BI_Rewind(void *ctx) {
+  BooleanIterator *it = ctx;
+  it->base.isValid = 1;
+  it->blockPos = 0;
+  it->lastDocId = 0;
+  it->base.current->docId = 0;
+}
+
+static void BI_Free(IndexIterator *self) {
+  BooleanIterator *it = self->ctx;
+  IndexResult_Free(it->base.current);
+  rm_free(it);
+}
+
+IndexIterator *NewBooleanIterator(const BooleanIndex *idx, int val, double weight) {
+  const DocIdBitmap *bitmap = &idx->values[!!val];
+  if (!bitmap->numDocs) {
+    return NULL;
+  }
+  BooleanIterator *it = rm_calloc(1, sizeof(*it));
+  it->bitmap = bitmap;
+  it->base.isValid = 1;
+  it->base.current = NewVirtualResult(weight);
+  it->base.current->fieldMask = RS_FIELDMASK_ALL;
+
+  IndexIterator *ret = &it->base;
+  ret->ctx = it;
+  ret->type = BOOLEAN_ITERATOR;
+  ret->mode = MODE_SORTED;
+  ret->GetCriteriaTester = BI_GetCriteriaTester;
+  ret->NumEstimated = BI_Len;
+  ret->Read = BI_Read;
+  ret->SkipTo = BI_SkipTo;
+  ret->LastDocId = BI_LastDocId;
+  ret->HasNext = NULL;
+  ret->Free = BI_Free;
+  ret->Len = BI_Len;
+  ret->Abort = BI_Abort;
+  ret->Rewind = BI_Rewind;
+  return ret;
+}
@@ -0,0 +1,45 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "redisearch.h"
+#include "search_ctx.h"
+#include "index_iterator.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// The values of a document in a boolean field, as a mask of BOOLEAN_VALUE_MASK(value)
+#define BOOLEAN_VALUE_MASK(val) (1 << !!(val))
+
+/*
+ * The index of a BOOLEAN field. The documents holding each of the two values are kept in a
+ * bitmap, split into blocks of consecutive document IDs. Only the blocks which contain documents
+ * are allocated.
+ *
+ * Deleted documents are removed from the bitmaps on the fly, so the index isn't garbage collected.
+ */
+typedef struct BooleanIndex BooleanIndex;
+
+// Open the index of a boolean field. Returns NULL if it doesn't exist yet and `write` is not set
+BooleanIndex *OpenBooleanIndex(IndexSpec *spec, const FieldSpec *fs, int write);
+
+RedisModuleString *fmtRedisBooleanIndexKey(RedisSearchCtx *ctx, const char *field);
+
+// Add a document with the values of `mask`. Returns the memory the index grew by
+size_t BooleanIndex_Index(BooleanIndex *idx, t_docId docId, int mask);
+
+// Remove the document from the boolean indexes of the spec, and update its stats
+void BooleanIndex_RemoveId(IndexSpec *spec, t_docId docId);
+
+// An iterator over the documents holding the value `val`. Returns NULL if there are none
+IndexIterator *NewBooleanIterator(const BooleanIndex *idx, int val, double weight);
+
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,178 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "cjk_dict.h"
+#include "config.h"
+#include "rmalloc.h"
+#include "redismodule.h"
+#include "triemap/triemap.h"
+
+#include <ctype.h>
+#include <pthread.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+#include <strings.h>
+
+#define CJK_DICT_STOP_STR "STOP"
+
+struct CJKDict {
+  TrieMap *words;
+  size_t maxChars;
+};
+
+// Particles, auxiliaries and the most common inflections
+static const char *jaStopWords_g[] = {
+    "は",     "が",     "を",       "に",       "の",     "で",     "と",     "も",
+    "へ",     "や",     "か",       "ね",       "よ",     "な",     "から",   "まで",
+    "より",   "だけ",   "ほど",     "など",     "って",   "です",   "でした", "ます",
+    "ました", "ません", "でしょう", "だ",       "だった", "である", "ない",   "なかった",
+    "た",     "て",     "たい",     "れる",     "られる", "せる",   "させる", "いる",
+    "いた",   "います", "いました", "ある",     "あった", "あります", "する", "した",
+    "します", "しました", "して",   "される",   "された", "こと",   "もの",   "ため",
+    "よう",   "これ",   "それ",     "あれ",     "この",   "その",   "あの",   "ここ",
+    "そこ",   "ございます", NULL};
+
+static const char *jaWords_g[] = {
+    "私",     "日本",   "日本語",   "東京",     "大阪",   "京都",   "東京都", "学生",
+    "先生",   "学校",   "大学",     "会社",     "仕事",   "時間",   "今日",   "明日",
+    "昨日",   "天気",   "電話",     "電車",     "駅",     "世界",   "情報",   "検索",
+    "言語",   "研究",   "開発",     "技術",     "料理",   "寿司",   "旅行",   "映画",
+    "音楽",   "新聞",   "図書館",   "病院",     "銀行",   "ありがとう", "こんにちは",
+    "さようなら", NULL};
+
+// Particles (josa), endings (eomi) and the most common forms of the copula and of 하다
+static const char *koStopWords_g[] = {
+    "이",     "가",     "은",       "는",       "을",     "를",     "의",     "에",
+    "에서",   "에게",   "께",       "께서",     "한테",   "와",     "과",     "도",
+    "만",     "로",     "으로",     "까지",     "부터",   "보다",   "처럼",   "하고",
+    "이나",   "나",     "이랑",     "랑",       "에는",   "에서는", "으로는", "로는",
+    "에도",   "에서도", "이다",     "입니다",   "이에요", "예요",   "였다",   "이었다",
+    "하다",   "한다",   "합니다",   "했다",     "했습니다", "해요",  "하는",   "습니다",
+    "니다",   "어요",   "아요",     NULL};
+
+static const char *koWords_g[] = {
+    "한국",   "한국어", "서울",     "부산",     "학교",   "대학",   "대학교", "학생",
+    "선생님", "회사",   "사람",     "시간",     "오늘",   "내일",   "어제",   "날씨",
+    "도서관", "병원",   "은행",     "컴퓨터",   "검색",   "정보",   "세계",   "데이터",
+    "음식",   "사과",   "나무",     "여행",     "영화",   "음악",   "신문",   "집",
+    "책",     "물",     "밥",       "차",       "말",     "눈",     "길",     "문",
+    NULL};
+
+/* The number of characters of a UTF-8 string */
+static size_t utf8Length(const char *s, size_t len) {
+  size_t n = 0;
+  for (size_t ii = 0; ii < len; ++ii) {
+    n += ((unsigned char)s[ii] & 0xC0) != 0x80;
+  }
+  return n;
+}
+
+// The entries are stored as the values, rather than pointers to them
+static void *replaceEntry(void *oldval, void *newval) {
+  return newval;
+}
+
+static void addWord(CJKDict *dict, const char *word, size_t len, CJKDictEntry entry) {
+  if (!len) {
+    return;
+  }
+  TrieMap_Add(dict->words, (char *)word, len, (void *)(uintptr_t)entry, replaceEntry);
+  size_t chars = utf8Length(word, len);
+  if (chars > dict->maxChars) {
+    dict->maxChars = chars;
+  }
+}
+
+static void addWords(CJKDict *dict, const char **words, CJKDictEntry entry) {
+  for (const char **w = words; *w; ++w) {
+    addWord(dict, *w, strlen(*w), entry);
+  }
+}
+
+/* Load a user dictionary. Unreadable files are logged and skipped, leaving the built-in words */
+static void loadFile(CJKDict *dict, const char *path) {
+  FILE *fp = fopen(path, "r");
+  if (!fp) {
+    RedisModule_Log(NULL, "warning", "Could not open dictionary file %s", path);
+    return;
+  }
+
+  char *line = NULL;
+  size_t cap = 0;
+  ssize_t n;
+  size_t numWords = 0;
+  while ((n = getline(&line, &cap, fp)) != -1) {
+    while (n > 0 && isspace((unsigned char)line[n - 1])) {
+      line[--n] = '\0';
+    }
+    if (!n || line[0] == '#') {
+      continue;
+    }
+    size_t len = strcspn(line, " \t");
+    const char *type = line + len;
+    while (*type && isspace((unsigned char)*type)) {
+      ++type;
+    }
+    if (*type && strcasecmp(type, CJK_DICT_STOP_STR)) {
+      RedisModule_Log(NULL, "warning", "Unknown word type `%s` in dictionary file %s", type, path);
+      continue;
+    }
+    addWord(dict, line, len, *type ? CJKDict_Stop : CJKDict_Word);
+    ++numWords;
+  }
+  free(line);
+  fclose(fp);
+  RedisModule_Log(NULL, "notice", "Loaded %zu words from dictionary file %s", numWords, path);
+}
+
+static CJKDict *newDict(const char **words, const char **stopWords, const char *path) {
+  CJKDict *dict = rm_calloc(1, sizeof(*dict));
+  dict->words = NewTrieMap();
+  addWords(dict, words, CJKDict_Word);
+  addWords(dict, stopWords, CJKDict_Stop);
+  // Words of the user dictionary replace the built-in ones
+  if (path) {
+    loadFile(dict, path);
+  }
+  return dict;
+}
+
+static CJKDict *jaDict_g = NULL;
+static CJKDict *koDict_g = NULL;
+static pthread_once_t jaDictOnce_g = PTHREAD_ONCE_INIT;
+static pthread_once_t koDictOnce_g = PTHREAD_ONCE_INIT;
+
+static void loadJapanese() {
+  jaDict_g = newDict(jaWords_g, jaStopWords_g, RSGlobalConfig.japaneseDict);
+}
+
+static void loadKorean() {
+  koDict_g = newDict(koWords_g, koStopWords_g, RSGlobalConfig.koreanDict);
+}
+
+const CJKDict *CJKDict_Get(RSLanguage language) {
+  // Tokenizers are also used by the indexing threads
+  switch (language) {
+    case RS_LANG_JAPANESE:
+      pthread_once(&jaDictOnce_g, loadJapanese);
+      return jaDict_g;
+    case RS_LANG_KOREAN:
+      pthread_once(&koDictOnce_g, loadKorean);
+      return koDict_g;
+    default:
+      return NULL;
+  }
+}
+
+CJKDictEntry CJKDict_Find(const CJKDict *dict, const char *s, size_t len) {
+  void *entry = TrieMap_Find(dict->words, s, len);
+  return entry == TRIEMAP_NOTFOUND ? CJKDict_NotFound : (CJKDictEntry)(uintptr_t)entry;
+}
+
+size_t CJKDict_MaxChars(const CJKDict *dict) {
+  return dict->maxChars;
+}
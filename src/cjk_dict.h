@@ -0,0 +1,43 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "language.h"
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Dictionaries of the Japanese and Korean tokenizers, made of built-in words and of the words of
+ * the user dictionary file given by the JAPANESE_DICT or KOREAN_DICT configuration.
+ *
+ * A user dictionary has a word per line. A word may be followed by whitespace and `STOP`, for
+ * particles, endings and auxiliaries which are segmented but not indexed. Empty lines and lines
+ * starting with `#` are ignored.
+ */
+
+typedef enum {
+  CJKDict_NotFound = 0,
+  CJKDict_Word,  // Indexed
+  CJKDict_Stop,  // Segmented, but not indexed
+} CJKDictEntry;
+
+typedef struct CJKDict CJKDict;
+
+/* The dictionary of a language, loaded on first use. Returns NULL if the language has none */
+const CJKDict *CJKDict_Get(RSLanguage language);
+
+CJKDictEntry CJKDict_Find(const CJKDict *dict, const char *s, size_t len);
+
+/* The length of the longest word, in characters */
+size_t CJKDict_MaxChars(const CJKDict *dict);
+
+#ifdef __cplusplus
+}
+#endif
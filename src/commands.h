@@ -34,6 +34,8 @@
 #define RS_DROP_INDEX_IF_X_CMD RS_CMD_WRITE_PREFIX "._DROPINDEXIFX"  // for replica of support
 #define RS_SYNADD_CMD RS_CMD_WRITE_PREFIX ".SYNADD"
 #define RS_SYNUPDATE_CMD RS_CMD_WRITE_PREFIX ".SYNUPDATE"
+#define RS_STEMADD_CMD RS_CMD_WRITE_PREFIX ".STEMADD"
+#define RS_STEMDEL_CMD RS_CMD_WRITE_PREFIX ".STEMDEL"
 #define RS_ALTER_CMD RS_CMD_WRITE_PREFIX ".ALTER"
 #define RS_ALTER_IF_NX_CMD RS_CMD_WRITE_PREFIX "._ALTERIFNX"  // for replica of support
 #define RS_DICT_ADD RS_CMD_WRITE_PREFIX ".DICTADD"
@@ -65,5 +67,6 @@
 #define RS_DICT_DUMP RS_CMD_READ_PREFIX ".DICTDUMP"
 #define RS_CONFIG RS_CMD_READ_PREFIX ".CONFIG"
 #define RS_SYNDUMP_CMD RS_CMD_READ_PREFIX ".SYNDUMP"
+#define RS_STEMDUMP_CMD RS_CMD_READ_PREFIX ".STEMDUMP"
 
 #endif
@@ -261,6 +261,24 @@ CONFIG_GETTER(getFrisoINI) {
   return config->frisoIni ? sdsnew(config->frisoIni) : NULL;
 }
 
+// JAPANESE_DICT
+CONFIG_SETTER(setJapaneseDict) {
+  int acrc = AC_GetString(ac, &config->japaneseDict, NULL, 0);
+  RETURN_STATUS(acrc);
+}
+CONFIG_GETTER(getJapaneseDict) {
+  return config->japaneseDict ? sdsnew(config->japaneseDict) : NULL;
+}
+
+// KOREAN_DICT
+CONFIG_SETTER(setKoreanDict) {
+  int acrc = AC_GetString(ac, &config->koreanDict, NULL, 0);
+  RETURN_STATUS(acrc);
+}
+CONFIG_GETTER(getKoreanDict) {
+  return config->koreanDict ? sdsnew(config->koreanDict) : NULL;
+}
+
 // ON_TIMEOUT
 CONFIG_SETTER(setOnTimeout) {
   const char *policy;
@@ -684,6 +702,16 @@ RSConfigOptions RSGlobalConfigOptions = {
          .setValue = setFrisoINI,
          .getValue = getFrisoINI,
          .flags = RSCONFIGVAR_F_IMMUTABLE},
+        {.name = "JAPANESE_DICT",
+         .helpText = "Path to a user dictionary file for Japanese tokenization",
+         .setValue = setJapaneseDict,
+         .getValue = getJapaneseDict,
+         .flags = RSCONFIGVAR_F_IMMUTABLE},
+        {.name = "KOREAN_DICT",
+         .helpText = "Path to a user dictionary file for Korean tokenization",
+         .setValue = setKoreanDict,
+         .getValue = getKoreanDict,
+         .flags = RSCONFIGVAR_F_IMMUTABLE},
         {.name = "ON_TIMEOUT",
          .helpText = "Action to perform when search timeout is exceeded (choose RETURN or FAIL)",
          .setValue = setOnTimeout,
@@ -827,6 +855,12 @@ sds RSConfig_GetInfoString(const RSConfig *config) {
   if (config->frisoIni) {
     ss = sdscatprintf(ss, "friso ini: %s, ", config->frisoIni);
   }
+  if (config->japaneseDict) {
+    ss = sdscatprintf(ss, "japanese dict: %s, ", config->japaneseDict);
+  }
+  if (config->koreanDict) {
+    ss = sdscatprintf(ss, "korean dict: %s, ", config->koreanDict);
+  }
   return ss;
 }
 
@@ -909,6 +943,12 @@ void RSConfig_AddToInfo(RedisModuleInfoCtx *ctx) {
   if (RSGlobalConfig.frisoIni != NULL) {
     RedisModule_InfoAddFieldCString(ctx, "friso_ini", (char*)RSGlobalConfig.frisoIni);
   }
+  if (RSGlobalConfig.japaneseDict != NULL) {
+    RedisModule_InfoAddFieldCString(ctx, "japanese_dict", (char*)RSGlobalConfig.japaneseDict);
+  }
+  if (RSGlobalConfig.koreanDict != NULL) {
+    RedisModule_InfoAddFieldCString(ctx, "korean_dict", (char*)RSGlobalConfig.koreanDict);
+  }
   RedisModule_InfoAddFieldCString(ctx, "enableGC", RSGlobalConfig.gcConfigParams.enableGC ? "ON" : "OFF");
   RedisModule_InfoAddFieldLongLong(ctx, "minimal_term_prefix", RSGlobalConfig.iteratorsConfigParams.minTermPrefix);
   RedisModule_InfoAddFieldLongLong(ctx, "maximal_prefix_expansions", RSGlobalConfig.iteratorsConfigParams.maxPrefixExpansions);
@@ -87,6 +87,9 @@ typedef struct {
   const char *extLoad;
   // Path to friso.ini for chinese dictionary file
   const char *frisoIni;
+  // Paths to user dictionaries for japanese and korean tokenization
+  const char *japaneseDict;
+  const char *koreanDict;
 
   IteratorsConfig iteratorsConfigParams;
 
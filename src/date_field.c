@@ -0,0 +1,182 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "date_field.h"
+
+#include <ctype.h>
+#include <math.h>
+#include <stdint.h>
+#include <string.h>
+#include <strings.h>
+#include <time.h>
+
+static int parseDigits(const char **p, const char *end, int n, int *out) {
+  int v = 0;
+  for (int i = 0; i < n; ++i, ++*p) {
+    if (*p >= end || !isdigit((unsigned char)**p)) {
+      return 0;
+    }
+    v = v * 10 + (**p - '0');
+  }
+  *out = v;
+  return 1;
+}
+
+static int isLeapYear(int y) {
+  return (y % 4 == 0 && y % 100 != 0) || y % 400 == 0;
+}
+
+static int daysInMonth(int y, int m) {
+  static const int days[] = {31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31};
+  return (m == 2 && isLeapYear(y)) ? 29 : days[m - 1];
+}
+
+// Number of days since 1970-01-01 of a proleptic Gregorian calendar date
+static int64_t daysFromCivil(int64_t y, int m, int d) {
+  y -= m <= 2;
+  const int64_t era = (y >= 0 ? y : y - 399) / 400;
+  const int64_t yoe = y - era * 400;
+  const int64_t doy = (153 * (m > 2 ? m - 3 : m + 9) + 2) / 5 + d - 1;
+  const int64_t doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+  return era * 146097 + doe - 719468;
+}
+
+int DateField_ParseISO8601(const char *s, size_t len, double *ts) {
+  const char *p = s, *end = s + len;
+  int year, month = 1, day = 1, hour = 0, min = 0, sec = 0, offset = 0;
+  double frac = 0;
+
+  if (!parseDigits(&p, end, 4, &year)) {
+    return 0;
+  }
+  if (p < end && *p == '-') {
+    ++p;
+    if (!parseDigits(&p, end, 2, &month)) {
+      return 0;
+    }
+    if (p < end && *p == '-') {
+      ++p;
+      if (!parseDigits(&p, end, 2, &day)) {
+        return 0;
+      }
+      if (p < end && (*p == 'T' || *p == 't' || *p == ' ')) {
+        ++p;
+        if (!parseDigits(&p, end, 2, &hour) || p >= end || *p++ != ':' ||
+            !parseDigits(&p, end, 2, &min)) {
+          return 0;
+        }
+        if (p < end && *p == ':') {
+          ++p;
+          if (!parseDigits(&p, end, 2, &sec)) {
+            return 0;
+          }
+          if (p < end && (*p == '.' || *p == ',')) {
+            ++p;
+            if (p >= end || !isdigit((unsigned char)*p)) {
+              return 0;
+            }
+            for (double scale = 0.1; p < end && isdigit((unsigned char)*p); ++p, scale /= 10) {
+              frac += (*p - '0') * scale;
+            }
+          }
+        }
+        // timezone designator
+        if (p < end && (*p == 'Z' || *p == 'z')) {
+          ++p;
+        } else if (p < end && (*p == '+' || *p == '-')) {
+          int sign = *p++ == '-' ? -1 : 1;
+          int tzh, tzm = 0;
+          if (!parseDigits(&p, end, 2, &tzh)) {
+            return 0;
+          }
+          if (p < end && *p == ':') {
+            ++p;
+          }
+          if (p < end && !parseDigits(&p, end, 2, &tzm)) {
+            return 0;
+          }
+          if (tzh > 23 || tzm > 59) {
+            return 0;
+          }
+          offset = sign * (tzh * 3600 + tzm * 60);
+        }
+      }
+    }
+  }
+
+  if (p != end || month < 1 || month > 12 || day < 1 || day > daysInMonth(year, month) ||
+      hour > 23 || min > 59 || sec > 59) {
+    return 0;
+  }
+
+  int64_t secs = daysFromCivil(year, month, day) * 86400 + hour * 3600 + min * 60 + sec - offset;
+  *ts = (double)secs + frac;
+  return 1;
+}
+
+static int unitSeconds(char unit, double *secs) {
+  switch (unit) {
+    case 's': *secs = 1; return 1;
+    case 'm': *secs = 60; return 1;
+    case 'h': *secs = 3600; return 1;
+    case 'd': *secs = 86400; return 1;
+    case 'w': *secs = 7 * 86400; return 1;
+    default:  return 0;
+  }
+}
+
+int DateField_ParseBound(const char *s, size_t len, double now, double *ts) {
+  size_t nowlen = strlen(DATE_FIELD_NOW_STR);
+  if (len < nowlen || strncasecmp(s, DATE_FIELD_NOW_STR, nowlen)) {
+    return DateField_ParseISO8601(s, len, ts);
+  }
+
+  // relative expression: now([+-]<amount><unit>)*
+  const char *p = s + nowlen, *end = s + len;
+  double val = now;
+  while (p < end) {
+    if (*p != '+' && *p != '-') {
+      return 0;
+    }
+    int sign = *p++ == '-' ? -1 : 1;
+    if (p >= end || !isdigit((unsigned char)*p)) {
+      return 0;
+    }
+    long long amount = 0;
+    for (; p < end && isdigit((unsigned char)*p); ++p) {
+      amount = amount * 10 + (*p - '0');
+      if (amount > 1000000000LL) {
+        return 0;
+      }
+    }
+    double unit;
+    if (p >= end || !unitSeconds(*p++, &unit)) {
+      return 0;
+    }
+    val += sign * amount * unit;
+  }
+  *ts = val;
+  return 1;
+}
+
+int DateField_ParseRangeBound(const char *s, size_t len, int isMax, double now, double *ts) {
+  if (len == 1 && *s == '*') {
+    *ts = isMax ? INFINITY : -INFINITY;
+  } else if (len == 4 && !strncasecmp(s, "-inf", 4)) {
+    *ts = -INFINITY;
+  } else if ((len == 4 && !strncasecmp(s, "+inf", 4)) || (len == 3 && !strncasecmp(s, "inf", 3))) {
+    *ts = INFINITY;
+  } else {
+    return DateField_ParseBound(s, len, now, ts);
+  }
+  return 1;
+}
+
+double DateField_Now() {
+  struct timespec now;
+  clock_gettime(CLOCK_REALTIME, &now);
+  return now.tv_sec + now.tv_nsec / 1e9;
+}
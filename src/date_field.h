@@ -0,0 +1,47 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define DATE_FIELD_NOW_STR "now"
+
+/**
+ * Parse an ISO-8601 date/time string into seconds since the epoch (UTC).
+ * Supported forms are `YYYY`, `YYYY-MM`, `YYYY-MM-DD`, optionally followed by a `T` (or a space)
+ * and `HH:MM[:SS[.fff]]` with an optional `Z` / `+HH[:MM]` / `-HH[:MM]` offset.
+ * Values without an offset are taken as UTC.
+ * Returns 1 on success, 0 if the string is not a valid date.
+ */
+int DateField_ParseISO8601(const char *s, size_t len, double *ts);
+
+/**
+ * Parse a date range bound. On top of ISO-8601 dates, relative expressions such as
+ * `now`, `now-7d` or `now-1d+12h` are accepted, relative to `now` (seconds since the epoch).
+ * Supported units are `s`, `m`, `h`, `d` and `w`.
+ * Returns 1 on success, 0 otherwise.
+ */
+int DateField_ParseBound(const char *s, size_t len, double now, double *ts);
+
+/**
+ * Parse a bound of a date range, `@field:[<from> TO <to>]`. On top of the bounds accepted by
+ * DateField_ParseBound, `*`, `-inf` and `+inf` are accepted for open ranges, `isMax` telling
+ * which end of the range `*` stands for.
+ * Returns 1 on success, 0 otherwise.
+ */
+int DateField_ParseRangeBound(const char *s, size_t len, int isMax, double now, double *ts);
+
+/* The current time in seconds since the epoch, which relative bounds refer to */
+double DateField_Now();
+
+#ifdef __cplusplus
+}
+#endif
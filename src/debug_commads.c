@@ -16,6 +16,7 @@
 #include "gc.h"
 #include "module.h"
 #include "suffix.h"
+#include "vector_compression.h"
 
 #define DUMP_PHONETIC_HASH "DUMP_PHONETIC_HASH"
 
@@ -889,9 +890,13 @@ DEBUG_COMMAND(VecsimInfo) {
   // This call can't fail, since we already checked that the key exists
   // (or should exist, and this call will create it).
   VecSimIndex *vecsimIndex = OpenVectorIndex(sctx->spec, keyName);
+  const char *fieldName = RedisModule_StringPtrLen(argv[1], NULL);
+  const FieldSpec *fs = IndexSpec_GetField(sctx->spec, fieldName, strlen(fieldName));
+  bool compressed = FieldSpec_IsVectorCompressed(fs);
 
   VecSimInfoIterator *infoIter = VecSimIndex_InfoIterator(vecsimIndex);
-  RedisModule_ReplyWithArray(ctx, VecSimInfoIterator_NumberOfFields(infoIter)*2);
+  size_t nfields = VecSimInfoIterator_NumberOfFields(infoIter) + (compressed ? 3 : 0);
+  RedisModule_ReplyWithArray(ctx, nfields * 2);
   while(VecSimInfoIterator_HasNextField(infoIter)) {
     VecSim_InfoField* infoField = VecSimInfoIterator_NextField(infoIter);
     RedisModule_ReplyWithSimpleString(ctx, infoField->fieldName);
@@ -914,6 +919,17 @@ DEBUG_COMMAND(VecsimInfo) {
     }
   }
   VecSimInfoIterator_Free(infoIter);
+  if (compressed) {
+    // The library reports the type and memory of the quantized vectors
+    VecSimIndexInfo info = VecSimIndex_Info(vecsimIndex);
+    RedisModule_ReplyWithSimpleString(ctx, "COMPRESSION");
+    RedisModule_ReplyWithSimpleString(ctx, VECSIM_COMPRESSION_SQ8);
+    RedisModule_ReplyWithSimpleString(ctx, "RERANK_FACTOR");
+    RedisModule_ReplyWithLongLong(ctx, fs->vectorOpts.compression.rerankFactor);
+    RedisModule_ReplyWithSimpleString(ctx, "RAW_MEMORY");
+    RedisModule_ReplyWithLongLong(ctx,
+                                  VecSimCompression_RawMemory(fs, vecsimIndex, info.hnswInfo.memory));
+  }
   SearchCtx_Free(sctx);
   return REDISMODULE_OK;
 }
@@ -16,10 +16,14 @@
 #include "util/mempool.h"
 #include "spec.h"
 #include "tokenize.h"
+#include "analyzer.h"
 #include "util/logging.h"
 #include "rmalloc.h"
 #include "indexer.h"
 #include "tag_index.h"
+#include "boolean_index.h"
+#include "date_field.h"
+#include "vector_index.h"
 #include "geometry/geometry_api.h"
 #include "aggregate/expr/expression.h"
 #include "rmutil/rm_assert.h"
@@ -39,6 +43,9 @@ static void freeDocumentContext(void *p) {
   if (aCtx->fwIdx) {
     ForwardIndexFree(aCtx->fwIdx);
   }
+  if (aCtx->analyzerTokenizer) {
+    aCtx->analyzerTokenizer->Free(aCtx->analyzerTokenizer);
+  }
 
   rm_free(aCtx->fspecs);
   rm_free(aCtx->fdatas);
@@ -221,7 +228,11 @@ RSAddDocumentCtx *NewAddDocumentCtx(IndexSpec *sp, Document *doc, QueryError *st
     aCtx->fwIdx->smap = NULL;
   }
 
+  // The exceptions are never modified, so the indexing thread can keep a reference to them
+  aCtx->fwIdx->stemExceptions = sp->stemExceptions ? StemExceptions_Ref(sp->stemExceptions) : NULL;
+
   aCtx->tokenizer = GetTokenizer(doc->language, aCtx->fwIdx->stemmer, sp->stopwords);
+  aCtx->tokenizer->ctx.stemExceptions = aCtx->fwIdx->stemExceptions;
 //  aCtx->doc->docId = 0;
   return aCtx;
 }
@@ -378,8 +389,8 @@ void AddDocumentCtx_Free(RSAddDocumentCtx *aCtx) {
       if (FIELD_IS(aCtx->fspecs + ii, INDEXFLD_T_TAG) && aCtx->fdatas[ii].tags) {
         TagIndex_FreePreprocessedData(aCtx->fdatas[ii].tags);
         aCtx->fdatas[ii].tags = NULL;
-      } else if (FIELD_IS(aCtx->fspecs + ii, INDEXFLD_T_GEO) && aCtx->fdatas[ii].isMulti &&
-                 aCtx->fdatas[ii].arrNumeric && !FIELD_IS_NULL(aCtx, ii)) {
+      } else if (FIELD_IS(aCtx->fspecs + ii, INDEXFLD_T_GEO | INDEXFLD_T_DATE) &&
+                 aCtx->fdatas[ii].isMulti && aCtx->fdatas[ii].arrNumeric && !FIELD_IS_NULL(aCtx, ii)) {
         array_free(aCtx->fdatas[ii].arrNumeric);
         aCtx->fdatas[ii].arrNumeric = NULL;
       }
@@ -407,6 +418,11 @@ void AddDocumentCtx_Free(RSAddDocumentCtx *aCtx) {
     aCtx->tokenizer = NULL;
   }
 
+  if (aCtx->analyzerTokenizer) {
+    // Release the stopwords, but keep the tokenizer for the next document
+    AnalyzerTokenizer_Reset(aCtx->analyzerTokenizer, NULL, NULL, NULL, 0);
+  }
+
   if (aCtx->oldMd) {
     DMD_Return(aCtx->oldMd);
     aCtx->oldMd = NULL;
@@ -479,6 +495,25 @@ FIELD_PREPROCESSOR(fulltextPreprocessor) {
     if (FieldSpec_IsPhonetics(fs)) {
       options |= TOKENIZE_PHONETICS;
     }
+    if (FieldSpec_IsAsciiFold(fs)) {
+      options |= TOKENIZE_ASCIIFOLD;
+    }
+    if (FieldSpec_IsNFKC(fs)) {
+      options |= TOKENIZE_NFKC;
+    }
+
+    // Fields with an analyzer have their own tokenizer, which continues the positions of the document
+    RSTokenizer *tokenizer = aCtx->tokenizer;
+    if (fs->analyzer) {
+      if (!aCtx->analyzerTokenizer) {
+        aCtx->analyzerTokenizer = NewAnalyzerTokenizer(fs->analyzer, NULL, NULL, 0);
+      }
+      tokenizer = aCtx->analyzerTokenizer;
+      AnalyzerTokenizer_Reset(tokenizer, fs->analyzer, aCtx->fwIdx->stemmer,
+                              aCtx->tokenizer->ctx.stopwords, options);
+      tokenizer->ctx.stemExceptions = aCtx->fwIdx->stemExceptions;
+      tokenizer->ctx.lastOffset = aCtx->tokenizer->ctx.lastOffset;
+    }
 
     unsigned int multiTextOffsetDelta;
     if (valueCount > 1 && RSGlobalConfig.multiTextOffsetDelta > 0) {
@@ -494,14 +529,14 @@ FIELD_PREPROCESSOR(fulltextPreprocessor) {
         c = DocumentField_GetArrayValueCStr(field, &fl, i);
       }
       ForwardIndexTokenizerCtx_Init(&tokCtx, aCtx->fwIdx, c, curOffsetWriter, fs->ftId, fs->ftWeight);
-      aCtx->tokenizer->Start(aCtx->tokenizer, (char *)c, fl, options);
+      tokenizer->Start(tokenizer, (char *)c, fl, options);
 
       Token tok = {0};
       uint32_t newTokPos;
-      while (0 != (newTokPos = aCtx->tokenizer->Next(aCtx->tokenizer, &tok))) {
+      while (0 != (newTokPos = tokenizer->Next(tokenizer, &tok))) {
         forwardIndexTokenFunc(&tokCtx, &tok);
       }
-      uint32_t lastTokPos = aCtx->tokenizer->ctx.lastOffset;
+      uint32_t lastTokPos = tokenizer->ctx.lastOffset;
 
       if (curOffsetField) {
         curOffsetField->lastTokPos = lastTokPos;
@@ -509,10 +544,14 @@ FIELD_PREPROCESSOR(fulltextPreprocessor) {
       aCtx->totalTokens = lastTokPos;
       Token_Destroy(&tok);
 
-      aCtx->tokenizer->ctx.lastOffset += multiTextOffsetDelta;
+      tokenizer->ctx.lastOffset += multiTextOffsetDelta;
     }
     // Decrease the last increment
-    aCtx->tokenizer->ctx.lastOffset -= multiTextOffsetDelta;
+    tokenizer->ctx.lastOffset -= multiTextOffsetDelta;
+
+    if (tokenizer != aCtx->tokenizer) {
+      aCtx->tokenizer->ctx.lastOffset = tokenizer->ctx.lastOffset;
+    }
   }
   return 0;
 }
@@ -566,25 +605,80 @@ FIELD_PREPROCESSOR(numericPreprocessor) {
 }
 
 
+FIELD_PREPROCESSOR(datePreprocessor) {
+  size_t len;
+  const char *str;
+  switch (field->unionType) {
+    case FLD_VAR_T_RMS:
+    case FLD_VAR_T_CSTR:
+      fdata->isMulti = 0;
+      str = DocumentField_GetValueCStr(field, &len);
+      if (!DateField_ParseISO8601(str, len, &fdata->numeric)) {
+        goto error;
+      }
+      break;
+    case FLD_VAR_T_NUM:
+      // already a timestamp
+      fdata->isMulti = 0;
+      fdata->numeric = field->numval;
+      break;
+    case FLD_VAR_T_NULL:
+      fdata->isNull = 1;
+      return 0;
+    case FLD_VAR_T_ARRAY: {
+      fdata->isMulti = 1;
+      arrayof(double) arr = array_new(double, field->arrayLen);
+      for (size_t i = 0; i < field->arrayLen; ++i) {
+        double ts;
+        str = DocumentField_GetArrayValueCStr(field, &len, i);
+        if (!DateField_ParseISO8601(str, len, &ts)) {
+          array_free(arr);
+          fdata->arrNumeric = NULL;
+          goto error;
+        }
+        array_ensure_append_1(arr, ts);
+      }
+      fdata->arrNumeric = arr;
+      break;
+    }
+    default:
+      goto error;
+  }
+
+  if (FieldSpec_IsSortable(fs)) {
+    if (field->unionType != FLD_VAR_T_ARRAY) {
+      RSSortingVector_Put(aCtx->sv, fs->sortIdx, &fdata->numeric, RS_SORTABLE_NUM, 0);
+    } else if (field->multisv) {
+      RSSortingVector_Put(aCtx->sv, fs->sortIdx, field->multisv, RS_SORTABLE_RSVAL, 0);
+      field->multisv = NULL;
+    }
+  }
+  return 0;
+
+error:
+  QueryError_SetErrorFmt(status, QUERY_EBADATTR, "Invalid date value for field `%s`", fs->name);
+  return -1;
+}
+
 FIELD_PREPROCESSOR(geometryPreprocessor) {
   switch (field->unionType) {
     case FLD_VAR_T_RMS:
     {
-      // From WKT RMS
+      // From WKT or GeoJSON RMS
       fdata->isMulti = 0;
       size_t len;
       const char *str = RedisModule_StringPtrLen(field->text, &len);
       fdata->str = str;
       fdata->strlen = len;
-      fdata->format = GEOMETRY_FORMAT_WKT;
+      fdata->format = Geometry_DetectFormat(str, len);
       break;
     }
     case FLD_VAR_T_CSTR:
-      // From WKT string
+      // From WKT or GeoJSON string
       fdata->isMulti = 0;
       fdata->str = field->strval;
       fdata->strlen = field->strlen;
-      fdata->format = GEOMETRY_FORMAT_WKT;
+      fdata->format = Geometry_DetectFormat(field->strval, field->strlen);
       break;
     case FLD_VAR_T_NUM:
     case FLD_VAR_T_NULL:
@@ -705,12 +799,15 @@ FIELD_BULK_INDEXER(vectorIndexer) {
       return -1;
     }
   }
-  char *curr_vec = (char *)fdata->vector;
-  for (size_t i = 0; i < fdata->numVec; i++) {
-    sp->stats.vectorIndexSize +=  VecSimIndex_AddVector(rt, curr_vec, aCtx->doc->docId);
-    curr_vec += fdata->vecLen;
-  }
+  sp->stats.vectorIndexSize += VecSim_AddVectors(rt, fs, fdata->vector, fdata->numVec,
+                                                 fdata->vecLen, aCtx->doc->docId);
   sp->stats.numRecords += fdata->numVec;
+  VecSimMigration *migration = fs->vectorOpts.migration;
+  if (migration) {
+    // Keep the index being built by FT.ALTER up to date
+    VecSim_AddVectors(migration->index, &migration->target, fdata->vector, fdata->numVec,
+                      fdata->vecLen, aCtx->doc->docId);
+  }
   return 0;
 }
 
@@ -825,6 +922,77 @@ FIELD_BULK_INDEXER(tagIndexer) {
   return 0;
 }
 
+FIELD_PREPROCESSOR(booleanPreprocessor) {
+  const char *str;
+  size_t len;
+  int val = 0;
+  if (field->unionType == FLD_VAR_T_NULL) {
+    fdata->isNull = 1;
+    return 0;
+  }
+
+  fdata->isMulti = 0;
+  fdata->booleans = 0;
+  switch (field->unionType) {
+    case FLD_VAR_T_RMS:
+    case FLD_VAR_T_CSTR:
+      str = DocumentField_GetValueCStr(field, &len);
+      if (!FieldSpec_ParseBoolean(str, len, &val)) {
+        goto error;
+      }
+      fdata->booleans = BOOLEAN_VALUE_MASK(val);
+      break;
+    case FLD_VAR_T_NUM:
+      if (field->numval != 0 && field->numval != 1) {
+        goto error;
+      }
+      val = field->numval == 1;
+      fdata->booleans = BOOLEAN_VALUE_MASK(val);
+      break;
+    case FLD_VAR_T_ARRAY:
+      fdata->isMulti = 1;
+      for (size_t ii = 0; ii < field->arrayLen; ++ii) {
+        str = DocumentField_GetArrayValueCStr(field, &len, ii);
+        if (!FieldSpec_ParseBoolean(str, len, &val)) {
+          goto error;
+        }
+        fdata->booleans |= BOOLEAN_VALUE_MASK(val);
+      }
+      break;
+    default:
+      goto error;
+  }
+
+  if (FieldSpec_IsSortable(fs)) {
+    if (field->unionType != FLD_VAR_T_ARRAY) {
+      double numval = val;
+      RSSortingVector_Put(aCtx->sv, fs->sortIdx, &numval, RS_SORTABLE_NUM, 0);
+    } else if (field->multisv) {
+      RSSortingVector_Put(aCtx->sv, fs->sortIdx, field->multisv, RS_SORTABLE_RSVAL, 0);
+      field->multisv = NULL;
+    }
+  }
+  return 0;
+
+error:
+  QueryError_SetCode(status, QUERY_EBADATTR);
+  return -1;
+}
+
+FIELD_BULK_INDEXER(booleanIndexer) {
+  if (!fdata->booleans) {
+    return 0;
+  }
+  BooleanIndex *idx = OpenBooleanIndex(ctx->spec, fs, 1);
+  if (!idx) {
+    QueryError_SetError(status, QUERY_EGENERIC, "Could not open boolean index for indexing");
+    return -1;
+  }
+  ctx->spec->stats.invertedSize += BooleanIndex_Index(idx, aCtx->doc->docId, fdata->booleans);
+  ctx->spec->stats.numRecords += __builtin_popcount(fdata->booleans);
+  return 0;
+}
+
 static PreprocessorFunc preprocessorMap[] = {
     // nl break
     [IXFLDPOS_FULLTEXT] = fulltextPreprocessor,
@@ -833,6 +1001,8 @@ static PreprocessorFunc preprocessorMap[] = {
     [IXFLDPOS_TAG] = tagPreprocessor,
     [IXFLDPOS_VECTOR] = vectorPreprocessor,
     [IXFLDPOS_GEOMETRY] = geometryPreprocessor,
+    [IXFLDPOS_BOOLEAN] = booleanPreprocessor,
+    [IXFLDPOS_DATE] = datePreprocessor,
     };
 
 int IndexerBulkAdd(IndexBulkData *bulk, RSAddDocumentCtx *cur, RedisSearchCtx *sctx,
@@ -848,6 +1018,7 @@ int IndexerBulkAdd(IndexBulkData *bulk, RSAddDocumentCtx *cur, RedisSearchCtx *s
           break;
         case IXFLDPOS_NUMERIC:
         case IXFLDPOS_GEO:
+        case IXFLDPOS_DATE:
           rc = numericIndexer(bulk, cur, sctx, field, fs, fdata, status);
           break;
         case IXFLDPOS_VECTOR:
@@ -856,6 +1027,9 @@ int IndexerBulkAdd(IndexBulkData *bulk, RSAddDocumentCtx *cur, RedisSearchCtx *s
         case IXFLDPOS_GEOMETRY:
           rc = geometryIndexer(bulk, cur, sctx, field, fs, fdata, status);
           break;
+        case IXFLDPOS_BOOLEAN:
+          rc = booleanIndexer(bulk, cur, sctx, field, fs, fdata, status);
+          break;
         case IXFLDPOS_FULLTEXT:
           break;
         default:
@@ -1047,6 +1221,27 @@ static void AddDocumentCtx_UpdateNoIndex(RSAddDocumentCtx *aCtx, RedisSearchCtx
           RSSortingVector_Put(md->sortVector, idx, &numval, RS_SORTABLE_NUM, 0);
           break;
         }
+        case INDEXFLD_T_BOOLEAN: {
+          size_t len;
+          const char *str = RedisModule_StringPtrLen(f->text, &len);
+          int val;
+          if (!FieldSpec_ParseBoolean(str, len, &val)) {
+            BAIL("Could not parse boolean index value");
+          }
+          double numval = val;
+          RSSortingVector_Put(md->sortVector, idx, &numval, RS_SORTABLE_NUM, 0);
+          break;
+        }
+        case INDEXFLD_T_DATE: {
+          size_t len;
+          const char *str = RedisModule_StringPtrLen(f->text, &len);
+          double numval;
+          if (!DateField_ParseISO8601(str, len, &numval)) {
+            BAIL("Could not parse date index value");
+          }
+          RSSortingVector_Put(md->sortVector, idx, &numval, RS_SORTABLE_NUM, 0);
+          break;
+        }
         default:
           BAIL("Unsupported sortable type");
           break;
@@ -257,6 +257,10 @@ struct FieldIndexerData;
 
 #define ACTX_F_NOFREEDOC 0x80
 
+// The document has been added to the missing/empty docs indexes of its INDEXMISSING and
+// INDEXEMPTY fields
+#define ACTX_F_FIELDDOCSINDEXED 0x100
+
 struct DocumentIndexer;
 
 /** Context used when indexing documents */
@@ -291,6 +295,8 @@ typedef struct RSAddDocumentCtx {
   // and cached, so that we can look it up without holding the GIL
   FieldSpec *fspecs;
   RSTokenizer *tokenizer;
+  // Tokenizer of the fields with an analyzer, kept on recycled contexts
+  RSTokenizer *analyzerTokenizer;
 
   // Old document data. Contains sortables
   RSDocumentMetadata *oldMd;
@@ -304,7 +310,7 @@ typedef struct RSAddDocumentCtx {
   uint32_t totalTokens;  // Number of tokens, used for offset vector
   uint32_t specFlags;    // Cached index flags
   uint8_t options;       // Indexing options - i.e. DOCUMENT_ADD_xxx
-  uint8_t stateFlags;    // Indexing state, ACTX_F_xxx
+  uint16_t stateFlags;   // Indexing state, ACTX_F_xxx
   DocumentAddCompleted donecb;
   void *donecbData;
 } RSAddDocumentCtx;
@@ -358,7 +358,8 @@ void Document_Clear(Document *d) {
           break;
         case FLD_VAR_T_ARRAY:
         // TODO: GEOMETRY Handle multi-value geometry fields
-          if (field->indexAs & (INDEXFLD_T_FULLTEXT | INDEXFLD_T_TAG | INDEXFLD_T_GEO)) {
+          if (field->indexAs & (INDEXFLD_T_FULLTEXT | INDEXFLD_T_TAG | INDEXFLD_T_GEO |
+                                INDEXFLD_T_BOOLEAN | INDEXFLD_T_DATE)) {
             for (int i = 0; i < field->arrayLen; ++i) {
               rm_free(field->multiVal[i]);
             }
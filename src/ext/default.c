@@ -322,30 +322,48 @@ static double HammingDistanceScorer(const ScoringFunctionArgs *ctx, const RSInde
 }
 
 typedef struct {
-  int isCn;
+  int isCJK;
   union {
     struct {
       RSTokenizer *tokenizer;
       Vector *tokList;
-    } cn;
+    } cjk;
     struct sb_stemmer *latin;
   } data;
 } defaultExpanderCtx;
 
-static void expandCn(RSQueryExpanderCtx *ctx, RSToken *token) {
+static int isCJKLanguage(RSLanguage language) {
+  return language == RS_LANG_CHINESE || language == RS_LANG_JAPANESE ||
+         language == RS_LANG_KOREAN;
+}
+
+static RSTokenizer *newCJKTokenizer(RSLanguage language) {
+  switch (language) {
+    case RS_LANG_JAPANESE:
+      return NewJapaneseTokenizer(NULL, NULL, 0);
+    case RS_LANG_KOREAN:
+      return NewKoreanTokenizer(NULL, NULL, 0);
+    default:
+      return NewChineseTokenizer(NULL, NULL, 0);
+  }
+}
+
+/* Segment the token of a chinese, japanese or korean query, and expand it with the phrase of its
+ * words */
+static void expandCJK(RSQueryExpanderCtx *ctx, RSToken *token) {
   defaultExpanderCtx *dd = ctx->privdata;
   RSTokenizer *tokenizer;
   if (!dd) {
     dd = ctx->privdata = rm_calloc(1, sizeof(*dd));
-    dd->isCn = 1;
+    dd->isCJK = 1;
   }
-  if (!dd->data.cn.tokenizer) {
-    tokenizer = dd->data.cn.tokenizer = NewChineseTokenizer(NULL, NULL, 0);
-    dd->data.cn.tokList = NewVector(char *, 4);
+  if (!dd->data.cjk.tokenizer) {
+    tokenizer = dd->data.cjk.tokenizer = newCJKTokenizer(ctx->language);
+    dd->data.cjk.tokList = NewVector(char *, 4);
   }
 
-  tokenizer = dd->data.cn.tokenizer;
-  Vector *tokVec = dd->data.cn.tokList;
+  tokenizer = dd->data.cjk.tokenizer;
+  Vector *tokVec = dd->data.cjk.tokList;
 
   tokVec->top = 0;
   tokenizer->Start(tokenizer, token->str, token->len, 0);
@@ -356,7 +374,10 @@ static void expandCn(RSQueryExpanderCtx *ctx, RSToken *token) {
     Vector_Push(tokVec, s);
   }
 
-  ctx->ExpandTokenWithPhrase(ctx, (const char **)tokVec->data, tokVec->top, token->flags, 0, 0);
+  // Nothing to expand if the token is made of words which are not indexed
+  if (tokVec->top) {
+    ctx->ExpandTokenWithPhrase(ctx, (const char **)tokVec->data, tokVec->top, token->flags, 0, 0);
+  }
 }
 
 /******************************************************************************************
@@ -364,6 +385,18 @@ static void expandCn(RSQueryExpanderCtx *ctx, RSToken *token) {
  * Stemmer based query expander
  *
  ******************************************************************************************/
+static void expandStem(RSQueryExpanderCtx *ctx, RSToken *token, const char *stem, size_t sl) {
+  // Make a copy of the stemmed buffer with the + prefix given to stems
+  char *dup = rm_malloc(sl + 2);
+  dup[0] = STEM_PREFIX;
+  memcpy(dup + 1, stem, sl);
+  dup[sl + 1] = '\0';
+  ctx->ExpandToken(ctx, dup, sl + 1, 0x0);  // TODO: Set proper flags here
+  if (sl != token->len || strncmp(stem, token->str, token->len)) {
+    ctx->ExpandToken(ctx, rm_strndup(stem, sl), sl, 0x0);
+  }
+}
+
 int StemmerExpander(RSQueryExpanderCtx *ctx, RSToken *token) {
 
   // we store the stemmer as private data on the first call to expand
@@ -371,18 +404,18 @@ int StemmerExpander(RSQueryExpanderCtx *ctx, RSToken *token) {
   struct sb_stemmer *sb;
 
   if (!ctx->privdata) {
-    if (ctx->language == RS_LANG_CHINESE) {
-      expandCn(ctx, token);
+    if (isCJKLanguage(ctx->language)) {
+      expandCJK(ctx, token);
       return REDISMODULE_OK;
     } else {
       dd = ctx->privdata = rm_calloc(1, sizeof(*dd));
-      dd->isCn = 0;
+      dd->isCJK = 0;
       sb = dd->data.latin = sb_stemmer_new(RSLanguage_ToString(ctx->language), NULL);
     }
   }
 
-  if (dd->isCn) {
-    expandCn(ctx, token);
+  if (dd->isCJK) {
+    expandCJK(ctx, token);
     return REDISMODULE_OK;
   }
 
@@ -394,20 +427,25 @@ int StemmerExpander(RSQueryExpanderCtx *ctx, RSToken *token) {
     return REDISMODULE_OK;
   }
 
+  // The exceptions of the index replace the stems of the stemmer
+  const char *exception;
+  size_t exceptionLen;
+  if (StemExceptions_Find(ctx->handle->spec->stemExceptions, token->str, token->len, &exception,
+                          &exceptionLen)) {
+    if (exception) {
+      // Skip the + prefix given to stems
+      expandStem(ctx, token, exception + 1, exceptionLen - 1);
+    } else {
+      expandStem(ctx, token, token->str, token->len);
+    }
+    return REDISMODULE_OK;
+  }
+
   const sb_symbol *b = (const sb_symbol *)token->str;
   const sb_symbol *stemmed = sb_stemmer_stem(sb, b, token->len);
 
   if (stemmed) {
-    int sl = sb_stemmer_length(sb);
-
-    // Make a copy of the stemmed buffer with the + prefix given to stems
-    char *dup = rm_malloc(sl + 2);
-    dup[0] = STEM_PREFIX;
-    memcpy(dup + 1, stemmed, sl + 1);
-    ctx->ExpandToken(ctx, dup, sl + 1, 0x0);  // TODO: Set proper flags here
-    if (sl != token->len || strncmp((const char *)stemmed, token->str, token->len)) {
-      ctx->ExpandToken(ctx, rm_strndup((const char *)stemmed, sl), sl, 0x0);
-    }
+    expandStem(ctx, token, (const char *)stemmed, sb_stemmer_length(sb));
   }
   return REDISMODULE_OK;
 }
@@ -417,9 +455,9 @@ void StemmerExpanderFree(void *p) {
     return;
   }
   defaultExpanderCtx *dd = p;
-  if (dd->isCn) {
-    dd->data.cn.tokenizer->Free(dd->data.cn.tokenizer);
-    Vector_Free(dd->data.cn.tokList);
+  if (dd->isCJK) {
+    dd->data.cjk.tokenizer->Free(dd->data.cjk.tokenizer);
+    Vector_Free(dd->data.cjk.tokList);
   } else if (dd->data.latin) {
     sb_stemmer_delete(dd->data.latin);
   }
@@ -13,6 +13,10 @@
 #include "index_result.h"
 #include "triemap/triemap.h"
 #include "query.h"
+#include "aggregate/functions/function.h"
+#include "aggregate/expr/expression.h"
+#include "aggregate/reducer.h"
+#include "util/arr.h"
 #include <err.h>
 
 /* The registry for query expanders. Initialized by Extensions_Init() */
@@ -21,6 +25,14 @@ static TrieMap *queryExpanders_g = NULL;
 /* The registry for scorers. Initialized by Extensions_Init() */
 static TrieMap *scorers_g = NULL;
 
+/* The expression functions registered by extensions. Lookup is done by the function registry */
+static ExtExprFunctionCtx **exprFunctions_g = NULL;
+
+/* The reducers registered by extensions. Lookup is done by the reducer registry */
+static ExtReducerCtx **reducers_g = NULL;
+
+static Reducer *newExtReducer(const ReducerOptions *options);
+
 /* Init the extension system - currently just create the regsistries */
 void Extensions_Init() {
   if (!queryExpanders_g) {
@@ -46,6 +58,33 @@ void Extensions_Free() {
     TrieMap_Free(scorers_g, freeScorerCb);
     scorers_g = NULL;
   }
+  if (exprFunctions_g) {
+    RSFunctionRegistry_RemoveExtFunctions();
+    for (size_t i = 0; i < array_len(exprFunctions_g); ++i) {
+      ExtExprFunctionCtx *ctx = exprFunctions_g[i];
+      if (ctx->ff) {
+        ctx->ff(ctx->privdata);
+      }
+      rm_free(ctx->name);
+      rm_free(ctx);
+    }
+    array_free(exprFunctions_g);
+    exprFunctions_g = NULL;
+  }
+  if (reducers_g) {
+    RDCR_RemoveFactory(newExtReducer);
+    for (size_t i = 0; i < array_len(reducers_g); ++i) {
+      ExtReducerCtx *ctx = reducers_g[i];
+      if (ctx->ff) {
+        ctx->ff(ctx->privdata);
+      }
+      rm_free(ctx->name);
+      rm_free(ctx->mergeReducer);
+      rm_free(ctx);
+    }
+    array_free(reducers_g);
+    reducers_g = NULL;
+  }
 }
 
 /* Register a scoring function by its alias. privdata is an optional pointer to a user defined
@@ -90,12 +129,82 @@ int Ext_RegisterQueryExpander(const char *alias, RSQueryTokenExpander exp, RSFre
   return REDISEARCH_OK;
 }
 
+/* Register an expression function for APPLY and FILTER */
+int Ext_RegisterExpressionFunction(const char *name, RSExprFunction func, int minArgs, int maxArgs,
+                                   RSExprValueType retType, RSFreeFunction ff, void *privdata) {
+  if (func == NULL || name == NULL || minArgs < 0 || (maxArgs >= 0 && maxArgs < minArgs) ||
+      retType < RSExprValue_Null || retType > RSExprValue_String) {
+    return REDISEARCH_ERR;
+  }
+  ExtExprFunctionCtx *ctx = rm_new(ExtExprFunctionCtx);
+  ctx->name = rm_strdup(name);
+  ctx->func = func;
+  ctx->ff = ff;
+  ctx->privdata = privdata;
+  ctx->retType = retType;
+
+  /* Make sure the function doesn't override a built-in or previously registered one */
+  if (!RSFunctionRegistry_RegisterExtFunction(ctx, minArgs, maxArgs < 0 ? -1 : maxArgs)) {
+    rm_free(ctx->name);
+    rm_free(ctx);
+    return REDISEARCH_ERR;
+  }
+  if (!exprFunctions_g) {
+    exprFunctions_g = array_new(ExtExprFunctionCtx *, 4);
+  }
+  exprFunctions_g = array_append(exprFunctions_g, ctx);
+  return REDISEARCH_OK;
+}
+
+/* Register a GROUPBY reducer */
+int Ext_RegisterReducer(const char *name, const RSReducerCallbacks *callbacks, int nargs,
+                        RSExprValueType retType, const char *mergeReducer, RSFreeFunction ff,
+                        void *privdata) {
+  if (name == NULL || callbacks == NULL || callbacks->NewInstance == NULL ||
+      callbacks->Add == NULL || callbacks->Finalize == NULL || nargs < 0 ||
+      retType < RSExprValue_Null || retType > RSExprValue_String) {
+    return REDISEARCH_ERR;
+  }
+
+  /* Make sure the reducer doesn't override a built-in or previously registered one */
+  if (RDCR_GetFactory(name)) {
+    return REDISEARCH_ERR;
+  }
+  /* The merge reducer is called on the coordinator with a single property, so it must be a known
+   * reducer accepting one argument, or the reducer itself */
+  if (mergeReducer && !strcasecmp(mergeReducer, name)) {
+    if (nargs != 1) {
+      return REDISEARCH_ERR;
+    }
+  } else if (mergeReducer && !Extensions_IsValidMergeReducer(mergeReducer)) {
+    return REDISEARCH_ERR;
+  }
+
+  ExtReducerCtx *ctx = rm_new(ExtReducerCtx);
+  ctx->name = rm_strdup(name);
+  ctx->callbacks = *callbacks;
+  ctx->nargs = nargs;
+  ctx->retType = retType;
+  ctx->mergeReducer = mergeReducer ? rm_strdup(mergeReducer) : NULL;
+  ctx->ff = ff;
+  ctx->privdata = privdata;
+
+  RDCR_RegisterFactory(ctx->name, newExtReducer);
+  if (!reducers_g) {
+    reducers_g = array_new(ExtReducerCtx *, 4);
+  }
+  reducers_g = array_append(reducers_g, ctx);
+  return REDISEARCH_OK;
+}
+
 /* Load an extension by calling its init function. return REDISEARCH_ERR or REDISEARCH_OK */
 int Extension_Load(const char *name, RSExtensionInitFunc func) {
   // bind the callbacks in the context
   RSExtensionCtx ctx = {
       .RegisterScoringFunction = Ext_RegisterScoringFunction,
       .RegisterQueryExpander = Ext_RegisterQueryExpander,
+      .RegisterExpressionFunction = Ext_RegisterExpressionFunction,
+      .RegisterReducer = Ext_RegisterReducer,
   };
 
   return func(&ctx);
@@ -230,3 +339,199 @@ ExtQueryExpanderCtx *Extensions_GetQueryExpander(RSQueryExpanderCtx *ctx, const
   }
   return NULL;
 }
+
+/* Get a reducer by name (case insensitive, like the reducer registry) */
+const ExtReducerCtx *Extensions_GetReducer(const char *name) {
+  for (size_t i = 0; reducers_g && i < array_len(reducers_g); ++i) {
+    if (!strcasecmp(reducers_g[i]->name, name)) {
+      return reducers_g[i];
+    }
+  }
+  return NULL;
+}
+
+int Extensions_IsValidMergeReducer(const char *name) {
+  if (!RDCR_GetFactory(name)) {
+    return 0;
+  }
+  const ExtReducerCtx *ext = Extensions_GetReducer(name);
+  return !ext || ext->nargs == 1;
+}
+
+/* The context passed to extension expression functions, keeping track of the string result */
+typedef struct {
+  RSExprFunctionCtx base;
+  char *strResult;
+} ExprFunctionCallCtx;
+
+static void setStringResult(RSExprFunctionCtx *ctx, RSExprValue *result, const char *str,
+                            size_t len) {
+  ExprFunctionCallCtx *callCtx = (ExprFunctionCallCtx *)ctx;
+  rm_free(callCtx->strResult);
+  callCtx->strResult = rm_strndup(str, len);
+  result->type = RSExprValue_String;
+  result->str = callCtx->strResult;
+  result->len = len;
+}
+
+/* Convert a value to its extension representation. Returns 0 if the type is not supported */
+static int toExprValue(RSValue *v, RSExprValue *out) {
+  v = RSValue_Dereference(v);
+  *out = (RSExprValue){.type = RSExprValue_Null};
+  if (v->t == RSValue_Number) {
+    out->type = RSExprValue_Number;
+    out->num = v->numval;
+  } else if (RSValue_IsString(v)) {
+    out->type = RSExprValue_String;
+    out->str = RSValue_StringPtrLen(v, &out->len);
+  } else if (!RSValue_IsNull(v)) {
+    return 0;
+  }
+  return 1;
+}
+
+/* Set `result` from a value returned by an extension. A string set by SetStringResult is moved to
+ * the result, any other string is copied */
+static void fromExprValue(RSValue *result, const RSExprValue *v, ExprFunctionCallCtx *callCtx) {
+  switch (v->type) {
+    case RSExprValue_Number:
+      RSValue_SetNumber(result, v->num);
+      break;
+    case RSExprValue_String:
+      if (v->str == callCtx->strResult) {
+        RSValue_SetString(result, callCtx->strResult, v->len);
+        callCtx->strResult = NULL;
+      } else {
+        RSValue_SetString(result, rm_strndup(v->str, v->len), v->len);
+      }
+      break;
+    default:
+      RSValue_MakeReference(result, RS_NullVal());
+      break;
+  }
+}
+
+int Extensions_CallExprFunction(const ExtExprFunctionCtx *ctx, RSValue *result, RSValue **args,
+                                size_t nargs, QueryError *err) {
+  RSExprValue extArgs[nargs ? nargs : 1];
+  for (size_t i = 0; i < nargs; ++i) {
+    if (!toExprValue(args[i], &extArgs[i])) {
+      QueryError_SetErrorFmt(err, QUERY_EPARSEARGS,
+                             "Invalid type (%s) for argument %zu in function '%s'",
+                             RSValue_TypeName(RSValue_Dereference(args[i])->t), i, ctx->name);
+      return EXPR_EVAL_ERR;
+    }
+  }
+
+  ExprFunctionCallCtx callCtx = {
+      .base = {.privdata = ctx->privdata, .SetStringResult = setStringResult},
+      .strResult = NULL,
+  };
+  RSExprValue extResult = {.type = RSExprValue_Null};
+  int rc = ctx->func(&callCtx.base, &extResult, extArgs, nargs);
+
+  if (rc != REDISEARCH_OK) {
+    if (!QueryError_HasError(err)) {
+      QueryError_SetErrorFmt(err, QUERY_EGENERIC, "Function '%s' failed", ctx->name);
+    }
+    rc = EXPR_EVAL_ERR;
+  } else if (extResult.type != RSExprValue_Null && extResult.type != ctx->retType) {
+    QueryError_SetErrorFmt(err, QUERY_EGENERIC, "Function '%s' returned a value of unexpected type",
+                           ctx->name);
+    rc = EXPR_EVAL_ERR;
+  } else {
+    fromExprValue(result, &extResult, &callCtx);
+    rc = EXPR_EVAL_OK;
+  }
+
+  rm_free(callCtx.strResult);
+  return rc;
+}
+
+/* A reducer registered by an extension, reading its arguments from `srckeys` */
+typedef struct {
+  Reducer base;
+  const ExtReducerCtx *ext;
+  const RLookupKey **srckeys;
+} ExtReducer;
+
+static void *extReducerNewInstance(Reducer *r) {
+  const ExtReducerCtx *ext = ((ExtReducer *)r)->ext;
+  return ext->callbacks.NewInstance(ext->privdata);
+}
+
+static int extReducerAdd(Reducer *r, void *instance, const RLookupRow *srcrow) {
+  const ExtReducer *er = (ExtReducer *)r;
+  int nargs = er->ext->nargs;
+  RSExprValue args[nargs ? nargs : 1];
+  for (int i = 0; i < nargs; ++i) {
+    RSValue *v = RLookup_GetItem(er->srckeys[i], srcrow);
+    // Missing values and values of unsupported types are passed as Null
+    if (!v || !toExprValue(v, &args[i])) {
+      args[i] = (RSExprValue){.type = RSExprValue_Null};
+    }
+  }
+  er->ext->callbacks.Add(er->ext->privdata, instance, args, nargs);
+  return 1;
+}
+
+static RSValue *extReducerFinalize(Reducer *r, void *instance) {
+  const ExtReducerCtx *ext = ((ExtReducer *)r)->ext;
+  ExprFunctionCallCtx callCtx = {
+      .base = {.privdata = ext->privdata, .SetStringResult = setStringResult},
+      .strResult = NULL,
+  };
+  RSExprValue extResult = {.type = RSExprValue_Null};
+  ext->callbacks.Finalize(&callCtx.base, instance, &extResult);
+
+  RSValue *result = RS_NewValue(RSValue_Undef);
+  if (extResult.type != ext->retType) {
+    // A result of an unexpected type is treated as a missing one
+    extResult.type = RSExprValue_Null;
+  }
+  fromExprValue(result, &extResult, &callCtx);
+  rm_free(callCtx.strResult);
+  return result;
+}
+
+static void extReducerFreeInstance(Reducer *r, void *instance) {
+  const ExtReducerCtx *ext = ((ExtReducer *)r)->ext;
+  if (ext->callbacks.FreeInstance) {
+    ext->callbacks.FreeInstance(ext->privdata, instance);
+  }
+}
+
+static void extReducerFree(Reducer *r) {
+  rm_free(((ExtReducer *)r)->srckeys);
+  rm_free(r);
+}
+
+static Reducer *newExtReducer(const ReducerOptions *options) {
+  const ExtReducerCtx *ext = Extensions_GetReducer(options->name);
+  if (!ext) {
+    QueryError_SetErrorFmt(options->status, QUERY_ENOREDUCER, "No such reducer: %s",
+                           options->name);
+    return NULL;
+  }
+
+  ExtReducer *r = rm_calloc(1, sizeof(*r));
+  r->ext = ext;
+  r->srckeys = rm_calloc(ext->nargs ? ext->nargs : 1, sizeof(*r->srckeys));
+  for (int i = 0; i < ext->nargs; ++i) {
+    if (!ReducerOpts_GetKey(options, &r->srckeys[i])) {
+      extReducerFree(&r->base);
+      return NULL;
+    }
+  }
+  if (!ReducerOpts_EnsureArgsConsumed(options)) {
+    extReducerFree(&r->base);
+    return NULL;
+  }
+
+  r->base.NewInstance = extReducerNewInstance;
+  r->base.Add = extReducerAdd;
+  r->base.Finalize = extReducerFinalize;
+  r->base.FreeInstance = extReducerFreeInstance;
+  r->base.Free = extReducerFree;
+  return &r->base;
+}
@@ -8,6 +8,8 @@
 #define __REDISEARCH_EXTN_H__
 
 #include "redisearch.h"
+#include "value.h"
+#include "query_error.h"
 
 #ifdef __cplusplus
 extern "C" {
@@ -31,12 +33,46 @@ typedef struct {
   void *privdata;
 } ExtQueryExpanderCtx;
 
+/* Context for saving an expression function registered by an extension, along with its
+ * return type and privdata */
+typedef struct {
+  char *name;
+  RSExprFunction func;
+  RSFreeFunction ff;
+  void *privdata;
+  RSExprValueType retType;
+} ExtExprFunctionCtx;
+
+/* Context for saving a GROUPBY reducer registered by an extension. mergeReducer is the reducer
+ * combining the shard results in a cluster, or NULL if the reducer can't be distributed */
+typedef struct {
+  char *name;
+  RSReducerCallbacks callbacks;
+  int nargs;
+  RSExprValueType retType;
+  char *mergeReducer;
+  RSFreeFunction ff;
+  void *privdata;
+} ExtReducerCtx;
+
 /* Get a scoring function by name. Returns NULL if no such scoring function exists */
 ExtScoringFunctionCtx *Extensions_GetScoringFunction(ScoringFunctionArgs *fnargs, const char *name);
 
 /* Get a query expander function by name. Returns NULL if no such function exists */
 ExtQueryExpanderCtx *Extensions_GetQueryExpander(RSQueryExpanderCtx *ctx, const char *name);
 
+/* Call an expression function registered by an extension, converting its arguments and result
+ * from and to RSValues. Returns EXPR_EVAL_OK or EXPR_EVAL_ERR */
+int Extensions_CallExprFunction(const ExtExprFunctionCtx *ctx, RSValue *result, RSValue **args,
+                                size_t nargs, QueryError *err);
+
+/* Get a reducer registered by an extension by name. Returns NULL if no such reducer exists */
+const ExtReducerCtx *Extensions_GetReducer(const char *name);
+
+/* Returns 1 if the reducer `name` exists and accepts a single property, so it can merge the shard
+ * results of a reducer registered by an extension */
+int Extensions_IsValidMergeReducer(const char *name);
+
 /* Load an extension explicitly with its name and an init function */
 int Extension_Load(const char *name, RSExtensionInitFunc func);
 
@@ -5,13 +5,18 @@
  */
 
 #include "field_spec.h"
+#include "analyzer.h"
 #include "indexer.h"
 #include "rmalloc.h"
 #include "rmutil/rm_assert.h"
 
+#include <strings.h>
+
 RSValueType fieldTypeToValueType(FieldType ft) {
   switch (ft) {
     case INDEXFLD_T_NUMERIC:
+    case INDEXFLD_T_BOOLEAN:
+    case INDEXFLD_T_DATE:
       return RSValue_Number;
 
     case INDEXFLD_T_FULLTEXT:
@@ -36,6 +41,8 @@ void FieldSpec_Cleanup(FieldSpec* fs) {
     rm_free(fs->name);
     fs->name = NULL;
   }
+  Analyzer_Free(fs->analyzer);
+  fs->analyzer = NULL;
 }
 
 void FieldSpec_SetSortable(FieldSpec* fs) {
@@ -51,9 +58,22 @@ const char *FieldSpec_GetTypeNames(int idx) {
   case IXFLDPOS_GEO:      return SPEC_GEO_STR;
   case IXFLDPOS_VECTOR:   return SPEC_VECTOR_STR;
   case IXFLDPOS_GEOMETRY: return SPEC_GEOMETRY_STR;
+  case IXFLDPOS_BOOLEAN:  return SPEC_BOOLEAN_STR;
+  case IXFLDPOS_DATE:     return SPEC_DATE_STR;
 
   default:
     RS_LOG_ASSERT(0, "oops");
     break;
   }
 }
+
+int FieldSpec_ParseBoolean(const char *s, size_t len, int *val) {
+  if ((len == 4 && !strncasecmp(s, "true", 4)) || (len == 1 && *s == '1')) {
+    *val = 1;
+  } else if ((len == 5 && !strncasecmp(s, "false", 5)) || (len == 1 && *s == '0')) {
+    *val = 0;
+  } else {
+    return 0;
+  }
+  return 1;
+}
@@ -29,9 +29,11 @@ typedef enum {
   INDEXFLD_T_TAG = 0x08,
   INDEXFLD_T_VECTOR = 0x10,
   INDEXFLD_T_GEOMETRY = 0x20,
+  INDEXFLD_T_BOOLEAN = 0x40,
+  INDEXFLD_T_DATE = 0x80,
 } FieldType;
 
-#define INDEXFLD_NUM_TYPES 6
+#define INDEXFLD_NUM_TYPES 8
 
 // clang-format off
 // otherwise, it looks h o r r i b l e
@@ -41,7 +43,9 @@ typedef enum {
   (T == INDEXFLD_T_GEO        ? 2 : \
   (T == INDEXFLD_T_TAG        ? 3 : \
   (T == INDEXFLD_T_VECTOR     ? 4 : \
-  (T == INDEXFLD_T_GEOMETRY   ? 5 : -1))))))
+  (T == INDEXFLD_T_GEOMETRY   ? 5 : \
+  (T == INDEXFLD_T_BOOLEAN    ? 6 : \
+  (T == INDEXFLD_T_DATE       ? 7 : -1))))))))
 
 #define INDEXTYPE_FROM_POS(P) (1<<(P))
 // clang-format on
@@ -52,6 +56,8 @@ typedef enum {
 #define IXFLDPOS_TAG INDEXTYPE_TO_POS(INDEXFLD_T_TAG)
 #define IXFLDPOS_VECTOR INDEXTYPE_TO_POS(INDEXFLD_T_VECTOR)
 #define IXFLDPOS_GEOMETRY INDEXTYPE_TO_POS(INDEXFLD_T_GEOMETRY)
+#define IXFLDPOS_BOOLEAN INDEXTYPE_TO_POS(INDEXFLD_T_BOOLEAN)
+#define IXFLDPOS_DATE INDEXTYPE_TO_POS(INDEXFLD_T_DATE)
 
 RS_ENUM_BITWISE_HELPER(FieldType)
 
@@ -64,6 +70,10 @@ typedef enum {
   FieldSpec_UNF = 0x20,
   FieldSpec_WithSuffixTrie = 0x40,
   FieldSpec_UndefinedOrder = 0x80,
+  FieldSpec_IndexMissing = 0x100,
+  FieldSpec_IndexEmpty = 0x200,
+  FieldSpec_AsciiFold = 0x400,
+  FieldSpec_NFKC = 0x800,
 } FieldSpecOptions;
 
 RS_ENUM_BITWISE_HELPER(FieldSpecOptions)
@@ -77,6 +87,18 @@ typedef enum {
 
 RS_ENUM_BITWISE_HELPER(TagFieldFlags)
 
+// Compression of the vectors stored in a vector index
+typedef enum {
+  VecSimCompression_None = 0,
+  VecSimCompression_SQ8,  // Scalar quantization of every element to 8 bits
+} VecSimCompression;
+
+typedef struct {
+  VecSimCompression type;
+  double range;          // SQ8 - elements are clamped to [-range, range] before quantization
+  size_t rerankFactor;   // k * rerankFactor candidates are reranked by their full precision distance
+} VecSimCompressionParams;
+
 /* The fieldSpec represents a single field in the document's field spec.
 Each field has a unique id that's a power of two, so we can filter fields
 by a bit mask.
@@ -85,7 +107,7 @@ typedef struct FieldSpec {
   char *name;
   char *path;
   FieldType types : 8;
-  FieldSpecOptions options : 8;
+  FieldSpecOptions options : 16;
 
   /** If this field is sortable, the sortable index */
   int16_t sortIdx;
@@ -104,6 +126,12 @@ typedef struct FieldSpec {
       VecSimParams vecSimParams;
       // expected size of vector blob.
       size_t expBlobSize;
+      // Compression of the vectors stored in the index. The blobs of the documents and
+      // queries are still given in the full precision type of `vecSimParams`.
+      VecSimCompressionParams compression;
+      // A new index of the field with parameters altered by FT.ALTER, which is built in the
+      // background and replaces the current index once all the documents were added to it.
+      struct VecSimMigration *migration;
     } vectorOpts;
     struct {
       // Geometry index parameters
@@ -115,17 +143,23 @@ typedef struct FieldSpec {
   double ftWeight;
   // ID used to identify the field within the field mask
   t_fieldId ftId;
+  // Tokenization of a TEXT field, NULL for the default one
+  struct RSAnalyzer *analyzer;
 
   // TODO: More options here..
 } FieldSpec;
 
-#define FIELD_IS(f, t) (((f)->types) & t)
+#define FIELD_IS(f, t) (((f)->types) & (t))
 #define FIELD_CHKIDX(fmask, ix) (fmask & ix)
 
 #define TAG_FIELD_DEFAULT_FLAGS (TagFieldFlags)(TagField_TrimSpace | TagField_RemoveAccents);
 #define TAG_FIELD_DEFAULT_HASH_SEP ','
 #define TAG_FIELD_DEFAULT_JSON_SEP '\0' // by default, JSON fields have no separetor
 
+// Normalized values stored in the index of boolean fields
+#define BOOLEAN_FIELD_TRUE_STR "true"
+#define BOOLEAN_FIELD_FALSE_STR "false"
+
 #define FieldSpec_IsSortable(fs) ((fs)->options & FieldSpec_Sortable)
 #define FieldSpec_IsNoStem(fs) ((fs)->options & FieldSpec_NoStemming)
 #define FieldSpec_IsPhonetics(fs) ((fs)->options & FieldSpec_Phonetics)
@@ -133,6 +167,10 @@ typedef struct FieldSpec {
 #define FieldSpec_HasSuffixTrie(fs) ((fs)->options & FieldSpec_WithSuffixTrie)
 #define FieldSpec_IsUndefinedOrder(fs) ((fs)->options & FieldSpec_UndefinedOrder)
 #define FieldSpec_IsUnf(fs) ((fs)->options & FieldSpec_UNF)
+#define FieldSpec_IndexesMissing(fs) ((fs)->options & FieldSpec_IndexMissing)
+#define FieldSpec_IndexesEmpty(fs) ((fs)->options & FieldSpec_IndexEmpty)
+#define FieldSpec_IsAsciiFold(fs) ((fs)->options & FieldSpec_AsciiFold)
+#define FieldSpec_IsNFKC(fs) ((fs)->options & FieldSpec_NFKC)
 
 void FieldSpec_SetSortable(FieldSpec* fs);
 void FieldSpec_Cleanup(FieldSpec* fs);
@@ -143,4 +181,10 @@ const char *FieldSpec_GetTypeNames(int idx);
 
 RSValueType fieldTypeToValueType(FieldType ft);
 
+/**
+ * Parse a boolean field value. Accepts `true`/`false` (case insensitive) and `1`/`0`.
+ * Returns 1 and sets `val` on success, 0 if the string is not a valid boolean value.
+ */
+int FieldSpec_ParseBoolean(const char *s, size_t len, int *val);
+
 #endif /* SRC_FIELD_SPEC_H_ */
@@ -28,7 +28,11 @@ void FieldsGlobalStats_UpdateStats(FieldSpec *fs, int toAdd) {
     }
   } else if (fs->types & INDEXFLD_T_GEOMETRY) {  // geometry field
     RSGlobalConfig.fieldsStats.numGeometryFields += toAdd;
-  } 
+  } else if (fs->types & INDEXFLD_T_BOOLEAN) {  // boolean field
+    RSGlobalConfig.fieldsStats.numBooleanFields += toAdd;
+  } else if (fs->types & INDEXFLD_T_DATE) {  // date field
+    RSGlobalConfig.fieldsStats.numDateFields += toAdd;
+  }
 
   if (fs->options & FieldSpec_Sortable) {
     if (fs->types & INDEXFLD_T_FULLTEXT) RSGlobalConfig.fieldsStats.numTextFieldsSortable += toAdd;
@@ -36,6 +40,8 @@ void FieldsGlobalStats_UpdateStats(FieldSpec *fs, int toAdd) {
     else if (fs->types & INDEXFLD_T_GEO) RSGlobalConfig.fieldsStats.numGeoFieldsSortable += toAdd;
     else if (fs->types & INDEXFLD_T_TAG) RSGlobalConfig.fieldsStats.numTagFieldsSortable += toAdd;
     else if (fs->types & INDEXFLD_T_GEOMETRY) RSGlobalConfig.fieldsStats.numGeometryFieldsSortable += toAdd;
+    else if (fs->types & INDEXFLD_T_BOOLEAN) RSGlobalConfig.fieldsStats.numBooleanFieldsSortable += toAdd;
+    else if (fs->types & INDEXFLD_T_DATE) RSGlobalConfig.fieldsStats.numDateFieldsSortable += toAdd;
   }
   if (fs->options & FieldSpec_NotIndexable) {
     if (fs->types & INDEXFLD_T_FULLTEXT) RSGlobalConfig.fieldsStats.numTextFieldsNoIndex += toAdd;
@@ -43,6 +49,8 @@ void FieldsGlobalStats_UpdateStats(FieldSpec *fs, int toAdd) {
     else if (fs->types & INDEXFLD_T_GEO) RSGlobalConfig.fieldsStats.numGeoFieldsNoIndex += toAdd;
     else if (fs->types & INDEXFLD_T_TAG) RSGlobalConfig.fieldsStats.numTagFieldsNoIndex += toAdd;
     else if (fs->types & INDEXFLD_T_GEOMETRY) RSGlobalConfig.fieldsStats.numGeometryFieldsNoIndex += toAdd;
+    else if (fs->types & INDEXFLD_T_BOOLEAN) RSGlobalConfig.fieldsStats.numBooleanFieldsNoIndex += toAdd;
+    else if (fs->types & INDEXFLD_T_DATE) RSGlobalConfig.fieldsStats.numDateFieldsNoIndex += toAdd;
   }
 }
 
@@ -110,4 +118,24 @@ void FieldsGlobalStats_AddToInfo(RedisModuleInfoCtx *ctx) {
       RedisModule_InfoAddFieldLongLong(ctx, "NoIndex", RSGlobalConfig.fieldsStats.numGeometryFieldsNoIndex);
     RedisModule_InfoEndDictField(ctx);
   }
+
+  if (RSGlobalConfig.fieldsStats.numBooleanFields > 0) {
+    RedisModule_InfoBeginDictField(ctx, "fields_boolean");
+    RedisModule_InfoAddFieldLongLong(ctx, "Boolean", RSGlobalConfig.fieldsStats.numBooleanFields);
+    if (RSGlobalConfig.fieldsStats.numBooleanFieldsSortable > 0)
+      RedisModule_InfoAddFieldLongLong(ctx, "Sortable", RSGlobalConfig.fieldsStats.numBooleanFieldsSortable);
+    if (RSGlobalConfig.fieldsStats.numBooleanFieldsNoIndex > 0)
+      RedisModule_InfoAddFieldLongLong(ctx, "NoIndex", RSGlobalConfig.fieldsStats.numBooleanFieldsNoIndex);
+    RedisModule_InfoEndDictField(ctx);
+  }
+
+  if (RSGlobalConfig.fieldsStats.numDateFields > 0) {
+    RedisModule_InfoBeginDictField(ctx, "fields_date");
+    RedisModule_InfoAddFieldLongLong(ctx, "Date", RSGlobalConfig.fieldsStats.numDateFields);
+    if (RSGlobalConfig.fieldsStats.numDateFieldsSortable > 0)
+      RedisModule_InfoAddFieldLongLong(ctx, "Sortable", RSGlobalConfig.fieldsStats.numDateFieldsSortable);
+    if (RSGlobalConfig.fieldsStats.numDateFieldsNoIndex > 0)
+      RedisModule_InfoAddFieldLongLong(ctx, "NoIndex", RSGlobalConfig.fieldsStats.numDateFieldsNoIndex);
+    RedisModule_InfoEndDictField(ctx);
+  }
 }
@@ -29,6 +29,12 @@ typedef struct {
   size_t numVectorFields;
   size_t numVectorFieldsFlat;
   size_t numVectorFieldsHSNW;
+  size_t numBooleanFields;
+  size_t numBooleanFieldsSortable;
+  size_t numBooleanFieldsNoIndex;
+  size_t numDateFields;
+  size_t numDateFieldsSortable;
+  size_t numDateFieldsNoIndex;
 } FieldsGlobalStats;
 
 /**
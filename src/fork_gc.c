@@ -405,7 +405,7 @@ static void sendKht(ForkGC *gc, const khash_t(cardvals) * kh) {
 
 static void FGC_childCollectNumeric(ForkGC *gc, RedisSearchCtx *sctx) {
   RedisModuleKey *idxKey = NULL;
-  arrayof(FieldSpec*) numericFields = getFieldsByType(sctx->spec, INDEXFLD_T_NUMERIC | INDEXFLD_T_GEO);
+  arrayof(FieldSpec*) numericFields = getFieldsByType(sctx->spec, INDEXFLD_T_NUMERIC | INDEXFLD_T_GEO | INDEXFLD_T_DATE);
 
   for (int i = 0; i < array_len(numericFields); ++i) {
     RedisModuleString *keyName = IndexSpec_GetFormattedKey(sctx->spec, numericFields[i], INDEXFLD_T_NUMERIC);
@@ -500,6 +500,24 @@ static void FGC_childCollectTags(ForkGC *gc, RedisSearchCtx *sctx) {
   FGC_sendTerminator(gc);
 }
 
+// Collects the per-field docs indexes kept in `fieldDict` (missing or empty values)
+static void FGC_childCollectFieldDocs(ForkGC *gc, RedisSearchCtx *sctx, dict *fieldDict) {
+  if (fieldDict) {
+    dictIterator *iter = dictGetIterator(fieldDict);
+    dictEntry *entry;
+    while ((entry = dictNext(iter))) {
+      const char *fieldName = dictGetKey(entry);
+      InvertedIndex *idx = dictGetVal(entry);
+      struct iovec iov = {.iov_base = (void *)fieldName, strlen(fieldName)};
+      FGC_childRepairInvidx(gc, sctx, idx, sendHeaderString, &iov, NULL);
+    }
+    dictReleaseIterator(iter);
+  }
+
+  // we are done with this kind of field docs
+  FGC_sendTerminator(gc);
+}
+
 static void FGC_childScanIndexes(ForkGC *gc) {
   StrongRef cur_run_ref = WeakRef_Promote(gc->index);
   IndexSpec *spec = StrongRef_Get(cur_run_ref);
@@ -513,6 +531,8 @@ static void FGC_childScanIndexes(ForkGC *gc) {
   FGC_childCollectTerms(gc, &sctx);
   FGC_childCollectNumeric(gc, &sctx);
   FGC_childCollectTags(gc, &sctx);
+  FGC_childCollectFieldDocs(gc, &sctx, spec->missingFieldDict);
+  FGC_childCollectFieldDocs(gc, &sctx, spec->emptyFieldDict);
 
   StrongRef_Release(cur_run_ref);
 }
@@ -1081,6 +1101,60 @@ static FGCError FGC_parentHandleTags(ForkGC *gc) {
   return status;
 }
 
+typedef InvertedIndex *(*FieldDocsIndexGetter)(IndexSpec *sp, const FieldSpec *fs, bool create);
+
+static FGCError FGC_parentHandleFieldDocs(ForkGC *gc, FieldDocsIndexGetter getIndex) {
+  FGCError status = FGC_COLLECTED;
+  size_t fieldNameLen;
+  char *fieldName = NULL;
+  if (FGC_recvBuffer(gc, (void **)&fieldName, &fieldNameLen) != REDISMODULE_OK) {
+    return FGC_CHILD_ERROR;
+  }
+
+  if (fieldName == RECV_BUFFER_EMPTY) {
+    return FGC_DONE;
+  }
+
+  InvIdxBuffers idxbufs = {0};
+  MSG_IndexInfo info = {0};
+  if (FGC_recvInvIdx(gc, &idxbufs, &info) != REDISMODULE_OK) {
+    rm_free(fieldName);
+    return FGC_CHILD_ERROR;
+  }
+
+  StrongRef spec_ref = WeakRef_Promote(gc->index);
+  IndexSpec *sp = StrongRef_Get(spec_ref);
+  if (!sp) {
+    status = FGC_SPEC_DELETED;
+    goto cleanup;
+  }
+
+  RedisSearchCtx sctx = SEARCH_CTX_STATIC(gc->ctx, sp);
+  FGC_lock(&sctx);
+
+  // The field docs index is kept even if it becomes empty, as running queries may still use it
+  const FieldSpec *fs = IndexSpec_GetField(sp, fieldName, fieldNameLen);
+  InvertedIndex *idx = fs ? getIndex(sp, fs, false) : NULL;
+  if (idx == NULL) {
+    status = FGC_PARENT_ERROR;
+  } else {
+    FGC_applyInvertedIndex(gc, &idxbufs, &info, idx);
+    FGC_updateStats(gc, &sctx, info.nentriesCollected, info.nbytesCollected);
+  }
+
+  FGC_unlock(&sctx);
+  StrongRef_Release(spec_ref);
+
+cleanup:
+  rm_free(fieldName);
+  if (status != FGC_COLLECTED) {
+    freeInvIdx(&idxbufs, &info);
+  } else {
+    rm_free(idxbufs.changedBlocks);
+  }
+  return status;
+}
+
 FGCError FGC_parentHandleFromChild(ForkGC *gc) {
   FGCError status = FGC_COLLECTED;
 
@@ -1094,6 +1168,8 @@ FGCError FGC_parentHandleFromChild(ForkGC *gc) {
   COLLECT_FROM_CHILD(FGC_parentHandleTerms(gc));
   COLLECT_FROM_CHILD(FGC_parentHandleNumeric(gc));
   COLLECT_FROM_CHILD(FGC_parentHandleTags(gc));
+  COLLECT_FROM_CHILD(FGC_parentHandleFieldDocs(gc, IndexSpec_GetMissingFieldIndex));
+  COLLECT_FROM_CHILD(FGC_parentHandleFieldDocs(gc, IndexSpec_GetEmptyFieldIndex));
 
   return status;
 }
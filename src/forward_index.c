@@ -104,6 +104,7 @@ ForwardIndex *NewForwardIndex(Document *doc, uint32_t idxFlags) {
   size_t termCount = estimtateTermCount(doc);
   idx->hits = rm_calloc(1, sizeof(*idx->hits));
   idx->stemmer = NULL;
+  idx->stemExceptions = NULL;
   idx->totalFreq = 0;
 
   KHTable_Init(idx->hits, &procs, &idx->entries, termCount);
@@ -131,6 +132,10 @@ void ForwardIndex_Reset(ForwardIndex *idx, Document *doc, uint32_t idxFlags) {
     SynonymMap_Free(idx->smap);
     idx->smap = NULL;
   }
+  if (idx->stemExceptions) {
+    StemExceptions_Unref(idx->stemExceptions);
+    idx->stemExceptions = NULL;
+  }
 
   ForwardIndex_InitCommon(idx, doc, idxFlags);
 }
@@ -156,6 +161,10 @@ void ForwardIndexFree(ForwardIndex *idx) {
 
   idx->smap = NULL;
 
+  if (idx->stemExceptions) {
+    StemExceptions_Unref(idx->stemExceptions);
+  }
+
   rm_free(idx);
 }
 
@@ -38,6 +38,7 @@ typedef struct ForwardIndex {
   uint32_t idxFlags;
   Stemmer *stemmer;
   SynonymMap *smap;
+  StemExceptions *stemExceptions;
   BlkAlloc terms;
   BlkAlloc entries;
   mempool_t *vvwPool;
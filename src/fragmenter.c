@@ -7,6 +7,7 @@
 #include "fragmenter.h"
 #include "toksep.h"
 #include "tokenize.h"
+#include "normalize.h"
 #include "util/minmax.h"
 #include <ctype.h>
 #include <float.h>
@@ -420,6 +421,21 @@ void FragmentList_Free(FragmentList *fragList) {
  *    list, then, consume the matches until the maximum distance has been reached,
  *    noting the terms for each.
  */
+/* The length of the token at `s`, which ends at a separator or at a character whose NFKC form is
+ * a separator */
+static size_t nfkcTokenLength(const char *s, const char *end) {
+  const char *p = s;
+  while (p < end && !istoksep(*p)) {
+    uint32_t cp;
+    const char *next = Normalize_ReadCodepoint(p, end, &cp);
+    if (!next || Normalize_IsNFKCSeparator(cp)) {
+      break;
+    }
+    p = next;
+  }
+  return p - s;
+}
+
 void FragmentList_FragmentizeIter(FragmentList *fragList, const char *doc, size_t docLen,
                                   FragmentTermIterator *iter, int options) {
   fragList->docLen = docLen;
@@ -448,6 +464,8 @@ void FragmentList_FragmentizeIter(FragmentList *fragList, const char *doc, size_
     size_t len;
     if (options & FRAGMENTIZE_TOKLEN_EXACT) {
       len = curTerm->len;
+    } else if (options & FRAGMENTIZE_NFKC) {
+      len = nfkcTokenLength(doc + curTerm->bytePos, doc + fragList->docLen);
     } else {
       len = 0;
       for (size_t ii = curTerm->bytePos; ii < fragList->docLen && !istoksep(doc[ii]); ++ii, ++len) {
@@ -175,6 +175,8 @@ void FragmentList_FragmentizeBuffer(FragmentList *fragList, const char *doc, Ste
                                     size_t numTerms);
 
 #define FRAGMENTIZE_TOKLEN_EXACT 0x01
+// Tokens also end at the characters whose NFKC form is a separator
+#define FRAGMENTIZE_NFKC 0x02
 void FragmentList_FragmentizeIter(FragmentList *fragList, const char *doc, size_t docLen,
                                   FragmentTermIterator *iter, int options);
 
@@ -11,6 +11,9 @@
 #include "rmutil/rm_assert.h"
 #include "query_node.h"
 #include "query_param.h"
+#include "geo_polygon.h"
+#include "util/arr.h"
+#include <math.h>
 
 static double extractUnitFactor(GeoDistance unit);
 
@@ -63,12 +66,13 @@ int GeoFilter_Parse(GeoFilter *gf, ArgsCursor *ac, QueryError *status) {
 void GeoFilter_Free(GeoFilter *gf) {
   if (gf->property) rm_free((char *)gf->property);
   if (gf->numericFilters) {
-    for (int i = 0; i < GEO_RANGE_COUNT; ++i) {
+    for (size_t i = 0; i < gf->numNumericFilters; ++i) {
       if (gf->numericFilters[i])
         NumericFilter_Free(gf->numericFilters[i]);
     }
     rm_free(gf->numericFilters);
   }
+  GeoPolygon_Free(gf->polygon);
   rm_free(gf);
 }
 
@@ -111,22 +115,14 @@ done:
   return docIds;
 }
 
-IndexIterator *NewGeoRangeIterator(RedisSearchCtx *ctx, const GeoFilter *gf, IteratorsConfig *config) {
-  // check input parameters are valid
-  if (gf->radius <= 0 ||
-      gf->lon > GEO_LONG_MAX || gf->lon < GEO_LONG_MIN ||
-      gf->lat > GEO_LAT_MAX || gf->lat < GEO_LAT_MIN) {
-    return NULL;
-  }
-
-  GeoHashRange ranges[GEO_RANGE_COUNT] = {{0}};
-  double radius_meter = gf->radius * extractUnitFactor(gf->unitType);
-  calcRanges(gf->lon, gf->lat, radius_meter, ranges);
-
-  IndexIterator **iters = rm_calloc(GEO_RANGE_COUNT, sizeof(*iters));
-  ((GeoFilter *)gf)->numericFilters = rm_calloc(GEO_RANGE_COUNT, sizeof(*gf->numericFilters));
+static IndexIterator *geoRangesIterator(RedisSearchCtx *ctx, const GeoFilter *gf,
+                                        const GeoHashRange *ranges, size_t numRanges,
+                                        IteratorsConfig *config) {
+  IndexIterator **iters = rm_calloc(numRanges, sizeof(*iters));
+  ((GeoFilter *)gf)->numericFilters = rm_calloc(numRanges, sizeof(*gf->numericFilters));
+  ((GeoFilter *)gf)->numNumericFilters = numRanges;
   size_t itersCount = 0;
-  for (size_t ii = 0; ii < GEO_RANGE_COUNT; ++ii) {
+  for (size_t ii = 0; ii < numRanges; ++ii) {
     if (ranges[ii].min != ranges[ii].max) {
       NumericFilter *filt = gf->numericFilters[ii] =
               NewNumericFilter(ranges[ii].min, ranges[ii].max, 1, 1, true);
@@ -154,6 +150,108 @@ IndexIterator *NewGeoRangeIterator(RedisSearchCtx *ctx, const GeoFilter *gf, Ite
   return it;
 }
 
+IndexIterator *NewGeoRangeIterator(RedisSearchCtx *ctx, const GeoFilter *gf, IteratorsConfig *config) {
+  if (gf->polygon) {
+    GeoHashRange *ranges = GeoPolygon_CalcRanges(gf->polygon);
+    IndexIterator *it = geoRangesIterator(ctx, gf, ranges, array_len(ranges), config);
+    array_free(ranges);
+    return it;
+  }
+
+  // check input parameters are valid
+  if (gf->radius <= 0 ||
+      gf->lon > GEO_LONG_MAX || gf->lon < GEO_LONG_MIN ||
+      gf->lat > GEO_LAT_MAX || gf->lat < GEO_LAT_MIN) {
+    return NULL;
+  }
+
+  GeoHashRange ranges[GEO_RANGE_COUNT] = {{0}};
+  double radius_meter = gf->radius * extractUnitFactor(gf->unitType);
+  calcRanges(gf->lon, gf->lat, radius_meter, ranges);
+  return geoRangesIterator(ctx, gf, ranges, GEO_RANGE_COUNT, config);
+}
+
+/* The geo reader replaces the value of the numeric records it matches with their distance from the
+ * center of the filter, in meters */
+static double hitDistance(const RSIndexResult *r) {
+  while (r->type & RS_RESULT_AGGREGATE) {
+    if (!r->agg.numChildren) {
+      return NAN;
+    }
+    r = r->agg.children[0];
+  }
+  return r->num.value;
+}
+
+static void GDI_SetYield(GeoDistanceIterator *gi, RSIndexResult *hit) {
+  ResultMetrics_Reset(hit);
+  ResultMetrics_Add(hit, gi->base.ownKey, RS_NumVal(hitDistance(hit) / gi->unitFactor));
+}
+
+static int GDI_Read(void *ctx, RSIndexResult **hit) {
+  GeoDistanceIterator *gi = ctx;
+  int rc = gi->child->Read(gi->child->ctx, hit);
+  gi->base.current = gi->child->current;
+  if (rc == INDEXREAD_OK) {
+    GDI_SetYield(gi, *hit);
+  }
+  return rc;
+}
+
+static int GDI_SkipTo(void *ctx, t_docId docId, RSIndexResult **hit) {
+  GeoDistanceIterator *gi = ctx;
+  int rc = gi->child->SkipTo(gi->child->ctx, docId, hit);
+  gi->base.current = gi->child->current;
+  if (rc == INDEXREAD_OK || rc == INDEXREAD_NOTFOUND) {
+    GDI_SetYield(gi, *hit);
+  }
+  return rc;
+}
+
+static void GDI_Free(IndexIterator *it) {
+  GeoDistanceIterator *gi = (GeoDistanceIterator *)it;
+  gi->child->Free(gi->child);
+  rm_free(gi);
+}
+
+#define GEO_DISTANCE_ITERATOR_FUNC_SIGN(func, rettype) \
+static rettype GDI_##func(void *ctx) {                 \
+  GeoDistanceIterator *gi = ctx;                       \
+  return gi->child->func(gi->child->ctx);              \
+}
+
+GEO_DISTANCE_ITERATOR_FUNC_SIGN(Abort, void);
+GEO_DISTANCE_ITERATOR_FUNC_SIGN(Len, size_t);
+GEO_DISTANCE_ITERATOR_FUNC_SIGN(Rewind, void);
+GEO_DISTANCE_ITERATOR_FUNC_SIGN(LastDocId, t_docId);
+GEO_DISTANCE_ITERATOR_FUNC_SIGN(NumEstimated, size_t);
+
+static int GDI_HasNext(void *ctx) {
+  GeoDistanceIterator *gi = ctx;
+  return IITER_HAS_NEXT(gi->child);
+}
+
+IndexIterator *NewGeoDistanceIterator(IndexIterator *child, const GeoFilter *gf) {
+  GeoDistanceIterator *gi = rm_calloc(1, sizeof(*gi));
+  gi->child = child;
+  gi->unitFactor = extractUnitFactor(gf->unitType);
+
+  IndexIterator *ret = &gi->base;
+  ret->ctx = gi;
+  ret->type = GEO_DISTANCE_ITERATOR;
+  ret->current = child->current;
+  ret->Free = GDI_Free;
+  ret->HasNext = GDI_HasNext;
+  ret->LastDocId = GDI_LastDocId;
+  ret->Len = GDI_Len;
+  ret->Read = GDI_Read;
+  ret->SkipTo = GDI_SkipTo;
+  ret->Abort = GDI_Abort;
+  ret->Rewind = GDI_Rewind;
+  ret->NumEstimated = GDI_NumEstimated;
+  return ret;
+}
+
 GeoDistance GeoDistance_Parse(const char *s) {
 #define X(c, val)            \
   if (!strcasecmp(val, s)) { \
@@ -200,6 +298,17 @@ GeoFilter *NewGeoFilter(double lon, double lat, double radius, const char *unit,
   return gf;
 }
 
+GeoFilter *NewGeoPolygonFilter(const char *property, const char *wkt, QueryError *status) {
+  GeoPolygon *polygon = GeoPolygon_ParseWkt(wkt, status);
+  if (!polygon) {
+    return NULL;
+  }
+  GeoFilter *gf = rm_calloc(1, sizeof(*gf));
+  gf->property = rm_strdup(property);
+  gf->polygon = polygon;
+  return gf;
+}
+
 int GeoFilter_EvalParams(dict *params, QueryNode *node, QueryError *status) {
   if (node->params) {
     for (size_t i = 0; i < QueryNode_NumParams(node); i++) {
@@ -298,6 +407,15 @@ int isWithinRadius(const GeoFilter *gf, double d, double *distance) {
   return rv;
 }
 
+int GeoFilter_Match(const GeoFilter *gf, double d, double *distance) {
+  if (!gf->polygon) {
+    return isWithinRadius(gf, d, distance);
+  }
+  double xy[2];
+  decodeGeo(d, xy);
+  return GeoPolygon_Contains(gf->polygon, xy[0], xy[1]);
+}
+
 static int checkResult(const GeoFilter *gf, const RSIndexResult *cur) {
   double distance;
   if (cur->type == RSResultType_Numeric) {
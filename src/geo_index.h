@@ -37,6 +37,8 @@ typedef enum {  // Placeholder for bad/invalid unit
 #undef X
 } GeoDistance;
 
+struct GeoPolygon;
+
 typedef struct GeoFilter {
   const char *property;
   double lat;
@@ -44,11 +46,17 @@ typedef struct GeoFilter {
   double radius;
   GeoDistance unitType;
   NumericFilter **numericFilters;
+  size_t numNumericFilters;
+  struct GeoPolygon *polygon;  // Set for polygon filters, which ignore lat, lon and radius
 } GeoFilter;
 
 /* Create a geo filter from parsed strings and numbers */
 GeoFilter *NewGeoFilter(double lon, double lat, double radius, const char *unit, size_t unit_len);
 
+/* Create a geo filter matching the points within a WKT polygon.
+ * Returns NULL and sets the error if the polygon is invalid */
+GeoFilter *NewGeoPolygonFilter(const char *property, const char *wkt, QueryError *status);
+
 /*
  * Substitute parameters with actual values used by geo filter
  * If a parameters is missing, has wrong kind, or the resulting geo filter is invalid
@@ -73,8 +81,22 @@ int GeoFilter_Parse(GeoFilter *gf, ArgsCursor *ac, QueryError *status);
 void GeoFilter_Free(GeoFilter *gf);
 IndexIterator *NewGeoRangeIterator(RedisSearchCtx *ctx, const GeoFilter *gf, IteratorsConfig *config);
 
+/* Wraps the iterator of a radius filter, and yields the distance of every hit from the center of
+ * the filter, in the unit of the filter */
+typedef struct {
+  IndexIterator base;
+  IndexIterator *child;
+  double unitFactor;  // Meters per unit of the filter
+} GeoDistanceIterator;
+
+IndexIterator *NewGeoDistanceIterator(IndexIterator *child, const GeoFilter *gf);
+
 /*****************************************************************************/
 
 #define INVALID_GEOHASH -1.0
 double calcGeoHash(double lon, double lat);
 int isWithinRadius(const GeoFilter *gf, double d, double *distance);
+
+/* Checks if the given coordinate d matches the filter. For radius filters, `distance` is set to
+ * the distance from the center */
+int GeoFilter_Match(const GeoFilter *gf, double d, double *distance);
@@ -0,0 +1,256 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "geo_polygon.h"
+#include "geohash/geohash_helper.h"
+#include "util/arr.h"
+#include "util/minmax.h"
+#include "rmalloc.h"
+
+#include <ctype.h>
+#include <stdlib.h>
+#include <strings.h>
+
+#define POLYGON_KEYWORD "POLYGON"
+
+static const char *skipSpaces(const char *s) {
+  while (isspace(*s)) ++s;
+  return s;
+}
+
+static int parseRing(const char **s, GeoPolygonPoint **ring, QueryError *status) {
+  const char *p = skipSpaces(*s);
+  if (*p != '(') {
+    QERR_MKSYNTAXERR(status, "Invalid polygon: expected `(` at offset %zu", p - *s);
+    return REDISMODULE_ERR;
+  }
+  ++p;
+  while (1) {
+    char *end;
+    GeoPolygonPoint pt;
+    pt.lon = strtod(p, &end);
+    if (end == p) goto syntax_error;
+    p = end;
+    pt.lat = strtod(p, &end);
+    if (end == p) goto syntax_error;
+    p = skipSpaces(end);
+
+    if (pt.lon < GEO_LONG_MIN || pt.lon > GEO_LONG_MAX ||
+        pt.lat < GEO_LAT_MIN || pt.lat > GEO_LAT_MAX) {
+      QERR_MKSYNTAXERR(status, "Invalid polygon: point (%g %g) is out of range", pt.lon, pt.lat);
+      return REDISMODULE_ERR;
+    }
+    *ring = array_append(*ring, pt);
+
+    if (*p == ',') {
+      ++p;
+    } else if (*p == ')') {
+      ++p;
+      break;
+    } else {
+      goto syntax_error;
+    }
+  }
+
+  // Rings may or may not repeat their first point at their end
+  size_t n = array_len(*ring);
+  if (n > 1 && (*ring)[0].lon == (*ring)[n - 1].lon && (*ring)[0].lat == (*ring)[n - 1].lat) {
+    --n;
+  }
+  if (n < 3) {
+    QERR_MKSYNTAXERR(status, "Invalid polygon: a ring must have at least 3 points");
+    return REDISMODULE_ERR;
+  }
+  *s = p;
+  return REDISMODULE_OK;
+
+syntax_error:
+  QERR_MKSYNTAXERR(status, "Invalid polygon: expected `<lon> <lat>` points separated by `,`");
+  return REDISMODULE_ERR;
+}
+
+GeoPolygon *GeoPolygon_ParseWkt(const char *wkt, QueryError *status) {
+  const char *p = skipSpaces(wkt);
+  if (strncasecmp(p, POLYGON_KEYWORD, strlen(POLYGON_KEYWORD))) {
+    QERR_MKSYNTAXERR(status, "Only POLYGON shapes can be queried on GEO fields");
+    return NULL;
+  }
+  p = skipSpaces(p + strlen(POLYGON_KEYWORD));
+  if (*p != '(') {
+    QERR_MKSYNTAXERR(status, "Invalid polygon: expected `(` after POLYGON");
+    return NULL;
+  }
+  ++p;
+
+  GeoPolygon *poly = rm_calloc(1, sizeof(*poly));
+  poly->rings = array_new(GeoPolygonPoint *, 1);
+  while (1) {
+    GeoPolygonPoint *ring = array_new(GeoPolygonPoint, 8);
+    poly->rings = array_append(poly->rings, ring);
+    if (parseRing(&p, &poly->rings[array_len(poly->rings) - 1], status) != REDISMODULE_OK) {
+      GeoPolygon_Free(poly);
+      return NULL;
+    }
+    p = skipSpaces(p);
+    if (*p == ',') {
+      ++p;
+    } else if (*p == ')') {
+      ++p;
+      break;
+    } else {
+      QERR_MKSYNTAXERR(status, "Invalid polygon: expected `,` or `)` after a ring");
+      GeoPolygon_Free(poly);
+      return NULL;
+    }
+  }
+  if (*skipSpaces(p)) {
+    QERR_MKSYNTAXERR(status, "Invalid polygon: unexpected `%s` after the polygon", skipSpaces(p));
+    GeoPolygon_Free(poly);
+    return NULL;
+  }
+
+  GeoPolygonPoint *outer = poly->rings[0];
+  poly->lonRange = (GeoHashRange){.min = outer[0].lon, .max = outer[0].lon};
+  poly->latRange = (GeoHashRange){.min = outer[0].lat, .max = outer[0].lat};
+  for (size_t ii = 1; ii < array_len(outer); ++ii) {
+    poly->lonRange.min = MIN(poly->lonRange.min, outer[ii].lon);
+    poly->lonRange.max = MAX(poly->lonRange.max, outer[ii].lon);
+    poly->latRange.min = MIN(poly->latRange.min, outer[ii].lat);
+    poly->latRange.max = MAX(poly->latRange.max, outer[ii].lat);
+  }
+  return poly;
+}
+
+void GeoPolygon_Free(GeoPolygon *poly) {
+  if (!poly) return;
+  array_free_ex(poly->rings, array_free(*(GeoPolygonPoint **)ptr));
+  rm_free(poly);
+}
+
+/* Even-odd rule: a point is inside if a ray cast from it crosses the rings an odd number of
+ * times, so points inside holes are outside of the polygon */
+bool GeoPolygon_Contains(const GeoPolygon *poly, double lon, double lat) {
+  if (lon < poly->lonRange.min || lon > poly->lonRange.max ||
+      lat < poly->latRange.min || lat > poly->latRange.max) {
+    return false;
+  }
+  bool inside = false;
+  for (size_t ii = 0; ii < array_len(poly->rings); ++ii) {
+    GeoPolygonPoint *ring = poly->rings[ii];
+    size_t n = array_len(ring);
+    for (size_t i = 0, j = n - 1; i < n; j = i++) {
+      if ((ring[i].lat > lat) != (ring[j].lat > lat) &&
+          lon < (ring[j].lon - ring[i].lon) * (lat - ring[i].lat) / (ring[j].lat - ring[i].lat) +
+                    ring[i].lon) {
+        inside = !inside;
+      }
+    }
+  }
+  return inside;
+}
+
+/* Liang-Barsky clipping of the segment against the area */
+static bool segmentIntersectsArea(const GeoPolygonPoint *a, const GeoPolygonPoint *b,
+                                  const GeoHashArea *area) {
+  double dx = b->lon - a->lon, dy = b->lat - a->lat;
+  double p[4] = {-dx, dx, -dy, dy};
+  double q[4] = {a->lon - area->longitude.min, area->longitude.max - a->lon,
+                 a->lat - area->latitude.min, area->latitude.max - a->lat};
+  double t0 = 0, t1 = 1;
+  for (int i = 0; i < 4; ++i) {
+    if (p[i] == 0) {
+      if (q[i] < 0) return false;
+      continue;
+    }
+    double t = q[i] / p[i];
+    if (p[i] < 0) {
+      if (t > t1) return false;
+      if (t > t0) t0 = t;
+    } else {
+      if (t < t0) return false;
+      if (t < t1) t1 = t;
+    }
+  }
+  return true;
+}
+
+static bool polygonIntersectsArea(const GeoPolygon *poly, const GeoHashArea *area) {
+  for (size_t ii = 0; ii < array_len(poly->rings); ++ii) {
+    GeoPolygonPoint *ring = poly->rings[ii];
+    size_t n = array_len(ring);
+    for (size_t i = 0, j = n - 1; i < n; j = i++) {
+      if (segmentIntersectsArea(&ring[j], &ring[i], area)) {
+        return true;
+      }
+    }
+  }
+  // No edge crosses the area, so it is either entirely inside the polygon or entirely outside
+  return GeoPolygon_Contains(poly, (area->longitude.min + area->longitude.max) / 2,
+                             (area->latitude.min + area->latitude.max) / 2);
+}
+
+static uint64_t cellIndex(double value, double min, double max, uint8_t step) {
+  uint64_t cells = 1ULL << step;
+  double ix = (value - min) / (max - min) * cells;
+  if (ix < 0) return 0;
+  return (uint64_t)ix < cells ? (uint64_t)ix : cells - 1;
+}
+
+static int cmpRanges(const void *a, const void *b) {
+  double ma = ((const GeoHashRange *)a)->min, mb = ((const GeoHashRange *)b)->min;
+  return ma < mb ? -1 : (ma > mb ? 1 : 0);
+}
+
+GeoHashRange *GeoPolygon_CalcRanges(const GeoPolygon *poly) {
+  // Use the finest cells for which the bounding box spans at most GEO_POLYGON_MAX_CELLS cells
+  uint8_t step = GEO_STEP_MAX;
+  uint64_t lonMin, lonMax, latMin, latMax;
+  while (1) {
+    lonMin = cellIndex(poly->lonRange.min, GEO_LONG_MIN, GEO_LONG_MAX, step);
+    lonMax = cellIndex(poly->lonRange.max, GEO_LONG_MIN, GEO_LONG_MAX, step);
+    latMin = cellIndex(poly->latRange.min, GEO_LAT_MIN, GEO_LAT_MAX, step);
+    latMax = cellIndex(poly->latRange.max, GEO_LAT_MIN, GEO_LAT_MAX, step);
+    if (step == 1 || (lonMax - lonMin + 1) * (latMax - latMin + 1) <= GEO_POLYGON_MAX_CELLS) {
+      break;
+    }
+    --step;
+  }
+
+  double lonWidth = (double)(GEO_LONG_MAX - GEO_LONG_MIN) / (1ULL << step);
+  double latWidth = (GEO_LAT_MAX - GEO_LAT_MIN) / (1ULL << step);
+  GeoHashRange *ranges = array_new(GeoHashRange, GEO_POLYGON_MAX_CELLS);
+  for (uint64_t y = latMin; y <= latMax; ++y) {
+    for (uint64_t x = lonMin; x <= lonMax; ++x) {
+      GeoHashBits hash;
+      GeoHashArea area;
+      if (!geohashEncodeWGS84(GEO_LONG_MIN + (x + 0.5) * lonWidth,
+                              GEO_LAT_MIN + (y + 0.5) * latWidth, step, &hash) ||
+          !geohashDecodeWGS84(hash, &area) || !polygonIntersectsArea(poly, &area)) {
+        continue;
+      }
+      GeoHashRange range = {.min = geohashAlign52Bits(hash)};
+      hash.bits++;
+      range.max = geohashAlign52Bits(hash);
+      ranges = array_append(ranges, range);
+    }
+  }
+
+  // Merge the ranges of adjacent cells
+  size_t n = array_len(ranges);
+  if (n > 1) {
+    qsort(ranges, n, sizeof(*ranges), cmpRanges);
+    size_t last = 0;
+    for (size_t ii = 1; ii < n; ++ii) {
+      if (ranges[ii].min <= ranges[last].max) {
+        ranges[last].max = MAX(ranges[last].max, ranges[ii].max);
+      } else {
+        ranges[++last] = ranges[ii];
+      }
+    }
+    ranges = array_trimm(ranges, last + 1, ARR_CAP_NOSHRINK);
+  }
+  return ranges;
+}
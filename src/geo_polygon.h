@@ -0,0 +1,58 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "query_error.h"
+#include "geohash/geohash.h"
+
+#include <stdbool.h>
+
+/*
+ * Polygons queried against GEO fields. The edges of a polygon are straight lines in the
+ * longitude/latitude plane, and its first ring is the outer boundary while the others are holes.
+ *
+ * A polygon is searched by covering its bounding box with geohash cells, dropping the cells
+ * which don't intersect it, and refining the candidates of the remaining cells with an exact
+ * point-in-polygon test.
+ */
+
+/** The maximal number of geohash cells used to cover a polygon */
+#define GEO_POLYGON_MAX_CELLS 64
+
+typedef struct {
+  double lon;
+  double lat;
+} GeoPolygonPoint;
+
+typedef struct GeoPolygon {
+  GeoPolygonPoint **rings;  // array of rings, each one an array of points
+  GeoHashRange lonRange;    // bounding box of the outer ring
+  GeoHashRange latRange;
+} GeoPolygon;
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/** Parse a WKT POLYGON. Returns NULL and sets the error if it is invalid */
+GeoPolygon *GeoPolygon_ParseWkt(const char *wkt, QueryError *status);
+
+/** Whether the point is inside the polygon, and not inside one of its holes */
+bool GeoPolygon_Contains(const GeoPolygon *poly, double lon, double lat);
+
+/**
+ * Calculate the ranges of 52 bit geohash scores of the cells covering the polygon.
+ * Each range includes its min and excludes its max. The ranges are sorted and don't overlap.
+ * Returns an array of ranges, to be freed by the caller with array_free.
+ */
+GeoHashRange *GeoPolygon_CalcRanges(const GeoPolygon *poly);
+
+void GeoPolygon_Free(GeoPolygon *poly);
+
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,182 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include <cctype>
+#include <cstdlib>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <vector>
+
+// A minimal JSON reader, enough for the geometry objects of GeoJSON (RFC 7946)
+namespace geojson {
+
+struct Value {
+  enum class Kind { Null, Bool, Number, String, Array, Object };
+
+  Kind kind_ = Kind::Null;
+  bool boolean_ = false;
+  double number_ = 0;
+  std::string string_{};
+  std::vector<Value> elements_{};   // Array elements, or object values
+  std::vector<std::string> keys_{}; // Object keys, matching `elements_`
+
+  [[nodiscard]] bool is(Kind kind) const noexcept {
+    return kind_ == kind;
+  }
+
+  [[nodiscard]] Value const* get(std::string_view key) const noexcept {
+    for (std::size_t i = 0; i < keys_.size(); ++i) {
+      if (keys_[i] == key) {
+        return &elements_[i];
+      }
+    }
+    return nullptr;
+  }
+};
+
+class Reader {
+ public:
+  explicit Reader(std::string_view json) noexcept : json_{json}, pos_{0} {
+  }
+
+  [[nodiscard]] Value read() {
+    Value value = read_value();
+    skip_spaces();
+    if (pos_ != json_.size()) {
+      fail("unexpected trailing characters");
+    }
+    return value;
+  }
+
+ private:
+  std::string_view json_;
+  std::size_t pos_;
+
+  [[noreturn]] void fail(const char* what) const {
+    throw std::runtime_error{std::string{"Invalid GeoJSON: "} + what + " at offset " +
+                             std::to_string(pos_)};
+  }
+
+  void skip_spaces() noexcept {
+    while (pos_ < json_.size() && std::isspace(static_cast<unsigned char>(json_[pos_]))) {
+      ++pos_;
+    }
+  }
+
+  [[nodiscard]] bool consume(char c) {
+    skip_spaces();
+    if (pos_ < json_.size() && json_[pos_] == c) {
+      ++pos_;
+      return true;
+    }
+    return false;
+  }
+
+  void expect(char c) {
+    if (!consume(c)) {
+      fail((std::string{"expected `"} + c + "`").c_str());
+    }
+  }
+
+  [[nodiscard]] bool consume_literal(std::string_view literal) noexcept {
+    if (json_.substr(pos_, literal.size()) == literal) {
+      pos_ += literal.size();
+      return true;
+    }
+    return false;
+  }
+
+  [[nodiscard]] Value read_value() {
+    skip_spaces();
+    if (pos_ == json_.size()) {
+      fail("unexpected end");
+    }
+    Value value{};
+    switch (json_[pos_]) {
+      case '{':
+        ++pos_;
+        value.kind_ = Value::Kind::Object;
+        if (consume('}')) {
+          break;
+        }
+        do {
+          skip_spaces();
+          value.keys_.push_back(read_string());
+          expect(':');
+          value.elements_.push_back(read_value());
+        } while (consume(','));
+        expect('}');
+        break;
+      case '[':
+        ++pos_;
+        value.kind_ = Value::Kind::Array;
+        if (consume(']')) {
+          break;
+        }
+        do {
+          value.elements_.push_back(read_value());
+        } while (consume(','));
+        expect(']');
+        break;
+      case '"':
+        value.kind_ = Value::Kind::String;
+        value.string_ = read_string();
+        break;
+      default:
+        if (consume_literal("null")) {
+          value.kind_ = Value::Kind::Null;
+        } else if (consume_literal("true")) {
+          value.kind_ = Value::Kind::Bool;
+          value.boolean_ = true;
+        } else if (consume_literal("false")) {
+          value.kind_ = Value::Kind::Bool;
+        } else {
+          value.kind_ = Value::Kind::Number;
+          value.number_ = read_number();
+        }
+        break;
+    }
+    return value;
+  }
+
+  [[nodiscard]] std::string read_string() {
+    if (pos_ == json_.size() || json_[pos_] != '"') {
+      fail("expected a string");
+    }
+    std::string str{};
+    for (++pos_; pos_ < json_.size() && json_[pos_] != '"'; ++pos_) {
+      if (json_[pos_] == '\\' && pos_ + 1 < json_.size()) {
+        ++pos_;
+      }
+      str.push_back(json_[pos_]);
+    }
+    if (pos_ == json_.size()) {
+      fail("unterminated string");
+    }
+    ++pos_;
+    return str;
+  }
+
+  [[nodiscard]] double read_number() {
+    std::string str{json_.substr(pos_, 64)};
+    char* end = nullptr;
+    double number = std::strtod(str.c_str(), &end);
+    if (end == str.c_str()) {
+      fail("expected a value");
+    }
+    pos_ += end - str.c_str();
+    return number;
+  }
+};
+
+[[nodiscard]] inline Value parse(std::string_view json) {
+  return Reader{json}.read();
+}
+
+}  // namespace geojson
@@ -6,11 +6,11 @@
 
 #include "geometry_api.h"
 #include "geometry.h"
+#ifdef WITH_S2GEOMETRY
+#include "s2index.h"
+#endif
 #include "rmalloc.h"
 
-// TOD: remove
-// #include "s2/s2point_index.h"
-
 GeometryApi* apis[GEOMETRY_LIB_TYPE__NUM] = {0};
 
 void bg_freeIndex(GeometryIndex *index) {
@@ -21,12 +21,12 @@ struct GeometryIndex* bg_createIndex() {
   return reinterpret_cast<GeometryIndex*>(RTree_New());
 }
 
-IndexIterator* bg_query(struct GeometryIndex *index, enum QueryType queryType, GEOMETRY_FORMAT format, const char *str, size_t len, RedisModuleString **err_msg) {
+IndexIterator* bg_query(struct GeometryIndex *index, enum QueryType queryType, double distance, GEOMETRY_FORMAT format, const char *str, size_t len, RedisModuleString **err_msg) {
   switch (format) {
   case GEOMETRY_FORMAT_WKT:
-    return RTree_Query_WKT((struct RTree*)index, str, len, queryType, err_msg);
-  
   case GEOMETRY_FORMAT_GEOJSON:
+    return RTree_Query_Str((struct RTree*)index, format, str, len, queryType, distance, err_msg);
+  
   default:
     return NULL;
   }
@@ -36,11 +36,10 @@ int bg_addGeomStr(struct GeometryIndex *index, GEOMETRY_FORMAT format, const cha
   
   switch (format) {
   case GEOMETRY_FORMAT_WKT:
-    return !RTree_Insert_WKT((struct RTree*)index, str, len, docId, err_msg);
+  case GEOMETRY_FORMAT_GEOJSON:
+    return !RTree_Insert((struct RTree*)index, format, str, len, docId, err_msg);
 
   default:
-  case GEOMETRY_FORMAT_GEOJSON:
-    // TODO: GEOMETRY Support GeoJSON
     return 1;
 
   }
@@ -55,9 +54,45 @@ void bg_dumpIndex(GeometryIndex *index, RedisModuleCtx *ctx) {
   RTree_Dump(reinterpret_cast<RTree*>(index), ctx);
 }
 
+#ifdef WITH_S2GEOMETRY
 void s2_freeIndex(GeometryIndex *index) {
-  // TODO: GEOMETRY
+  S2Index_Free(reinterpret_cast<S2Index*>(index));
+}
+
+struct GeometryIndex* s2_createIndex() {
+  return reinterpret_cast<GeometryIndex*>(S2Index_New());
+}
+
+IndexIterator* s2_query(struct GeometryIndex *index, enum QueryType queryType, double distance, GEOMETRY_FORMAT format, const char *str, size_t len, RedisModuleString **err_msg) {
+  switch (format) {
+  case GEOMETRY_FORMAT_WKT:
+  case GEOMETRY_FORMAT_GEOJSON:
+    return S2Index_Query_Str(reinterpret_cast<S2Index*>(index), format, str, len, queryType, distance, err_msg);
+
+  default:
+    return NULL;
+  }
+}
+
+int s2_addGeomStr(struct GeometryIndex *index, GEOMETRY_FORMAT format, const char *str, size_t len, t_docId docId, RedisModuleString **err_msg) {
+  switch (format) {
+  case GEOMETRY_FORMAT_WKT:
+  case GEOMETRY_FORMAT_GEOJSON:
+    return !S2Index_Insert(reinterpret_cast<S2Index*>(index), format, str, len, docId, err_msg);
+
+  default:
+    return 1;
+  }
+}
+
+int s2_delGeom(struct GeometryIndex *index, t_docId docId) {
+  return S2Index_RemoveByDocId(reinterpret_cast<S2Index*>(index), docId);
+}
+
+void s2_dumpIndex(GeometryIndex *index, RedisModuleCtx *ctx) {
+  S2Index_Dump(reinterpret_cast<S2Index*>(index), ctx);
 }
+#endif // WITH_S2GEOMETRY
 
 extern "C"
 GeometryApi* GeometryApi_GetOrCreate(GEOMETRY_LIB_TYPE type, __attribute__((__unused__)) void *pdata) {
@@ -78,10 +113,16 @@ GeometryApi* GeometryApi_GetOrCreate(GEOMETRY_LIB_TYPE type, __attribute__((__un
     api->query = bg_query;
     api->dump = bg_dumpIndex;
     break;
+#ifdef WITH_S2GEOMETRY
    case GEOMETRY_LIB_TYPE_S2:
+    api->createIndex = s2_createIndex;
     api->freeIndex = s2_freeIndex;
-    // TODO: GEOMETRY
+    api->addGeomStr = s2_addGeomStr;
+    api->delGeom = s2_delGeom;
+    api->query = s2_query;
+    api->dump = s2_dumpIndex;
     break;
+#endif
    default:
     rm_free(api);
     return NULL;
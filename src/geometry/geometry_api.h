@@ -15,7 +15,8 @@ typedef struct {
     void (*freeIndex)(GeometryIndex *index);
     int (*addGeomStr)(GeometryIndex *index, GEOMETRY_FORMAT format, const char *str, size_t len, t_docId docId, RedisModuleString **err_msg);
     int (*delGeom)(GeometryIndex *index, t_docId docId);
-    IndexIterator* (*query)(GeometryIndex *index, enum QueryType queryType, GEOMETRY_FORMAT format, const char *str, size_t len, RedisModuleString **err_msg);
+    // `distance` is only used by DWITHIN queries
+    IndexIterator* (*query)(GeometryIndex *index, enum QueryType queryType, double distance, GEOMETRY_FORMAT format, const char *str, size_t len, RedisModuleString **err_msg);
     void (*dump)(GeometryIndex *index, RedisModuleCtx *ctx);
 } GeometryApi; // TODO: GEOMETRY Rename to GeometryIndex
 
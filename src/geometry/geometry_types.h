@@ -24,4 +24,7 @@ typedef enum {
 enum QueryType {
   CONTAINS,
   WITHIN,
+  INTERSECTS,
+  DISJOINT,
+  DWITHIN,    // Within a given distance
 };
\ No newline at end of file
@@ -10,7 +10,7 @@
 // TODO: GEOMETRY - remove this function if not used by tests
 RTDoc *From_WKT(const char *wkt, size_t len, t_docId id, RedisModuleString **err_msg) {
   try {
-    auto geometry = Polygon::from_wkt(std::string_view{wkt, len});
+    auto geometry = Shape::from_wkt(std::string_view{wkt, len});
     return new RTDoc{geometry, id};
   } catch (const std::exception &e) {
     if (err_msg)
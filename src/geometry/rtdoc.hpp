@@ -7,6 +7,7 @@
 #pragma once
 
 #include "polygon.hpp"
+#include "shape.hpp"
 #include "rtdoc.h"
 
 #include <ranges>
@@ -30,6 +31,9 @@ struct RTDoc {
   explicit RTDoc(poly_type const& poly, t_docId id = 0)
       : rect_{to_rect(poly)}, id_{id} {
   }
+  explicit RTDoc(Shape const& shape, t_docId id = 0)
+      : rect_{shape.envelope()}, id_{id} {
+  }
   
   [[nodiscard]] t_docId id() const noexcept {
     return id_;
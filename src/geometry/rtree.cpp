@@ -19,7 +19,7 @@ RTree *Load_WKT_File(RTree *rtree, const char *path) {
 
   auto file = std::ifstream{path};
   for (string wkt{}; std::getline(file, wkt, '\n');) {
-    auto geometry = Polygon::from_wkt(wkt);
+    auto geometry = Shape::from_wkt(wkt);
     rtree->insert(geometry, 0);
   }
 
@@ -30,9 +30,9 @@ void RTree_Free(RTree *rtree) noexcept {
   delete rtree;
 }
 
-int RTree_Insert_WKT(RTree *rtree, const char *wkt, size_t len, t_docId id, RedisModuleString **err_msg) {
+int RTree_Insert(RTree *rtree, GEOMETRY_FORMAT format, const char *str, size_t len, t_docId id, RedisModuleString **err_msg) {
   try {
-    auto geometry = Polygon::from_wkt(std::string_view{wkt, len});
+    auto geometry = Shape::from_str(format, std::string_view{str, len});
     rtree->insert(geometry, id);
     return 0;
   } catch (const std::exception &e) {
@@ -57,7 +57,7 @@ int RTree_Remove_WKT(RTree *rtree, const char *wkt, size_t len, t_docId id) {
       rtree->removeId(id);
       return rtree->remove(RTDoc{geometry.value(), id});
     } else {
-      auto geometry = Polygon::from_wkt(std::string_view{wkt,len});
+      auto geometry = Shape::from_wkt(std::string_view{wkt,len});
       return rtree->remove(RTDoc{geometry, id});
     }
   } catch (...) {
@@ -76,16 +76,16 @@ IndexIterator *generate_query_iterator(RTree::ResultsVec&& results) {
   return gqi->base();
 }
 
-IndexIterator *RTree_Query(RTree const *rtree, RTDoc const *queryDoc, QueryType queryType) {
+IndexIterator *RTree_Query(RTree const *rtree, RTDoc const *queryDoc, QueryType queryType, double distance) {
   auto geometry = rtree->lookup(queryDoc->id());
-  return generate_query_iterator(rtree->query(*queryDoc, queryType, geometry.value()));
+  return generate_query_iterator(rtree->query(*queryDoc, queryType, geometry.value(), distance));
 }
 
-IndexIterator *RTree_Query_WKT(RTree const *rtree, const char *wkt, size_t len, enum QueryType queryType, RedisModuleString **err_msg) {
+IndexIterator *RTree_Query_Str(RTree const *rtree, GEOMETRY_FORMAT format, const char *str, size_t len, enum QueryType queryType, double distance, RedisModuleString **err_msg) {
   try
   {
-    auto geometry = Polygon::from_wkt(wkt);
-    auto res = rtree->query(RTDoc{geometry, 0}, queryType, geometry);
+    auto geometry = Shape::from_str(format, std::string_view{str, len});
+    auto res = rtree->query(RTDoc{geometry, 0}, queryType, geometry, distance);
     return generate_query_iterator(std::move(res));
   }
   catch(const std::exception& e)
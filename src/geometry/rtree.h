@@ -22,7 +22,7 @@ struct RedisModuleCtx;
 NODISCARD struct RTree *RTree_New();
 struct RTree *Load_WKT_File(struct RTree *rtree, const char *path);
 void RTree_Free(struct RTree *rtree) NOEXCEPT;
-int RTree_Insert_WKT(struct RTree *rtree, const char *wkt, size_t len, t_docId id, RedisModuleString **err_msg);
+int RTree_Insert(struct RTree *rtree, GEOMETRY_FORMAT format, const char *str, size_t len, t_docId id, RedisModuleString **err_msg);
 bool RTree_Remove(struct RTree *rtree, struct RTDoc const *doc);
 bool RTree_RemoveByDocId(struct RTree *rtree, t_docId);
 int RTree_Remove_WKT(struct RTree *rtree, const char *wkt, size_t len, t_docId id);
@@ -32,10 +32,11 @@ NODISCARD bool RTree_IsEmpty(struct RTree const *rtree) NOEXCEPT;
 void RTree_Clear(struct RTree *rtree) NOEXCEPT;
 NODISCARD struct RTDoc *RTree_Bounds(struct RTree const *rtree);
 
-NODISCARD IndexIterator *RTree_Query(struct RTree const *rtree, struct RTDoc const *queryDoc, enum QueryType queryType);
+// `distance` is only used by DWITHIN queries
+NODISCARD IndexIterator *RTree_Query(struct RTree const *rtree, struct RTDoc const *queryDoc, enum QueryType queryType, double distance);
 
 // Caller should free the returned err_msg
-NODISCARD IndexIterator *RTree_Query_WKT(struct RTree const *rtree, const char *wkt, size_t len, enum QueryType queryType, RedisModuleString **err_msg);
+NODISCARD IndexIterator *RTree_Query_Str(struct RTree const *rtree, GEOMETRY_FORMAT format, const char *str, size_t len, enum QueryType queryType, double distance, RedisModuleString **err_msg);
 
 NODISCARD size_t RTree_MemUsage(struct RTree const *rtree);
 #ifdef __cplusplus
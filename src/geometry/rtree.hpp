@@ -19,7 +19,7 @@ struct RTree {
   using rtree_internal =
       bgi::rtree<RTDoc, parameter_type, RTDoc_Indexable, RTDoc_EqualTo, rm_allocator<RTDoc>>;
   using docLookup_internal = 
-    std::unordered_map<t_docId, RTDoc::poly_type, std::hash<t_docId>, std::equal_to<t_docId>, rm_allocator<std::pair<const t_docId, RTDoc::poly_type>>>;
+    std::unordered_map<t_docId, Shape, std::hash<t_docId>, std::equal_to<t_docId>, rm_allocator<std::pair<const t_docId, Shape>>>;
   
   
   rtree_internal rtree_;
@@ -29,17 +29,17 @@ struct RTree {
   explicit RTree(rtree_internal const& rt) noexcept : rtree_{rt} {
   }
 
-  [[nodiscard]] std::optional<std::reference_wrapper<const RTDoc::poly_type>> lookup(t_docId id) const {
+  [[nodiscard]] std::optional<std::reference_wrapper<const Shape>> lookup(t_docId id) const {
     if (auto it = docLookup_.find(id); it != docLookup_.end()) {
       return it->second;
     }
     return {};
   }
 
-  void insert(const RTDoc::poly_type& poly, t_docId id) {
-    RTDoc doc{poly, id};
+  void insert(const Shape& shape, t_docId id) {
+    RTDoc doc{shape, id};
     rtree_.insert(doc);
-    docLookup_.insert({id, poly});
+    docLookup_.insert({id, shape});
   }
 
   bool remove(t_docId id) {
@@ -60,11 +60,8 @@ struct RTree {
     return rtree_.remove(doc);
   }
 
-  [[nodiscard]] static string geometry_to_string(const RTDoc::poly_type& geometry) {
-    using sstream = std::basic_stringstream<char, std::char_traits<char>, rm_allocator<char>>;
-    sstream ss{};
-    ss << bg::wkt(geometry);
-    return ss.str();
+  [[nodiscard]] static string geometry_to_string(const Shape& geometry) {
+    return geometry.to_string();
   }
 
   void dump(RedisModuleCtx* ctx) const {
@@ -137,33 +134,51 @@ struct RTree {
 
   using ResultsVec = std::vector<RTDoc, rm_allocator<RTDoc>>;
 
-	[[nodiscard]] ResultsVec query(RTDoc const& queryDoc, QueryType queryType, const RTDoc::poly_type& queryGeometry) const {
-		switch (queryType) {
-		 case QueryType::CONTAINS:
-			return contains(queryDoc, queryGeometry);
-		 case QueryType::WITHIN:
-			return within(queryDoc, queryGeometry);
-		 default:
-			return {};
-		}
-	}
-
-	[[nodiscard]] ResultsVec contains(RTDoc const& queryDoc, const RTDoc::poly_type& queryGeometry) const {
-		auto results = query(bgi::contains(queryDoc.rect_));
-		std::erase_if(results, [&](auto const& doc) {
-      auto geometry = lookup(doc.id());
-      return geometry && !bg::within(queryGeometry, geometry.value().get());
-		});
-		return results;
-	}
-	[[nodiscard]] ResultsVec within(RTDoc const& queryDoc, const RTDoc::poly_type& queryGeometry) const {
-		auto results = query(bgi::within(queryDoc.rect_));
-		std::erase_if(results, [&](auto const& doc) {
+  [[nodiscard]] ResultsVec query(RTDoc const& queryDoc, QueryType queryType, const Shape& queryGeometry, double distance) const {
+    ResultsVec results{};
+    switch (queryType) {
+     case QueryType::CONTAINS:
+      results = query(bgi::contains(queryDoc.rect_));
+      break;
+     case QueryType::WITHIN:
+      results = query(bgi::within(queryDoc.rect_));
+      break;
+     case QueryType::INTERSECTS:
+      results = query(bgi::intersects(queryDoc.rect_));
+      break;
+     case QueryType::DISJOINT: {
+      // The complement of INTERSECTS: the documents whose bounding box is disjoint from the one of
+      // the query, and the ones whose bounding box intersects it but whose geometry doesn't.
+      // Only the latter are checked, but the results are still linear in the size of the index.
+      results = query(bgi::intersects(queryDoc.rect_));
+      std::erase_if(results, [&](auto const& doc) {
+        auto geometry = lookup(doc.id());
+        return geometry && !geometry.value().get().matches(queryType, queryGeometry, distance);
+      });
+      auto outside = query(bgi::disjoint(queryDoc.rect_));
+      results.insert(results.end(), outside.begin(), outside.end());
+      return results;
+     }
+     case QueryType::DWITHIN:
+      results = query(bgi::intersects(expand(queryDoc.rect_, distance)));
+      break;
+     default:
+      return {};
+    }
+    std::erase_if(results, [&](auto const& doc) {
       auto geometry = lookup(doc.id());
-			return geometry && !bg::within(geometry.value().get(), queryGeometry);
-		});
-		return results;
-	}
+      return geometry && !geometry.value().get().matches(queryType, queryGeometry, distance);
+    });
+    return results;
+  }
+
+  [[nodiscard]] static RTDoc::rect_internal expand(RTDoc::rect_internal const& rect, double distance) {
+    auto const& min = rect.min_corner();
+    auto const& max = rect.max_corner();
+    return RTDoc::rect_internal{
+      RTDoc::point_type{bg::get<0>(min) - distance, bg::get<1>(min) - distance},
+      RTDoc::point_type{bg::get<0>(max) + distance, bg::get<1>(max) + distance}};
+  }
 
   template <typename Predicate>
   [[nodiscard]] ResultsVec query(Predicate p) const {
@@ -0,0 +1,300 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "s2index.hpp"
+
+#include "s2/s2boolean_operation.h"
+#include "s2/s2closest_edge_query.h"
+#include "s2/s2earth.h"
+#include "s2/s2error.h"
+#include "s2/s2latlng.h"
+#include "s2/s2loop.h"
+#include "s2/s2point_vector_shape.h"
+
+#include <algorithm>
+#include <stdexcept>
+#include <string>
+
+namespace {
+
+[[nodiscard]] S2Point to_s2(Shape::point_type const& point) {
+  auto latlng = S2LatLng::FromDegrees(bg::get<1>(point), bg::get<0>(point));
+  if (!latlng.is_valid()) {
+    throw std::runtime_error{"Invalid coordinates (" + std::to_string(bg::get<0>(point)) + " " +
+                             std::to_string(bg::get<1>(point)) + ")"};
+  }
+  return latlng.ToPoint();
+}
+
+template <typename Range>
+[[nodiscard]] std::vector<S2Point> to_s2_points(Range const& range) {
+  std::vector<S2Point> points{};
+  points.reserve(bg::num_points(range));
+  std::ranges::transform(range, std::back_inserter(points), to_s2);
+  return points;
+}
+
+template <typename Object>
+void validate(Object const& object, const char* what) {
+  S2Error error{};
+  if (object.FindValidationError(&error)) {
+    throw std::runtime_error{std::string{"Invalid "} + what + ": " + error.text()};
+  }
+}
+
+[[nodiscard]] std::unique_ptr<S2Loop> to_s2_loop(Shape::polygon_internal::ring_type const& ring) {
+  auto vertices = to_s2_points(ring);
+  // WKT and GeoJSON rings repeat their first point at their end, S2 loops don't
+  if (vertices.size() > 1 && vertices.front() == vertices.back()) {
+    vertices.pop_back();
+  }
+  auto loop = std::make_unique<S2Loop>(vertices, S2Debug::DISABLE);
+  validate(*loop, "polygon");
+  // The order of the points doesn't tell which side of a ring is inside on the sphere,
+  // so the interior is the smaller of the two regions it bounds
+  loop->Normalize();
+  return loop;
+}
+
+[[nodiscard]] std::unique_ptr<S2Polygon> to_s2_polygon(Shape::polygon_internal const& polygon) {
+  std::vector<std::unique_ptr<S2Loop>> loops{};
+  loops.push_back(to_s2_loop(polygon.outer()));
+  for (auto const& inner : polygon.inners()) {
+    loops.push_back(to_s2_loop(inner));
+  }
+  auto s2polygon = std::make_unique<S2Polygon>();
+  s2polygon->set_s2debug_override(S2Debug::DISABLE);
+  s2polygon->InitNested(std::move(loops));
+  validate(*s2polygon, "polygon");
+  return s2polygon;
+}
+
+[[nodiscard]] std::unique_ptr<S2Polyline> to_s2_polyline(Shape::linestring_internal const& line) {
+  auto polyline = std::make_unique<S2Polyline>(to_s2_points(line), S2Debug::DISABLE);
+  validate(*polyline, "linestring");
+  return polyline;
+}
+
+// Points and lines on the boundary of a polygon are considered to be in it
+[[nodiscard]] S2BooleanOperation::Options closed_options() {
+  S2BooleanOperation::Options options{};
+  options.set_polygon_model(S2BooleanOperation::PolygonModel::CLOSED);
+  options.set_polyline_model(S2BooleanOperation::PolylineModel::CLOSED);
+  return options;
+}
+
+[[nodiscard]] S1ChordAngle meters_to_angle(double meters) {
+  return S1ChordAngle{S2Earth::MetersToAngle(meters)};
+}
+
+}  // anonymous namespace
+
+S2Geometry::S2Geometry(Shape const& shape) : str_{shape.to_string()} {
+  for (auto const& member : shape.geometries_) {
+    std::visit(
+        [this](auto const& geometry) {
+          using T = std::decay_t<decltype(geometry)>;
+          if constexpr (std::is_same_v<T, Shape::point_type>) {
+            points_.push_back(to_s2(geometry));
+          } else if constexpr (std::is_same_v<T, Shape::linestring_internal>) {
+            polylines_.push_back(to_s2_polyline(geometry));
+          } else if constexpr (std::is_same_v<T, Shape::polygon_internal>) {
+            polygons_.push_back(to_s2_polygon(geometry));
+          } else {
+            for (auto const& polygon : geometry) {
+              polygons_.push_back(to_s2_polygon(polygon));
+            }
+          }
+        },
+        member);
+  }
+  add_to(index_);
+}
+
+std::unique_ptr<S2Geometry> S2Geometry::from_str(GEOMETRY_FORMAT format, std::string_view str) {
+  return std::make_unique<S2Geometry>(Shape::from_str(format, str));
+}
+
+ShapeIds S2Geometry::add_to(MutableS2ShapeIndex& index) const {
+  ShapeIds ids{};
+  if (!points_.empty()) {
+    ids.push_back(index.Add(std::make_unique<S2PointVectorShape>(points_)));
+  }
+  for (auto const& polyline : polylines_) {
+    ids.push_back(index.Add(std::make_unique<S2Polyline::Shape>(polyline.get())));
+  }
+  for (auto const& polygon : polygons_) {
+    ids.push_back(index.Add(std::make_unique<S2Polygon::Shape>(polygon.get())));
+  }
+  return ids;
+}
+
+bool S2Geometry::matches(QueryType queryType, S2Geometry const& query,
+                         S1ChordAngle distance) const {
+  switch (queryType) {
+    case QueryType::WITHIN:
+      return S2BooleanOperation::Contains(query.index_, index_, closed_options());
+    case QueryType::CONTAINS:
+      return S2BooleanOperation::Contains(index_, query.index_, closed_options());
+    case QueryType::INTERSECTS:
+      return S2BooleanOperation::Intersects(index_, query.index_, closed_options());
+    case QueryType::DISJOINT:
+      return !S2BooleanOperation::Intersects(index_, query.index_, closed_options());
+    case QueryType::DWITHIN: {
+      S2ClosestEdgeQuery closest{&index_};
+      closest.mutable_options()->set_include_interiors(true);
+      S2ClosestEdgeQuery::ShapeIndexTarget target{&query.index_};
+      target.set_include_interiors(true);
+      return closest.IsDistanceLessOrEqual(&target, distance);
+    }
+    default:
+      return false;
+  }
+}
+
+void S2Index::insert(std::unique_ptr<S2Geometry> geometry, t_docId id) {
+  remove(id);
+  auto shapeIds = geometry->add_to(index_);
+  for (int shapeId : shapeIds) {
+    if (static_cast<size_t>(shapeId) >= shapeDocs_.size()) {
+      shapeDocs_.resize(shapeId + 1);
+    }
+    shapeDocs_[shapeId] = id;
+  }
+  docs_.emplace(id, Doc{std::move(geometry), std::move(shapeIds)});
+}
+
+bool S2Index::remove(t_docId id) {
+  auto it = docs_.find(id);
+  if (it == docs_.end()) {
+    return false;
+  }
+  // The shapes of the index refer to the geometry, so they are released before it is freed
+  for (int shapeId : it->second.shapeIds_) {
+    index_.Release(shapeId);
+    shapeDocs_[shapeId] = 0;
+  }
+  docs_.erase(it);
+  return true;
+}
+
+GeometryQueryIterator::container S2Index::candidates(S2Geometry const& query,
+                                                     S1ChordAngle distance) const {
+  S2ClosestEdgeQuery::Options options{};
+  options.set_include_interiors(true);
+  options.set_inclusive_max_distance(distance);
+  S2ClosestEdgeQuery closest{&index_, options};
+  S2ClosestEdgeQuery::ShapeIndexTarget target{&query.index_};
+  target.set_include_interiors(true);
+
+  GeometryQueryIterator::container ids{};
+  for (auto const& result : closest.FindClosestEdges(&target)) {
+    ids.push_back(shapeDocs_[result.shape_id()]);
+  }
+  // A document is found once per edge or member close enough to the query
+  std::ranges::sort(ids);
+  auto duplicates = std::ranges::unique(ids);
+  ids.erase(duplicates.begin(), duplicates.end());
+  return ids;
+}
+
+GeometryQueryIterator::container S2Index::query(QueryType queryType, S2Geometry const& query,
+                                                double distance) const {
+  auto maxDistance =
+      queryType == QueryType::DWITHIN ? meters_to_angle(distance) : S1ChordAngle::Zero();
+  // Only documents intersecting the query can match it, and DISJOINT matches all the others
+  auto predicate = queryType == QueryType::DISJOINT ? QueryType::INTERSECTS : queryType;
+  auto ids = candidates(query, maxDistance);
+  std::erase_if(ids, [&](t_docId id) {
+    return !docs_.at(id).geometry_->matches(predicate, query, maxDistance);
+  });
+  if (queryType != QueryType::DISJOINT) {
+    return ids;
+  }
+
+  GeometryQueryIterator::container disjoint{};
+  for (auto const& [id, doc] : docs_) {
+    if (!std::ranges::binary_search(ids, id)) {
+      disjoint.push_back(id);
+    }
+  }
+  return disjoint;
+}
+
+void S2Index::dump(RedisModuleCtx* ctx) const {
+  size_t lenTop = 0;
+  RedisModule_ReplyWithArray(ctx, REDISMODULE_POSTPONED_ARRAY_LEN);
+
+  RedisModule_ReplyWithStringBuffer(ctx, "type", strlen("type"));
+  RedisModule_ReplyWithStringBuffer(ctx, "s2", strlen("s2"));
+  lenTop += 2;
+
+  RedisModule_ReplyWithStringBuffer(ctx, "ptr", strlen("ptr"));
+  char addr[1024] = {0};
+  sprintf(addr, "%p", &index_);
+  RedisModule_ReplyWithStringBuffer(ctx, addr, strlen(addr));
+  lenTop += 2;
+
+  RedisModule_ReplyWithStringBuffer(ctx, "num_docs", strlen("num_docs"));
+  RedisModule_ReplyWithLongLong(ctx, (long long)docs_.size());
+  lenTop += 2;
+
+  RedisModule_ReplyWithStringBuffer(ctx, "docs", strlen("docs"));
+  RedisModule_ReplyWithArray(ctx, docs_.size());
+  lenTop += 2;
+  for (auto const& [id, doc] : docs_) {
+    RedisModule_ReplyWithArray(ctx, 4);
+    RedisModule_ReplyWithStringBuffer(ctx, "id", strlen("id"));
+    RedisModule_ReplyWithLongLong(ctx, id);
+    RedisModule_ReplyWithStringBuffer(ctx, "geometry", strlen("geometry"));
+    RedisModule_ReplyWithStringBuffer(ctx, doc.geometry_->str_.data(), doc.geometry_->str_.size());
+  }
+
+  RedisModule_ReplySetArrayLength(ctx, lenTop);
+}
+
+S2Index *S2Index_New() {
+  return new S2Index{};
+}
+
+void S2Index_Free(S2Index *index) noexcept {
+  delete index;
+}
+
+int S2Index_Insert(S2Index *index, GEOMETRY_FORMAT format, const char *str, size_t len, t_docId id, RedisModuleString **err_msg) {
+  try {
+    index->insert(S2Geometry::from_str(format, std::string_view{str, len}), id);
+    return 0;
+  } catch (const std::exception &e) {
+    if (err_msg)
+      *err_msg = RedisModule_CreateString(nullptr, e.what(), strlen(e.what()));
+    return 1;
+  }
+}
+
+bool S2Index_RemoveByDocId(S2Index *index, t_docId id) {
+  return index->remove(id);
+}
+
+void S2Index_Dump(S2Index *index, RedisModuleCtx *ctx) {
+  index->dump(ctx);
+}
+
+size_t S2Index_Size(S2Index const *index) noexcept {
+  return index->size();
+}
+
+IndexIterator *S2Index_Query_Str(S2Index const *index, GEOMETRY_FORMAT format, const char *str, size_t len, enum QueryType queryType, double distance, RedisModuleString **err_msg) {
+  try {
+    auto geometry = S2Geometry::from_str(format, std::string_view{str, len});
+    auto gqi = new GeometryQueryIterator(index->query(queryType, *geometry, distance));
+    return gqi->base();
+  } catch (const std::exception &e) {
+    if (err_msg)
+      *err_msg = RedisModule_CreateString(nullptr, e.what(), strlen(e.what()));
+    return nullptr;
+  }
+}
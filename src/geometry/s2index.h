@@ -0,0 +1,35 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include <stddef.h>
+#include <stdbool.h>
+#include "index_iterator.h"
+#include "rtdoc.h"
+#include "geometry_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+struct S2Index;
+struct RedisModuleCtx;
+
+NODISCARD struct S2Index *S2Index_New();
+void S2Index_Free(struct S2Index *index) NOEXCEPT;
+int S2Index_Insert(struct S2Index *index, GEOMETRY_FORMAT format, const char *str, size_t len, t_docId id, RedisModuleString **err_msg);
+bool S2Index_RemoveByDocId(struct S2Index *index, t_docId id);
+void S2Index_Dump(struct S2Index *index, RedisModuleCtx *ctx);
+NODISCARD size_t S2Index_Size(struct S2Index const *index) NOEXCEPT;
+
+// `distance` is in meters, and is only used by DWITHIN queries
+// Caller should free the returned err_msg
+NODISCARD IndexIterator *S2Index_Query_Str(struct S2Index const *index, GEOMETRY_FORMAT format, const char *str, size_t len, enum QueryType queryType, double distance, RedisModuleString **err_msg);
+
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,102 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "allocator.hpp"
+#include "shape.hpp"
+#include "query_iterator.hpp"
+#include "s2index.h"
+
+#include "s2/mutable_s2shape_index.h"
+#include "s2/s1chord_angle.h"
+#include "s2/s2polygon.h"
+#include "s2/s2polyline.h"
+
+#include <memory>
+#include <unordered_map>
+#include <vector>
+
+using ShapeIds = std::vector<int, rm_allocator<int>>;
+
+// A shape on the sphere. Coordinates are `{lon} {lat}` in degrees, and edges are geodesics.
+struct S2Geometry {
+  std::vector<S2Point> points_;
+  std::vector<std::unique_ptr<S2Polyline>> polylines_;
+  std::vector<std::unique_ptr<S2Polygon>> polygons_;
+  // The members of the geometry, used to evaluate the predicates against it
+  MutableS2ShapeIndex index_;
+  // The geometry as it was given, for dumping the index
+  Shape::string str_;
+
+  explicit S2Geometry(Shape const& shape);
+  S2Geometry(S2Geometry const&) = delete;
+  S2Geometry& operator=(S2Geometry const&) = delete;
+
+  [[nodiscard]] static std::unique_ptr<S2Geometry> from_str(GEOMETRY_FORMAT format,
+                                                            std::string_view str);
+
+  // Add the members of the geometry to `index`, without transferring their ownership.
+  // Returns the ids of the added shapes.
+  ShapeIds add_to(MutableS2ShapeIndex& index) const;
+
+  // Whether the geometry matches the predicate against the query geometry
+  [[nodiscard]] bool matches(QueryType queryType, S2Geometry const& query,
+                             S1ChordAngle distance) const;
+
+  using Self = S2Geometry;
+  [[nodiscard]] void* operator new(std::size_t) {
+    return rm_allocator<Self>().allocate(1);
+  }
+  void operator delete(void* p) noexcept {
+    rm_allocator<Self>().deallocate(static_cast<Self*>(p), 1);
+  }
+};
+
+struct S2Index {
+  struct Doc {
+    std::unique_ptr<S2Geometry> geometry_;
+    ShapeIds shapeIds_;
+  };
+  using docs_internal = std::unordered_map<t_docId, Doc, std::hash<t_docId>, std::equal_to<t_docId>,
+                                           rm_allocator<std::pair<const t_docId, Doc>>>;
+
+  // The members of the geometries of all the documents
+  MutableS2ShapeIndex index_;
+  // The document of each shape id of `index_`
+  std::vector<t_docId, rm_allocator<t_docId>> shapeDocs_;
+  docs_internal docs_;
+
+  explicit S2Index() = default;
+  S2Index(S2Index const&) = delete;
+  S2Index& operator=(S2Index const&) = delete;
+
+  void insert(std::unique_ptr<S2Geometry> geometry, t_docId id);
+  bool remove(t_docId id);
+
+  [[nodiscard]] size_t size() const noexcept {
+    return docs_.size();
+  }
+
+  void dump(RedisModuleCtx* ctx) const;
+
+  // `distance` is in meters, and is only used by DWITHIN queries
+  [[nodiscard]] GeometryQueryIterator::container query(QueryType queryType, S2Geometry const& query,
+                                                       double distance) const;
+
+  using Self = S2Index;
+  [[nodiscard]] void* operator new(std::size_t) {
+    return rm_allocator<Self>().allocate(1);
+  }
+  void operator delete(void* p) noexcept {
+    rm_allocator<Self>().deallocate(static_cast<Self*>(p), 1);
+  }
+
+ private:
+  // The documents which are within `distance` of the query, including the ones intersecting it
+  [[nodiscard]] GeometryQueryIterator::container candidates(S2Geometry const& query,
+                                                            S1ChordAngle distance) const;
+};
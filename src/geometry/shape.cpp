@@ -0,0 +1,275 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "shape.hpp"
+#include "geojson.hpp"
+
+#include <algorithm>
+#include <cctype>
+#include <limits>
+#include <sstream>
+#include <stdexcept>
+
+namespace {
+
+using point_type = Shape::point_type;
+using linestring_internal = Shape::linestring_internal;
+using polygon_internal = Shape::polygon_internal;
+using multipolygon_internal = Shape::multipolygon_internal;
+using geometry_internal = Shape::geometry_internal;
+
+constexpr std::string_view COLLECTION_WKT = "GEOMETRYCOLLECTION";
+
+[[nodiscard]] std::string_view trim(std::string_view sv) noexcept {
+  while (!sv.empty() && std::isspace(static_cast<unsigned char>(sv.front()))) sv.remove_prefix(1);
+  while (!sv.empty() && std::isspace(static_cast<unsigned char>(sv.back()))) sv.remove_suffix(1);
+  return sv;
+}
+
+// The geometry type of a WKT string, e.g. `POLYGON` for `POLYGON((1 1, ...))`
+[[nodiscard]] std::string wkt_type(std::string_view wkt) {
+  std::string type{};
+  for (char c : wkt) {
+    if (!std::isalpha(static_cast<unsigned char>(c))) break;
+    type.push_back(static_cast<char>(std::toupper(static_cast<unsigned char>(c))));
+  }
+  return type;
+}
+
+template <typename Geometry>
+[[nodiscard]] Geometry read_wkt(std::string_view wkt) {
+  Geometry geometry{};
+  bg::read_wkt(std::string{wkt}, geometry);
+  if constexpr (std::is_same_v<Geometry, polygon_internal> ||
+                std::is_same_v<Geometry, multipolygon_internal>) {
+    // Fix the orientation and closure of the rings
+    bg::correct(geometry);
+  }
+  return geometry;
+}
+
+void append_wkt(Shape& shape, std::string_view wkt) {
+  wkt = trim(wkt);
+  auto type = wkt_type(wkt);
+  if (type == "POINT") {
+    shape.geometries_.emplace_back(read_wkt<point_type>(wkt));
+  } else if (type == "LINESTRING") {
+    shape.geometries_.emplace_back(read_wkt<linestring_internal>(wkt));
+  } else if (type == "POLYGON") {
+    shape.geometries_.emplace_back(read_wkt<polygon_internal>(wkt));
+  } else if (type == "MULTIPOLYGON") {
+    shape.geometries_.emplace_back(read_wkt<multipolygon_internal>(wkt));
+  } else if (type == COLLECTION_WKT) {
+    // Members of nested collections are flattened into the shape
+    shape.is_collection_ = true;
+    auto members = trim(wkt.substr(COLLECTION_WKT.size()));
+    if (members.size() < 2 || members.front() != '(' || members.back() != ')') {
+      throw std::runtime_error{"Invalid GEOMETRYCOLLECTION"};
+    }
+    members = members.substr(1, members.size() - 2);
+    int depth = 0;
+    std::size_t begin = 0;
+    for (std::size_t i = 0; i <= members.size(); ++i) {
+      if (i == members.size() || (members[i] == ',' && depth == 0)) {
+        append_wkt(shape, members.substr(begin, i - begin));
+        begin = i + 1;
+      } else if (members[i] == '(') {
+        ++depth;
+      } else if (members[i] == ')') {
+        --depth;
+      }
+    }
+  } else {
+    throw std::runtime_error{"Unsupported geometry type `" + type + "`"};
+  }
+}
+
+[[nodiscard]] geojson::Value const& geojson_member(geojson::Value const& object,
+                                                   std::string_view key,
+                                                   geojson::Value::Kind kind) {
+  auto value = object.get(key);
+  if (!value || !value->is(kind)) {
+    throw std::runtime_error{"Invalid GeoJSON: missing or invalid `" + std::string{key} + "`"};
+  }
+  return *value;
+}
+
+[[nodiscard]] point_type geojson_point(geojson::Value const& coords) {
+  if (!coords.is(geojson::Value::Kind::Array) || coords.elements_.size() < 2 ||
+      !coords.elements_[0].is(geojson::Value::Kind::Number) ||
+      !coords.elements_[1].is(geojson::Value::Kind::Number)) {
+    throw std::runtime_error{"Invalid GeoJSON: a position must be an array of numbers"};
+  }
+  return point_type{coords.elements_[0].number_, coords.elements_[1].number_};
+}
+
+template <typename Range>
+[[nodiscard]] Range geojson_points(geojson::Value const& coords) {
+  if (!coords.is(geojson::Value::Kind::Array)) {
+    throw std::runtime_error{"Invalid GeoJSON: expected an array of positions"};
+  }
+  Range range{};
+  for (auto const& position : coords.elements_) {
+    bg::append(range, geojson_point(position));
+  }
+  return range;
+}
+
+[[nodiscard]] polygon_internal geojson_polygon(geojson::Value const& coords) {
+  if (!coords.is(geojson::Value::Kind::Array) || coords.elements_.empty()) {
+    throw std::runtime_error{"Invalid GeoJSON: expected an array of rings"};
+  }
+  polygon_internal polygon{};
+  polygon.outer() = geojson_points<polygon_internal::ring_type>(coords.elements_[0]);
+  for (std::size_t i = 1; i < coords.elements_.size(); ++i) {
+    polygon.inners().push_back(geojson_points<polygon_internal::ring_type>(coords.elements_[i]));
+  }
+  bg::correct(polygon);
+  return polygon;
+}
+
+void append_geojson(Shape& shape, geojson::Value const& object) {
+  if (!object.is(geojson::Value::Kind::Object)) {
+    throw std::runtime_error{"Invalid GeoJSON: expected a geometry object"};
+  }
+  auto const& type = geojson_member(object, "type", geojson::Value::Kind::String).string_;
+  if (type == "GeometryCollection") {
+    shape.is_collection_ = true;
+    for (auto const& member :
+         geojson_member(object, "geometries", geojson::Value::Kind::Array).elements_) {
+      append_geojson(shape, member);
+    }
+    return;
+  }
+
+  auto const& coords = geojson_member(object, "coordinates", geojson::Value::Kind::Array);
+  if (type == "Point") {
+    shape.geometries_.emplace_back(geojson_point(coords));
+  } else if (type == "LineString") {
+    shape.geometries_.emplace_back(geojson_points<linestring_internal>(coords));
+  } else if (type == "Polygon") {
+    shape.geometries_.emplace_back(geojson_polygon(coords));
+  } else if (type == "MultiPolygon") {
+    multipolygon_internal multipolygon{};
+    for (auto const& polygon : coords.elements_) {
+      multipolygon.push_back(geojson_polygon(polygon));
+    }
+    shape.geometries_.emplace_back(std::move(multipolygon));
+  } else {
+    throw std::runtime_error{"Unsupported GeoJSON type `" + type + "`"};
+  }
+}
+
+[[nodiscard]] bool member_intersects(geometry_internal const& lhs, geometry_internal const& rhs) {
+  return std::visit([](auto const& a, auto const& b) { return bg::intersects(a, b); }, lhs, rhs);
+}
+
+[[nodiscard]] bool member_within(geometry_internal const& lhs, geometry_internal const& rhs) {
+  // The DE-9IM definition of within, which unlike bg::within is defined for any two geometries
+  static const bg::de9im::mask within_mask{"T*F**F***"};
+  return std::visit([](auto const& a, auto const& b) { return bg::relate(a, b, within_mask); },
+                    lhs, rhs);
+}
+
+[[nodiscard]] double member_distance(geometry_internal const& lhs, geometry_internal const& rhs) {
+  return std::visit([](auto const& a, auto const& b) { return double(bg::distance(a, b)); }, lhs,
+                    rhs);
+}
+
+}  // anonymous namespace
+
+Shape Shape::from_wkt(std::string_view wkt) {
+  Shape shape{};
+  append_wkt(shape, wkt);
+  return shape;
+}
+
+Shape Shape::from_geojson(std::string_view json) {
+  Shape shape{};
+  append_geojson(shape, geojson::parse(json));
+  return shape;
+}
+
+Shape Shape::from_str(GEOMETRY_FORMAT format, std::string_view str) {
+  switch (format) {
+    case GEOMETRY_FORMAT_WKT:
+      return from_wkt(str);
+    case GEOMETRY_FORMAT_GEOJSON:
+      return from_geojson(str);
+    default:
+      throw std::runtime_error{"Unknown geometry format"};
+  }
+}
+
+Shape::rect_internal Shape::envelope() const {
+  rect_internal rect{};
+  bg::assign_inverse(rect);
+  for (auto const& geometry : geometries_) {
+    bg::expand(rect, std::visit(
+                         [](auto const& g) { return bg::return_envelope<rect_internal>(g); },
+                         geometry));
+  }
+  return rect;
+}
+
+Shape::string Shape::to_string() const {
+  using sstream = std::basic_stringstream<char, std::char_traits<char>, rm_allocator<char>>;
+  sstream ss{};
+  if (is_collection_) {
+    ss << COLLECTION_WKT << '(';
+  }
+  for (std::size_t i = 0; i < geometries_.size(); ++i) {
+    if (i > 0) {
+      ss << ',';
+    }
+    std::visit([&ss](auto const& g) { ss << bg::wkt(g); }, geometries_[i]);
+  }
+  if (is_collection_) {
+    ss << ')';
+  }
+  return ss.str();
+}
+
+bool Shape::intersects(Shape const& other) const {
+  return std::ranges::any_of(geometries_, [&](auto const& lhs) {
+    return std::ranges::any_of(other.geometries_,
+                               [&](auto const& rhs) { return member_intersects(lhs, rhs); });
+  });
+}
+
+bool Shape::within(Shape const& other) const {
+  return !geometries_.empty() && std::ranges::all_of(geometries_, [&](auto const& lhs) {
+    return std::ranges::any_of(other.geometries_,
+                               [&](auto const& rhs) { return member_within(lhs, rhs); });
+  });
+}
+
+double Shape::distance(Shape const& other) const {
+  double min = std::numeric_limits<double>::infinity();
+  for (auto const& lhs : geometries_) {
+    for (auto const& rhs : other.geometries_) {
+      min = std::min(min, member_distance(lhs, rhs));
+    }
+  }
+  return min;
+}
+
+bool Shape::matches(QueryType queryType, Shape const& query, double maxDistance) const {
+  switch (queryType) {
+    case QueryType::WITHIN:
+      return within(query);
+    case QueryType::CONTAINS:
+      return query.within(*this);
+    case QueryType::INTERSECTS:
+      return intersects(query);
+    case QueryType::DISJOINT:
+      return !intersects(query);
+    case QueryType::DWITHIN:
+      return distance(query) <= maxDistance;
+    default:
+      return false;
+  }
+}
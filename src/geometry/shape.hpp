@@ -0,0 +1,51 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "polygon.hpp"
+#include "geometry_types.h"
+
+#include <string_view>
+#include <variant>
+#include <vector>
+
+namespace bg = boost::geometry;
+namespace bgm = bg::model;
+
+// A geometry of a document or a query: a POINT, LINESTRING, POLYGON or MULTIPOLYGON,
+// or a GEOMETRYCOLLECTION of those.
+struct Shape {
+  using point_type = Point::point_internal;
+  using linestring_internal = bgm::linestring<point_type, std::vector, rm_allocator>;
+  using polygon_internal = Polygon::polygon_internal;
+  using multipolygon_internal = bgm::multi_polygon<polygon_internal, std::vector, rm_allocator>;
+  using geometry_internal =
+      std::variant<point_type, linestring_internal, polygon_internal, multipolygon_internal>;
+  using rect_internal = bgm::box<point_type>;
+  using container = std::vector<geometry_internal, rm_allocator<geometry_internal>>;
+  using string = std::basic_string<char, std::char_traits<char>, rm_allocator<char>>;
+
+  // A single geometry, or the members of a collection
+  container geometries_;
+  bool is_collection_ = false;
+
+  [[nodiscard]] static Shape from_wkt(std::string_view wkt);
+  [[nodiscard]] static Shape from_geojson(std::string_view json);
+  [[nodiscard]] static Shape from_str(GEOMETRY_FORMAT format, std::string_view str);
+
+  [[nodiscard]] rect_internal envelope() const;
+  [[nodiscard]] string to_string() const;
+
+  [[nodiscard]] bool intersects(Shape const& other) const;
+  // Whether each member of the shape is within a member of `other`
+  [[nodiscard]] bool within(Shape const& other) const;
+  // The minimal distance between the members of the shapes
+  [[nodiscard]] double distance(Shape const& other) const;
+
+  // Whether the shape matches the predicate against the query shape
+  [[nodiscard]] bool matches(QueryType queryType, Shape const& query, double distance) const;
+};
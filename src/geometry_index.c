@@ -9,10 +9,20 @@
 #include "rmalloc.h"
 #include "field_spec.h"
 
+#include <ctype.h>
+
 void GeometryQuery_Free(GeometryQuery *geomq) {
     rm_free(geomq);
 }
 
+GEOMETRY_FORMAT Geometry_DetectFormat(const char *str, size_t len) {
+  while (len && isspace(*str)) {
+    ++str;
+    --len;
+  }
+  return (len && *str == '{') ? GEOMETRY_FORMAT_GEOJSON : GEOMETRY_FORMAT_WKT;
+}
+
 RedisModuleType *GeometryIndexType = NULL;
 #define GEOMETRYINDEX_KEY_FMT "gm:%s/%s"
 
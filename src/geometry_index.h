@@ -18,10 +18,14 @@ typedef struct GeometryQuery {
     const char *str;
     size_t str_len;
     enum QueryType query_type;
+    double distance;  // The distance of DWITHIN queries
 } GeometryQuery;
 
 void GeometryQuery_Free(GeometryQuery *geomq);
 
+// GeoJSON geometries are objects, any other string is read as WKT
+GEOMETRY_FORMAT Geometry_DetectFormat(const char *str, size_t len);
+
 GeometryIndex *OpenGeometryIndex(RedisModuleCtx *redisCtx, IndexSpec *spec,
                                  RedisModuleKey **idxKey, const FieldSpec *fs);
 
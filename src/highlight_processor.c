@@ -54,6 +54,15 @@ static int fragmentizeOffsets(const RLookup *lookup, const char *fieldName, cons
     return 0;
   }
 
+  // The terms of normalized fields don't have the length of the text they were normalized from,
+  // which is shown as is
+  if (FieldSpec_IsAsciiFold(fs) || FieldSpec_IsNFKC(fs)) {
+    options &= ~FRAGMENTIZE_TOKLEN_EXACT;
+  }
+  if (FieldSpec_IsNFKC(fs)) {
+    options |= FRAGMENTIZE_NFKC;
+  }
+
   int rc = 0;
   RSOffsetIterator offsIter = RSIndexResult_IterateOffsets(indexResult);
   FragmentTermIterator fragIter = {NULL};
@@ -352,7 +361,8 @@ static void hlpFree(ResultProcessor *p) {
 ResultProcessor *RPHighlighter_New(const RSSearchOptions *searchopts, const FieldList *fields,
                                    const RLookup *lookup) {
   HlpProcessor *hlp = rm_calloc(1, sizeof(*hlp));
-  if (searchopts->language == RS_LANG_CHINESE) {
+  if (searchopts->language == RS_LANG_CHINESE || searchopts->language == RS_LANG_JAPANESE ||
+      searchopts->language == RS_LANG_KOREAN) {
     hlp->fragmentizeOptions = FRAGMENTIZE_TOKLEN_EXACT;
   }
   hlp->base.Next = hlpNext;
@@ -0,0 +1,375 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "hybrid_fusion.h"
+#include "redismodule.h"
+#include "rmalloc.h"
+#include "util/arr.h"
+#include "util/minmax_heap.h"
+
+int FusionOptions_Parse(FusionOptions *opts, ArgsCursor *ac, QueryError *status) {
+  if (opts->method != FUSION_NONE) {
+    QERR_MKBADARGS_FMT(status, "Multiple FUSION clauses are not allowed");
+    return REDISMODULE_ERR;
+  }
+
+  ArgsCursor subArgs = {0};
+  if (AC_GetVarArgs(ac, &subArgs) != AC_OK) {
+    QERR_MKBADARGS_FMT(status, "Bad arguments for FUSION");
+    return REDISMODULE_ERR;
+  }
+
+  if (AC_AdvanceIfMatch(&subArgs, "RRF")) {
+    opts->method = FUSION_RRF;
+    opts->rrfConstant = FUSION_DEFAULT_RRF_CONSTANT;
+  } else if (AC_AdvanceIfMatch(&subArgs, "LINEAR")) {
+    opts->method = FUSION_LINEAR;
+    if (AC_GetDouble(&subArgs, &opts->lexicalWeight, AC_F_GE0) != AC_OK ||
+        AC_GetDouble(&subArgs, &opts->vectorWeight, AC_F_GE0) != AC_OK) {
+      QERR_MKBADARGS_FMT(status, "LINEAR fusion requires two non negative weights");
+      return REDISMODULE_ERR;
+    }
+  } else {
+    QERR_MKBADARGS_FMT(status, "FUSION method must be RRF or LINEAR");
+    return REDISMODULE_ERR;
+  }
+
+  while (!AC_IsAtEnd(&subArgs)) {
+    if (opts->method == FUSION_RRF && AC_AdvanceIfMatch(&subArgs, "CONSTANT")) {
+      if (AC_GetDouble(&subArgs, &opts->rrfConstant, AC_F_GE0) != AC_OK) {
+        QERR_MKBADARGS_FMT(status, "FUSION CONSTANT requires a non negative number");
+        return REDISMODULE_ERR;
+      }
+    } else if (AC_AdvanceIfMatch(&subArgs, "WINDOW")) {
+      if (AC_GetSize(&subArgs, &opts->lexicalWindow, AC_F_GE1) != AC_OK ||
+          AC_GetSize(&subArgs, &opts->vectorWindow, AC_F_GE1) != AC_OK) {
+        QERR_MKBADARGS_FMT(status, "FUSION WINDOW requires two positive integers");
+        return REDISMODULE_ERR;
+      }
+    } else {
+      const char *s = AC_GetStringNC(&subArgs, NULL);
+      QERR_MKBADARGS_FMT(status, "Unknown argument `%s` in FUSION", s);
+      return REDISMODULE_ERR;
+    }
+  }
+  return REDISMODULE_OK;
+}
+
+/*******************************************************************************************************************
+ *  Fusion scoring
+ *
+ * Each branch ranks its own members: the lexical branch by descending score and the vector
+ * branch by ascending distance. Only the top `window` members of a branch contribute to the
+ * fused score, and a document outside the windows of both branches is not fused at all.
+ *******************************************************************************************************************/
+
+typedef struct {
+  FusionCandidate *cand;
+  double value;  // higher is better
+} rankEntry;
+
+static int cmpRankEntries(const void *p1, const void *p2) {
+  const rankEntry *e1 = p1, *e2 = p2;
+  if (e1->value > e2->value) {
+    return -1;
+  } else if (e1->value < e2->value) {
+    return 1;
+  }
+  // keep the original order of tied members
+  return e1->cand < e2->cand ? -1 : 1;
+}
+
+static void fuseBranch(const FusionOptions *opts, rankEntry *entries, size_t n, size_t window,
+                       double weight) {
+  qsort(entries, n, sizeof(*entries), cmpRankEntries);
+  if (window && n > window) {
+    n = window;
+  }
+  if (n == 0) {
+    return;
+  }
+
+  double best = entries[0].value, worst = entries[n - 1].value;
+  for (size_t i = 0; i < n; i++) {
+    FusionCandidate *c = entries[i].cand;
+    c->fused = true;
+    if (opts->method == FUSION_RRF) {
+      c->score += 1.0 / (opts->rrfConstant + i + 1);
+    } else {
+      // min-max normalization within the window
+      double norm = best == worst ? 1 : (entries[i].value - worst) / (best - worst);
+      c->score += weight * norm;
+    }
+  }
+}
+
+void HybridFusion_Score(const FusionOptions *opts, FusionCandidate *cands, size_t n) {
+  if (n == 0) {
+    return;
+  }
+  rankEntry *lexical = rm_malloc(n * sizeof(*lexical));
+  rankEntry *vector = rm_malloc(n * sizeof(*vector));
+  size_t nlexical = 0, nvector = 0;
+
+  for (size_t i = 0; i < n; i++) {
+    FusionCandidate *c = cands + i;
+    c->score = 0;
+    c->fused = false;
+    if (c->hasLexical) {
+      lexical[nlexical++] = (rankEntry){.cand = c, .value = c->lexicalScore};
+    }
+    if (c->hasVector) {
+      vector[nvector++] = (rankEntry){.cand = c, .value = -c->distance};
+    }
+  }
+
+  fuseBranch(opts, lexical, nlexical, opts->lexicalWindow, opts->lexicalWeight);
+  fuseBranch(opts, vector, nvector, opts->vectorWindow, opts->vectorWeight);
+
+  rm_free(lexical);
+  rm_free(vector);
+}
+
+/*******************************************************************************************************************
+ *  Hybrid Fusion Processor
+ *
+ * Ranks are only known once all the results are in, so this processor reads every result of
+ * its upstream (the scorer, which gives the lexical score), fuses them and then yields the
+ * fused results by descending fused score. Only the results within the window of a branch can
+ * be fused, so each branch keeps its best `window` members in a heap, and a result which falls
+ * out of the windows of both branches is released right away.
+ *
+ * In fusion mode, the hybrid iterator yields results with two children: the vector distance
+ * first, and the lexical subtree second. A branch that did not return the document is
+ * represented by a placeholder child whose docId is 0.
+ *******************************************************************************************************************/
+
+typedef struct {
+  SearchResult r;
+  FusionCandidate cand;
+  // Whether the result is within the window of the branch, i.e. held by its heap
+  bool inLexical;
+  bool inVector;
+} fusionHit;
+
+typedef struct {
+  ResultProcessor base;
+  FusionOptions opts;
+  const RLookupKey *scoreKey;
+  const RLookupKey *lexicalKey;
+
+  heap_t *lexical;       // The best hits of the lexical branch, the worst one being the minimum
+  heap_t *vector;        // The best hits of the vector branch, the worst one being the minimum
+  fusionHit **results;   // array_*, the fused hits once the upstream is done
+  size_t next;           // next result to yield
+} RPHybridFusion;
+
+static void fillCandidate(FusionCandidate *c, const SearchResult *r) {
+  const RSIndexResult *ir = r->indexResult;
+  *c = (FusionCandidate){.lexicalScore = r->score};
+  if (!ir || ir->type != RSResultType_HybridMetric || ir->agg.numChildren < 2) {
+    c->hasLexical = true;
+    return;
+  }
+  const RSIndexResult *vec = ir->agg.children[0], *lex = ir->agg.children[1];
+  c->hasVector = vec->docId == ir->docId;
+  c->distance = vec->num.value;
+  c->hasLexical = lex->docId == ir->docId;
+}
+
+// Ties are broken like HybridFusion_Score() does: the document read first ranks higher
+static int cmpLexicalHits(const void *p1, const void *p2, const void *udata) {
+  const fusionHit *h1 = p1, *h2 = p2;
+  if (h1->cand.lexicalScore != h2->cand.lexicalScore) {
+    return h1->cand.lexicalScore < h2->cand.lexicalScore ? -1 : 1;
+  }
+  return h1->r.docId > h2->r.docId ? -1 : 1;
+}
+
+static int cmpVectorHits(const void *p1, const void *p2, const void *udata) {
+  const fusionHit *h1 = p1, *h2 = p2;
+  if (h1->cand.distance != h2->cand.distance) {
+    return h1->cand.distance > h2->cand.distance ? -1 : 1;
+  }
+  return h1->r.docId > h2->r.docId ? -1 : 1;
+}
+
+static void fusionHitFree(fusionHit *h) {
+  SearchResult_Destroy(&h->r);
+  rm_free(h);
+}
+
+// Releases a hit which is no longer within any window, and reduces the result count
+static void discardHit(RPHybridFusion *self, fusionHit *h) {
+  if (!h->inLexical && !h->inVector) {
+    fusionHitFree(h);
+    self->base.parent->totalResults--;
+  }
+}
+
+/* Adds the hit to the window of a branch, evicting the worst member if the window is full.
+ * Returns false if the hit is not better than any member of a full window, or the window is empty
+ * (KNN 0). */
+static bool addToWindow(RPHybridFusion *self, heap_t *window, size_t size, fusionHit *h) {
+  if (!size) {
+    return false;
+  } else if (window->count < size) {
+    mmh_insert(window, h);
+    return true;
+  }
+  fusionHit *worst = mmh_peek_min(window);
+  if (window->cmp(h, worst, NULL) <= 0) {
+    return false;
+  }
+  mmh_pop_min(window);
+  mmh_insert(window, h);
+  if (window == self->lexical) {
+    worst->inLexical = false;
+  } else {
+    worst->inVector = false;
+  }
+  discardHit(self, worst);
+  return true;
+}
+
+static void addHit(RPHybridFusion *self, fusionHit *h) {
+  if (h->cand.hasLexical) {
+    h->inLexical = addToWindow(self, self->lexical, self->opts.lexicalWindow, h);
+  }
+  if (h->cand.hasVector) {
+    h->inVector = addToWindow(self, self->vector, self->opts.vectorWindow, h);
+  }
+  discardHit(self, h);
+}
+
+static int cmpHitsById(const void *p1, const void *p2) {
+  const fusionHit *h1 = *(const fusionHit **)p1, *h2 = *(const fusionHit **)p2;
+  return h1->r.docId < h2->r.docId ? -1 : 1;
+}
+
+static int cmpFusedHits(const void *p1, const void *p2) {
+  const fusionHit *h1 = *(const fusionHit **)p1, *h2 = *(const fusionHit **)p2;
+  if (h1->r.score > h2->r.score) {
+    return -1;
+  } else if (h1->r.score < h2->r.score) {
+    return 1;
+  }
+  return h1->r.docId < h2->r.docId ? -1 : 1;
+}
+
+static void fuseResults(RPHybridFusion *self) {
+  // Collect the members of both windows, in the order they were read
+  fusionHit *h;
+  while ((h = mmh_pop_min(self->lexical))) {
+    self->results = array_append(self->results, h);
+  }
+  while ((h = mmh_pop_min(self->vector))) {
+    if (!h->inLexical) {
+      self->results = array_append(self->results, h);
+    }
+  }
+  size_t n = array_len(self->results);
+  qsort(self->results, n, sizeof(*self->results), cmpHitsById);
+
+  // A branch only ranks the members of its window
+  FusionCandidate *cands = rm_malloc(n * sizeof(*cands));
+  for (size_t i = 0; i < n; i++) {
+    cands[i] = self->results[i]->cand;
+    cands[i].hasLexical = self->results[i]->inLexical;
+    cands[i].hasVector = self->results[i]->inVector;
+  }
+  HybridFusion_Score(&self->opts, cands, n);
+
+  for (size_t i = 0; i < n; i++) {
+    SearchResult *r = &self->results[i]->r;
+    r->score = cands[i].score;
+    RLookup_WriteOwnKey(self->scoreKey, &r->rowdata, RS_NumVal(cands[i].score));
+    if (cands[i].hasLexical) {
+      RLookup_WriteOwnKey(self->lexicalKey, &r->rowdata, RS_NumVal(cands[i].lexicalScore));
+    }
+  }
+  rm_free(cands);
+  qsort(self->results, n, sizeof(*self->results), cmpFusedHits);
+}
+
+static int rpFusionNext_Yield(ResultProcessor *rp, SearchResult *r) {
+  RPHybridFusion *self = (RPHybridFusion *)rp;
+  if (self->next >= array_len(self->results)) {
+    return RS_RESULT_EOF;
+  }
+
+  fusionHit *h = self->results[self->next];
+  self->results[self->next++] = NULL;
+  RLookupRow oldrow = r->rowdata;
+  *r = h->r;
+
+  rm_free(h);
+  RLookupRow_Cleanup(&oldrow);
+  return RS_RESULT_OK;
+}
+
+static int rpFusionNext_Accum(ResultProcessor *rp, SearchResult *r) {
+  RPHybridFusion *self = (RPHybridFusion *)rp;
+
+  while (1) {
+    fusionHit *h = rm_calloc(1, sizeof(*h));
+    int rc = rp->upstream->Next(rp->upstream, &h->r);
+    if (rc != RS_RESULT_OK) {
+      fusionHitFree(h);
+      // if our upstream has finished - fuse the results, and change the state to yield
+      if (rc == RS_RESULT_EOF ||
+          (rc == RS_RESULT_TIMEDOUT && rp->parent->timeoutPolicy == TimeoutPolicy_Return)) {
+        fuseResults(self);
+        rp->Next = rpFusionNext_Yield;
+        return rpFusionNext_Yield(rp, r);
+      }
+      return rc;
+    }
+
+    fillCandidate(&h->cand, &h->r);
+    // the index result is owned by the iterator, and is only valid until the next read
+    h->r.indexResult = NULL;
+    addHit(self, h);
+  }
+}
+
+static void rpFusionFree(ResultProcessor *rp) {
+  RPHybridFusion *self = (RPHybridFusion *)rp;
+  fusionHit *h;
+  while ((h = mmh_pop_min(self->lexical))) {
+    h->inLexical = false;
+    if (!h->inVector) {
+      fusionHitFree(h);
+    }
+  }
+  while ((h = mmh_pop_min(self->vector))) {
+    fusionHitFree(h);
+  }
+  mmh_free(self->lexical);
+  mmh_free(self->vector);
+  for (size_t i = 0; i < array_len(self->results); i++) {
+    if (self->results[i]) {
+      fusionHitFree(self->results[i]);
+    }
+  }
+  array_free(self->results);
+  rm_free(self);
+}
+
+ResultProcessor *RPHybridFusion_New(const FusionOptions *opts, const RLookupKey *scoreKey,
+                                    const RLookupKey *lexicalKey) {
+  RPHybridFusion *ret = rm_calloc(1, sizeof(*ret));
+  ret->opts = *opts;
+  ret->scoreKey = scoreKey;
+  ret->lexicalKey = lexicalKey;
+  ret->lexical = mmh_init(cmpLexicalHits, NULL, NULL);
+  ret->vector = mmh_init(cmpVectorHits, NULL, NULL);
+  ret->results = array_new(fusionHit *, 16);
+  ret->base.Next = rpFusionNext_Accum;
+  ret->base.Free = rpFusionFree;
+  ret->base.type = RP_HYBRID_FUSION;
+  return &ret->base;
+}
@@ -0,0 +1,63 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include <stdbool.h>
+#include "search_options.h"
+#include "result_processor.h"
+#include "query_error.h"
+#include "rmutil/args.h"
+
+#define FUSION_DEFAULT_RRF_CONSTANT 60
+
+// Hidden fields holding the fused score and the score of the lexical branch.
+// The coordinator requests them to fuse the results of all the shards.
+#define FUSION_SCORE_FIELD "__fusion_score"
+#define FUSION_LEXICAL_SCORE_FIELD "__lexical_score"
+
+/** A document taking part in the fusion, with what each branch knows about it */
+typedef struct {
+  double lexicalScore;  // Score given by the scorer to the lexical branch
+  double distance;      // Vector distance, lower is better
+  bool hasLexical;      // The document matched the lexical branch
+  bool hasVector;       // The document was returned by the vector branch
+
+  // Set by HybridFusion_Score()
+  double score;
+  bool fused;  // The document is within the window of at least one branch
+} FusionCandidate;
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Parse the arguments of FUSION, the cursor being right after the keyword:
+ *
+ *  FUSION {nargs} RRF [CONSTANT {k}] [WINDOW {lexical} {vector}]
+ *  FUSION {nargs} LINEAR {lexical weight} {vector weight} [WINDOW {lexical} {vector}]
+ */
+int FusionOptions_Parse(FusionOptions *opts, ArgsCursor *ac, QueryError *status);
+
+/**
+ * Rank the candidates within each branch and compute their fused score.
+ * A window of 0 does not limit the branch.
+ */
+void HybridFusion_Score(const FusionOptions *opts, FusionCandidate *cands, size_t n);
+
+/**
+ * Creates the fusion result processor. It is placed after the scorer, collects
+ * all the results of the hybrid iterator, and yields the fused ones by descending
+ * fused score. The fused score replaces the result score, and is also written to
+ * `scoreKey`, while the lexical score is written to `lexicalKey`.
+ */
+ResultProcessor *RPHybridFusion_New(const FusionOptions *opts, const RLookupKey *scoreKey,
+                                    const RLookupKey *lexicalKey);
+
+#ifdef __cplusplus
+}
+#endif
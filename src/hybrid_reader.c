@@ -114,7 +114,8 @@ static void alternatingIterate(HybridIterator *hr, VecSimQueryResult_Iterator *v
   while (IITER_HAS_NEXT(hr->child)) {
     if (cur_vec_res->docId == cur_child_res->docId) {
       // Found a match - check if it should be added to the results heap.
-      if (heap_count(hr->topResults) < hr->query.k || cur_vec_res->num.value < *upper_bound) {
+      if (cur_vec_res->num.value <= hr->maxDistance &&
+          (heap_count(hr->topResults) < hr->query.k || cur_vec_res->num.value < *upper_bound)) {
         // Otherwise, set the vector and child results as the children the res
         // and insert result to the heap.
         insertResultToHeap(hr, cur_res, cur_child_res, cur_vec_res, upper_bound);
@@ -151,7 +152,8 @@ void computeDistances(HybridIterator *hr) {
   RSIndexResult *cur_vec_res = NewMetricResult();
   void *qvector = hr->query.vector;
 
-  if (hr->indexMetric == VecSimMetric_Cosine) {
+  // The query vector of a compressed index was already normalized before it was quantized
+  if (hr->indexMetric == VecSimMetric_Cosine && !hr->quantized) {
     qvector = rm_malloc(hr->dimension * VecSimType_sizeof(hr->vecType));
     memcpy(qvector, hr->query.vector, hr->dimension * VecSimType_sizeof(hr->vecType));
     VecSim_Normalize(qvector, hr->dimension, hr->vecType);
@@ -167,6 +169,9 @@ void computeDistances(HybridIterator *hr) {
     if (isnan(metric)) {
       continue;
     }
+    if (metric > hr->maxDistance) {
+      continue;
+    }
     if (heap_count(hr->topResults) < hr->query.k || metric < upper_bound) {
       // Populate the vector result.
       cur_vec_res->docId = cur_child_res->docId;
@@ -218,6 +223,21 @@ static bool reviewHybridSearchPolicy(HybridIterator *hr, size_t n_res_left, size
   return false;
 }
 
+// Whether the current batch has results beyond the distance cutoff. The batches are returned by
+// ascending distance, so the next batches would not have results within the cutoff.
+static bool batchExceedsMaxDistance(HybridIterator *hr) {
+  if (hr->maxDistance == INFINITY) {
+    return false;
+  }
+  bool exceeds = false;
+  VecSimQueryResult_Iterator *iter = VecSimQueryResult_List_GetIterator(hr->list);
+  while (!exceeds && VecSimQueryResult_IteratorHasNext(iter)) {
+    exceeds = VecSimQueryResult_GetScore(VecSimQueryResult_IteratorNext(iter)) > hr->maxDistance;
+  }
+  VecSimQueryResult_IteratorFree(iter);
+  return exceeds;
+}
+
 static void prepareResults(HybridIterator *hr) {
     if (hr->searchMode == VECSIM_STANDARD_KNN) {
       hr->list =
@@ -268,7 +288,7 @@ static void prepareResults(HybridIterator *hr) {
 
     // Go over both iterators and save mutual results in the heap.
     alternatingIterate(hr, hr->iter, &upper_bound);
-    if (heap_count(hr->topResults) == hr->query.k) {
+    if (heap_count(hr->topResults) == hr->query.k || batchExceedsMaxDistance(hr)) {
       break;
     }
 
@@ -322,7 +342,8 @@ static int HR_ReadKnnUnsorted(void *ctx, RSIndexResult **hit) {
     return INDEXREAD_EOF;
   }
   *hit = hr->base.current;
-  if (HR_ReadInBatch(hr, hit) == INDEXREAD_EOF) {
+  // The results are sorted by distance, so there are no more results within the cutoff
+  if (HR_ReadInBatch(hr, hit) == INDEXREAD_EOF || (*hit)->num.value > hr->maxDistance) {
     hr->base.isValid = false;
     return INDEXREAD_EOF;
   }
@@ -332,10 +353,77 @@ static int HR_ReadKnnUnsorted(void *ctx, RSIndexResult **hit) {
   return INDEXREAD_OK;
 }
 
+// In FUSION mode, the results of the child and the vector query are merged by id, so every
+// document returned by either branch is yielded once, with both its distance and child subtree.
+static int HR_ReadFusion(void *ctx, RSIndexResult **hit) {
+  HybridIterator *hr = ctx;
+  if (!hr->resultsPrepared) {
+    hr->list = VecSimIndex_TopKQuery(hr->index, hr->query.vector, hr->query.k, &(hr->runtimeParams), BY_ID);
+    hr->iter = VecSimQueryResult_List_GetIterator(hr->list);
+    hr->resultsPrepared = true;
+    hr->advanceVec = hr->advanceChild = true;
+    if (hr->list.code == VecSim_QueryResult_TimedOut) {
+      return INDEXREAD_TIMEOUT;
+    }
+  }
+  if (!HR_HasNext(ctx)) {
+    return INDEXREAD_EOF;
+  }
+
+  // Advance the branches whose current result was yielded by the previous read.
+  if (hr->advanceVec) {
+    // The vector results are sorted by id, so the ones beyond the cutoff are skipped
+    do {
+      hr->vecValid = HR_ReadInBatch(hr, &hr->fusionVecRes) == INDEXREAD_OK;
+    } while (hr->vecValid && hr->fusionVecRes->num.value > hr->maxDistance);
+    hr->advanceVec = false;
+  }
+  if (hr->advanceChild) {
+    int rc = hr->child->Read(hr->child->ctx, &hr->fusionChildRes);
+    if (rc == INDEXREAD_TIMEOUT) {
+      return INDEXREAD_TIMEOUT;
+    }
+    hr->childValid = rc == INDEXREAD_OK;
+    hr->advanceChild = false;
+  }
+  if (!hr->vecValid && !hr->childValid) {
+    hr->base.isValid = false;
+    return INDEXREAD_EOF;
+  }
+
+  t_docId docId;
+  if (!hr->childValid ||
+      (hr->vecValid && hr->fusionVecRes->docId <= hr->fusionChildRes->docId)) {
+    docId = hr->fusionVecRes->docId;
+  } else {
+    docId = hr->fusionChildRes->docId;
+  }
+  hr->advanceVec = hr->vecValid && hr->fusionVecRes->docId == docId;
+  hr->advanceChild = hr->childValid && hr->fusionChildRes->docId == docId;
+
+  RSIndexResult *res = hr->base.current;
+  AggregateResult_Reset(res);
+  res->freq = 0;
+  res->fieldMask = 0;
+  AggregateResult_AddChild(res, hr->advanceVec ? hr->fusionVecRes : hr->vecPlaceholder);
+  AggregateResult_AddChild(res, hr->advanceChild ? hr->fusionChildRes : hr->childPlaceholder);
+  res->docId = docId;
+  if (hr->advanceVec) {
+    ResultMetrics_Add(res, hr->base.ownKey, RS_NumVal(hr->fusionVecRes->num.value));
+  }
+  hr->lastDocId = docId;
+  *hit = res;
+  return INDEXREAD_OK;
+}
+
 static size_t HR_NumEstimated(void *ctx) {
   HybridIterator *hr = ctx;
   size_t vec_res_num = MIN(hr->query.k, VecSimIndex_IndexSize(hr->index));
   if (hr->child == NULL) return vec_res_num;
+  if (hr->fuseResults) {
+    // The union of both branches
+    return vec_res_num + hr->child->NumEstimated(hr->child->ctx);
+  }
   return MIN(vec_res_num, hr->child->NumEstimated(hr->child->ctx));
 }
 
@@ -377,6 +465,11 @@ static void HR_Rewind(void *ctx) {
     array_clear(hr->returnedResults);
     hr->child->Rewind(hr->child->ctx);
   }
+  if (hr->fuseResults) {
+    hr->vecValid = hr->childValid = false;
+    hr->advanceVec = hr->advanceChild = false;
+    hr->child->Rewind(hr->child->ctx);
+  }
 }
 
 void HybridIterator_Free(struct indexIterator *self) {
@@ -393,6 +486,14 @@ void HybridIterator_Free(struct indexIterator *self) {
   if (it->returnedResults) {   // Iterator is in one of the hybrid modes.
     array_free_ex(it->returnedResults, IndexResult_Free(*(RSIndexResult **)ptr));
   }
+  if (it->fuseResults) {
+    IndexResult_Free(it->fusionVecRes);
+    IndexResult_Free(it->vecPlaceholder);
+    IndexResult_Free(it->childPlaceholder);
+  }
+  if (it->quantized) {
+    rm_free(it->query.vector);
+  }
   IndexResult_Free(it->base.current);
   VecSimQueryResult_Free(it->list);
   if (it->iter) VecSimQueryResult_IteratorFree(it->iter);
@@ -428,8 +529,21 @@ IndexIterator *NewHybridVectorIterator(HybridIteratorParams hParams, QueryError
   hi->ignoreScores = hParams.ignoreDocScore;
   hi->timeoutCtx = (TimeoutCtx){ .timeout = hParams.timeout, .counter = 0 };
   hi->runtimeParams.timeoutCtx = &hi->timeoutCtx;
-
-  if (hParams.childIt == NULL || hParams.query.k == 0) {
+  hi->fuseResults = hParams.fuseResults && hParams.childIt != NULL;
+  hi->fusionVecRes = hi->fusionChildRes = NULL;
+  hi->vecPlaceholder = hi->childPlaceholder = NULL;
+  hi->vecValid = hi->childValid = false;
+  hi->advanceVec = hi->advanceChild = false;
+  hi->quantized = hParams.quantized;
+  hi->maxDistance = hParams.hasMaxDistance ? hParams.maxDistance : INFINITY;
+
+  if (hi->fuseResults) {
+    // The vector query runs over the entire vector index, regardless of the child results.
+    hi->searchMode = VECSIM_STANDARD_KNN;
+    hi->fusionVecRes = NewMetricResult();
+    hi->vecPlaceholder = NewMetricResult();
+    hi->childPlaceholder = NewVirtualResult(0);
+  } else if (hParams.childIt == NULL || hParams.query.k == 0) {
     // If there is no child iterator, or the query is going to return 0 results, we can use simple KNN.
     hi->searchMode = VECSIM_STANDARD_KNN;
   } else {
@@ -439,6 +553,9 @@ IndexIterator *NewHybridVectorIterator(HybridIteratorParams hParams, QueryError
     size_t subset_size = hParams.childIt->NumEstimated(hParams.childIt->ctx);
     // IITER_INVALID_NUM_ESTIMATED_RESULTS is the default (invalid) value for indicating invalid intersection iterator.
     if (subset_size == IITER_INVALID_NUM_ESTIMATED_RESULTS) {
+      if (hi->quantized) {
+        rm_free(hi->query.vector);
+      }
       rm_free(hi);
       return NULL;
     }
@@ -478,7 +595,10 @@ IndexIterator *NewHybridVectorIterator(HybridIteratorParams hParams, QueryError
   ri->Rewind = HR_Rewind;
   ri->HasNext = HR_HasNext;
   ri->SkipTo = NULL; // As long as we return results by score (unsorted by id), this has no meaning.
-  if (hi->searchMode == VECSIM_STANDARD_KNN) {
+  if (hi->fuseResults) {
+    ri->Read = HR_ReadFusion;
+    ri->current = NewHybridResult();
+  } else if (hi->searchMode == VECSIM_STANDARD_KNN) {
     ri->Read = HR_ReadKnnUnsorted;
     ri->current = NewMetricResult();
   } else {
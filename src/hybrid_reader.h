@@ -22,8 +22,15 @@ typedef struct {
   VecSimQueryParams qParams;
   char *vectorScoreField;
   bool ignoreDocScore;
+  bool fuseResults;  // FUSION - yield the results of both the child and the vector query.
   IndexIterator *childIt;
   struct timespec timeout;
+  // Compressed index - the query vector was quantized (and normalized), and is owned by the
+  // iterator. The results are reranked later on in the pipeline.
+  bool quantized;
+  // Results farther than `maxDistance` from the query vector are dropped, if set
+  bool hasMaxDistance;
+  double maxDistance;
 } HybridIteratorParams;
 
 typedef struct {
@@ -48,6 +55,24 @@ typedef struct {
   size_t numIterations;
   bool ignoreScores;               // Ignore the document scores, only vector score matters.
   TimeoutCtx timeoutCtx;           // Timeout parameters
+
+  // FUSION mode - the union of the child results and the top K vector results is yielded
+  // sorted by id. The first child of every result is the vector distance, and the second one
+  // is the child subtree. A branch that did not return the document is represented by a
+  // placeholder with docId 0.
+  bool fuseResults;
+  RSIndexResult *fusionVecRes;     // Current vector result
+  RSIndexResult *fusionChildRes;   // Current child result (uses the memory of child->current)
+  RSIndexResult *vecPlaceholder;
+  RSIndexResult *childPlaceholder;
+  bool vecValid, childValid;       // Whether the current result of the branch is valid
+  bool advanceVec, advanceChild;   // Whether the branch should advance on the next read
+
+  bool quantized;                  // Compressed index - the query vector is quantized
+
+  // Distance cutoff. Since the vector results are read by ascending distance, reading stops at
+  // the first result beyond it.
+  double maxDistance;
 } HybridIterator;
 
 #ifdef __cplusplus
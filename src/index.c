@@ -20,6 +20,7 @@
 #include "hybrid_reader.h"
 #include "metric_iterator.h"
 #include "optimizer_reader.h"
+#include "geo_index.h"
 
 static int UI_SkipTo(void *ctx, t_docId docId, RSIndexResult **hit);
 static int UI_SkipToHigh(void *ctx, t_docId docId, RSIndexResult **hit);
@@ -2034,6 +2035,8 @@ PRINT_PROFILE_SINGLE(printIdListIt, DummyIterator, "ID-LIST", 0);
 PRINT_PROFILE_SINGLE(printEmptyIt, DummyIterator, "EMPTY", 0);
 PRINT_PROFILE_SINGLE(printHybridIt, HybridIterator, "VECTOR", 1);
 PRINT_PROFILE_SINGLE(printOptimusIt, OptimizerIterator, "OPTIMIZER", 1);
+PRINT_PROFILE_SINGLE(printGeoDistanceIt, GeoDistanceIterator, "GEO DISTANCE", 1);
+PRINT_PROFILE_SINGLE(printBooleanIt, DummyIterator, "BOOLEAN", 0);
 
 PRINT_PROFILE_FUNC(printProfileIt) {
   ProfileIterator *pi = (ProfileIterator *)root;
@@ -2066,6 +2069,8 @@ void printIteratorProfile(RedisModuleCtx *ctx, IndexIterator *root, size_t count
     case HYBRID_ITERATOR:     { printHybridIt(ctx, root, counter, cpuTime, depth, limited, config);     break; }
     case METRIC_ITERATOR:     { printMetricIt(ctx, root, counter, cpuTime, depth, limited, config);     break; }
     case OPTIMUS_ITERATOR:    { printOptimusIt(ctx, root, counter, cpuTime, depth, limited, config);    break; }
+    case GEO_DISTANCE_ITERATOR: { printGeoDistanceIt(ctx, root, counter, cpuTime, depth, limited, config); break; }
+    case BOOLEAN_ITERATOR:    { printBooleanIt(ctx, root, counter, cpuTime, depth, limited, config);    break; }
     case MAX_ITERATOR:        { RS_LOG_ASSERT(0, "nope");   break; }
   }
 }
@@ -2091,6 +2096,9 @@ void Profile_AddIters(IndexIterator **root) {
     case OPTIMUS_ITERATOR:
       Profile_AddIters(&((OptimizerIterator *)((*root)->ctx))->child);
       break;
+    case GEO_DISTANCE_ITERATOR:
+      Profile_AddIters(&((GeoDistanceIterator *)((*root)->ctx))->child);
+      break;
     case UNION_ITERATOR:
       ui = (*root)->ctx;
       for (int i = 0; i < ui->norig; i++) {
@@ -2109,6 +2117,7 @@ void Profile_AddIters(IndexIterator **root) {
     case EMPTY_ITERATOR:
     case ID_LIST_ITERATOR:
     case METRIC_ITERATOR:
+    case BOOLEAN_ITERATOR:
       break;
     case PROFILE_ITERATOR:
     case MAX_ITERATOR:
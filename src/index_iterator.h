@@ -34,6 +34,8 @@ enum iteratorType {
   METRIC_ITERATOR,
   PROFILE_ITERATOR,
   OPTIMUS_ITERATOR,
+  GEO_DISTANCE_ITERATOR,
+  BOOLEAN_ITERATOR,
   MAX_ITERATOR,
 };
 
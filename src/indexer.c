@@ -10,6 +10,7 @@
 #include "inverted_index.h"
 #include "geo_index.h"
 #include "vector_index.h"
+#include "boolean_index.h"
 #include "index.h"
 #include "redis_index.h"
 #include "suffix.h"
@@ -285,10 +286,12 @@ static RSDocumentMetadata *makeDocumentId(RedisModuleCtx *ctx, RSAddDocumentCtx
             // TODO: use VecSimReplace instead and if successful, do not insert and remove from doc
           }
         }
+        VecSimMigration_DeleteDocument(spec, dmd->id);
       }
       if (spec->flags & Index_HasGeometry) {
         GeometryIndex_RemoveId(ctx, spec, dmd->id);
       }
+      BooleanIndex_RemoveId(spec, dmd->id);
     }
   }
 
@@ -381,6 +384,73 @@ static void indexBulkFields(RSAddDocumentCtx *aCtx, RedisSearchCtx *sctx) {
   }
 }
 
+// Returns the document field holding a non-null value for `fs`, or NULL if there is none
+static const DocumentField *getPresentField(const RSAddDocumentCtx *aCtx, const FieldSpec *fs) {
+  for (size_t ii = 0; ii < aCtx->doc->numFields; ++ii) {
+    if (aCtx->fspecs[ii].name && aCtx->fspecs[ii].index == fs->index) {
+      return aCtx->fdatas[ii].isNull ? NULL : aCtx->doc->fields + ii;
+    }
+  }
+  return NULL;
+}
+
+// A value is empty if it is an empty string, or an array containing an empty string
+static int isFieldEmpty(const DocumentField *field) {
+  size_t len;
+  switch (field->unionType) {
+    case FLD_VAR_T_RMS:
+      RedisModule_StringPtrLen(field->text, &len);
+      return len == 0;
+    case FLD_VAR_T_CSTR:
+      return field->strlen == 0;
+    case FLD_VAR_T_ARRAY:
+      for (size_t ii = 0; ii < field->arrayLen; ++ii) {
+        if (*field->multiVal[ii] == '\0') {
+          return 1;
+        }
+      }
+      return 0;
+    default:
+      return 0;
+  }
+}
+
+static void writeFieldDocsEntry(IndexSpec *spec, InvertedIndex *iv, t_docId docId) {
+  IndexEncoder enc = InvertedIndex_GetEncoder(Index_DocIdsOnly);
+  RSIndexResult rec = {.type = RSResultType_Virtual, .docId = docId, .offsetsSz = 0, .freq = 0};
+  spec->stats.invertedSize += InvertedIndex_WriteEntryGeneric(iv, enc, docId, &rec);
+  spec->stats.numRecords++;
+}
+
+/**
+ * Add the document to the missing-docs index of every INDEXMISSING field which it doesn't
+ * contain, or which has a null value, and to the empty-docs index of every INDEXEMPTY field
+ * which holds an empty value.
+ */
+static void indexFieldDocs(RSAddDocumentCtx *aCtx, RedisSearchCtx *sctx) {
+  if (aCtx->stateFlags & (ACTX_F_ERRORED | ACTX_F_FIELDDOCSINDEXED)) {
+    return;
+  }
+  aCtx->stateFlags |= ACTX_F_FIELDDOCSINDEXED;
+
+  IndexSpec *spec = sctx->spec;
+  t_docId docId = aCtx->doc->docId;
+  for (size_t ii = 0; ii < spec->numFields; ++ii) {
+    const FieldSpec *fs = spec->fields + ii;
+    if (!FieldSpec_IndexesMissing(fs) && !FieldSpec_IndexesEmpty(fs)) {
+      continue;
+    }
+    const DocumentField *field = getPresentField(aCtx, fs);
+    if (!field) {
+      if (FieldSpec_IndexesMissing(fs)) {
+        writeFieldDocsEntry(spec, IndexSpec_GetMissingFieldIndex(spec, fs, true), docId);
+      }
+    } else if (FieldSpec_IndexesEmpty(fs) && isFieldEmpty(field)) {
+      writeFieldDocsEntry(spec, IndexSpec_GetEmptyFieldIndex(spec, fs, true), docId);
+    }
+  }
+}
+
 static void reopenCb(void *arg) {}
 
 // Routines for the merged hash table
@@ -472,6 +542,12 @@ static void Indexer_Process(DocumentIndexer *indexer, RSAddDocumentCtx *aCtx) {
     indexBulkFields(aCtx, &ctx);
   }
 
+  // Documents of the chain may already be fully indexed by the time they are processed, so
+  // all the documents which got an ID are handled here
+  for (RSAddDocumentCtx *cur = aCtx; cur && cur->doc->docId; cur = cur->next) {
+    indexFieldDocs(cur, &ctx);
+  }
+
 cleanup:
   if (isBlocked) {
     ConcurrentSearchCtx_Unlock(&indexer->concCtx);
@@ -24,6 +24,7 @@ typedef struct FieldIndexerData {
     // Single value
     double numeric;  // i.e. the numeric value of the field
     arrayof(char*) tags;
+    int booleans;  // The values of a boolean field, as a mask of BOOLEAN_VALUE_MASK(value)
     struct {
       const void *vector;
       size_t vecLen;
@@ -8,6 +8,8 @@
 #include "spec.h"
 #include "inverted_index.h"
 #include "vector_index.h"
+#include "vector_compression.h"
+#include "analyzer.h"
 #include "cursor.h"
 
 #define REPLY_KVNUM(n, k, v)                       \
@@ -155,6 +157,11 @@ int IndexInfoCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
 
     if (FIELD_IS(fs, INDEXFLD_T_FULLTEXT)) {
       REPLY_KVNUM(nn, SPEC_WEIGHT_STR, fs->ftWeight);
+      if (fs->analyzer) {
+        RedisModule_ReplyWithSimpleString(ctx, SPEC_ANALYZER_STR);
+        Analyzer_Reply(ctx, fs->analyzer);
+        nn += 2;
+      }
     }
 
     if (FIELD_IS(fs, INDEXFLD_T_TAG)) {
@@ -166,6 +173,12 @@ int IndexInfoCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
         ++nn;
       }
     }
+    if (FIELD_IS(fs, INDEXFLD_T_VECTOR) && FieldSpec_IsVectorCompressed(fs)) {
+      REPLY_KVSTR(nn, "compression", VECSIM_COMPRESSION_SQ8);
+    }
+    if (FIELD_IS(fs, INDEXFLD_T_GEOMETRY) && fs->geometryOpts.geometryLibType == GEOMETRY_LIB_TYPE_S2) {
+      REPLY_KVSTR(nn, SPEC_GEOMETRY_COORD_SYSTEM_STR, SPEC_GEOMETRY_SPHERICAL_STR);
+    }
     if (FieldSpec_IsSortable(fs)) {
       RedisModule_ReplyWithSimpleString(ctx, SPEC_SORTABLE_STR);
       ++nn;
@@ -178,6 +191,14 @@ int IndexInfoCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
       RedisModule_ReplyWithSimpleString(ctx, SPEC_NOSTEM_STR);
       ++nn;
     }
+    if (FieldSpec_IsAsciiFold(fs)) {
+      RedisModule_ReplyWithSimpleString(ctx, SPEC_ASCIIFOLD_STR);
+      ++nn;
+    }
+    if (FieldSpec_IsNFKC(fs)) {
+      RedisModule_ReplyWithSimpleString(ctx, SPEC_NFKC_STR);
+      ++nn;
+    }
     if (!FieldSpec_IsIndexable(fs)) {
       RedisModule_ReplyWithSimpleString(ctx, SPEC_NOINDEX_STR);
       ++nn;
@@ -186,6 +207,14 @@ int IndexInfoCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
       RedisModule_ReplyWithSimpleString(ctx, SPEC_WITHSUFFIXTRIE_STR);
       ++nn;
     }
+    if (FieldSpec_IndexesMissing(fs)) {
+      RedisModule_ReplyWithSimpleString(ctx, SPEC_INDEXMISSING_STR);
+      ++nn;
+    }
+    if (FieldSpec_IndexesEmpty(fs)) {
+      RedisModule_ReplyWithSimpleString(ctx, SPEC_INDEXEMPTY_STR);
+      ++nn;
+    }
     RedisModule_ReplySetArrayLength(ctx, nn);
   }
   n += 2;
@@ -196,6 +225,7 @@ int IndexInfoCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
   REPLY_KVNUM(n, "num_records", sp->stats.numRecords);
   REPLY_KVNUM(n, "inverted_sz_mb", sp->stats.invertedSize / (float)0x100000);
   REPLY_KVNUM(n, "vector_index_sz_mb", sp->stats.vectorIndexSize / (float)0x100000);
+  REPLY_KVNUM(n, "vector_index_raw_sz_mb", VecSimCompression_SpecRawMemory(sp) / (float)0x100000);
   REPLY_KVNUM(n, "total_inverted_index_blocks", TotalIIBlocks);
   // REPLY_KVNUM(n, "inverted_cap_mb", sp->stats.invertedCap / (float)0x100000);
 
@@ -120,6 +120,12 @@ void IndexReader_OnReopen(void *privdata) {
     }
   }
 
+  IndexReader_OnReopenPersistent(ir);
+}
+
+void IndexReader_OnReopenPersistent(void *privdata) {
+  IndexReader *ir = privdata;
+
   // the gc marker tells us if there is a chance the keys has undergone GC while we were asleep
   if (ir->gcMarker == ir->idx->gcMarker) {
     // no GC - we just go to the same offset we were at
@@ -762,7 +768,7 @@ DECODER(readNumeric) {
     if (NumericFilter_IsNumeric(f)) {
       return NumericFilter_Match(f, res->num.value);
     } else {
-      return isWithinRadius(f->geoFilter, res->num.value, &res->num.value);
+      return GeoFilter_Match(f->geoFilter, res->num.value, &res->num.value);
     }
   }
 
@@ -1293,6 +1299,21 @@ IndexReader *NewTermIndexReader(InvertedIndex *idx, IndexSpec *sp, t_fieldMask f
   return NewIndexReaderGeneric(sp, idx, decoder, dctx, false, record);
 }
 
+IndexReader *NewDocsIndexReader(InvertedIndex *idx, const IndexSpec *sp, double weight) {
+  IndexDecoderProcs decoder = InvertedIndex_GetDecoder((uint32_t)idx->flags & INDEX_STORAGE_MASK);
+  if (!decoder.decoder) {
+    return NULL;
+  }
+
+  RSIndexResult *record = NewVirtualResult(weight);
+  record->fieldMask = RS_FIELDMASK_ALL;
+  record->freq = 1;
+
+  IndexDecoderCtx dctx = {.num = RS_FIELDMASK_ALL};
+
+  return NewIndexReaderGeneric(sp, idx, decoder, dctx, false, record);
+}
+
 void IR_Free(IndexReader *ir) {
 
   IndexResult_Free(ir->record);
@@ -169,6 +169,10 @@ typedef struct IndexReader {
 
 void IndexReader_OnReopen(void *privdata);
 
+/* Same as IndexReader_OnReopen, for readers of inverted indexes which are never deleted while their
+ * spec is alive (e.g. the missing-docs indexes of INDEXMISSING fields) */
+void IndexReader_OnReopenPersistent(void *privdata);
+
 /* An index encoder is a callback that writes records to the index. It accepts a pre-calculated
  * delta for encoding */
 typedef size_t (*IndexEncoder)(BufferWriter *bw, uint32_t delta, RSIndexResult *record);
@@ -202,6 +206,10 @@ IndexEncoder InvertedIndex_GetEncoder(IndexFlags flags);
 IndexReader *NewTermIndexReader(InvertedIndex *idx, IndexSpec *sp, t_fieldMask fieldMask,
                                 RSQueryTerm *term, double weight);
 
+/* Create a new index reader on an inverted index of document IDs only, which isn't the index of a
+ * term (e.g. the missing-docs index of an INDEXMISSING field). Its records are virtual */
+IndexReader *NewDocsIndexReader(InvertedIndex *idx, const IndexSpec *sp, double weight);
+
 void IR_Abort(void *ctx);
 
 /* free an index reader */
@@ -9,6 +9,7 @@
 #include "document.h"
 #include "rmutil/rm_assert.h"
 #include "vector_index.h"
+#include "util/float16.h"
 
 #include <string.h>
 
@@ -95,22 +96,25 @@ int FieldSpec_CheckJsonType(FieldType fieldType, JSONType type) {
   int rv = REDISMODULE_ERR;
   switch (type) {
   // TEXT, TAG and GEO fields are represented as string
-  // GEOMETRY field can be represented as WKT string
+  // GEOMETRY field can be represented as WKT or GeoJSON string
+  // DATE field can be represented as ISO-8601 string
   case JSONType_String:
-    if (fieldType & (INDEXFLD_T_FULLTEXT | INDEXFLD_T_TAG | INDEXFLD_T_GEO | INDEXFLD_T_GEOMETRY)) {
+    if (fieldType & (INDEXFLD_T_FULLTEXT | INDEXFLD_T_TAG | INDEXFLD_T_GEO | INDEXFLD_T_GEOMETRY |
+                     INDEXFLD_T_DATE)) {
       rv = REDISMODULE_OK;
     }
     break;
   // NUMERIC field is represented as either integer or double
+  // DATE field can also be given as a timestamp (seconds since the epoch)
   case JSONType_Int:
   case JSONType_Double:
-    if (fieldType == INDEXFLD_T_NUMERIC) {
+    if (fieldType == INDEXFLD_T_NUMERIC || fieldType == INDEXFLD_T_DATE) {
       rv = REDISMODULE_OK;
     }
     break;
-  // Boolean values can be represented only as TAG
+  // Boolean values can be represented only as TAG or BOOLEAN
   case JSONType_Bool:
-    if (fieldType == INDEXFLD_T_TAG) {
+    if (fieldType == INDEXFLD_T_TAG || fieldType == INDEXFLD_T_BOOLEAN) {
       rv = REDISMODULE_OK;
     }
     break;
@@ -124,7 +128,6 @@ int FieldSpec_CheckJsonType(FieldType fieldType, JSONType type) {
     break;
   case JSONType_Object:
     if (fieldType == INDEXFLD_T_GEOMETRY) {
-      // TODO: GEOMETRY
       // GEOMETRY field can be represented as GEOJSON "geometry" object
       rv = REDISMODULE_OK;
     }
@@ -173,6 +176,39 @@ static int JSON_getFloat64(RedisJSON json, double *val) {
   }
 }
 
+static int JSON_getFloat16(RedisJSON json, uint16_t *val) {
+  float temp;
+  int ret = JSON_getFloat32(json, &temp);
+  *val = float_to_fp16(temp);
+  return ret;
+}
+
+static int JSON_getBFloat16(RedisJSON json, uint16_t *val) {
+  float temp;
+  int ret = JSON_getFloat32(json, &temp);
+  *val = float_to_bf16(temp);
+  return ret;
+}
+
+// Integer elements must be given as integers within the range of the type
+static int JSON_getInt8(RedisJSON json, int8_t *val) {
+  long long temp;
+  if (japi->getInt(json, &temp) != REDISMODULE_OK || temp < INT8_MIN || temp > INT8_MAX) {
+    return REDISMODULE_ERR;
+  }
+  *val = (int8_t)temp;
+  return REDISMODULE_OK;
+}
+
+static int JSON_getUInt8(RedisJSON json, uint8_t *val) {
+  long long temp;
+  if (japi->getInt(json, &temp) != REDISMODULE_OK || temp < 0 || temp > UINT8_MAX) {
+    return REDISMODULE_ERR;
+  }
+  *val = (uint8_t)temp;
+  return REDISMODULE_OK;
+}
+
 typedef int (*getJSONElementFunc)(RedisJSON, void *);
 int JSON_StoreVectorAt(RedisJSON arr, size_t len, getJSONElementFunc getElement, char *target, unsigned char step) {
   for (int i = 0; i < len; ++i) {
@@ -193,6 +229,14 @@ getJSONElementFunc VecSimGetJSONCallback(VecSimType type) {
       return (getJSONElementFunc)JSON_getFloat32;
     case VecSimType_FLOAT64:
       return (getJSONElementFunc)JSON_getFloat64;
+    case VecSimType_FLOAT16:
+      return (getJSONElementFunc)JSON_getFloat16;
+    case VecSimType_BFLOAT16:
+      return (getJSONElementFunc)JSON_getBFloat16;
+    case VecSimType_INT8:
+      return (getJSONElementFunc)JSON_getInt8;
+    case VecSimType_UINT8:
+      return (getJSONElementFunc)JSON_getUInt8;
     // Uncomment when support for more types is added
     // case VecSimType_INT32:
     //   return (getJSONElementFunc)JSON_getInt32;
@@ -239,6 +283,34 @@ int JSON_StoreSingleVectorInDocField(FieldSpec *fs, RedisJSON arr, struct Docume
   return REDISMODULE_OK;
 }
 
+// Append a vector to the blob array of the field, growing the array if it is full.
+static int JSON_AppendVector(RedisJSON arr, size_t dim, getJSONElementFunc getElement, unsigned char step,
+                             struct DocumentField *df, size_t *cap) {
+  size_t cur_dim;
+  if ((REDISMODULE_OK != japi->getLen(arr, &cur_dim)) || (cur_dim != dim)) {
+    return REDISMODULE_ERR;
+  }
+  if (df->blobArrLen == *cap) {
+    *cap = *cap ? *cap * 2 : 1;
+    df->blobArr = rm_realloc(df->blobArr, df->blobSize * *cap);
+  }
+  if (REDISMODULE_OK != JSON_StoreVectorAt(arr, dim, getElement, df->blobArr + df->blobSize * df->blobArrLen, step)) {
+    return REDISMODULE_ERR;
+  }
+  df->blobArrLen++;
+  return REDISMODULE_OK;
+}
+
+bool JSON_IsArrayOfVectors(RedisJSON element, size_t len) {
+  for (size_t i = 0; i < len; i++) {
+    JSONType type = japi->getType(japi->getAt(element, i));
+    if (JSONType_Null != type) {
+      return JSONType_Array == type;
+    }
+  }
+  return len > 0;
+}
+
 int JSON_StoreMultiVectorInDocField(FieldSpec *fs, JSONIterable *itr, size_t len, struct DocumentField *df) {
   VecSimType type;
   size_t dim;
@@ -270,7 +342,8 @@ int JSON_StoreMultiVectorInDocField(FieldSpec *fs, JSONIterable *itr, size_t len
     goto fail;
   }
   df->blobSize = fs->vectorOpts.expBlobSize;
-  size_t count = 0;
+  df->blobArrLen = 0; // counts only the valid non-null vectors, so we store only valid vectors continuously.
+  size_t cap = len;
 
   while ((element = JSONIterable_Next(itr))) {
     JSONType jsonType = japi->getType(element);
@@ -279,16 +352,25 @@ int JSON_StoreMultiVectorInDocField(FieldSpec *fs, JSONIterable *itr, size_t len
     } else if (JSONType_Array != jsonType) {
       goto cleanup;
     }
-    size_t cur_dim;
-    if ((REDISMODULE_OK != japi->getLen(element, &cur_dim)) || (cur_dim != dim)) {
+    size_t cur_len;
+    if (REDISMODULE_OK != japi->getLen(element, &cur_len)) {
       goto cleanup;
     }
-    if (REDISMODULE_OK != JSON_StoreVectorAt(element, cur_dim, getElement, df->blobArr + df->blobSize * count, step)) {
+    if (JSON_IsArrayOfVectors(element, cur_len)) {
+      // An array of vectors (e.g. the embeddings of all the paragraphs of a section)
+      for (size_t i = 0; i < cur_len; i++) {
+        RedisJSON vec = japi->getAt(element, i);
+        if (JSONType_Null == japi->getType(vec)) {
+          continue;
+        }
+        if (REDISMODULE_OK != JSON_AppendVector(vec, dim, getElement, step, df, &cap)) {
+          goto cleanup;
+        }
+      }
+    } else if (REDISMODULE_OK != JSON_AppendVector(element, dim, getElement, step, df, &cap)) {
       goto cleanup;
     }
-    count++; // counts only the valid non-null vectors, so we store only valid vectors continuously.
   }
-  df->blobArrLen = count;
   df->unionType = FLD_VAR_T_BLOB_ARRAY;
   return REDISMODULE_OK;
 
@@ -389,6 +471,52 @@ int JSON_StoreTextInDocFieldFromArr(RedisJSON arr, struct DocumentField *df) {
   return JSON_StoreTextInDocField(len, &iter, df);
 }
 
+int JSON_StoreBooleanInDocField(size_t len, JSONIterable *iterable, struct DocumentField *df) {
+  df->multiVal = rm_calloc(len , sizeof(*df->multiVal));
+  int i = 0, nulls = 0;
+  int boolval;
+  RedisJSON json;
+  while ((json = JSONIterable_Next(iterable))) {
+    JSONType jsonType = japi->getType(json);
+    if (jsonType == JSONType_Bool) {
+      japi->getBoolean(json, &boolval);
+      df->multiVal[i++] = rm_strdup(boolval ? BOOLEAN_FIELD_TRUE_STR : BOOLEAN_FIELD_FALSE_STR);
+    } else if (jsonType == JSONType_Null) {
+      nulls++; // Skip Nulls
+    } else {
+      // Boolean fields can handle only booleans or Nulls
+      goto error;
+    }
+  }
+  RS_LOG_ASSERT ((i + nulls) == len, "BOOLEAN iterator count and len must be equal");
+  df->arrayLen = i;
+  df->unionType = FLD_VAR_T_ARRAY;
+  return REDISMODULE_OK;
+
+error:
+  for (int j = 0; j < i; ++j) {
+    rm_free(df->multiVal[j]);
+  }
+  rm_free(df->multiVal);
+  df->arrayLen = 0;
+  return REDISMODULE_ERR;
+}
+
+int JSON_StoreBooleanInDocFieldFromIter(size_t len, JSONResultsIterator jsonIter, struct DocumentField *df) {
+  JSONIterable iter = (JSONIterable) {.type = ITERABLE_ITER,
+                                      .iter = jsonIter};
+  return JSON_StoreBooleanInDocField(len, &iter, df);
+}
+
+int JSON_StoreBooleanInDocFieldFromArr(RedisJSON arr, struct DocumentField *df) {
+  size_t len;
+  japi->getLen(arr, &len);
+  JSONIterable iter = (JSONIterable) {.type = ITERABLE_ARRAY,
+                                      .array.arr = arr,
+                                      .array.index = 0};
+  return JSON_StoreBooleanInDocField(len, &iter, df);
+}
+
 int JSON_StoreNumericInDocField(size_t len, JSONIterable *iterable, struct DocumentField *df) {
   arrayof(double) arr = array_new(double, len);
   int nulls = 0;
@@ -473,7 +601,8 @@ int JSON_StoreInDocField(RedisJSON json, JSONType jsonType, FieldSpec *fs, struc
         case INDEXFLD_T_FULLTEXT:
         case INDEXFLD_T_TAG:
         case INDEXFLD_T_GEO:
-          // (initially GEO is stored as TEXT)
+        case INDEXFLD_T_DATE:
+          // (initially GEO and DATE are stored as TEXT)
           rv = JSON_StoreTextInDocFieldFromArr(json, df);
           break;
         case INDEXFLD_T_VECTOR:
@@ -482,6 +611,9 @@ int JSON_StoreInDocField(RedisJSON json, JSONType jsonType, FieldSpec *fs, struc
         case INDEXFLD_T_NUMERIC:
           rv = JSON_StoreNumericInDocFieldFromArr(json, df);
           break;
+        case INDEXFLD_T_BOOLEAN:
+          rv = JSON_StoreBooleanInDocFieldFromArr(json, df);
+          break;
         case INDEXFLD_T_GEOMETRY:
           rv = REDISMODULE_ERR; // TODO: GEOMETRY = JSON_StoreGeometryInDocFieldFromArr(json, df);
           break;
@@ -491,7 +623,20 @@ int JSON_StoreInDocField(RedisJSON json, JSONType jsonType, FieldSpec *fs, struc
       }
       break;
     case JSONType_Object:
-      rv = REDISMODULE_ERR;
+      if (fs->types == INDEXFLD_T_GEOMETRY) {
+        // A GeoJSON geometry object, indexed from its serialization
+        RedisModuleString *rstr;
+        if (japi->getJSON(json, NULL, &rstr) != REDISMODULE_OK) {
+          rv = REDISMODULE_ERR;
+          break;
+        }
+        str = RedisModule_StringPtrLen(rstr, &df->strlen);
+        df->strval = rm_strndup(str, df->strlen);
+        df->unionType = FLD_VAR_T_CSTR;
+        RedisModule_FreeString(NULL, rstr);
+      } else {
+        rv = REDISMODULE_ERR;
+      }
       break;
     case JSONType__EOF:
       RS_LOG_ASSERT(0, "Should not happen");
@@ -520,14 +665,19 @@ int JSON_LoadDocumentField(JSONResultsIterator jsonIter, size_t len,
       case INDEXFLD_T_TAG:
       case INDEXFLD_T_FULLTEXT:
       case INDEXFLD_T_GEO:
+      case INDEXFLD_T_DATE:
         // Handling multiple values as Text
-        // (initially GEO is stored as TEXT)
+        // (initially GEO and DATE are stored as TEXT)
         rv = JSON_StoreTextInDocFieldFromIter(len, jsonIter, df);
         break;
       case INDEXFLD_T_NUMERIC:
         // Handling multiple values as Numeric
         rv = JSON_StoreNumericInDocFieldFromIter(len, jsonIter, df);
         break;
+      case INDEXFLD_T_BOOLEAN:
+        // Handling multiple values as Boolean
+        rv = JSON_StoreBooleanInDocFieldFromIter(len, jsonIter, df);
+        break;
       case INDEXFLD_T_VECTOR:;
         // Handling multiple values as Vector
         rv = JSON_StoreMultiVectorInDocFieldFromIter(fs, jsonIter, len, df);
@@ -41,6 +41,10 @@ typedef struct {
 
 RedisJSON JSONIterable_Next(JSONIterable *iterable);
 
+/* Whether an element of a multi-value vector field is an array of vectors rather than a vector,
+ * according to its first non-null item. An array of nulls only is an empty array of vectors */
+bool JSON_IsArrayOfVectors(RedisJSON element, size_t len);
+
 int GetJSONAPIs(RedisModuleCtx *ctx, int subscribeToModuleChange);
 
 /* Creates a Redis Module String from JSONType string, int, double, bool */
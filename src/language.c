@@ -43,6 +43,8 @@ langPair_t __langPairs[] = {
   { "turkish",    RS_LANG_TURKISH },
   { "yiddish",    RS_LANG_YIDDISH },
   { "chinese",    RS_LANG_CHINESE },
+  { "japanese",   RS_LANG_JAPANESE },
+  { "korean",     RS_LANG_KOREAN },
   { NULL,         RS_LANG_UNSUPPORTED }
 };
 
@@ -78,6 +80,8 @@ const char *RSLanguage_ToString(RSLanguage language) {
     case  RS_LANG_TURKISH:     ret = "turkish";    break;
     case  RS_LANG_YIDDISH:     ret = "yiddish";    break;
     case  RS_LANG_CHINESE:     ret = "chinese";    break;
+    case  RS_LANG_JAPANESE:    ret = "japanese";   break;
+    case  RS_LANG_KOREAN:      ret = "korean";     break;
     case  RS_LANG_UNSUPPORTED:
     default: break;
   }
@@ -41,6 +41,8 @@ typedef enum {
   RS_LANG_ARMENIAN,
   RS_LANG_SERBIAN,
   RS_LANG_YIDDISH,
+  RS_LANG_JAPANESE,
+  RS_LANG_KOREAN,
   RS_LANG_UNSUPPORTED
 } RSLanguage;
 
@@ -5,6 +5,7 @@
  */
 
 
+#include <ctype.h>
 #include <stdio.h>
 #include <stdlib.h>
 #include <string.h>
@@ -595,6 +596,126 @@ int SynDumpCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
   return REDISMODULE_OK;
 }
 
+/* Copy a term given to FT.STEMADD or FT.STEMDEL, lowercased as the tokens are */
+static char *stemExceptionTerm(RedisModuleString *s, size_t *len) {
+  const char *str = RedisModule_StringPtrLen(s, len);
+  char *term = rm_strndup(str, *len);
+  for (size_t ii = 0; ii < *len; ++ii) {
+    term[ii] = tolower((unsigned char)term[ii]);
+  }
+  return term;
+}
+
+/* Replace the stemming exceptions of an index by a modified copy, and reindex its documents unless
+ * SKIPINITIALSCAN is given */
+static int updateStemExceptions(RedisModuleCtx *ctx, RedisModuleString **argv, int argc,
+                                bool isAdd) {
+  StrongRef ref = IndexSpec_LoadUnsafe(ctx, RedisModule_StringPtrLen(argv[1], NULL), 0);
+  IndexSpec *sp = StrongRef_Get(ref);
+  if (!sp) {
+    return RedisModule_ReplyWithError(ctx, "Unknown index name");
+  }
+
+  bool initialScan = true;
+  int offset = 2;
+  if (RMUtil_ArgIndex(SPEC_SKIPINITIALSCAN_STR, &argv[2], 1) == 0) {
+    initialScan = false;
+    offset = 3;
+  }
+  argv += offset;
+  argc -= offset;
+  if (!argc || (isAdd && argc % 2)) {
+    return RedisModule_WrongArity(ctx);
+  }
+
+  RedisSearchCtx sctx = SEARCH_CTX_STATIC(ctx, sp);
+  RedisSearchCtx_LockSpecWrite(&sctx);
+
+  // The documents being indexed keep reading the current exceptions
+  StemExceptions *se = sp->stemExceptions ? StemExceptions_Copy(sp->stemExceptions)
+                                          : NewStemExceptions();
+  long long n = 0;
+  for (int ii = 0; ii < argc; ii += isAdd ? 2 : 1) {
+    size_t len;
+    char *term = stemExceptionTerm(argv[ii], &len);
+    if (isAdd) {
+      size_t stemLen;
+      char *stem = stemExceptionTerm(argv[ii + 1], &stemLen);
+      n += StemExceptions_Add(se, term, len, stem, stemLen);
+      rm_free(stem);
+    } else {
+      n += StemExceptions_Del(se, term, len);
+    }
+    rm_free(term);
+  }
+  IndexSpec_SetStemExceptions(sp, se);
+
+  // Replacing an existing exception doesn't count as an addition, but changes the stems too
+  if (initialScan && (isAdd || n)) {
+    IndexSpec_ScanAndReindex(ctx, ref);
+  }
+  IndexSpec_UpdateVersion(sp);
+  RedisSearchCtx_UnlockSpec(&sctx);
+
+  RedisModule_ReplyWithLongLong(ctx, n);
+
+  RedisModule_ReplicateVerbatim(ctx);
+  return REDISMODULE_OK;
+}
+
+/**
+ * FT.STEMADD <index> [SKIPINITIALSCAN] <term> <stem> [<term> <stem> ...]
+ *
+ * Set the stems of terms, replacing those of the stemmer. A term given with itself as its stem is
+ * indexed without a stem. Unless SKIPINITIALSCAN is given, the documents of the index are reindexed.
+ * Returns the number of terms which had no stem set.
+ */
+int StemAddCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
+  if (argc < 4) return RedisModule_WrongArity(ctx);
+  return updateStemExceptions(ctx, argv, argc, true);
+}
+
+/**
+ * FT.STEMDEL <index> [SKIPINITIALSCAN] <term> [<term> ...]
+ *
+ * Remove the stems set to terms, which are stemmed by the stemmer again. Unless SKIPINITIALSCAN is
+ * given, the documents of the index are reindexed.
+ * Returns the number of terms which had a stem set.
+ */
+int StemDelCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
+  if (argc < 3) return RedisModule_WrongArity(ctx);
+  return updateStemExceptions(ctx, argv, argc, false);
+}
+
+/**
+ * FT.STEMDUMP <index>
+ *
+ * Dump the stems set to terms in the following format:
+ *    - term1
+ *    - stem1
+ *    - term2
+ *    - stem2
+ */
+int StemDumpCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc) {
+  if (argc != 2) return RedisModule_WrongArity(ctx);
+
+  StrongRef ref = IndexSpec_LoadUnsafe(ctx, RedisModule_StringPtrLen(argv[1], NULL), 0);
+  IndexSpec *sp = StrongRef_Get(ref);
+  if (!sp) {
+    return RedisModule_ReplyWithError(ctx, "Unknown index name");
+  }
+
+  if (!sp->stemExceptions) {
+    return RedisModule_ReplyWithArray(ctx, 0);
+  }
+
+  RedisSearchCtx sctx = SEARCH_CTX_STATIC(ctx, sp);
+  RedisSearchCtx_LockSpecRead(&sctx);
+  StemExceptions_Reply(ctx, sp->stemExceptions);
+  RedisSearchCtx_UnlockSpec(&sctx);
+  return REDISMODULE_OK;
+}
+
 static int AlterIndexInternalCommand(RedisModuleCtx *ctx, RedisModuleString **argv, int argc,
                                      bool ifnx) {
   ArgsCursor ac = {0};
@@ -624,7 +745,10 @@ static int AlterIndexInternalCommand(RedisModuleCtx *ctx, RedisModuleString **ar
     return RedisModule_ReplyWithError(ctx, "ALTER must be followed by SCHEMA");
   }
 
-  if (!AC_AdvanceIfMatch(&ac, "ADD")) {
+  bool modify = false;
+  if (AC_AdvanceIfMatch(&ac, "MODIFY")) {
+    modify = true;
+  } else if (!AC_AdvanceIfMatch(&ac, "ADD")) {
     return RedisModule_ReplyWithError(ctx, "Unknown action passed to ALTER SCHEMA");
   }
 
@@ -632,6 +756,20 @@ static int AlterIndexInternalCommand(RedisModuleCtx *ctx, RedisModuleString **ar
     return RedisModule_ReplyWithError(ctx, "No fields provided");
   }
 
+  if (modify) {
+    RedisSearchCtx_LockSpecWrite(&sctx);
+    IndexSpec_ModifyVectorField(ref, sp, ctx, &ac, &status);
+    if (QueryError_HasError(&status)) {
+      RedisSearchCtx_UnlockSpec(&sctx);
+      return QueryError_ReplyAndClear(ctx, &status);
+    }
+    IndexSpec_UpdateVersion(sp);
+    RedisSearchCtx_UnlockSpec(&sctx);
+
+    RedisModule_Replicate(ctx, RS_ALTER_IF_NX_CMD, "v", argv + 1, (size_t)argc - 1);
+    return RedisModule_ReplyWithSimpleString(ctx, "OK");
+  }
+
   if (ifnx) {
     const char *fieldName;
     size_t fieldNameSize;
@@ -1079,6 +1217,15 @@ int RediSearch_InitModuleInternal(RedisModuleCtx *ctx, RedisModuleString **argv,
   RM_TRY(RedisModule_CreateCommand, ctx, RS_SYNDUMP_CMD, SynDumpCommand, "readonly",
          INDEX_ONLY_CMD_ARGS);
 
+  RM_TRY(RedisModule_CreateCommand, ctx, RS_STEMADD_CMD, StemAddCommand, "write",
+         INDEX_ONLY_CMD_ARGS);
+
+  RM_TRY(RedisModule_CreateCommand, ctx, RS_STEMDEL_CMD, StemDelCommand, "write",
+         INDEX_ONLY_CMD_ARGS);
+
+  RM_TRY(RedisModule_CreateCommand, ctx, RS_STEMDUMP_CMD, StemDumpCommand, "readonly",
+         INDEX_ONLY_CMD_ARGS);
+
   RM_TRY(RedisModule_CreateCommand, ctx, RS_ALTER_CMD, AlterIndexCommand, "write",
          INDEX_ONLY_CMD_ARGS);
   RM_TRY(RedisModule_CreateCommand, ctx, RS_ALTER_IF_NX_CMD, AlterIndexIfNXCommand, "write",
@@ -0,0 +1,246 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "normalize.h"
+#include "tokenize.h"
+#include "toksep.h"
+#include "rmalloc.h"
+#include "libnu/libnu.h"
+
+#include <ctype.h>
+#include <stdlib.h>
+#include <string.h>
+
+typedef struct {
+  uint16_t cp;
+  const char *nfkc;
+} NFKCMapping;
+
+typedef struct {
+  uint16_t first;
+  uint16_t second;
+  uint16_t composed;
+} NFKCComposition;
+
+#include "normalize_table.h"
+
+const char *Normalize_ReadCodepoint(const char *s, const char *end, uint32_t *cp) {
+  unsigned char c = *s;
+  size_t n = (c & 0xE0) == 0xC0 ? 2 : (c & 0xF0) == 0xE0 ? 3 : (c & 0xF8) == 0xF0 ? 4 : 1;
+  if (s + n > end) {
+    return NULL;
+  } else if (n == 1) {
+    *cp = c;
+    return s + 1;
+  }
+  return nu_utf8_read(s, cp);
+}
+
+/***************************************************************************************************
+ * ASCII folding
+ **************************************************************************************************/
+
+#define ASCII_FOLDING_FIRST 0xC0
+#define ASCII_FOLDING_LAST 0x17F
+
+#define COMBINING_MARKS_FIRST 0x300
+#define COMBINING_MARKS_LAST 0x36F
+
+// ASCII forms of the letters of the Latin-1 Supplement and Latin Extended-A blocks
+static const char *asciiFolding_g[ASCII_FOLDING_LAST - ASCII_FOLDING_FIRST + 1] = {
+    // U+00C0
+    "A", "A", "A", "A", "A", "A", "AE", "C", "E", "E", "E", "E", "I", "I", "I", "I",
+    "D", "N", "O", "O", "O", "O", "O", NULL, "O", "U", "U", "U", "U", "Y", "TH", "ss",
+    "a", "a", "a", "a", "a", "a", "ae", "c", "e", "e", "e", "e", "i", "i", "i", "i",
+    "d", "n", "o", "o", "o", "o", "o", NULL, "o", "u", "u", "u", "u", "y", "th", "y",
+    // U+0100
+    "A", "a", "A", "a", "A", "a", "C", "c", "C", "c", "C", "c", "C", "c", "D", "d",
+    "D", "d", "E", "e", "E", "e", "E", "e", "E", "e", "E", "e", "G", "g", "G", "g",
+    "G", "g", "G", "g", "H", "h", "H", "h", "I", "i", "I", "i", "I", "i", "I", "i",
+    "I", "i", "IJ", "ij", "J", "j", "K", "k", "k", "L", "l", "L", "l", "L", "l", "L",
+    "l", "L", "l", "N", "n", "N", "n", "N", "n", "n", "N", "n", "O", "o", "O", "o",
+    "O", "o", "OE", "oe", "R", "r", "R", "r", "R", "r", "S", "s", "S", "s", "S", "s",
+    "S", "s", "T", "t", "T", "t", "T", "t", "U", "u", "U", "u", "U", "u", "U", "u",
+    "U", "u", "U", "u", "W", "w", "Y", "y", "Y", "Z", "z", "Z", "z", "Z", "z", "s",
+};
+
+size_t Normalize_AsciiFold(const char *s, size_t len, char *out) {
+  const char *end = s + len;
+  char *o = out;
+  while (s < end) {
+    uint32_t cp;
+    const char *next = Normalize_ReadCodepoint(s, end, &cp);
+    if (!next) {
+      break;
+    }
+    const char *folded = NULL;
+    if (cp >= ASCII_FOLDING_FIRST && cp <= ASCII_FOLDING_LAST) {
+      folded = asciiFolding_g[cp - ASCII_FOLDING_FIRST];
+    }
+    // The folded forms are never longer than the letters, so only read bytes are overwritten
+    if (folded) {
+      size_t n = strlen(folded);
+      memcpy(o, folded, n);
+      o += n;
+    } else if (cp < COMBINING_MARKS_FIRST || cp > COMBINING_MARKS_LAST) {
+      memmove(o, s, next - s);
+      o += next - s;
+    }
+    s = next;
+  }
+  // A truncated sequence at the end is kept as is
+  memmove(o, s, end - s);
+  o += end - s;
+  return o - out;
+}
+
+/***************************************************************************************************
+ * NFKC
+ **************************************************************************************************/
+
+#define HANGUL_S_BASE 0xAC00
+#define HANGUL_L_BASE 0x1100
+#define HANGUL_V_BASE 0x1161
+#define HANGUL_T_BASE 0x11A7
+#define HANGUL_L_COUNT 19
+#define HANGUL_V_COUNT 21
+#define HANGUL_T_COUNT 28
+#define HANGUL_S_COUNT (HANGUL_L_COUNT * HANGUL_V_COUNT * HANGUL_T_COUNT)
+
+static int cmpMapping(const void *key, const void *elem) {
+  uint32_t cp = *(const uint32_t *)key;
+  const NFKCMapping *m = elem;
+  return cp < m->cp ? -1 : cp > m->cp;
+}
+
+static const NFKCMapping *findMapping(uint32_t cp) {
+  if (cp < nfkcMappings_g[0].cp) {
+    return NULL;
+  }
+  return bsearch(&cp, nfkcMappings_g, sizeof(nfkcMappings_g) / sizeof(*nfkcMappings_g),
+                 sizeof(*nfkcMappings_g), cmpMapping);
+}
+
+static int cmpComposition(const void *key, const void *elem) {
+  const uint32_t *pair = key;
+  const NFKCComposition *c = elem;
+  if (pair[0] != c->first) {
+    return pair[0] < c->first ? -1 : 1;
+  }
+  return pair[1] < c->second ? -1 : pair[1] > c->second;
+}
+
+/* The character composed of two characters, or 0 if there is none */
+static uint32_t compose(uint32_t first, uint32_t second) {
+  if (first >= HANGUL_L_BASE && first < HANGUL_L_BASE + HANGUL_L_COUNT &&
+      second >= HANGUL_V_BASE && second < HANGUL_V_BASE + HANGUL_V_COUNT) {
+    return HANGUL_S_BASE +
+           ((first - HANGUL_L_BASE) * HANGUL_V_COUNT + (second - HANGUL_V_BASE)) * HANGUL_T_COUNT;
+  }
+  if (first >= HANGUL_S_BASE && first < HANGUL_S_BASE + HANGUL_S_COUNT &&
+      (first - HANGUL_S_BASE) % HANGUL_T_COUNT == 0 && second > HANGUL_T_BASE &&
+      second < HANGUL_T_BASE + HANGUL_T_COUNT) {
+    return first + (second - HANGUL_T_BASE);
+  }
+  uint32_t pair[] = {first, second};
+  const NFKCComposition *c =
+      bsearch(pair, nfkcCompositions_g, sizeof(nfkcCompositions_g) / sizeof(*nfkcCompositions_g),
+              sizeof(*nfkcCompositions_g), cmpComposition);
+  return c ? c->composed : 0;
+}
+
+typedef struct {
+  char *pos;
+  // The last character written, which may be composed with the next one. NULL after invalid bytes
+  char *lastPos;
+  uint32_t last;
+} nfkcWriter;
+
+/* Write a character, from its bytes in the text if `raw` is set */
+static void nfkcWrite(nfkcWriter *w, uint32_t cp, const char *raw, size_t rawLen) {
+  uint32_t composed = w->lastPos ? compose(w->last, cp) : 0;
+  if (composed) {
+    // A composed character is never longer than the two it replaces
+    w->pos = nu_utf8_write(composed, w->lastPos);
+    w->last = composed;
+    return;
+  }
+  w->lastPos = w->pos;
+  w->last = cp;
+  if (raw) {
+    memcpy(w->pos, raw, rawLen);
+    w->pos += rawLen;
+  } else {
+    w->pos = nu_utf8_write(cp, w->pos);
+  }
+}
+
+size_t Normalize_NFKCMaxLen(size_t len) {
+  return len * NFKC_MAX_EXPANSION;
+}
+
+size_t Normalize_NFKC(const char *s, size_t len, char *out) {
+  const char *end = s + len;
+  nfkcWriter w = {.pos = out};
+  while (s < end) {
+    uint32_t cp;
+    const char *next = Normalize_ReadCodepoint(s, end, &cp);
+    if (!next) {
+      break;
+    }
+    const NFKCMapping *m = findMapping(cp);
+    if (m) {
+      for (const char *p = m->nfkc; *p;) {
+        p = nu_utf8_read(p, &cp);
+        nfkcWrite(&w, cp, NULL, 0);
+      }
+    } else if (cp >= 0x80 && next - s == 1) {
+      // Invalid bytes are copied, and are never composed
+      *w.pos++ = *s;
+      w.lastPos = NULL;
+    } else {
+      nfkcWrite(&w, cp, s, next - s);
+    }
+    s = next;
+  }
+  // A truncated sequence at the end is kept as is
+  memcpy(w.pos, s, end - s);
+  w.pos += end - s;
+  return w.pos - out;
+}
+
+bool Normalize_IsNFKCSeparator(uint32_t cp) {
+  if (cp < 0x80) {
+    return false;
+  }
+  const NFKCMapping *m = findMapping(cp);
+  return m && m->nfkc[1] == '\0' && istoksep(m->nfkc[0]);
+}
+
+char *Normalize_Token(const char *s, size_t *len, uint32_t options, char **buf, size_t *cap) {
+  size_t n = *len;
+  size_t maxLen = (options & TOKENIZE_NFKC) ? Normalize_NFKCMaxLen(n) : n;
+  if (maxLen + 1 > *cap) {
+    *cap = maxLen + 1;
+    *buf = rm_realloc(*buf, *cap);
+  }
+  char *out = *buf;
+
+  if (options & TOKENIZE_NFKC) {
+    n = Normalize_NFKC(s, n, out);
+  } else {
+    memmove(out, s, n);
+  }
+  if (options & TOKENIZE_ASCIIFOLD) {
+    n = Normalize_AsciiFold(out, n, out);
+  }
+  for (size_t ii = 0; ii < n; ++ii) {
+    out[ii] = tolower((unsigned char)out[ii]);
+  }
+  out[n] = '\0';
+  *len = n;
+  return out;
+}
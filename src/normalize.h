@@ -0,0 +1,54 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Unicode normalization of terms, used by the ASCIIFOLD and NFKC options of TEXT fields and by
+ * the ASCIIFOLDING analyzer filter.
+ *
+ * The NFKC normalization covers the compatibility characters of the Latin, punctuation, letterlike,
+ * number, enclosed alphanumeric, Hangul compatibility jamo and halfwidth and fullwidth blocks, and
+ * composes the Latin letters, kana and Hangul syllables written with combining characters. Other
+ * characters are left as is.
+ */
+
+/* Read the code point at `s`, returning the position of the next one, or NULL if the sequence is
+ * truncated. Invalid bytes are read as code points of their own */
+const char *Normalize_ReadCodepoint(const char *s, const char *end, uint32_t *cp);
+
+/* Replace the Latin letters with diacritics by their ASCII form, and drop combining diacritical
+ * marks. The output is never longer than the input, so `out` may be `s`. Returns the length of
+ * the output */
+size_t Normalize_AsciiFold(const char *s, size_t len, char *out);
+
+/* The maximal length of the NFKC form of `len` bytes */
+size_t Normalize_NFKCMaxLen(size_t len);
+
+/* Write the NFKC form of `len` bytes of `s` into `out`, which holds at least
+ * Normalize_NFKCMaxLen(len) bytes. Returns the length of the output */
+size_t Normalize_NFKC(const char *s, size_t len, char *out);
+
+/* Whether the NFKC form of a character is an ASCII separator, e.g. fullwidth punctuation and
+ * ideographic spaces */
+bool Normalize_IsNFKCSeparator(uint32_t cp);
+
+/* Normalize a token with the TOKENIZE_NFKC and TOKENIZE_ASCIIFOLD options, and lowercase the
+ * ASCII letters of the result. The token is written to `*buf`, which is grown as needed.
+ * Returns the normalized token and sets `len` to its length */
+char *Normalize_Token(const char *s, size_t *len, uint32_t options, char **buf, size_t *cap);
+
+#ifdef __cplusplus
+}
+#endif
@@ -0,0 +1,1271 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+// Generated by srcutil/gen_normalize_table.py from Unicode 14.0.0. Do not edit.
+
+#pragma once
+
+#define NFKC_MAX_EXPANSION 4
+
+static const NFKCMapping nfkcMappings_g[] = {
+    {0x00A0, "\x20"},
+    {0x00A8, "\x20\xcc\x88"},
+    {0x00AA, "\x61"},
+    {0x00AF, "\x20\xcc\x84"},
+    {0x00B2, "\x32"},
+    {0x00B3, "\x33"},
+    {0x00B4, "\x20\xcc\x81"},
+    {0x00B5, "\xce\xbc"},
+    {0x00B8, "\x20\xcc\xa7"},
+    {0x00B9, "\x31"},
+    {0x00BA, "\x6f"},
+    {0x00BC, "\x31\xe2\x81\x84\x34"},
+    {0x00BD, "\x31\xe2\x81\x84\x32"},
+    {0x00BE, "\x33\xe2\x81\x84\x34"},
+    {0x0132, "\x49\x4a"},
+    {0x0133, "\x69\x6a"},
+    {0x013F, "\x4c\xc2\xb7"},
+    {0x0140, "\x6c\xc2\xb7"},
+    {0x0149, "\xca\xbc\x6e"},
+    {0x017F, "\x73"},
+    {0x01C4, "\x44\xc5\xbd"},
+    {0x01C5, "\x44\xc5\xbe"},
+    {0x01C6, "\x64\xc5\xbe"},
+    {0x01C7, "\x4c\x4a"},
+    {0x01C8, "\x4c\x6a"},
+    {0x01C9, "\x6c\x6a"},
+    {0x01CA, "\x4e\x4a"},
+    {0x01CB, "\x4e\x6a"},
+    {0x01CC, "\x6e\x6a"},
+    {0x01F1, "\x44\x5a"},
+    {0x01F2, "\x44\x7a"},
+    {0x01F3, "\x64\x7a"},
+    {0x02B0, "\x68"},
+    {0x02B1, "\xc9\xa6"},
+    {0x02B2, "\x6a"},
+    {0x02B3, "\x72"},
+    {0x02B4, "\xc9\xb9"},
+    {0x02B5, "\xc9\xbb"},
+    {0x02B6, "\xca\x81"},
+    {0x02B7, "\x77"},
+    {0x02B8, "\x79"},
+    {0x02D8, "\x20\xcc\x86"},
+    {0x02D9, "\x20\xcc\x87"},
+    {0x02DA, "\x20\xcc\x8a"},
+    {0x02DB, "\x20\xcc\xa8"},
+    {0x02DC, "\x20\xcc\x83"},
+    {0x02DD, "\x20\xcc\x8b"},
+    {0x02E0, "\xc9\xa3"},
+    {0x02E1, "\x6c"},
+    {0x02E2, "\x73"},
+    {0x02E3, "\x78"},
+    {0x02E4, "\xca\x95"},
+    {0x1E9A, "\x61\xca\xbe"},
+    {0x1E9B, "\xe1\xb9\xa1"},
+    {0x2000, "\x20"},
+    {0x2001, "\x20"},
+    {0x2002, "\x20"},
+    {0x2003, "\x20"},
+    {0x2004, "\x20"},
+    {0x2005, "\x20"},
+    {0x2006, "\x20"},
+    {0x2007, "\x20"},
+    {0x2008, "\x20"},
+    {0x2009, "\x20"},
+    {0x200A, "\x20"},
+    {0x2011, "\xe2\x80\x90"},
+    {0x2017, "\x20\xcc\xb3"},
+    {0x2024, "\x2e"},
+    {0x2025, "\x2e\x2e"},
+    {0x2026, "\x2e\x2e\x2e"},
+    {0x202F, "\x20"},
+    {0x2033, "\xe2\x80\xb2\xe2\x80\xb2"},
+    {0x2034, "\xe2\x80\xb2\xe2\x80\xb2\xe2\x80\xb2"},
+    {0x2036, "\xe2\x80\xb5\xe2\x80\xb5"},
+    {0x2037, "\xe2\x80\xb5\xe2\x80\xb5\xe2\x80\xb5"},
+    {0x203C, "\x21\x21"},
+    {0x203E, "\x20\xcc\x85"},
+    {0x2047, "\x3f\x3f"},
+    {0x2048, "\x3f\x21"},
+    {0x2049, "\x21\x3f"},
+    {0x2057, "\xe2\x80\xb2\xe2\x80\xb2\xe2\x80\xb2\xe2\x80\xb2"},
+    {0x205F, "\x20"},
+    {0x2070, "\x30"},
+    {0x2071, "\x69"},
+    {0x2074, "\x34"},
+    {0x2075, "\x35"},
+    {0x2076, "\x36"},
+    {0x2077, "\x37"},
+    {0x2078, "\x38"},
+    {0x2079, "\x39"},
+    {0x207A, "\x2b"},
+    {0x207B, "\xe2\x88\x92"},
+    {0x207C, "\x3d"},
+    {0x207D, "\x28"},
+    {0x207E, "\x29"},
+    {0x207F, "\x6e"},
+    {0x2080, "\x30"},
+    {0x2081, "\x31"},
+    {0x2082, "\x32"},
+    {0x2083, "\x33"},
+    {0x2084, "\x34"},
+    {0x2085, "\x35"},
+    {0x2086, "\x36"},
+    {0x2087, "\x37"},
+    {0x2088, "\x38"},
+    {0x2089, "\x39"},
+    {0x208A, "\x2b"},
+    {0x208B, "\xe2\x88\x92"},
+    {0x208C, "\x3d"},
+    {0x208D, "\x28"},
+    {0x208E, "\x29"},
+    {0x2090, "\x61"},
+    {0x2091, "\x65"},
+    {0x2092, "\x6f"},
+    {0x2093, "\x78"},
+    {0x2094, "\xc9\x99"},
+    {0x2095, "\x68"},
+    {0x2096, "\x6b"},
+    {0x2097, "\x6c"},
+    {0x2098, "\x6d"},
+    {0x2099, "\x6e"},
+    {0x209A, "\x70"},
+    {0x209B, "\x73"},
+    {0x209C, "\x74"},
+    {0x2100, "\x61\x2f\x63"},
+    {0x2101, "\x61\x2f\x73"},
+    {0x2102, "\x43"},
+    {0x2103, "\xc2\xb0\x43"},
+    {0x2105, "\x63\x2f\x6f"},
+    {0x2106, "\x63\x2f\x75"},
+    {0x2107, "\xc6\x90"},
+    {0x2109, "\xc2\xb0\x46"},
+    {0x210A, "\x67"},
+    {0x210B, "\x48"},
+    {0x210C, "\x48"},
+    {0x210D, "\x48"},
+    {0x210E, "\x68"},
+    {0x210F, "\xc4\xa7"},
+    {0x2110, "\x49"},
+    {0x2111, "\x49"},
+    {0x2112, "\x4c"},
+    {0x2113, "\x6c"},
+    {0x2115, "\x4e"},
+    {0x2116, "\x4e\x6f"},
+    {0x2119, "\x50"},
+    {0x211A, "\x51"},
+    {0x211B, "\x52"},
+    {0x211C, "\x52"},
+    {0x211D, "\x52"},
+    {0x2120, "\x53\x4d"},
+    {0x2121, "\x54\x45\x4c"},
+    {0x2122, "\x54\x4d"},
+    {0x2124, "\x5a"},
+    {0x2126, "\xce\xa9"},
+    {0x2128, "\x5a"},
+    {0x212A, "\x4b"},
+    {0x212B, "\xc3\x85"},
+    {0x212C, "\x42"},
+    {0x212D, "\x43"},
+    {0x212F, "\x65"},
+    {0x2130, "\x45"},
+    {0x2131, "\x46"},
+    {0x2133, "\x4d"},
+    {0x2134, "\x6f"},
+    {0x2135, "\xd7\x90"},
+    {0x2136, "\xd7\x91"},
+    {0x2137, "\xd7\x92"},
+    {0x2138, "\xd7\x93"},
+    {0x2139, "\x69"},
+    {0x213B, "\x46\x41\x58"},
+    {0x213C, "\xcf\x80"},
+    {0x213D, "\xce\xb3"},
+    {0x213E, "\xce\x93"},
+    {0x213F, "\xce\xa0"},
+    {0x2140, "\xe2\x88\x91"},
+    {0x2145, "\x44"},
+    {0x2146, "\x64"},
+    {0x2147, "\x65"},
+    {0x2148, "\x69"},
+    {0x2149, "\x6a"},
+    {0x2150, "\x31\xe2\x81\x84\x37"},
+    {0x2151, "\x31\xe2\x81\x84\x39"},
+    {0x2152, "\x31\xe2\x81\x84\x31\x30"},
+    {0x2153, "\x31\xe2\x81\x84\x33"},
+    {0x2154, "\x32\xe2\x81\x84\x33"},
+    {0x2155, "\x31\xe2\x81\x84\x35"},
+    {0x2156, "\x32\xe2\x81\x84\x35"},
+    {0x2157, "\x33\xe2\x81\x84\x35"},
+    {0x2158, "\x34\xe2\x81\x84\x35"},
+    {0x2159, "\x31\xe2\x81\x84\x36"},
+    {0x215A, "\x35\xe2\x81\x84\x36"},
+    {0x215B, "\x31\xe2\x81\x84\x38"},
+    {0x215C, "\x33\xe2\x81\x84\x38"},
+    {0x215D, "\x35\xe2\x81\x84\x38"},
+    {0x215E, "\x37\xe2\x81\x84\x38"},
+    {0x215F, "\x31\xe2\x81\x84"},
+    {0x2160, "\x49"},
+    {0x2161, "\x49\x49"},
+    {0x2162, "\x49\x49\x49"},
+    {0x2163, "\x49\x56"},
+    {0x2164, "\x56"},
+    {0x2165, "\x56\x49"},
+    {0x2166, "\x56\x49\x49"},
+    {0x2167, "\x56\x49\x49\x49"},
+    {0x2168, "\x49\x58"},
+    {0x2169, "\x58"},
+    {0x216A, "\x58\x49"},
+    {0x216B, "\x58\x49\x49"},
+    {0x216C, "\x4c"},
+    {0x216D, "\x43"},
+    {0x216E, "\x44"},
+    {0x216F, "\x4d"},
+    {0x2170, "\x69"},
+    {0x2171, "\x69\x69"},
+    {0x2172, "\x69\x69\x69"},
+    {0x2173, "\x69\x76"},
+    {0x2174, "\x76"},
+    {0x2175, "\x76\x69"},
+    {0x2176, "\x76\x69\x69"},
+    {0x2177, "\x76\x69\x69\x69"},
+    {0x2178, "\x69\x78"},
+    {0x2179, "\x78"},
+    {0x217A, "\x78\x69"},
+    {0x217B, "\x78\x69\x69"},
+    {0x217C, "\x6c"},
+    {0x217D, "\x63"},
+    {0x217E, "\x64"},
+    {0x217F, "\x6d"},
+    {0x2189, "\x30\xe2\x81\x84\x33"},
+    {0x2460, "\x31"},
+    {0x2461, "\x32"},
+    {0x2462, "\x33"},
+    {0x2463, "\x34"},
+    {0x2464, "\x35"},
+    {0x2465, "\x36"},
+    {0x2466, "\x37"},
+    {0x2467, "\x38"},
+    {0x2468, "\x39"},
+    {0x2469, "\x31\x30"},
+    {0x246A, "\x31\x31"},
+    {0x246B, "\x31\x32"},
+    {0x246C, "\x31\x33"},
+    {0x246D, "\x31\x34"},
+    {0x246E, "\x31\x35"},
+    {0x246F, "\x31\x36"},
+    {0x2470, "\x31\x37"},
+    {0x2471, "\x31\x38"},
+    {0x2472, "\x31\x39"},
+    {0x2473, "\x32\x30"},
+    {0x2474, "\x28\x31\x29"},
+    {0x2475, "\x28\x32\x29"},
+    {0x2476, "\x28\x33\x29"},
+    {0x2477, "\x28\x34\x29"},
+    {0x2478, "\x28\x35\x29"},
+    {0x2479, "\x28\x36\x29"},
+    {0x247A, "\x28\x37\x29"},
+    {0x247B, "\x28\x38\x29"},
+    {0x247C, "\x28\x39\x29"},
+    {0x247D, "\x28\x31\x30\x29"},
+    {0x247E, "\x28\x31\x31\x29"},
+    {0x247F, "\x28\x31\x32\x29"},
+    {0x2480, "\x28\x31\x33\x29"},
+    {0x2481, "\x28\x31\x34\x29"},
+    {0x2482, "\x28\x31\x35\x29"},
+    {0x2483, "\x28\x31\x36\x29"},
+    {0x2484, "\x28\x31\x37\x29"},
+    {0x2485, "\x28\x31\x38\x29"},
+    {0x2486, "\x28\x31\x39\x29"},
+    {0x2487, "\x28\x32\x30\x29"},
+    {0x2488, "\x31\x2e"},
+    {0x2489, "\x32\x2e"},
+    {0x248A, "\x33\x2e"},
+    {0x248B, "\x34\x2e"},
+    {0x248C, "\x35\x2e"},
+    {0x248D, "\x36\x2e"},
+    {0x248E, "\x37\x2e"},
+    {0x248F, "\x38\x2e"},
+    {0x2490, "\x39\x2e"},
+    {0x2491, "\x31\x30\x2e"},
+    {0x2492, "\x31\x31\x2e"},
+    {0x2493, "\x31\x32\x2e"},
+    {0x2494, "\x31\x33\x2e"},
+    {0x2495, "\x31\x34\x2e"},
+    {0x2496, "\x31\x35\x2e"},
+    {0x2497, "\x31\x36\x2e"},
+    {0x2498, "\x31\x37\x2e"},
+    {0x2499, "\x31\x38\x2e"},
+    {0x249A, "\x31\x39\x2e"},
+    {0x249B, "\x32\x30\x2e"},
+    {0x249C, "\x28\x61\x29"},
+    {0x249D, "\x28\x62\x29"},
+    {0x249E, "\x28\x63\x29"},
+    {0x249F, "\x28\x64\x29"},
+    {0x24A0, "\x28\x65\x29"},
+    {0x24A1, "\x28\x66\x29"},
+    {0x24A2, "\x28\x67\x29"},
+    {0x24A3, "\x28\x68\x29"},
+    {0x24A4, "\x28\x69\x29"},
+    {0x24A5, "\x28\x6a\x29"},
+    {0x24A6, "\x28\x6b\x29"},
+    {0x24A7, "\x28\x6c\x29"},
+    {0x24A8, "\x28\x6d\x29"},
+    {0x24A9, "\x28\x6e\x29"},
+    {0x24AA, "\x28\x6f\x29"},
+    {0x24AB, "\x28\x70\x29"},
+    {0x24AC, "\x28\x71\x29"},
+    {0x24AD, "\x28\x72\x29"},
+    {0x24AE, "\x28\x73\x29"},
+    {0x24AF, "\x28\x74\x29"},
+    {0x24B0, "\x28\x75\x29"},
+    {0x24B1, "\x28\x76\x29"},
+    {0x24B2, "\x28\x77\x29"},
+    {0x24B3, "\x28\x78\x29"},
+    {0x24B4, "\x28\x79\x29"},
+    {0x24B5, "\x28\x7a\x29"},
+    {0x24B6, "\x41"},
+    {0x24B7, "\x42"},
+    {0x24B8, "\x43"},
+    {0x24B9, "\x44"},
+    {0x24BA, "\x45"},
+    {0x24BB, "\x46"},
+    {0x24BC, "\x47"},
+    {0x24BD, "\x48"},
+    {0x24BE, "\x49"},
+    {0x24BF, "\x4a"},
+    {0x24C0, "\x4b"},
+    {0x24C1, "\x4c"},
+    {0x24C2, "\x4d"},
+    {0x24C3, "\x4e"},
+    {0x24C4, "\x4f"},
+    {0x24C5, "\x50"},
+    {0x24C6, "\x51"},
+    {0x24C7, "\x52"},
+    {0x24C8, "\x53"},
+    {0x24C9, "\x54"},
+    {0x24CA, "\x55"},
+    {0x24CB, "\x56"},
+    {0x24CC, "\x57"},
+    {0x24CD, "\x58"},
+    {0x24CE, "\x59"},
+    {0x24CF, "\x5a"},
+    {0x24D0, "\x61"},
+    {0x24D1, "\x62"},
+    {0x24D2, "\x63"},
+    {0x24D3, "\x64"},
+    {0x24D4, "\x65"},
+    {0x24D5, "\x66"},
+    {0x24D6, "\x67"},
+    {0x24D7, "\x68"},
+    {0x24D8, "\x69"},
+    {0x24D9, "\x6a"},
+    {0x24DA, "\x6b"},
+    {0x24DB, "\x6c"},
+    {0x24DC, "\x6d"},
+    {0x24DD, "\x6e"},
+    {0x24DE, "\x6f"},
+    {0x24DF, "\x70"},
+    {0x24E0, "\x71"},
+    {0x24E1, "\x72"},
+    {0x24E2, "\x73"},
+    {0x24E3, "\x74"},
+    {0x24E4, "\x75"},
+    {0x24E5, "\x76"},
+    {0x24E6, "\x77"},
+    {0x24E7, "\x78"},
+    {0x24E8, "\x79"},
+    {0x24E9, "\x7a"},
+    {0x24EA, "\x30"},
+    {0x3000, "\x20"},
+    {0x3036, "\xe3\x80\x92"},
+    {0x3038, "\xe5\x8d\x81"},
+    {0x3039, "\xe5\x8d\x84"},
+    {0x303A, "\xe5\x8d\x85"},
+    {0x3131, "\xe1\x84\x80"},
+    {0x3132, "\xe1\x84\x81"},
+    {0x3133, "\xe1\x86\xaa"},
+    {0x3134, "\xe1\x84\x82"},
+    {0x3135, "\xe1\x86\xac"},
+    {0x3136, "\xe1\x86\xad"},
+    {0x3137, "\xe1\x84\x83"},
+    {0x3138, "\xe1\x84\x84"},
+    {0x3139, "\xe1\x84\x85"},
+    {0x313A, "\xe1\x86\xb0"},
+    {0x313B, "\xe1\x86\xb1"},
+    {0x313C, "\xe1\x86\xb2"},
+    {0x313D, "\xe1\x86\xb3"},
+    {0x313E, "\xe1\x86\xb4"},
+    {0x313F, "\xe1\x86\xb5"},
+    {0x3140, "\xe1\x84\x9a"},
+    {0x3141, "\xe1\x84\x86"},
+    {0x3142, "\xe1\x84\x87"},
+    {0x3143, "\xe1\x84\x88"},
+    {0x3144, "\xe1\x84\xa1"},
+    {0x3145, "\xe1\x84\x89"},
+    {0x3146, "\xe1\x84\x8a"},
+    {0x3147, "\xe1\x84\x8b"},
+    {0x3148, "\xe1\x84\x8c"},
+    {0x3149, "\xe1\x84\x8d"},
+    {0x314A, "\xe1\x84\x8e"},
+    {0x314B, "\xe1\x84\x8f"},
+    {0x314C, "\xe1\x84\x90"},
+    {0x314D, "\xe1\x84\x91"},
+    {0x314E, "\xe1\x84\x92"},
+    {0x314F, "\xe1\x85\xa1"},
+    {0x3150, "\xe1\x85\xa2"},
+    {0x3151, "\xe1\x85\xa3"},
+    {0x3152, "\xe1\x85\xa4"},
+    {0x3153, "\xe1\x85\xa5"},
+    {0x3154, "\xe1\x85\xa6"},
+    {0x3155, "\xe1\x85\xa7"},
+    {0x3156, "\xe1\x85\xa8"},
+    {0x3157, "\xe1\x85\xa9"},
+    {0x3158, "\xe1\x85\xaa"},
+    {0x3159, "\xe1\x85\xab"},
+    {0x315A, "\xe1\x85\xac"},
+    {0x315B, "\xe1\x85\xad"},
+    {0x315C, "\xe1\x85\xae"},
+    {0x315D, "\xe1\x85\xaf"},
+    {0x315E, "\xe1\x85\xb0"},
+    {0x315F, "\xe1\x85\xb1"},
+    {0x3160, "\xe1\x85\xb2"},
+    {0x3161, "\xe1\x85\xb3"},
+    {0x3162, "\xe1\x85\xb4"},
+    {0x3163, "\xe1\x85\xb5"},
+    {0x3164, "\xe1\x85\xa0"},
+    {0x3165, "\xe1\x84\x94"},
+    {0x3166, "\xe1\x84\x95"},
+    {0x3167, "\xe1\x87\x87"},
+    {0x3168, "\xe1\x87\x88"},
+    {0x3169, "\xe1\x87\x8c"},
+    {0x316A, "\xe1\x87\x8e"},
+    {0x316B, "\xe1\x87\x93"},
+    {0x316C, "\xe1\x87\x97"},
+    {0x316D, "\xe1\x87\x99"},
+    {0x316E, "\xe1\x84\x9c"},
+    {0x316F, "\xe1\x87\x9d"},
+    {0x3170, "\xe1\x87\x9f"},
+    {0x3171, "\xe1\x84\x9d"},
+    {0x3172, "\xe1\x84\x9e"},
+    {0x3173, "\xe1\x84\xa0"},
+    {0x3174, "\xe1\x84\xa2"},
+    {0x3175, "\xe1\x84\xa3"},
+    {0x3176, "\xe1\x84\xa7"},
+    {0x3177, "\xe1\x84\xa9"},
+    {0x3178, "\xe1\x84\xab"},
+    {0x3179, "\xe1\x84\xac"},
+    {0x317A, "\xe1\x84\xad"},
+    {0x317B, "\xe1\x84\xae"},
+    {0x317C, "\xe1\x84\xaf"},
+    {0x317D, "\xe1\x84\xb2"},
+    {0x317E, "\xe1\x84\xb6"},
+    {0x317F, "\xe1\x85\x80"},
+    {0x3180, "\xe1\x85\x87"},
+    {0x3181, "\xe1\x85\x8c"},
+    {0x3182, "\xe1\x87\xb1"},
+    {0x3183, "\xe1\x87\xb2"},
+    {0x3184, "\xe1\x85\x97"},
+    {0x3185, "\xe1\x85\x98"},
+    {0x3186, "\xe1\x85\x99"},
+    {0x3187, "\xe1\x86\x84"},
+    {0x3188, "\xe1\x86\x85"},
+    {0x3189, "\xe1\x86\x88"},
+    {0x318A, "\xe1\x86\x91"},
+    {0x318B, "\xe1\x86\x92"},
+    {0x318C, "\xe1\x86\x94"},
+    {0x318D, "\xe1\x86\x9e"},
+    {0x318E, "\xe1\x86\xa1"},
+    {0xFB00, "\x66\x66"},
+    {0xFB01, "\x66\x69"},
+    {0xFB02, "\x66\x6c"},
+    {0xFB03, "\x66\x66\x69"},
+    {0xFB04, "\x66\x66\x6c"},
+    {0xFB05, "\x73\x74"},
+    {0xFB06, "\x73\x74"},
+    {0xFF01, "\x21"},
+    {0xFF02, "\x22"},
+    {0xFF03, "\x23"},
+    {0xFF04, "\x24"},
+    {0xFF05, "\x25"},
+    {0xFF06, "\x26"},
+    {0xFF07, "\x27"},
+    {0xFF08, "\x28"},
+    {0xFF09, "\x29"},
+    {0xFF0A, "\x2a"},
+    {0xFF0B, "\x2b"},
+    {0xFF0C, "\x2c"},
+    {0xFF0D, "\x2d"},
+    {0xFF0E, "\x2e"},
+    {0xFF0F, "\x2f"},
+    {0xFF10, "\x30"},
+    {0xFF11, "\x31"},
+    {0xFF12, "\x32"},
+    {0xFF13, "\x33"},
+    {0xFF14, "\x34"},
+    {0xFF15, "\x35"},
+    {0xFF16, "\x36"},
+    {0xFF17, "\x37"},
+    {0xFF18, "\x38"},
+    {0xFF19, "\x39"},
+    {0xFF1A, "\x3a"},
+    {0xFF1B, "\x3b"},
+    {0xFF1C, "\x3c"},
+    {0xFF1D, "\x3d"},
+    {0xFF1E, "\x3e"},
+    {0xFF1F, "\x3f"},
+    {0xFF20, "\x40"},
+    {0xFF21, "\x41"},
+    {0xFF22, "\x42"},
+    {0xFF23, "\x43"},
+    {0xFF24, "\x44"},
+    {0xFF25, "\x45"},
+    {0xFF26, "\x46"},
+    {0xFF27, "\x47"},
+    {0xFF28, "\x48"},
+    {0xFF29, "\x49"},
+    {0xFF2A, "\x4a"},
+    {0xFF2B, "\x4b"},
+    {0xFF2C, "\x4c"},
+    {0xFF2D, "\x4d"},
+    {0xFF2E, "\x4e"},
+    {0xFF2F, "\x4f"},
+    {0xFF30, "\x50"},
+    {0xFF31, "\x51"},
+    {0xFF32, "\x52"},
+    {0xFF33, "\x53"},
+    {0xFF34, "\x54"},
+    {0xFF35, "\x55"},
+    {0xFF36, "\x56"},
+    {0xFF37, "\x57"},
+    {0xFF38, "\x58"},
+    {0xFF39, "\x59"},
+    {0xFF3A, "\x5a"},
+    {0xFF3B, "\x5b"},
+    {0xFF3C, "\x5c"},
+    {0xFF3D, "\x5d"},
+    {0xFF3E, "\x5e"},
+    {0xFF3F, "\x5f"},
+    {0xFF40, "\x60"},
+    {0xFF41, "\x61"},
+    {0xFF42, "\x62"},
+    {0xFF43, "\x63"},
+    {0xFF44, "\x64"},
+    {0xFF45, "\x65"},
+    {0xFF46, "\x66"},
+    {0xFF47, "\x67"},
+    {0xFF48, "\x68"},
+    {0xFF49, "\x69"},
+    {0xFF4A, "\x6a"},
+    {0xFF4B, "\x6b"},
+    {0xFF4C, "\x6c"},
+    {0xFF4D, "\x6d"},
+    {0xFF4E, "\x6e"},
+    {0xFF4F, "\x6f"},
+    {0xFF50, "\x70"},
+    {0xFF51, "\x71"},
+    {0xFF52, "\x72"},
+    {0xFF53, "\x73"},
+    {0xFF54, "\x74"},
+    {0xFF55, "\x75"},
+    {0xFF56, "\x76"},
+    {0xFF57, "\x77"},
+    {0xFF58, "\x78"},
+    {0xFF59, "\x79"},
+    {0xFF5A, "\x7a"},
+    {0xFF5B, "\x7b"},
+    {0xFF5C, "\x7c"},
+    {0xFF5D, "\x7d"},
+    {0xFF5E, "\x7e"},
+    {0xFF5F, "\xe2\xa6\x85"},
+    {0xFF60, "\xe2\xa6\x86"},
+    {0xFF61, "\xe3\x80\x82"},
+    {0xFF62, "\xe3\x80\x8c"},
+    {0xFF63, "\xe3\x80\x8d"},
+    {0xFF64, "\xe3\x80\x81"},
+    {0xFF65, "\xe3\x83\xbb"},
+    {0xFF66, "\xe3\x83\xb2"},
+    {0xFF67, "\xe3\x82\xa1"},
+    {0xFF68, "\xe3\x82\xa3"},
+    {0xFF69, "\xe3\x82\xa5"},
+    {0xFF6A, "\xe3\x82\xa7"},
+    {0xFF6B, "\xe3\x82\xa9"},
+    {0xFF6C, "\xe3\x83\xa3"},
+    {0xFF6D, "\xe3\x83\xa5"},
+    {0xFF6E, "\xe3\x83\xa7"},
+    {0xFF6F, "\xe3\x83\x83"},
+    {0xFF70, "\xe3\x83\xbc"},
+    {0xFF71, "\xe3\x82\xa2"},
+    {0xFF72, "\xe3\x82\xa4"},
+    {0xFF73, "\xe3\x82\xa6"},
+    {0xFF74, "\xe3\x82\xa8"},
+    {0xFF75, "\xe3\x82\xaa"},
+    {0xFF76, "\xe3\x82\xab"},
+    {0xFF77, "\xe3\x82\xad"},
+    {0xFF78, "\xe3\x82\xaf"},
+    {0xFF79, "\xe3\x82\xb1"},
+    {0xFF7A, "\xe3\x82\xb3"},
+    {0xFF7B, "\xe3\x82\xb5"},
+    {0xFF7C, "\xe3\x82\xb7"},
+    {0xFF7D, "\xe3\x82\xb9"},
+    {0xFF7E, "\xe3\x82\xbb"},
+    {0xFF7F, "\xe3\x82\xbd"},
+    {0xFF80, "\xe3\x82\xbf"},
+    {0xFF81, "\xe3\x83\x81"},
+    {0xFF82, "\xe3\x83\x84"},
+    {0xFF83, "\xe3\x83\x86"},
+    {0xFF84, "\xe3\x83\x88"},
+    {0xFF85, "\xe3\x83\x8a"},
+    {0xFF86, "\xe3\x83\x8b"},
+    {0xFF87, "\xe3\x83\x8c"},
+    {0xFF88, "\xe3\x83\x8d"},
+    {0xFF89, "\xe3\x83\x8e"},
+    {0xFF8A, "\xe3\x83\x8f"},
+    {0xFF8B, "\xe3\x83\x92"},
+    {0xFF8C, "\xe3\x83\x95"},
+    {0xFF8D, "\xe3\x83\x98"},
+    {0xFF8E, "\xe3\x83\x9b"},
+    {0xFF8F, "\xe3\x83\x9e"},
+    {0xFF90, "\xe3\x83\x9f"},
+    {0xFF91, "\xe3\x83\xa0"},
+    {0xFF92, "\xe3\x83\xa1"},
+    {0xFF93, "\xe3\x83\xa2"},
+    {0xFF94, "\xe3\x83\xa4"},
+    {0xFF95, "\xe3\x83\xa6"},
+    {0xFF96, "\xe3\x83\xa8"},
+    {0xFF97, "\xe3\x83\xa9"},
+    {0xFF98, "\xe3\x83\xaa"},
+    {0xFF99, "\xe3\x83\xab"},
+    {0xFF9A, "\xe3\x83\xac"},
+    {0xFF9B, "\xe3\x83\xad"},
+    {0xFF9C, "\xe3\x83\xaf"},
+    {0xFF9D, "\xe3\x83\xb3"},
+    {0xFF9E, "\xe3\x82\x99"},
+    {0xFF9F, "\xe3\x82\x9a"},
+    {0xFFA0, "\xe1\x85\xa0"},
+    {0xFFA1, "\xe1\x84\x80"},
+    {0xFFA2, "\xe1\x84\x81"},
+    {0xFFA3, "\xe1\x86\xaa"},
+    {0xFFA4, "\xe1\x84\x82"},
+    {0xFFA5, "\xe1\x86\xac"},
+    {0xFFA6, "\xe1\x86\xad"},
+    {0xFFA7, "\xe1\x84\x83"},
+    {0xFFA8, "\xe1\x84\x84"},
+    {0xFFA9, "\xe1\x84\x85"},
+    {0xFFAA, "\xe1\x86\xb0"},
+    {0xFFAB, "\xe1\x86\xb1"},
+    {0xFFAC, "\xe1\x86\xb2"},
+    {0xFFAD, "\xe1\x86\xb3"},
+    {0xFFAE, "\xe1\x86\xb4"},
+    {0xFFAF, "\xe1\x86\xb5"},
+    {0xFFB0, "\xe1\x84\x9a"},
+    {0xFFB1, "\xe1\x84\x86"},
+    {0xFFB2, "\xe1\x84\x87"},
+    {0xFFB3, "\xe1\x84\x88"},
+    {0xFFB4, "\xe1\x84\xa1"},
+    {0xFFB5, "\xe1\x84\x89"},
+    {0xFFB6, "\xe1\x84\x8a"},
+    {0xFFB7, "\xe1\x84\x8b"},
+    {0xFFB8, "\xe1\x84\x8c"},
+    {0xFFB9, "\xe1\x84\x8d"},
+    {0xFFBA, "\xe1\x84\x8e"},
+    {0xFFBB, "\xe1\x84\x8f"},
+    {0xFFBC, "\xe1\x84\x90"},
+    {0xFFBD, "\xe1\x84\x91"},
+    {0xFFBE, "\xe1\x84\x92"},
+    {0xFFC2, "\xe1\x85\xa1"},
+    {0xFFC3, "\xe1\x85\xa2"},
+    {0xFFC4, "\xe1\x85\xa3"},
+    {0xFFC5, "\xe1\x85\xa4"},
+    {0xFFC6, "\xe1\x85\xa5"},
+    {0xFFC7, "\xe1\x85\xa6"},
+    {0xFFCA, "\xe1\x85\xa7"},
+    {0xFFCB, "\xe1\x85\xa8"},
+    {0xFFCC, "\xe1\x85\xa9"},
+    {0xFFCD, "\xe1\x85\xaa"},
+    {0xFFCE, "\xe1\x85\xab"},
+    {0xFFCF, "\xe1\x85\xac"},
+    {0xFFD2, "\xe1\x85\xad"},
+    {0xFFD3, "\xe1\x85\xae"},
+    {0xFFD4, "\xe1\x85\xaf"},
+    {0xFFD5, "\xe1\x85\xb0"},
+    {0xFFD6, "\xe1\x85\xb1"},
+    {0xFFD7, "\xe1\x85\xb2"},
+    {0xFFDA, "\xe1\x85\xb3"},
+    {0xFFDB, "\xe1\x85\xb4"},
+    {0xFFDC, "\xe1\x85\xb5"},
+    {0xFFE0, "\xc2\xa2"},
+    {0xFFE1, "\xc2\xa3"},
+    {0xFFE2, "\xc2\xac"},
+    {0xFFE3, "\x20\xcc\x84"},
+    {0xFFE4, "\xc2\xa6"},
+    {0xFFE5, "\xc2\xa5"},
+    {0xFFE6, "\xe2\x82\xa9"},
+    {0xFFE8, "\xe2\x94\x82"},
+    {0xFFE9, "\xe2\x86\x90"},
+    {0xFFEA, "\xe2\x86\x91"},
+    {0xFFEB, "\xe2\x86\x92"},
+    {0xFFEC, "\xe2\x86\x93"},
+    {0xFFED, "\xe2\x96\xa0"},
+    {0xFFEE, "\xe2\x97\x8b"},
+};
+
+static const NFKCComposition nfkcCompositions_g[] = {
+    {0x0041, 0x0300, 0x00C0},
+    {0x0041, 0x0301, 0x00C1},
+    {0x0041, 0x0302, 0x00C2},
+    {0x0041, 0x0303, 0x00C3},
+    {0x0041, 0x0304, 0x0100},
+    {0x0041, 0x0306, 0x0102},
+    {0x0041, 0x0307, 0x0226},
+    {0x0041, 0x0308, 0x00C4},
+    {0x0041, 0x0309, 0x1EA2},
+    {0x0041, 0x030A, 0x00C5},
+    {0x0041, 0x030C, 0x01CD},
+    {0x0041, 0x030F, 0x0200},
+    {0x0041, 0x0311, 0x0202},
+    {0x0041, 0x0323, 0x1EA0},
+    {0x0041, 0x0325, 0x1E00},
+    {0x0041, 0x0328, 0x0104},
+    {0x0042, 0x0307, 0x1E02},
+    {0x0042, 0x0323, 0x1E04},
+    {0x0042, 0x0331, 0x1E06},
+    {0x0043, 0x0301, 0x0106},
+    {0x0043, 0x0302, 0x0108},
+    {0x0043, 0x0307, 0x010A},
+    {0x0043, 0x030C, 0x010C},
+    {0x0043, 0x0327, 0x00C7},
+    {0x0044, 0x0307, 0x1E0A},
+    {0x0044, 0x030C, 0x010E},
+    {0x0044, 0x0323, 0x1E0C},
+    {0x0044, 0x0327, 0x1E10},
+    {0x0044, 0x032D, 0x1E12},
+    {0x0044, 0x0331, 0x1E0E},
+    {0x0045, 0x0300, 0x00C8},
+    {0x0045, 0x0301, 0x00C9},
+    {0x0045, 0x0302, 0x00CA},
+    {0x0045, 0x0303, 0x1EBC},
+    {0x0045, 0x0304, 0x0112},
+    {0x0045, 0x0306, 0x0114},
+    {0x0045, 0x0307, 0x0116},
+    {0x0045, 0x0308, 0x00CB},
+    {0x0045, 0x0309, 0x1EBA},
+    {0x0045, 0x030C, 0x011A},
+    {0x0045, 0x030F, 0x0204},
+    {0x0045, 0x0311, 0x0206},
+    {0x0045, 0x0323, 0x1EB8},
+    {0x0045, 0x0327, 0x0228},
+    {0x0045, 0x0328, 0x0118},
+    {0x0045, 0x032D, 0x1E18},
+    {0x0045, 0x0330, 0x1E1A},
+    {0x0046, 0x0307, 0x1E1E},
+    {0x0047, 0x0301, 0x01F4},
+    {0x0047, 0x0302, 0x011C},
+    {0x0047, 0x0304, 0x1E20},
+    {0x0047, 0x0306, 0x011E},
+    {0x0047, 0x0307, 0x0120},
+    {0x0047, 0x030C, 0x01E6},
+    {0x0047, 0x0327, 0x0122},
+    {0x0048, 0x0302, 0x0124},
+    {0x0048, 0x0307, 0x1E22},
+    {0x0048, 0x0308, 0x1E26},
+    {0x0048, 0x030C, 0x021E},
+    {0x0048, 0x0323, 0x1E24},
+    {0x0048, 0x0327, 0x1E28},
+    {0x0048, 0x032E, 0x1E2A},
+    {0x0049, 0x0300, 0x00CC},
+    {0x0049, 0x0301, 0x00CD},
+    {0x0049, 0x0302, 0x00CE},
+    {0x0049, 0x0303, 0x0128},
+    {0x0049, 0x0304, 0x012A},
+    {0x0049, 0x0306, 0x012C},
+    {0x0049, 0x0307, 0x0130},
+    {0x0049, 0x0308, 0x00CF},
+    {0x0049, 0x0309, 0x1EC8},
+    {0x0049, 0x030C, 0x01CF},
+    {0x0049, 0x030F, 0x0208},
+    {0x0049, 0x0311, 0x020A},
+    {0x0049, 0x0323, 0x1ECA},
+    {0x0049, 0x0328, 0x012E},
+    {0x0049, 0x0330, 0x1E2C},
+    {0x004A, 0x0302, 0x0134},
+    {0x004B, 0x0301, 0x1E30},
+    {0x004B, 0x030C, 0x01E8},
+    {0x004B, 0x0323, 0x1E32},
+    {0x004B, 0x0327, 0x0136},
+    {0x004B, 0x0331, 0x1E34},
+    {0x004C, 0x0301, 0x0139},
+    {0x004C, 0x030C, 0x013D},
+    {0x004C, 0x0323, 0x1E36},
+    {0x004C, 0x0327, 0x013B},
+    {0x004C, 0x032D, 0x1E3C},
+    {0x004C, 0x0331, 0x1E3A},
+    {0x004D, 0x0301, 0x1E3E},
+    {0x004D, 0x0307, 0x1E40},
+    {0x004D, 0x0323, 0x1E42},
+    {0x004E, 0x0300, 0x01F8},
+    {0x004E, 0x0301, 0x0143},
+    {0x004E, 0x0303, 0x00D1},
+    {0x004E, 0x0307, 0x1E44},
+    {0x004E, 0x030C, 0x0147},
+    {0x004E, 0x0323, 0x1E46},
+    {0x004E, 0x0327, 0x0145},
+    {0x004E, 0x032D, 0x1E4A},
+    {0x004E, 0x0331, 0x1E48},
+    {0x004F, 0x0300, 0x00D2},
+    {0x004F, 0x0301, 0x00D3},
+    {0x004F, 0x0302, 0x00D4},
+    {0x004F, 0x0303, 0x00D5},
+    {0x004F, 0x0304, 0x014C},
+    {0x004F, 0x0306, 0x014E},
+    {0x004F, 0x0307, 0x022E},
+    {0x004F, 0x0308, 0x00D6},
+    {0x004F, 0x0309, 0x1ECE},
+    {0x004F, 0x030B, 0x0150},
+    {0x004F, 0x030C, 0x01D1},
+    {0x004F, 0x030F, 0x020C},
+    {0x004F, 0x0311, 0x020E},
+    {0x004F, 0x031B, 0x01A0},
+    {0x004F, 0x0323, 0x1ECC},
+    {0x004F, 0x0328, 0x01EA},
+    {0x0050, 0x0301, 0x1E54},
+    {0x0050, 0x0307, 0x1E56},
+    {0x0052, 0x0301, 0x0154},
+    {0x0052, 0x0307, 0x1E58},
+    {0x0052, 0x030C, 0x0158},
+    {0x0052, 0x030F, 0x0210},
+    {0x0052, 0x0311, 0x0212},
+    {0x0052, 0x0323, 0x1E5A},
+    {0x0052, 0x0327, 0x0156},
+    {0x0052, 0x0331, 0x1E5E},
+    {0x0053, 0x0301, 0x015A},
+    {0x0053, 0x0302, 0x015C},
+    {0x0053, 0x0307, 0x1E60},
+    {0x0053, 0x030C, 0x0160},
+    {0x0053, 0x0323, 0x1E62},
+    {0x0053, 0x0326, 0x0218},
+    {0x0053, 0x0327, 0x015E},
+    {0x0054, 0x0307, 0x1E6A},
+    {0x0054, 0x030C, 0x0164},
+    {0x0054, 0x0323, 0x1E6C},
+    {0x0054, 0x0326, 0x021A},
+    {0x0054, 0x0327, 0x0162},
+    {0x0054, 0x032D, 0x1E70},
+    {0x0054, 0x0331, 0x1E6E},
+    {0x0055, 0x0300, 0x00D9},
+    {0x0055, 0x0301, 0x00DA},
+    {0x0055, 0x0302, 0x00DB},
+    {0x0055, 0x0303, 0x0168},
+    {0x0055, 0x0304, 0x016A},
+    {0x0055, 0x0306, 0x016C},
+    {0x0055, 0x0308, 0x00DC},
+    {0x0055, 0x0309, 0x1EE6},
+    {0x0055, 0x030A, 0x016E},
+    {0x0055, 0x030B, 0x0170},
+    {0x0055, 0x030C, 0x01D3},
+    {0x0055, 0x030F, 0x0214},
+    {0x0055, 0x0311, 0x0216},
+    {0x0055, 0x031B, 0x01AF},
+    {0x0055, 0x0323, 0x1EE4},
+    {0x0055, 0x0324, 0x1E72},
+    {0x0055, 0x0328, 0x0172},
+    {0x0055, 0x032D, 0x1E76},
+    {0x0055, 0x0330, 0x1E74},
+    {0x0056, 0x0303, 0x1E7C},
+    {0x0056, 0x0323, 0x1E7E},
+    {0x0057, 0x0300, 0x1E80},
+    {0x0057, 0x0301, 0x1E82},
+    {0x0057, 0x0302, 0x0174},
+    {0x0057, 0x0307, 0x1E86},
+    {0x0057, 0x0308, 0x1E84},
+    {0x0057, 0x0323, 0x1E88},
+    {0x0058, 0x0307, 0x1E8A},
+    {0x0058, 0x0308, 0x1E8C},
+    {0x0059, 0x0300, 0x1EF2},
+    {0x0059, 0x0301, 0x00DD},
+    {0x0059, 0x0302, 0x0176},
+    {0x0059, 0x0303, 0x1EF8},
+    {0x0059, 0x0304, 0x0232},
+    {0x0059, 0x0307, 0x1E8E},
+    {0x0059, 0x0308, 0x0178},
+    {0x0059, 0x0309, 0x1EF6},
+    {0x0059, 0x0323, 0x1EF4},
+    {0x005A, 0x0301, 0x0179},
+    {0x005A, 0x0302, 0x1E90},
+    {0x005A, 0x0307, 0x017B},
+    {0x005A, 0x030C, 0x017D},
+    {0x005A, 0x0323, 0x1E92},
+    {0x005A, 0x0331, 0x1E94},
+    {0x0061, 0x0300, 0x00E0},
+    {0x0061, 0x0301, 0x00E1},
+    {0x0061, 0x0302, 0x00E2},
+    {0x0061, 0x0303, 0x00E3},
+    {0x0061, 0x0304, 0x0101},
+    {0x0061, 0x0306, 0x0103},
+    {0x0061, 0x0307, 0x0227},
+    {0x0061, 0x0308, 0x00E4},
+    {0x0061, 0x0309, 0x1EA3},
+    {0x0061, 0x030A, 0x00E5},
+    {0x0061, 0x030C, 0x01CE},
+    {0x0061, 0x030F, 0x0201},
+    {0x0061, 0x0311, 0x0203},
+    {0x0061, 0x0323, 0x1EA1},
+    {0x0061, 0x0325, 0x1E01},
+    {0x0061, 0x0328, 0x0105},
+    {0x0062, 0x0307, 0x1E03},
+    {0x0062, 0x0323, 0x1E05},
+    {0x0062, 0x0331, 0x1E07},
+    {0x0063, 0x0301, 0x0107},
+    {0x0063, 0x0302, 0x0109},
+    {0x0063, 0x0307, 0x010B},
+    {0x0063, 0x030C, 0x010D},
+    {0x0063, 0x0327, 0x00E7},
+    {0x0064, 0x0307, 0x1E0B},
+    {0x0064, 0x030C, 0x010F},
+    {0x0064, 0x0323, 0x1E0D},
+    {0x0064, 0x0327, 0x1E11},
+    {0x0064, 0x032D, 0x1E13},
+    {0x0064, 0x0331, 0x1E0F},
+    {0x0065, 0x0300, 0x00E8},
+    {0x0065, 0x0301, 0x00E9},
+    {0x0065, 0x0302, 0x00EA},
+    {0x0065, 0x0303, 0x1EBD},
+    {0x0065, 0x0304, 0x0113},
+    {0x0065, 0x0306, 0x0115},
+    {0x0065, 0x0307, 0x0117},
+    {0x0065, 0x0308, 0x00EB},
+    {0x0065, 0x0309, 0x1EBB},
+    {0x0065, 0x030C, 0x011B},
+    {0x0065, 0x030F, 0x0205},
+    {0x0065, 0x0311, 0x0207},
+    {0x0065, 0x0323, 0x1EB9},
+    {0x0065, 0x0327, 0x0229},
+    {0x0065, 0x0328, 0x0119},
+    {0x0065, 0x032D, 0x1E19},
+    {0x0065, 0x0330, 0x1E1B},
+    {0x0066, 0x0307, 0x1E1F},
+    {0x0067, 0x0301, 0x01F5},
+    {0x0067, 0x0302, 0x011D},
+    {0x0067, 0x0304, 0x1E21},
+    {0x0067, 0x0306, 0x011F},
+    {0x0067, 0x0307, 0x0121},
+    {0x0067, 0x030C, 0x01E7},
+    {0x0067, 0x0327, 0x0123},
+    {0x0068, 0x0302, 0x0125},
+    {0x0068, 0x0307, 0x1E23},
+    {0x0068, 0x0308, 0x1E27},
+    {0x0068, 0x030C, 0x021F},
+    {0x0068, 0x0323, 0x1E25},
+    {0x0068, 0x0327, 0x1E29},
+    {0x0068, 0x032E, 0x1E2B},
+    {0x0068, 0x0331, 0x1E96},
+    {0x0069, 0x0300, 0x00EC},
+    {0x0069, 0x0301, 0x00ED},
+    {0x0069, 0x0302, 0x00EE},
+    {0x0069, 0x0303, 0x0129},
+    {0x0069, 0x0304, 0x012B},
+    {0x0069, 0x0306, 0x012D},
+    {0x0069, 0x0308, 0x00EF},
+    {0x0069, 0x0309, 0x1EC9},
+    {0x0069, 0x030C, 0x01D0},
+    {0x0069, 0x030F, 0x0209},
+    {0x0069, 0x0311, 0x020B},
+    {0x0069, 0x0323, 0x1ECB},
+    {0x0069, 0x0328, 0x012F},
+    {0x0069, 0x0330, 0x1E2D},
+    {0x006A, 0x0302, 0x0135},
+    {0x006A, 0x030C, 0x01F0},
+    {0x006B, 0x0301, 0x1E31},
+    {0x006B, 0x030C, 0x01E9},
+    {0x006B, 0x0323, 0x1E33},
+    {0x006B, 0x0327, 0x0137},
+    {0x006B, 0x0331, 0x1E35},
+    {0x006C, 0x0301, 0x013A},
+    {0x006C, 0x030C, 0x013E},
+    {0x006C, 0x0323, 0x1E37},
+    {0x006C, 0x0327, 0x013C},
+    {0x006C, 0x032D, 0x1E3D},
+    {0x006C, 0x0331, 0x1E3B},
+    {0x006D, 0x0301, 0x1E3F},
+    {0x006D, 0x0307, 0x1E41},
+    {0x006D, 0x0323, 0x1E43},
+    {0x006E, 0x0300, 0x01F9},
+    {0x006E, 0x0301, 0x0144},
+    {0x006E, 0x0303, 0x00F1},
+    {0x006E, 0x0307, 0x1E45},
+    {0x006E, 0x030C, 0x0148},
+    {0x006E, 0x0323, 0x1E47},
+    {0x006E, 0x0327, 0x0146},
+    {0x006E, 0x032D, 0x1E4B},
+    {0x006E, 0x0331, 0x1E49},
+    {0x006F, 0x0300, 0x00F2},
+    {0x006F, 0x0301, 0x00F3},
+    {0x006F, 0x0302, 0x00F4},
+    {0x006F, 0x0303, 0x00F5},
+    {0x006F, 0x0304, 0x014D},
+    {0x006F, 0x0306, 0x014F},
+    {0x006F, 0x0307, 0x022F},
+    {0x006F, 0x0308, 0x00F6},
+    {0x006F, 0x0309, 0x1ECF},
+    {0x006F, 0x030B, 0x0151},
+    {0x006F, 0x030C, 0x01D2},
+    {0x006F, 0x030F, 0x020D},
+    {0x006F, 0x0311, 0x020F},
+    {0x006F, 0x031B, 0x01A1},
+    {0x006F, 0x0323, 0x1ECD},
+    {0x006F, 0x0328, 0x01EB},
+    {0x0070, 0x0301, 0x1E55},
+    {0x0070, 0x0307, 0x1E57},
+    {0x0072, 0x0301, 0x0155},
+    {0x0072, 0x0307, 0x1E59},
+    {0x0072, 0x030C, 0x0159},
+    {0x0072, 0x030F, 0x0211},
+    {0x0072, 0x0311, 0x0213},
+    {0x0072, 0x0323, 0x1E5B},
+    {0x0072, 0x0327, 0x0157},
+    {0x0072, 0x0331, 0x1E5F},
+    {0x0073, 0x0301, 0x015B},
+    {0x0073, 0x0302, 0x015D},
+    {0x0073, 0x0307, 0x1E61},
+    {0x0073, 0x030C, 0x0161},
+    {0x0073, 0x0323, 0x1E63},
+    {0x0073, 0x0326, 0x0219},
+    {0x0073, 0x0327, 0x015F},
+    {0x0074, 0x0307, 0x1E6B},
+    {0x0074, 0x0308, 0x1E97},
+    {0x0074, 0x030C, 0x0165},
+    {0x0074, 0x0323, 0x1E6D},
+    {0x0074, 0x0326, 0x021B},
+    {0x0074, 0x0327, 0x0163},
+    {0x0074, 0x032D, 0x1E71},
+    {0x0074, 0x0331, 0x1E6F},
+    {0x0075, 0x0300, 0x00F9},
+    {0x0075, 0x0301, 0x00FA},
+    {0x0075, 0x0302, 0x00FB},
+    {0x0075, 0x0303, 0x0169},
+    {0x0075, 0x0304, 0x016B},
+    {0x0075, 0x0306, 0x016D},
+    {0x0075, 0x0308, 0x00FC},
+    {0x0075, 0x0309, 0x1EE7},
+    {0x0075, 0x030A, 0x016F},
+    {0x0075, 0x030B, 0x0171},
+    {0x0075, 0x030C, 0x01D4},
+    {0x0075, 0x030F, 0x0215},
+    {0x0075, 0x0311, 0x0217},
+    {0x0075, 0x031B, 0x01B0},
+    {0x0075, 0x0323, 0x1EE5},
+    {0x0075, 0x0324, 0x1E73},
+    {0x0075, 0x0328, 0x0173},
+    {0x0075, 0x032D, 0x1E77},
+    {0x0075, 0x0330, 0x1E75},
+    {0x0076, 0x0303, 0x1E7D},
+    {0x0076, 0x0323, 0x1E7F},
+    {0x0077, 0x0300, 0x1E81},
+    {0x0077, 0x0301, 0x1E83},
+    {0x0077, 0x0302, 0x0175},
+    {0x0077, 0x0307, 0x1E87},
+    {0x0077, 0x0308, 0x1E85},
+    {0x0077, 0x030A, 0x1E98},
+    {0x0077, 0x0323, 0x1E89},
+    {0x0078, 0x0307, 0x1E8B},
+    {0x0078, 0x0308, 0x1E8D},
+    {0x0079, 0x0300, 0x1EF3},
+    {0x0079, 0x0301, 0x00FD},
+    {0x0079, 0x0302, 0x0177},
+    {0x0079, 0x0303, 0x1EF9},
+    {0x0079, 0x0304, 0x0233},
+    {0x0079, 0x0307, 0x1E8F},
+    {0x0079, 0x0308, 0x00FF},
+    {0x0079, 0x0309, 0x1EF7},
+    {0x0079, 0x030A, 0x1E99},
+    {0x0079, 0x0323, 0x1EF5},
+    {0x007A, 0x0301, 0x017A},
+    {0x007A, 0x0302, 0x1E91},
+    {0x007A, 0x0307, 0x017C},
+    {0x007A, 0x030C, 0x017E},
+    {0x007A, 0x0323, 0x1E93},
+    {0x007A, 0x0331, 0x1E95},
+    {0x00C2, 0x0300, 0x1EA6},
+    {0x00C2, 0x0301, 0x1EA4},
+    {0x00C2, 0x0303, 0x1EAA},
+    {0x00C2, 0x0309, 0x1EA8},
+    {0x00C4, 0x0304, 0x01DE},
+    {0x00C5, 0x0301, 0x01FA},
+    {0x00C6, 0x0301, 0x01FC},
+    {0x00C6, 0x0304, 0x01E2},
+    {0x00C7, 0x0301, 0x1E08},
+    {0x00CA, 0x0300, 0x1EC0},
+    {0x00CA, 0x0301, 0x1EBE},
+    {0x00CA, 0x0303, 0x1EC4},
+    {0x00CA, 0x0309, 0x1EC2},
+    {0x00CF, 0x0301, 0x1E2E},
+    {0x00D4, 0x0300, 0x1ED2},
+    {0x00D4, 0x0301, 0x1ED0},
+    {0x00D4, 0x0303, 0x1ED6},
+    {0x00D4, 0x0309, 0x1ED4},
+    {0x00D5, 0x0301, 0x1E4C},
+    {0x00D5, 0x0304, 0x022C},
+    {0x00D5, 0x0308, 0x1E4E},
+    {0x00D6, 0x0304, 0x022A},
+    {0x00D8, 0x0301, 0x01FE},
+    {0x00DC, 0x0300, 0x01DB},
+    {0x00DC, 0x0301, 0x01D7},
+    {0x00DC, 0x0304, 0x01D5},
+    {0x00DC, 0x030C, 0x01D9},
+    {0x00E2, 0x0300, 0x1EA7},
+    {0x00E2, 0x0301, 0x1EA5},
+    {0x00E2, 0x0303, 0x1EAB},
+    {0x00E2, 0x0309, 0x1EA9},
+    {0x00E4, 0x0304, 0x01DF},
+    {0x00E5, 0x0301, 0x01FB},
+    {0x00E6, 0x0301, 0x01FD},
+    {0x00E6, 0x0304, 0x01E3},
+    {0x00E7, 0x0301, 0x1E09},
+    {0x00EA, 0x0300, 0x1EC1},
+    {0x00EA, 0x0301, 0x1EBF},
+    {0x00EA, 0x0303, 0x1EC5},
+    {0x00EA, 0x0309, 0x1EC3},
+    {0x00EF, 0x0301, 0x1E2F},
+    {0x00F4, 0x0300, 0x1ED3},
+    {0x00F4, 0x0301, 0x1ED1},
+    {0x00F4, 0x0303, 0x1ED7},
+    {0x00F4, 0x0309, 0x1ED5},
+    {0x00F5, 0x0301, 0x1E4D},
+    {0x00F5, 0x0304, 0x022D},
+    {0x00F5, 0x0308, 0x1E4F},
+    {0x00F6, 0x0304, 0x022B},
+    {0x00F8, 0x0301, 0x01FF},
+    {0x00FC, 0x0300, 0x01DC},
+    {0x00FC, 0x0301, 0x01D8},
+    {0x00FC, 0x0304, 0x01D6},
+    {0x00FC, 0x030C, 0x01DA},
+    {0x0102, 0x0300, 0x1EB0},
+    {0x0102, 0x0301, 0x1EAE},
+    {0x0102, 0x0303, 0x1EB4},
+    {0x0102, 0x0309, 0x1EB2},
+    {0x0103, 0x0300, 0x1EB1},
+    {0x0103, 0x0301, 0x1EAF},
+    {0x0103, 0x0303, 0x1EB5},
+    {0x0103, 0x0309, 0x1EB3},
+    {0x0112, 0x0300, 0x1E14},
+    {0x0112, 0x0301, 0x1E16},
+    {0x0113, 0x0300, 0x1E15},
+    {0x0113, 0x0301, 0x1E17},
+    {0x014C, 0x0300, 0x1E50},
+    {0x014C, 0x0301, 0x1E52},
+    {0x014D, 0x0300, 0x1E51},
+    {0x014D, 0x0301, 0x1E53},
+    {0x015A, 0x0307, 0x1E64},
+    {0x015B, 0x0307, 0x1E65},
+    {0x0160, 0x0307, 0x1E66},
+    {0x0161, 0x0307, 0x1E67},
+    {0x0168, 0x0301, 0x1E78},
+    {0x0169, 0x0301, 0x1E79},
+    {0x016A, 0x0308, 0x1E7A},
+    {0x016B, 0x0308, 0x1E7B},
+    {0x017F, 0x0307, 0x1E9B},
+    {0x01A0, 0x0300, 0x1EDC},
+    {0x01A0, 0x0301, 0x1EDA},
+    {0x01A0, 0x0303, 0x1EE0},
+    {0x01A0, 0x0309, 0x1EDE},
+    {0x01A0, 0x0323, 0x1EE2},
+    {0x01A1, 0x0300, 0x1EDD},
+    {0x01A1, 0x0301, 0x1EDB},
+    {0x01A1, 0x0303, 0x1EE1},
+    {0x01A1, 0x0309, 0x1EDF},
+    {0x01A1, 0x0323, 0x1EE3},
+    {0x01AF, 0x0300, 0x1EEA},
+    {0x01AF, 0x0301, 0x1EE8},
+    {0x01AF, 0x0303, 0x1EEE},
+    {0x01AF, 0x0309, 0x1EEC},
+    {0x01AF, 0x0323, 0x1EF0},
+    {0x01B0, 0x0300, 0x1EEB},
+    {0x01B0, 0x0301, 0x1EE9},
+    {0x01B0, 0x0303, 0x1EEF},
+    {0x01B0, 0x0309, 0x1EED},
+    {0x01B0, 0x0323, 0x1EF1},
+    {0x01B7, 0x030C, 0x01EE},
+    {0x01EA, 0x0304, 0x01EC},
+    {0x01EB, 0x0304, 0x01ED},
+    {0x0226, 0x0304, 0x01E0},
+    {0x0227, 0x0304, 0x01E1},
+    {0x0228, 0x0306, 0x1E1C},
+    {0x0229, 0x0306, 0x1E1D},
+    {0x022E, 0x0304, 0x0230},
+    {0x022F, 0x0304, 0x0231},
+    {0x0292, 0x030C, 0x01EF},
+    {0x1E36, 0x0304, 0x1E38},
+    {0x1E37, 0x0304, 0x1E39},
+    {0x1E5A, 0x0304, 0x1E5C},
+    {0x1E5B, 0x0304, 0x1E5D},
+    {0x1E62, 0x0307, 0x1E68},
+    {0x1E63, 0x0307, 0x1E69},
+    {0x1EA0, 0x0302, 0x1EAC},
+    {0x1EA0, 0x0306, 0x1EB6},
+    {0x1EA1, 0x0302, 0x1EAD},
+    {0x1EA1, 0x0306, 0x1EB7},
+    {0x1EB8, 0x0302, 0x1EC6},
+    {0x1EB9, 0x0302, 0x1EC7},
+    {0x1ECC, 0x0302, 0x1ED8},
+    {0x1ECD, 0x0302, 0x1ED9},
+    {0x3046, 0x3099, 0x3094},
+    {0x304B, 0x3099, 0x304C},
+    {0x304D, 0x3099, 0x304E},
+    {0x304F, 0x3099, 0x3050},
+    {0x3051, 0x3099, 0x3052},
+    {0x3053, 0x3099, 0x3054},
+    {0x3055, 0x3099, 0x3056},
+    {0x3057, 0x3099, 0x3058},
+    {0x3059, 0x3099, 0x305A},
+    {0x305B, 0x3099, 0x305C},
+    {0x305D, 0x3099, 0x305E},
+    {0x305F, 0x3099, 0x3060},
+    {0x3061, 0x3099, 0x3062},
+    {0x3064, 0x3099, 0x3065},
+    {0x3066, 0x3099, 0x3067},
+    {0x3068, 0x3099, 0x3069},
+    {0x306F, 0x3099, 0x3070},
+    {0x306F, 0x309A, 0x3071},
+    {0x3072, 0x3099, 0x3073},
+    {0x3072, 0x309A, 0x3074},
+    {0x3075, 0x3099, 0x3076},
+    {0x3075, 0x309A, 0x3077},
+    {0x3078, 0x3099, 0x3079},
+    {0x3078, 0x309A, 0x307A},
+    {0x307B, 0x3099, 0x307C},
+    {0x307B, 0x309A, 0x307D},
+    {0x309D, 0x3099, 0x309E},
+    {0x30A6, 0x3099, 0x30F4},
+    {0x30AB, 0x3099, 0x30AC},
+    {0x30AD, 0x3099, 0x30AE},
+    {0x30AF, 0x3099, 0x30B0},
+    {0x30B1, 0x3099, 0x30B2},
+    {0x30B3, 0x3099, 0x30B4},
+    {0x30B5, 0x3099, 0x30B6},
+    {0x30B7, 0x3099, 0x30B8},
+    {0x30B9, 0x3099, 0x30BA},
+    {0x30BB, 0x3099, 0x30BC},
+    {0x30BD, 0x3099, 0x30BE},
+    {0x30BF, 0x3099, 0x30C0},
+    {0x30C1, 0x3099, 0x30C2},
+    {0x30C4, 0x3099, 0x30C5},
+    {0x30C6, 0x3099, 0x30C7},
+    {0x30C8, 0x3099, 0x30C9},
+    {0x30CF, 0x3099, 0x30D0},
+    {0x30CF, 0x309A, 0x30D1},
+    {0x30D2, 0x3099, 0x30D3},
+    {0x30D2, 0x309A, 0x30D4},
+    {0x30D5, 0x3099, 0x30D6},
+    {0x30D5, 0x309A, 0x30D7},
+    {0x30D8, 0x3099, 0x30D9},
+    {0x30D8, 0x309A, 0x30DA},
+    {0x30DB, 0x3099, 0x30DC},
+    {0x30DB, 0x309A, 0x30DD},
+    {0x30EF, 0x3099, 0x30F7},
+    {0x30F0, 0x3099, 0x30F8},
+    {0x30F1, 0x3099, 0x30F9},
+    {0x30F2, 0x3099, 0x30FA},
+    {0x30FD, 0x3099, 0x30FE},
+};
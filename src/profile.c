@@ -12,7 +12,10 @@ void printReadIt(RedisModuleCtx *ctx, IndexIterator *root, size_t counter, doubl
   size_t nlen = 0;
   RedisModule_ReplyWithArray(ctx, REDISMODULE_POSTPONED_ARRAY_LEN);
 
-  if (ir->idx->flags == Index_DocIdsOnly) {
+  if (ir->record->type == RSResultType_Virtual) {
+    printProfileType("FIELD DOCS");
+
+  } else if (ir->idx->flags == Index_DocIdsOnly) {
     printProfileType("TAG");
     RedisModule_ReplyWithSimpleString(ctx, "Term");
     RedisModule_ReplyWithSimpleString(ctx, ir->record->term.term->str);
@@ -38,8 +41,8 @@ void printReadIt(RedisModuleCtx *ctx, IndexIterator *root, size_t counter, doubl
     RedisModule_ReplyWithSimpleString(ctx, "Term");
     RedisModule_ReplyWithSimpleString(ctx, ir->record->term.term->str);
   }
-  // We have added both Type and Term fields
-  nlen += 4;
+  // We have added both Type and Term fields, except for docs indexes which have no term
+  nlen += ir->record->type == RSResultType_Virtual ? 2 : 4;
 
   // print counter and clock
   if (config->printProfileClock) {
@@ -79,6 +82,9 @@ static double _recursiveProfilePrint(RedisModuleCtx *ctx, ResultProcessor *rp, s
       case RP_HIGHLIGHTER:
       case RP_GROUP:
       case RP_NETWORK:
+      case RP_FACETS:
+      case RP_HYBRID_FUSION:
+      case RP_VECTOR_LOADER:
         printProfileType(RPTypeToString(rp->type));
         break;
 
@@ -4,7 +4,9 @@
  * the Server Side Public License v1 (SSPLv1).
  */
 
+#include <ctype.h>
 #include <inttypes.h>
+#include <math.h>
 #include <stdio.h>
 #include <stdlib.h>
 #include <string.h>
@@ -21,6 +23,7 @@
 #include "ext/default.h"
 #include "rmutil/sds.h"
 #include "tag_index.h"
+#include "boolean_index.h"
 #include "err.h"
 #include "concurrent_ctx.h"
 #include "numeric_index.h"
@@ -34,6 +37,8 @@
 #include "suffix.h"
 #include "wildcard/wildcard.h"
 #include "geometry/geometry_api.h"
+#include "analyzer.h"
+#include "normalize.h"
 
 #define EFFECTIVE_FIELDMASK(q_, qn_) ((qn_)->opts.fieldMask & (q)->opts->fieldmask)
 
@@ -46,17 +51,27 @@ static void QueryTagNode_Free(QueryTagNode *tag) {
   rm_free((char *)tag->fieldName);
 }
 
+static void QueryMissingNode_Free(QueryMissingNode *miss) {
+  rm_free((char *)miss->fieldName);
+}
+
+static void QueryIsEmptyNode_Free(QueryIsEmptyNode *isempty) {
+  rm_free((char *)isempty->fieldName);
+}
+
 static void QueryGeometryNode_Free(QueryGeometryNode *geom) {
   if (geom->geomq) {
-    if (geom->geomq->str) {
-      rm_free((void*)geom->geomq->str);
-      rm_free((void*)geom->geomq->attr);
-      geom->geomq->str = NULL;
-      geom->geomq->attr = NULL;
-    }
+    rm_free((void*)geom->geomq->str);
+    rm_free((void*)geom->geomq->attr);
+    geom->geomq->str = NULL;
+    geom->geomq->attr = NULL;
     rm_free(geom->geomq);
     geom->geomq = NULL;
   }
+  if (geom->gf) {
+    GeoFilter_Free(geom->gf);
+    geom->gf = NULL;
+  }
 }
 
 static void QueryLexRangeNode_Free(QueryLexRangeNode *lx) {
@@ -126,6 +141,12 @@ void QueryNode_Free(QueryNode *n) {
     case QN_TAG:
       QueryTagNode_Free(&n->tag);
       break;
+    case QN_MISSING:
+      QueryMissingNode_Free(&n->miss);
+      break;
+    case QN_ISEMPTY:
+      QueryIsEmptyNode_Free(&n->isempty);
+      break;
     case QN_GEOMETRY:
       QueryGeometryNode_Free(&n->gmn);
       break;
@@ -289,6 +310,20 @@ QueryNode *NewPhraseNode(int exact) {
   return ret;
 }
 
+QueryNode *NewMissingNode(const char *field, size_t len) {
+  QueryNode *ret = NewQueryNode(QN_MISSING);
+  ret->miss.fieldName = field;
+  ret->miss.len = len;
+  return ret;
+}
+
+QueryNode *NewIsEmptyNode(const char *field, size_t len) {
+  QueryNode *ret = NewQueryNode(QN_ISEMPTY);
+  ret->isempty.fieldName = field;
+  ret->isempty.len = len;
+  return ret;
+}
+
 QueryNode *NewTagNode(const char *field, size_t len) {
 
   QueryNode *ret = NewQueryNode(QN_TAG);
@@ -314,33 +349,82 @@ QueryNode *NewGeofilterNode(QueryParam *p) {
   // Move data and params pointers
   ret->gn.gf = p->gf;
   ret->params = p->params;
+  ret->opts.flags |= QueryNode_YieldsDistance;
   p->gf = NULL;
   p->params = NULL;
   rm_free(p);
   return ret;
 }
 
-QueryNode *NewGeometryNode_FromWkt(const char *wkt, size_t len) {
-  
-  QueryNode *ret = NULL;
-  char *delim = strpbrk(wkt, " \t");
-  if (delim) {
-    enum QueryType query_type;
-    if (strncasecmp(wkt, "WITHIN", delim - wkt) == 0) {
-      query_type = WITHIN;
-    } else if (strncasecmp(wkt, "CONTAINS", delim - wkt) == 0) {
-      query_type = CONTAINS;
+static const struct {
+  const char *name;
+  enum QueryType type;
+} geometryPredicates[] = {
+    {"WITHIN", WITHIN},       {"CONTAINS", CONTAINS}, {"INTERSECTS", INTERSECTS},
+    {"DISJOINT", DISJOINT},   {"DWITHIN", DWITHIN},
+};
+
+// Read the next space delimited word of a geometry predicate
+static const char *geometryPredicateWord(const char **s, const char *end, size_t *len) {
+  const char *word = *s;
+  while (word < end && isspace(*word)) ++word;
+  const char *wordEnd = word;
+  while (wordEnd < end && !isspace(*wordEnd)) ++wordEnd;
+  *s = wordEnd;
+  *len = wordEnd - word;
+  return word;
+}
+
+QueryNode *NewGeometryNode_WithParams(QueryParseCtx *q, const char *str, size_t len) {
+  const char *end = str + len;
+  size_t n;
+  const char *name = geometryPredicateWord(&str, end, &n);
+  size_t ii = 0;
+  const size_t numPredicates = sizeof(geometryPredicates) / sizeof(*geometryPredicates);
+  for (; ii < numPredicates; ++ii) {
+    if (n == strlen(geometryPredicates[ii].name) && !strncasecmp(name, geometryPredicates[ii].name, n)) {
+      break;
+    }
+  }
+  if (ii == numPredicates) {
+    return NULL;
+  }
+
+  QueryNode *ret = NewQueryNode(QN_GEOMETRY);
+  GeometryQuery *geomq = rm_calloc(1, sizeof(*geomq));
+  geomq->format = GEOMETRY_FORMAT_WKT;
+  geomq->query_type = geometryPredicates[ii].type;
+  ret->gmn.geomq = geomq;
+  QueryNode_InitParams(ret, 2);
+
+  if (geomq->query_type == DWITHIN) {
+    // `DWITHIN <distance> <shape>`
+    const char *distance = geometryPredicateWord(&str, end, &n);
+    if (n > 1 && *distance == '$') {
+      QueryToken tok = {.type = QT_PARAM_NUMERIC, .s = distance + 1, .len = n - 1};
+      QueryNode_SetParam(q, &ret->params[1], &geomq->distance, NULL, &tok);
     } else {
-      return NULL;
+      char *distanceEnd;
+      geomq->distance = n ? strtod(distance, &distanceEnd) : 0;
+      if (!n || distanceEnd != distance + n) {
+        QERR_MKSYNTAXERR(q->status, "Invalid distance `%.*s` for DWITHIN", (int)n, distance);
+        QueryNode_Free(ret);
+        return NULL;
+      }
     }
-    ret = NewQueryNode(QN_GEOMETRY);
-    GeometryQuery *geomq = rm_calloc(1, sizeof(*geomq));
-    geomq->format = GEOMETRY_FORMAT_WKT;
-    geomq->query_type = query_type;
-    len = len - (delim - wkt) - 1;
-    geomq->str = rm_strndup(delim + 1, len);
+  }
+
+  // The rest of the predicate is the shape
+  while (str < end && isspace(*str)) ++str;
+  while (end > str && isspace(end[-1])) --end;
+  len = end - str;
+  if (len > 1 && *str == '$') {
+    // The shape is given as a parameter, e.g. `[WITHIN $poly]`
+    QueryToken tok = {.type = QT_PARAM_TERM_CASE, .s = str + 1, .len = len - 1};
+    QueryNode_SetParam(q, &ret->params[0], &geomq->str, &geomq->str_len, &tok);
+  } else {
+    geomq->str = rm_strndup(str, len);
     geomq->str_len = len;
-    ret->gmn.geomq = geomq;
   }
   return ret;
 }
@@ -351,6 +435,7 @@ QueryNode *NewVectorNode_WithParams(struct QueryParseCtx *q, VectorQueryType typ
   VectorQuery *vq = rm_calloc(1, sizeof(*vq));
   ret->vn.vq = vq;
   vq->type = type;
+  vq->maxDistance = INFINITY;
   ret->opts.flags |= QueryNode_YieldsDistance;
   switch (type) {
     case VECSIM_QT_KNN:
@@ -839,6 +924,46 @@ static IndexIterator *Query_EvalWildcardNode(QueryEvalCtx *q, QueryNode *qn) {
   return NewWildcardIterator(q->docTable->maxDocId, q->docTable->size);
 }
 
+// Opens an iterator over a per-field docs index (missing or empty values)
+static IndexIterator *openFieldDocsIterator(QueryEvalCtx *q, InvertedIndex *iv, double weight) {
+  if (!iv || iv->numDocs == 0) {
+    return NULL;
+  }
+
+  IndexReader *r = NewDocsIndexReader(iv, q->sctx->spec, weight);
+  if (!r) {
+    return NULL;
+  }
+  if (q->conc) {
+    ConcurrentSearch_AddKey(q->conc, IndexReader_OnReopenPersistent, r, NULL);
+  }
+  return NewReadIterator(r);
+}
+
+static IndexIterator *Query_EvalMissingNode(QueryEvalCtx *q, QueryNode *qn) {
+  if (qn->type != QN_MISSING) {
+    return NULL;
+  }
+  IndexSpec *sp = q->sctx->spec;
+  const FieldSpec *fs = IndexSpec_GetField(sp, qn->miss.fieldName, qn->miss.len);
+  if (!fs || !FieldSpec_IndexesMissing(fs)) {
+    return NULL;
+  }
+  return openFieldDocsIterator(q, IndexSpec_GetMissingFieldIndex(sp, fs, false), qn->opts.weight);
+}
+
+static IndexIterator *Query_EvalIsEmptyNode(QueryEvalCtx *q, QueryNode *qn) {
+  if (qn->type != QN_ISEMPTY) {
+    return NULL;
+  }
+  IndexSpec *sp = q->sctx->spec;
+  const FieldSpec *fs = IndexSpec_GetField(sp, qn->isempty.fieldName, qn->isempty.len);
+  if (!fs || !FieldSpec_IndexesEmpty(fs)) {
+    return NULL;
+  }
+  return openFieldDocsIterator(q, IndexSpec_GetEmptyFieldIndex(sp, fs, false), qn->opts.weight);
+}
+
 static IndexIterator *Query_EvalNotNode(QueryEvalCtx *q, QueryNode *qn) {
   if (qn->type != QN_NOT) {
     return NULL;
@@ -862,7 +987,7 @@ static IndexIterator *Query_EvalOptionalNode(QueryEvalCtx *q, QueryNode *qn) {
 static IndexIterator *Query_EvalNumericNode(QueryEvalCtx *q, QueryNode *node) {
   const FieldSpec *fs =
       IndexSpec_GetField(q->sctx->spec, node->nn.nf->fieldName, strlen(node->nn.nf->fieldName));
-  if (!fs || !FIELD_IS(fs, INDEXFLD_T_NUMERIC)) {
+  if (!fs || !FIELD_IS(fs, INDEXFLD_T_NUMERIC | INDEXFLD_T_DATE)) {
     return NULL;
   }
   return NewNumericFilterIterator(q->sctx, node->nn.nf, q->conc, INDEXFLD_T_NUMERIC, q->config);
@@ -880,13 +1005,44 @@ static IndexIterator *Query_EvalGeofilterNode(QueryEvalCtx *q, QueryNode *node,
   if (!fs || !FIELD_IS(fs, INDEXFLD_T_GEO)) {
     return NULL;
   }
-  return NewGeoRangeIterator(q->sctx, node->gn.gf, q->config);
+  if (!node->opts.distField) {
+    return NewGeoRangeIterator(q->sctx, node->gn.gf, q->config);
+  }
+
+  // Yield the distance of every result from the center of the filter, so it can be sorted by
+  size_t idx = addMetricRequest(q, node->opts.distField, NULL);
+  IndexIterator *it = NewGeoRangeIterator(q->sctx, node->gn.gf, q->config);
+  if (it) {
+    it = NewGeoDistanceIterator(it, node->gn.gf);
+    array_ensure_at(q->metricRequestsP, idx, MetricRequest)->key_ptr = &it->ownKey;
+  }
+  return it;
+}
+
+static IndexIterator *Query_EvalGeoPolygonNode(QueryEvalCtx *q, QueryNode *node,
+                                               const FieldSpec *fs) {
+  GeometryQuery *gq = node->gmn.geomq;
+  if (gq->query_type != WITHIN) {
+    QueryError_SetErrorFmt(q->status, QUERY_EBADVAL,
+                           "Only WITHIN queries are supported for GEO field `%s`", fs->name);
+    return NULL;
+  }
+  if (!node->gmn.gf) {
+    node->gmn.gf = NewGeoPolygonFilter(fs->name, gq->str, q->status);
+    if (!node->gmn.gf) {
+      return NULL;
+    }
+  }
+  return NewGeoRangeIterator(q->sctx, node->gmn.gf, q->config);
 }
 
 static IndexIterator *Query_EvalGeometryNode(QueryEvalCtx *q, QueryNode *node) {
   
   const FieldSpec *fs =
       IndexSpec_GetField(q->sctx->spec, node->gmn.geomq->attr, strlen(node->gmn.geomq->attr));
+  if (fs && FIELD_IS(fs, INDEXFLD_T_GEO)) {
+    return Query_EvalGeoPolygonNode(q, node, fs);
+  }
   if (!fs || !FIELD_IS(fs, INDEXFLD_T_GEOMETRY)) {
     return NULL;
   }
@@ -899,8 +1055,14 @@ static IndexIterator *Query_EvalGeometryNode(QueryEvalCtx *q, QueryNode *node) {
     return NULL;
   }
   GeometryQuery *gq = node->gmn.geomq;
+  if (gq->query_type == DWITHIN && !(gq->distance >= 0)) {
+    QueryError_SetErrorFmt(q->status, QUERY_EBADVAL, "Invalid distance %g for DWITHIN", gq->distance);
+    return NULL;
+  }
+  gq->format = Geometry_DetectFormat(gq->str, gq->str_len);
   RedisModuleString *errMsg;
-  IndexIterator *ret = api->query(index, gq->query_type, gq->format, gq->str, gq->str_len, &errMsg);
+  IndexIterator *ret = api->query(index, gq->query_type, gq->distance, gq->format, gq->str,
+                                  gq->str_len, &errMsg);
   if (ret == NULL) {
     QueryError_SetErrorFmt(q->status, QUERY_EBADVAL, "Error querying geometry index: %s",
                            RedisModule_StringPtrLen(errMsg, NULL));
@@ -911,7 +1073,8 @@ static IndexIterator *Query_EvalGeometryNode(QueryEvalCtx *q, QueryNode *node) {
 
 
 static IndexIterator *Query_EvalVectorNode(QueryEvalCtx *q, QueryNode *qn) {
-  if((q->reqFlags & QEXEC_F_IS_EXTENDED)) {
+  // FT.AGGREGATE only supports KNN when fusing it with the lexical query
+  if((q->reqFlags & QEXEC_F_IS_EXTENDED) && q->opts->fusion.method == FUSION_NONE) {
     QueryError_SetErrorFmt(q->status, QUERY_EAGGPLAN, "VSS is not yet supported on FT.AGGREGATE");
     return NULL;
   }
@@ -949,6 +1112,20 @@ static IndexIterator *Query_EvalVectorNode(QueryEvalCtx *q, QueryNode *qn) {
   if (qn->vn.vq->scoreField) {
     idx = addMetricRequest(q, qn->vn.vq->scoreField, NULL);
   }
+  // The matched vector is yielded by the vector loader result processor
+  if (qn->vn.vq->matchField) {
+    if (qn->vn.vq->type != VECSIM_QT_KNN) {
+      QueryError_SetErrorFmt(q->status, QUERY_EBADATTR, "%s is only supported for KNN queries",
+                             VECSIM_YIELD_MATCHED_VECTOR_AS);
+      return NULL;
+    }
+    addMetricRequest(q, qn->vn.vq->matchField, NULL);
+  }
+  if (qn->vn.vq->maxDistance != INFINITY && qn->vn.vq->type != VECSIM_QT_KNN) {
+    QueryError_SetErrorFmt(q->status, QUERY_EBADATTR, "%s is only supported for KNN queries",
+                           VECSIM_MAX_DISTANCE);
+    return NULL;
+  }
   IndexIterator *child_it = NULL;
   if (QueryNode_NumChildren(qn) > 0) {
     RedisModule_Assert(QueryNode_NumChildren(qn) == 1);
@@ -1290,6 +1467,23 @@ static IndexIterator *query_EvalSingleTagNode(QueryEvalCtx *q, TagIndex *idx, Qu
   return ret;
 }
 
+// Boolean fields are queried as tags with a single `true` or `false` value
+static IndexIterator *Query_EvalBooleanNode(QueryEvalCtx *q, QueryNode *qn, const FieldSpec *fs) {
+  int val;
+  if (QueryNode_NumChildren(qn) != 1) {
+    return NULL;
+  }
+  QueryNode *n = qn->children[0];
+  if (n->type != QN_TOKEN || !n->tn.str || !FieldSpec_ParseBoolean(n->tn.str, n->tn.len, &val)) {
+    return NULL;
+  }
+  BooleanIndex *idx = OpenBooleanIndex(q->sctx->spec, fs, 0);
+  if (!idx) {
+    return NULL;
+  }
+  return NewBooleanIterator(idx, val, qn->opts.weight);
+}
+
 static IndexIterator *Query_EvalTagNode(QueryEvalCtx *q, QueryNode *qn) {
   if (qn->type != QN_TAG) {
     return NULL;
@@ -1300,6 +1494,9 @@ static IndexIterator *Query_EvalTagNode(QueryEvalCtx *q, QueryNode *qn) {
   if (!fs) {
     return NULL;
   }
+  if (FIELD_IS(fs, INDEXFLD_T_BOOLEAN)) {
+    return Query_EvalBooleanNode(q, qn, fs);
+  }
   RedisModuleString *kstr = IndexSpec_GetFormattedKey(q->sctx->spec, fs, INDEXFLD_T_TAG);
   TagIndex *idx = TagIndex_Open(q->sctx, kstr, 0, &k);
 
@@ -1386,6 +1583,10 @@ IndexIterator *Query_EvalNode(QueryEvalCtx *q, QueryNode *n) {
       return Query_EvalIdFilterNode(q, &n->fn);
     case QN_WILDCARD:
       return Query_EvalWildcardNode(q, n);
+    case QN_MISSING:
+      return Query_EvalMissingNode(q, n);
+    case QN_ISEMPTY:
+      return Query_EvalIsEmptyNode(q, n);
     case QN_WILDCARD_QUERY:
       return Query_EvalWildcardQueryNode(q,n);
     case QN_GEOMETRY:
@@ -1477,6 +1678,300 @@ void QAST_Destroy(QueryAST *q) {
   q->query = NULL;
 }
 
+typedef struct {
+  QueryAST *q;
+  const IndexSpec *spec;
+  t_fieldMask fieldmask;
+  StopWordList *stopwords;
+  bool noStem;
+  // Reused for all the terms of the query
+  RSTokenizer *tokenizer;
+} QueryAnalyzeCtx;
+
+/* Replace a node without children by NULL, and a node with a single child by the child */
+static QueryNode *unwrapNode(QueryNode *qn) {
+  QueryNode *ret = qn;
+  size_t n = QueryNode_NumChildren(qn);
+  if (n == 0) {
+    ret = NULL;
+  } else if (n == 1) {
+    ret = qn->children[0];
+    ret->opts.weight = qn->opts.weight;
+    QueryNode_ClearChildren(qn, 0);
+  }
+  if (ret != qn) {
+    QueryNode_Free(qn);
+  }
+  return ret;
+}
+
+/* Run the filters of a field analyzer over a token. Returns NULL if the token was dropped */
+static QueryNode *analyzeToken(QueryAnalyzeCtx *ctx, const RSAnalyzer *analyzer,
+                               const QueryNode *qn) {
+  uint32_t options = TOKENIZE_SINGLE_TOKEN;
+  if (ctx->noStem || (qn->opts.flags & QueryNode_Verbatim)) {
+    options |= TOKENIZE_NOSTEM;
+  }
+  // STEM filters without a language are left to the query expander, like the default stemming
+  if (!ctx->tokenizer) {
+    ctx->tokenizer = NewAnalyzerTokenizer(analyzer, NULL, NULL, 0);
+  }
+  RSTokenizer *tokenizer = ctx->tokenizer;
+  AnalyzerTokenizer_Reset(tokenizer, analyzer, NULL, ctx->stopwords, options);
+  tokenizer->ctx.stemExceptions = ctx->spec->stemExceptions;
+  char *text = rm_strndup(qn->tn.str, qn->tn.len);
+  tokenizer->Start(tokenizer, text, qn->tn.len, options);
+
+  // The n-gram tokenizers split long terms into grams, which are consecutive in the documents
+  QueryNode *phrase = NewPhraseNode(1);
+  Token tok = {0};
+  while (tokenizer->Next(tokenizer, &tok)) {
+    QueryNode *tn = NewTokenNodeExpanded(ctx->q, rm_strndup(tok.tok, tok.tokLen), tok.tokLen,
+                                         qn->tn.flags);
+    if (tok.stem) {
+      QueryNode *un = NewUnionNode();
+      QueryNode_AddChild(un, tn);
+      // The stems are expanded as the stemmer expander does, but in the language of the filter.
+      // They are given with the STEM_PREFIX, as they are indexed
+      QueryNode_AddChild(un, NewTokenNodeExpanded(ctx->q, rm_strndup(tok.stem, tok.stemLen),
+                                                  tok.stemLen, qn->tn.flags));
+      const char *stem = tok.stem + 1;
+      size_t stemLen = tok.stemLen - 1;
+      if (stemLen != tok.tokLen || strncmp(stem, tok.tok, tok.tokLen)) {
+        QueryNode_AddChild(un, NewTokenNodeExpanded(ctx->q, rm_strndup(stem, stemLen), stemLen,
+                                                    qn->tn.flags));
+      }
+      for (size_t ii = 1; ii < QueryNode_NumChildren(un); ++ii) {
+        un->children[ii]->opts.flags |= QueryNode_Verbatim;
+      }
+      tn = un;
+    }
+    QueryNode_AddChild(phrase, tn);
+  }
+  Token_Destroy(&tok);
+  rm_free(text);
+  return unwrapNode(phrase);
+}
+
+// The normalizations of fields without an analyzer: none, ASCIIFOLD, NFKC, or both
+#define NORMALIZATION_COUNT 4
+#define FIELD_NORMALIZATION(fs) ((FieldSpec_IsAsciiFold(fs) ? 1 : 0) | (FieldSpec_IsNFKC(fs) ? 2 : 0))
+
+static uint32_t normalizationOptions(int normalization) {
+  return ((normalization & 1) ? TOKENIZE_ASCIIFOLD : 0) | ((normalization & 2) ? TOKENIZE_NFKC : 0);
+}
+
+/* Normalize a token with the ASCIIFOLD and NFKC options of a field. Like in the documents, a token
+ * with characters which are normalized into separators is split, into a phrase. Returns NULL if
+ * the token was dropped */
+static QueryNode *normalizeToken(QueryAnalyzeCtx *ctx, uint32_t options, const QueryNode *qn) {
+  QueryNode *phrase = NewPhraseNode(1);
+  char *buf = NULL;
+  size_t cap = 0;
+  const char *s = qn->tn.str;
+  const char *end = s + qn->tn.len;
+  while (s < end) {
+    const char *tokEnd = end;
+    const char *next = end;
+    for (const char *p = s; (options & TOKENIZE_NFKC) && p < end;) {
+      uint32_t cp;
+      const char *cpEnd = Normalize_ReadCodepoint(p, end, &cp);
+      if (!cpEnd) {
+        break;
+      } else if (Normalize_IsNFKCSeparator(cp)) {
+        tokEnd = p;
+        next = cpEnd;
+        break;
+      }
+      p = cpEnd;
+    }
+
+    size_t len = tokEnd - s;
+    const char *tok = Normalize_Token(s, &len, options, &buf, &cap);
+    if (len && !StopWordList_Contains(ctx->stopwords, tok, len)) {
+      QueryNode_AddChild(phrase, NewTokenNodeExpanded(ctx->q, rm_strndup(tok, len), len,
+                                                      qn->tn.flags));
+    }
+    s = next;
+  }
+  rm_free(buf);
+  return unwrapNode(phrase);
+}
+
+/* Normalize a term which isn't tokenized, with the analyzer of a field or with its ASCIIFOLD and
+ * NFKC options. Returns a new string */
+static char *normalizeTerm(const RSAnalyzer *analyzer, uint32_t options, const char *s,
+                           size_t *len) {
+  if (analyzer) {
+    return Analyzer_NormalizeTerm(analyzer, s, len);
+  }
+  char *buf = NULL;
+  size_t cap = 0;
+  char *ret = rm_strndup(Normalize_Token(s, len, options, &buf, &cap), *len);
+  rm_free(buf);
+  return ret;
+}
+
+/* Copy a prefix, fuzzy or lexical range node with its terms normalized. Only the filters which
+ * transform the characters of a term apply to these nodes, as their terms aren't whole tokens.
+ * Returns NULL if the term of a prefix or fuzzy node became empty */
+static QueryNode *normalizeTermNode(QueryAnalyzeCtx *ctx, const RSAnalyzer *analyzer,
+                                    uint32_t options, const QueryNode *qn) {
+  QueryNode *ret = NewQueryNode(qn->type);
+  RSToken *tok = NULL;
+  switch (qn->type) {
+    case QN_PREFIX:
+      ret->pfx = qn->pfx;
+      tok = &ret->pfx.tok;
+      break;
+    case QN_FUZZY:
+      ret->fz = qn->fz;
+      tok = &ret->fz.tok;
+      break;
+    case QN_LEXRANGE:
+      ret->lxrng = qn->lxrng;
+      for (int ii = 0; ii < 2; ++ii) {
+        char **bound = ii ? &ret->lxrng.end : &ret->lxrng.begin;
+        if (*bound) {
+          size_t len = strlen(*bound);
+          *bound = normalizeTerm(analyzer, options, *bound, &len);
+        }
+      }
+      return ret;
+    default:
+      RS_LOG_ASSERT(0, "unexpected query node type");
+  }
+  ctx->q->numTokens++;
+  tok->str = normalizeTerm(analyzer, options, tok->str, &tok->len);
+  if (!tok->len) {
+    QueryNode_Free(ret);
+    return NULL;
+  }
+  return ret;
+}
+
+/* The form of a term node in fields with an analyzer, or with the normalization `options`.
+ * Returns NULL if the term was dropped */
+static QueryNode *analyzeTerm(QueryAnalyzeCtx *ctx, const RSAnalyzer *analyzer, uint32_t options,
+                              const QueryNode *qn) {
+  if (qn->type != QN_TOKEN) {
+    return normalizeTermNode(ctx, analyzer, options, qn);
+  }
+  return analyzer ? analyzeToken(ctx, analyzer, qn) : normalizeToken(ctx, options, qn);
+}
+
+/* Apply the analyzers and normalizations of the fields of a token, prefix, fuzzy or lexical range
+ * node. When it targets fields which transform it differently, the node is replaced by a union of
+ * its forms, each limited to its fields */
+static QueryNode *analyzeTermNode(QueryAnalyzeCtx *ctx, QueryNode *qn) {
+  const IndexSpec *spec = ctx->spec;
+  t_fieldMask mask = qn->opts.fieldMask & ctx->fieldmask;
+  t_fieldMask normalizationMasks[NORMALIZATION_COUNT] = {0};
+  bool isAnalyzed = false;
+  for (size_t ii = 0; ii < spec->numFields; ++ii) {
+    const FieldSpec *fs = spec->fields + ii;
+    if (!FIELD_IS(fs, INDEXFLD_T_FULLTEXT) || !(mask & FIELD_BIT(fs))) {
+      continue;
+    }
+    if (fs->analyzer) {
+      isAnalyzed = true;
+    } else {
+      normalizationMasks[FIELD_NORMALIZATION(fs)] |= FIELD_BIT(fs);
+      isAnalyzed |= FIELD_NORMALIZATION(fs) != 0;
+    }
+  }
+  if (!isAnalyzed) {
+    return qn;
+  }
+
+  QueryNode *un = NewUnionNode();
+  un->opts = qn->opts;
+  for (size_t ii = 0; ii < spec->numFields; ++ii) {
+    const FieldSpec *fs = spec->fields + ii;
+    if (!FIELD_IS(fs, INDEXFLD_T_FULLTEXT) || !(mask & FIELD_BIT(fs)) || !fs->analyzer) {
+      continue;
+    }
+    QueryNode *analyzed = analyzeTerm(ctx, fs->analyzer, 0, qn);
+    if (analyzed) {
+      analyzed->opts.fieldMask = FIELD_BIT(fs);
+      analyzed->opts.flags = qn->opts.flags;
+      analyzed->opts.phonetic = qn->opts.phonetic;
+      QueryNode_AddChild(un, analyzed);
+    }
+  }
+  for (int ii = 1; ii < NORMALIZATION_COUNT; ++ii) {
+    if (!normalizationMasks[ii]) {
+      continue;
+    }
+    QueryNode *normalized = analyzeTerm(ctx, NULL, normalizationOptions(ii), qn);
+    if (normalized) {
+      normalized->opts.fieldMask = normalizationMasks[ii];
+      normalized->opts.flags = qn->opts.flags;
+      normalized->opts.phonetic = qn->opts.phonetic;
+      QueryNode_AddChild(un, normalized);
+    }
+  }
+  t_fieldMask defaultMask = normalizationMasks[0];
+  if (defaultMask) {
+    qn->opts.fieldMask = defaultMask;
+    qn->opts.weight = 1;
+    QueryNode_AddChild(un, qn);
+  } else {
+    QueryNode_Free(qn);
+  }
+
+  return unwrapNode(un);
+}
+
+/* Returns NULL if all the terms of the node were dropped by the analyzers or normalizations */
+static QueryNode *QueryNode_Analyze(QueryAnalyzeCtx *ctx, QueryNode *qn) {
+  if (qn->type == QN_TOKEN || qn->type == QN_PREFIX || qn->type == QN_FUZZY ||
+      qn->type == QN_LEXRANGE) {
+    return analyzeTermNode(ctx, qn);
+  } else if (qn->type == QN_TAG || !qn->children) {
+    // The terms of tag fields are not tokenized
+    return qn;
+  }
+
+  size_t n = 0;
+  size_t numChildren = QueryNode_NumChildren(qn);
+  for (size_t ii = 0; ii < numChildren; ++ii) {
+    QueryNode *child = QueryNode_Analyze(ctx, qn->children[ii]);
+    if (child) {
+      qn->children[n++] = child;
+    }
+  }
+  qn->children = array_trimm_len(qn->children, numChildren - n);
+  if (numChildren && !n) {
+    QueryNode_Free(qn);
+    return NULL;
+  }
+  return qn;
+}
+
+void QAST_Analyze(QueryAST *q, const IndexSpec *spec, const RSSearchOptions *opts) {
+  bool isAnalyzed = false;
+  for (size_t ii = 0; ii < spec->numFields && !isAnalyzed; ++ii) {
+    const FieldSpec *fs = spec->fields + ii;
+    isAnalyzed = fs->analyzer || FieldSpec_IsAsciiFold(fs) || FieldSpec_IsNFKC(fs);
+  }
+  if (!q->root || !isAnalyzed) {
+    return;
+  }
+  QueryAnalyzeCtx ctx = {.q = q,
+                         .spec = spec,
+                         .fieldmask = opts->fieldmask,
+                         .stopwords = (StopWordList *)opts->stopwords,
+                         .noStem = opts->flags & Search_Verbatim};
+  q->root = QueryNode_Analyze(&ctx, q->root);
+  if (ctx.tokenizer) {
+    ctx.tokenizer->Free(ctx.tokenizer);
+  }
+  if (!q->root) {
+    q->root = NewQueryNode(QN_NULL);
+  }
+}
+
 int QAST_Expand(QueryAST *q, const char *expander, RSSearchOptions *opts, RedisSearchCtx *sctx,
                 QueryError *status) {
   if (!q->root) {
@@ -1527,6 +2022,8 @@ int QueryNode_EvalParams(dict *params, QueryNode *n, QueryError *status) {
     case QN_OPTIONAL:
     case QN_IDS:
     case QN_WILDCARD:
+    case QN_MISSING:
+    case QN_ISEMPTY:
     case QN_WILDCARD_QUERY:
     case QN_GEOMETRY:
       res = QueryNode_EvalParamsCommon(params, n, status);
@@ -1581,6 +2078,8 @@ int QueryNode_CheckIsValid(QueryNode *n, IndexSpec *spec, RSSearchOptions *opts,
     case QN_PREFIX:
     case QN_IDS:
     case QN_WILDCARD:
+    case QN_MISSING:
+    case QN_ISEMPTY:
     case QN_WILDCARD_QUERY:
     case QN_TAG:
     case QN_FUZZY:
@@ -1682,7 +2181,8 @@ static sds QueryNode_DumpSds(sds s, const IndexSpec *spec, const QueryNode *qs,
   }
 
   if (qs->opts.fieldMask && qs->opts.fieldMask != RS_FIELDMASK_ALL && qs->type != QN_NUMERIC &&
-      qs->type != QN_GEO && qs->type != QN_IDS) {
+      qs->type != QN_GEO && qs->type != QN_IDS && qs->type != QN_MISSING &&
+      qs->type != QN_ISEMPTY) {
     if (!spec) {
       s = sdscatprintf(s, "@%" PRIu64, (uint64_t)qs->opts.fieldMask);
     } else {
@@ -1812,10 +2312,22 @@ static sds QueryNode_DumpSds(sds s, const IndexSpec *spec, const QueryNode *qs,
       if (qs->vn.vq->scoreField) {
         s = sdscatprintf(s, ", yields distance as `%s`", qs->vn.vq->scoreField);
       }
+      if (qs->vn.vq->matchField) {
+        s = sdscatprintf(s, ", yields matched vector as `%s`", qs->vn.vq->matchField);
+      }
+      if (qs->vn.vq->maxDistance != INFINITY) {
+        s = sdscatprintf(s, ", within %g distance", qs->vn.vq->maxDistance);
+      }
       break;
     case QN_WILDCARD:
       s = sdscat(s, "<WILDCARD>");
       break;
+    case QN_MISSING:
+      s = sdscatprintf(s, "ISMISSING{@%.*s", (int)qs->miss.len, qs->miss.fieldName);
+      break;
+    case QN_ISEMPTY:
+      s = sdscatprintf(s, "ISEMPTY{@%.*s", (int)qs->isempty.len, qs->isempty.fieldName);
+      break;
     case QN_FUZZY:
       s = sdscatprintf(s, "FUZZY{%s}\n", qs->fz.tok.str);
       return s;
@@ -1906,7 +2418,7 @@ int QueryNode_ForEach(QueryNode *q, QueryNode_ForEachCallback callback, void *ct
 
 // Convert the query attribute into a raw vector param to be resolved by the vector iterator
 // down the road. return 0 in case of an unrecognized parameter.
-static int QueryVectorNode_ApplyAttribute(VectorQuery *vq, QueryAttribute *attr) {
+static int QueryVectorNode_ApplyAttribute(VectorQuery *vq, QueryAttribute *attr, QueryError *status) {
   if (STR_EQCASE(attr->name, attr->namelen, VECSIM_EFRUNTIME) ||
       STR_EQCASE(attr->name, attr->namelen, VECSIM_EPSILON) ||
       STR_EQCASE(attr->name, attr->namelen, VECSIM_HYBRID_POLICY) ||
@@ -1922,6 +2434,22 @@ static int QueryVectorNode_ApplyAttribute(VectorQuery *vq, QueryAttribute *attr)
     bool resolve_required = false;  // at this point, we have the actual value in hand, not the query param.
     vq->params.needResolve = array_ensure_append_1(vq->params.needResolve, resolve_required);
     return 1;
+  } else if (STR_EQCASE(attr->name, attr->namelen, VECSIM_YIELD_MATCHED_VECTOR_AS)) {
+    // Move ownership on the value string, so it won't get freed when releasing the QueryAttribute.
+    rm_free(vq->matchField);
+    vq->matchField = (char *)attr->value;
+    attr->value = NULL;
+    return 1;
+  } else if (STR_EQCASE(attr->name, attr->namelen, VECSIM_MAX_DISTANCE)) {
+    // Apply max distance: a number (may be negative for the inner product)
+    double d;
+    if (!ParseDouble(attr->value, &d) || isnan(d)) {
+      QueryError_SetErrorFmt(status, QUERY_ESYNTAX, "Invalid value (%.*s) for `%.*s`",
+                             (int)attr->vallen, attr->value, (int)attr->namelen, attr->name);
+      return 0;
+    }
+    vq->maxDistance = d;
+    return 1;
   }
   return 0;
 }
@@ -1988,7 +2516,7 @@ static int QueryNode_ApplyAttribute(QueryNode *qn, QueryAttribute *attr, QueryEr
     res = 1;
 
   } else if (qn->type == QN_VECTOR) {
-    res = QueryVectorNode_ApplyAttribute(qn->vn.vq, attr);
+    res = QueryVectorNode_ApplyAttribute(qn->vn.vq, attr, status);
   }
 
   if (!res) {
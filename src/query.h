@@ -122,6 +122,16 @@ IndexIterator *QAST_Iterate(QueryAST *ast, const RSSearchOptions *options,
 int QAST_Expand(QueryAST *q, const char *expander, RSSearchOptions *opts, RedisSearchCtx *sctx,
                 QueryError *status);
 
+/**
+ * Apply the analyzers and the ASCIIFOLD and NFKC normalizations of the TEXT fields to the terms
+ * which are searched in them, as they were applied to the documents. This is done before the
+ * expansion, even for VERBATIM queries.
+ * @param q the query
+ * @param spec the index
+ * @param opts query options
+ */
+void QAST_Analyze(QueryAST *q, const IndexSpec *spec, const RSSearchOptions *opts);
+
 int QAST_EvalParams(QueryAST *q, RSSearchOptions *opts, QueryError *status);
 int QueryNode_EvalParams(dict *params, QueryNode *node, QueryError *status);
 
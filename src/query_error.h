@@ -61,6 +61,8 @@ extern "C" {
   X(QUERY_EADHOCWBATCHSIZE, "'batch size' is irrelevant for 'ADHOC_BF' policy")           \
   X(QUERY_EADHOCWEFRUNTIME, "'EF_RUNTIME' is irrelevant for 'ADHOC_BF' policy")           \
   X(QUERY_ENRANGE, "range query attributes were sent for a non-range query")              \
+  X(QUERY_EMISSING, "'ismissing' requires field to be defined with 'INDEXMISSING'")       \
+  X(QUERY_EEMPTY, "Querying empty values requires field to be defined with 'INDEXEMPTY'") \
 
 typedef enum {
   QUERY_OK = 0,
@@ -49,6 +49,12 @@ typedef enum {
   /* Wildcard node, used only in conjunction with negative root node to allow negative queries */
   QN_WILDCARD,
 
+  /* Missing field node, matches the documents which don't have a value for an INDEXMISSING field */
+  QN_MISSING,
+
+  /* Empty value node, matches the documents which have an empty value for an INDEXEMPTY field */
+  QN_ISEMPTY,
+
   /* Tag node, a list of tags for a specific tag field */
   QN_TAG,
 
@@ -87,6 +93,16 @@ typedef struct {
   size_t len;
 } QueryTagNode;
 
+typedef struct {
+  const char *fieldName;
+  size_t len;
+} QueryMissingNode;
+
+typedef struct {
+  const char *fieldName;
+  size_t len;
+} QueryIsEmptyNode;
+
 /* A token node is a terminal, single term/token node. An expansion of synonyms is represented by a
  * Union node with several token nodes. A token can have private metadata written by expanders or
  * tokenizers. Later this gets passed to scoring functions in a Term object. See RSIndexRecord */
@@ -114,6 +130,7 @@ typedef struct {
 
 typedef struct {
   struct GeometryQuery *geomq;
+  struct GeoFilter *gf;  // Filter of the queries over GEO fields, created on evaluation
 } QueryGeometryNode;
 
 typedef struct {
@@ -193,6 +210,8 @@ typedef struct RSQueryNode {
     QueryOptionalNode opt;
     QueryPrefixNode pfx;
     QueryTagNode tag;
+    QueryMissingNode miss;
+    QueryIsEmptyNode isempty;
     QueryFuzzyNode fz;
     QueryLexRangeNode lxrng;
     QueryVerbatimNode verb;
@@ -29,7 +29,7 @@ void QOptimizer_Parse(AREQ *req) {
     if (arng->sortKeys) {
       const char *name = arng->sortKeys[0];
       const FieldSpec *field = IndexSpec_GetField(req->sctx->spec, name, strlen(name));
-      if (field && field->types == INDEXFLD_T_NUMERIC) {
+      if (field && (field->types == INDEXFLD_T_NUMERIC || field->types == INDEXFLD_T_DATE)) {
         opt->field = field;
         opt->fieldName = name;
         opt->asc = arng->sortAscMap & 0x01;
@@ -120,6 +120,8 @@ static QueryNode *checkQueryTypes(QueryNode *node, const char *name, QueryNode *
     case QN_TAG:       // NO SCORE
     case QN_VECTOR:    // NO SCORE
     case QN_WILDCARD:  // No SCORE
+    case QN_MISSING:   // NO SCORE
+    case QN_ISEMPTY:   // NO SCORE
     case QN_NULL:
       break;
   }
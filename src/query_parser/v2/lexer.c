@@ -9,6 +9,7 @@
 #include <stdlib.h>
 #include <stdio.h>
 #include <string.h>
+#include <strings.h>
 #include <assert.h>
 #include <math.h>
 
@@ -27,213 +28,462 @@ void RSQuery_Parse_v2(void *yyp, int yymajor, QueryToken yyminor, QueryParseCtx
 void *RSQuery_ParseAlloc_v2(void *(*mallocProc)(size_t));
 void RSQuery_ParseFree_v2(void *p, void (*freeProc)(void *));
 
+/* A term immediately followed by an opening parenthesis may be a function call */
+static int termTokenType(const char *ts, const char *te, const char *pe) {
+  size_t len = te - ts;
+  if (te < pe && *te == '(' && len == strlen("ismissing") && !strncasecmp(ts, "ismissing", len)) {
+    return ISMISSING;
+  }
+  return TERM;
+}
+
 
-/* #line 332 "lexer.rl" */
+/* #line 355 "lexer.rl" */
 
 
 
-/* #line 36 "lexer.c" */
+/* #line 46 "lexer.c" */
 static const char _query_actions[] = {
 	0, 1, 0, 1, 1, 1, 2, 1, 
-	14, 1, 15, 1, 16, 1, 17, 1, 
-	18, 1, 19, 1, 20, 1, 21, 1, 
-	22, 1, 23, 1, 24, 1, 25, 1, 
-	26, 1, 27, 1, 28, 1, 29, 1, 
-	30, 1, 31, 1, 32, 1, 33, 1, 
-	34, 1, 35, 1, 36, 1, 37, 1, 
-	38, 1, 39, 1, 40, 1, 41, 1, 
-	42, 1, 43, 1, 44, 1, 45, 1, 
-	46, 1, 47, 1, 48, 1, 49, 1, 
-	50, 1, 51, 2, 2, 3, 2, 2, 
-	4, 2, 2, 5, 2, 2, 6, 2, 
-	2, 7, 2, 2, 8, 2, 2, 9, 
-	2, 2, 10, 2, 2, 11, 2, 2, 
-	12, 2, 2, 13
+	15, 1, 16, 1, 17, 1, 18, 1, 
+	19, 1, 20, 1, 21, 1, 22, 1, 
+	23, 1, 24, 1, 25, 1, 26, 1, 
+	27, 1, 28, 1, 29, 1, 30, 1, 
+	31, 1, 32, 1, 33, 1, 34, 1, 
+	35, 1, 36, 1, 37, 1, 38, 1, 
+	39, 1, 40, 1, 41, 1, 42, 1, 
+	43, 1, 44, 1, 45, 1, 46, 1, 
+	47, 1, 48, 1, 49, 1, 50, 1, 
+	51, 1, 52, 1, 53, 1, 54, 1, 
+	55, 1, 56, 1, 57, 1, 58, 1, 
+	59, 1, 60, 1, 61, 1, 62, 1, 
+	63, 1, 64, 1, 65, 1, 66, 1, 
+	67, 1, 68, 1, 69, 1, 70, 1, 
+	71, 1, 72, 1, 73, 1, 74, 1, 
+	75, 2, 2, 3, 2, 2, 4, 2, 
+	2, 5, 2, 2, 6, 2, 2, 7, 
+	2, 2, 8, 2, 2, 9, 2, 2, 
+	10, 2, 2, 11, 2, 2, 12, 2, 
+	2, 13, 2, 2, 14
 };
 
 static const short _query_key_offsets[] = {
-	0, 10, 20, 22, 22, 25, 28, 38, 
-	48, 50, 52, 55, 57, 58, 59, 61, 
-	64, 66, 76, 81, 83, 85, 87, 89, 
-	91, 93, 95, 98, 99, 100, 102, 104, 
-	106, 108, 110, 112, 114, 114, 155, 166, 
-	176, 187, 190, 192, 206, 217, 223, 228, 
-	231, 247, 259, 260, 263, 269, 274, 277, 
-	293, 307, 320, 321, 331, 344, 352, 362, 
-	374, 386
+	0, 0, 10, 20, 28, 30, 41, 61, 
+	63, 73, 74, 76, 86, 98, 133, 151, 
+	175, 193, 211, 219, 230, 232, 233, 236, 
+	238, 243, 255, 273, 291, 309, 327, 335, 
+	337, 340, 342, 344, 362, 380, 398, 416, 
+	419, 437, 455, 473, 491, 503, 521, 539, 
+	557, 575, 587, 605, 623, 641, 653, 657, 
+	675, 693, 711, 716, 734, 739, 784, 807, 
+	807, 807, 807, 807, 827, 827, 838, 838, 
+	838, 864, 865, 868, 899, 899, 899, 900, 
+	920, 949, 984, 994, 994, 1017, 1043, 1069, 
+	1069, 1069, 1069, 1069, 1069, 1092, 1115, 1146, 
+	1152, 1178, 1178, 1198, 1221, 1247, 1247, 1247, 
+	1253, 1279, 1284, 1307, 1330, 1338, 1343, 1343, 
+	1346, 1346, 1349, 1349
 };
 
 static const char _query_trans_keys[] = {
 	9, 13, 32, 47, 58, 64, 91, 96, 
 	123, 126, 9, 13, 32, 47, 58, 64, 
-	91, 96, 123, 126, 39, 92, 39, 92, 
-	110, 39, 92, 102, 9, 13, 32, 47, 
-	58, 64, 91, 96, 123, 126, 92, 96, 
-	0, 47, 58, 64, 91, 94, 123, 127, 
-	48, 57, 48, 57, 45, 48, 57, 48, 
-	57, 110, 102, 48, 57, 45, 48, 57, 
-	48, 57, 9, 13, 32, 47, 58, 64, 
-	91, 96, 123, 126, 41, 67, 87, 99, 
-	119, 79, 111, 78, 110, 84, 116, 65, 
-	97, 73, 105, 78, 110, 83, 115, 32, 
-	9, 13, 93, 93, 73, 105, 84, 116, 
-	72, 104, 73, 105, 78, 110, 39, 92, 
-	39, 92, 32, 34, 36, 37, 39, 40, 
-	41, 42, 43, 45, 58, 59, 61, 64, 
-	65, 91, 92, 93, 95, 97, 105, 119, 
-	123, 124, 125, 126, 127, 0, 8, 9, 
-	13, 14, 31, 33, 47, 48, 57, 60, 
-	63, 94, 96, 42, 92, 96, 0, 47, 
-	58, 64, 91, 94, 123, 127, 92, 96, 
-	0, 47, 58, 64, 91, 94, 123, 127, 
-	42, 92, 96, 0, 47, 58, 64, 91, 
-	94, 123, 127, 39, 92, 105, 39, 92, 
-	36, 45, 92, 96, 0, 47, 48, 57, 
-	58, 64, 91, 94, 123, 127, 42, 92, 
-	96, 0, 47, 58, 64, 91, 94, 123, 
-	127, 42, 46, 69, 101, 48, 57, 42, 
-	69, 101, 48, 57, 42, 48, 57, 42, 
-	46, 69, 92, 96, 101, 0, 47, 48, 
-	57, 58, 64, 91, 94, 123, 127, 42, 
-	45, 92, 96, 0, 47, 58, 64, 91, 
-	94, 123, 127, 105, 105, 48, 57, 42, 
-	46, 69, 101, 48, 57, 42, 69, 101, 
-	48, 57, 42, 48, 57, 42, 46, 69, 
-	92, 96, 101, 0, 47, 48, 57, 58, 
-	64, 91, 94, 123, 127, 42, 45, 92, 
-	96, 0, 47, 48, 57, 58, 64, 91, 
-	94, 123, 127, 42, 92, 96, 0, 47, 
-	48, 57, 58, 64, 91, 94, 123, 127, 
-	62, 92, 96, 0, 47, 58, 64, 91, 
-	94, 123, 127, 42, 83, 92, 96, 115, 
-	0, 47, 58, 64, 91, 94, 123, 127, 
-	32, 41, 67, 87, 99, 119, 9, 13, 
+	91, 96, 123, 126, 39, 92, -128, 38, 
+	40, 91, 93, 127, -128, 127, 39, 92, 
+	110, -128, 38, 40, 91, 93, 109, 111, 
+	127, 91, 92, 95, 96, -128, -1, 0, 
+	47, 48, 57, 58, 64, 65, 90, 93, 
+	94, 97, 122, 123, 127, 48, 57, 9, 
+	13, 32, 47, 58, 64, 91, 96, 123, 
+	126, 110, 48, 57, 9, 13, 32, 47, 
+	58, 64, 91, 96, 123, 126, 32, 93, 
+	-128, 8, 9, 13, 14, 31, 33, 92, 
+	94, 127, 32, 41, 67, 68, 73, 87, 
+	93, 99, 100, 105, 119, -128, 8, 9, 
+	13, 14, 31, 33, 40, 42, 66, 69, 
+	72, 74, 86, 88, 92, 94, 98, 101, 
+	104, 106, 118, 120, 127, 32, 79, 93, 
+	111, -128, 8, 9, 13, 14, 31, 33, 
+	78, 80, 92, 94, 110, 112, 127, 32, 
+	73, 87, 93, 105, 119, -128, 8, 9, 
+	13, 14, 31, 33, 72, 74, 86, 88, 
+	92, 94, 104, 106, 118, 120, 127, 32, 
+	78, 93, 110, -128, 8, 9, 13, 14, 
+	31, 33, 77, 79, 92, 94, 109, 111, 
+	127, 32, 73, 93, 105, -128, 8, 9, 
+	13, 14, 31, 33, 72, 74, 92, 94, 
+	104, 106, 127, 39, 92, -128, 38, 40, 
+	91, 93, 127, 39, 92, 102, -128, 38, 
+	40, 91, 93, 101, 103, 127, 48, 57, 
+	102, 45, 48, 57, 48, 57, 32, 84, 
+	116, 9, 13, 32, 93, -128, 8, 9, 
+	13, 14, 31, 33, 92, 94, 127, 32, 
+	78, 93, 110, -128, 8, 9, 13, 14, 
+	31, 33, 77, 79, 92, 94, 109, 111, 
+	127, 32, 83, 93, 115, -128, 8, 9, 
+	13, 14, 31, 33, 82, 84, 92, 94, 
+	114, 116, 127, 32, 84, 93, 116, -128, 
+	8, 9, 13, 14, 31, 33, 83, 85, 
+	92, 94, 115, 117, 127, 32, 84, 93, 
+	116, -128, 8, 9, 13, 14, 31, 33, 
+	83, 85, 92, 94, 115, 117, 127, 39, 
+	92, -128, 38, 40, 91, 93, 127, -128, 
+	127, 45, 48, 57, 48, 57, 79, 111, 
+	32, 84, 93, 116, -128, 8, 9, 13, 
+	14, 31, 33, 83, 85, 92, 94, 115, 
+	117, 127, 32, 74, 93, 106, -128, 8, 
+	9, 13, 14, 31, 33, 73, 75, 92, 
+	94, 105, 107, 127, 32, 69, 93, 101, 
+	-128, 8, 9, 13, 14, 31, 33, 68, 
+	70, 92, 94, 100, 102, 127, 32, 72, 
+	93, 104, -128, 8, 9, 13, 14, 31, 
+	33, 71, 73, 92, 94, 103, 105, 127, 
+	32, 9, 13, 32, 65, 93, 97, -128, 
+	8, 9, 13, 14, 31, 33, 64, 66, 
+	92, 94, 96, 98, 127, 32, 79, 93, 
+	111, -128, 8, 9, 13, 14, 31, 33, 
+	78, 80, 92, 94, 110, 112, 127, 32, 
+	82, 93, 114, -128, 8, 9, 13, 14, 
+	31, 33, 81, 83, 92, 94, 113, 115, 
+	127, 32, 73, 93, 105, -128, 8, 9, 
+	13, 14, 31, 33, 72, 74, 92, 94, 
+	104, 106, 127, 32, 93, -128, 8, 9, 
+	13, 14, 31, 33, 92, 94, 127, 32, 
+	73, 93, 105, -128, 8, 9, 13, 14, 
+	31, 33, 72, 74, 92, 94, 104, 106, 
+	127, 32, 73, 93, 105, -128, 8, 9, 
+	13, 14, 31, 33, 72, 74, 92, 94, 
+	104, 106, 127, 32, 83, 93, 115, -128, 
+	8, 9, 13, 14, 31, 33, 82, 84, 
+	92, 94, 114, 116, 127, 32, 78, 93, 
+	110, -128, 8, 9, 13, 14, 31, 33, 
+	77, 79, 92, 94, 109, 111, 127, 32, 
+	93, -128, 8, 9, 13, 14, 31, 33, 
+	92, 94, 127, 32, 78, 93, 110, -128, 
+	8, 9, 13, 14, 31, 33, 77, 79, 
+	92, 94, 109, 111, 127, 32, 78, 93, 
+	110, -128, 8, 9, 13, 14, 31, 33, 
+	77, 79, 92, 94, 109, 111, 127, 32, 
+	69, 93, 101, -128, 8, 9, 13, 14, 
+	31, 33, 68, 70, 92, 94, 100, 102, 
+	127, 32, 93, -128, 8, 9, 13, 14, 
+	31, 33, 92, 94, 127, 32, 93, 9, 
+	13, 32, 83, 93, 115, -128, 8, 9, 
+	13, 14, 31, 33, 82, 84, 92, 94, 
+	114, 116, 127, 32, 84, 93, 116, -128, 
+	8, 9, 13, 14, 31, 33, 83, 85, 
+	92, 94, 115, 117, 127, 32, 67, 93, 
+	99, -128, 8, 9, 13, 14, 31, 33, 
+	66, 68, 92, 94, 98, 100, 127, 93, 
+	-128, 92, 94, 127, 32, 84, 93, 116, 
+	-128, 8, 9, 13, 14, 31, 33, 83, 
+	85, 92, 94, 115, 117, 127, 93, -128, 
+	92, 94, 127, 32, 34, 36, 37, 39, 
+	40, 41, 42, 43, 45, 58, 59, 61, 
+	64, 65, 91, 92, 93, 95, 97, 105, 
+	119, 123, 124, 125, 126, 127, -128, -1, 
+	0, 8, 9, 13, 14, 31, 48, 57, 
+	66, 90, 98, 104, 106, 118, 120, 122, 
+	42, 91, 92, 95, 96, -128, -1, 0, 
+	41, 43, 47, 48, 57, 58, 64, 65, 
+	90, 93, 94, 97, 122, 123, 127, 91, 
+	92, 95, 96, -128, -1, 0, 47, 48, 
+	57, 58, 64, 65, 90, 93, 94, 97, 
+	122, 123, 127, 39, 92, 105, -128, 38, 
+	40, 91, 93, 104, 106, 127, 36, 45, 
+	91, 92, 95, 96, -128, -1, 0, 35, 
+	37, 44, 46, 47, 48, 57, 58, 64, 
+	65, 90, 93, 94, 97, 122, 123, 127, 
+	105, 105, 48, 57, 42, 46, 47, 69, 
+	91, 92, 95, 96, 101, -128, -1, 0, 
+	41, 43, 45, 48, 57, 58, 64, 65, 
+	68, 70, 90, 93, 94, 97, 100, 102, 
+	122, 123, 127, 62, 91, 92, 95, 96, 
+	-128, -1, 0, 47, 48, 57, 58, 64, 
+	65, 90, 93, 94, 97, 122, 123, 127, 
+	42, 83, 91, 92, 95, 96, 115, -128, 
+	-1, 0, 41, 43, 47, 48, 57, 58, 
+	64, 65, 82, 84, 90, 93, 94, 97, 
+	114, 116, 122, 123, 127, 32, 41, 67, 
+	68, 73, 87, 93, 99, 100, 105, 119, 
+	-128, 8, 9, 13, 14, 31, 33, 40, 
+	42, 66, 69, 72, 74, 86, 88, 92, 
+	94, 98, 101, 104, 106, 118, 120, 127, 
 	9, 13, 32, 47, 58, 64, 91, 96, 
-	123, 126, 42, 92, 96, 110, 0, 47, 
-	58, 64, 91, 94, 123, 127, 42, 92, 
-	96, 102, 0, 47, 58, 64, 91, 94, 
-	123, 127, 39, 42, 92, 96, 0, 47, 
-	58, 64, 91, 94, 123, 127, 0
+	123, 126, 42, 91, 92, 95, 96, -128, 
+	-1, 0, 41, 43, 47, 48, 57, 58, 
+	64, 65, 90, 93, 94, 97, 122, 123, 
+	127, 42, 91, 92, 95, 96, 110, -128, 
+	-1, 0, 41, 43, 47, 48, 57, 58, 
+	64, 65, 90, 93, 94, 97, 109, 111, 
+	122, 123, 127, 39, 42, 91, 92, 95, 
+	96, -128, -1, 0, 38, 40, 41, 43, 
+	47, 48, 57, 58, 64, 65, 90, 93, 
+	94, 97, 122, 123, 127, 42, 91, 92, 
+	95, 96, -128, -1, 0, 41, 43, 47, 
+	48, 57, 58, 64, 65, 90, 93, 94, 
+	97, 122, 123, 127, 42, 91, 92, 95, 
+	96, -128, -1, 0, 41, 43, 47, 48, 
+	57, 58, 64, 65, 90, 93, 94, 97, 
+	122, 123, 127, 42, 46, 47, 69, 91, 
+	92, 95, 96, 101, -128, -1, 0, 41, 
+	43, 45, 48, 57, 58, 64, 65, 68, 
+	70, 90, 93, 94, 97, 100, 102, 122, 
+	123, 127, 42, 46, 69, 101, 48, 57, 
+	42, 45, 91, 92, 95, 96, -128, -1, 
+	0, 41, 43, 44, 46, 47, 48, 57, 
+	58, 64, 65, 90, 93, 94, 97, 122, 
+	123, 127, 91, 92, 95, 96, -128, -1, 
+	0, 47, 48, 57, 58, 64, 65, 90, 
+	93, 94, 97, 122, 123, 127, 42, 91, 
+	92, 95, 96, -128, -1, 0, 41, 43, 
+	47, 48, 57, 58, 64, 65, 90, 93, 
+	94, 97, 122, 123, 127, 42, 91, 92, 
+	95, 96, 102, -128, -1, 0, 41, 43, 
+	47, 48, 57, 58, 64, 65, 90, 93, 
+	94, 97, 101, 103, 122, 123, 127, 42, 
+	46, 69, 101, 48, 57, 42, 45, 91, 
+	92, 95, 96, -128, -1, 0, 41, 43, 
+	44, 46, 47, 48, 57, 58, 64, 65, 
+	90, 93, 94, 97, 122, 123, 127, 42, 
+	69, 101, 48, 57, 42, 91, 92, 95, 
+	96, -128, -1, 0, 41, 43, 47, 48, 
+	57, 58, 64, 65, 90, 93, 94, 97, 
+	122, 123, 127, 42, 91, 92, 95, 96, 
+	-128, -1, 0, 41, 43, 47, 48, 57, 
+	58, 64, 65, 90, 93, 94, 97, 122, 
+	123, 127, 39, 92, -128, 38, 40, 91, 
+	93, 127, 42, 69, 101, 48, 57, 42, 
+	48, 57, 42, 48, 57, 0
 };
 
 static const char _query_single_lengths[] = {
-	0, 0, 2, 0, 3, 3, 0, 2, 
-	0, 0, 1, 0, 1, 1, 0, 1, 
-	0, 0, 5, 2, 2, 2, 2, 2, 
-	2, 2, 1, 1, 1, 2, 2, 2, 
-	2, 2, 2, 2, 0, 27, 3, 2, 
-	3, 3, 2, 4, 3, 4, 3, 1, 
-	6, 4, 1, 1, 4, 3, 1, 6, 
-	4, 3, 1, 2, 5, 6, 0, 4, 
-	4, 4
+	0, 0, 0, 2, 0, 3, 4, 0, 
+	0, 1, 0, 0, 2, 11, 4, 6, 
+	4, 4, 2, 3, 0, 1, 1, 0, 
+	3, 2, 4, 4, 4, 4, 2, 0, 
+	1, 0, 2, 4, 4, 4, 4, 1, 
+	4, 4, 4, 4, 2, 4, 4, 4, 
+	4, 2, 4, 4, 4, 2, 2, 4, 
+	4, 4, 1, 4, 1, 27, 5, 0, 
+	0, 0, 0, 4, 0, 3, 0, 0, 
+	6, 1, 1, 9, 0, 0, 1, 4, 
+	7, 11, 0, 0, 5, 6, 6, 0, 
+	0, 0, 0, 0, 5, 5, 9, 4, 
+	6, 0, 4, 5, 6, 0, 0, 4, 
+	6, 3, 5, 5, 2, 3, 0, 1, 
+	0, 1, 0, 0
 };
 
 static const char _query_range_lengths[] = {
-	5, 5, 0, 0, 0, 0, 5, 4, 
-	1, 1, 1, 1, 0, 0, 1, 1, 
-	1, 5, 0, 0, 0, 0, 0, 0, 
-	0, 0, 1, 0, 0, 0, 0, 0, 
-	0, 0, 0, 0, 0, 7, 4, 4, 
-	4, 0, 0, 5, 4, 1, 1, 1, 
-	5, 4, 0, 1, 1, 1, 1, 5, 
-	5, 5, 0, 4, 4, 1, 5, 4, 
-	4, 4
+	0, 5, 5, 3, 1, 4, 8, 1, 
+	5, 0, 1, 5, 5, 12, 7, 9, 
+	7, 7, 3, 4, 1, 0, 1, 1, 
+	1, 5, 7, 7, 7, 7, 3, 1, 
+	1, 1, 0, 7, 7, 7, 7, 1, 
+	7, 7, 7, 7, 5, 7, 7, 7, 
+	7, 5, 7, 7, 7, 5, 1, 7, 
+	7, 7, 2, 7, 2, 9, 9, 0, 
+	0, 0, 0, 8, 0, 4, 0, 0, 
+	10, 0, 1, 11, 0, 0, 0, 8, 
+	11, 12, 5, 0, 9, 10, 10, 0, 
+	0, 0, 0, 0, 9, 9, 11, 1, 
+	10, 0, 8, 9, 10, 0, 0, 1, 
+	10, 1, 9, 9, 3, 1, 0, 1, 
+	0, 1, 0, 0
 };
 
 static const short _query_index_offsets[] = {
-	0, 6, 12, 15, 16, 20, 24, 30, 
-	37, 39, 41, 44, 46, 48, 50, 52, 
-	55, 57, 63, 69, 72, 75, 78, 81, 
-	84, 87, 90, 93, 95, 97, 100, 103, 
-	106, 109, 112, 115, 118, 119, 154, 162, 
-	169, 177, 181, 184, 194, 202, 208, 213, 
-	216, 228, 237, 239, 242, 248, 253, 256, 
-	268, 278, 287, 289, 296, 306, 314, 320, 
-	329, 338
+	0, 1, 7, 13, 19, 21, 29, 42, 
+	44, 50, 52, 54, 60, 68, 92, 104, 
+	120, 132, 144, 150, 158, 160, 162, 165, 
+	167, 172, 180, 192, 204, 216, 228, 234, 
+	236, 239, 241, 244, 256, 268, 280, 292, 
+	295, 307, 319, 331, 343, 351, 363, 375, 
+	387, 399, 407, 419, 431, 443, 451, 455, 
+	467, 479, 491, 495, 507, 511, 548, 563, 
+	564, 565, 566, 567, 580, 581, 589, 590, 
+	591, 608, 610, 613, 634, 635, 636, 638, 
+	651, 670, 694, 700, 701, 716, 733, 750, 
+	751, 752, 753, 754, 755, 770, 785, 806, 
+	812, 829, 830, 843, 858, 875, 876, 877, 
+	883, 900, 905, 920, 935, 941, 946, 947, 
+	950, 951, 954, 955
 };
 
-static const char _query_indicies[] = {
-	1, 1, 1, 1, 1, 0, 2, 2, 
-	2, 2, 2, 0, 4, 5, 3, 3, 
-	4, 5, 7, 3, 4, 5, 8, 3, 
-	9, 9, 9, 9, 9, 0, 11, 10, 
-	10, 10, 10, 10, 9, 12, 10, 14, 
-	13, 15, 16, 13, 16, 13, 17, 0, 
-	18, 0, 19, 0, 21, 22, 20, 22, 
-	0, 23, 23, 23, 23, 23, 0, 25, 
-	25, 26, 25, 26, 24, 27, 27, 24, 
-	28, 28, 24, 29, 29, 24, 30, 30, 
-	24, 31, 31, 24, 32, 32, 24, 33, 
-	33, 24, 34, 34, 24, 24, 35, 36, 
-	35, 37, 37, 24, 38, 38, 24, 39, 
-	39, 24, 40, 40, 24, 33, 33, 24, 
-	41, 43, 42, 44, 43, 42, 42, 46, 
-	48, 49, 50, 51, 52, 53, 54, 55, 
-	56, 58, 59, 60, 61, 62, 63, 64, 
-	65, 66, 62, 67, 68, 69, 70, 71, 
-	72, 45, 45, 46, 45, 47, 57, 47, 
-	47, 1, 73, 74, 0, 0, 0, 0, 
-	0, 1, 76, 75, 75, 75, 75, 75, 
-	2, 73, 76, 77, 77, 77, 77, 77, 
-	2, 75, 5, 78, 3, 4, 5, 3, 
-	81, 82, 11, 80, 80, 83, 80, 80, 
-	80, 9, 85, 11, 84, 84, 84, 84, 
-	84, 9, 85, 86, 87, 87, 12, 84, 
-	85, 87, 87, 14, 84, 85, 16, 84, 
-	85, 86, 88, 11, 84, 88, 84, 83, 
-	84, 84, 84, 9, 85, 15, 11, 84, 
-	84, 84, 84, 84, 9, 89, 75, 89, 
-	91, 90, 73, 93, 94, 94, 91, 92, 
-	73, 94, 94, 19, 92, 73, 22, 92, 
-	73, 93, 96, 74, 95, 96, 95, 57, 
-	95, 95, 95, 1, 73, 21, 74, 97, 
-	97, 98, 97, 97, 97, 1, 73, 74, 
-	92, 92, 98, 92, 92, 92, 1, 99, 
-	75, 100, 0, 0, 0, 0, 0, 23, 
-	73, 101, 74, 97, 101, 97, 97, 97, 
-	97, 1, 103, 25, 25, 26, 25, 26, 
-	103, 102, 1, 1, 1, 1, 1, 75, 
-	73, 74, 97, 104, 97, 97, 97, 97, 
-	1, 73, 74, 97, 105, 97, 97, 97, 
-	97, 1, 106, 73, 74, 97, 97, 97, 
-	97, 97, 1, 0
+static const unsigned char _query_indicies[] = {
+	0, 1, 1, 1, 1, 1, 0, 2, 
+	2, 2, 2, 2, 0, 4, 5, 3, 
+	3, 3, 0, 3, 0, 4, 5, 6, 
+	3, 3, 3, 3, 7, 9, 10, 8, 
+	9, 8, 9, 8, 9, 8, 9, 8, 
+	9, 11, 12, 11, 8, 8, 8, 8, 
+	8, 0, 13, 0, 14, 0, 15, 15, 
+	15, 15, 15, 0, 17, 9, 16, 17, 
+	16, 16, 16, 18, 19, 20, 20, 21, 
+	22, 23, 9, 20, 21, 22, 23, 16, 
+	19, 16, 16, 16, 16, 16, 16, 16, 
+	16, 16, 16, 18, 17, 24, 9, 24, 
+	16, 17, 16, 16, 16, 16, 16, 18, 
+	17, 25, 23, 9, 25, 23, 16, 17, 
+	16, 16, 16, 16, 16, 16, 16, 18, 
+	17, 26, 9, 26, 16, 17, 16, 16, 
+	16, 16, 16, 18, 17, 27, 9, 27, 
+	16, 17, 16, 16, 16, 16, 16, 18, 
+	9, 29, 28, 28, 28, 30, 4, 5, 
+	31, 3, 3, 3, 3, 7, 32, 33, 
+	34, 0, 35, 36, 37, 36, 0, 17, 
+	38, 38, 17, 18, 19, 9, 16, 19, 
+	16, 16, 16, 18, 17, 39, 9, 39, 
+	16, 17, 16, 16, 16, 16, 16, 18, 
+	17, 40, 9, 40, 16, 17, 16, 16, 
+	16, 16, 16, 18, 17, 41, 9, 41, 
+	16, 17, 16, 16, 16, 16, 16, 18, 
+	17, 42, 9, 42, 16, 17, 16, 16, 
+	16, 16, 16, 18, 43, 29, 28, 28, 
+	28, 30, 28, 30, 44, 45, 33, 45, 
+	33, 46, 46, 18, 17, 47, 9, 47, 
+	16, 17, 16, 16, 16, 16, 16, 18, 
+	17, 48, 9, 48, 16, 17, 16, 16, 
+	16, 16, 16, 18, 17, 49, 9, 49, 
+	16, 17, 16, 16, 16, 16, 16, 18, 
+	17, 50, 9, 50, 16, 17, 16, 16, 
+	16, 16, 16, 18, 51, 51, 18, 17, 
+	52, 9, 52, 16, 17, 16, 16, 16, 
+	16, 16, 18, 17, 53, 9, 53, 16, 
+	17, 16, 16, 16, 16, 16, 18, 17, 
+	54, 9, 54, 16, 17, 16, 16, 16, 
+	16, 16, 18, 17, 55, 9, 55, 16, 
+	17, 16, 16, 16, 16, 16, 18, 51, 
+	9, 56, 51, 56, 56, 56, 18, 17, 
+	57, 9, 57, 16, 17, 16, 16, 16, 
+	16, 16, 18, 17, 58, 9, 58, 16, 
+	17, 16, 16, 16, 16, 16, 18, 17, 
+	59, 9, 59, 16, 17, 16, 16, 16, 
+	16, 16, 18, 17, 60, 9, 60, 16, 
+	17, 16, 16, 16, 16, 16, 18, 61, 
+	62, 56, 61, 56, 56, 56, 18, 17, 
+	63, 9, 63, 16, 17, 16, 16, 16, 
+	16, 16, 18, 17, 64, 9, 64, 16, 
+	17, 16, 16, 16, 16, 16, 18, 17, 
+	65, 9, 65, 16, 17, 16, 16, 16, 
+	16, 16, 18, 66, 9, 16, 66, 16, 
+	16, 16, 18, 61, 62, 61, 18, 17, 
+	60, 9, 60, 16, 17, 16, 16, 16, 
+	16, 16, 18, 17, 60, 9, 60, 16, 
+	17, 16, 16, 16, 16, 16, 18, 17, 
+	67, 9, 67, 16, 17, 16, 16, 16, 
+	16, 16, 18, 9, 68, 68, 18, 17, 
+	63, 9, 63, 16, 17, 16, 16, 16, 
+	16, 16, 18, 69, 68, 68, 18, 71, 
+	73, 74, 75, 76, 77, 78, 79, 80, 
+	81, 83, 84, 85, 86, 87, 88, 89, 
+	90, 91, 87, 92, 93, 94, 95, 96, 
+	97, 70, 1, 70, 71, 70, 82, 1, 
+	1, 1, 1, 72, 98, 9, 99, 1, 
+	9, 1, 9, 9, 1, 9, 1, 9, 
+	1, 9, 100, 101, 102, 103, 104, 9, 
+	105, 2, 9, 2, 9, 2, 9, 2, 
+	9, 2, 9, 103, 106, 9, 5, 107, 
+	3, 3, 3, 3, 103, 108, 109, 110, 
+	111, 9, 10, 8, 9, 8, 9, 9, 
+	9, 112, 9, 8, 9, 8, 9, 113, 
+	114, 103, 114, 115, 116, 98, 117, 9, 
+	118, 9, 99, 1, 9, 118, 1, 9, 
+	9, 82, 9, 1, 1, 9, 1, 1, 
+	9, 119, 120, 121, 122, 103, 9, 123, 
+	15, 9, 15, 9, 15, 9, 15, 9, 
+	15, 9, 103, 98, 124, 9, 99, 1, 
+	9, 124, 1, 9, 9, 1, 9, 1, 
+	1, 9, 1, 1, 9, 100, 125, 20, 
+	20, 21, 22, 23, 9, 20, 21, 22, 
+	23, 16, 125, 16, 16, 16, 16, 16, 
+	16, 16, 16, 16, 16, 126, 1, 1, 
+	1, 1, 1, 103, 127, 98, 9, 99, 
+	1, 9, 1, 9, 9, 1, 9, 1, 
+	9, 1, 9, 103, 98, 9, 99, 1, 
+	9, 128, 1, 9, 9, 1, 9, 1, 
+	9, 1, 1, 9, 100, 129, 98, 9, 
+	99, 1, 9, 1, 9, 9, 9, 1, 
+	9, 1, 9, 1, 9, 100, 130, 131, 
+	132, 133, 134, 98, 9, 105, 2, 9, 
+	2, 9, 9, 2, 9, 2, 9, 2, 
+	9, 135, 136, 9, 10, 8, 9, 8, 
+	9, 9, 8, 9, 8, 9, 8, 9, 
+	137, 136, 138, 9, 139, 9, 10, 8, 
+	9, 139, 8, 9, 9, 112, 9, 8, 
+	8, 9, 8, 8, 9, 137, 98, 117, 
+	140, 140, 115, 141, 98, 35, 9, 99, 
+	1, 9, 1, 9, 9, 9, 142, 9, 
+	1, 9, 1, 9, 100, 143, 9, 123, 
+	15, 9, 15, 9, 15, 9, 15, 9, 
+	15, 9, 144, 98, 9, 99, 1, 9, 
+	1, 9, 9, 1, 9, 1, 9, 1, 
+	9, 145, 98, 9, 99, 1, 9, 146, 
+	1, 9, 9, 1, 9, 1, 9, 1, 
+	1, 9, 100, 147, 148, 136, 138, 149, 
+	149, 12, 137, 136, 44, 9, 10, 8, 
+	9, 8, 9, 9, 9, 8, 9, 8, 
+	9, 8, 9, 137, 98, 140, 140, 14, 
+	141, 98, 9, 99, 1, 9, 1, 9, 
+	9, 142, 9, 1, 9, 1, 9, 141, 
+	98, 9, 99, 1, 9, 1, 9, 9, 
+	1, 9, 1, 9, 1, 9, 150, 4, 
+	5, 3, 3, 3, 150, 136, 149, 149, 
+	32, 137, 150, 98, 36, 141, 151, 136, 
+	45, 137, 152, 153, 0
 };
 
 static const char _query_trans_targs[] = {
-	37, 38, 40, 2, 37, 3, 37, 5, 
-	42, 44, 37, 6, 45, 37, 46, 11, 
-	47, 13, 37, 53, 37, 16, 54, 59, 
-	37, 19, 29, 20, 21, 22, 23, 24, 
-	25, 26, 27, 28, 37, 30, 31, 32, 
-	33, 37, 35, 36, 37, 37, 37, 37, 
-	37, 39, 37, 41, 37, 37, 43, 50, 
-	51, 55, 37, 37, 58, 59, 60, 61, 
-	62, 37, 38, 63, 65, 37, 37, 37, 
-	37, 37, 0, 37, 1, 37, 4, 37, 
-	37, 7, 8, 48, 37, 37, 9, 10, 
-	49, 12, 37, 52, 37, 14, 15, 37, 
-	56, 37, 57, 37, 17, 38, 37, 18, 
-	64, 38, 34
+	61, 62, 92, 3, 61, 4, 19, 61, 
+	93, 0, 8, 61, 103, 21, 105, 98, 
+	12, 24, 61, 25, 14, 15, 16, 17, 
+	26, 27, 28, 29, 30, 31, 61, 108, 
+	109, 61, 61, 23, 111, 61, 34, 35, 
+	36, 37, 38, 61, 33, 113, 39, 40, 
+	41, 42, 43, 44, 45, 46, 47, 48, 
+	49, 50, 51, 52, 53, 54, 61, 55, 
+	56, 57, 58, 59, 60, 61, 61, 61, 
+	61, 61, 67, 61, 69, 61, 61, 72, 
+	73, 74, 75, 61, 61, 78, 79, 80, 
+	81, 82, 61, 84, 85, 86, 61, 61, 
+	61, 61, 61, 1, 61, 61, 61, 61, 
+	61, 2, 61, 5, 61, 61, 6, 7, 
+	94, 61, 9, 95, 61, 10, 96, 61, 
+	61, 61, 61, 11, 99, 13, 61, 61, 
+	100, 18, 61, 61, 61, 61, 61, 61, 
+	61, 61, 20, 104, 22, 61, 106, 61, 
+	61, 61, 107, 61, 61, 32, 61, 61, 
+	61, 61
 };
 
-static const char _query_trans_actions[] = {
-	81, 110, 92, 0, 43, 0, 75, 0, 
-	98, 113, 71, 0, 5, 79, 5, 0, 
-	0, 0, 9, 86, 69, 0, 0, 89, 
-	73, 0, 0, 0, 0, 0, 0, 0, 
-	0, 0, 0, 0, 47, 0, 0, 0, 
-	0, 77, 0, 0, 45, 37, 33, 35, 
-	11, 107, 29, 107, 15, 17, 104, 107, 
-	101, 83, 23, 25, 0, 107, 110, 5, 
-	0, 31, 107, 110, 110, 19, 13, 21, 
-	27, 39, 0, 63, 0, 53, 0, 55, 
-	59, 0, 0, 113, 67, 41, 0, 0, 
-	113, 0, 57, 86, 51, 0, 0, 49, 
-	110, 65, 86, 7, 0, 95, 61, 0, 
-	110, 98, 0
+static const unsigned char _query_trans_actions[] = {
+	127, 159, 138, 0, 43, 0, 0, 121, 
+	162, 0, 0, 117, 5, 0, 132, 135, 
+	0, 0, 119, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 123, 144, 
+	5, 125, 9, 0, 5, 115, 0, 0, 
+	0, 0, 0, 45, 0, 5, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 49, 0, 
+	0, 0, 0, 0, 0, 47, 37, 33, 
+	35, 11, 156, 29, 156, 15, 17, 150, 
+	156, 147, 129, 23, 25, 5, 156, 159, 
+	153, 5, 31, 156, 159, 159, 19, 13, 
+	21, 27, 39, 0, 99, 97, 93, 95, 
+	65, 0, 87, 0, 69, 71, 0, 0, 
+	162, 85, 0, 132, 81, 0, 159, 51, 
+	77, 79, 7, 0, 141, 0, 89, 91, 
+	159, 0, 73, 67, 75, 83, 101, 57, 
+	41, 103, 0, 162, 0, 53, 132, 59, 
+	55, 61, 144, 107, 105, 0, 63, 109, 
+	113, 111
 };
 
 static const char _query_to_state_actions[] = {
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
@@ -242,10 +492,16 @@ static const char _query_to_state_actions[] = {
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
-	0, 0
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0
 };
 
 static const char _query_from_state_actions[] = {
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
@@ -254,29 +510,38 @@ static const char _query_from_state_actions[] = {
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
 	0, 0, 0, 0, 0, 0, 0, 0, 
-	0, 0
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0, 0, 0, 0, 0, 
+	0, 0, 0, 0
 };
 
 static const short _query_eof_trans[] = {
-	1, 1, 1, 1, 7, 7, 1, 11, 
-	11, 14, 14, 14, 1, 1, 1, 21, 
-	1, 1, 25, 25, 25, 25, 25, 25, 
-	25, 25, 25, 25, 25, 25, 25, 25, 
-	25, 25, 42, 42, 42, 0, 1, 76, 
-	78, 76, 80, 81, 85, 85, 85, 85, 
-	85, 85, 76, 91, 93, 93, 93, 96, 
-	98, 93, 76, 1, 98, 103, 76, 98, 
-	98, 98
+	1, 1, 1, 1, 1, 8, 12, 12, 
+	1, 1, 1, 1, 19, 19, 19, 19, 
+	19, 19, 31, 8, 34, 1, 38, 1, 
+	19, 19, 19, 19, 19, 19, 31, 31, 
+	34, 34, 19, 19, 19, 19, 19, 19, 
+	19, 19, 19, 19, 19, 19, 19, 19, 
+	19, 19, 19, 19, 19, 19, 19, 19, 
+	19, 19, 19, 19, 19, 0, 101, 102, 
+	103, 104, 105, 104, 107, 104, 109, 110, 
+	114, 104, 117, 120, 121, 122, 104, 104, 
+	101, 127, 104, 128, 104, 101, 101, 131, 
+	132, 133, 134, 135, 136, 138, 138, 142, 
+	101, 144, 145, 146, 101, 148, 149, 138, 
+	138, 142, 142, 151, 151, 138, 151, 142, 
+	152, 138, 153, 154
 };
 
-static const int query_start = 37;
-static const int query_first_final = 37;
+static const int query_start = 61;
+static const int query_first_final = 61;
 static const int query_error = -1;
 
-static const int query_en_main = 37;
+static const int query_en_main = 61;
 
 
-/* #line 335 "lexer.rl" */
+/* #line 358 "lexer.rl" */
 
 QueryNode *RSQuery_ParseRaw_v2(QueryParseCtx *q) {
   void *pParser = RSQuery_ParseAlloc_v2(rm_malloc);
@@ -286,7 +551,7 @@ QueryNode *RSQuery_ParseRaw_v2(QueryParseCtx *q) {
   const char* ts = q->raw;
   const char* te = q->raw + q->len;
   
-/* #line 290 "lexer.c" */
+/* #line 555 "lexer.c" */
 	{
 	cs = query_start;
 	ts = 0;
@@ -294,7 +559,7 @@ QueryNode *RSQuery_ParseRaw_v2(QueryParseCtx *q) {
 	act = 0;
 	}
 
-/* #line 344 "lexer.rl" */
+/* #line 367 "lexer.rl" */
   QueryToken tok = {.len = 0, .pos = 0, .s = 0};
   
   //parseCtx ctx = {.root = NULL, .ok = 1, .errorMsg = NULL, .q = q};
@@ -303,7 +568,7 @@ QueryNode *RSQuery_ParseRaw_v2(QueryParseCtx *q) {
   const char* eof = pe;
   
   
-/* #line 307 "lexer.c" */
+/* #line 572 "lexer.c" */
 	{
 	int _klen;
 	unsigned int _trans;
@@ -322,7 +587,7 @@ _resume:
 /* #line 1 "NONE" */
 	{ts = p;}
 	break;
-/* #line 326 "lexer.c" */
+/* #line 591 "lexer.c" */
 		}
 	}
 
@@ -393,51 +658,55 @@ _eof_trans:
 	{te = p+1;}
 	break;
 	case 3:
-/* #line 67 "lexer.rl" */
+/* #line 79 "lexer.rl" */
 	{act = 1;}
 	break;
 	case 4:
-/* #line 78 "lexer.rl" */
+/* #line 90 "lexer.rl" */
 	{act = 2;}
 	break;
 	case 5:
-/* #line 89 "lexer.rl" */
+/* #line 101 "lexer.rl" */
 	{act = 3;}
 	break;
 	case 6:
-/* #line 98 "lexer.rl" */
+/* #line 110 "lexer.rl" */
 	{act = 4;}
 	break;
 	case 7:
-/* #line 116 "lexer.rl" */
+/* #line 128 "lexer.rl" */
 	{act = 6;}
 	break;
 	case 8:
-/* #line 125 "lexer.rl" */
+/* #line 137 "lexer.rl" */
 	{act = 7;}
 	break;
 	case 9:
-/* #line 194 "lexer.rl" */
+/* #line 206 "lexer.rl" */
 	{act = 16;}
 	break;
 	case 10:
-/* #line 208 "lexer.rl" */
+/* #line 220 "lexer.rl" */
 	{act = 18;}
 	break;
 	case 11:
-/* #line 237 "lexer.rl" */
-	{act = 23;}
+/* #line 234 "lexer.rl" */
+	{act = 20;}
 	break;
 	case 12:
-/* #line 240 "lexer.rl" */
-	{act = 25;}
+/* #line 249 "lexer.rl" */
+	{act = 23;}
 	break;
 	case 13:
-/* #line 264 "lexer.rl" */
-	{act = 27;}
+/* #line 252 "lexer.rl" */
+	{act = 25;}
 	break;
 	case 14:
-/* #line 107 "lexer.rl" */
+/* #line 276 "lexer.rl" */
+	{act = 27;}
+	break;
+	case 15:
+/* #line 119 "lexer.rl" */
 	{te = p+1;{
     tok.pos = ts-q->raw;
     tok.len = te - ts;
@@ -448,8 +717,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 15:
-/* #line 125 "lexer.rl" */
+	case 16:
+/* #line 137 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     tok.s = ts;
@@ -461,8 +730,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 16:
-/* #line 136 "lexer.rl" */
+	case 17:
+/* #line 148 "lexer.rl" */
 	{te = p+1;{
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, QUOTE, tok, q);  
@@ -471,8 +740,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 17:
-/* #line 143 "lexer.rl" */
+	case 18:
+/* #line 155 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, OR, tok, q);
@@ -481,8 +750,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 18:
-/* #line 150 "lexer.rl" */
+	case 19:
+/* #line 162 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, LP, tok, q);
@@ -491,8 +760,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 19:
-/* #line 158 "lexer.rl" */
+	case 20:
+/* #line 170 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, RP, tok, q);
@@ -501,8 +770,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 20:
-/* #line 165 "lexer.rl" */
+	case 21:
+/* #line 177 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, LB, tok, q);
@@ -511,8 +780,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 21:
-/* #line 172 "lexer.rl" */
+	case 22:
+/* #line 184 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, RB, tok, q);
@@ -521,8 +790,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 22:
-/* #line 179 "lexer.rl" */
+	case 23:
+/* #line 191 "lexer.rl" */
 	{te = p+1;{ 
      tok.pos = ts-q->raw;
      RSQuery_Parse_v2(pParser, COLON, tok, q);
@@ -531,8 +800,8 @@ _eof_trans:
     }
    }}
 	break;
-	case 23:
-/* #line 186 "lexer.rl" */
+	case 24:
+/* #line 198 "lexer.rl" */
 	{te = p+1;{ 
      tok.pos = ts-q->raw;
      RSQuery_Parse_v2(pParser, SEMICOLON, tok, q);
@@ -541,8 +810,8 @@ _eof_trans:
     }
    }}
 	break;
-	case 24:
-/* #line 201 "lexer.rl" */
+	case 25:
+/* #line 213 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, TILDE, tok, q);  
@@ -551,8 +820,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 25:
-/* #line 215 "lexer.rl" */
+	case 26:
+/* #line 227 "lexer.rl" */
 	{te = p+1;{
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, PERCENT, tok, q);
@@ -561,8 +830,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 26:
-/* #line 229 "lexer.rl" */
+	case 27:
+/* #line 241 "lexer.rl" */
 	{te = p+1;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, RSQB, tok, q);   
@@ -570,21 +839,21 @@ _eof_trans:
       {p++; goto _out; }
     } 
   }}
-	break;
-	case 27:
-/* #line 236 "lexer.rl" */
-	{te = p+1;}
 	break;
 	case 28:
-/* #line 237 "lexer.rl" */
+/* #line 248 "lexer.rl" */
 	{te = p+1;}
 	break;
 	case 29:
-/* #line 238 "lexer.rl" */
+/* #line 249 "lexer.rl" */
 	{te = p+1;}
 	break;
 	case 30:
 /* #line 250 "lexer.rl" */
+	{te = p+1;}
+	break;
+	case 31:
+/* #line 262 "lexer.rl" */
 	{te = p+1;{
     int is_attr = (*ts == '$') ? 1 : 0;
     tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
@@ -600,8 +869,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 31:
-/* #line 278 "lexer.rl" */
+	case 32:
+/* #line 290 "lexer.rl" */
 	{te = p+1;{
     int is_attr = (*(ts+1) == '$') ? 1 : 0;
     tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
@@ -617,8 +886,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 32:
-/* #line 293 "lexer.rl" */
+	case 33:
+/* #line 305 "lexer.rl" */
 	{te = p+1;{
     int is_attr = (*(ts+2) == '$') ? 1 : 0;
     tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
@@ -632,8 +901,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 33:
-/* #line 306 "lexer.rl" */
+	case 34:
+/* #line 318 "lexer.rl" */
 	{te = p+1;{
     int is_attr = (*(ts+2) == '$') ? 1 : 0;
     tok.type = is_attr ? QT_PARAM_WILDCARD : QT_WILDCARD;
@@ -647,8 +916,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 34:
-/* #line 319 "lexer.rl" */
+	case 35:
+/* #line 331 "lexer.rl" */
 	{te = p+1;{
     tok.pos = ts-q->raw + 2;
     tok.len = te - ts - 2;
@@ -660,8 +929,21 @@ _eof_trans:
     }
   }}
 	break;
-	case 35:
-/* #line 67 "lexer.rl" */
+	case 36:
+/* #line 342 "lexer.rl" */
+	{te = p+1;{
+    tok.pos = ts-q->raw + 1;
+    tok.len = te - ts - 2;
+    tok.s = ts + 1;
+    tok.numval = 0;
+    RSQuery_Parse_v2(pParser, DATE_RANGE, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 37:
+/* #line 79 "lexer.rl" */
 	{te = p;p--;{ 
     tok.s = ts;
     tok.len = te-ts;
@@ -674,8 +956,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 36:
-/* #line 78 "lexer.rl" */
+	case 38:
+/* #line 90 "lexer.rl" */
 	{te = p;p--;{ 
     tok.s = ts;
     tok.len = te-ts;
@@ -688,8 +970,20 @@ _eof_trans:
     }
   }}
 	break;
-	case 37:
-/* #line 98 "lexer.rl" */
+	case 39:
+/* #line 101 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw;
+    tok.len = te - (ts + 1);
+    tok.s = ts+1;
+    RSQuery_Parse_v2(pParser, MODIFIER, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 40:
+/* #line 110 "lexer.rl" */
 	{te = p;p--;{
     tok.pos = ts-q->raw;
     tok.len = te - (ts + 1);
@@ -700,8 +994,32 @@ _eof_trans:
     }
   }}
 	break;
-	case 38:
-/* #line 125 "lexer.rl" */
+	case 41:
+/* #line 119 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw;
+    tok.len = te - ts;
+    tok.s = ts+1;
+    RSQuery_Parse_v2(pParser, ARROW, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 42:
+/* #line 128 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw;
+    tok.len = te - ts;
+    tok.s = ts;
+    RSQuery_Parse_v2(pParser, AS_T, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 43:
+/* #line 137 "lexer.rl" */
 	{te = p;p--;{ 
     tok.pos = ts-q->raw;
     tok.s = ts;
@@ -713,8 +1031,88 @@ _eof_trans:
     }
   }}
 	break;
-	case 39:
-/* #line 194 "lexer.rl" */
+	case 44:
+/* #line 148 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, QUOTE, tok, q);  
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 45:
+/* #line 155 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, OR, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 46:
+/* #line 162 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, LP, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 47:
+/* #line 170 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, RP, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 48:
+/* #line 177 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, LB, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 49:
+/* #line 184 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, RB, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 50:
+/* #line 191 "lexer.rl" */
+	{te = p;p--;{ 
+     tok.pos = ts-q->raw;
+     RSQuery_Parse_v2(pParser, COLON, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+   }}
+	break;
+	case 51:
+/* #line 198 "lexer.rl" */
+	{te = p;p--;{ 
+     tok.pos = ts-q->raw;
+     RSQuery_Parse_v2(pParser, SEMICOLON, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+   }}
+	break;
+	case 52:
+/* #line 206 "lexer.rl" */
 	{te = p;p--;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, MINUS, tok, q);  
@@ -723,8 +1121,18 @@ _eof_trans:
     }
   }}
 	break;
-	case 40:
-/* #line 208 "lexer.rl" */
+	case 53:
+/* #line 213 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, TILDE, tok, q);  
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 54:
+/* #line 220 "lexer.rl" */
 	{te = p;p--;{
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, STAR, tok, q);
@@ -733,8 +1141,18 @@ _eof_trans:
     }
   }}
 	break;
-	case 41:
-/* #line 222 "lexer.rl" */
+	case 55:
+/* #line 227 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, PERCENT, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 56:
+/* #line 234 "lexer.rl" */
 	{te = p;p--;{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, LSQB, tok, q);  
@@ -743,25 +1161,60 @@ _eof_trans:
     }  
   }}
 	break;
-	case 42:
-/* #line 237 "lexer.rl" */
+	case 57:
+/* #line 241 "lexer.rl" */
+	{te = p;p--;{ 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, RSQB, tok, q);   
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    } 
+  }}
+	break;
+	case 58:
+/* #line 248 "lexer.rl" */
 	{te = p;p--;}
 	break;
-	case 43:
-/* #line 240 "lexer.rl" */
+	case 59:
+/* #line 249 "lexer.rl" */
+	{te = p;p--;}
+	break;
+	case 60:
+/* #line 250 "lexer.rl" */
+	{te = p;p--;}
+	break;
+	case 61:
+/* #line 252 "lexer.rl" */
 	{te = p;p--;{
     tok.len = te-ts;
     tok.s = ts;
     tok.numval = 0;
     tok.pos = ts-q->raw;
-    RSQuery_Parse_v2(pParser, TERM, tok, q);
+    RSQuery_Parse_v2(pParser, termTokenType(ts, te, pe), tok, q);
     if (!QPCTX_ISOK(q)) {
       {p++; goto _out; }
     }
   }}
 	break;
-	case 44:
-/* #line 264 "lexer.rl" */
+	case 62:
+/* #line 262 "lexer.rl" */
+	{te = p;p--;{
+    int is_attr = (*ts == '$') ? 1 : 0;
+    tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
+    tok.len = te - (ts + 1 + is_attr);
+    tok.s = ts + is_attr;
+    tok.numval = 0;
+    tok.pos = ts-q->raw;
+
+    RSQuery_Parse_v2(pParser, PREFIX, tok, q);
+    
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 63:
+/* #line 276 "lexer.rl" */
 	{te = p;p--;{
     int is_attr = (*(ts+1) == '$') ? 1 : 0;
     tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
@@ -777,8 +1230,81 @@ _eof_trans:
     }
   }}
 	break;
-	case 45:
-/* #line 78 "lexer.rl" */
+	case 64:
+/* #line 290 "lexer.rl" */
+	{te = p;p--;{
+    int is_attr = (*(ts+1) == '$') ? 1 : 0;
+    tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
+    tok.len = te - (ts + 2 + is_attr);
+    tok.s = ts + 1 + is_attr;
+    tok.numval = 0;
+    tok.pos = ts-q->raw;
+
+    RSQuery_Parse_v2(pParser, CONTAINS, tok, q);
+    
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 65:
+/* #line 305 "lexer.rl" */
+	{te = p;p--;{
+    int is_attr = (*(ts+2) == '$') ? 1 : 0;
+    tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
+    tok.pos = ts-q->raw;
+    tok.len = te - (ts + 2 + is_attr);
+    tok.s = ts + 1 + is_attr;
+    tok.numval = 0;
+    RSQuery_Parse_v2(pParser, VERBATIM, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 66:
+/* #line 318 "lexer.rl" */
+	{te = p;p--;{
+    int is_attr = (*(ts+2) == '$') ? 1 : 0;
+    tok.type = is_attr ? QT_PARAM_WILDCARD : QT_WILDCARD;
+    tok.pos = ts-q->raw + 2;
+    tok.len = te - (ts + 3 + is_attr);
+    tok.s = ts + 2 + is_attr;
+    tok.numval = 0;
+    RSQuery_Parse_v2(pParser, WILDCARD, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 67:
+/* #line 331 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw + 2;
+    tok.len = te - ts - 2;
+    tok.s = ts + 1;
+    tok.numval = 0;
+    RSQuery_Parse_v2(pParser, NAMED_PREDICATE, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 68:
+/* #line 342 "lexer.rl" */
+	{te = p;p--;{
+    tok.pos = ts-q->raw + 1;
+    tok.len = te - ts - 2;
+    tok.s = ts + 1;
+    tok.numval = 0;
+    RSQuery_Parse_v2(pParser, DATE_RANGE, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }
+  }}
+	break;
+	case 69:
+/* #line 90 "lexer.rl" */
 	{{p = ((te))-1;}{ 
     tok.s = ts;
     tok.len = te-ts;
@@ -791,8 +1317,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 46:
-/* #line 208 "lexer.rl" */
+	case 70:
+/* #line 220 "lexer.rl" */
 	{{p = ((te))-1;}{
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, STAR, tok, q);
@@ -801,8 +1327,8 @@ _eof_trans:
     }
   }}
 	break;
-	case 47:
-/* #line 222 "lexer.rl" */
+	case 71:
+/* #line 234 "lexer.rl" */
 	{{p = ((te))-1;}{ 
     tok.pos = ts-q->raw;
     RSQuery_Parse_v2(pParser, LSQB, tok, q);  
@@ -811,25 +1337,25 @@ _eof_trans:
     }  
   }}
 	break;
-	case 48:
-/* #line 237 "lexer.rl" */
+	case 72:
+/* #line 249 "lexer.rl" */
 	{{p = ((te))-1;}}
 	break;
-	case 49:
-/* #line 240 "lexer.rl" */
+	case 73:
+/* #line 252 "lexer.rl" */
 	{{p = ((te))-1;}{
     tok.len = te-ts;
     tok.s = ts;
     tok.numval = 0;
     tok.pos = ts-q->raw;
-    RSQuery_Parse_v2(pParser, TERM, tok, q);
+    RSQuery_Parse_v2(pParser, termTokenType(ts, te, pe), tok, q);
     if (!QPCTX_ISOK(q)) {
       {p++; goto _out; }
     }
   }}
 	break;
-	case 50:
-/* #line 264 "lexer.rl" */
+	case 74:
+/* #line 276 "lexer.rl" */
 	{{p = ((te))-1;}{
     int is_attr = (*(ts+1) == '$') ? 1 : 0;
     tok.type = is_attr ? QT_PARAM_TERM : QT_TERM;
@@ -845,7 +1371,7 @@ _eof_trans:
     }
   }}
 	break;
-	case 51:
+	case 75:
 /* #line 1 "NONE" */
 	{	switch( act ) {
 	case 1:
@@ -935,6 +1461,15 @@ _eof_trans:
     if (!QPCTX_ISOK(q)) {
       {p++; goto _out; }
     }
+  }
+	break;
+	case 20:
+	{{p = ((te))-1;} 
+    tok.pos = ts-q->raw;
+    RSQuery_Parse_v2(pParser, LSQB, tok, q);  
+    if (!QPCTX_ISOK(q)) {
+      {p++; goto _out; }
+    }  
   }
 	break;
 	case 25:
@@ -943,7 +1478,7 @@ _eof_trans:
     tok.s = ts;
     tok.numval = 0;
     tok.pos = ts-q->raw;
-    RSQuery_Parse_v2(pParser, TERM, tok, q);
+    RSQuery_Parse_v2(pParser, termTokenType(ts, te, pe), tok, q);
     if (!QPCTX_ISOK(q)) {
       {p++; goto _out; }
     }
@@ -971,7 +1506,7 @@ _eof_trans:
 	}
 	}
 	break;
-/* #line 975 "lexer.c" */
+/* #line 1510 "lexer.c" */
 		}
 	}
 
@@ -984,7 +1519,7 @@ _again:
 /* #line 1 "NONE" */
 	{ts = 0;}
 	break;
-/* #line 988 "lexer.c" */
+/* #line 1523 "lexer.c" */
 		}
 	}
 
@@ -1002,7 +1537,7 @@ _again:
 	_out: {}
 	}
 
-/* #line 352 "lexer.rl" */
+/* #line 375 "lexer.rl" */
   
   if (QPCTX_ISOK(q)) {
     RSQuery_Parse_v2(pParser, 0, tok, q);
@@ -7,6 +7,7 @@
 #include <stdlib.h>
 #include <stdio.h>
 #include <string.h>
+#include <strings.h>
 #include <assert.h>
 #include <math.h>
 
@@ -25,6 +26,15 @@ void RSQuery_Parse_v2(void *yyp, int yymajor, QueryToken yyminor, QueryParseCtx
 void *RSQuery_ParseAlloc_v2(void *(*mallocProc)(size_t));
 void RSQuery_ParseFree_v2(void *p, void (*freeProc)(void *));
 
+/* A term immediately followed by an opening parenthesis may be a function call */
+static int termTokenType(const char *ts, const char *te, const char *pe) {
+  size_t len = te - ts;
+  if (te < pe && *te == '(' && len == strlen("ismissing") && !strncasecmp(ts, "ismissing", len)) {
+    return ISMISSING;
+  }
+  return TERM;
+}
+
 %%{
 
 machine query;
@@ -60,7 +70,9 @@ suffix = (star.term | star.number | star.attr) $1;
 as = 'AS'|'aS'|'As'|'as';
 verbatim = squote . ((any - squote - escape) | escape.any)+ . squote $4;
 wildcard = 'w' . verbatim $4;
-named_predicate = lsqb.space?.(([Ww][Ii][Tt][Hh][Ii][Nn])|([)Cc][Oo][Nn][Tt][Aa][Ii][Nn][Ss])).space+.((any - rsqb)+).rsqb;
+named_predicate = lsqb.space?.(([Ww][Ii][Tt][Hh][Ii][Nn])|([)Cc][Oo][Nn][Tt][Aa][Ii][Nn][Ss])|([Ii][Nn][Tt][Ee][Rr][Ss][Ee][Cc][Tt][Ss])|([Dd][Ii][Ss][Jj][Oo][Ii][Nn][Tt])|([Dd][Ww][Ii][Tt][Hh][Ii][Nn])).space+.((any - rsqb)+).rsqb;
+date_bound = (any - (space | rsqb))+;
+date_range = lsqb.space*.date_bound.space+.[Tt][Oo].space+.date_bound.space*.rsqb;
 
 main := |*
 
@@ -242,7 +254,7 @@ main := |*
     tok.s = ts;
     tok.numval = 0;
     tok.pos = ts-q->raw;
-    RSQuery_Parse_v2(pParser, TERM, tok, q);
+    RSQuery_Parse_v2(pParser, termTokenType(ts, te, pe), tok, q);
     if (!QPCTX_ISOK(q)) {
       fbreak;
     }
@@ -327,6 +339,17 @@ main := |*
     }
   };
 
+  date_range => {
+    tok.pos = ts-q->raw + 1;
+    tok.len = te - ts - 2;
+    tok.s = ts + 1;
+    tok.numval = 0;
+    RSQuery_Parse_v2(pParser, DATE_RANGE, tok, q);
+    if (!QPCTX_ISOK(q)) {
+      fbreak;
+    }
+  };
+
   
 *|;
 }%%
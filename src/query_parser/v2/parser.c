@@ -31,6 +31,7 @@
 #include <assert.h>
 
 #include "../parse.h"
+#include "../../date_field.h"
 
 // unescape a string (non null terminated) and return the new length (may be shorter than the original. This manipulates the string itself
 static size_t unescapen(char *s, size_t sz) {
@@ -92,6 +93,86 @@ static void reportSyntaxError(QueryError *status, QueryToken* tok, const char *m
   }
 }
 
+// Creates a node matching the empty values of the field `fieldTok`, which must be of type `type`
+// and be defined with INDEXEMPTY
+static QueryNode *newIsEmptyNode(QueryParseCtx *ctx, QueryToken *fieldTok, FieldType type,
+                                 const char *typeName) {
+  if (ctx->sctx->spec) {
+    const FieldSpec *fs = IndexSpec_GetField(ctx->sctx->spec, fieldTok->s, fieldTok->len);
+    if (!fs || !FIELD_IS(fs, type) || !FieldSpec_IndexesEmpty(fs)) {
+      QueryError_SetErrorFmt(ctx->status, QUERY_EEMPTY,
+        "Querying empty values requires field '%.*s' to be a %s field defined with 'INDEXEMPTY'",
+        (int)fieldTok->len, fieldTok->s, typeName);
+      return NULL;
+    }
+  }
+  return NewIsEmptyNode(rm_strndup(fieldTok->s, fieldTok->len), fieldTok->len);
+}
+
+// Parses the bound of a date range which starts at `*p`, and advances `*p` past it.
+// Parameters are resolved later, like the bounds of numeric ranges
+static int parseDateBound(QueryParseCtx *ctx, const char **p, const char *end, int isMax,
+                          double now, QueryToken *tok) {
+  const char *s = *p;
+  while (s < end && isspace((unsigned char)*s)) ++s;
+  const char *e = s;
+  while (e < end && !isspace((unsigned char)*e)) ++e;
+  *p = e;
+
+  tok->s = s;
+  tok->len = e - s;
+  tok->pos = s - ctx->raw;
+  tok->inclusive = 1;
+  if (*s == '(') {
+    tok->inclusive = 0;
+    ++s;
+  }
+  if (e - s > 1 && *s == '$') {
+    tok->type = isMax ? QT_PARAM_NUMERIC_MAX_RANGE : QT_PARAM_NUMERIC_MIN_RANGE;
+    tok->s = s + 1;
+    tok->len = e - s - 1;
+    return 1;
+  }
+  tok->type = QT_NUMERIC;
+  return DateField_ParseRangeBound(s, e - s, isMax, now, &tok->numval);
+}
+
+// Creates a numeric node for the range `[<from> TO <to>]` of the DATE field `fieldTok`.
+// `rangeTok` holds the text between the brackets
+static QueryNode *newDateRangeNode(QueryParseCtx *ctx, QueryToken *fieldTok, QueryToken *rangeTok) {
+  if (ctx->sctx->spec) {
+    const FieldSpec *fs = IndexSpec_GetField(ctx->sctx->spec, fieldTok->s, fieldTok->len);
+    if (!fs || !FIELD_IS(fs, INDEXFLD_T_DATE)) {
+      QueryError_SetErrorFmt(ctx->status, QUERY_ESYNTAX,
+        "Date ranges require field '%.*s' to be a %s field",
+        (int)fieldTok->len, fieldTok->s, SPEC_DATE_STR);
+      return NULL;
+    }
+  }
+
+  // The lexer only matches two bounds separated by `TO`
+  const char *p = rangeTok->s, *end = rangeTok->s + rangeTok->len;
+  double now = DateField_Now();
+  QueryToken bounds[2] = {{0}};
+  for (int i = 0; i < 2; ++i) {
+    if (i) {
+      while (isspace((unsigned char)*p)) ++p;
+      p += 2;
+    }
+    if (!parseDateBound(ctx, &p, end, i, now, &bounds[i])) {
+      QueryError_SetErrorFmt(ctx->status, QUERY_ESYNTAX, "Invalid date `%.*s` for field `%.*s`",
+        (int)bounds[i].len, bounds[i].s, (int)fieldTok->len, fieldTok->s);
+      return NULL;
+    }
+  }
+
+  QueryParam *qp = NewNumericFilterQueryParam_WithParams(ctx, &bounds[0], &bounds[1],
+                                                         bounds[0].inclusive, bounds[1].inclusive);
+  // we keep the capitalization as is
+  qp->nf->fieldName = rm_strndup(fieldTok->s, fieldTok->len);
+  return NewNumericNode(qp);
+}
+
 /**************** End of %include directives **********************************/
 /* These constants specify the various numeric values for terminal symbols.
 ***************** Begin token definitions *************************************/
@@ -105,30 +186,32 @@ static void reportSyntaxError(QueryError *status, QueryToken* tok, const char *m
 #define RB                              7
 #define RSQB                            8
 #define TERM                            9
-#define QUOTE                          10
-#define LP                             11
-#define LB                             12
-#define LSQB                           13
-#define TILDE                          14
-#define MINUS                          15
-#define AND                            16
-#define ARROW                          17
-#define COLON                          18
-#define NUMBER                         19
-#define SIZE                           20
-#define STAR                           21
-#define TAGLIST                        22
-#define TERMLIST                       23
-#define PREFIX                         24
-#define SUFFIX                         25
-#define CONTAINS                       26
-#define PERCENT                        27
-#define ATTRIBUTE                      28
-#define VERBATIM                       29
-#define WILDCARD                       30
-#define NAMED_PREDICATE                31
-#define AS_T                           32
-#define SEMICOLON                      33
+#define ISMISSING                      10
+#define QUOTE                          11
+#define LP                             12
+#define LB                             13
+#define LSQB                           14
+#define TILDE                          15
+#define MINUS                          16
+#define AND                            17
+#define ARROW                          18
+#define COLON                          19
+#define NUMBER                         20
+#define SIZE                           21
+#define STAR                           22
+#define TAGLIST                        23
+#define TERMLIST                       24
+#define PREFIX                         25
+#define SUFFIX                         26
+#define CONTAINS                       27
+#define PERCENT                        28
+#define ATTRIBUTE                      29
+#define VERBATIM                       30
+#define WILDCARD                       31
+#define NAMED_PREDICATE                32
+#define DATE_RANGE                     33
+#define AS_T                           34
+#define SEMICOLON                      35
 #endif
 /**************** End token definitions ***************************************/
 
@@ -188,20 +271,20 @@ static void reportSyntaxError(QueryError *status, QueryToken* tok, const char *m
 #endif
 /************* Begin control #defines *****************************************/
 #define YYCODETYPE unsigned char
-#define YYNOCODE 66
+#define YYNOCODE 68
 #define YYACTIONTYPE unsigned short int
 #define RSQueryParser_v2_TOKENTYPE QueryToken
 typedef union {
   int yyinit;
   RSQueryParser_v2_TOKENTYPE yy0;
-  VectorQueryParams yy2;
-  Vector* yy42;
-  QueryAttribute yy43;
-  QueryAttribute * yy45;
-  RangeNumber yy47;
-  QueryParam * yy62;
-  QueryNode * yy119;
-  SingleVectorQueryParam yy125;
+  QueryNode * yy19;
+  QueryAttribute yy23;
+  VectorQueryParams yy26;
+  Vector* yy27;
+  RangeNumber yy39;
+  SingleVectorQueryParam yy45;
+  QueryAttribute * yy105;
+  QueryParam * yy134;
 } YYMINORTYPE;
 #ifndef YYSTACKDEPTH
 #define YYSTACKDEPTH 256
@@ -217,18 +300,18 @@ typedef union {
 #define RSQueryParser_v2_CTX_FETCH
 #define RSQueryParser_v2_CTX_STORE
 #define YYFALLBACK 1
-#define YYNSTATE             121
-#define YYNRULE              98
-#define YYNRULE_WITH_ACTION  95
-#define YYNTOKEN             34
-#define YY_MAX_SHIFT         120
-#define YY_MIN_SHIFTREDUCE   183
-#define YY_MAX_SHIFTREDUCE   280
-#define YY_ERROR_ACTION      281
-#define YY_ACCEPT_ACTION     282
-#define YY_NO_ACTION         283
-#define YY_MIN_REDUCE        284
-#define YY_MAX_REDUCE        381
+#define YYNSTATE             127
+#define YYNRULE              102
+#define YYNRULE_WITH_ACTION  99
+#define YYNTOKEN             36
+#define YY_MAX_SHIFT         126
+#define YY_MIN_SHIFTREDUCE   193
+#define YY_MAX_SHIFTREDUCE   294
+#define YY_ERROR_ACTION      295
+#define YY_ACCEPT_ACTION     296
+#define YY_NO_ACTION         297
+#define YY_MIN_REDUCE        298
+#define YY_MAX_REDUCE        399
 /************* End control #defines *******************************************/
 #define YY_NLOOKAHEAD ((int)(sizeof(yy_lookahead)/sizeof(yy_lookahead[0])))
 
@@ -295,212 +378,224 @@ typedef union {
 **  yy_default[]       Default action for each state.
 **
 *********** Begin parsing tables **********************************************/
-#define YY_ACTTAB_COUNT (759)
+#define YY_ACTTAB_COUNT (814)
 static const YYACTIONTYPE yy_action[] = {
- /*     0 */   377,  362,   46,   52,   12,  229,  201,   55,  367,  268,
- /*    10 */    45,   15,  352,   54,   13,   14,  368,  105,   42,  269,
- /*    20 */   270,  232,   61,  116,  222,  223,  224,   51,  272,  268,
- /*    30 */   225,   16,  229,  202,  113,  372,  268,   45,   15,  269,
- /*    40 */   270,   13,   14,  377,  118,   74,  269,  270,  115,   53,
- /*    50 */   106,  222,  223,  224,   51,  272,  284,  225,  107,   30,
- /*    60 */    12,  229,  377,  114,  372,  268,   45,   15,  229,   49,
- /*    70 */    13,   14,  280,   87,  339,  269,  270,  340,   56,  273,
- /*    80 */   222,  223,  224,   51,  272,  285,  225,  274,  318,  372,
- /*    90 */   229,  361,  360,  372,  268,   45,    1,   57,  372,   13,
- /*   100 */    14,   76,  351,   54,  269,  270,  278,  117,  268,  222,
- /*   110 */   223,  224,   51,  272,  268,  225,   16,  229,  269,  270,
- /*   120 */    75,  268,   45,   15,  269,  270,   13,   14,  377,   81,
- /*   130 */    77,  269,  270,  272,   26,   47,  222,  223,  224,   51,
- /*   140 */   272,  332,  225,   12,  229,  349,  355,  372,  268,   45,
- /*   150 */    15,  108,  372,   13,   14,  350,  105,  331,  269,  270,
- /*   160 */   317,  372,   94,  222,  223,  224,   51,  272,  101,  225,
- /*   170 */    16,  229,   98,  111,  372,  268,   45,   15,  304,  372,
- /*   180 */    13,   14,  286,  118,  306,  269,  270,  305,   79,  357,
- /*   190 */   222,  223,  224,   51,  272,  348,  225,   95,   43,  100,
- /*   200 */   268,   45,   33,   40,   44,   31,   32,  305,   82,   93,
- /*   210 */   269,  270,  103,   99,   43,  222,  223,  224,   51,  272,
- /*   220 */    63,  225,  246,   34,  279,  202,  104,   43,  268,   45,
- /*   230 */    33,   80,  358,   31,   32,   78,  118,   65,  269,  270,
- /*   240 */   305,   85,   97,  222,  223,  224,   51,  272,  102,  225,
- /*   250 */   229,  305,   88,  208,  268,   45,    1,  305,   92,   13,
- /*   260 */    14,  209,   70,   64,  269,  270,  278,   83,   78,  222,
- /*   270 */   223,  224,   51,  272,   78,  225,  229,   69,   68,   78,
- /*   280 */   268,   45,   15,   66,  258,   13,   14,   78,  105,  356,
- /*   290 */   269,  270,   67,   86,   69,  222,  223,  224,   51,  272,
- /*   300 */   263,  225,  229,  262,  244,  228,  268,   45,   15,  268,
- /*   310 */   210,   13,   14,  109,  118,  110,  269,  270,  227,  269,
- /*   320 */   270,  222,  223,  224,   51,  272,   34,  225,  272,  112,
- /*   330 */   226,  268,   45,   33,  212,  211,   31,   32,   62,  118,
- /*   340 */    70,  269,  270,   35,   17,  283,  222,  223,  224,   51,
- /*   350 */   272,  283,  225,  229,  283,  283,  283,  268,   45,   15,
- /*   360 */   283,   73,   13,   14,  283,   72,  283,  269,  270,  265,
- /*   370 */   264,  283,  222,  223,  224,   51,  272,  283,  225,  283,
- /*   380 */   283,  283,  268,   45,   33,  283,  283,   31,   32,  283,
- /*   390 */   118,  283,  269,  270,  283,  283,  283,  222,  223,  224,
- /*   400 */    51,  272,   71,  225,  283,  283,   72,  268,   45,   33,
- /*   410 */   265,  264,   31,   32,  283,  283,  283,  269,  270,  275,
- /*   420 */   283,  268,  222,  223,  224,   51,  272,    4,  225,  283,
- /*   430 */   315,  269,  270,  316,  283,  120,  119,    5,  315,   60,
- /*   440 */   272,  316,  283,  283,  119,   38,  283,   89,  283,  283,
- /*   450 */   282,   84,   91,  314,  372,  283,  283,    2,  283,  283,
- /*   460 */   315,  314,  372,  316,  283,  120,  119,    3,  315,  283,
- /*   470 */    22,  316,  283,  315,  119,   39,  316,   89,  120,  119,
- /*   480 */    25,   96,   91,  314,  372,   73,  283,  283,  283,   72,
- /*   490 */    89,  314,  372,  265,  264,   91,  314,  372,   23,  283,
- /*   500 */   283,  315,  277,  283,  316,  283,  120,  119,   24,  315,
- /*   510 */   283,    9,  316,  283,  315,  119,   36,  316,   89,  120,
- /*   520 */   119,   10,  283,   91,  314,  372,  283,  283,  283,  283,
- /*   530 */   283,   89,  314,  372,  283,  283,   91,  314,  372,   19,
- /*   540 */   283,  283,  315,  283,  283,  316,  283,  120,  119,   20,
- /*   550 */   315,  283,   18,  316,  283,  315,  119,   37,  316,   89,
- /*   560 */   120,  119,   21,  283,   91,  314,  372,  283,  283,  283,
- /*   570 */   283,  283,   89,  314,  372,  283,  283,   91,  314,  372,
- /*   580 */     2,  283,  283,  315,  283,  283,  316,  283,  120,  119,
- /*   590 */     3,  315,  283,    8,  316,  283,  315,  119,   27,  316,
- /*   600 */    89,  120,  119,   11,  283,   91,  314,  372,  283,  283,
- /*   610 */   283,  283,  283,   89,  314,  372,  283,  283,   91,  314,
- /*   620 */   372,    6,  283,  283,  315,  283,  283,  316,  283,  120,
- /*   630 */   119,    7,  283,  283,  335,  283,  283,  336,   58,  283,
- /*   640 */   283,   89,  105,   90,  269,  270,   91,  314,  372,  222,
- /*   650 */   223,  224,   51,  272,   48,  225,   71,   59,  372,  118,
- /*   660 */    72,  269,  270,  283,  265,  264,  222,  223,  224,   51,
- /*   670 */   272,  283,  225,  275,  283,  283,  283,  283,  283,  283,
- /*   680 */   315,  283,  283,  316,  283,  283,  119,   41,  283,  315,
- /*   690 */   344,  346,  316,  283,  283,  119,   29,  283,  283,  342,
- /*   700 */   269,  270,  283,  314,  372,  222,  223,  224,   51,  272,
- /*   710 */   268,  225,  314,  372,  283,  283,  283,  283,  283,  283,
- /*   720 */   269,  270,  283,  283,  283,  222,  223,  224,  283,  272,
- /*   730 */   315,  225,  283,  316,  283,  242,  119,   28,   71,  268,
- /*   740 */   283,  283,   72,  283,  283,  283,  265,  264,  283,  269,
- /*   750 */   270,  283,  283,  314,  372,  275,  283,   50,  272,
+ /*     0 */   395,  395,  380,   47,   12,  239,  211,   56,  110,  282,
+ /*    10 */   109,   46,   15,  370,   55,   13,   14,   77,  107,  385,
+ /*    20 */   283,  284,   54,   95,  379,  232,  233,  234,   52,  286,
+ /*    30 */   282,  235,   16,  239,  212,  369,   55,  282,  109,   46,
+ /*    40 */    15,  283,  284,   13,   14,  395,  124,  294,  283,  284,
+ /*    50 */   121,   80,   48,  232,  233,  234,   52,  286,  298,  235,
+ /*    60 */   282,  123,   12,  239,  119,  390,  386,  282,  109,   46,
+ /*    70 */    15,  283,  284,   13,   14,  346,   89,  345,  283,  284,
+ /*    80 */   286,  120,  390,  232,  233,  234,   52,  286,  299,  235,
+ /*    90 */    99,  111,   53,  239,  282,  395,   78,  282,  109,   46,
+ /*   100 */     1,  239,   50,   13,   14,  283,  284,  104,  283,  284,
+ /*   110 */   292,   62,  122,  232,  233,  234,   52,  286,   80,  235,
+ /*   120 */    16,  239,  332,  390,  103,  282,  109,   46,   15,  319,
+ /*   130 */    81,   13,   14,  218,   83,   80,  283,  284,  320,  378,
+ /*   140 */   390,  232,  233,  234,   52,  286,  375,  235,   12,  239,
+ /*   150 */   367,  373,  390,  282,  109,   46,   15,  112,  390,   13,
+ /*   160 */    14,   80,  107,  219,  283,  284,  331,  390,   96,  232,
+ /*   170 */   233,  234,   52,  286,   75,  235,   16,  239,  368,  117,
+ /*   180 */   390,  282,  109,   46,   15,  318,  390,   13,   14,   30,
+ /*   190 */   124,   80,  283,  284,   97,   43,  100,  232,  233,  234,
+ /*   200 */    52,  286,  282,  235,   45,   33,   36,   44,   31,   32,
+ /*   210 */   101,   43,  353,  283,  284,  354,   57,   64,  232,  233,
+ /*   220 */   234,   52,  286,  300,  235,  260,  256,  239,  319,   84,
+ /*   230 */   108,  282,  109,   46,    1,   58,  390,   13,   14,  319,
+ /*   240 */    87,  102,  283,  284,  292,  106,   43,  232,  233,  234,
+ /*   250 */    52,  286,   42,  235,  239,  242,  319,   90,  282,  109,
+ /*   260 */    46,   15,  319,   94,   13,   14,  366,  107,   76,  283,
+ /*   270 */   284,  287,   71,   65,  232,  233,  234,   52,  286,  288,
+ /*   280 */   235,  239,   82,   26,  105,  282,  109,   46,   15,  293,
+ /*   290 */   376,   13,   14,   66,  124,   85,  283,  284,   70,   69,
+ /*   300 */    67,  232,  233,  234,   52,  286,   34,  235,  212,  374,
+ /*   310 */   272,  282,   68,   46,   33,   88,   70,   31,   32,  251,
+ /*   320 */   124,  277,  283,  284,   79,  276,  258,  232,  233,  234,
+ /*   330 */    52,  286,  252,  235,  239,  113,  238,  222,  282,  109,
+ /*   340 */    46,   15,  115,   74,   13,   14,   63,   73,  116,  283,
+ /*   350 */   284,  279,  278,  221,  232,  233,  234,   52,  286,   34,
+ /*   360 */   235,  237,  118,  236,  282,   71,   46,   33,   35,   17,
+ /*   370 */    31,   32,  297,  124,  297,  283,  284,  297,  297,  297,
+ /*   380 */   232,  233,  234,   52,  286,  282,  235,   46,   33,  297,
+ /*   390 */   297,   31,   32,  297,  124,  297,  283,  284,  297,  297,
+ /*   400 */   297,  232,  233,  234,   52,  286,  282,  235,   46,   33,
+ /*   410 */   297,  297,   31,   32,  297,  297,  297,  283,  284,  297,
+ /*   420 */   297,  297,  232,  233,  234,   52,  286,  329,  235,    4,
+ /*   430 */   330,  297,  329,  125,   39,  330,  297,  126,  125,    5,
+ /*   440 */   297,  297,  297,  297,  297,  297,  297,  297,  297,   91,
+ /*   450 */   328,  390,  296,   86,   93,  328,  390,  297,  297,    2,
+ /*   460 */   297,  297,  329,  297,  297,  330,  297,  126,  125,    3,
+ /*   470 */   329,  297,   22,  330,  297,  329,  125,   40,  330,   91,
+ /*   480 */   126,  125,   25,   98,   93,  328,  390,  297,  297,  282,
+ /*   490 */   297,  253,   91,  328,  390,  297,  297,   93,  328,  390,
+ /*   500 */   283,  284,   23,  297,  297,  329,  297,  297,  330,  121,
+ /*   510 */   126,  125,   24,  329,  297,    9,  330,  297,  329,  125,
+ /*   520 */    37,  330,   91,  126,  125,   10,  297,   93,  328,  390,
+ /*   530 */   297,  297,  282,  297,  220,   91,  328,  390,  297,  297,
+ /*   540 */    93,  328,  390,  283,  284,   19,  297,  297,  329,  297,
+ /*   550 */   297,  330,  286,  126,  125,   20,  329,  297,   18,  330,
+ /*   560 */   297,  329,  125,   38,  330,   91,  126,  125,   21,  297,
+ /*   570 */    93,  328,  390,  297,  297,  297,  297,  297,   91,  328,
+ /*   580 */   390,  297,  297,   93,  328,  390,  297,  297,    2,  297,
+ /*   590 */   297,  329,  297,  297,  330,  297,  126,  125,    3,  329,
+ /*   600 */   297,    8,  330,  297,  329,  125,   27,  330,   91,  126,
+ /*   610 */   125,   11,  297,   93,  328,  390,  297,  297,  297,  297,
+ /*   620 */   297,   91,  328,  390,  282,  297,   93,  328,  390,  297,
+ /*   630 */   297,    6,  297,  297,  329,  283,  284,  330,  297,  126,
+ /*   640 */   125,    7,  297,   61,  286,  297,  297,  297,  297,  297,
+ /*   650 */   297,   91,  297,  297,  297,  297,   93,  328,  390,  107,
+ /*   660 */   297,  283,  284,  297,  297,  297,  232,  233,  234,   52,
+ /*   670 */   286,  297,  235,  297,  297,  124,  297,  283,  284,  297,
+ /*   680 */   297,  297,  232,  233,  234,   52,  286,  297,  235,  297,
+ /*   690 */   297,  329,   49,  297,  330,   72,  297,  125,   41,   73,
+ /*   700 */   297,  362,  364,  279,  278,  297,  297,  282,  297,  114,
+ /*   710 */   359,  297,  289,  297,  328,  390,  297,  297,  283,  284,
+ /*   720 */   297,  297,  297,  232,  233,  234,  297,  286,  297,  235,
+ /*   730 */   283,  284,  297,  282,  297,  232,  233,  234,   52,  286,
+ /*   740 */   297,  235,  297,  297,  283,  284,  297,  297,  297,  232,
+ /*   750 */   233,  234,  297,  286,  329,  235,  297,  330,  297,  329,
+ /*   760 */   125,   29,  330,  297,  349,  125,   28,  350,   59,  297,
+ /*   770 */   297,  297,   72,   92,  297,  297,   73,  328,  390,  297,
+ /*   780 */   279,  278,  328,  390,  297,  297,  255,   60,  390,  289,
+ /*   790 */    72,  282,  297,  297,   73,  297,   74,  297,  279,  278,
+ /*   800 */    73,  297,  283,  284,  279,  278,  297,  289,  297,  297,
+ /*   810 */    51,  286,  297,  291,
 };
 static const YYCODETYPE yy_lookahead[] = {
- /*     0 */    55,   52,   53,   41,    4,    5,    6,   62,   55,    9,
- /*    10 */    10,   11,   63,   64,   14,   15,   55,   17,    4,   19,
- /*    20 */    20,    7,   60,   61,   24,   25,   26,   27,   28,    9,
- /*    30 */    30,    4,    5,    6,   60,   61,    9,   10,   11,   19,
- /*    40 */    20,   14,   15,   55,   17,    4,   19,   20,   28,    9,
- /*    50 */    62,   24,   25,   26,   27,   28,    0,   30,   51,   18,
- /*    60 */     4,    5,   55,   60,   61,    9,   10,   11,    5,   62,
- /*    70 */    14,   15,   32,   17,   37,   19,   20,   40,   41,   20,
- /*    80 */    24,   25,   26,   27,   28,    0,   30,   28,   60,   61,
- /*    90 */     5,   52,   60,   61,    9,   10,   11,   60,   61,   14,
- /*   100 */    15,    9,   63,   64,   19,   20,   21,   28,    9,   24,
- /*   110 */    25,   26,   27,   28,    9,   30,    4,    5,   19,   20,
- /*   120 */     4,    9,   10,   11,   19,   20,   14,   15,   55,   17,
- /*   130 */    65,   19,   20,   28,   18,   62,   24,   25,   26,   27,
- /*   140 */    28,   61,   30,    4,    5,    0,   60,   61,    9,   10,
- /*   150 */    11,   60,   61,   14,   15,    0,   17,   61,   19,   20,
- /*   160 */    60,   61,   17,   24,   25,   26,   27,   28,   59,   30,
- /*   170 */     4,    5,   17,   60,   61,    9,   10,   11,   60,   61,
- /*   180 */    14,   15,    0,   17,   35,   19,   20,   35,   36,    0,
- /*   190 */    24,   25,   26,   27,   28,    0,   30,   49,   50,   17,
- /*   200 */     9,   10,   11,   12,   13,   14,   15,   35,   36,    7,
- /*   210 */    19,   20,   17,   49,   50,   24,   25,   26,   27,   28,
- /*   220 */    12,   30,   31,    4,    6,    6,   49,   50,    9,   10,
- /*   230 */    11,    8,    0,   14,   15,   33,   17,   12,   19,   20,
- /*   240 */    35,   36,    7,   24,   25,   26,   27,   28,    7,   30,
- /*   250 */     5,   35,   36,    7,    9,   10,   11,   35,   36,   14,
- /*   260 */    15,    7,   12,   13,   19,   20,   21,    8,   33,   24,
- /*   270 */    25,   26,   27,   28,   33,   30,    5,   12,   13,   33,
- /*   280 */     9,   10,   11,   13,   28,   14,   15,   33,   17,    0,
- /*   290 */    19,   20,   12,    8,   12,   24,   25,   26,   27,   28,
- /*   300 */    28,   30,    5,    8,    8,   27,    9,   10,   11,    9,
- /*   310 */    10,   14,   15,   27,   17,   27,   19,   20,   27,   19,
- /*   320 */    20,   24,   25,   26,   27,   28,    4,   30,   28,   27,
- /*   330 */    27,    9,   10,   11,   10,   10,   14,   15,   18,   17,
- /*   340 */    12,   19,   20,    4,    4,   66,   24,   25,   26,   27,
- /*   350 */    28,   66,   30,    5,   66,   66,   66,    9,   10,   11,
- /*   360 */    66,   11,   14,   15,   66,   15,   66,   19,   20,   19,
- /*   370 */    20,   66,   24,   25,   26,   27,   28,   66,   30,   66,
- /*   380 */    66,   66,    9,   10,   11,   66,   66,   14,   15,   66,
- /*   390 */    17,   66,   19,   20,   66,   66,   66,   24,   25,   26,
- /*   400 */    27,   28,   11,   30,   66,   66,   15,    9,   10,   11,
- /*   410 */    19,   20,   14,   15,   66,   66,   66,   19,   20,   28,
- /*   420 */    66,    9,   24,   25,   26,   27,   28,   34,   30,   66,
- /*   430 */    37,   19,   20,   40,   66,   42,   43,   44,   37,   27,
- /*   440 */    28,   40,   66,   66,   43,   44,   66,   54,   66,   66,
- /*   450 */    57,   58,   59,   60,   61,   66,   66,   34,   66,   66,
- /*   460 */    37,   60,   61,   40,   66,   42,   43,   44,   37,   66,
- /*   470 */    34,   40,   66,   37,   43,   44,   40,   54,   42,   43,
- /*   480 */    44,   58,   59,   60,   61,   11,   66,   66,   66,   15,
- /*   490 */    54,   60,   61,   19,   20,   59,   60,   61,   34,   66,
- /*   500 */    66,   37,   28,   66,   40,   66,   42,   43,   44,   37,
- /*   510 */    66,   34,   40,   66,   37,   43,   44,   40,   54,   42,
- /*   520 */    43,   44,   66,   59,   60,   61,   66,   66,   66,   66,
- /*   530 */    66,   54,   60,   61,   66,   66,   59,   60,   61,   34,
- /*   540 */    66,   66,   37,   66,   66,   40,   66,   42,   43,   44,
- /*   550 */    37,   66,   34,   40,   66,   37,   43,   44,   40,   54,
- /*   560 */    42,   43,   44,   66,   59,   60,   61,   66,   66,   66,
- /*   570 */    66,   66,   54,   60,   61,   66,   66,   59,   60,   61,
- /*   580 */    34,   66,   66,   37,   66,   66,   40,   66,   42,   43,
- /*   590 */    44,   37,   66,   34,   40,   66,   37,   43,   44,   40,
- /*   600 */    54,   42,   43,   44,   66,   59,   60,   61,   66,   66,
- /*   610 */    66,   66,   66,   54,   60,   61,   66,   66,   59,   60,
- /*   620 */    61,   34,   66,   66,   37,   66,   66,   40,   66,   42,
- /*   630 */    43,   44,   66,   66,   37,   66,   66,   40,   41,   66,
- /*   640 */    66,   54,   17,   46,   19,   20,   59,   60,   61,   24,
- /*   650 */    25,   26,   27,   28,    9,   30,   11,   60,   61,   17,
- /*   660 */    15,   19,   20,   66,   19,   20,   24,   25,   26,   27,
- /*   670 */    28,   66,   30,   28,   66,   66,   66,   66,   66,   66,
- /*   680 */    37,   66,   66,   40,   66,   66,   43,   44,   66,   37,
- /*   690 */    47,   48,   40,   66,   66,   43,   44,   66,   66,   56,
- /*   700 */    19,   20,   66,   60,   61,   24,   25,   26,   27,   28,
- /*   710 */     9,   30,   60,   61,   66,   66,   66,   66,   66,   66,
- /*   720 */    19,   20,   66,   66,   66,   24,   25,   26,   66,   28,
- /*   730 */    37,   30,   66,   40,   66,    8,   43,   44,   11,    9,
- /*   740 */    66,   66,   15,   66,   66,   66,   19,   20,   66,   19,
- /*   750 */    20,   66,   66,   60,   61,   28,   66,   27,   28,   66,
- /*   760 */    66,   66,   66,   66,   66,   66,   66,   66,   66,   66,
- /*   770 */    66,   66,   66,   66,   34,   34,   34,   34,   34,   34,
- /*   780 */    34,   34,   34,   34,   34,   34,   34,   34,   34,   34,
- /*   790 */    34,   34,   34,
+ /*     0 */    57,   57,   54,   55,    4,    5,    6,   64,   64,    9,
+ /*    10 */    10,   11,   12,   65,   66,   15,   16,    9,   18,   57,
+ /*    20 */    20,   21,    9,    7,   54,   25,   26,   27,   28,   29,
+ /*    30 */     9,   31,    4,    5,    6,   65,   66,    9,   10,   11,
+ /*    40 */    12,   20,   21,   15,   16,   57,   18,   34,   20,   21,
+ /*    50 */    29,   35,   64,   25,   26,   27,   28,   29,    0,   31,
+ /*    60 */     9,   29,    4,    5,   62,   63,   57,    9,   10,   11,
+ /*    70 */    12,   20,   21,   15,   16,   63,   18,   63,   20,   21,
+ /*    80 */    29,   62,   63,   25,   26,   27,   28,   29,    0,   31,
+ /*    90 */     7,   53,   43,    5,    9,   57,   67,    9,   10,   11,
+ /*   100 */    12,    5,   64,   15,   16,   20,   21,    7,   20,   21,
+ /*   110 */    22,   62,   63,   25,   26,   27,   28,   29,   35,   31,
+ /*   120 */     4,    5,   62,   63,   61,    9,   10,   11,   12,   37,
+ /*   130 */    38,   15,   16,    7,   18,   35,   20,   21,   37,   62,
+ /*   140 */    63,   25,   26,   27,   28,   29,    0,   31,    4,    5,
+ /*   150 */     0,   62,   63,    9,   10,   11,   12,   62,   63,   15,
+ /*   160 */    16,   35,   18,    7,   20,   21,   62,   63,   18,   25,
+ /*   170 */    26,   27,   28,   29,    4,   31,    4,    5,    0,   62,
+ /*   180 */    63,    9,   10,   11,   12,   62,   63,   15,   16,   19,
+ /*   190 */    18,   35,   20,   21,   51,   52,   18,   25,   26,   27,
+ /*   200 */    28,   29,    9,   31,   11,   12,   13,   14,   15,   16,
+ /*   210 */    51,   52,   39,   20,   21,   42,   43,   13,   25,   26,
+ /*   220 */    27,   28,   29,    0,   31,   32,   33,    5,   37,   38,
+ /*   230 */    61,    9,   10,   11,   12,   62,   63,   15,   16,   37,
+ /*   240 */    38,   18,   20,   21,   22,   51,   52,   25,   26,   27,
+ /*   250 */    28,   29,    4,   31,    5,    7,   37,   38,    9,   10,
+ /*   260 */    11,   12,   37,   38,   15,   16,    0,   18,    4,   20,
+ /*   270 */    21,   21,   13,   14,   25,   26,   27,   28,   29,   29,
+ /*   280 */    31,    5,    8,   19,   18,    9,   10,   11,   12,    6,
+ /*   290 */     0,   15,   16,   13,   18,    8,   20,   21,   13,   14,
+ /*   300 */    14,   25,   26,   27,   28,   29,    4,   31,    6,    0,
+ /*   310 */    29,    9,   13,   11,   12,    8,   13,   15,   16,    6,
+ /*   320 */    18,   29,   20,   21,   12,    8,    8,   25,   26,   27,
+ /*   330 */    28,   29,    7,   31,    5,   11,   28,   11,    9,   10,
+ /*   340 */    11,   12,   28,   12,   15,   16,   19,   16,   28,   20,
+ /*   350 */    21,   20,   21,   11,   25,   26,   27,   28,   29,    4,
+ /*   360 */    31,   28,   28,   28,    9,   13,   11,   12,    4,    4,
+ /*   370 */    15,   16,   68,   18,   68,   20,   21,   68,   68,   68,
+ /*   380 */    25,   26,   27,   28,   29,    9,   31,   11,   12,   68,
+ /*   390 */    68,   15,   16,   68,   18,   68,   20,   21,   68,   68,
+ /*   400 */    68,   25,   26,   27,   28,   29,    9,   31,   11,   12,
+ /*   410 */    68,   68,   15,   16,   68,   68,   68,   20,   21,   68,
+ /*   420 */    68,   68,   25,   26,   27,   28,   29,   39,   31,   36,
+ /*   430 */    42,   68,   39,   45,   46,   42,   68,   44,   45,   46,
+ /*   440 */    68,   68,   68,   68,   68,   68,   68,   68,   68,   56,
+ /*   450 */    62,   63,   59,   60,   61,   62,   63,   68,   68,   36,
+ /*   460 */    68,   68,   39,   68,   68,   42,   68,   44,   45,   46,
+ /*   470 */    39,   68,   36,   42,   68,   39,   45,   46,   42,   56,
+ /*   480 */    44,   45,   46,   60,   61,   62,   63,   68,   68,    9,
+ /*   490 */    68,   11,   56,   62,   63,   68,   68,   61,   62,   63,
+ /*   500 */    20,   21,   36,   68,   68,   39,   68,   68,   42,   29,
+ /*   510 */    44,   45,   46,   39,   68,   36,   42,   68,   39,   45,
+ /*   520 */    46,   42,   56,   44,   45,   46,   68,   61,   62,   63,
+ /*   530 */    68,   68,    9,   68,   11,   56,   62,   63,   68,   68,
+ /*   540 */    61,   62,   63,   20,   21,   36,   68,   68,   39,   68,
+ /*   550 */    68,   42,   29,   44,   45,   46,   39,   68,   36,   42,
+ /*   560 */    68,   39,   45,   46,   42,   56,   44,   45,   46,   68,
+ /*   570 */    61,   62,   63,   68,   68,   68,   68,   68,   56,   62,
+ /*   580 */    63,   68,   68,   61,   62,   63,   68,   68,   36,   68,
+ /*   590 */    68,   39,   68,   68,   42,   68,   44,   45,   46,   39,
+ /*   600 */    68,   36,   42,   68,   39,   45,   46,   42,   56,   44,
+ /*   610 */    45,   46,   68,   61,   62,   63,   68,   68,   68,   68,
+ /*   620 */    68,   56,   62,   63,    9,   68,   61,   62,   63,   68,
+ /*   630 */    68,   36,   68,   68,   39,   20,   21,   42,   68,   44,
+ /*   640 */    45,   46,   68,   28,   29,   68,   68,   68,   68,   68,
+ /*   650 */    68,   56,   68,   68,   68,   68,   61,   62,   63,   18,
+ /*   660 */    68,   20,   21,   68,   68,   68,   25,   26,   27,   28,
+ /*   670 */    29,   68,   31,   68,   68,   18,   68,   20,   21,   68,
+ /*   680 */    68,   68,   25,   26,   27,   28,   29,   68,   31,   68,
+ /*   690 */    68,   39,    9,   68,   42,   12,   68,   45,   46,   16,
+ /*   700 */    68,   49,   50,   20,   21,   68,   68,    9,   68,   11,
+ /*   710 */    58,   68,   29,   68,   62,   63,   68,   68,   20,   21,
+ /*   720 */    68,   68,   68,   25,   26,   27,   68,   29,   68,   31,
+ /*   730 */    20,   21,   68,    9,   68,   25,   26,   27,   28,   29,
+ /*   740 */    68,   31,   68,   68,   20,   21,   68,   68,   68,   25,
+ /*   750 */    26,   27,   68,   29,   39,   31,   68,   42,   68,   39,
+ /*   760 */    45,   46,   42,   68,   39,   45,   46,   42,   43,   68,
+ /*   770 */    68,   68,   12,   48,   68,   68,   16,   62,   63,   68,
+ /*   780 */    20,   21,   62,   63,   68,   68,    8,   62,   63,   29,
+ /*   790 */    12,    9,   68,   68,   16,   68,   12,   68,   20,   21,
+ /*   800 */    16,   68,   20,   21,   20,   21,   68,   29,   68,   68,
+ /*   810 */    28,   29,   68,   29,   68,   68,   68,   68,   68,   68,
+ /*   820 */    68,   68,   68,   68,   68,   68,   68,   68,   68,   68,
+ /*   830 */    68,   68,   68,   36,   36,   36,   36,   36,   36,   36,
+ /*   840 */    36,   36,   36,   36,   36,   36,   36,   36,   36,   36,
 };
-#define YY_SHIFT_COUNT    (120)
+#define YY_SHIFT_COUNT    (126)
 #define YY_SHIFT_MIN      (0)
-#define YY_SHIFT_MAX      (730)
+#define YY_SHIFT_MAX      (784)
 static const unsigned short int yy_shift_ofst[] = {
- /*     0 */    85,  245,    0,   27,   56,  112,  139,  166,  271,  271,
- /*    10 */   297,  297,  348,  348,  348,  348,  348,  348,  625,  625,
- /*    20 */   642,  642,  625,  625,  642,  642,  191,  219,  322,  373,
- /*    30 */   398,  398,  398,  398,  398,  398,  642,  642,  642,  681,
- /*    40 */   701,  681,  701,   40,  645,   20,   40,  727,  391,  391,
- /*    50 */   412,  730,  300,  105,  105,  105,  105,  105,  105,  105,
- /*    60 */   105,  105,  105,   79,   92,   79,   92,   79,   92,   79,
- /*    70 */    79,  474,  350,  350,   99,   99,   59,   63,   79,  202,
- /*    80 */   145,  250,  235,  155,  182,  241,  195,  265,  246,   41,
- /*    90 */    14,  116,  254,  189,  208,  223,  218,  232,  225,  259,
- /*   100 */   270,  256,  289,  280,  285,  282,  272,  295,  296,  278,
- /*   110 */   286,  288,  291,  302,  303,  324,  325,  320,  328,  339,
- /*   120 */   340,
+ /*     0 */    88,  222,    0,   28,   58,  116,  144,  172,  249,  249,
+ /*    10 */   276,  276,  329,  329,  329,  329,  329,  329,  641,  641,
+ /*    20 */   657,  657,  641,  641,  657,  657,  193,  302,  355,  376,
+ /*    30 */   397,  397,  397,  397,  397,  397,  698,  657,  657,  657,
+ /*    40 */   710,  710,  724,   13,  683,  480,   21,   13,  778,  760,
+ /*    50 */   760,  615,  782,  523,   51,   51,   51,   51,   51,   51,
+ /*    60 */    51,   51,   51,   51,   32,    8,   32,    8,   32,    8,
+ /*    70 */    32,   32,  784,  331,  331,   85,   85,  250,   96,   96,
+ /*    80 */    32,   16,  150,  259,   83,  178,  223,  100,  266,  285,
+ /*    90 */   126,  170,  248,  264,  156,  146,  204,  274,  283,  290,
+ /*   100 */   280,  287,  286,  281,  309,  299,  307,  303,  313,  312,
+ /*   110 */   292,  317,  318,  325,  324,  308,  314,  320,  333,  334,
+ /*   120 */   335,  326,  342,  327,  352,  364,  365,
 };
-#define YY_REDUCE_COUNT (78)
-#define YY_REDUCE_MIN   (-55)
-#define YY_REDUCE_MAX   (693)
+#define YY_REDUCE_COUNT (80)
+#define YY_REDUCE_MIN   (-57)
+#define YY_REDUCE_MAX   (725)
 static const short yy_reduce_ofst[] = {
- /*     0 */   393,  423,  436,  464,  436,  464,  436,  464,  436,  436,
- /*    10 */   464,  464,  477,  505,  518,  546,  559,  587,  436,  436,
- /*    20 */   464,  464,  436,  436,  464,  464,  643,  401,  401,  401,
- /*    30 */   431,  472,  513,  554,  652,  693,  401,  401,  401,  401,
- /*    40 */   597,  401,   37,  -51,    7,  -38,   39,  -55,  -12,   73,
- /*    50 */   -26,    3,   28,   32,   86,   91,   28,  100,   28,  100,
- /*    60 */   113,  100,  118,  152,  148,  172,  164,  205,  177,  216,
- /*    70 */   222,  -47,  -39,  -47,   80,   96,   65,  109,  149,
+ /*     0 */   393,  423,  436,  466,  436,  466,  436,  466,  436,  436,
+ /*    10 */   466,  466,  479,  509,  522,  552,  565,  595,  436,  436,
+ /*    20 */   466,  466,  436,  436,  466,  466,  652,  388,  388,  388,
+ /*    30 */   431,  474,  517,  560,  715,  720,  725,  388,  388,  388,
+ /*    40 */   388,  388,  173,  -52,   38,   49,   49,  -30,  -57,  -56,
+ /*    50 */   -12,    2,   19,   60,   77,   89,   95,   60,  104,   60,
+ /*    60 */   104,  117,  104,  123,   92,  143,  191,  159,  202,  194,
+ /*    70 */   219,  225,  -38,    9,  -38,   12,   14,   29,   63,  169,
+ /*    80 */   101,
 };
 static const YYACTIONTYPE yy_default[] = {
- /*     0 */   281,  281,  281,  281,  281,  287,  294,  287,  295,  293,
- /*    10 */   296,  298,  281,  281,  281,  281,  281,  281,  319,  321,
- /*    20 */   322,  320,  288,  289,  291,  290,  281,  281,  299,  298,
- /*    30 */   281,  281,  281,  281,  281,  281,  322,  320,  291,  301,
- /*    40 */   281,  300,  281,  354,  281,  281,  353,  281,  281,  281,
- /*    50 */   281,  281,  281,  281,  281,  281,  341,  338,  337,  334,
- /*    60 */   281,  281,  281,  308,  281,  308,  281,  308,  281,  308,
- /*    70 */   308,  281,  281,  281,  281,  281,  281,  281,  307,  281,
- /*    80 */   281,  281,  281,  281,  281,  281,  281,  281,  281,  281,
- /*    90 */   281,  281,  281,  281,  281,  281,  281,  281,  281,  281,
- /*   100 */   281,  281,  281,  281,  281,  281,  281,  281,  281,  281,
- /*   110 */   281,  281,  281,  281,  281,  373,  372,  281,  281,  297,
- /*   120 */   292,
+ /*     0 */   295,  295,  295,  295,  295,  301,  308,  301,  309,  307,
+ /*    10 */   310,  312,  295,  295,  295,  295,  295,  295,  333,  335,
+ /*    20 */   336,  334,  302,  303,  305,  304,  295,  295,  313,  312,
+ /*    30 */   295,  295,  295,  295,  295,  295,  295,  336,  334,  305,
+ /*    40 */   315,  314,  295,  372,  295,  295,  295,  371,  295,  295,
+ /*    50 */   295,  295,  295,  295,  295,  295,  295,  355,  352,  351,
+ /*    60 */   348,  295,  295,  295,  322,  295,  322,  295,  322,  295,
+ /*    70 */   322,  322,  295,  295,  295,  295,  295,  295,  295,  295,
+ /*    80 */   321,  295,  295,  295,  295,  295,  295,  295,  295,  295,
+ /*    90 */   295,  295,  295,  295,  295,  295,  295,  295,  295,  295,
+ /*   100 */   295,  295,  295,  295,  295,  295,  295,  295,  295,  295,
+ /*   110 */   295,  295,  295,  295,  295,  295,  295,  295,  295,  295,
+ /*   120 */   295,  391,  390,  295,  295,  311,  306,
 };
 /********** End of lemon-generated parsing tables *****************************/
 
@@ -530,6 +625,7 @@ static const YYCODETYPE yyFallback[] = {
     0,  /*         RB => nothing */
     0,  /*       RSQB => nothing */
     0,  /*       TERM => nothing */
+    9,  /*  ISMISSING => TERM */
     0,  /*      QUOTE => nothing */
     0,  /*         LP => nothing */
     0,  /*         LB => nothing */
@@ -552,6 +648,7 @@ static const YYCODETYPE yyFallback[] = {
     0,  /*   VERBATIM => nothing */
     0,  /*   WILDCARD => nothing */
     0,  /* NAMED_PREDICATE => nothing */
+    0,  /* DATE_RANGE => nothing */
     9,  /*       AS_T => TERM */
     0,  /*  SEMICOLON => nothing */
 };
@@ -652,62 +749,64 @@ static const char *const yyTokenName[] = {
   /*    7 */ "RB",
   /*    8 */ "RSQB",
   /*    9 */ "TERM",
-  /*   10 */ "QUOTE",
-  /*   11 */ "LP",
-  /*   12 */ "LB",
-  /*   13 */ "LSQB",
-  /*   14 */ "TILDE",
-  /*   15 */ "MINUS",
-  /*   16 */ "AND",
-  /*   17 */ "ARROW",
-  /*   18 */ "COLON",
-  /*   19 */ "NUMBER",
-  /*   20 */ "SIZE",
-  /*   21 */ "STAR",
-  /*   22 */ "TAGLIST",
-  /*   23 */ "TERMLIST",
-  /*   24 */ "PREFIX",
-  /*   25 */ "SUFFIX",
-  /*   26 */ "CONTAINS",
-  /*   27 */ "PERCENT",
-  /*   28 */ "ATTRIBUTE",
-  /*   29 */ "VERBATIM",
-  /*   30 */ "WILDCARD",
-  /*   31 */ "NAMED_PREDICATE",
-  /*   32 */ "AS_T",
-  /*   33 */ "SEMICOLON",
-  /*   34 */ "expr",
-  /*   35 */ "attribute",
-  /*   36 */ "attribute_list",
-  /*   37 */ "affix",
-  /*   38 */ "suffix",
-  /*   39 */ "contains",
-  /*   40 */ "verbatim",
-  /*   41 */ "termlist",
-  /*   42 */ "union",
-  /*   43 */ "text_union",
-  /*   44 */ "text_expr",
-  /*   45 */ "fuzzy",
-  /*   46 */ "tag_list",
-  /*   47 */ "geo_filter",
-  /*   48 */ "geometry_query",
-  /*   49 */ "vector_query",
-  /*   50 */ "vector_command",
-  /*   51 */ "vector_range_command",
-  /*   52 */ "vector_attribute",
-  /*   53 */ "vector_attribute_list",
-  /*   54 */ "modifierlist",
-  /*   55 */ "num",
-  /*   56 */ "numeric_range",
-  /*   57 */ "query",
-  /*   58 */ "star",
-  /*   59 */ "modifier",
-  /*   60 */ "param_term",
-  /*   61 */ "term",
-  /*   62 */ "param_num",
-  /*   63 */ "vector_score_field",
-  /*   64 */ "as",
-  /*   65 */ "param_size",
+  /*   10 */ "ISMISSING",
+  /*   11 */ "QUOTE",
+  /*   12 */ "LP",
+  /*   13 */ "LB",
+  /*   14 */ "LSQB",
+  /*   15 */ "TILDE",
+  /*   16 */ "MINUS",
+  /*   17 */ "AND",
+  /*   18 */ "ARROW",
+  /*   19 */ "COLON",
+  /*   20 */ "NUMBER",
+  /*   21 */ "SIZE",
+  /*   22 */ "STAR",
+  /*   23 */ "TAGLIST",
+  /*   24 */ "TERMLIST",
+  /*   25 */ "PREFIX",
+  /*   26 */ "SUFFIX",
+  /*   27 */ "CONTAINS",
+  /*   28 */ "PERCENT",
+  /*   29 */ "ATTRIBUTE",
+  /*   30 */ "VERBATIM",
+  /*   31 */ "WILDCARD",
+  /*   32 */ "NAMED_PREDICATE",
+  /*   33 */ "DATE_RANGE",
+  /*   34 */ "AS_T",
+  /*   35 */ "SEMICOLON",
+  /*   36 */ "expr",
+  /*   37 */ "attribute",
+  /*   38 */ "attribute_list",
+  /*   39 */ "affix",
+  /*   40 */ "suffix",
+  /*   41 */ "contains",
+  /*   42 */ "verbatim",
+  /*   43 */ "termlist",
+  /*   44 */ "union",
+  /*   45 */ "text_union",
+  /*   46 */ "text_expr",
+  /*   47 */ "fuzzy",
+  /*   48 */ "tag_list",
+  /*   49 */ "geo_filter",
+  /*   50 */ "geometry_query",
+  /*   51 */ "vector_query",
+  /*   52 */ "vector_command",
+  /*   53 */ "vector_range_command",
+  /*   54 */ "vector_attribute",
+  /*   55 */ "vector_attribute_list",
+  /*   56 */ "modifierlist",
+  /*   57 */ "num",
+  /*   58 */ "numeric_range",
+  /*   59 */ "query",
+  /*   60 */ "star",
+  /*   61 */ "modifier",
+  /*   62 */ "param_term",
+  /*   63 */ "term",
+  /*   64 */ "param_num",
+  /*   65 */ "vector_score_field",
+  /*   66 */ "as",
+  /*   67 */ "param_size",
 };
 #endif /* defined(YYCOVERAGE) || !defined(NDEBUG) */
 
@@ -773,46 +872,50 @@ static const char *const yyRuleName[] = {
  /*  55 */ "tag_list ::= tag_list OR affix",
  /*  56 */ "tag_list ::= tag_list OR verbatim",
  /*  57 */ "tag_list ::= tag_list OR termlist",
- /*  58 */ "expr ::= modifier COLON numeric_range",
- /*  59 */ "numeric_range ::= LSQB param_num param_num RSQB",
- /*  60 */ "expr ::= modifier COLON geo_filter",
- /*  61 */ "geo_filter ::= LSQB param_num param_num param_num param_term RSQB",
- /*  62 */ "expr ::= modifier COLON geometry_query",
- /*  63 */ "geometry_query ::= NAMED_PREDICATE",
- /*  64 */ "query ::= expr ARROW LSQB vector_query RSQB",
- /*  65 */ "query ::= text_expr ARROW LSQB vector_query RSQB",
- /*  66 */ "query ::= star ARROW LSQB vector_query RSQB",
- /*  67 */ "vector_query ::= vector_command vector_attribute_list vector_score_field",
- /*  68 */ "vector_query ::= vector_command vector_score_field",
- /*  69 */ "vector_query ::= vector_command vector_attribute_list",
- /*  70 */ "vector_query ::= vector_command",
- /*  71 */ "vector_score_field ::= as param_term",
- /*  72 */ "query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB",
- /*  73 */ "query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB",
- /*  74 */ "query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB",
- /*  75 */ "vector_command ::= TERM param_size modifier ATTRIBUTE",
- /*  76 */ "vector_attribute ::= TERM param_term",
- /*  77 */ "vector_attribute_list ::= vector_attribute_list vector_attribute",
- /*  78 */ "vector_attribute_list ::= vector_attribute",
- /*  79 */ "expr ::= modifier COLON LSQB vector_range_command RSQB",
- /*  80 */ "vector_range_command ::= TERM param_num ATTRIBUTE",
- /*  81 */ "num ::= SIZE",
- /*  82 */ "num ::= NUMBER",
- /*  83 */ "num ::= LP num",
- /*  84 */ "num ::= MINUS num",
- /*  85 */ "term ::= TERM",
- /*  86 */ "term ::= NUMBER",
- /*  87 */ "term ::= SIZE",
- /*  88 */ "param_term ::= term",
- /*  89 */ "param_term ::= ATTRIBUTE",
- /*  90 */ "param_size ::= SIZE",
- /*  91 */ "param_size ::= ATTRIBUTE",
- /*  92 */ "param_num ::= ATTRIBUTE",
- /*  93 */ "param_num ::= num",
- /*  94 */ "param_num ::= LP ATTRIBUTE",
- /*  95 */ "star ::= STAR",
- /*  96 */ "star ::= LP star RP",
- /*  97 */ "as ::= AS_T",
+ /*  58 */ "expr ::= ISMISSING LP modifier RP",
+ /*  59 */ "expr ::= modifier COLON LB QUOTE QUOTE RB",
+ /*  60 */ "expr ::= modifier COLON QUOTE QUOTE",
+ /*  61 */ "expr ::= modifier COLON numeric_range",
+ /*  62 */ "numeric_range ::= LSQB param_num param_num RSQB",
+ /*  63 */ "expr ::= modifier COLON DATE_RANGE",
+ /*  64 */ "expr ::= modifier COLON geo_filter",
+ /*  65 */ "geo_filter ::= LSQB param_num param_num param_num param_term RSQB",
+ /*  66 */ "expr ::= modifier COLON geometry_query",
+ /*  67 */ "geometry_query ::= NAMED_PREDICATE",
+ /*  68 */ "query ::= expr ARROW LSQB vector_query RSQB",
+ /*  69 */ "query ::= text_expr ARROW LSQB vector_query RSQB",
+ /*  70 */ "query ::= star ARROW LSQB vector_query RSQB",
+ /*  71 */ "vector_query ::= vector_command vector_attribute_list vector_score_field",
+ /*  72 */ "vector_query ::= vector_command vector_score_field",
+ /*  73 */ "vector_query ::= vector_command vector_attribute_list",
+ /*  74 */ "vector_query ::= vector_command",
+ /*  75 */ "vector_score_field ::= as param_term",
+ /*  76 */ "query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB",
+ /*  77 */ "query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB",
+ /*  78 */ "query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB",
+ /*  79 */ "vector_command ::= TERM param_size modifier ATTRIBUTE",
+ /*  80 */ "vector_attribute ::= TERM param_term",
+ /*  81 */ "vector_attribute_list ::= vector_attribute_list vector_attribute",
+ /*  82 */ "vector_attribute_list ::= vector_attribute",
+ /*  83 */ "expr ::= modifier COLON LSQB vector_range_command RSQB",
+ /*  84 */ "vector_range_command ::= TERM param_num ATTRIBUTE",
+ /*  85 */ "num ::= SIZE",
+ /*  86 */ "num ::= NUMBER",
+ /*  87 */ "num ::= LP num",
+ /*  88 */ "num ::= MINUS num",
+ /*  89 */ "term ::= TERM",
+ /*  90 */ "term ::= NUMBER",
+ /*  91 */ "term ::= SIZE",
+ /*  92 */ "param_term ::= term",
+ /*  93 */ "param_term ::= ATTRIBUTE",
+ /*  94 */ "param_size ::= SIZE",
+ /*  95 */ "param_size ::= ATTRIBUTE",
+ /*  96 */ "param_num ::= ATTRIBUTE",
+ /*  97 */ "param_num ::= num",
+ /*  98 */ "param_num ::= LP ATTRIBUTE",
+ /*  99 */ "star ::= STAR",
+ /* 100 */ "star ::= LP star RP",
+ /* 101 */ "as ::= AS_T",
 };
 #endif /* NDEBUG */
 
@@ -939,82 +1042,82 @@ static void yy_destructor(
     */
 /********* Begin destructor definitions ***************************************/
       /* Default NON-TERMINAL Destructor */
-    case 52: /* vector_attribute */
-    case 55: /* num */
-    case 57: /* query */
-    case 58: /* star */
-    case 59: /* modifier */
-    case 60: /* param_term */
-    case 61: /* term */
-    case 62: /* param_num */
-    case 63: /* vector_score_field */
-    case 64: /* as */
-    case 65: /* param_size */
+    case 54: /* vector_attribute */
+    case 57: /* num */
+    case 59: /* query */
+    case 60: /* star */
+    case 61: /* modifier */
+    case 62: /* param_term */
+    case 63: /* term */
+    case 64: /* param_num */
+    case 65: /* vector_score_field */
+    case 66: /* as */
+    case 67: /* param_size */
 {
  
 }
       break;
-    case 34: /* expr */
-    case 37: /* affix */
-    case 38: /* suffix */
-    case 39: /* contains */
-    case 40: /* verbatim */
-    case 41: /* termlist */
-    case 42: /* union */
-    case 43: /* text_union */
-    case 44: /* text_expr */
-    case 45: /* fuzzy */
-    case 46: /* tag_list */
-    case 48: /* geometry_query */
-    case 49: /* vector_query */
-    case 50: /* vector_command */
-    case 51: /* vector_range_command */
-{
- QueryNode_Free((yypminor->yy119)); 
+    case 36: /* expr */
+    case 39: /* affix */
+    case 40: /* suffix */
+    case 41: /* contains */
+    case 42: /* verbatim */
+    case 43: /* termlist */
+    case 44: /* union */
+    case 45: /* text_union */
+    case 46: /* text_expr */
+    case 47: /* fuzzy */
+    case 48: /* tag_list */
+    case 50: /* geometry_query */
+    case 51: /* vector_query */
+    case 52: /* vector_command */
+    case 53: /* vector_range_command */
+{
+ QueryNode_Free((yypminor->yy19)); 
 }
       break;
-    case 35: /* attribute */
+    case 37: /* attribute */
 {
- rm_free((char*)(yypminor->yy43).value); 
+ rm_free((char*)(yypminor->yy23).value); 
 }
       break;
-    case 36: /* attribute_list */
+    case 38: /* attribute_list */
 {
- array_free_ex((yypminor->yy45), rm_free((char*)((QueryAttribute*)ptr )->value)); 
+ array_free_ex((yypminor->yy105), rm_free((char*)((QueryAttribute*)ptr )->value)); 
 }
       break;
-    case 47: /* geo_filter */
+    case 49: /* geo_filter */
 {
- QueryParam_Free((yypminor->yy62)); 
+ QueryParam_Free((yypminor->yy134)); 
 }
       break;
-    case 53: /* vector_attribute_list */
+    case 55: /* vector_attribute_list */
 {
 
-  array_free((yypminor->yy2).needResolve);
-  array_free_ex((yypminor->yy2).params, {
+  array_free((yypminor->yy26).needResolve);
+  array_free_ex((yypminor->yy26).params, {
     rm_free((char*)((VecSimRawParam*)ptr)->value);
     rm_free((char*)((VecSimRawParam*)ptr)->name);
   });
 
 }
       break;
-    case 54: /* modifierlist */
+    case 56: /* modifierlist */
 {
 
-    for (size_t i = 0; i < Vector_Size((yypminor->yy42)); i++) {
+    for (size_t i = 0; i < Vector_Size((yypminor->yy27)); i++) {
         char *s;
-        Vector_Get((yypminor->yy42), i, &s);
+        Vector_Get((yypminor->yy27), i, &s);
         rm_free(s);
     }
-    Vector_Free((yypminor->yy42));
+    Vector_Free((yypminor->yy27));
 
 }
       break;
-    case 56: /* numeric_range */
+    case 58: /* numeric_range */
 {
 
-  QueryParam_Free((yypminor->yy62));
+  QueryParam_Free((yypminor->yy134));
 
 }
       break;
@@ -1307,104 +1410,108 @@ static void yy_shift(
 /* For rule J, yyRuleInfoLhs[J] contains the symbol on the left-hand side
 ** of that rule */
 static const YYCODETYPE yyRuleInfoLhs[] = {
-    57,  /* (0) query ::= expr */
-    57,  /* (1) query ::= */
-    57,  /* (2) query ::= star */
-    34,  /* (3) expr ::= text_expr */
-    34,  /* (4) expr ::= expr expr */
-    34,  /* (5) expr ::= text_expr expr */
-    34,  /* (6) expr ::= expr text_expr */
-    44,  /* (7) text_expr ::= text_expr text_expr */
-    34,  /* (8) expr ::= union */
-    42,  /* (9) union ::= expr OR expr */
-    42,  /* (10) union ::= union OR expr */
-    42,  /* (11) union ::= text_expr OR expr */
-    42,  /* (12) union ::= expr OR text_expr */
-    44,  /* (13) text_expr ::= text_union */
-    43,  /* (14) text_union ::= text_expr OR text_expr */
-    43,  /* (15) text_union ::= text_union OR text_expr */
-    34,  /* (16) expr ::= modifier COLON text_expr */
-    34,  /* (17) expr ::= modifierlist COLON text_expr */
-    34,  /* (18) expr ::= LP expr RP */
-    44,  /* (19) text_expr ::= LP text_expr RP */
-    35,  /* (20) attribute ::= ATTRIBUTE COLON param_term */
-    36,  /* (21) attribute_list ::= attribute */
-    36,  /* (22) attribute_list ::= attribute_list SEMICOLON attribute */
-    36,  /* (23) attribute_list ::= attribute_list SEMICOLON */
-    36,  /* (24) attribute_list ::= */
-    34,  /* (25) expr ::= expr ARROW LB attribute_list RB */
-    44,  /* (26) text_expr ::= text_expr ARROW LB attribute_list RB */
-    44,  /* (27) text_expr ::= QUOTE termlist QUOTE */
-    44,  /* (28) text_expr ::= QUOTE term QUOTE */
-    44,  /* (29) text_expr ::= QUOTE ATTRIBUTE QUOTE */
-    44,  /* (30) text_expr ::= param_term */
-    44,  /* (31) text_expr ::= affix */
-    44,  /* (32) text_expr ::= verbatim */
-    41,  /* (33) termlist ::= param_term param_term */
-    41,  /* (34) termlist ::= termlist param_term */
-    34,  /* (35) expr ::= MINUS expr */
-    44,  /* (36) text_expr ::= MINUS text_expr */
-    34,  /* (37) expr ::= TILDE expr */
-    44,  /* (38) text_expr ::= TILDE text_expr */
-    37,  /* (39) affix ::= PREFIX */
-    37,  /* (40) affix ::= SUFFIX */
-    37,  /* (41) affix ::= CONTAINS */
-    40,  /* (42) verbatim ::= WILDCARD */
-    44,  /* (43) text_expr ::= PERCENT param_term PERCENT */
-    44,  /* (44) text_expr ::= PERCENT PERCENT param_term PERCENT PERCENT */
-    44,  /* (45) text_expr ::= PERCENT PERCENT PERCENT param_term PERCENT PERCENT PERCENT */
-    59,  /* (46) modifier ::= MODIFIER */
-    54,  /* (47) modifierlist ::= modifier OR term */
-    54,  /* (48) modifierlist ::= modifierlist OR term */
-    34,  /* (49) expr ::= modifier COLON LB tag_list RB */
-    46,  /* (50) tag_list ::= param_term */
-    46,  /* (51) tag_list ::= affix */
-    46,  /* (52) tag_list ::= verbatim */
-    46,  /* (53) tag_list ::= termlist */
-    46,  /* (54) tag_list ::= tag_list OR param_term */
-    46,  /* (55) tag_list ::= tag_list OR affix */
-    46,  /* (56) tag_list ::= tag_list OR verbatim */
-    46,  /* (57) tag_list ::= tag_list OR termlist */
-    34,  /* (58) expr ::= modifier COLON numeric_range */
-    56,  /* (59) numeric_range ::= LSQB param_num param_num RSQB */
-    34,  /* (60) expr ::= modifier COLON geo_filter */
-    47,  /* (61) geo_filter ::= LSQB param_num param_num param_num param_term RSQB */
-    34,  /* (62) expr ::= modifier COLON geometry_query */
-    48,  /* (63) geometry_query ::= NAMED_PREDICATE */
-    57,  /* (64) query ::= expr ARROW LSQB vector_query RSQB */
-    57,  /* (65) query ::= text_expr ARROW LSQB vector_query RSQB */
-    57,  /* (66) query ::= star ARROW LSQB vector_query RSQB */
-    49,  /* (67) vector_query ::= vector_command vector_attribute_list vector_score_field */
-    49,  /* (68) vector_query ::= vector_command vector_score_field */
-    49,  /* (69) vector_query ::= vector_command vector_attribute_list */
-    49,  /* (70) vector_query ::= vector_command */
-    63,  /* (71) vector_score_field ::= as param_term */
-    57,  /* (72) query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-    57,  /* (73) query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-    57,  /* (74) query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-    50,  /* (75) vector_command ::= TERM param_size modifier ATTRIBUTE */
-    52,  /* (76) vector_attribute ::= TERM param_term */
-    53,  /* (77) vector_attribute_list ::= vector_attribute_list vector_attribute */
-    53,  /* (78) vector_attribute_list ::= vector_attribute */
-    34,  /* (79) expr ::= modifier COLON LSQB vector_range_command RSQB */
-    51,  /* (80) vector_range_command ::= TERM param_num ATTRIBUTE */
-    55,  /* (81) num ::= SIZE */
-    55,  /* (82) num ::= NUMBER */
-    55,  /* (83) num ::= LP num */
-    55,  /* (84) num ::= MINUS num */
-    61,  /* (85) term ::= TERM */
-    61,  /* (86) term ::= NUMBER */
-    61,  /* (87) term ::= SIZE */
-    60,  /* (88) param_term ::= term */
-    60,  /* (89) param_term ::= ATTRIBUTE */
-    65,  /* (90) param_size ::= SIZE */
-    65,  /* (91) param_size ::= ATTRIBUTE */
-    62,  /* (92) param_num ::= ATTRIBUTE */
-    62,  /* (93) param_num ::= num */
-    62,  /* (94) param_num ::= LP ATTRIBUTE */
-    58,  /* (95) star ::= STAR */
-    58,  /* (96) star ::= LP star RP */
-    64,  /* (97) as ::= AS_T */
+    59,  /* (0) query ::= expr */
+    59,  /* (1) query ::= */
+    59,  /* (2) query ::= star */
+    36,  /* (3) expr ::= text_expr */
+    36,  /* (4) expr ::= expr expr */
+    36,  /* (5) expr ::= text_expr expr */
+    36,  /* (6) expr ::= expr text_expr */
+    46,  /* (7) text_expr ::= text_expr text_expr */
+    36,  /* (8) expr ::= union */
+    44,  /* (9) union ::= expr OR expr */
+    44,  /* (10) union ::= union OR expr */
+    44,  /* (11) union ::= text_expr OR expr */
+    44,  /* (12) union ::= expr OR text_expr */
+    46,  /* (13) text_expr ::= text_union */
+    45,  /* (14) text_union ::= text_expr OR text_expr */
+    45,  /* (15) text_union ::= text_union OR text_expr */
+    36,  /* (16) expr ::= modifier COLON text_expr */
+    36,  /* (17) expr ::= modifierlist COLON text_expr */
+    36,  /* (18) expr ::= LP expr RP */
+    46,  /* (19) text_expr ::= LP text_expr RP */
+    37,  /* (20) attribute ::= ATTRIBUTE COLON param_term */
+    38,  /* (21) attribute_list ::= attribute */
+    38,  /* (22) attribute_list ::= attribute_list SEMICOLON attribute */
+    38,  /* (23) attribute_list ::= attribute_list SEMICOLON */
+    38,  /* (24) attribute_list ::= */
+    36,  /* (25) expr ::= expr ARROW LB attribute_list RB */
+    46,  /* (26) text_expr ::= text_expr ARROW LB attribute_list RB */
+    46,  /* (27) text_expr ::= QUOTE termlist QUOTE */
+    46,  /* (28) text_expr ::= QUOTE term QUOTE */
+    46,  /* (29) text_expr ::= QUOTE ATTRIBUTE QUOTE */
+    46,  /* (30) text_expr ::= param_term */
+    46,  /* (31) text_expr ::= affix */
+    46,  /* (32) text_expr ::= verbatim */
+    43,  /* (33) termlist ::= param_term param_term */
+    43,  /* (34) termlist ::= termlist param_term */
+    36,  /* (35) expr ::= MINUS expr */
+    46,  /* (36) text_expr ::= MINUS text_expr */
+    36,  /* (37) expr ::= TILDE expr */
+    46,  /* (38) text_expr ::= TILDE text_expr */
+    39,  /* (39) affix ::= PREFIX */
+    39,  /* (40) affix ::= SUFFIX */
+    39,  /* (41) affix ::= CONTAINS */
+    42,  /* (42) verbatim ::= WILDCARD */
+    46,  /* (43) text_expr ::= PERCENT param_term PERCENT */
+    46,  /* (44) text_expr ::= PERCENT PERCENT param_term PERCENT PERCENT */
+    46,  /* (45) text_expr ::= PERCENT PERCENT PERCENT param_term PERCENT PERCENT PERCENT */
+    61,  /* (46) modifier ::= MODIFIER */
+    56,  /* (47) modifierlist ::= modifier OR term */
+    56,  /* (48) modifierlist ::= modifierlist OR term */
+    36,  /* (49) expr ::= modifier COLON LB tag_list RB */
+    48,  /* (50) tag_list ::= param_term */
+    48,  /* (51) tag_list ::= affix */
+    48,  /* (52) tag_list ::= verbatim */
+    48,  /* (53) tag_list ::= termlist */
+    48,  /* (54) tag_list ::= tag_list OR param_term */
+    48,  /* (55) tag_list ::= tag_list OR affix */
+    48,  /* (56) tag_list ::= tag_list OR verbatim */
+    48,  /* (57) tag_list ::= tag_list OR termlist */
+    36,  /* (58) expr ::= ISMISSING LP modifier RP */
+    36,  /* (59) expr ::= modifier COLON LB QUOTE QUOTE RB */
+    36,  /* (60) expr ::= modifier COLON QUOTE QUOTE */
+    36,  /* (61) expr ::= modifier COLON numeric_range */
+    58,  /* (62) numeric_range ::= LSQB param_num param_num RSQB */
+    36,  /* (63) expr ::= modifier COLON DATE_RANGE */
+    36,  /* (64) expr ::= modifier COLON geo_filter */
+    49,  /* (65) geo_filter ::= LSQB param_num param_num param_num param_term RSQB */
+    36,  /* (66) expr ::= modifier COLON geometry_query */
+    50,  /* (67) geometry_query ::= NAMED_PREDICATE */
+    59,  /* (68) query ::= expr ARROW LSQB vector_query RSQB */
+    59,  /* (69) query ::= text_expr ARROW LSQB vector_query RSQB */
+    59,  /* (70) query ::= star ARROW LSQB vector_query RSQB */
+    51,  /* (71) vector_query ::= vector_command vector_attribute_list vector_score_field */
+    51,  /* (72) vector_query ::= vector_command vector_score_field */
+    51,  /* (73) vector_query ::= vector_command vector_attribute_list */
+    51,  /* (74) vector_query ::= vector_command */
+    65,  /* (75) vector_score_field ::= as param_term */
+    59,  /* (76) query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+    59,  /* (77) query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+    59,  /* (78) query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+    52,  /* (79) vector_command ::= TERM param_size modifier ATTRIBUTE */
+    54,  /* (80) vector_attribute ::= TERM param_term */
+    55,  /* (81) vector_attribute_list ::= vector_attribute_list vector_attribute */
+    55,  /* (82) vector_attribute_list ::= vector_attribute */
+    36,  /* (83) expr ::= modifier COLON LSQB vector_range_command RSQB */
+    53,  /* (84) vector_range_command ::= TERM param_num ATTRIBUTE */
+    57,  /* (85) num ::= SIZE */
+    57,  /* (86) num ::= NUMBER */
+    57,  /* (87) num ::= LP num */
+    57,  /* (88) num ::= MINUS num */
+    63,  /* (89) term ::= TERM */
+    63,  /* (90) term ::= NUMBER */
+    63,  /* (91) term ::= SIZE */
+    62,  /* (92) param_term ::= term */
+    62,  /* (93) param_term ::= ATTRIBUTE */
+    67,  /* (94) param_size ::= SIZE */
+    67,  /* (95) param_size ::= ATTRIBUTE */
+    64,  /* (96) param_num ::= ATTRIBUTE */
+    64,  /* (97) param_num ::= num */
+    64,  /* (98) param_num ::= LP ATTRIBUTE */
+    60,  /* (99) star ::= STAR */
+    60,  /* (100) star ::= LP star RP */
+    66,  /* (101) as ::= AS_T */
 };
 
 /* For rule J, yyRuleInfoNRhs[J] contains the negative of the number
@@ -1468,46 +1575,50 @@ static const signed char yyRuleInfoNRhs[] = {
    -3,  /* (55) tag_list ::= tag_list OR affix */
    -3,  /* (56) tag_list ::= tag_list OR verbatim */
    -3,  /* (57) tag_list ::= tag_list OR termlist */
-   -3,  /* (58) expr ::= modifier COLON numeric_range */
-   -4,  /* (59) numeric_range ::= LSQB param_num param_num RSQB */
-   -3,  /* (60) expr ::= modifier COLON geo_filter */
-   -6,  /* (61) geo_filter ::= LSQB param_num param_num param_num param_term RSQB */
-   -3,  /* (62) expr ::= modifier COLON geometry_query */
-   -1,  /* (63) geometry_query ::= NAMED_PREDICATE */
-   -5,  /* (64) query ::= expr ARROW LSQB vector_query RSQB */
-   -5,  /* (65) query ::= text_expr ARROW LSQB vector_query RSQB */
-   -5,  /* (66) query ::= star ARROW LSQB vector_query RSQB */
-   -3,  /* (67) vector_query ::= vector_command vector_attribute_list vector_score_field */
-   -2,  /* (68) vector_query ::= vector_command vector_score_field */
-   -2,  /* (69) vector_query ::= vector_command vector_attribute_list */
-   -1,  /* (70) vector_query ::= vector_command */
-   -2,  /* (71) vector_score_field ::= as param_term */
-   -9,  /* (72) query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-   -9,  /* (73) query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-   -9,  /* (74) query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-   -4,  /* (75) vector_command ::= TERM param_size modifier ATTRIBUTE */
-   -2,  /* (76) vector_attribute ::= TERM param_term */
-   -2,  /* (77) vector_attribute_list ::= vector_attribute_list vector_attribute */
-   -1,  /* (78) vector_attribute_list ::= vector_attribute */
-   -5,  /* (79) expr ::= modifier COLON LSQB vector_range_command RSQB */
-   -3,  /* (80) vector_range_command ::= TERM param_num ATTRIBUTE */
-   -1,  /* (81) num ::= SIZE */
-   -1,  /* (82) num ::= NUMBER */
-   -2,  /* (83) num ::= LP num */
-   -2,  /* (84) num ::= MINUS num */
-   -1,  /* (85) term ::= TERM */
-   -1,  /* (86) term ::= NUMBER */
-   -1,  /* (87) term ::= SIZE */
-   -1,  /* (88) param_term ::= term */
-   -1,  /* (89) param_term ::= ATTRIBUTE */
-   -1,  /* (90) param_size ::= SIZE */
-   -1,  /* (91) param_size ::= ATTRIBUTE */
-   -1,  /* (92) param_num ::= ATTRIBUTE */
-   -1,  /* (93) param_num ::= num */
-   -2,  /* (94) param_num ::= LP ATTRIBUTE */
-   -1,  /* (95) star ::= STAR */
-   -3,  /* (96) star ::= LP star RP */
-   -1,  /* (97) as ::= AS_T */
+   -4,  /* (58) expr ::= ISMISSING LP modifier RP */
+   -6,  /* (59) expr ::= modifier COLON LB QUOTE QUOTE RB */
+   -4,  /* (60) expr ::= modifier COLON QUOTE QUOTE */
+   -3,  /* (61) expr ::= modifier COLON numeric_range */
+   -4,  /* (62) numeric_range ::= LSQB param_num param_num RSQB */
+   -3,  /* (63) expr ::= modifier COLON DATE_RANGE */
+   -3,  /* (64) expr ::= modifier COLON geo_filter */
+   -6,  /* (65) geo_filter ::= LSQB param_num param_num param_num param_term RSQB */
+   -3,  /* (66) expr ::= modifier COLON geometry_query */
+   -1,  /* (67) geometry_query ::= NAMED_PREDICATE */
+   -5,  /* (68) query ::= expr ARROW LSQB vector_query RSQB */
+   -5,  /* (69) query ::= text_expr ARROW LSQB vector_query RSQB */
+   -5,  /* (70) query ::= star ARROW LSQB vector_query RSQB */
+   -3,  /* (71) vector_query ::= vector_command vector_attribute_list vector_score_field */
+   -2,  /* (72) vector_query ::= vector_command vector_score_field */
+   -2,  /* (73) vector_query ::= vector_command vector_attribute_list */
+   -1,  /* (74) vector_query ::= vector_command */
+   -2,  /* (75) vector_score_field ::= as param_term */
+   -9,  /* (76) query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+   -9,  /* (77) query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+   -9,  /* (78) query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+   -4,  /* (79) vector_command ::= TERM param_size modifier ATTRIBUTE */
+   -2,  /* (80) vector_attribute ::= TERM param_term */
+   -2,  /* (81) vector_attribute_list ::= vector_attribute_list vector_attribute */
+   -1,  /* (82) vector_attribute_list ::= vector_attribute */
+   -5,  /* (83) expr ::= modifier COLON LSQB vector_range_command RSQB */
+   -3,  /* (84) vector_range_command ::= TERM param_num ATTRIBUTE */
+   -1,  /* (85) num ::= SIZE */
+   -1,  /* (86) num ::= NUMBER */
+   -2,  /* (87) num ::= LP num */
+   -2,  /* (88) num ::= MINUS num */
+   -1,  /* (89) term ::= TERM */
+   -1,  /* (90) term ::= NUMBER */
+   -1,  /* (91) term ::= SIZE */
+   -1,  /* (92) param_term ::= term */
+   -1,  /* (93) param_term ::= ATTRIBUTE */
+   -1,  /* (94) param_size ::= SIZE */
+   -1,  /* (95) param_size ::= ATTRIBUTE */
+   -1,  /* (96) param_num ::= ATTRIBUTE */
+   -1,  /* (97) param_num ::= num */
+   -2,  /* (98) param_num ::= LP ATTRIBUTE */
+   -1,  /* (99) star ::= STAR */
+   -3,  /* (100) star ::= LP star RP */
+   -1,  /* (101) as ::= AS_T */
 };
 
 static void yy_accept(yyParser*);  /* Forward Declaration */
@@ -1552,7 +1663,7 @@ static YYACTIONTYPE yy_reduce(
       case 0: /* query ::= expr */
 {
   setup_trace(ctx);
-  ctx->root = yymsp[0].minor.yy119;
+  ctx->root = yymsp[0].minor.yy19;
 }
         break;
       case 1: /* query ::= */
@@ -1561,7 +1672,7 @@ static YYACTIONTYPE yy_reduce(
 }
         break;
       case 2: /* query ::= star */
-{  yy_destructor(yypParser,58,&yymsp[0].minor);
+{  yy_destructor(yypParser,60,&yymsp[0].minor);
 {
   setup_trace(ctx);
   ctx->root = NewWildcardNode();
@@ -1571,119 +1682,142 @@ static YYACTIONTYPE yy_reduce(
       case 3: /* expr ::= text_expr */
       case 8: /* expr ::= union */ yytestcase(yyruleno==8);
       case 13: /* text_expr ::= text_union */ yytestcase(yyruleno==13);
-      case 70: /* vector_query ::= vector_command */ yytestcase(yyruleno==70);
+      case 74: /* vector_query ::= vector_command */ yytestcase(yyruleno==74);
 {
-  yylhsminor.yy119 = yymsp[0].minor.yy119;
+  yylhsminor.yy19 = yymsp[0].minor.yy19;
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 4: /* expr ::= expr expr */
       case 5: /* expr ::= text_expr expr */ yytestcase(yyruleno==5);
       case 6: /* expr ::= expr text_expr */ yytestcase(yyruleno==6);
       case 7: /* text_expr ::= text_expr text_expr */ yytestcase(yyruleno==7);
 {
-    int rv = one_not_null(yymsp[-1].minor.yy119, yymsp[0].minor.yy119, (void**)&yylhsminor.yy119);
+    int rv = one_not_null(yymsp[-1].minor.yy19, yymsp[0].minor.yy19, (void**)&yylhsminor.yy19);
     if (rv == NODENN_BOTH_INVALID) {
-        yylhsminor.yy119 = NULL;
+        yylhsminor.yy19 = NULL;
     } else if (rv == NODENN_ONE_NULL) {
         // Nothing- `out` is already assigned
     } else {
-        if (yymsp[-1].minor.yy119 && yymsp[-1].minor.yy119->type == QN_PHRASE && yymsp[-1].minor.yy119->pn.exact == 0 &&
-            yymsp[-1].minor.yy119->opts.fieldMask == RS_FIELDMASK_ALL ) {
-            yylhsminor.yy119 = yymsp[-1].minor.yy119;
+        if (yymsp[-1].minor.yy19 && yymsp[-1].minor.yy19->type == QN_PHRASE && yymsp[-1].minor.yy19->pn.exact == 0 &&
+            yymsp[-1].minor.yy19->opts.fieldMask == RS_FIELDMASK_ALL ) {
+            yylhsminor.yy19 = yymsp[-1].minor.yy19;
         } else {
-            yylhsminor.yy119 = NewPhraseNode(0);
-            QueryNode_AddChild(yylhsminor.yy119, yymsp[-1].minor.yy119);
+            yylhsminor.yy19 = NewPhraseNode(0);
+            QueryNode_AddChild(yylhsminor.yy19, yymsp[-1].minor.yy19);
         }
-        QueryNode_AddChild(yylhsminor.yy119, yymsp[0].minor.yy119);
+        QueryNode_AddChild(yylhsminor.yy19, yymsp[0].minor.yy19);
     }
 }
-  yymsp[-1].minor.yy119 = yylhsminor.yy119;
+  yymsp[-1].minor.yy19 = yylhsminor.yy19;
         break;
       case 9: /* union ::= expr OR expr */
       case 11: /* union ::= text_expr OR expr */ yytestcase(yyruleno==11);
       case 12: /* union ::= expr OR text_expr */ yytestcase(yyruleno==12);
       case 14: /* text_union ::= text_expr OR text_expr */ yytestcase(yyruleno==14);
 {
-    int rv = one_not_null(yymsp[-2].minor.yy119, yymsp[0].minor.yy119, (void**)&yylhsminor.yy119);
+    int rv = one_not_null(yymsp[-2].minor.yy19, yymsp[0].minor.yy19, (void**)&yylhsminor.yy19);
     if (rv == NODENN_BOTH_INVALID) {
-        yylhsminor.yy119 = NULL;
+        yylhsminor.yy19 = NULL;
     } else if (rv == NODENN_ONE_NULL) {
         // Nothing- already assigned
     } else {
-        if (yymsp[-2].minor.yy119->type == QN_UNION && yymsp[-2].minor.yy119->opts.fieldMask == RS_FIELDMASK_ALL) {
-            yylhsminor.yy119 = yymsp[-2].minor.yy119;
+        if (yymsp[-2].minor.yy19->type == QN_UNION && yymsp[-2].minor.yy19->opts.fieldMask == RS_FIELDMASK_ALL) {
+            yylhsminor.yy19 = yymsp[-2].minor.yy19;
         } else {
-            yylhsminor.yy119 = NewUnionNode();
-            QueryNode_AddChild(yylhsminor.yy119, yymsp[-2].minor.yy119);
-            yylhsminor.yy119->opts.fieldMask |= yymsp[-2].minor.yy119->opts.fieldMask;
+            yylhsminor.yy19 = NewUnionNode();
+            QueryNode_AddChild(yylhsminor.yy19, yymsp[-2].minor.yy19);
+            yylhsminor.yy19->opts.fieldMask |= yymsp[-2].minor.yy19->opts.fieldMask;
         }
-        // Handle yymsp[0].minor.yy119
-        QueryNode_AddChild(yylhsminor.yy119, yymsp[0].minor.yy119);
-        yylhsminor.yy119->opts.fieldMask |= yymsp[0].minor.yy119->opts.fieldMask;
-        QueryNode_SetFieldMask(yylhsminor.yy119, yylhsminor.yy119->opts.fieldMask);
+        // Handle yymsp[0].minor.yy19
+        QueryNode_AddChild(yylhsminor.yy19, yymsp[0].minor.yy19);
+        yylhsminor.yy19->opts.fieldMask |= yymsp[0].minor.yy19->opts.fieldMask;
+        QueryNode_SetFieldMask(yylhsminor.yy19, yylhsminor.yy19->opts.fieldMask);
     }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
       case 10: /* union ::= union OR expr */
       case 15: /* text_union ::= text_union OR text_expr */ yytestcase(yyruleno==15);
 {
-    yylhsminor.yy119 = yymsp[-2].minor.yy119;
-    if (yymsp[0].minor.yy119) {
-        QueryNode_AddChild(yylhsminor.yy119, yymsp[0].minor.yy119);
-        yylhsminor.yy119->opts.fieldMask |= yymsp[0].minor.yy119->opts.fieldMask;
-        QueryNode_SetFieldMask(yymsp[0].minor.yy119, yylhsminor.yy119->opts.fieldMask);
+    yylhsminor.yy19 = yymsp[-2].minor.yy19;
+    if (yymsp[0].minor.yy19) {
+        QueryNode_AddChild(yylhsminor.yy19, yymsp[0].minor.yy19);
+        yylhsminor.yy19->opts.fieldMask |= yymsp[0].minor.yy19->opts.fieldMask;
+        QueryNode_SetFieldMask(yymsp[0].minor.yy19, yylhsminor.yy19->opts.fieldMask);
     }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
       case 16: /* expr ::= modifier COLON text_expr */
 {
-    if (yymsp[0].minor.yy119 == NULL) {
-        yylhsminor.yy119 = NULL;
+    const FieldSpec *fs = NULL;
+    if (yymsp[0].minor.yy19 && ctx->sctx->spec) {
+        fs = IndexSpec_GetField(ctx->sctx->spec, yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
+    }
+    if (yymsp[0].minor.yy19 == NULL) {
+        yylhsminor.yy19 = NULL;
+    } else if (fs && FIELD_IS(fs, INDEXFLD_T_BOOLEAN)) {
+        // Boolean fields are queried with a single `true`/`false` value, as a tag node.
+        // Parameterized values are validated once the parameters are evaluated
+        int val;
+        if (yymsp[0].minor.yy19->type != QN_TOKEN ||
+            (yymsp[0].minor.yy19->tn.str && !FieldSpec_ParseBoolean(yymsp[0].minor.yy19->tn.str, yymsp[0].minor.yy19->tn.len, &val))) {
+            QueryError_SetErrorFmt(ctx->status, QUERY_ESYNTAX,
+                                   "Invalid boolean value for field `%.*s`", (int)yymsp[-2].minor.yy0.len, yymsp[-2].minor.yy0.s);
+            QueryNode_Free(yymsp[0].minor.yy19);
+            yylhsminor.yy19 = NULL;
+        } else {
+            if (yymsp[0].minor.yy19->tn.str) {
+                rm_free(yymsp[0].minor.yy19->tn.str);
+                yymsp[0].minor.yy19->tn.str = rm_strdup(val ? BOOLEAN_FIELD_TRUE_STR : BOOLEAN_FIELD_FALSE_STR);
+                yymsp[0].minor.yy19->tn.len = strlen(yymsp[0].minor.yy19->tn.str);
+            }
+            yylhsminor.yy19 = NewTagNode(rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len), yymsp[-2].minor.yy0.len);
+            QueryNode_AddChild(yylhsminor.yy19, yymsp[0].minor.yy19);
+        }
     } else {
         if (ctx->sctx->spec) {
-            QueryNode_SetFieldMask(yymsp[0].minor.yy119, IndexSpec_GetFieldBit(ctx->sctx->spec, yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len));
+            QueryNode_SetFieldMask(yymsp[0].minor.yy19, IndexSpec_GetFieldBit(ctx->sctx->spec, yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len));
         }
-        yylhsminor.yy119 = yymsp[0].minor.yy119;
+        yylhsminor.yy19 = yymsp[0].minor.yy19;
     }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
       case 17: /* expr ::= modifierlist COLON text_expr */
 {
 
-    if (yymsp[0].minor.yy119 == NULL) {
-        for (size_t i = 0; i < Vector_Size(yymsp[-2].minor.yy42); i++) {
+    if (yymsp[0].minor.yy19 == NULL) {
+        for (size_t i = 0; i < Vector_Size(yymsp[-2].minor.yy27); i++) {
           char *s;
-          Vector_Get(yymsp[-2].minor.yy42, i, &s);
+          Vector_Get(yymsp[-2].minor.yy27, i, &s);
           rm_free(s);
         }
-        Vector_Free(yymsp[-2].minor.yy42);
-        yylhsminor.yy119 = NULL;
+        Vector_Free(yymsp[-2].minor.yy27);
+        yylhsminor.yy19 = NULL;
     } else {
-        //yymsp[0].minor.yy119->opts.fieldMask = 0;
+        //yymsp[0].minor.yy19->opts.fieldMask = 0;
         t_fieldMask mask = 0;
-        for (int i = 0; i < Vector_Size(yymsp[-2].minor.yy42); i++) {
+        for (int i = 0; i < Vector_Size(yymsp[-2].minor.yy27); i++) {
             char *p;
-            Vector_Get(yymsp[-2].minor.yy42, i, &p);
+            Vector_Get(yymsp[-2].minor.yy27, i, &p);
             if (ctx->sctx->spec) {
               mask |= IndexSpec_GetFieldBit(ctx->sctx->spec, p, strlen(p));
             }
             rm_free(p);
         }
-        Vector_Free(yymsp[-2].minor.yy42);
-        QueryNode_SetFieldMask(yymsp[0].minor.yy119, mask);
-        yylhsminor.yy119=yymsp[0].minor.yy119;
+        Vector_Free(yymsp[-2].minor.yy27);
+        QueryNode_SetFieldMask(yymsp[0].minor.yy19, mask);
+        yylhsminor.yy19=yymsp[0].minor.yy19;
     }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
       case 18: /* expr ::= LP expr RP */
       case 19: /* text_expr ::= LP text_expr RP */ yytestcase(yyruleno==19);
 {
-  yymsp[-2].minor.yy119 = yymsp[-1].minor.yy119;
+  yymsp[-2].minor.yy19 = yymsp[-1].minor.yy19;
 }
         break;
       case 20: /* attribute ::= ATTRIBUTE COLON param_term */
@@ -1699,60 +1833,60 @@ static YYACTIONTYPE yy_reduce(
       value_len = found_value_len;
     }
   }
-  yylhsminor.yy43 = (QueryAttribute){ .name = yymsp[-2].minor.yy0.s, .namelen = yymsp[-2].minor.yy0.len, .value = value, .vallen = value_len };
+  yylhsminor.yy23 = (QueryAttribute){ .name = yymsp[-2].minor.yy0.s, .namelen = yymsp[-2].minor.yy0.len, .value = value, .vallen = value_len };
 }
-  yymsp[-2].minor.yy43 = yylhsminor.yy43;
+  yymsp[-2].minor.yy23 = yylhsminor.yy23;
         break;
       case 21: /* attribute_list ::= attribute */
 {
-  yylhsminor.yy45 = array_new(QueryAttribute, 2);
-  yylhsminor.yy45 = array_append(yylhsminor.yy45, yymsp[0].minor.yy43);
+  yylhsminor.yy105 = array_new(QueryAttribute, 2);
+  yylhsminor.yy105 = array_append(yylhsminor.yy105, yymsp[0].minor.yy23);
 }
-  yymsp[0].minor.yy45 = yylhsminor.yy45;
+  yymsp[0].minor.yy105 = yylhsminor.yy105;
         break;
       case 22: /* attribute_list ::= attribute_list SEMICOLON attribute */
 {
-  yylhsminor.yy45 = array_append(yymsp[-2].minor.yy45, yymsp[0].minor.yy43);
+  yylhsminor.yy105 = array_append(yymsp[-2].minor.yy105, yymsp[0].minor.yy23);
 }
-  yymsp[-2].minor.yy45 = yylhsminor.yy45;
+  yymsp[-2].minor.yy105 = yylhsminor.yy105;
         break;
       case 23: /* attribute_list ::= attribute_list SEMICOLON */
 {
-  yylhsminor.yy45 = yymsp[-1].minor.yy45;
+  yylhsminor.yy105 = yymsp[-1].minor.yy105;
 }
-  yymsp[-1].minor.yy45 = yylhsminor.yy45;
+  yymsp[-1].minor.yy105 = yylhsminor.yy105;
         break;
       case 24: /* attribute_list ::= */
 {
-  yymsp[1].minor.yy45 = NULL;
+  yymsp[1].minor.yy105 = NULL;
 }
         break;
       case 25: /* expr ::= expr ARROW LB attribute_list RB */
       case 26: /* text_expr ::= text_expr ARROW LB attribute_list RB */ yytestcase(yyruleno==26);
 {
 
-    if (yymsp[-4].minor.yy119 && yymsp[-1].minor.yy45) {
-        QueryNode_ApplyAttributes(yymsp[-4].minor.yy119, yymsp[-1].minor.yy45, array_len(yymsp[-1].minor.yy45), ctx->status);
+    if (yymsp[-4].minor.yy19 && yymsp[-1].minor.yy105) {
+        QueryNode_ApplyAttributes(yymsp[-4].minor.yy19, yymsp[-1].minor.yy105, array_len(yymsp[-1].minor.yy105), ctx->status);
     }
-    array_free_ex(yymsp[-1].minor.yy45, rm_free((char*)((QueryAttribute*)ptr )->value));
-    yylhsminor.yy119 = yymsp[-4].minor.yy119;
+    array_free_ex(yymsp[-1].minor.yy105, rm_free((char*)((QueryAttribute*)ptr )->value));
+    yylhsminor.yy19 = yymsp[-4].minor.yy19;
 }
-  yymsp[-4].minor.yy119 = yylhsminor.yy119;
+  yymsp[-4].minor.yy19 = yylhsminor.yy19;
         break;
       case 27: /* text_expr ::= QUOTE termlist QUOTE */
 {
   // TODO: Quoted/verbatim string in termlist should not be handled as parameters
   // Also need to add the leading '$' which was consumed by the lexer
-  yymsp[-1].minor.yy119->pn.exact = 1;
-  yymsp[-1].minor.yy119->opts.flags |= QueryNode_Verbatim;
+  yymsp[-1].minor.yy19->pn.exact = 1;
+  yymsp[-1].minor.yy19->opts.flags |= QueryNode_Verbatim;
 
-  yymsp[-2].minor.yy119 = yymsp[-1].minor.yy119;
+  yymsp[-2].minor.yy19 = yymsp[-1].minor.yy19;
 }
         break;
       case 28: /* text_expr ::= QUOTE term QUOTE */
 {
-  yymsp[-2].minor.yy119 = NewTokenNode(ctx, rm_strdupcase(yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len), -1);
-  yymsp[-2].minor.yy119->opts.flags |= QueryNode_Verbatim;
+  yymsp[-2].minor.yy19 = NewTokenNode(ctx, rm_strdupcase(yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len), -1);
+  yymsp[-2].minor.yy19->opts.flags |= QueryNode_Verbatim;
 }
         break;
       case 29: /* text_expr ::= QUOTE ATTRIBUTE QUOTE */
@@ -1762,102 +1896,102 @@ static YYACTIONTYPE yy_reduce(
   char *s = rm_malloc(yymsp[-1].minor.yy0.len + 1);
   *s = '$';
   memcpy(s + 1, yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len);
-  yymsp[-2].minor.yy119 = NewTokenNode(ctx, rm_strdupcase(s, yymsp[-1].minor.yy0.len + 1), -1);
+  yymsp[-2].minor.yy19 = NewTokenNode(ctx, rm_strdupcase(s, yymsp[-1].minor.yy0.len + 1), -1);
   rm_free(s);
-  yymsp[-2].minor.yy119->opts.flags |= QueryNode_Verbatim;
+  yymsp[-2].minor.yy19->opts.flags |= QueryNode_Verbatim;
 }
         break;
       case 30: /* text_expr ::= param_term */
 {
   if (yymsp[0].minor.yy0.type == QT_TERM && StopWordList_Contains(ctx->opts->stopwords, yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len)) {
-    yylhsminor.yy119 = NULL;
+    yylhsminor.yy19 = NULL;
   } else {
-    yylhsminor.yy119 = NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0);
+    yylhsminor.yy19 = NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0);
   }
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 31: /* text_expr ::= affix */
       case 32: /* text_expr ::= verbatim */ yytestcase(yyruleno==32);
 {
-yylhsminor.yy119 = yymsp[0].minor.yy119;
+yylhsminor.yy19 = yymsp[0].minor.yy19;
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 33: /* termlist ::= param_term param_term */
 {
-  yylhsminor.yy119 = NewPhraseNode(0);
-  QueryNode_AddChild(yylhsminor.yy119, NewTokenNode_WithParams(ctx, &yymsp[-1].minor.yy0));
-  QueryNode_AddChild(yylhsminor.yy119, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
+  yylhsminor.yy19 = NewPhraseNode(0);
+  QueryNode_AddChild(yylhsminor.yy19, NewTokenNode_WithParams(ctx, &yymsp[-1].minor.yy0));
+  QueryNode_AddChild(yylhsminor.yy19, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
 }
-  yymsp[-1].minor.yy119 = yylhsminor.yy119;
+  yymsp[-1].minor.yy19 = yylhsminor.yy19;
         break;
       case 34: /* termlist ::= termlist param_term */
 {
-    yylhsminor.yy119 = yymsp[-1].minor.yy119;
+    yylhsminor.yy19 = yymsp[-1].minor.yy19;
     if (!(yymsp[0].minor.yy0.type == QT_TERM && StopWordList_Contains(ctx->opts->stopwords, yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len))) {
-       QueryNode_AddChild(yylhsminor.yy119, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
+       QueryNode_AddChild(yylhsminor.yy19, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
     }
 }
-  yymsp[-1].minor.yy119 = yylhsminor.yy119;
+  yymsp[-1].minor.yy19 = yylhsminor.yy19;
         break;
       case 35: /* expr ::= MINUS expr */
       case 36: /* text_expr ::= MINUS text_expr */ yytestcase(yyruleno==36);
 {
-    if (yymsp[0].minor.yy119) {
-        yymsp[-1].minor.yy119 = NewNotNode(yymsp[0].minor.yy119);
+    if (yymsp[0].minor.yy19) {
+        yymsp[-1].minor.yy19 = NewNotNode(yymsp[0].minor.yy19);
     } else {
-        yymsp[-1].minor.yy119 = NULL;
+        yymsp[-1].minor.yy19 = NULL;
     }
 }
         break;
       case 37: /* expr ::= TILDE expr */
       case 38: /* text_expr ::= TILDE text_expr */ yytestcase(yyruleno==38);
 {
-    if (yymsp[0].minor.yy119) {
-        yymsp[-1].minor.yy119 = NewOptionalNode(yymsp[0].minor.yy119);
+    if (yymsp[0].minor.yy19) {
+        yymsp[-1].minor.yy19 = NewOptionalNode(yymsp[0].minor.yy19);
     } else {
-        yymsp[-1].minor.yy119 = NULL;
+        yymsp[-1].minor.yy19 = NULL;
     }
 }
         break;
       case 39: /* affix ::= PREFIX */
 {
-    yylhsminor.yy119 = NewPrefixNode_WithParams(ctx, &yymsp[0].minor.yy0, true, false);
+    yylhsminor.yy19 = NewPrefixNode_WithParams(ctx, &yymsp[0].minor.yy0, true, false);
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 40: /* affix ::= SUFFIX */
 {
-    yylhsminor.yy119 = NewPrefixNode_WithParams(ctx, &yymsp[0].minor.yy0, false, true);
+    yylhsminor.yy19 = NewPrefixNode_WithParams(ctx, &yymsp[0].minor.yy0, false, true);
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 41: /* affix ::= CONTAINS */
 {
-    yylhsminor.yy119 = NewPrefixNode_WithParams(ctx, &yymsp[0].minor.yy0, true, true);
+    yylhsminor.yy19 = NewPrefixNode_WithParams(ctx, &yymsp[0].minor.yy0, true, true);
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 42: /* verbatim ::= WILDCARD */
 {
-    yylhsminor.yy119 = NewWildcardNode_WithParams(ctx, &yymsp[0].minor.yy0);
+    yylhsminor.yy19 = NewWildcardNode_WithParams(ctx, &yymsp[0].minor.yy0);
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 43: /* text_expr ::= PERCENT param_term PERCENT */
 {
-  yymsp[-2].minor.yy119 = NewFuzzyNode_WithParams(ctx, &yymsp[-1].minor.yy0, 1);
+  yymsp[-2].minor.yy19 = NewFuzzyNode_WithParams(ctx, &yymsp[-1].minor.yy0, 1);
 }
         break;
       case 44: /* text_expr ::= PERCENT PERCENT param_term PERCENT PERCENT */
 {
-  yymsp[-4].minor.yy119 = NewFuzzyNode_WithParams(ctx, &yymsp[-2].minor.yy0, 2);
+  yymsp[-4].minor.yy19 = NewFuzzyNode_WithParams(ctx, &yymsp[-2].minor.yy0, 2);
 }
         break;
       case 45: /* text_expr ::= PERCENT PERCENT PERCENT param_term PERCENT PERCENT PERCENT */
 {
-  yymsp[-6].minor.yy119 = NewFuzzyNode_WithParams(ctx, &yymsp[-3].minor.yy0, 3);
+  yymsp[-6].minor.yy19 = NewFuzzyNode_WithParams(ctx, &yymsp[-3].minor.yy0, 3);
 }
         break;
       case 46: /* modifier ::= MODIFIER */
@@ -1869,60 +2003,60 @@ yylhsminor.yy119 = yymsp[0].minor.yy119;
         break;
       case 47: /* modifierlist ::= modifier OR term */
 {
-    yylhsminor.yy42 = NewVector(char *, 2);
+    yylhsminor.yy27 = NewVector(char *, 2);
     char *s = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
-    Vector_Push(yylhsminor.yy42, s);
+    Vector_Push(yylhsminor.yy27, s);
     s = rm_strndup(yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len);
-    Vector_Push(yylhsminor.yy42, s);
+    Vector_Push(yylhsminor.yy27, s);
 }
-  yymsp[-2].minor.yy42 = yylhsminor.yy42;
+  yymsp[-2].minor.yy27 = yylhsminor.yy27;
         break;
       case 48: /* modifierlist ::= modifierlist OR term */
 {
     char *s = rm_strndup(yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len);
-    Vector_Push(yymsp[-2].minor.yy42, s);
-    yylhsminor.yy42 = yymsp[-2].minor.yy42;
+    Vector_Push(yymsp[-2].minor.yy27, s);
+    yylhsminor.yy27 = yymsp[-2].minor.yy27;
 }
-  yymsp[-2].minor.yy42 = yylhsminor.yy42;
+  yymsp[-2].minor.yy27 = yylhsminor.yy27;
         break;
       case 49: /* expr ::= modifier COLON LB tag_list RB */
 {
-    if (!yymsp[-1].minor.yy119) {
-        yylhsminor.yy119 = NULL;
+    if (!yymsp[-1].minor.yy19) {
+        yylhsminor.yy19 = NULL;
     } else {
       // Tag field names must be case sensitive, we can't do rm_strdupcase
         char *s = rm_strndup(yymsp[-4].minor.yy0.s, yymsp[-4].minor.yy0.len);
         size_t slen = unescapen((char*)s, yymsp[-4].minor.yy0.len);
 
-        yylhsminor.yy119 = NewTagNode(s, slen);
-        QueryNode_AddChildren(yylhsminor.yy119, yymsp[-1].minor.yy119->children, QueryNode_NumChildren(yymsp[-1].minor.yy119));
+        yylhsminor.yy19 = NewTagNode(s, slen);
+        QueryNode_AddChildren(yylhsminor.yy19, yymsp[-1].minor.yy19->children, QueryNode_NumChildren(yymsp[-1].minor.yy19));
 
-        // Set the children count on yymsp[-1].minor.yy119 to 0 so they won't get recursively free'd
-        QueryNode_ClearChildren(yymsp[-1].minor.yy119, 0);
-        QueryNode_Free(yymsp[-1].minor.yy119);
+        // Set the children count on yymsp[-1].minor.yy19 to 0 so they won't get recursively free'd
+        QueryNode_ClearChildren(yymsp[-1].minor.yy19, 0);
+        QueryNode_Free(yymsp[-1].minor.yy19);
     }
 }
-  yymsp[-4].minor.yy119 = yylhsminor.yy119;
+  yymsp[-4].minor.yy19 = yylhsminor.yy19;
         break;
       case 50: /* tag_list ::= param_term */
 {
-  yylhsminor.yy119 = NewPhraseNode(0);
+  yylhsminor.yy19 = NewPhraseNode(0);
   if (yymsp[0].minor.yy0.type == QT_TERM)
     yymsp[0].minor.yy0.type = QT_TERM_CASE;
   else if (yymsp[0].minor.yy0.type == QT_PARAM_TERM)
     yymsp[0].minor.yy0.type = QT_PARAM_TERM_CASE;
-  QueryNode_AddChild(yylhsminor.yy119, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
+  QueryNode_AddChild(yylhsminor.yy19, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 51: /* tag_list ::= affix */
       case 52: /* tag_list ::= verbatim */ yytestcase(yyruleno==52);
       case 53: /* tag_list ::= termlist */ yytestcase(yyruleno==53);
 {
-    yylhsminor.yy119 = NewPhraseNode(0);
-    QueryNode_AddChild(yylhsminor.yy119, yymsp[0].minor.yy119);
+    yylhsminor.yy19 = NewPhraseNode(0);
+    QueryNode_AddChild(yylhsminor.yy19, yymsp[0].minor.yy19);
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
       case 54: /* tag_list ::= tag_list OR param_term */
 {
@@ -1930,33 +2064,57 @@ yylhsminor.yy119 = yymsp[0].minor.yy119;
     yymsp[0].minor.yy0.type = QT_TERM_CASE;
   else if (yymsp[0].minor.yy0.type == QT_PARAM_TERM)
     yymsp[0].minor.yy0.type = QT_PARAM_TERM_CASE;
-  QueryNode_AddChild(yymsp[-2].minor.yy119, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
-  yylhsminor.yy119 = yymsp[-2].minor.yy119;
+  QueryNode_AddChild(yymsp[-2].minor.yy19, NewTokenNode_WithParams(ctx, &yymsp[0].minor.yy0));
+  yylhsminor.yy19 = yymsp[-2].minor.yy19;
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
       case 55: /* tag_list ::= tag_list OR affix */
       case 56: /* tag_list ::= tag_list OR verbatim */ yytestcase(yyruleno==56);
       case 57: /* tag_list ::= tag_list OR termlist */ yytestcase(yyruleno==57);
 {
-    QueryNode_AddChild(yymsp[-2].minor.yy119, yymsp[0].minor.yy119);
-    yylhsminor.yy119 = yymsp[-2].minor.yy119;
+    QueryNode_AddChild(yymsp[-2].minor.yy19, yymsp[0].minor.yy19);
+    yylhsminor.yy19 = yymsp[-2].minor.yy19;
+}
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
+        break;
+      case 58: /* expr ::= ISMISSING LP modifier RP */
+{
+    const FieldSpec *fs = ctx->sctx->spec ? IndexSpec_GetField(ctx->sctx->spec, yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len) : NULL;
+    if (ctx->sctx->spec && (!fs || !FieldSpec_IndexesMissing(fs))) {
+        QueryError_SetErrorFmt(ctx->status, QUERY_EMISSING,
+            "'ismissing' requires field '%.*s' to be defined with 'INDEXMISSING'", (int)yymsp[-1].minor.yy0.len, yymsp[-1].minor.yy0.s);
+        yymsp[-3].minor.yy19 = NULL;
+    } else {
+        yymsp[-3].minor.yy19 = NewMissingNode(rm_strndup(yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len), yymsp[-1].minor.yy0.len);
+    }
+}
+        break;
+      case 59: /* expr ::= modifier COLON LB QUOTE QUOTE RB */
+{
+    yylhsminor.yy19 = newIsEmptyNode(ctx, &yymsp[-5].minor.yy0, INDEXFLD_T_TAG, SPEC_TAG_STR);
+}
+  yymsp[-5].minor.yy19 = yylhsminor.yy19;
+        break;
+      case 60: /* expr ::= modifier COLON QUOTE QUOTE */
+{
+    yylhsminor.yy19 = newIsEmptyNode(ctx, &yymsp[-3].minor.yy0, INDEXFLD_T_FULLTEXT, SPEC_TEXT_STR);
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-3].minor.yy19 = yylhsminor.yy19;
         break;
-      case 58: /* expr ::= modifier COLON numeric_range */
+      case 61: /* expr ::= modifier COLON numeric_range */
 {
-  if (yymsp[0].minor.yy62) {
+  if (yymsp[0].minor.yy134) {
     // we keep the capitalization as is
-    yymsp[0].minor.yy62->nf->fieldName = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
-    yylhsminor.yy119 = NewNumericNode(yymsp[0].minor.yy62);
+    yymsp[0].minor.yy134->nf->fieldName = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
+    yylhsminor.yy19 = NewNumericNode(yymsp[0].minor.yy134);
   } else {
-    yylhsminor.yy119 = NewQueryNode(QN_NULL);
+    yylhsminor.yy19 = NewQueryNode(QN_NULL);
   }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
-      case 59: /* numeric_range ::= LSQB param_num param_num RSQB */
+      case 62: /* numeric_range ::= LSQB param_num param_num RSQB */
 {
   if (yymsp[-2].minor.yy0.type == QT_PARAM_NUMERIC) {
     yymsp[-2].minor.yy0.type = QT_PARAM_NUMERIC_MIN_RANGE;
@@ -1964,22 +2122,28 @@ yylhsminor.yy119 = yymsp[0].minor.yy119;
   if (yymsp[-1].minor.yy0.type == QT_PARAM_NUMERIC) {
     yymsp[-1].minor.yy0.type = QT_PARAM_NUMERIC_MAX_RANGE;
   }
-  yymsp[-3].minor.yy62 = NewNumericFilterQueryParam_WithParams(ctx, &yymsp[-2].minor.yy0, &yymsp[-1].minor.yy0, yymsp[-2].minor.yy0.inclusive, yymsp[-1].minor.yy0.inclusive);
+  yymsp[-3].minor.yy134 = NewNumericFilterQueryParam_WithParams(ctx, &yymsp[-2].minor.yy0, &yymsp[-1].minor.yy0, yymsp[-2].minor.yy0.inclusive, yymsp[-1].minor.yy0.inclusive);
 }
         break;
-      case 60: /* expr ::= modifier COLON geo_filter */
+      case 63: /* expr ::= modifier COLON DATE_RANGE */
 {
-  if (yymsp[0].minor.yy62) {
+  yylhsminor.yy19 = newDateRangeNode(ctx, &yymsp[-2].minor.yy0, &yymsp[0].minor.yy0);
+}
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
+        break;
+      case 64: /* expr ::= modifier COLON geo_filter */
+{
+  if (yymsp[0].minor.yy134) {
     // we keep the capitalization as is
-    yymsp[0].minor.yy62->gf->property = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
-    yylhsminor.yy119 = NewGeofilterNode(yymsp[0].minor.yy62);
+    yymsp[0].minor.yy134->gf->property = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
+    yylhsminor.yy19 = NewGeofilterNode(yymsp[0].minor.yy134);
   } else {
-    yylhsminor.yy119 = NewQueryNode(QN_NULL);
+    yylhsminor.yy19 = NewQueryNode(QN_NULL);
   }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
-      case 61: /* geo_filter ::= LSQB param_num param_num param_num param_term RSQB */
+      case 65: /* geo_filter ::= LSQB param_num param_num param_num param_term RSQB */
 {
   if (yymsp[-4].minor.yy0.type == QT_PARAM_NUMERIC)
     yymsp[-4].minor.yy0.type = QT_PARAM_GEO_COORD;
@@ -1989,256 +2153,256 @@ yylhsminor.yy119 = yymsp[0].minor.yy119;
   if (yymsp[-1].minor.yy0.type == QT_PARAM_TERM)
     yymsp[-1].minor.yy0.type = QT_PARAM_GEO_UNIT;
 
-  yymsp[-5].minor.yy62 = NewGeoFilterQueryParam_WithParams(ctx, &yymsp[-4].minor.yy0, &yymsp[-3].minor.yy0, &yymsp[-2].minor.yy0, &yymsp[-1].minor.yy0);
+  yymsp[-5].minor.yy134 = NewGeoFilterQueryParam_WithParams(ctx, &yymsp[-4].minor.yy0, &yymsp[-3].minor.yy0, &yymsp[-2].minor.yy0, &yymsp[-1].minor.yy0);
 }
         break;
-      case 62: /* expr ::= modifier COLON geometry_query */
+      case 66: /* expr ::= modifier COLON geometry_query */
 {
-  if (yymsp[0].minor.yy119) {
+  if (yymsp[0].minor.yy19) {
     // we keep the capitalization as is
-    yymsp[0].minor.yy119->gmn.geomq->attr = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
-    yylhsminor.yy119 = yymsp[0].minor.yy119;
+    yymsp[0].minor.yy19->gmn.geomq->attr = rm_strndup(yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len);
+    yylhsminor.yy19 = yymsp[0].minor.yy19;
   } else {
-    yylhsminor.yy119 = NewQueryNode(QN_NULL);
+    yylhsminor.yy19 = NewQueryNode(QN_NULL);
   }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
-      case 63: /* geometry_query ::= NAMED_PREDICATE */
+      case 67: /* geometry_query ::= NAMED_PREDICATE */
 {
-  yylhsminor.yy119 = NewGeometryNode_FromWkt(yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len);
+  yylhsminor.yy19 = NewGeometryNode_WithParams(ctx, yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len);
 }
-  yymsp[0].minor.yy119 = yylhsminor.yy119;
+  yymsp[0].minor.yy19 = yylhsminor.yy19;
         break;
-      case 64: /* query ::= expr ARROW LSQB vector_query RSQB */
-      case 65: /* query ::= text_expr ARROW LSQB vector_query RSQB */ yytestcase(yyruleno==65);
+      case 68: /* query ::= expr ARROW LSQB vector_query RSQB */
+      case 69: /* query ::= text_expr ARROW LSQB vector_query RSQB */ yytestcase(yyruleno==69);
 { // main parse, hybrid query as entire query case.
   setup_trace(ctx);
-  RS_LOG_ASSERT(yymsp[-1].minor.yy119->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
-  ctx->root = yymsp[-1].minor.yy119;
-  if (yymsp[-4].minor.yy119) {
-    QueryNode_AddChild(yymsp[-1].minor.yy119, yymsp[-4].minor.yy119);
+  RS_LOG_ASSERT(yymsp[-1].minor.yy19->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
+  ctx->root = yymsp[-1].minor.yy19;
+  if (yymsp[-4].minor.yy19) {
+    QueryNode_AddChild(yymsp[-1].minor.yy19, yymsp[-4].minor.yy19);
   }
 }
         break;
-      case 66: /* query ::= star ARROW LSQB vector_query RSQB */
-{  yy_destructor(yypParser,58,&yymsp[-4].minor);
+      case 70: /* query ::= star ARROW LSQB vector_query RSQB */
+{  yy_destructor(yypParser,60,&yymsp[-4].minor);
 { // main parse, simple vecsim search as entire query case.
   setup_trace(ctx);
-  RS_LOG_ASSERT(yymsp[-1].minor.yy119->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
-  yymsp[-1].minor.yy119->vn.vq->knn.order = BY_SCORE;
+  RS_LOG_ASSERT(yymsp[-1].minor.yy19->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
+  yymsp[-1].minor.yy19->vn.vq->knn.order = BY_SCORE;
 
-  ctx->root = yymsp[-1].minor.yy119;
+  ctx->root = yymsp[-1].minor.yy19;
 }
 }
         break;
-      case 67: /* vector_query ::= vector_command vector_attribute_list vector_score_field */
+      case 71: /* vector_query ::= vector_command vector_attribute_list vector_score_field */
 {
-  if (yymsp[-2].minor.yy119->vn.vq->scoreField) {
-    rm_free(yymsp[-2].minor.yy119->vn.vq->scoreField);
-    yymsp[-2].minor.yy119->vn.vq->scoreField = NULL;
+  if (yymsp[-2].minor.yy19->vn.vq->scoreField) {
+    rm_free(yymsp[-2].minor.yy19->vn.vq->scoreField);
+    yymsp[-2].minor.yy19->vn.vq->scoreField = NULL;
   }
-  yymsp[-2].minor.yy119->params = array_grow(yymsp[-2].minor.yy119->params, 1);
-  memset(&array_tail(yymsp[-2].minor.yy119->params), 0, sizeof(*yymsp[-2].minor.yy119->params));
-  QueryNode_SetParam(ctx, &(array_tail(yymsp[-2].minor.yy119->params)), &(yymsp[-2].minor.yy119->vn.vq->scoreField), NULL, &yymsp[0].minor.yy0);
-  yymsp[-2].minor.yy119->vn.vq->params = yymsp[-1].minor.yy2;
-  yylhsminor.yy119 = yymsp[-2].minor.yy119;
+  yymsp[-2].minor.yy19->params = array_grow(yymsp[-2].minor.yy19->params, 1);
+  memset(&array_tail(yymsp[-2].minor.yy19->params), 0, sizeof(*yymsp[-2].minor.yy19->params));
+  QueryNode_SetParam(ctx, &(array_tail(yymsp[-2].minor.yy19->params)), &(yymsp[-2].minor.yy19->vn.vq->scoreField), NULL, &yymsp[0].minor.yy0);
+  yymsp[-2].minor.yy19->vn.vq->params = yymsp[-1].minor.yy26;
+  yylhsminor.yy19 = yymsp[-2].minor.yy19;
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
-      case 68: /* vector_query ::= vector_command vector_score_field */
+      case 72: /* vector_query ::= vector_command vector_score_field */
 {
-  if (yymsp[-1].minor.yy119->vn.vq->scoreField) {
-    rm_free(yymsp[-1].minor.yy119->vn.vq->scoreField);
-    yymsp[-1].minor.yy119->vn.vq->scoreField = NULL;
+  if (yymsp[-1].minor.yy19->vn.vq->scoreField) {
+    rm_free(yymsp[-1].minor.yy19->vn.vq->scoreField);
+    yymsp[-1].minor.yy19->vn.vq->scoreField = NULL;
   }
-  yymsp[-1].minor.yy119->params = array_grow(yymsp[-1].minor.yy119->params, 1);
-  memset(&array_tail(yymsp[-1].minor.yy119->params), 0, sizeof(*yymsp[-1].minor.yy119->params));
-  QueryNode_SetParam(ctx, &(array_tail(yymsp[-1].minor.yy119->params)), &(yymsp[-1].minor.yy119->vn.vq->scoreField), NULL, &yymsp[0].minor.yy0);
-  yylhsminor.yy119 = yymsp[-1].minor.yy119;
+  yymsp[-1].minor.yy19->params = array_grow(yymsp[-1].minor.yy19->params, 1);
+  memset(&array_tail(yymsp[-1].minor.yy19->params), 0, sizeof(*yymsp[-1].minor.yy19->params));
+  QueryNode_SetParam(ctx, &(array_tail(yymsp[-1].minor.yy19->params)), &(yymsp[-1].minor.yy19->vn.vq->scoreField), NULL, &yymsp[0].minor.yy0);
+  yylhsminor.yy19 = yymsp[-1].minor.yy19;
 }
-  yymsp[-1].minor.yy119 = yylhsminor.yy119;
+  yymsp[-1].minor.yy19 = yylhsminor.yy19;
         break;
-      case 69: /* vector_query ::= vector_command vector_attribute_list */
+      case 73: /* vector_query ::= vector_command vector_attribute_list */
 {
-  yymsp[-1].minor.yy119->vn.vq->params = yymsp[0].minor.yy2;
-  yylhsminor.yy119 = yymsp[-1].minor.yy119;
+  yymsp[-1].minor.yy19->vn.vq->params = yymsp[0].minor.yy26;
+  yylhsminor.yy19 = yymsp[-1].minor.yy19;
 }
-  yymsp[-1].minor.yy119 = yylhsminor.yy119;
+  yymsp[-1].minor.yy19 = yylhsminor.yy19;
         break;
-      case 71: /* vector_score_field ::= as param_term */
-{  yy_destructor(yypParser,64,&yymsp[-1].minor);
+      case 75: /* vector_score_field ::= as param_term */
+{  yy_destructor(yypParser,66,&yymsp[-1].minor);
 {
   yymsp[-1].minor.yy0 = yymsp[0].minor.yy0;
 }
 }
         break;
-      case 72: /* query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+      case 76: /* query ::= expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
 {
   setup_trace(ctx);
-  RS_LOG_ASSERT(yymsp[-5].minor.yy119->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
-  ctx->root = yymsp[-5].minor.yy119;
-  if (yymsp[-5].minor.yy119 && yymsp[-1].minor.yy45) {
-     QueryNode_ApplyAttributes(yymsp[-5].minor.yy119, yymsp[-1].minor.yy45, array_len(yymsp[-1].minor.yy45), ctx->status);
+  RS_LOG_ASSERT(yymsp[-5].minor.yy19->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
+  ctx->root = yymsp[-5].minor.yy19;
+  if (yymsp[-5].minor.yy19 && yymsp[-1].minor.yy105) {
+     QueryNode_ApplyAttributes(yymsp[-5].minor.yy19, yymsp[-1].minor.yy105, array_len(yymsp[-1].minor.yy105), ctx->status);
   }
-  array_free_ex(yymsp[-1].minor.yy45, rm_free((char*)((QueryAttribute*)ptr )->value));
+  array_free_ex(yymsp[-1].minor.yy105, rm_free((char*)((QueryAttribute*)ptr )->value));
 
-  if (yymsp[-8].minor.yy119) {
-      QueryNode_AddChild(yymsp[-5].minor.yy119, yymsp[-8].minor.yy119);
+  if (yymsp[-8].minor.yy19) {
+      QueryNode_AddChild(yymsp[-5].minor.yy19, yymsp[-8].minor.yy19);
   }
 }
         break;
-      case 73: /* query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+      case 77: /* query ::= text_expr ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
 {
   setup_trace(ctx);
-  RS_LOG_ASSERT(yymsp[-5].minor.yy119->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
-  ctx->root = yymsp[-5].minor.yy119;
-  if (yymsp[-5].minor.yy119 && yymsp[-1].minor.yy45) {
-     QueryNode_ApplyAttributes(yymsp[-5].minor.yy119, yymsp[-1].minor.yy45, array_len(yymsp[-1].minor.yy45), ctx->status);
+  RS_LOG_ASSERT(yymsp[-5].minor.yy19->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
+  ctx->root = yymsp[-5].minor.yy19;
+  if (yymsp[-5].minor.yy19 && yymsp[-1].minor.yy105) {
+     QueryNode_ApplyAttributes(yymsp[-5].minor.yy19, yymsp[-1].minor.yy105, array_len(yymsp[-1].minor.yy105), ctx->status);
   }
-  array_free_ex(yymsp[-1].minor.yy45, rm_free((char*)((QueryAttribute*)ptr )->value));
+  array_free_ex(yymsp[-1].minor.yy105, rm_free((char*)((QueryAttribute*)ptr )->value));
 
-  if (yymsp[-8].minor.yy119) {
-    QueryNode_AddChild(yymsp[-5].minor.yy119, yymsp[-8].minor.yy119);
+  if (yymsp[-8].minor.yy19) {
+    QueryNode_AddChild(yymsp[-5].minor.yy19, yymsp[-8].minor.yy19);
   }
 }
         break;
-      case 74: /* query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
-{  yy_destructor(yypParser,58,&yymsp[-8].minor);
+      case 78: /* query ::= star ARROW LSQB vector_query RSQB ARROW LB attribute_list RB */
+{  yy_destructor(yypParser,60,&yymsp[-8].minor);
 {
   setup_trace(ctx);
-  RS_LOG_ASSERT(yymsp[-5].minor.yy119->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
-  yymsp[-5].minor.yy119->vn.vq->knn.order = BY_SCORE;
+  RS_LOG_ASSERT(yymsp[-5].minor.yy19->vn.vq->type == VECSIM_QT_KNN, "vector_query must be KNN");
+  yymsp[-5].minor.yy19->vn.vq->knn.order = BY_SCORE;
 
-  ctx->root = yymsp[-5].minor.yy119;
-  if (yymsp[-5].minor.yy119 && yymsp[-1].minor.yy45) {
-     QueryNode_ApplyAttributes(yymsp[-5].minor.yy119, yymsp[-1].minor.yy45, array_len(yymsp[-1].minor.yy45), ctx->status);
+  ctx->root = yymsp[-5].minor.yy19;
+  if (yymsp[-5].minor.yy19 && yymsp[-1].minor.yy105) {
+     QueryNode_ApplyAttributes(yymsp[-5].minor.yy19, yymsp[-1].minor.yy105, array_len(yymsp[-1].minor.yy105), ctx->status);
   }
-  array_free_ex(yymsp[-1].minor.yy45, rm_free((char*)((QueryAttribute*)ptr )->value));
+  array_free_ex(yymsp[-1].minor.yy105, rm_free((char*)((QueryAttribute*)ptr )->value));
 
 }
 }
         break;
-      case 75: /* vector_command ::= TERM param_size modifier ATTRIBUTE */
+      case 79: /* vector_command ::= TERM param_size modifier ATTRIBUTE */
 {
   if (!strncasecmp("KNN", yymsp[-3].minor.yy0.s, yymsp[-3].minor.yy0.len)) {
     yymsp[0].minor.yy0.type = QT_PARAM_VEC;
-    yylhsminor.yy119 = NewVectorNode_WithParams(ctx, VECSIM_QT_KNN, &yymsp[-2].minor.yy0, &yymsp[0].minor.yy0);
-    yylhsminor.yy119->vn.vq->property = rm_strndup(yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len);
-    RedisModule_Assert(-1 != (rm_asprintf(&yylhsminor.yy119->vn.vq->scoreField, "__%.*s_score", yymsp[-1].minor.yy0.len, yymsp[-1].minor.yy0.s)));
+    yylhsminor.yy19 = NewVectorNode_WithParams(ctx, VECSIM_QT_KNN, &yymsp[-2].minor.yy0, &yymsp[0].minor.yy0);
+    yylhsminor.yy19->vn.vq->property = rm_strndup(yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len);
+    RedisModule_Assert(-1 != (rm_asprintf(&yylhsminor.yy19->vn.vq->scoreField, "__%.*s_score", yymsp[-1].minor.yy0.len, yymsp[-1].minor.yy0.s)));
   } else {
     reportSyntaxError(ctx->status, &yymsp[-3].minor.yy0, "Syntax error: Expecting Vector Similarity command");
-    yylhsminor.yy119 = NULL;
+    yylhsminor.yy19 = NULL;
   }
 }
-  yymsp[-3].minor.yy119 = yylhsminor.yy119;
+  yymsp[-3].minor.yy19 = yylhsminor.yy19;
         break;
-      case 76: /* vector_attribute ::= TERM param_term */
+      case 80: /* vector_attribute ::= TERM param_term */
 {
   const char *value = rm_strndup(yymsp[0].minor.yy0.s, yymsp[0].minor.yy0.len);
   const char *name = rm_strndup(yymsp[-1].minor.yy0.s, yymsp[-1].minor.yy0.len);
-  yylhsminor.yy125.param = (VecSimRawParam){ .name = name, .nameLen = yymsp[-1].minor.yy0.len, .value = value, .valLen = yymsp[0].minor.yy0.len };
+  yylhsminor.yy45.param = (VecSimRawParam){ .name = name, .nameLen = yymsp[-1].minor.yy0.len, .value = value, .valLen = yymsp[0].minor.yy0.len };
   if (yymsp[0].minor.yy0.type == QT_PARAM_TERM) {
-    yylhsminor.yy125.needResolve = true;
+    yylhsminor.yy45.needResolve = true;
   }
   else { // if yymsp[0].minor.yy0.type == QT_TERM
-    yylhsminor.yy125.needResolve = false;
+    yylhsminor.yy45.needResolve = false;
   }
 }
-  yymsp[-1].minor.yy125 = yylhsminor.yy125;
+  yymsp[-1].minor.yy45 = yylhsminor.yy45;
         break;
-      case 77: /* vector_attribute_list ::= vector_attribute_list vector_attribute */
+      case 81: /* vector_attribute_list ::= vector_attribute_list vector_attribute */
 {
-  yylhsminor.yy2.params = array_append(yymsp[-1].minor.yy2.params, yymsp[0].minor.yy125.param);
-  yylhsminor.yy2.needResolve = array_append(yymsp[-1].minor.yy2.needResolve, yymsp[0].minor.yy125.needResolve);
+  yylhsminor.yy26.params = array_append(yymsp[-1].minor.yy26.params, yymsp[0].minor.yy45.param);
+  yylhsminor.yy26.needResolve = array_append(yymsp[-1].minor.yy26.needResolve, yymsp[0].minor.yy45.needResolve);
 }
-  yymsp[-1].minor.yy2 = yylhsminor.yy2;
+  yymsp[-1].minor.yy26 = yylhsminor.yy26;
         break;
-      case 78: /* vector_attribute_list ::= vector_attribute */
+      case 82: /* vector_attribute_list ::= vector_attribute */
 {
-  yylhsminor.yy2.params = array_new(VecSimRawParam, 1);
-  yylhsminor.yy2.needResolve = array_new(bool, 1);
-  yylhsminor.yy2.params = array_append(yylhsminor.yy2.params, yymsp[0].minor.yy125.param);
-  yylhsminor.yy2.needResolve = array_append(yylhsminor.yy2.needResolve, yymsp[0].minor.yy125.needResolve);
+  yylhsminor.yy26.params = array_new(VecSimRawParam, 1);
+  yylhsminor.yy26.needResolve = array_new(bool, 1);
+  yylhsminor.yy26.params = array_append(yylhsminor.yy26.params, yymsp[0].minor.yy45.param);
+  yylhsminor.yy26.needResolve = array_append(yylhsminor.yy26.needResolve, yymsp[0].minor.yy45.needResolve);
 }
-  yymsp[0].minor.yy2 = yylhsminor.yy2;
+  yymsp[0].minor.yy26 = yylhsminor.yy26;
         break;
-      case 79: /* expr ::= modifier COLON LSQB vector_range_command RSQB */
+      case 83: /* expr ::= modifier COLON LSQB vector_range_command RSQB */
 {
-    yymsp[-1].minor.yy119->vn.vq->property = rm_strndup(yymsp[-4].minor.yy0.s, yymsp[-4].minor.yy0.len);
-    yylhsminor.yy119 = yymsp[-1].minor.yy119;
+    yymsp[-1].minor.yy19->vn.vq->property = rm_strndup(yymsp[-4].minor.yy0.s, yymsp[-4].minor.yy0.len);
+    yylhsminor.yy19 = yymsp[-1].minor.yy19;
 }
-  yymsp[-4].minor.yy119 = yylhsminor.yy119;
+  yymsp[-4].minor.yy19 = yylhsminor.yy19;
         break;
-      case 80: /* vector_range_command ::= TERM param_num ATTRIBUTE */
+      case 84: /* vector_range_command ::= TERM param_num ATTRIBUTE */
 {
   if (!strncasecmp("VECTOR_RANGE", yymsp[-2].minor.yy0.s, yymsp[-2].minor.yy0.len)) {
     yymsp[0].minor.yy0.type = QT_PARAM_VEC;
-    yylhsminor.yy119 = NewVectorNode_WithParams(ctx, VECSIM_QT_RANGE, &yymsp[-1].minor.yy0, &yymsp[0].minor.yy0);
+    yylhsminor.yy19 = NewVectorNode_WithParams(ctx, VECSIM_QT_RANGE, &yymsp[-1].minor.yy0, &yymsp[0].minor.yy0);
   } else {
     reportSyntaxError(ctx->status, &yymsp[-2].minor.yy0, "Syntax error: expecting vector similarity range command");
-    yylhsminor.yy119 = NULL;
+    yylhsminor.yy19 = NULL;
   }
 }
-  yymsp[-2].minor.yy119 = yylhsminor.yy119;
+  yymsp[-2].minor.yy19 = yylhsminor.yy19;
         break;
-      case 81: /* num ::= SIZE */
-      case 82: /* num ::= NUMBER */ yytestcase(yyruleno==82);
+      case 85: /* num ::= SIZE */
+      case 86: /* num ::= NUMBER */ yytestcase(yyruleno==86);
 {
-  yylhsminor.yy47.num = yymsp[0].minor.yy0.numval;
-  yylhsminor.yy47.inclusive = 1;
+  yylhsminor.yy39.num = yymsp[0].minor.yy0.numval;
+  yylhsminor.yy39.inclusive = 1;
 }
-  yymsp[0].minor.yy47 = yylhsminor.yy47;
+  yymsp[0].minor.yy39 = yylhsminor.yy39;
         break;
-      case 83: /* num ::= LP num */
+      case 87: /* num ::= LP num */
 {
-  yymsp[-1].minor.yy47=yymsp[0].minor.yy47;
-  yymsp[-1].minor.yy47.inclusive = 0;
+  yymsp[-1].minor.yy39=yymsp[0].minor.yy39;
+  yymsp[-1].minor.yy39.inclusive = 0;
 }
         break;
-      case 84: /* num ::= MINUS num */
+      case 88: /* num ::= MINUS num */
 {
-  yymsp[0].minor.yy47.num = -yymsp[0].minor.yy47.num;
-  yymsp[-1].minor.yy47 = yymsp[0].minor.yy47;
+  yymsp[0].minor.yy39.num = -yymsp[0].minor.yy39.num;
+  yymsp[-1].minor.yy39 = yymsp[0].minor.yy39;
 }
         break;
-      case 85: /* term ::= TERM */
-      case 86: /* term ::= NUMBER */ yytestcase(yyruleno==86);
-      case 87: /* term ::= SIZE */ yytestcase(yyruleno==87);
+      case 89: /* term ::= TERM */
+      case 90: /* term ::= NUMBER */ yytestcase(yyruleno==90);
+      case 91: /* term ::= SIZE */ yytestcase(yyruleno==91);
 {
   yylhsminor.yy0 = yymsp[0].minor.yy0;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 88: /* param_term ::= term */
+      case 92: /* param_term ::= term */
 {
   yylhsminor.yy0 = yymsp[0].minor.yy0;
   yylhsminor.yy0.type = QT_TERM;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 89: /* param_term ::= ATTRIBUTE */
+      case 93: /* param_term ::= ATTRIBUTE */
 {
   yylhsminor.yy0 = yymsp[0].minor.yy0;
   yylhsminor.yy0.type = QT_PARAM_TERM;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 90: /* param_size ::= SIZE */
+      case 94: /* param_size ::= SIZE */
 {
   yylhsminor.yy0 = yymsp[0].minor.yy0;
   yylhsminor.yy0.type = QT_SIZE;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 91: /* param_size ::= ATTRIBUTE */
+      case 95: /* param_size ::= ATTRIBUTE */
 {
   yylhsminor.yy0 = yymsp[0].minor.yy0;
   yylhsminor.yy0.type = QT_PARAM_SIZE;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 92: /* param_num ::= ATTRIBUTE */
+      case 96: /* param_num ::= ATTRIBUTE */
 {
     yylhsminor.yy0 = yymsp[0].minor.yy0;
     yylhsminor.yy0.type = QT_PARAM_NUMERIC;
@@ -2246,29 +2410,29 @@ yylhsminor.yy119 = yymsp[0].minor.yy119;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 93: /* param_num ::= num */
+      case 97: /* param_num ::= num */
 {
-  yylhsminor.yy0.numval = yymsp[0].minor.yy47.num;
-  yylhsminor.yy0.inclusive = yymsp[0].minor.yy47.inclusive;
+  yylhsminor.yy0.numval = yymsp[0].minor.yy39.num;
+  yylhsminor.yy0.inclusive = yymsp[0].minor.yy39.inclusive;
   yylhsminor.yy0.type = QT_NUMERIC;
 }
   yymsp[0].minor.yy0 = yylhsminor.yy0;
         break;
-      case 94: /* param_num ::= LP ATTRIBUTE */
+      case 98: /* param_num ::= LP ATTRIBUTE */
 {
     yymsp[-1].minor.yy0 = yymsp[0].minor.yy0;
     yymsp[-1].minor.yy0.type = QT_PARAM_NUMERIC;
     yymsp[-1].minor.yy0.inclusive = 0;
 }
         break;
-      case 96: /* star ::= LP star RP */
+      case 100: /* star ::= LP star RP */
 {
 }
-  yy_destructor(yypParser,58,&yymsp[-1].minor);
+  yy_destructor(yypParser,60,&yymsp[-1].minor);
         break;
       default:
-      /* (95) star ::= STAR */ yytestcase(yyruleno==95);
-      /* (97) as ::= AS_T */ yytestcase(yyruleno==97);
+      /* (99) star ::= STAR */ yytestcase(yyruleno==99);
+      /* (101) as ::= AS_T */ yytestcase(yyruleno==101);
         break;
 /********** End reduce actions ************************************************/
   };
@@ -7,27 +7,29 @@
 #define RB                               7
 #define RSQB                             8
 #define TERM                             9
-#define QUOTE                           10
-#define LP                              11
-#define LB                              12
-#define LSQB                            13
-#define TILDE                           14
-#define MINUS                           15
-#define AND                             16
-#define ARROW                           17
-#define COLON                           18
-#define NUMBER                          19
-#define SIZE                            20
-#define STAR                            21
-#define TAGLIST                         22
-#define TERMLIST                        23
-#define PREFIX                          24
-#define SUFFIX                          25
-#define CONTAINS                        26
-#define PERCENT                         27
-#define ATTRIBUTE                       28
-#define VERBATIM                        29
-#define WILDCARD                        30
-#define NAMED_PREDICATE                 31
-#define AS_T                            32
-#define SEMICOLON                       33
+#define ISMISSING                       10
+#define QUOTE                           11
+#define LP                              12
+#define LB                              13
+#define LSQB                            14
+#define TILDE                           15
+#define MINUS                           16
+#define AND                             17
+#define ARROW                           18
+#define COLON                           19
+#define NUMBER                          20
+#define SIZE                            21
+#define STAR                            22
+#define TAGLIST                         23
+#define TERMLIST                        24
+#define PREFIX                          25
+#define SUFFIX                          26
+#define CONTAINS                        27
+#define PERCENT                         28
+#define ATTRIBUTE                       29
+#define VERBATIM                        30
+#define WILDCARD                        31
+#define NAMED_PREDICATE                 32
+#define DATE_RANGE                      33
+#define AS_T                            34
+#define SEMICOLON                       35
@@ -18,7 +18,7 @@
 
 %left RP RB RSQB.
 
-%left TERM.
+%left TERM ISMISSING.
 %left QUOTE.
 %left LP LB LSQB.
 
@@ -38,12 +38,13 @@
 %left PERCENT.
 %left ATTRIBUTE.
 %left VERBATIM WILDCARD.
-%left NAMED_PREDICATE.
+%left NAMED_PREDICATE DATE_RANGE.
 
 // Thanks to these fallback directives, Any "as" appearing in the query,
 // other than in a vector_query, Will either be considered as a term,
 // if "as" is not a stop-word, Or be considered as a stop-word if it is a stop-word.
-%fallback TERM AS_T.
+// The same goes for an "ismissing" which is not a function call.
+%fallback TERM AS_T ISMISSING.
 
 %token_type {QueryToken}
 
@@ -70,6 +71,7 @@
 #include <assert.h>
 
 #include "../parse.h"
+#include "../../date_field.h"
 
 // unescape a string (non null terminated) and return the new length (may be shorter than the original. This manipulates the string itself
 static size_t unescapen(char *s, size_t sz) {
@@ -131,6 +133,86 @@ static void reportSyntaxError(QueryError *status, QueryToken* tok, const char *m
   }
 }
 
+// Creates a node matching the empty values of the field `fieldTok`, which must be of type `type`
+// and be defined with INDEXEMPTY
+static QueryNode *newIsEmptyNode(QueryParseCtx *ctx, QueryToken *fieldTok, FieldType type,
+                                 const char *typeName) {
+  if (ctx->sctx->spec) {
+    const FieldSpec *fs = IndexSpec_GetField(ctx->sctx->spec, fieldTok->s, fieldTok->len);
+    if (!fs || !FIELD_IS(fs, type) || !FieldSpec_IndexesEmpty(fs)) {
+      QueryError_SetErrorFmt(ctx->status, QUERY_EEMPTY,
+        "Querying empty values requires field '%.*s' to be a %s field defined with 'INDEXEMPTY'",
+        (int)fieldTok->len, fieldTok->s, typeName);
+      return NULL;
+    }
+  }
+  return NewIsEmptyNode(rm_strndup(fieldTok->s, fieldTok->len), fieldTok->len);
+}
+
+// Parses the bound of a date range which starts at `*p`, and advances `*p` past it.
+// Parameters are resolved later, like the bounds of numeric ranges
+static int parseDateBound(QueryParseCtx *ctx, const char **p, const char *end, int isMax,
+                          double now, QueryToken *tok) {
+  const char *s = *p;
+  while (s < end && isspace((unsigned char)*s)) ++s;
+  const char *e = s;
+  while (e < end && !isspace((unsigned char)*e)) ++e;
+  *p = e;
+
+  tok->s = s;
+  tok->len = e - s;
+  tok->pos = s - ctx->raw;
+  tok->inclusive = 1;
+  if (*s == '(') {
+    tok->inclusive = 0;
+    ++s;
+  }
+  if (e - s > 1 && *s == '$') {
+    tok->type = isMax ? QT_PARAM_NUMERIC_MAX_RANGE : QT_PARAM_NUMERIC_MIN_RANGE;
+    tok->s = s + 1;
+    tok->len = e - s - 1;
+    return 1;
+  }
+  tok->type = QT_NUMERIC;
+  return DateField_ParseRangeBound(s, e - s, isMax, now, &tok->numval);
+}
+
+// Creates a numeric node for the range `[<from> TO <to>]` of the DATE field `fieldTok`.
+// `rangeTok` holds the text between the brackets
+static QueryNode *newDateRangeNode(QueryParseCtx *ctx, QueryToken *fieldTok, QueryToken *rangeTok) {
+  if (ctx->sctx->spec) {
+    const FieldSpec *fs = IndexSpec_GetField(ctx->sctx->spec, fieldTok->s, fieldTok->len);
+    if (!fs || !FIELD_IS(fs, INDEXFLD_T_DATE)) {
+      QueryError_SetErrorFmt(ctx->status, QUERY_ESYNTAX,
+        "Date ranges require field '%.*s' to be a %s field",
+        (int)fieldTok->len, fieldTok->s, SPEC_DATE_STR);
+      return NULL;
+    }
+  }
+
+  // The lexer only matches two bounds separated by `TO`
+  const char *p = rangeTok->s, *end = rangeTok->s + rangeTok->len;
+  double now = DateField_Now();
+  QueryToken bounds[2] = {{0}};
+  for (int i = 0; i < 2; ++i) {
+    if (i) {
+      while (isspace((unsigned char)*p)) ++p;
+      p += 2;
+    }
+    if (!parseDateBound(ctx, &p, end, i, now, &bounds[i])) {
+      QueryError_SetErrorFmt(ctx->status, QUERY_ESYNTAX, "Invalid date `%.*s` for field `%.*s`",
+        (int)bounds[i].len, bounds[i].s, (int)fieldTok->len, fieldTok->s);
+      return NULL;
+    }
+  }
+
+  QueryParam *qp = NewNumericFilterQueryParam_WithParams(ctx, &bounds[0], &bounds[1],
+                                                         bounds[0].inclusive, bounds[1].inclusive);
+  // we keep the capitalization as is
+  qp->nf->fieldName = rm_strndup(fieldTok->s, fieldTok->len);
+  return NewNumericNode(qp);
+}
+
 } // END %include
 
 %extra_argument { QueryParseCtx *ctx }
@@ -454,8 +536,31 @@ text_union(A) ::= text_union(B) OR text_expr(C). [ORX] {
 /////////////////////////////////////////////////////////////////
 
 expr(A) ::= modifier(B) COLON text_expr(C) . {
+    const FieldSpec *fs = NULL;
+    if (C && ctx->sctx->spec) {
+        fs = IndexSpec_GetField(ctx->sctx->spec, B.s, B.len);
+    }
     if (C == NULL) {
         A = NULL;
+    } else if (fs && FIELD_IS(fs, INDEXFLD_T_BOOLEAN)) {
+        // Boolean fields are queried with a single `true`/`false` value, as a tag node.
+        // Parameterized values are validated once the parameters are evaluated
+        int val;
+        if (C->type != QN_TOKEN ||
+            (C->tn.str && !FieldSpec_ParseBoolean(C->tn.str, C->tn.len, &val))) {
+            QueryError_SetErrorFmt(ctx->status, QUERY_ESYNTAX,
+                                   "Invalid boolean value for field `%.*s`", (int)B.len, B.s);
+            QueryNode_Free(C);
+            A = NULL;
+        } else {
+            if (C->tn.str) {
+                rm_free(C->tn.str);
+                C->tn.str = rm_strdup(val ? BOOLEAN_FIELD_TRUE_STR : BOOLEAN_FIELD_FALSE_STR);
+                C->tn.len = strlen(C->tn.str);
+            }
+            A = NewTagNode(rm_strndup(B.s, B.len), B.len);
+            QueryNode_AddChild(A, C);
+        }
     } else {
         if (ctx->sctx->spec) {
             QueryNode_SetFieldMask(C, IndexSpec_GetFieldBit(ctx->sctx->spec, B.s, B.len));
@@ -785,6 +890,29 @@ tag_list(A) ::= tag_list(B) OR termlist(C) . [TAGLIST] {
     A = B;
 }
 
+/////////////////////////////////////////////////////////////////
+// Missing and empty values
+/////////////////////////////////////////////////////////////////
+
+expr(A) ::= ISMISSING LP modifier(B) RP . {
+    const FieldSpec *fs = ctx->sctx->spec ? IndexSpec_GetField(ctx->sctx->spec, B.s, B.len) : NULL;
+    if (ctx->sctx->spec && (!fs || !FieldSpec_IndexesMissing(fs))) {
+        QueryError_SetErrorFmt(ctx->status, QUERY_EMISSING,
+            "'ismissing' requires field '%.*s' to be defined with 'INDEXMISSING'", (int)B.len, B.s);
+        A = NULL;
+    } else {
+        A = NewMissingNode(rm_strndup(B.s, B.len), B.len);
+    }
+}
+
+expr(A) ::= modifier(B) COLON LB QUOTE QUOTE RB . {
+    A = newIsEmptyNode(ctx, &B, INDEXFLD_T_TAG, SPEC_TAG_STR);
+}
+
+expr(A) ::= modifier(B) COLON QUOTE QUOTE . {
+    A = newIsEmptyNode(ctx, &B, INDEXFLD_T_FULLTEXT, SPEC_TEXT_STR);
+}
+
 /////////////////////////////////////////////////////////////////
 // Numeric Ranges
 /////////////////////////////////////////////////////////////////
@@ -809,6 +937,10 @@ numeric_range(A) ::= LSQB param_num(B) param_num(C) RSQB. [NUMBER]{
   A = NewNumericFilterQueryParam_WithParams(ctx, &B, &C, B.inclusive, C.inclusive);
 }
 
+expr(A) ::= modifier(B) COLON DATE_RANGE(C). {
+  A = newDateRangeNode(ctx, &B, &C);
+}
+
 /////////////////////////////////////////////////////////////////
 // Geo Filters
 /////////////////////////////////////////////////////////////////
@@ -850,7 +982,7 @@ expr(A) ::= modifier(B) COLON geometry_query(C). {
 
 
 geometry_query(A) ::= NAMED_PREDICATE(B) . [NUMBER] {
-  A = NewGeometryNode_FromWkt(B.s, B.len);
+  A = NewGeometryNode_WithParams(ctx, B.s, B.len);
 }
 
 /////////////////////////////////////////////////////////////////
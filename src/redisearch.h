@@ -390,13 +390,72 @@ typedef struct {
 typedef double (*RSScoringFunction)(const ScoringFunctionArgs *ctx, const RSIndexResult *res,
                                     const RSDocumentMetadata *dmd, double minScore);
 
+/* The types of the values passed to and returned from extension expression functions */
+typedef enum {
+  RSExprValue_Null = 0,
+  RSExprValue_Number = 1,
+  RSExprValue_String = 2,
+} RSExprValueType;
+
+/* A value passed to or returned from an extension expression function. Strings are not
+ * necessarily NULL terminated */
+typedef struct {
+  RSExprValueType type;
+  double num;
+  const char *str;
+  size_t len;
+} RSExprValue;
+
+/* The context given to an expression function when it is called from APPLY or FILTER */
+typedef struct RSExprFunctionCtx {
+  /* Private data set by the extension on registration */
+  void *privdata;
+
+  /* Set a string result. The string is copied, so the function keeps ownership of `str` */
+  void (*SetStringResult)(struct RSExprFunctionCtx *ctx, RSExprValue *result, const char *str,
+                          size_t len);
+} RSExprFunctionCtx;
+
+/* RSExprFunction is a callback type for custom APPLY/FILTER functions. The result is Null unless
+ * set by the function. Returns REDISEARCH_OK, or REDISEARCH_ERR to fail the query */
+typedef int (*RSExprFunction)(RSExprFunctionCtx *ctx, RSExprValue *result, const RSExprValue *args,
+                              size_t nargs);
+
+/* The callbacks of a custom GROUPBY reducer. The reducer is invoked as
+ * `REDUCE <name> <nargs> @property ...`, and every group gets its own instance */
+typedef struct {
+  /* Create the state of a new group */
+  void *(*NewInstance)(void *privdata);
+
+  /* Add the property values of a row to the group. Missing values are passed as Null */
+  void (*Add)(void *privdata, void *instance, const RSExprValue *args, size_t nargs);
+
+  /* Set the result of the group after all of its rows were added. ctx->privdata is the reducer's
+   * privdata, and strings are set with ctx->SetStringResult */
+  void (*Finalize)(RSExprFunctionCtx *ctx, void *instance, RSExprValue *result);
+
+  /* Free the state of a group */
+  void (*FreeInstance)(void *privdata, void *instance);
+} RSReducerCallbacks;
+
 /* The extension registeration context, containing the callbacks avaliable to the extension for
- * registering query expanders and scorers. */
+ * registering query expanders, scorers, expression functions and reducers. */
 typedef struct RSExtensionCtx {
   int (*RegisterScoringFunction)(const char *alias, RSScoringFunction func, RSFreeFunction ff,
                                  void *privdata);
   int (*RegisterQueryExpander)(const char *alias, RSQueryTokenExpander exp, RSFreeFunction ff,
                                void *privdata);
+  /* Register a function usable in APPLY and FILTER expressions, accepting between minArgs and
+   * maxArgs arguments (-1 for no maximum). ff frees privdata when the module is unloaded */
+  int (*RegisterExpressionFunction)(const char *name, RSExprFunction func, int minArgs,
+                                    int maxArgs, RSExprValueType retType, RSFreeFunction ff,
+                                    void *privdata);
+  /* Register a GROUPBY reducer accepting exactly nargs properties. In a cluster, the reducer runs
+   * on the shards and mergeReducer (e.g. "SUM") combines the shard results on the coordinator.
+   * If mergeReducer is NULL, the GROUPBY step is executed on the coordinator instead */
+  int (*RegisterReducer)(const char *name, const RSReducerCallbacks *callbacks, int nargs,
+                         RSExprValueType retType, const char *mergeReducer, RSFreeFunction ff,
+                         void *privdata);
 } RSExtensionCtx;
 
 /* An extension initialization function  */
@@ -151,6 +151,14 @@ RSFieldID RediSearch_CreateField(RefManager* rm, const char* name, unsigned type
     fs->types |= INDEXFLD_T_TAG;
     numTypes++;
   }
+  if (types & RSFLDTYPE_BOOLEAN) {
+    fs->types |= INDEXFLD_T_BOOLEAN;
+    numTypes++;
+  }
+  if (types & RSFLDTYPE_DATE) {
+    fs->types |= INDEXFLD_T_DATE;
+    numTypes++;
+  }
   // TODO: GEOMETRY
   // if (types & RSFLDTYPE_GEOMETRY) {
   //   fs->types |= INDEXFLD_T_GEOMETRY;
@@ -175,6 +183,12 @@ RSFieldID RediSearch_CreateField(RefManager* rm, const char* name, unsigned type
     fs->options |= FieldSpec_Phonetics;
     sp->flags |= Index_HasPhonetic;
   }
+  if (options & RSFLDOPT_TXTASCIIFOLD) {
+    fs->options |= FieldSpec_AsciiFold;
+  }
+  if (options & RSFLDOPT_TXTNFKC) {
+    fs->options |= FieldSpec_NFKC;
+  }
   if (options & RSFLDOPT_WITHSUFFIXTRIE) {
     fs->options |= FieldSpec_WithSuffixTrie;
     if (fs->types == INDEXFLD_T_FULLTEXT) {
@@ -185,6 +199,9 @@ RSFieldID RediSearch_CreateField(RefManager* rm, const char* name, unsigned type
       }
     }
   }
+  if (options & RSFLDOPT_INDEXMISSING) {
+    fs->options |= FieldSpec_IndexMissing;
+  }
 
   RWLOCK_RELEASE();
   return fs->index;
@@ -281,7 +298,7 @@ void RediSearch_DocumentAddFieldString(Document* d, const char* fieldname, const
 }
 
 void RediSearch_DocumentAddFieldNumber(Document* d, const char* fieldname, double val, unsigned as) {
-  if (as == RSFLDTYPE_NUMERIC) {
+  if (as == RSFLDTYPE_NUMERIC || as == RSFLDTYPE_BOOLEAN || as == RSFLDTYPE_DATE) {
     Document_AddNumericField(d, fieldname, val, as);
   } else {
     char buf[512];
@@ -404,6 +421,8 @@ QueryNode* RediSearch_CreateGeoNode(RefManager* rm, const char* field, double la
   flt->lon = lon;
   flt->radius = radius;
   flt->numericFilters = NULL;
+  flt->numNumericFilters = 0;
+  flt->polygon = NULL;
   flt->property = rm_strdup(field);
   flt->unitType = (GeoDistance)unitType;
 
@@ -502,6 +521,10 @@ QueryNode* RediSearch_CreateTagNode(RefManager* rm, const char* field) {
   return ret;
 }
 
+QueryNode* RediSearch_CreateMissingNode(RefManager* rm, const char* field) {
+  return NewMissingNode(rm_strdup(field), strlen(field));
+}
+
 QueryNode* RediSearch_CreateIntersectNode(RefManager* rm, int exact) {
   QueryNode* ret = NewQueryNode(QN_PHRASE);
   ret->pn.exact = exact;
@@ -592,6 +615,7 @@ static RS_ApiIter* handleIterCommon(IndexSpec* sp, QueryInput* input, char** err
   // set queryAST configuration parameters
   iteratorsConfig_init(&it->qast.config);
 
+  QAST_Analyze(&it->qast, sp, &options);
   if (QAST_Expand(&it->qast, NULL, &options, &sctx, &status) != REDISMODULE_OK) {
     goto end;
   }
@@ -812,6 +836,12 @@ void RediSearch_FieldInfo(struct RSIdxField *infoField, FieldSpec *specField) {
   if (specField->types & INDEXFLD_T_GEO) {
     infoField->types |= RSFLDTYPE_GEO;
   }
+  if (specField->types & INDEXFLD_T_BOOLEAN) {
+    infoField->types |= RSFLDTYPE_BOOLEAN;
+  }
+  if (specField->types & INDEXFLD_T_DATE) {
+    infoField->types |= RSFLDTYPE_DATE;
+  }
   // TODO: GEMOMETRY
   // if (specField->types & INDEXFLD_T_GEOMETRY) {
   //   infoField->types |= RSFLDTYPE_GEOMETRY;
@@ -827,9 +857,18 @@ void RediSearch_FieldInfo(struct RSIdxField *infoField, FieldSpec *specField) {
   if (FieldSpec_IsPhonetics(specField)) {
     infoField->options |= RSFLDOPT_TXTPHONETIC;
   }
+  if (FieldSpec_IsAsciiFold(specField)) {
+    infoField->options |= RSFLDOPT_TXTASCIIFOLD;
+  }
+  if (FieldSpec_IsNFKC(specField)) {
+    infoField->options |= RSFLDOPT_TXTNFKC;
+  }
   if (!FieldSpec_IsIndexable(specField)) {
     infoField->options |= RSFLDOPT_NOINDEX;
   }
+  if (FieldSpec_IndexesMissing(specField)) {
+    infoField->options |= RSFLDOPT_INDEXMISSING;
+  }
 }
 
 int RediSearch_IndexInfo(RSIndex* rm, RSIdxInfo *info) {
@@ -60,6 +60,8 @@ typedef struct RSIdxOptions RSIndexOptions;
 #define RSFLDTYPE_TAG 0x08
 #define RSFLDTYPE_VECTOR 0x10
 // TODO: GEOMETRY #define RSFLDTYPE_GEOMETRY 0x20
+#define RSFLDTYPE_BOOLEAN 0x40
+#define RSFLDTYPE_DATE 0x80
 
 #define RSFLDOPT_NONE 0x00
 #define RSFLDOPT_SORTABLE 0x01
@@ -67,6 +69,9 @@ typedef struct RSIdxOptions RSIndexOptions;
 #define RSFLDOPT_TXTNOSTEM 0x04
 #define RSFLDOPT_TXTPHONETIC 0x08
 #define RSFLDOPT_WITHSUFFIXTRIE 0x10
+#define RSFLDOPT_INDEXMISSING 0x20
+#define RSFLDOPT_TXTASCIIFOLD 0x40
+#define RSFLDOPT_TXTNFKC 0x80
 
 // This enum copies
 typedef enum {
@@ -206,6 +211,10 @@ MODULE_API_FUNC(RSFieldID, RediSearch_CreateField)
   RediSearch_CreateField(idx, name, RSFLDTYPE_GEO, RSFLDOPT_NONE)
 #define RediSearch_CreateVectorField(idx, name) \
   RediSearch_CreateField(idx, name, RSFLDTYPE_VECTOR, RSFLDOPT_NONE)
+#define RediSearch_CreateBooleanField(idx, name) \
+  RediSearch_CreateField(idx, name, RSFLDTYPE_BOOLEAN, RSFLDOPT_NONE)
+#define RediSearch_CreateDateField(idx, name) \
+  RediSearch_CreateField(idx, name, RSFLDTYPE_DATE, RSFLDOPT_NONE)
 // TODO: GEOMETRY 
 // #define RediSearch_CreateGeometryField(idx, name) \
 //   RediSearch_CreateField(idx, name, RSFLDTYPE_GEOMETRY, RSFLDOPT_NONE)
@@ -295,6 +304,9 @@ MODULE_API_FUNC(RSQNode*, RediSearch_CreateTagLexRangeNode)
 (RSIndex* sp, const char* begin, const char* end, int includeBegin,
  int includeEnd);
 
+// Matches the documents which have no value for a field created with RSFLDOPT_INDEXMISSING
+MODULE_API_FUNC(RSQNode*, RediSearch_CreateMissingNode)(RSIndex* sp, const char* field);
+
 MODULE_API_FUNC(RSQNode*, RediSearch_CreateIntersectNode)(RSIndex* sp, int exact);
 MODULE_API_FUNC(RSQNode*, RediSearch_CreateUnionNode)(RSIndex* sp);
 MODULE_API_FUNC(RSQNode*, RediSearch_CreateEmptyNode)(RSIndex* sp);
@@ -402,6 +414,7 @@ MODULE_API_FUNC(void, RediSearch_IndexInfoFree)(RSIdxInfo *info);
   X(CreateTagContainsNode)           \
   X(CreateTagSuffixNode)             \
   X(CreateTagLexRangeNode)           \
+  X(CreateMissingNode)               \
   X(CreateIntersectNode)             \
   X(CreateUnionNode)                 \
   X(CreateNotNode)                   \
@@ -67,6 +67,9 @@ typedef enum {
   RP_PROFILE,
   RP_NETWORK,
   RP_METRICS,
+  RP_FACETS,
+  RP_HYBRID_FUSION,
+  RP_VECTOR_LOADER,
   RP_MAX,
 } ResultProcessorType;
 
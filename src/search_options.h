@@ -84,6 +84,26 @@ typedef enum {
 
 #define RS_DEFAULT_QUERY_FLAGS 0x00
 
+typedef enum {
+  FUSION_NONE = 0,
+  // Reciprocal Rank Fusion: sum of 1 / (constant + rank) over the branches
+  FUSION_RRF,
+  // Weighted sum of the min-max normalized lexical score and vector distance
+  FUSION_LINEAR
+} FusionMethod;
+
+/** Options of the FUSION clause, combining the lexical and the vector branch of a hybrid query */
+typedef struct {
+  FusionMethod method;
+  double rrfConstant;
+  double lexicalWeight;
+  double vectorWeight;
+  // Number of top results of each branch that take part in the fusion.
+  // 0 until resolved against the K of the vector query.
+  size_t lexicalWindow;
+  size_t vectorWindow;
+} FusionOptions;
+
 typedef struct {
   const char *expanderName;
   const char *scorerName;
@@ -104,6 +124,8 @@ typedef struct {
   const StopWordList *stopwords;
   dict *params;
 
+  FusionOptions fusion;
+
   /** Legacy options */
   struct {
     NumericFilter **filters;
@@ -19,6 +19,8 @@
 #include "config.h"
 #include "cursor.h"
 #include "tag_index.h"
+#include "boolean_index.h"
+#include "inverted_index.h"
 #include "redis_index.h"
 #include "indexer.h"
 #include "suffix.h"
@@ -32,6 +34,8 @@
 #include "commands.h"
 #include "rmutil/cxx/chrono-clock.h"
 #include "geometry/geometry_api.h"
+#include "vector_index.h"
+#include "analyzer.h"
 
 #define INITIAL_DOC_TABLE_SIZE 1000
 
@@ -444,6 +448,19 @@ static int parseTextField(FieldSpec *fs, ArgsCursor *ac, QueryError *status) {
       continue;
     } else if(AC_AdvanceIfMatch(ac, SPEC_WITHSUFFIXTRIE_STR)) {
       fs->options |= FieldSpec_WithSuffixTrie;
+    } else if (AC_AdvanceIfMatch(ac, SPEC_ASCIIFOLD_STR)) {
+      fs->options |= FieldSpec_AsciiFold;
+    } else if (AC_AdvanceIfMatch(ac, SPEC_NFKC_STR)) {
+      fs->options |= FieldSpec_NFKC;
+    } else if (AC_AdvanceIfMatch(ac, SPEC_ANALYZER_STR)) {
+      if (fs->analyzer) {
+        QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Duplicate " SPEC_ANALYZER_STR
+                               " for field `%s`", fs->name);
+        return 0;
+      }
+      if (!(fs->analyzer = Analyzer_Parse(ac, status))) {
+        return 0;
+      }
     } else {
       break;
     }
@@ -465,6 +482,14 @@ static int parseVectorField_GetType(ArgsCursor *ac, VecSimType *type) {
     *type = VecSimType_FLOAT32;
   else if (!strncasecmp(VECSIM_TYPE_FLOAT64, typeStr, len))
     *type = VecSimType_FLOAT64;
+  else if (!strncasecmp(VECSIM_TYPE_FLOAT16, typeStr, len))
+    *type = VecSimType_FLOAT16;
+  else if (!strncasecmp(VECSIM_TYPE_BFLOAT16, typeStr, len))
+    *type = VecSimType_BFLOAT16;
+  else if (!strncasecmp(VECSIM_TYPE_INT8, typeStr, len))
+    *type = VecSimType_INT8;
+  else if (!strncasecmp(VECSIM_TYPE_UINT8, typeStr, len))
+    *type = VecSimType_UINT8;
   // else if (!strncasecmp(VECSIM_TYPE_INT32, typeStr, len))
   //   *type = VecSimType_INT32;
   // else if (!strncasecmp(VECSIM_TYPE_INT64, typeStr, len))
@@ -474,6 +499,21 @@ static int parseVectorField_GetType(ArgsCursor *ac, VecSimType *type) {
   return AC_OK;
 }
 
+// Tries to get the vector compression from ac.
+static int parseVectorField_GetCompression(ArgsCursor *ac, VecSimCompression *compression) {
+  const char *compressionStr;
+  size_t len;
+  int rc;
+  if ((rc = AC_GetString(ac, &compressionStr, &len, 0)) != AC_OK) {
+    return rc;
+  }
+  if (!strncasecmp(VECSIM_COMPRESSION_SQ8, compressionStr, len))
+    *compression = VecSimCompression_SQ8;
+  else
+    return AC_ERR_ENOENT;
+  return AC_OK;
+}
+
 // Tries to get distance metric from ac. This function need to stay updated with
 // the supported distance metric functions list of VecSim.
 static int parseVectorField_GetMetric(ArgsCursor *ac, VecSimMetric *metric) {
@@ -494,6 +534,28 @@ static int parseVectorField_GetMetric(ArgsCursor *ac, VecSimMetric *metric) {
   return AC_OK;
 }
 
+// The full precision vectors are read back from the documents to rerank the candidates,
+// so compression is limited to single value fields of the common floating point types.
+static int parseVectorField_validate_compression(FieldSpec *fs, bool optcompression, QueryError *status) {
+  const HNSWParams *params = &fs->vectorOpts.vecSimParams.hnswParams;
+  if (fs->vectorOpts.compression.type == VecSimCompression_None) {
+    if (optcompression) {
+      QERR_MKBADARGS_FMT(status, "%s and %s require %s", VECSIM_COMPRESSION_RANGE, VECSIM_RERANK_FACTOR, VECSIM_COMPRESSION);
+      return 0;
+    }
+    return 1;
+  }
+  if (params->type != VecSimType_FLOAT32 && params->type != VecSimType_FLOAT64) {
+    QERR_MKBADARGS_FMT(status, "%s requires a %s or %s vector type", VECSIM_COMPRESSION, VECSIM_TYPE_FLOAT32, VECSIM_TYPE_FLOAT64);
+    return 0;
+  }
+  if (params->multi) {
+    QERR_MKBADARGS_FMT(status, "%s is not supported for multi-value vector fields", VECSIM_COMPRESSION);
+    return 0;
+  }
+  return 1;
+}
+
 // memoryLimit / 10 - default is 10% of global memory limit
 #define BLOCK_MEMORY_LIMIT ((RSGlobalConfig.vssMaxResize) ? RSGlobalConfig.vssMaxResize : memoryLimit / 10)
 
@@ -567,6 +629,8 @@ static int parseVectorField_hnsw(FieldSpec *fs, ArgsCursor *ac, QueryError *stat
   bool mandtype = false;
   bool mandsize = false;
   bool mandmetric = false;
+  // Whether a parameter that only applies to compressed indexes was given.
+  bool optcompression = false;
 
   // Get number of parameters
   size_t expNumParam, numParam = 0;
@@ -624,6 +688,24 @@ static int parseVectorField_hnsw(FieldSpec *fs, ArgsCursor *ac, QueryError *stat
         QERR_MKBADARGS_AC(status, "vector similarity HNSW index epsilon", rc);
         return 0;
       }
+    } else if (AC_AdvanceIfMatch(ac, VECSIM_COMPRESSION)) {
+      if ((rc = parseVectorField_GetCompression(ac, &fs->vectorOpts.compression.type)) != AC_OK) {
+        QERR_MKBADARGS_AC(status, "vector similarity HNSW index compression", rc);
+        return 0;
+      }
+    } else if (AC_AdvanceIfMatch(ac, VECSIM_COMPRESSION_RANGE)) {
+      if ((rc = AC_GetDouble(ac, &fs->vectorOpts.compression.range, AC_F_GE0)) != AC_OK ||
+          fs->vectorOpts.compression.range == 0) {
+        QERR_MKBADARGS_AC(status, "vector similarity HNSW index compression range", rc != AC_OK ? rc : AC_ERR_ELIMIT);
+        return 0;
+      }
+      optcompression = true;
+    } else if (AC_AdvanceIfMatch(ac, VECSIM_RERANK_FACTOR)) {
+      if ((rc = AC_GetSize(ac, &fs->vectorOpts.compression.rerankFactor, AC_F_GE1)) != AC_OK) {
+        QERR_MKBADARGS_AC(status, "vector similarity HNSW index rerank factor", rc);
+        return 0;
+      }
+      optcompression = true;
     } else {
       QERR_MKBADARGS_FMT(status, "Bad arguments for algorithm %s: %s", VECSIM_ALGORITHM_HNSW, AC_GetStringNC(ac, NULL));
       return 0;
@@ -646,6 +728,9 @@ static int parseVectorField_hnsw(FieldSpec *fs, ArgsCursor *ac, QueryError *stat
     VECSIM_ERR_MANDATORY(status, VECSIM_ALGORITHM_HNSW, VECSIM_DISTANCE_METRIC);
     return 0;
   }
+  if (!parseVectorField_validate_compression(fs, optcompression, status)) {
+    return 0;
+  }
   // Calculating expected blob size of a vector in bytes.
   fs->vectorOpts.expBlobSize = fs->vectorOpts.vecSimParams.hnswParams.dim * VecSimType_sizeof(fs->vectorOpts.vecSimParams.hnswParams.type);
 
@@ -734,6 +819,11 @@ static int parseVectorField(IndexSpec *sp, FieldSpec *fs, ArgsCursor *ac, QueryE
   // init default type, size, distance metric and algorithm
 
   memset(&fs->vectorOpts.vecSimParams, 0, sizeof(VecSimParams));
+  fs->vectorOpts.compression = (VecSimCompressionParams){
+      .type = VecSimCompression_None,
+      .range = VECSIM_DEFAULT_COMPRESSION_RANGE,
+      .rerankFactor = VECSIM_DEFAULT_RERANK_FACTOR,
+  };
 
   // If the index is on JSON and the given path is dynamic, create a multi-value index.
   bool multi = false;
@@ -792,6 +882,21 @@ static int parseFieldSpec(ArgsCursor *ac, IndexSpec *sp, FieldSpec *fs, QueryErr
     if (!parseTextField(fs, ac, status)) {
       goto error;
     }
+    // Long query terms are searched as phrases of grams
+    if (fs->analyzer && fs->analyzer->tokenizer == AnalyzerTokenizer_NGram &&
+        !(sp->flags & Index_StoreTermOffsets)) {
+      QueryError_SetErrorFmt(status, QUERY_EPARSEARGS,
+                             "NGRAM tokenizer of field `%s` requires term offsets", fs->name);
+      goto error;
+    }
+    // Analyzers normalize the tokens with their own filters
+    if (fs->analyzer && (FieldSpec_IsAsciiFold(fs) || FieldSpec_IsNFKC(fs))) {
+      QueryError_SetErrorFmt(status, QUERY_EPARSEARGS,
+                             SPEC_ASCIIFOLD_STR " and " SPEC_NFKC_STR
+                             " can't be used with the " SPEC_ANALYZER_STR " of field `%s`",
+                             fs->name);
+      goto error;
+    }
   } else if (AC_AdvanceIfMatch(ac, SPEC_NUMERIC_STR)) {  // numeric field
     fs->types |= INDEXFLD_T_NUMERIC;
   } else if (AC_AdvanceIfMatch(ac, SPEC_GEO_STR)) {  // geo field
@@ -829,8 +934,29 @@ static int parseFieldSpec(ArgsCursor *ac, IndexSpec *sp, FieldSpec *fs, QueryErr
   } else if (AC_AdvanceIfMatch(ac, SPEC_GEOMETRY_STR)) {  // geometry field
     sp->flags |= Index_HasGeometry;
     fs->types |= INDEXFLD_T_GEOMETRY;
-    // TODO: GEMOMETRY - Support more geometry libraries - if an optional successive token exist
+    // Planar coordinates are indexed with boost.geometry, and geographic ones with S2
     fs->geometryOpts.geometryLibType = GEOMETRY_LIB_TYPE_BOOST_GEOMETRY;
+    if (AC_AdvanceIfMatch(ac, SPEC_GEOMETRY_COORD_SYSTEM_STR)) {
+      if (AC_AdvanceIfMatch(ac, SPEC_GEOMETRY_SPHERICAL_STR)) {
+        // S2 is an optional dependency
+        if (!GeometryApi_GetOrCreate(GEOMETRY_LIB_TYPE_S2, NULL)) {
+          QueryError_SetErrorFmt(status, QUERY_EPARSEARGS,
+                                 SPEC_GEOMETRY_COORD_SYSTEM_STR " " SPEC_GEOMETRY_SPHERICAL_STR
+                                 " is not supported by this build");
+          goto error;
+        }
+        fs->geometryOpts.geometryLibType = GEOMETRY_LIB_TYPE_S2;
+      } else if (!AC_AdvanceIfMatch(ac, SPEC_GEOMETRY_FLAT_STR)) {
+        QueryError_SetErrorFmt(status, QUERY_EPARSEARGS,
+                               SPEC_GEOMETRY_COORD_SYSTEM_STR " for field `%s` must be "
+                               SPEC_GEOMETRY_SPHERICAL_STR " or " SPEC_GEOMETRY_FLAT_STR, fs->name);
+        goto error;
+      }
+    }
+  } else if (AC_AdvanceIfMatch(ac, SPEC_BOOLEAN_STR)) {  // boolean field
+    fs->types |= INDEXFLD_T_BOOLEAN;
+  } else if (AC_AdvanceIfMatch(ac, SPEC_DATE_STR)) {  // date field
+    fs->types |= INDEXFLD_T_DATE;
   } else {  // nothing more supported currently
     QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Invalid field type for field `%s`", fs->name);
     goto error;
@@ -846,6 +972,18 @@ static int parseFieldSpec(ArgsCursor *ac, IndexSpec *sp, FieldSpec *fs, QueryErr
     } else if (AC_AdvanceIfMatch(ac, SPEC_NOINDEX_STR)) {
       fs->options |= FieldSpec_NotIndexable;
       continue;
+    } else if (AC_AdvanceIfMatch(ac, SPEC_INDEXMISSING_STR)) {
+      fs->options |= FieldSpec_IndexMissing;
+      continue;
+    } else if (AC_AdvanceIfMatch(ac, SPEC_INDEXEMPTY_STR)) {
+      if (!FIELD_IS(fs, INDEXFLD_T_FULLTEXT | INDEXFLD_T_TAG)) {
+        QueryError_SetErrorFmt(status, QUERY_EPARSEARGS,
+                               "`%s` is only supported for TEXT and TAG fields (field `%s`)",
+                               SPEC_INDEXEMPTY_STR, fs->name);
+        goto error;
+      }
+      fs->options |= FieldSpec_IndexEmpty;
+      continue;
     } else {
       break;
     }
@@ -882,6 +1020,7 @@ int IndexSpec_CreateTextId(const IndexSpec *sp) {
 }
 
 static IndexSpecCache *IndexSpec_BuildSpecCache(const IndexSpec *spec);
+static void IndexSpec_ScanAndReindexAsync(StrongRef spec_ref, bool vectorsOnly);
 
 /**
  * Add fields to an existing (or newly created) index. If the addition fails,
@@ -1053,6 +1192,78 @@ int IndexSpec_AddFields(StrongRef spec_ref, IndexSpec *sp, RedisModuleCtx *ctx,
   return rc;
 }
 
+// Replace the vector indexes of the fields modified by FT.ALTER with their new indexes.
+// Assumes the spec is locked for write
+static void IndexSpec_CompleteVecSimMigrations(IndexSpec *sp) {
+  VecSimMigration_Complete(sp);
+  IndexSpecCache_Decref(sp->spcache);
+  sp->spcache = IndexSpec_BuildSpecCache(sp);
+  IndexSpec_UpdateVersion(sp);
+}
+
+static VecSimType vecsimFieldType(const FieldSpec *fs) {
+  return fs->vectorOpts.vecSimParams.algo == VecSimAlgo_HNSWLIB
+             ? fs->vectorOpts.vecSimParams.hnswParams.type
+             : fs->vectorOpts.vecSimParams.bfParams.type;
+}
+
+// Assumes the spec is locked for write
+int IndexSpec_ModifyVectorField(StrongRef spec_ref, IndexSpec *sp, RedisModuleCtx *ctx,
+                                ArgsCursor *ac, QueryError *status) {
+  setMemoryInfo(ctx);
+
+  size_t namelen;
+  const char *fieldName;
+  if (AC_GetString(ac, &fieldName, &namelen, 0) != AC_OK) {
+    QueryError_SetError(status, QUERY_EPARSEARGS, "Fields arguments are missing");
+    return 0;
+  }
+  FieldSpec *fs = (FieldSpec *)IndexSpec_GetField(sp, fieldName, namelen);
+  if (!fs) {
+    QueryError_SetErrorFmt(status, QUERY_ENOPROPKEY, "Unknown field `%.*s`", (int)namelen,
+                           fieldName);
+    return 0;
+  }
+  if (!FIELD_IS(fs, INDEXFLD_T_VECTOR) || !AC_AdvanceIfMatch(ac, SPEC_VECTOR_STR)) {
+    QueryError_SetErrorFmt(status, QUERY_EINVAL, "Only the parameters of %s fields can be modified",
+                           SPEC_VECTOR_STR);
+    return 0;
+  }
+
+  FieldSpec target = *fs;
+  target.vectorOpts.migration = NULL;
+  if (!parseVectorField(sp, &target, ac, status)) {
+    return 0;
+  }
+  if (!AC_IsAtEnd(ac)) {
+    QueryError_SetErrorFmt(status, QUERY_EPARSEARGS, "Unexpected argument `%s`",
+                           AC_GetStringNC(ac, NULL));
+    return 0;
+  }
+  if (vecsimFieldType(&target) != vecsimFieldType(fs) ||
+      target.vectorOpts.expBlobSize != fs->vectorOpts.expBlobSize) {
+    QueryError_SetErrorFmt(status, QUERY_EINVAL,
+                           "The TYPE and DIM of vector field `%s` can't be modified", fs->name);
+    return 0;
+  }
+
+  // A previous modification which is still in progress is discarded
+  VecSimMigration_Free(fs->vectorOpts.migration);
+  fs->vectorOpts.migration = VecSimMigration_New(&target, sp->docs.maxDocId);
+
+  IndexesScanner *scanner = sp->scanner;
+  if (RedisModule_DbSize(ctx) == 0) {
+    IndexSpec_CompleteVecSimMigrations(sp);
+  } else if (scanner && !scanner->vectorsOnly && !scanner->cancelled) {
+    // Documents which were already indexed by the ongoing scan are missing from the new index.
+    // Restart the scan, which adds all the documents to both indexes.
+    IndexSpec_ScanAndReindexAsync(spec_ref, false);
+  } else {
+    IndexSpec_ScanAndReindexAsync(spec_ref, true);
+  }
+  return 1;
+}
+
 /* The format currently is FT.CREATE {index} [NOOFFSETS] [NOFIELDS]
     SCHEMA {field} [TEXT [WEIGHT {weight}]] | [NUMERIC]
   */
@@ -1260,10 +1471,21 @@ static void IndexSpec_FreeUnlinkedData(IndexSpec *spec) {
   if (spec->keysDict) {
     dictRelease(spec->keysDict);
   }
+  // Free the inverted indexes of documents missing INDEXMISSING fields
+  if (spec->missingFieldDict) {
+    dictRelease(spec->missingFieldDict);
+  }
+  // Free the inverted indexes of documents with empty values in INDEXEMPTY fields
+  if (spec->emptyFieldDict) {
+    dictRelease(spec->emptyFieldDict);
+  }
   // Free synonym data
   if (spec->smap) {
     SynonymMap_Free(spec->smap);
   }
+  if (spec->stemExceptions) {
+    StemExceptions_Unref(spec->stemExceptions);
+  }
   // Destroy spec rule
   if (spec->rule) {
     SchemaRule_Free(spec->rule);
@@ -1292,6 +1514,10 @@ static void IndexSpec_FreeUnlinkedData(IndexSpec *spec) {
         rm_free(spec->fields[i].name);
       }
       rm_free(spec->fields[i].path);
+      Analyzer_Free(spec->fields[i].analyzer);
+      if (FIELD_IS(spec->fields + i, INDEXFLD_T_VECTOR)) {
+        VecSimMigration_Free(spec->fields[i].vectorOpts.migration);
+      }
     }
     rm_free(spec->fields);
   }
@@ -1481,12 +1707,16 @@ RedisModuleString *IndexSpec_GetFormattedKey(IndexSpec *sp, const FieldSpec *fs,
     RedisSearchCtx sctx = {.redisCtx = RSDummyContext, .spec = sp};
     switch (forType) {
       case INDEXFLD_T_NUMERIC:
+      case INDEXFLD_T_DATE:  // dates are kept in a numeric index
       case INDEXFLD_T_GEO:  // TODO?? change the name
         ret = fmtRedisNumericIndexKey(&sctx, fs->name);
         break;
       case INDEXFLD_T_TAG:
         ret = TagIndex_FormatName(&sctx, fs->name);
         break;
+      case INDEXFLD_T_BOOLEAN:
+        ret = fmtRedisBooleanIndexKey(&sctx, fs->name);
+        break;
       case INDEXFLD_T_VECTOR:
         // TODO: remove the whole thing
         // NOT NECESSARY ANYMORE - used when field were in keyspace
@@ -1525,6 +1755,23 @@ void IndexSpec_InitializeSynonym(IndexSpec *sp) {
   }
 }
 
+void IndexSpec_SetStemExceptions(IndexSpec *sp, StemExceptions *se) {
+  // Documents being indexed keep their reference to the previous exceptions
+  if (sp->stemExceptions) {
+    StemExceptions_Unref(sp->stemExceptions);
+  }
+  if (se && !StemExceptions_Size(se)) {
+    StemExceptions_Unref(se);
+    se = NULL;
+  }
+  sp->stemExceptions = se;
+  if (se) {
+    sp->flags |= Index_HasStemExceptions;
+  } else {
+    sp->flags &= ~Index_HasStemExceptions;
+  }
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////
 
 IndexSpec *NewIndexSpec(const char *name) {
@@ -1540,6 +1787,8 @@ IndexSpec *NewIndexSpec(const char *name) {
   sp->suffix = NULL;
   sp->suffixMask = (t_fieldMask)0;
   sp->keysDict = NULL;
+  sp->missingFieldDict = NULL;
+  sp->emptyFieldDict = NULL;
   sp->getValue = NULL;
   sp->getValueCtx = NULL;
 
@@ -1615,6 +1864,42 @@ void IndexSpec_MakeKeyless(IndexSpec *sp) {
   sp->keysDict = dictCreate(&invidxDictType, NULL);
 }
 
+static dictType fieldDocsDictType = {0};
+
+static void fieldDocsValFreeCb(void *unused, void *p) {
+  InvertedIndex_Free(p);
+}
+
+static InvertedIndex *getFieldDocsIndex(dict **fieldDict, const FieldSpec *fs, bool create) {
+  if (!*fieldDict) {
+    if (!create) {
+      return NULL;
+    }
+    if (!fieldDocsDictType.valDestructor) {
+      fieldDocsDictType = dictTypeHeapStrings;
+      fieldDocsDictType.valDestructor = fieldDocsValFreeCb;
+    }
+    *fieldDict = dictCreate(&fieldDocsDictType, NULL);
+  }
+
+  InvertedIndex *iv = dictFetchValue(*fieldDict, fs->name);
+  if (!iv && create) {
+    iv = NewInvertedIndex(Index_DocIdsOnly, 1);
+    dictAdd(*fieldDict, fs->name, iv);
+  }
+  return iv;
+}
+
+// Assuming the spec is properly locked before calling this function.
+InvertedIndex *IndexSpec_GetMissingFieldIndex(IndexSpec *sp, const FieldSpec *fs, bool create) {
+  return getFieldDocsIndex(&sp->missingFieldDict, fs, create);
+}
+
+// Assuming the spec is properly locked before calling this function.
+InvertedIndex *IndexSpec_GetEmptyFieldIndex(IndexSpec *sp, const FieldSpec *fs, bool create) {
+  return getFieldDocsIndex(&sp->emptyFieldDict, fs, create);
+}
+
 // Only used on new specs so it's thread safe
 void IndexSpec_StartGCFromSpec(StrongRef global, IndexSpec *sp, uint32_t gcPolicy) {
   sp->gc = GCContext_CreateGC(global, gcPolicy);
@@ -1700,19 +1985,24 @@ static void FieldSpec_RdbSave(RedisModuleIO *rdb, FieldSpec *f) {
   if (FIELD_IS(f, INDEXFLD_T_FULLTEXT) || (f->options & FieldSpec_Dynamic)) {
     RedisModule_SaveUnsigned(rdb, f->ftId);
     RedisModule_SaveDouble(rdb, f->ftWeight);
+    Analyzer_RdbSave(rdb, f->analyzer);
   }
   if (FIELD_IS(f, INDEXFLD_T_TAG) || (f->options & FieldSpec_Dynamic)) {
     RedisModule_SaveUnsigned(rdb, f->tagOpts.tagFlags);
     RedisModule_SaveStringBuffer(rdb, &f->tagOpts.tagSep, 1);
   }
   if (FIELD_IS(f, INDEXFLD_T_VECTOR)) {
-    RedisModule_SaveUnsigned(rdb, f->vectorOpts.expBlobSize);
-    VecSim_RdbSave(rdb, &f->vectorOpts.vecSimParams);
+    // The index is rebuilt on load, so a pending FT.ALTER is saved as if it was completed
+    const FieldSpec *vf = f->vectorOpts.migration ? &f->vectorOpts.migration->target : f;
+    RedisModule_SaveUnsigned(rdb, vf->vectorOpts.expBlobSize);
+    VecSim_RdbSave(rdb, &vf->vectorOpts.vecSimParams);
+    RedisModule_SaveUnsigned(rdb, vf->vectorOpts.compression.type);
+    RedisModule_SaveDouble(rdb, vf->vectorOpts.compression.range);
+    RedisModule_SaveUnsigned(rdb, vf->vectorOpts.compression.rerankFactor);
+  }
+  if (FIELD_IS(f, INDEXFLD_T_GEOMETRY)) {
+    RedisModule_SaveUnsigned(rdb, f->geometryOpts.geometryLibType);
   }
-  // TODO: GEOMETRY - save geometry options if more than one geometry library is supported
-  // if (FIELD_IS(f, INDEXFLD_T_GEOMETRY) || (f->options & FieldSpec_Dynamic)) {
-  //   RedisModule_SaveUnsigned(rdb, f->geometryOpts.geometryLibType);
-  // }
 }
 
 static const FieldType fieldTypeMap[] = {[IDXFLD_LEGACY_FULLTEXT] = INDEXFLD_T_FULLTEXT,
@@ -1744,11 +2034,22 @@ static int FieldSpec_RdbLoad(RedisModuleIO *rdb, FieldSpec *f, int encver) {
     RS_LOG_ASSERT(f->types <= IDXFLD_LEGACY_MAX, "field type should be string or numeric");
     f->types = fieldTypeMap[f->types];
   }
+  // Boolean fields don't exist in older versions
+  if (encver < INDEX_BOOLEAN_VERSION && FIELD_IS(f, INDEXFLD_T_BOOLEAN)) {
+    goto fail;
+  }
+  // Neither do date fields
+  if (encver < INDEX_DATE_VERSION && FIELD_IS(f, INDEXFLD_T_DATE)) {
+    goto fail;
+  }
 
   // Load text specific options
   if (FIELD_IS(f, INDEXFLD_T_FULLTEXT) || (f->options & FieldSpec_Dynamic)) {
     f->ftId = LoadUnsigned_IOError(rdb, goto fail);
     f->ftWeight = LoadDouble_IOError(rdb, goto fail);
+    if (encver >= INDEX_ANALYZER_VERSION && Analyzer_RdbLoad(rdb, &f->analyzer) != REDISMODULE_OK) {
+      goto fail;
+    }
   }
   // Load tag specific options
   if (FIELD_IS(f, INDEXFLD_T_TAG) || (f->options & FieldSpec_Dynamic)) {
@@ -1774,6 +2075,13 @@ static int FieldSpec_RdbLoad(RedisModuleIO *rdb, FieldSpec *f, int encver) {
         goto fail;
       }
     }
+    if (encver >= INDEX_VECSIM_COMPRESSION_VERSION) {
+      f->vectorOpts.compression.type = LoadUnsigned_IOError(rdb, goto fail);
+      f->vectorOpts.compression.range = LoadDouble_IOError(rdb, goto fail);
+      f->vectorOpts.compression.rerankFactor = LoadUnsigned_IOError(rdb, goto fail);
+    } else {
+      f->vectorOpts.compression = (VecSimCompressionParams){.type = VecSimCompression_None};
+    }
     // Calculate blob size limitation on lower encvers.
     if(encver < INDEX_VECSIM_2_VERSION) {
       switch (f->vectorOpts.vecSimParams.algo)
@@ -1792,8 +2100,16 @@ static int FieldSpec_RdbLoad(RedisModuleIO *rdb, FieldSpec *f, int encver) {
   
   // Load geometry specific options
   if (FIELD_IS(f, INDEXFLD_T_GEOMETRY) || (f->options & FieldSpec_Dynamic)) {
-    // TODO: GEOMETRY - if more than one geometry library is supported - load it from rdb (currently hard-coded)
+    // Indexes saved before the COORD_SYSTEM option are all planar
     f->geometryOpts.geometryLibType = GEOMETRY_LIB_TYPE_BOOST_GEOMETRY;
+    if (FIELD_IS(f, INDEXFLD_T_GEOMETRY) && encver >= INDEX_GEOMETRY_COORD_SYSTEM_VERSION) {
+      uint64_t libType = LoadUnsigned_IOError(rdb, goto fail);
+      if (libType == GEOMETRY_LIB_TYPE_NONE || libType >= GEOMETRY_LIB_TYPE__NUM ||
+          !GeometryApi_GetOrCreate(libType, NULL)) {
+        goto fail;
+      }
+      f->geometryOpts.geometryLibType = libType;
+    }
   }
   
   return REDISMODULE_OK;
@@ -1848,10 +2164,11 @@ static IndexesScanner *IndexesScanner_NewGlobal() {
   return scanner;
 }
 
-static IndexesScanner *IndexesScanner_New(StrongRef global_ref) {
+static IndexesScanner *IndexesScanner_New(StrongRef global_ref, bool vectorsOnly) {
 
   IndexesScanner *scanner = rm_calloc(1, sizeof(IndexesScanner));
   scanner->global = false;
+  scanner->vectorsOnly = vectorsOnly;
   scanner->scannedKeys = 0;
   scanner->totalKeys = RedisModule_DbSize(RSDummyContext);
 
@@ -1904,6 +2221,37 @@ static void IndexSpec_DoneIndexingCallabck(struct RSAddDocumentCtx *docCtx, Redi
 //---------------------------------------------------------------------------------------------
 
 int IndexSpec_UpdateDoc(IndexSpec *spec, RedisModuleCtx *ctx, RedisModuleString *key, DocumentType type);
+
+// Add an indexed document to the vector indexes being built by FT.ALTER
+static void IndexSpec_MigrateVectorsDoc(IndexSpec *spec, RedisModuleCtx *ctx, RedisModuleString *key,
+                                        DocumentType type) {
+  t_docId id = DocTable_GetIdR(&spec->docs, key);
+  if (!id) {
+    // Not indexed (yet). It will be added to the new indexes when it is indexed
+    return;
+  }
+  RedisSearchCtx sctx = SEARCH_CTX_STATIC(ctx, spec);
+  Document doc = {0};
+  Document_Init(&doc, key, DEFAULT_SCORE, DEFAULT_LANGUAGE, type);
+  int rv = REDISMODULE_ERR;
+  switch (type) {
+  case DocumentType_Hash:
+    rv = Document_LoadSchemaFieldHash(&doc, &sctx);
+    break;
+  case DocumentType_Json:
+    rv = Document_LoadSchemaFieldJson(&doc, &sctx);
+    break;
+  case DocumentType_Unsupported:
+    RS_LOG_ASSERT(0, "Should receieve valid type");
+  }
+  if (rv == REDISMODULE_OK) {
+    RedisSearchCtx_LockSpecWrite(&sctx);
+    VecSimMigration_AddDocument(spec, &doc, id);
+    RedisSearchCtx_UnlockSpec(&sctx);
+  }
+  Document_Free(&doc);
+}
+
 static void Indexes_ScanProc(RedisModuleCtx *ctx, RedisModuleString *keyname, RedisModuleKey *key,
                              IndexesScanner *scanner) {
   if (scanner->cancelled) {
@@ -1934,7 +2282,11 @@ static void Indexes_ScanProc(RedisModuleCtx *ctx, RedisModuleString *keyname, Re
     if (sp) {
       // This check is performed without locking the spec, but it's ok since we locked the GIL
       // So the main thread is not running and the GC is not touching the relevant data
-      if (SchemaRule_ShouldIndex(sp, keyname, type)) {
+      if (!SchemaRule_ShouldIndex(sp, keyname, type)) {
+        // Not a document of the index
+      } else if (scanner->vectorsOnly) {
+        IndexSpec_MigrateVectorsDoc(sp, ctx, keyname, type);
+      } else {
         IndexSpec_UpdateDoc(sp, ctx, keyname, type);
       }
       StrongRef_Release(curr_run_ref);
@@ -1992,6 +2344,18 @@ end:
   if (!scanner->cancelled && scanner->global) {
     Indexes_SetTempSpecsTimers(TimerOp_Add);
   }
+  if (!scanner->cancelled && !scanner->global) {
+    // All the documents were added to the vector indexes being built by FT.ALTER, if any
+    StrongRef spec_ref = WeakRef_Promote(scanner->spec_ref);
+    IndexSpec *sp = StrongRef_Get(spec_ref);
+    if (sp) {
+      RedisSearchCtx sctx = SEARCH_CTX_STATIC(ctx, sp);
+      RedisSearchCtx_LockSpecWrite(&sctx);
+      IndexSpec_CompleteVecSimMigrations(sp);
+      RedisSearchCtx_UnlockSpec(&sctx);
+      StrongRef_Release(spec_ref);
+    }
+  }
 
   IndexesScanner_Free(scanner);
 
@@ -2002,14 +2366,14 @@ end:
 
 //---------------------------------------------------------------------------------------------
 
-static void IndexSpec_ScanAndReindexAsync(StrongRef spec_ref) {
+static void IndexSpec_ScanAndReindexAsync(StrongRef spec_ref, bool vectorsOnly) {
   if (!reindexPool) {
     reindexPool = redisearch_thpool_init(1);
   }
 #ifdef _DEBUG
   RedisModule_Log(NULL, "notice", "Register index %s for async scan", ((IndexSpec*)StrongRef_Get(spec_ref))->name);
 #endif
-  IndexesScanner *scanner = IndexesScanner_New(spec_ref);
+  IndexesScanner *scanner = IndexesScanner_New(spec_ref, vectorsOnly);
   redisearch_thpool_add_work(reindexPool, (redisearch_thpool_proc)Indexes_ScanAndReindexTask, scanner);
 }
 
@@ -2104,8 +2468,16 @@ void IndexSpec_AddToInfo(RedisModuleInfoCtx *ctx, IndexSpec *sp) {
       RedisModule_InfoAddFieldCString(ctx, SPEC_SORTABLE_STR, "ON");
     if (FieldSpec_IsNoStem(fs))
       RedisModule_InfoAddFieldCString(ctx, SPEC_NOSTEM_STR, "ON");
+    if (FieldSpec_IsAsciiFold(fs))
+      RedisModule_InfoAddFieldCString(ctx, SPEC_ASCIIFOLD_STR, "ON");
+    if (FieldSpec_IsNFKC(fs))
+      RedisModule_InfoAddFieldCString(ctx, SPEC_NFKC_STR, "ON");
     if (!FieldSpec_IsIndexable(fs))
       RedisModule_InfoAddFieldCString(ctx, SPEC_NOINDEX_STR, "ON");
+    if (FieldSpec_IndexesMissing(fs))
+      RedisModule_InfoAddFieldCString(ctx, SPEC_INDEXMISSING_STR, "ON");
+    if (FieldSpec_IndexesEmpty(fs))
+      RedisModule_InfoAddFieldCString(ctx, SPEC_INDEXEMPTY_STR, "ON");
 
     RedisModule_InfoEndDictField(ctx);
   }
@@ -2162,7 +2534,7 @@ void IndexSpec_AddToInfo(RedisModuleInfoCtx *ctx, IndexSpec *sp) {
 void IndexSpec_ScanAndReindex(RedisModuleCtx *ctx, StrongRef spec_ref) {
   size_t nkeys = RedisModule_DbSize(ctx);
   if (nkeys > 0) {
-    IndexSpec_ScanAndReindexAsync(spec_ref);
+    IndexSpec_ScanAndReindexAsync(spec_ref, false);
   }
 }
 
@@ -2190,7 +2562,7 @@ void IndexSpec_DropLegacyIndexFromKeySpace(IndexSpec *sp) {
   // Delete the numeric, tag, and geo indexes which reside on separate keys
   for (size_t i = 0; i < ctx.spec->numFields; i++) {
     const FieldSpec *fs = ctx.spec->fields + i;
-    if (FIELD_IS(fs, INDEXFLD_T_NUMERIC)) {
+    if (FIELD_IS(fs, INDEXFLD_T_NUMERIC | INDEXFLD_T_DATE)) {
       Redis_DeleteKey(ctx.redisCtx, IndexSpec_GetFormattedKey(ctx.spec, fs, INDEXFLD_T_NUMERIC));
     }
     if (FIELD_IS(fs, INDEXFLD_T_TAG)) {
@@ -2320,6 +2692,12 @@ int IndexSpec_CreateFromRdb(RedisModuleCtx *ctx, RedisModuleIO *rdb, int encver,
       goto cleanup;
   }
 
+  if (encver >= INDEX_STEM_EXCEPTIONS_VERSION && (sp->flags & Index_HasStemExceptions)) {
+    sp->stemExceptions = StemExceptions_RdbLoad(rdb, encver);
+    if (sp->stemExceptions == NULL)
+      goto cleanup;
+  }
+
   sp->timeout = LoadUnsigned_IOError(rdb, goto cleanup);
 
   size_t narr = LoadUnsigned_IOError(rdb, goto cleanup);
@@ -2531,6 +2909,10 @@ void Indexes_RdbSave(RedisModuleIO *rdb, int when) {
       SynonymMap_RdbSave(rdb, sp->smap);
     }
 
+    if (sp->flags & Index_HasStemExceptions) {
+      StemExceptions_RdbSave(rdb, sp->stemExceptions);
+    }
+
     RedisModule_SaveUnsigned(rdb, sp->timeout);
 
     if (sp->aliases) {
@@ -2715,11 +3097,15 @@ void IndexSpec_DeleteDoc_Unsafe(IndexSpec *spec, RedisModuleCtx *ctx, RedisModul
         spec->stats.vectorIndexSize += VecSimIndex_DeleteVector(vecsim, id);
       }
     }
+    VecSimMigration_DeleteDocument(spec, id);
   }
 
   if (spec->flags & Index_HasGeometry) {
     GeometryIndex_RemoveId(ctx, spec, id);
   }
+
+  // Boolean fields clear deleted data on the fly as well
+  BooleanIndex_RemoveId(spec, id);
 }
 
 int IndexSpec_DeleteDoc(IndexSpec *spec, RedisModuleCtx *ctx, RedisModuleString *key) {
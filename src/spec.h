@@ -18,6 +18,7 @@
 #include "stopwords.h"
 #include "gc.h"
 #include "synonym_map.h"
+#include "stem_exceptions.h"
 #include "query_error.h"
 #include "field_spec.h"
 #include "util/dict.h"
@@ -41,6 +42,8 @@ struct DocumentIndexer;
 #define SPEC_TEXT_STR "TEXT"
 #define SPEC_VECTOR_STR "VECTOR"
 #define SPEC_NUMERIC_STR "NUMERIC"
+#define SPEC_BOOLEAN_STR "BOOLEAN"
+#define SPEC_DATE_STR "DATE"
 
 #define SPEC_NOOFFSETS_STR "NOOFFSETS"
 #define SPEC_NOFIELDS_STR "NOFIELDS"
@@ -53,16 +56,24 @@ struct DocumentIndexer;
 #define SPEC_WEIGHT_STR "WEIGHT"
 #define SPEC_NOSTEM_STR "NOSTEM"
 #define SPEC_PHONETIC_STR "PHONETIC"
+#define SPEC_ANALYZER_STR "ANALYZER"
+#define SPEC_ASCIIFOLD_STR "ASCIIFOLD"
+#define SPEC_NFKC_STR "NFKC"
 #define SPEC_SORTABLE_STR "SORTABLE"
 #define SPEC_UNF_STR "UNF"
 #define SPEC_STOPWORDS_STR "STOPWORDS"
 #define SPEC_NOINDEX_STR "NOINDEX"
+#define SPEC_INDEXMISSING_STR "INDEXMISSING"
+#define SPEC_INDEXEMPTY_STR "INDEXEMPTY"
 #define SPEC_TAG_SEPARATOR_STR "SEPARATOR"
 #define SPEC_TAG_CASE_SENSITIVE_STR "CASESENSITIVE"
 #define SPEC_MULTITYPE_STR "MULTITYPE"
 #define SPEC_ASYNC_STR "ASYNC"
 #define SPEC_SKIPINITIALSCAN_STR "SKIPINITIALSCAN"
 #define SPEC_WITHSUFFIXTRIE_STR "WITHSUFFIXTRIE"
+#define SPEC_GEOMETRY_COORD_SYSTEM_STR "COORD_SYSTEM"
+#define SPEC_GEOMETRY_FLAT_STR "FLAT"
+#define SPEC_GEOMETRY_SPHERICAL_STR "SPHERICAL"
 
 #define DEFAULT_SCORE 1.0
 
@@ -154,6 +165,8 @@ typedef enum {
 
   Index_HasGeometry = 0x40000,
 
+  Index_HasStemExceptions = 0x100000,
+
 } IndexFlags;
 
 // redis version (its here because most file include it with no problem,
@@ -186,7 +199,13 @@ typedef uint16_t FieldSpecDedupeArray[SPEC_MAX_FIELDS];
   (Index_StoreFreqs | Index_StoreFieldFlags | Index_StoreTermOffsets | Index_StoreNumeric | \
    Index_WideSchema)
 
-#define INDEX_CURRENT_VERSION 21
+#define INDEX_CURRENT_VERSION 27
+#define INDEX_STEM_EXCEPTIONS_VERSION 27
+#define INDEX_ANALYZER_VERSION 26
+#define INDEX_GEOMETRY_COORD_SYSTEM_VERSION 25
+#define INDEX_VECSIM_COMPRESSION_VERSION 24
+#define INDEX_DATE_VERSION 23
+#define INDEX_BOOLEAN_VERSION 22
 #define INDEX_VECSIM_MULTI_VERSION 21
 #define INDEX_VECSIM_2_VERSION 20
 #define INDEX_VECSIM_VERSION 19
@@ -260,6 +279,8 @@ typedef struct IndexSpec {
   Trie *suffix;                   // Trie of suffix tokens of terms. Used for contains queries
   t_fieldMask suffixMask;         // Mask of all field that support contains query
   dict *keysDict;                 // Global dictionary. Contains inverted indexes of all TEXT TAG NUMERIC VECTOR and GEOMETRY terms
+  dict *missingFieldDict;         // Maps INDEXMISSING field names to inverted indexes of documents missing them
+  dict *emptyFieldDict;           // Maps INDEXEMPTY field names to inverted indexes of documents with an empty value
 
   RSSortingTable *sortables;      // Contains sortable data of documents
 
@@ -270,6 +291,7 @@ typedef struct IndexSpec {
   GCContext *gc;                  // Garbage collection
 
   SynonymMap *smap;               // List of synonym
+  StemExceptions *stemExceptions; // Stems replacing those of the stemmer, set by FT.STEMADD
   char **aliases;                 // Aliases to self-remove when the index is deleted
 
   struct SchemaRule *rule;        // Contains schema rules for follow-the-hash/JSON
@@ -462,6 +484,11 @@ int IndexSpec_CreateTextId(const IndexSpec *sp);
 int IndexSpec_AddFields(StrongRef ref, IndexSpec *sp, RedisModuleCtx *ctx, ArgsCursor *ac, bool initialScan,
                         QueryError *status);
 
+/* Modify the parameters of a vector field. The new vector index is built in the background and
+ * replaces the current one once it holds all the documents */
+int IndexSpec_ModifyVectorField(StrongRef ref, IndexSpec *sp, RedisModuleCtx *ctx, ArgsCursor *ac,
+                                QueryError *status);
+
 /**
  * Checks that the given parameters pass memory limits (used while starting from RDB)
  */
@@ -539,6 +566,21 @@ int IndexSpec_AddTerm(IndexSpec *sp, const char *term, size_t len);
 RedisModuleString *IndexSpec_GetFormattedKey(IndexSpec *sp, const FieldSpec *fs, FieldType forType);
 RedisModuleString *IndexSpec_GetFormattedKeyByName(IndexSpec *sp, const char *s, FieldType forType);
 
+/**
+ * Get the inverted index of the documents missing the field `fs`, which must be defined with
+ * INDEXMISSING. If `create` is set, the index is created if it doesn't exist yet; otherwise NULL
+ * is returned in that case.
+ */
+struct InvertedIndex *IndexSpec_GetMissingFieldIndex(IndexSpec *sp, const FieldSpec *fs,
+                                                     bool create);
+
+/**
+ * Same as IndexSpec_GetMissingFieldIndex, for the inverted index of the documents holding an
+ * empty value in the field `fs`, which must be defined with INDEXEMPTY.
+ */
+struct InvertedIndex *IndexSpec_GetEmptyFieldIndex(IndexSpec *sp, const FieldSpec *fs,
+                                                   bool create);
+
 IndexSpec *NewIndexSpec(const char *name);
 int IndexSpec_AddField(IndexSpec *sp, FieldSpec *fs);
 int IndexSpec_RdbLoad(RedisModuleIO *rdb, int encver, int when);
@@ -561,6 +603,10 @@ size_t IndexSpec_GetVersion(const IndexSpec *sp);
 void IndexSpec_UpdateVersion(IndexSpec *sp);
 
 void IndexSpec_InitializeSynonym(IndexSpec *sp);
+
+/* Replace the stemming exceptions of the index, taking ownership of `se`. Assumes the spec is
+ * locked for writing */
+void IndexSpec_SetStemExceptions(IndexSpec *sp, StemExceptions *se);
 void Indexes_SetTempSpecsTimers(TimerOp op);
 
 //---------------------------------------------------------------------------------------------
@@ -568,6 +614,7 @@ void Indexes_SetTempSpecsTimers(TimerOp op);
 typedef struct IndexesScanner {
   bool global;
   bool cancelled;
+  bool vectorsOnly;  // Only adds the documents to the vector indexes being built by FT.ALTER
   WeakRef spec_ref;
   char *spec_name;
   size_t scannedKeys, totalKeys;
@@ -0,0 +1,153 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include "stem_exceptions.h"
+#include "stemmer.h"
+#include "rmalloc.h"
+#include "rdb.h"
+#include "triemap/triemap.h"
+
+#include <string.h>
+
+struct StemExceptions {
+  // Maps the terms to their stems with the STEM_PREFIX, or to NULL for the terms which are their stem
+  TrieMap *stems;
+  size_t refcount;
+};
+
+StemExceptions *NewStemExceptions() {
+  StemExceptions *se = rm_malloc(sizeof(*se));
+  se->stems = NewTrieMap();
+  se->refcount = 1;
+  return se;
+}
+
+StemExceptions *StemExceptions_Copy(const StemExceptions *se) {
+  StemExceptions *copy = NewStemExceptions();
+  TrieMapIterator *it = TrieMap_Iterate(se->stems, "", 0);
+  char *term;
+  tm_len_t len;
+  void *stem;
+  while (TrieMapIterator_Next(it, &term, &len, &stem)) {
+    TrieMap_Add(copy->stems, term, len, stem ? rm_strdup(stem) : NULL, NULL);
+  }
+  TrieMapIterator_Free(it);
+  return copy;
+}
+
+StemExceptions *StemExceptions_Ref(StemExceptions *se) {
+  __sync_fetch_and_add(&se->refcount, 1);
+  return se;
+}
+
+void StemExceptions_Unref(StemExceptions *se) {
+  if (__sync_sub_and_fetch(&se->refcount, 1)) {
+    return;
+  }
+  TrieMap_Free(se->stems, rm_free);
+  rm_free(se);
+}
+
+static void *replaceStem(void *oldval, void *newval) {
+  rm_free(oldval);
+  return newval;
+}
+
+int StemExceptions_Add(StemExceptions *se, const char *term, size_t len, const char *stem,
+                       size_t stemLen) {
+  char *value = NULL;
+  if (stemLen != len || memcmp(stem, term, len)) {
+    value = rm_malloc(stemLen + 2);
+    value[0] = STEM_PREFIX;
+    memcpy(value + 1, stem, stemLen);
+    value[stemLen + 1] = '\0';
+  }
+  return TrieMap_Add(se->stems, (char *)term, len, value, replaceStem);
+}
+
+int StemExceptions_Del(StemExceptions *se, const char *term, size_t len) {
+  return TrieMap_Delete(se->stems, term, len, rm_free);
+}
+
+bool StemExceptions_Find(const StemExceptions *se, const char *term, size_t len, const char **stem,
+                         size_t *stemLen) {
+  if (!se) {
+    return false;
+  }
+  void *value = TrieMap_Find(se->stems, term, len);
+  if (value == TRIEMAP_NOTFOUND) {
+    return false;
+  }
+  *stem = value;
+  *stemLen = value ? strlen(value) : 0;
+  return true;
+}
+
+size_t StemExceptions_Size(const StemExceptions *se) {
+  return se->stems->cardinality;
+}
+
+typedef void (*stemCallback)(void *arg, const char *term, size_t len, const char *stem,
+                             size_t stemLen);
+
+/* Call `cb` with the terms and their stems, without the STEM_PREFIX */
+static void iterate(const StemExceptions *se, stemCallback cb, void *arg) {
+  TrieMapIterator *it = TrieMap_Iterate(se->stems, "", 0);
+  char *term;
+  tm_len_t len;
+  void *value;
+  while (TrieMapIterator_Next(it, &term, &len, &value)) {
+    if (value) {
+      cb(arg, term, len, (const char *)value + 1, strlen(value) - 1);
+    } else {
+      cb(arg, term, len, term, len);
+    }
+  }
+  TrieMapIterator_Free(it);
+}
+
+static void replyStem(void *ctx, const char *term, size_t len, const char *stem, size_t stemLen) {
+  RedisModule_ReplyWithStringBuffer(ctx, term, len);
+  RedisModule_ReplyWithStringBuffer(ctx, stem, stemLen);
+}
+
+void StemExceptions_Reply(RedisModuleCtx *ctx, const StemExceptions *se) {
+  RedisModule_ReplyWithArray(ctx, StemExceptions_Size(se) * 2);
+  iterate(se, replyStem, ctx);
+}
+
+static void saveStem(void *rdb, const char *term, size_t len, const char *stem, size_t stemLen) {
+  RedisModule_SaveStringBuffer(rdb, term, len);
+  RedisModule_SaveStringBuffer(rdb, stem, stemLen);
+}
+
+void StemExceptions_RdbSave(RedisModuleIO *rdb, const StemExceptions *se) {
+  RedisModule_SaveUnsigned(rdb, StemExceptions_Size(se));
+  iterate(se, saveStem, rdb);
+}
+
+StemExceptions *StemExceptions_RdbLoad(RedisModuleIO *rdb, int encver) {
+  StemExceptions *se = NewStemExceptions();
+  char *term = NULL;
+  uint64_t n = LoadUnsigned_IOError(rdb, goto cleanup);
+  while (n--) {
+    size_t len, stemLen;
+    term = LoadStringBuffer_IOError(rdb, &len, goto cleanup);
+    char *stem = LoadStringBuffer_IOError(rdb, &stemLen, goto cleanup);
+    StemExceptions_Add(se, term, len, stem, stemLen);
+    RedisModule_Free(term);
+    RedisModule_Free(stem);
+    term = NULL;
+  }
+  return se;
+
+cleanup:
+  if (term) {
+    RedisModule_Free(term);
+  }
+  StemExceptions_Unref(se);
+  return NULL;
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "redismodule.h"
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Stemming exceptions of an index, managed with FT.STEMADD and FT.STEMDEL. An exception replaces
+ * the stem the stemmer gives to a term. A term whose stem is the term itself is indexed without a
+ * stem, so that it isn't matched by the other words which had the same stem. The exceptions are
+ * consulted wherever the stemmer is, when indexing and when expanding the query terms.
+ *
+ * The exceptions are read by the indexing threads, so they are never modified once shared: the
+ * commands modify a copy, which replaces the exceptions of the index.
+ */
+typedef struct StemExceptions StemExceptions;
+
+StemExceptions *NewStemExceptions();
+
+/* A copy of the exceptions, with a reference count of 1 */
+StemExceptions *StemExceptions_Copy(const StemExceptions *se);
+
+StemExceptions *StemExceptions_Ref(StemExceptions *se);
+void StemExceptions_Unref(StemExceptions *se);
+
+/* Set the stem of a term. Returns 1 if the term had no exception */
+int StemExceptions_Add(StemExceptions *se, const char *term, size_t len, const char *stem,
+                       size_t stemLen);
+
+/* Remove the exception of a term. Returns 1 if the term had one */
+int StemExceptions_Del(StemExceptions *se, const char *term, size_t len);
+
+/* Whether a term has an exception. If it does, `stem` is set to its stem with the STEM_PREFIX, as
+ * returned by the stemmer, or to NULL if the stem is the term itself. `se` may be NULL */
+bool StemExceptions_Find(const StemExceptions *se, const char *term, size_t len, const char **stem,
+                         size_t *stemLen);
+
+size_t StemExceptions_Size(const StemExceptions *se);
+
+/* Reply with the terms and their stems */
+void StemExceptions_Reply(RedisModuleCtx *ctx, const StemExceptions *se);
+
+void StemExceptions_RdbSave(RedisModuleIO *rdb, const StemExceptions *se);
+StemExceptions *StemExceptions_RdbLoad(RedisModuleIO *rdb, int encver);
+
+#ifdef __cplusplus
+}
+#endif
@@ -8,6 +8,7 @@
 #include "stopwords.h"
 #include "tokenize.h"
 #include "toksep.h"
+#include "normalize.h"
 #include "rmalloc.h"
 #include <ctype.h>
 #include <stdlib.h>
@@ -17,7 +18,11 @@
 typedef struct {
   RSTokenizer base;
   char **pos;
+  const char *end;
   Stemmer *stemmer;
+  // Tokens normalized by the TOKENIZE_ASCIIFOLD and TOKENIZE_NFKC options
+  char *buf;
+  size_t cap;
 } simpleTokenizer;
 
 static void simpleTokenizer_Start(RSTokenizer *base, char *text, size_t len, uint32_t options) {
@@ -27,6 +32,7 @@ static void simpleTokenizer_Start(RSTokenizer *base, char *text, size_t len, uin
   ctx->options = options;
   ctx->len = len;
   self->pos = &ctx->text;
+  self->end = text + len;
 }
 
 // Shortest word which can/should actually be stemmed
@@ -74,6 +80,39 @@ static char *DefaultNormalize(char *s, char *dst, size_t *len) {
   return dst;
 }
 
+/**
+ * Like toksep(), but also splits the text on the characters whose NFKC form is a separator, such
+ * as fullwidth punctuation
+ */
+static char *nfkcToksep(char **s, const char *end, size_t *tokLen) {
+  char *orig = *s;
+  const char *pos = orig;
+  while (pos < end && *pos) {
+    const char *next = pos + 1;
+    int isSep;
+    if ((unsigned char)*pos < 0x80) {
+      isSep = istoksep(*pos) && (pos == orig || pos[-1] != '\\');
+    } else {
+      uint32_t cp;
+      next = Normalize_ReadCodepoint(pos, end, &cp);
+      isSep = next && Normalize_IsNFKCSeparator(cp);
+      if (!next) {
+        next = end;
+      }
+    }
+    if (isSep) {
+      *tokLen = pos - orig;
+      *s = next < end ? (char *)next : NULL;
+      return orig;
+    }
+    pos = next;
+  }
+
+  *s = NULL;
+  *tokLen = pos - orig;
+  return orig;
+}
+
 // tokenize the text in the context
 uint32_t simpleTokenizer_Next(RSTokenizer *base, Token *t) {
   TokenizerCtx *ctx = &base->ctx;
@@ -81,7 +120,8 @@ uint32_t simpleTokenizer_Next(RSTokenizer *base, Token *t) {
   while (*self->pos != NULL) {
     // get the next token
     size_t origLen;
-    char *tok = toksep(self->pos, &origLen);
+    char *tok = (ctx->options & TOKENIZE_NFKC) ? nfkcToksep(self->pos, self->end, &origLen)
+                                               : toksep(self->pos, &origLen);
 
     // normalize the token
     size_t normLen = origLen;
@@ -98,6 +138,12 @@ uint32_t simpleTokenizer_Next(RSTokenizer *base, Token *t) {
     }
 
     char *normalized = DefaultNormalize(tok, normBuf, &normLen);
+    uint32_t flags = Token_CopyStem;
+    if (normalized && (ctx->options & (TOKENIZE_ASCIIFOLD | TOKENIZE_NFKC))) {
+      normalized = Normalize_Token(normalized, &normLen, ctx->options, &self->buf, &self->cap);
+      // The buffer is reused by the next token
+      flags |= Token_CopyRaw;
+    }
     // ignore tokens that turn into nothing
     if (normalized == NULL || normLen == 0) {
       continue;
@@ -113,13 +159,17 @@ uint32_t simpleTokenizer_Next(RSTokenizer *base, Token *t) {
                  .raw = tok,
                  .rawLen = origLen,
                  .pos = ++ctx->lastOffset,
-                 .flags = Token_CopyStem,
+                 .flags = flags,
                  .phoneticsPrimary = t->phoneticsPrimary};
 
-    // if we support stemming - try to stem the word
-    if (!(ctx->options & TOKENIZE_NOSTEM) && self->stemmer && normLen >= MIN_STEM_CANDIDATE_LEN) {
+    // if we support stemming - try to stem the word, unless the index has an exception for it
+    if (!(ctx->options & TOKENIZE_NOSTEM) && self->stemmer) {
       size_t sl;
-      const char *stem = self->stemmer->Stem(self->stemmer->ctx, tok, normLen, &sl);
+      const char *stem = NULL;
+      if (!StemExceptions_Find(ctx->stemExceptions, normalized, normLen, &stem, &sl) &&
+          normLen >= MIN_STEM_CANDIDATE_LEN) {
+        stem = self->stemmer->Stem(self->stemmer->ctx, normalized, normLen, &sl);
+      }
       if (stem) {
         t->stem = stem;
         t->stemLen = sl;
@@ -141,7 +191,9 @@ uint32_t simpleTokenizer_Next(RSTokenizer *base, Token *t) {
   return 0;
 }
 
-void simpleTokenizer_Free(RSTokenizer *self) {
+void simpleTokenizer_Free(RSTokenizer *base) {
+  simpleTokenizer *self = (simpleTokenizer *)base;
+  rm_free(self->buf);
   rm_free(self);
 }
 
@@ -150,6 +202,7 @@ static void doReset(RSTokenizer *tokbase, Stemmer *stemmer, StopWordList *stopwo
   simpleTokenizer *t = (simpleTokenizer *)tokbase;
   t->stemmer = stemmer;
   t->base.ctx.stopwords = stopwords;
+  t->base.ctx.stemExceptions = NULL;
   t->base.ctx.options = opts;
   t->base.ctx.lastOffset = 0;
   if (stopwords) {
@@ -171,6 +224,8 @@ RSTokenizer *NewSimpleTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint3
 
 static mempool_t *tokpoolLatin_g = NULL;
 static mempool_t *tokpoolCn_g = NULL;
+static mempool_t *tokpoolJa_g = NULL;
+static mempool_t *tokpoolKo_g = NULL;
 
 static void *newLatinTokenizerAlloc() {
   return NewSimpleTokenizer(NULL, NULL, 0);
@@ -178,6 +233,12 @@ static void *newLatinTokenizerAlloc() {
 static void *newCnTokenizerAlloc() {
   return NewChineseTokenizer(NULL, NULL, 0);
 }
+static void *newJaTokenizerAlloc() {
+  return NewJapaneseTokenizer(NULL, NULL, 0);
+}
+static void *newKoTokenizerAlloc() {
+  return NewKoreanTokenizer(NULL, NULL, 0);
+}
 static void tokenizerFree(void *p) {
   RSTokenizer *t = p;
   t->Free(t);
@@ -186,6 +247,10 @@ static void tokenizerFree(void *p) {
 RSTokenizer *GetTokenizer(RSLanguage language, Stemmer *stemmer, StopWordList *stopwords) {
   if (language == RS_LANG_CHINESE) {
     return GetChineseTokenizer(stemmer, stopwords);
+  } else if (language == RS_LANG_JAPANESE) {
+    return GetJapaneseTokenizer(stemmer, stopwords);
+  } else if (language == RS_LANG_KOREAN) {
+    return GetKoreanTokenizer(stemmer, stopwords);
   } else {
     return GetSimpleTokenizer(stemmer, stopwords);
   }
@@ -203,6 +268,30 @@ RSTokenizer *GetChineseTokenizer(Stemmer *stemmer, StopWordList *stopwords) {
   return t;
 }
 
+RSTokenizer *GetJapaneseTokenizer(Stemmer *stemmer, StopWordList *stopwords) {
+  if (!tokpoolJa_g) {
+    mempool_options opts = {
+        .initialCap = 16, .alloc = newJaTokenizerAlloc, .free = tokenizerFree};
+    mempool_test_set_global(&tokpoolJa_g, &opts);
+  }
+
+  RSTokenizer *t = mempool_get(tokpoolJa_g);
+  t->Reset(t, stemmer, stopwords, 0);
+  return t;
+}
+
+RSTokenizer *GetKoreanTokenizer(Stemmer *stemmer, StopWordList *stopwords) {
+  if (!tokpoolKo_g) {
+    mempool_options opts = {
+        .initialCap = 16, .alloc = newKoTokenizerAlloc, .free = tokenizerFree};
+    mempool_test_set_global(&tokpoolKo_g, &opts);
+  }
+
+  RSTokenizer *t = mempool_get(tokpoolKo_g);
+  t->Reset(t, stemmer, stopwords, 0);
+  return t;
+}
+
 RSTokenizer *GetSimpleTokenizer(Stemmer *stemmer, StopWordList *stopwords) {
   if (!tokpoolLatin_g) {
     mempool_options opts = {
@@ -223,6 +312,12 @@ void Tokenizer_Release(RSTokenizer *t) {
       t->ctx.stopwords = NULL;
     }
     mempool_release(tokpoolLatin_g, t);
+  } else if (CJKTokenizer_GetLanguage(t) != RS_LANG_UNSUPPORTED) {
+    if (t->ctx.stopwords) {
+      StopWordList_Unref(t->ctx.stopwords);
+      t->ctx.stopwords = NULL;
+    }
+    mempool_release(CJKTokenizer_GetLanguage(t) == RS_LANG_JAPANESE ? tokpoolJa_g : tokpoolKo_g, t);
   } else {
     mempool_release(tokpoolCn_g, t);
   }
@@ -9,6 +9,7 @@
 #define __TOKENIZE_H__
 
 #include "stemmer.h"
+#include "stem_exceptions.h"
 #include "stopwords.h"
 #include "redisearch.h"
 #include "varint.h"
@@ -63,6 +64,8 @@ typedef struct {
   char *text;
   size_t len;
   StopWordList *stopwords;
+  // Consulted before the stemmer. May be NULL
+  const StemExceptions *stemExceptions;
   uint32_t lastOffset;
   uint32_t options;
 } TokenizerCtx;
@@ -78,6 +81,11 @@ typedef struct RSTokenizer {
 
 RSTokenizer *NewSimpleTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint32_t opts);
 RSTokenizer *NewChineseTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint32_t opts);
+RSTokenizer *NewJapaneseTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint32_t opts);
+RSTokenizer *NewKoreanTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint32_t opts);
+
+// The language of a Japanese or Korean tokenizer, or RS_LANG_UNSUPPORTED for other tokenizers
+RSLanguage CJKTokenizer_GetLanguage(const RSTokenizer *t);
 
 #define TOKENIZE_DEFAULT_OPTIONS 0x00
 // Don't modify buffer at all during tokenization.
@@ -86,6 +94,12 @@ RSTokenizer *NewChineseTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint
 #define TOKENIZE_NOSTEM 0x02
 // perform phonetic matching
 #define TOKENIZE_PHONETICS 0x04
+// the text is a single token, e.g. a term of a query which was already tokenized
+#define TOKENIZE_SINGLE_TOKEN 0x08
+// replace the latin letters with diacritics with their ASCII form
+#define TOKENIZE_ASCIIFOLD 0x10
+// normalize the text to its NFKC form, and split it on the characters which become separators
+#define TOKENIZE_NFKC 0x20
 
 /**
  * Pooled tokenizer functions:
@@ -100,6 +114,8 @@ RSTokenizer *NewChineseTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint
  */
 RSTokenizer *GetTokenizer(RSLanguage language, Stemmer *stemmer, StopWordList *stopwords);
 RSTokenizer *GetChineseTokenizer(Stemmer *stemmer, StopWordList *stopwords);
+RSTokenizer *GetJapaneseTokenizer(Stemmer *stemmer, StopWordList *stopwords);
+RSTokenizer *GetKoreanTokenizer(Stemmer *stemmer, StopWordList *stopwords);
 RSTokenizer *GetSimpleTokenizer(Stemmer *stemmer, StopWordList *stopwords);
 void Tokenizer_Release(RSTokenizer *t);
 
@@ -0,0 +1,347 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+/*
+ * Dictionary based tokenization of Japanese and Korean.
+ *
+ * The text is split on separators, and every run of Japanese or Korean characters is segmented
+ * into the words of the lowest cost: words of the dictionary, and unknown words made of characters
+ * of the same script, whose cost grows with their length. Dictionary words marked as STOP, such as
+ * particles and endings, are segmented but not indexed, and neither are unknown words of Hiragana,
+ * which are mostly inflections. The text of other scripts is split and lowercased like the
+ * default tokenizer does.
+ */
+
+#include "tokenize.h"
+#include "toksep.h"
+#include "cjk_dict.h"
+#include "rmalloc.h"
+#include "util/arr.h"
+
+#include <ctype.h>
+#include <string.h>
+
+#define CJK_COST_WORD 10
+#define CJK_COST_STOP 5
+#define CJK_COST_UNKNOWN 5
+#define CJK_COST_UNKNOWN_CHAR 10
+// Unknown words are split after this number of characters
+#define CJK_MAX_UNKNOWN_CHARS 32
+
+typedef enum {
+  CJKChar_Other,  // Latin and other scripts, which are split on separators
+  CJKChar_Separator,
+  CJKChar_Kanji,
+  CJKChar_Hiragana,
+  CJKChar_Katakana,
+  CJKChar_Hangul,
+} CJKCharClass;
+
+#define CJKChar_IsSegmented(c) ((c) >= CJKChar_Kanji)
+
+typedef struct {
+  // Byte offsets in the text
+  uint32_t start;
+  uint32_t end;
+  CJKDictEntry entry;
+  CJKCharClass charClass;
+} cjkWord;
+
+typedef struct {
+  uint32_t cost;
+  // The character where the word ending here starts
+  uint32_t from;
+  CJKDictEntry entry;
+} cjkLatticeNode;
+
+typedef struct {
+  RSTokenizer base;
+  RSLanguage language;
+  const CJKDict *dict;
+  const char *pos;
+  // The words of the last segmented run, and the next one to return
+  cjkWord *words;
+  size_t nextWord;
+  // By character of the run, its byte offset and class, and the best segmentation ending there
+  uint32_t *chars;
+  CJKCharClass *charClasses;
+  cjkLatticeNode *lattice;
+  // Lowercased tokens of other scripts
+  char *buf;
+  size_t cap;
+} cjkTokenizer;
+
+/* Read the character at `s`. Invalid and truncated sequences are read byte by byte */
+static const char *readChar(const char *s, const char *end, uint32_t *cp) {
+  const unsigned char *u = (const unsigned char *)s;
+  size_t n = (u[0] & 0xE0) == 0xC0 ? 2 : (u[0] & 0xF0) == 0xE0 ? 3 : (u[0] & 0xF8) == 0xF0 ? 4 : 1;
+  if (n == 1 || s + n > end) {
+    *cp = u[0];
+    return s + 1;
+  }
+  *cp = u[0] & (0x7F >> n);
+  for (size_t ii = 1; ii < n; ++ii) {
+    if ((u[ii] & 0xC0) != 0x80) {
+      *cp = u[0];
+      return s + 1;
+    }
+    *cp = (*cp << 6) | (u[ii] & 0x3F);
+  }
+  return s + n;
+}
+
+static CJKCharClass charClass(uint32_t cp) {
+  if (cp < 0x80) {
+    return istoksep(cp) || isspace(cp) || iscntrl(cp) ? CJKChar_Separator : CJKChar_Other;
+  } else if (cp == 0x3005 || cp == 0x3006 || (cp >= 0x3400 && cp <= 0x4DBF) ||
+             (cp >= 0x4E00 && cp <= 0x9FFF) || (cp >= 0xF900 && cp <= 0xFAFF)) {
+    // Including the iteration mark 々
+    return CJKChar_Kanji;
+  } else if (cp >= 0x3041 && cp <= 0x309F) {
+    return CJKChar_Hiragana;
+  } else if ((cp >= 0x30A1 && cp <= 0x30FF && cp != 0x30FB) || (cp >= 0x31F0 && cp <= 0x31FF) ||
+             (cp >= 0xFF66 && cp <= 0xFF9F)) {
+    // Including the prolonged sound mark ー, but not the middle dot ・
+    return CJKChar_Katakana;
+  } else if ((cp >= 0xAC00 && cp <= 0xD7A3) || (cp >= 0x1100 && cp <= 0x11FF) ||
+             (cp >= 0x3131 && cp <= 0x318E)) {
+    return CJKChar_Hangul;
+  } else if ((cp >= 0x2000 && cp <= 0x206F) || (cp >= 0x3000 && cp <= 0x30FF) ||
+             (cp >= 0xFF00 && cp <= 0xFF65 && !(cp >= 0xFF10 && cp <= 0xFF19) &&
+              !(cp >= 0xFF21 && cp <= 0xFF3A) && !(cp >= 0xFF41 && cp <= 0xFF5A))) {
+    // General and CJK punctuation, and the full width forms of the ASCII punctuation
+    return CJKChar_Separator;
+  }
+  return CJKChar_Other;
+}
+
+static void relax(cjkLatticeNode *to, uint32_t cost, uint32_t from, CJKDictEntry entry) {
+  if (cost < to->cost) {
+    *to = (cjkLatticeNode){.cost = cost, .from = from, .entry = entry};
+  }
+}
+
+/* Segment the run of characters between `start` and `end` into the words to return */
+static void segment(cjkTokenizer *self, const char *start, const char *end) {
+  const char *text = self->base.ctx.text;
+  array_clear(self->chars);
+  array_clear(self->charClasses);
+  for (const char *p = start; p < end;) {
+    uint32_t cp;
+    const char *next = readChar(p, end, &cp);
+    self->chars = array_append(self->chars, p - text);
+    self->charClasses = array_append(self->charClasses, charClass(cp));
+    p = next;
+  }
+  uint32_t n = array_len(self->chars);
+  self->chars = array_append(self->chars, end - text);
+
+  array_clear(self->lattice);
+  for (uint32_t ii = 0; ii <= n; ++ii) {
+    cjkLatticeNode node = {.cost = ii ? UINT32_MAX : 0};
+    self->lattice = array_append(self->lattice, node);
+  }
+
+  size_t maxChars = self->dict ? CJKDict_MaxChars(self->dict) : 0;
+  for (uint32_t ii = 0; ii < n; ++ii) {
+    uint32_t cost = self->lattice[ii].cost;
+    if (cost == UINT32_MAX) {
+      continue;
+    }
+    // Dictionary words come first, so that they win ties with unknown words
+    for (uint32_t jj = ii + 1; jj <= n && jj - ii <= maxChars; ++jj) {
+      CJKDictEntry entry = CJKDict_Find(self->dict, text + self->chars[ii],
+                                        self->chars[jj] - self->chars[ii]);
+      if (entry != CJKDict_NotFound) {
+        relax(self->lattice + jj, cost + (entry == CJKDict_Stop ? CJK_COST_STOP : CJK_COST_WORD),
+              ii, entry);
+      }
+    }
+    CJKCharClass cls = self->charClasses[ii];
+    for (uint32_t jj = ii + 1; jj <= n && jj - ii <= CJK_MAX_UNKNOWN_CHARS &&
+                               self->charClasses[jj - 1] == cls;
+         ++jj) {
+      uint32_t unknownCost = CJK_COST_UNKNOWN + CJK_COST_UNKNOWN_CHAR * (jj - ii);
+      // A single Hangul syllable is rarely a word, but often a particle
+      if (cls == CJKChar_Hangul && jj - ii == 1) {
+        unknownCost += CJK_COST_UNKNOWN_CHAR;
+      }
+      // Particles end words, so splitting one from the start of an unknown word of the same script
+      // costs more than extending the unknown word over it
+      if (ii && self->lattice[ii].entry == CJKDict_Stop && self->charClasses[ii - 1] == cls) {
+        unknownCost += CJK_COST_UNKNOWN_CHAR * (ii - self->lattice[ii].from);
+      }
+      relax(self->lattice + jj, cost + unknownCost, ii, CJKDict_NotFound);
+    }
+  }
+
+  // Collect the words of the best segmentation, from the end
+  array_clear(self->words);
+  for (uint32_t ii = n; ii > 0; ii = self->lattice[ii].from) {
+    uint32_t from = self->lattice[ii].from;
+    cjkWord word = {.start = self->chars[from],
+                    .end = self->chars[ii],
+                    .entry = self->lattice[ii].entry,
+                    .charClass = self->charClasses[from]};
+    self->words = array_append(self->words, word);
+  }
+  size_t numWords = array_len(self->words);
+  for (size_t ii = 0; ii < numWords / 2; ++ii) {
+    cjkWord tmp = self->words[ii];
+    self->words[ii] = self->words[numWords - ii - 1];
+    self->words[numWords - ii - 1] = tmp;
+  }
+  self->nextWord = 0;
+}
+
+static int isIndexed(const cjkWord *word) {
+  if (word->entry == CJKDict_Stop) {
+    return 0;
+  }
+  return word->entry != CJKDict_NotFound || word->charClass != CJKChar_Hiragana;
+}
+
+static void cjkTokenizer_Start(RSTokenizer *base, char *text, size_t len, uint32_t options) {
+  cjkTokenizer *self = (cjkTokenizer *)base;
+  base->ctx.text = text;
+  base->ctx.len = len;
+  base->ctx.options = options;
+  self->pos = text;
+  array_clear(self->words);
+  self->nextWord = 0;
+}
+
+static uint32_t cjkTokenizer_Next(RSTokenizer *base, Token *t) {
+  cjkTokenizer *self = (cjkTokenizer *)base;
+  TokenizerCtx *ctx = &base->ctx;
+  const char *end = ctx->text + ctx->len;
+
+  while (1) {
+    const char *tok, *raw;
+    size_t tokLen, rawLen;
+
+    if (self->nextWord < array_len(self->words)) {
+      const cjkWord *word = self->words + self->nextWord++;
+      if (!isIndexed(word)) {
+        continue;
+      }
+      tok = raw = ctx->text + word->start;
+      tokLen = rawLen = word->end - word->start;
+
+    } else if (self->pos < end) {
+      const char *start = self->pos;
+      uint32_t cp;
+      const char *next = readChar(start, end, &cp);
+      CJKCharClass cls = charClass(cp);
+      if (cls == CJKChar_Separator) {
+        self->pos = next;
+        continue;
+      }
+
+      // Read the run of characters to segment, or the word of another script
+      const char *p = next;
+      while (p < end) {
+        next = readChar(p, end, &cp);
+        CJKCharClass nextCls = charClass(cp);
+        if (CJKChar_IsSegmented(nextCls) != CJKChar_IsSegmented(cls) ||
+            nextCls == CJKChar_Separator) {
+          break;
+        }
+        p = next;
+      }
+      self->pos = p;
+      if (CJKChar_IsSegmented(cls)) {
+        segment(self, start, p);
+        continue;
+      }
+
+      rawLen = tokLen = p - start;
+      if (tokLen + 1 > self->cap) {
+        self->cap = tokLen + 1;
+        self->buf = rm_realloc(self->buf, self->cap);
+      }
+      for (size_t ii = 0; ii < tokLen; ++ii) {
+        self->buf[ii] = tolower((unsigned char)start[ii]);
+      }
+      self->buf[tokLen] = '\0';
+      tok = self->buf;
+      raw = start;
+
+    } else {
+      return 0;
+    }
+
+    if (StopWordList_Contains(ctx->stopwords, tok, tokLen)) {
+      continue;
+    }
+    *t = (Token){.tok = tok,
+                 .tokLen = tokLen,
+                 .raw = raw,
+                 .rawLen = rawLen,
+                 .pos = ++ctx->lastOffset,
+                 .flags = Token_CopyRaw | Token_CopyStem,
+                 .phoneticsPrimary = t->phoneticsPrimary};
+    return ctx->lastOffset;
+  }
+}
+
+static void cjkTokenizer_Free(RSTokenizer *base) {
+  cjkTokenizer *self = (cjkTokenizer *)base;
+  if (base->ctx.stopwords) {
+    StopWordList_Unref(base->ctx.stopwords);
+  }
+  array_free(self->words);
+  array_free(self->chars);
+  array_free(self->charClasses);
+  array_free(self->lattice);
+  rm_free(self->buf);
+  rm_free(self);
+}
+
+static void cjkTokenizer_Reset(RSTokenizer *base, Stemmer *stemmer, StopWordList *stopwords,
+                               uint32_t opts) {
+  // Words are not stemmed
+  if (stopwords) {
+    StopWordList_Ref(stopwords);
+  }
+  if (base->ctx.stopwords) {
+    StopWordList_Unref(base->ctx.stopwords);
+  }
+  base->ctx.stopwords = stopwords;
+  base->ctx.options = opts;
+  base->ctx.lastOffset = 0;
+}
+
+static RSTokenizer *newCJKTokenizer(RSLanguage language, StopWordList *stopwords, uint32_t opts) {
+  cjkTokenizer *self = rm_calloc(1, sizeof(*self));
+  self->language = language;
+  self->dict = CJKDict_Get(language);
+  self->words = array_new(cjkWord, 16);
+  self->chars = array_new(uint32_t, 64);
+  self->charClasses = array_new(CJKCharClass, 64);
+  self->lattice = array_new(cjkLatticeNode, 64);
+  self->base.Start = cjkTokenizer_Start;
+  self->base.Next = cjkTokenizer_Next;
+  self->base.Free = cjkTokenizer_Free;
+  self->base.Reset = cjkTokenizer_Reset;
+  self->base.Reset(&self->base, NULL, stopwords, opts);
+  return &self->base;
+}
+
+RSTokenizer *NewJapaneseTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint32_t opts) {
+  return newCJKTokenizer(RS_LANG_JAPANESE, stopwords, opts);
+}
+
+RSTokenizer *NewKoreanTokenizer(Stemmer *stemmer, StopWordList *stopwords, uint32_t opts) {
+  return newCJKTokenizer(RS_LANG_KOREAN, stopwords, opts);
+}
+
+RSLanguage CJKTokenizer_GetLanguage(const RSTokenizer *t) {
+  if (t->Next != cjkTokenizer_Next) {
+    return RS_LANG_UNSUPPORTED;
+  }
+  return ((const cjkTokenizer *)t)->language;
+}
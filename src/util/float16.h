@@ -0,0 +1,105 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#ifndef RS_FLOAT16_H_
+#define RS_FLOAT16_H_
+
+#include <stdint.h>
+#include <string.h>
+
+/* Conversions of single precision floats to the 16 bit formats stored in vector blobs.
+ * Both round to the nearest value, ties to even. The conversions back are exact. */
+
+// IEEE 754 half precision: 1 sign bit, 5 exponent bits, 10 mantissa bits
+static inline uint16_t float_to_fp16(float f) {
+  uint32_t x;
+  memcpy(&x, &f, sizeof(x));
+  uint16_t sign = (x >> 16) & 0x8000;
+  uint32_t exp = (x >> 23) & 0xff;
+  uint32_t mant = x & 0x7fffff;
+
+  if (exp == 0xff) {
+    // Inf or NaN (keep NaN quiet)
+    return sign | 0x7c00 | (mant ? 0x200 : 0);
+  }
+  int32_t e = (int32_t)exp - 127 + 15;
+  if (e >= 0x1f) {
+    // Overflow to infinity
+    return sign | 0x7c00;
+  }
+  if (e <= 0) {
+    // Subnormal half, or underflow to zero
+    if (e < -10) {
+      return sign;
+    }
+    mant |= 0x800000;
+    uint32_t shift = 14 - e;
+    uint32_t half = mant >> shift;
+    uint32_t rem = mant & ((1u << shift) - 1);
+    uint32_t mid = 1u << (shift - 1);
+    if (rem > mid || (rem == mid && (half & 1))) {
+      half++;
+    }
+    return sign | half;
+  }
+  uint16_t half = sign | (e << 10) | (mant >> 13);
+  uint32_t rem = mant & 0x1fff;
+  if (rem > 0x1000 || (rem == 0x1000 && (half & 1))) {
+    // May carry into the exponent, which correctly rounds up to the next power of 2 or to infinity
+    half++;
+  }
+  return half;
+}
+
+// bfloat16: the upper half of a single precision float
+static inline uint16_t float_to_bf16(float f) {
+  uint32_t x;
+  memcpy(&x, &f, sizeof(x));
+  if ((x & 0x7fffffff) > 0x7f800000) {
+    // NaN (keep NaN quiet)
+    return (x >> 16) | 0x40;
+  }
+  x += 0x7fff + ((x >> 16) & 1);
+  return x >> 16;
+}
+
+static inline float fp16_to_float(uint16_t h) {
+  uint32_t sign = (uint32_t)(h & 0x8000) << 16;
+  uint32_t exp = (h >> 10) & 0x1f;
+  uint32_t mant = h & 0x3ff;
+  uint32_t x;
+
+  if (exp == 0x1f) {
+    // Inf or NaN
+    x = sign | 0x7f800000 | (mant << 13);
+  } else if (exp == 0) {
+    if (mant == 0) {
+      x = sign;
+    } else {
+      // Subnormal half - normalize it
+      exp = 127 - 15 + 1;
+      while (!(mant & 0x400)) {
+        mant <<= 1;
+        exp--;
+      }
+      x = sign | (exp << 23) | ((mant & 0x3ff) << 13);
+    }
+  } else {
+    x = sign | ((exp - 15 + 127) << 23) | (mant << 13);
+  }
+  float f;
+  memcpy(&f, &x, sizeof(f));
+  return f;
+}
+
+static inline float bf16_to_float(uint16_t b) {
+  uint32_t x = (uint32_t)b << 16;
+  float f;
+  memcpy(&f, &x, sizeof(f));
+  return f;
+}
+
+#endif
@@ -318,3 +318,93 @@ void QS_Dump(const QuantStream *stream, FILE *fp) {
 size_t QS_GetCount(const QuantStream *stream) {
   return stream->n;
 }
+
+/** Serialized stream format */
+typedef struct __attribute__((packed)) {
+  uint32_t flags;  // Currently unused
+  uint64_t n;
+  uint32_t numSamples;
+} QSSerializedHeader;
+
+/** Each of the samples following the header, ordered by value */
+typedef struct __attribute__((packed)) {
+  double v;
+  float g;
+  float d;
+} QSSerializedSample;
+
+char *QS_Serialize(QuantStream *stream, size_t *len) {
+  if (stream->bufferLength) {
+    QS_Flush(stream);
+  }
+
+  QSSerializedHeader hdr = {.flags = 0, .n = stream->n, .numSamples = stream->samplesLength};
+  *len = sizeof(hdr) + stream->samplesLength * sizeof(QSSerializedSample);
+  char *buf = rm_malloc(*len);
+  memcpy(buf, &hdr, sizeof(hdr));
+
+  char *pos = buf + sizeof(hdr);
+  for (const Sample *cur = stream->firstSample; cur; cur = cur->next) {
+    QSSerializedSample sample = {.v = cur->v, .g = cur->g, .d = cur->d};
+    memcpy(pos, &sample, sizeof(sample));
+    pos += sizeof(sample);
+  }
+  return buf;
+}
+
+int QS_Merge(QuantStream *stream, const char *buf, size_t len) {
+  QSSerializedHeader hdr;
+  if (len < sizeof(hdr)) {
+    return 0;
+  }
+  memcpy(&hdr, buf, sizeof(hdr));
+  if (len != sizeof(hdr) + (size_t)hdr.numSamples * sizeof(QSSerializedSample)) {
+    return 0;
+  }
+
+  // Verify the samples are ordered before touching the stream
+  const char *samples = buf + sizeof(hdr);
+  QSSerializedSample sample, prev;
+  for (uint32_t ii = 0; ii < hdr.numSamples; ++ii) {
+    memcpy(&sample, samples + ii * sizeof(sample), sizeof(sample));
+    if (isnan(sample.v) || !(sample.g >= 0) || (ii && sample.v < prev.v)) {
+      return 0;
+    }
+    prev = sample;
+  }
+
+  if (stream->bufferLength) {
+    QS_Flush(stream);
+  }
+
+  // Both lists are ordered, so we only advance in the stream's samples
+  Sample *pos = stream->firstSample;
+  for (uint32_t ii = 0; ii < hdr.numSamples; ++ii) {
+    memcpy(&sample, samples + ii * sizeof(sample), sizeof(sample));
+    while (pos && pos->v <= sample.v) {
+      pos = pos->next;
+    }
+
+    Sample *newSample = QS_NewSample(stream);
+    newSample->v = sample.v;
+    newSample->g = sample.g;
+    newSample->d = sample.d;
+    if (pos) {
+      // The rank of the new sample is also uncertain within the span of the sample it precedes
+      double span = pos->g + pos->d - 1;
+      if (span > 0) {
+        newSample->d += span;
+      }
+      QS_InsertSampleAt(stream, pos, newSample);
+    } else {
+      QS_AppendSample(stream, newSample);
+    }
+  }
+  stream->n += hdr.n;
+
+  // Keep small streams exact, like streams which were never flushed
+  if (stream->samplesLength > stream->bufferCap) {
+    QS_Compress(stream);
+  }
+  return 1;
+}
@@ -19,4 +19,12 @@ void QS_Free(QuantStream *qs);
 void QS_Dump(const QuantStream *stream, FILE *fp);
 size_t QS_GetCount(const QuantStream *stream);
 
+/* Serialize the samples of the stream, so they can be merged into another stream. The returned
+ * buffer is allocated with rm_malloc */
+char *QS_Serialize(QuantStream *stream, size_t *len);
+
+/* Merge a serialized stream into `stream`. Returns 0 if the buffer is not a valid serialized
+ * stream, in which case `stream` is not modified */
+int QS_Merge(QuantStream *stream, const char *buf, size_t len);
+
 #endif
\ No newline at end of file
@@ -0,0 +1,516 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#include <math.h>
+#include "vector_compression.h"
+#include "vector_index.h"
+#include "doc_table.h"
+#include "json.h"
+#include "rmalloc.h"
+#include "util/arr.h"
+#include "util/float16.h"
+
+#define SQ8_MAX 127
+
+static inline const VecSimParams *fieldParams(const FieldSpec *fs) {
+  return &fs->vectorOpts.vecSimParams;
+}
+
+static inline size_t fieldDim(const FieldSpec *fs) {
+  const VecSimParams *params = fieldParams(fs);
+  return params->algo == VecSimAlgo_HNSWLIB ? params->hnswParams.dim : params->bfParams.dim;
+}
+
+static inline VecSimType fieldType(const FieldSpec *fs) {
+  const VecSimParams *params = fieldParams(fs);
+  return params->algo == VecSimAlgo_HNSWLIB ? params->hnswParams.type : params->bfParams.type;
+}
+
+static inline VecSimMetric fieldMetric(const FieldSpec *fs) {
+  const VecSimParams *params = fieldParams(fs);
+  return params->algo == VecSimAlgo_HNSWLIB ? params->hnswParams.metric : params->bfParams.metric;
+}
+
+static void blobToDoubles(const FieldSpec *fs, const void *vec, double *out) {
+  size_t dim = fieldDim(fs);
+  switch (fieldType(fs)) {
+    case VecSimType_FLOAT64:
+      memcpy(out, vec, dim * sizeof(double));
+      break;
+    case VecSimType_FLOAT16:
+      for (size_t i = 0; i < dim; i++) {
+        out[i] = fp16_to_float(((const uint16_t *)vec)[i]);
+      }
+      break;
+    case VecSimType_BFLOAT16:
+      for (size_t i = 0; i < dim; i++) {
+        out[i] = bf16_to_float(((const uint16_t *)vec)[i]);
+      }
+      break;
+    case VecSimType_INT8:
+      for (size_t i = 0; i < dim; i++) {
+        out[i] = ((const int8_t *)vec)[i];
+      }
+      break;
+    case VecSimType_UINT8:
+      for (size_t i = 0; i < dim; i++) {
+        out[i] = ((const uint8_t *)vec)[i];
+      }
+      break;
+    default:
+      for (size_t i = 0; i < dim; i++) {
+        out[i] = ((const float *)vec)[i];
+      }
+      break;
+  }
+}
+
+static double norm(const double *v, size_t dim) {
+  double sum = 0;
+  for (size_t i = 0; i < dim; i++) {
+    sum += v[i] * v[i];
+  }
+  return sqrt(sum);
+}
+
+VecSimParams VecSimCompression_IndexParams(const FieldSpec *fs) {
+  VecSimParams params = fs->vectorOpts.vecSimParams;
+  if (FieldSpec_IsVectorCompressed(fs)) {
+    params.hnswParams.type = VECSIM_SQ8_TYPE;
+  }
+  return params;
+}
+
+void VecSimCompression_Quantize(const FieldSpec *fs, const void *vec, int8_t *out) {
+  size_t dim = fieldDim(fs);
+  double *v = rm_malloc(dim * sizeof(*v));
+  blobToDoubles(fs, vec, v);
+
+  double scale = SQ8_MAX / fs->vectorOpts.compression.range;
+  if (fieldMetric(fs) == VecSimMetric_Cosine) {
+    double n = norm(v, dim);
+    if (n > 0) {
+      scale /= n;
+    }
+  }
+  for (size_t i = 0; i < dim; i++) {
+    double x = round(v[i] * scale);
+    out[i] = x > SQ8_MAX ? SQ8_MAX : x < -SQ8_MAX ? -SQ8_MAX : (int8_t)x;
+  }
+  rm_free(v);
+}
+
+double VecSimCompression_QuantizeRadius(const FieldSpec *fs, double radius) {
+  double scale = SQ8_MAX / fs->vectorOpts.compression.range;
+  switch (fieldMetric(fs)) {
+    case VecSimMetric_L2:
+      // Squared euclidean distance
+      return radius * scale * scale;
+    case VecSimMetric_IP:
+      // 1 - the inner product
+      return 1 - (1 - radius) * scale * scale;
+    case VecSimMetric_Cosine:
+      // Does not depend on the scale
+      return radius;
+  }
+  return radius;
+}
+
+size_t VecSimCompression_RawMemory(const FieldSpec *fs, VecSimIndex *index, size_t memory) {
+  if (!FieldSpec_IsVectorCompressed(fs)) {
+    return memory;
+  }
+  size_t saved = VecSimType_sizeof(fieldType(fs)) - sizeof(int8_t);
+  return memory + VecSimIndex_IndexSize(index) * fieldDim(fs) * saved;
+}
+
+size_t VecSimCompression_SpecRawMemory(IndexSpec *sp) {
+  size_t memory = sp->stats.vectorIndexSize;
+  for (size_t i = 0; i < sp->numFields; i++) {
+    const FieldSpec *fs = sp->fields + i;
+    if (!FIELD_IS(fs, INDEXFLD_T_VECTOR) || !FieldSpec_IsVectorCompressed(fs)) {
+      continue;
+    }
+    RedisModuleString *keyName = IndexSpec_GetFormattedKey(sp, fs, INDEXFLD_T_VECTOR);
+    VecSimIndex *index = OpenVectorIndex(sp, keyName);
+    if (index) {
+      memory = VecSimCompression_RawMemory(fs, index, memory);
+    }
+  }
+  return memory;
+}
+
+/*******************************************************************************************************************
+ *  Reranking
+ *******************************************************************************************************************/
+
+static bool loadHashVector(RedisModuleCtx *ctx, const FieldSpec *fs, const char *keyPtr, double *out) {
+  RedisModuleString *keyName = RedisModule_CreateString(ctx, keyPtr, strlen(keyPtr));
+  RedisModuleKey *key = RedisModule_OpenKey(ctx, keyName, REDISMODULE_READ);
+  RedisModule_FreeString(ctx, keyName);
+  if (!key) {
+    return false;
+  }
+
+  bool ok = false;
+  RedisModuleString *val = NULL;
+  if (RedisModule_KeyType(key) == REDISMODULE_KEYTYPE_HASH &&
+      RedisModule_HashGet(key, REDISMODULE_HASH_CFIELDS, fs->path, &val, NULL) == REDISMODULE_OK &&
+      val) {
+    size_t len;
+    const char *blob = RedisModule_StringPtrLen(val, &len);
+    if (len == fs->vectorOpts.expBlobSize) {
+      blobToDoubles(fs, blob, out);
+      ok = true;
+    }
+    RedisModule_FreeString(ctx, val);
+  }
+  RedisModule_CloseKey(key);
+  return ok;
+}
+
+// Read a JSON array of `dim` numbers
+static bool readJSONVector(RedisJSON arr, size_t dim, double *out) {
+  size_t len;
+  if (japi->getType(arr) != JSONType_Array || japi->getLen(arr, &len) != REDISMODULE_OK || len != dim) {
+    return false;
+  }
+  for (size_t i = 0; i < len; i++) {
+    RedisJSON element = japi->getAt(arr, i);
+    long long intval;
+    if (japi->getDouble(element, &out[i]) != REDISMODULE_OK) {
+      // On RedisJSON<2.0.9, getDouble can't handle integer values
+      if (japi->getInt(element, &intval) != REDISMODULE_OK) {
+        return false;
+      }
+      out[i] = intval;
+    }
+  }
+  return true;
+}
+
+typedef struct {
+  double *vecs;
+  size_t n, cap, dim;
+} JSONVectors;
+
+static bool appendJSONVector(JSONVectors *vecs, RedisJSON arr) {
+  if (vecs->n == vecs->cap) {
+    vecs->cap = vecs->cap ? vecs->cap * 2 : 1;
+    vecs->vecs = rm_realloc(vecs->vecs, vecs->cap * vecs->dim * sizeof(double));
+  }
+  if (!readJSONVector(arr, vecs->dim, vecs->vecs + vecs->n * vecs->dim)) {
+    return false;
+  }
+  vecs->n++;
+  return true;
+}
+
+// An element of a multi-value field is either a vector or an array of vectors (nulls are skipped),
+// as it is indexed by JSON_StoreMultiVectorInDocField
+static bool appendJSONElementVectors(JSONVectors *vecs, RedisJSON element) {
+  size_t len;
+  if (japi->getType(element) == JSONType_Null) {
+    return true;
+  }
+  if (japi->getType(element) != JSONType_Array || japi->getLen(element, &len) != REDISMODULE_OK) {
+    return false;
+  }
+  if (!JSON_IsArrayOfVectors(element, len)) {
+    return appendJSONVector(vecs, element);
+  }
+  for (size_t i = 0; i < len; i++) {
+    RedisJSON vec = japi->getAt(element, i);
+    if (japi->getType(vec) != JSONType_Null && !appendJSONVector(vecs, vec)) {
+      return false;
+    }
+  }
+  return true;
+}
+
+// Load the vectors of the field of a JSON document, in the order they were indexed.
+// Returns false if the vectors can't be read.
+static bool loadJSONVectors(RedisModuleCtx *ctx, const FieldSpec *fs, const char *keyPtr, JSONVectors *vecs) {
+  *vecs = (JSONVectors){.dim = fieldDim(fs)};
+  if (!japi) {
+    return false;
+  }
+  RedisJSON json = japi->openKeyFromStr(ctx, keyPtr);
+  if (!json) {
+    return false;
+  }
+  JSONResultsIterator iter = japi->get(json, fs->path);
+  if (!iter) {
+    return false;
+  }
+
+  bool ok = true;
+  size_t len;
+  RedisJSON match;
+  if (japi->len(iter) == 1) {
+    // A single vector, or an array of elements
+    match = japi->next(iter);
+    if (japi->getType(match) == JSONType_Array && japi->getLen(match, &len) == REDISMODULE_OK && len > 0 &&
+        japi->getType(japi->getAt(match, 0)) == JSONType_Array) {
+      for (size_t i = 0; i < len && ok; i++) {
+        ok = appendJSONElementVectors(vecs, japi->getAt(match, i));
+      }
+    } else if (japi->getType(match) != JSONType_Null) {
+      ok = appendJSONVector(vecs, match);
+    }
+  } else {
+    while (ok && (match = japi->next(iter))) {
+      ok = appendJSONElementVectors(vecs, match);
+    }
+  }
+  japi->freeIter(iter);
+  if (!ok) {
+    rm_free(vecs->vecs);
+    vecs->vecs = NULL;
+    vecs->n = 0;
+  }
+  return ok;
+}
+
+// Distances as computed by the vector library
+static double fullPrecisionDistance(VecSimMetric metric, const double *q, const double *v, size_t dim) {
+  double sum = 0;
+  switch (metric) {
+    case VecSimMetric_L2:
+      for (size_t i = 0; i < dim; i++) {
+        sum += (q[i] - v[i]) * (q[i] - v[i]);
+      }
+      return sum;
+    case VecSimMetric_IP:
+      for (size_t i = 0; i < dim; i++) {
+        sum += q[i] * v[i];
+      }
+      return 1 - sum;
+    case VecSimMetric_Cosine: {
+      double n = norm(q, dim) * norm(v, dim);
+      if (n == 0) {
+        return 1;
+      }
+      for (size_t i = 0; i < dim; i++) {
+        sum += q[i] * v[i];
+      }
+      return 1 - sum / n;
+    }
+  }
+  return NAN;
+}
+
+/*******************************************************************************************************************
+ *  Vector Loader Processor
+ *
+ * The results of a query over a compressed index come with their distance in the quantized space.
+ * The processor reads the full precision vectors of the results from the documents, and replaces
+ * their distances by the full precision ones. It also yields the position of the vector of every
+ * result which matched a KNN query, among the vectors of a multi-value field. It accesses the
+ * keyspace, so when the query runs in a background thread it runs after the spec lock is released,
+ * with the GIL held.
+ *
+ * A KNN query yields `RERANK_FACTOR * K` candidates, which are all collected, and the best K of
+ * them are yielded by ascending distance. The results of a range query are yielded as they come,
+ * if they are within the radius.
+ *******************************************************************************************************************/
+
+typedef struct {
+  SearchResult r;
+  double distance;
+} vectorHit;
+
+typedef struct {
+  ResultProcessor base;
+  FieldSpec fs;               // A copy, since the spec is not locked while the processor runs
+  double *query;              // The full precision query vector
+  bool rerank;                // Whether the field is compressed
+  const RLookupKey *distKey;  // The key the distance is yielded as, if any
+  const RLookupKey *matchKey; // The key the matched vector position is yielded as, if requested
+  size_t k;                   // KNN - the number of results to yield
+  double maxDistance;         // The radius of a range query, or the distance cutoff of a KNN query
+  vectorHit **hits;           // array_*, KNN - the collected candidates
+  size_t next;                // next hit to yield
+} RPVectorLoader;
+
+static void vectorHitFree(vectorHit *h) {
+  SearchResult_Destroy(&h->r);
+  rm_free(h);
+}
+
+// Read the vectors of the result, and find the one closest to the query vector. Its distance
+// replaces the distance of the result if the field is compressed, and its position is yielded if
+// requested. Returns false if the vectors can't be read.
+static bool loadResult(RPVectorLoader *self, SearchResult *r, double *distance) {
+  const RSDocumentMetadata *dmd = r->dmd;
+  if (!dmd) {
+    return false;
+  }
+  RedisModuleCtx *ctx = self->base.parent->sctx->redisCtx;
+  JSONVectors vecs = {.dim = fieldDim(&self->fs)};
+  if (dmd->type == DocumentType_Json) {
+    loadJSONVectors(ctx, &self->fs, dmd->keyPtr, &vecs);
+  } else {
+    // Hash fields hold a single vector
+    vecs.vecs = rm_malloc(vecs.dim * sizeof(double));
+    vecs.n = loadHashVector(ctx, &self->fs, dmd->keyPtr, vecs.vecs);
+  }
+
+  int matched = -1;
+  VecSimMetric metric = fieldMetric(&self->fs);
+  for (size_t i = 0; i < vecs.n; i++) {
+    double d = fullPrecisionDistance(metric, self->query, vecs.vecs + i * vecs.dim, vecs.dim);
+    if (matched < 0 || d < *distance) {
+      *distance = d;
+      matched = i;
+    }
+  }
+  rm_free(vecs.vecs);
+  if (matched < 0) {
+    return false;
+  }
+
+  if (self->rerank && self->distKey) {
+    RLookup_WriteOwnKey(self->distKey, &r->rowdata, RS_NumVal(*distance));
+  }
+  if (self->matchKey) {
+    RLookup_WriteOwnKey(self->matchKey, &r->rowdata, RS_NumVal(matched));
+  }
+  return true;
+}
+
+static int rpVectorLoaderNext_Match(ResultProcessor *rp, SearchResult *r) {
+  RPVectorLoader *self = (RPVectorLoader *)rp;
+  int rc = rp->upstream->Next(rp->upstream, r);
+  double distance;
+  // A fused result which was not returned by the vector query has no distance
+  if (rc == RS_RESULT_OK && (!self->distKey || RLookup_GetItem(self->distKey, &r->rowdata))) {
+    loadResult(self, r, &distance);
+  }
+  return rc;
+}
+
+static int rpVectorLoaderNext_Range(ResultProcessor *rp, SearchResult *r) {
+  RPVectorLoader *self = (RPVectorLoader *)rp;
+  int rc;
+  while ((rc = rp->upstream->Next(rp->upstream, r)) == RS_RESULT_OK) {
+    double distance;
+    if (loadResult(self, r, &distance) && distance <= self->maxDistance) {
+      return RS_RESULT_OK;
+    }
+    rp->parent->totalResults--;
+    SearchResult_Clear(r);
+  }
+  return rc;
+}
+
+static int cmpHitsByDistance(const void *p1, const void *p2) {
+  const vectorHit *h1 = *(const vectorHit **)p1, *h2 = *(const vectorHit **)p2;
+  if (h1->distance < h2->distance) {
+    return -1;
+  } else if (h1->distance > h2->distance) {
+    return 1;
+  }
+  return h1->r.docId < h2->r.docId ? -1 : h1->r.docId > h2->r.docId;
+}
+
+// Sort the candidates by ascending distance, and release the ones which are not among the best K
+// or are beyond the distance cutoff
+static void keepBestHits(RPVectorLoader *self) {
+  size_t n = array_len(self->hits);
+  qsort(self->hits, n, sizeof(*self->hits), cmpHitsByDistance);
+  size_t keep = MIN(n, self->k);
+  while (keep > 0 && self->hits[keep - 1]->distance > self->maxDistance) {
+    keep--;
+  }
+  for (size_t i = keep; i < n; i++) {
+    vectorHitFree(self->hits[i]);
+    self->base.parent->totalResults--;
+  }
+  self->hits = array_trimm_len(self->hits, n - keep);
+}
+
+static int rpVectorLoaderNext_Yield(ResultProcessor *rp, SearchResult *r) {
+  RPVectorLoader *self = (RPVectorLoader *)rp;
+  if (self->next >= array_len(self->hits)) {
+    return RS_RESULT_EOF;
+  }
+
+  vectorHit *h = self->hits[self->next];
+  self->hits[self->next++] = NULL;
+  RLookupRow oldrow = r->rowdata;
+  *r = h->r;
+
+  rm_free(h);
+  RLookupRow_Cleanup(&oldrow);
+  return RS_RESULT_OK;
+}
+
+static int rpVectorLoaderNext_Accum(ResultProcessor *rp, SearchResult *r) {
+  RPVectorLoader *self = (RPVectorLoader *)rp;
+
+  while (1) {
+    vectorHit *h = rm_calloc(1, sizeof(*h));
+    int rc = rp->upstream->Next(rp->upstream, &h->r);
+    if (rc != RS_RESULT_OK) {
+      vectorHitFree(h);
+      // if our upstream has finished - keep the best candidates, and change the state to yield
+      if (rc == RS_RESULT_EOF ||
+          (rc == RS_RESULT_TIMEDOUT && rp->parent->timeoutPolicy == TimeoutPolicy_Return)) {
+        keepBestHits(self);
+        rp->Next = rpVectorLoaderNext_Yield;
+        return rpVectorLoaderNext_Yield(rp, r);
+      }
+      return rc;
+    }
+
+    // the index result is owned by the iterator, and is only valid until the next read
+    h->r.indexResult = NULL;
+    if (loadResult(self, &h->r, &h->distance)) {
+      self->hits = array_append(self->hits, h);
+    } else {
+      vectorHitFree(h);
+      rp->parent->totalResults--;
+    }
+  }
+}
+
+static void rpVectorLoaderFree(ResultProcessor *rp) {
+  RPVectorLoader *self = (RPVectorLoader *)rp;
+  for (size_t i = 0; i < array_len(self->hits); i++) {
+    if (self->hits[i]) {
+      vectorHitFree(self->hits[i]);
+    }
+  }
+  array_free(self->hits);
+  rm_free(self->query);
+  rm_free(self);
+}
+
+ResultProcessor *RPVectorLoader_New(const FieldSpec *fs, const VectorQuery *vq,
+                                    const RLookupKey *distKey, const RLookupKey *matchKey) {
+  RPVectorLoader *ret = rm_calloc(1, sizeof(*ret));
+  ret->fs = *fs;
+  ret->rerank = FieldSpec_IsVectorCompressed(fs);
+  ret->distKey = distKey;
+  ret->matchKey = matchKey;
+  ret->query = rm_malloc(fieldDim(fs) * sizeof(*ret->query));
+  if (vq->type == VECSIM_QT_KNN) {
+    blobToDoubles(fs, vq->knn.vector, ret->query);
+    ret->k = vq->knn.k;
+    ret->maxDistance = vq->maxDistance;
+    ret->hits = array_new(vectorHit *, 16);
+    ret->base.Next = ret->rerank ? rpVectorLoaderNext_Accum : rpVectorLoaderNext_Match;
+  } else {
+    blobToDoubles(fs, vq->range.vector, ret->query);
+    ret->maxDistance = vq->range.radius;
+    ret->base.Next = rpVectorLoaderNext_Range;
+  }
+  ret->base.Free = rpVectorLoaderFree;
+  ret->base.type = RP_VECTOR_LOADER;
+  ret->base.flags |= RESULT_PROCESSOR_F_ACCESS_REDIS;
+  return &ret->base;
+}
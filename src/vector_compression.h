@@ -0,0 +1,73 @@
+/*
+ * Copyright Redis Ltd. 2016 - present
+ * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or
+ * the Server Side Public License v1 (SSPLv1).
+ */
+
+#pragma once
+
+#include "search_ctx.h"
+#include "result_processor.h"
+#include "vector_index.h"
+#include "VecSim/vec_sim.h"
+
+/*
+ * Compressed vector indexes store quantized vectors in the index, while the documents keep
+ * their full precision vectors. Queries run over the quantized vectors, and the best
+ * candidates are then reranked by their full precision distance by the vector loader result
+ * processor, which reads them back from the documents.
+ *
+ * SQ8 quantizes every element to a signed byte, by clamping it to [-range, range] and scaling
+ * it to [-127, 127]. Vectors of COSINE fields are normalized before they are quantized.
+ *
+ * The vector loader is also used to find which vector of a multi-value document matched a KNN
+ * query.
+ */
+
+// The element type of the quantized vectors, in the index and in the queries
+#define VECSIM_SQ8_TYPE VecSimType_INT8
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+static inline bool FieldSpec_IsVectorCompressed(const FieldSpec *fs) {
+  return fs->vectorOpts.compression.type != VecSimCompression_None;
+}
+
+/** The parameters of the index created in the vector library, over the quantized vectors */
+VecSimParams VecSimCompression_IndexParams(const FieldSpec *fs);
+
+/** Quantize a full precision vector of the field into `out`, which holds `dim` bytes */
+void VecSimCompression_Quantize(const FieldSpec *fs, const void *vec, int8_t *out);
+
+/** The radius of a range query in the quantized space */
+double VecSimCompression_QuantizeRadius(const FieldSpec *fs, double radius);
+
+/** Estimated memory of the index if its vectors were stored in full precision */
+size_t VecSimCompression_RawMemory(const FieldSpec *fs, VecSimIndex *index, size_t memory);
+
+/** Estimated memory of all the vector indexes of the spec if their vectors were stored in full
+ * precision */
+size_t VecSimCompression_SpecRawMemory(IndexSpec *sp);
+
+/**
+ * Creates the vector loader result processor of a query whose vector query `vq` is the root of
+ * the query. It reads the full precision vectors of the results from the documents.
+ *
+ * If the field is compressed, the results are yielded with their full precision distance,
+ * written to `distKey` if it is set. The best K results of a KNN query are yielded by ascending
+ * distance, and the results of a range query are yielded if they are within its radius.
+ *
+ * If `matchKey` is set, the position of the document vector which is the closest to the query
+ * vector, among the vectors of the field in the order they were indexed, is written to it.
+ *
+ * The processor accesses the keyspace, so it is placed after the scorer, which needs the spec to
+ * be locked.
+ */
+ResultProcessor *RPVectorLoader_New(const FieldSpec *fs, const VectorQuery *vq,
+                                    const RLookupKey *distKey, const RLookupKey *matchKey);
+
+#ifdef __cplusplus
+}
+#endif
@@ -4,11 +4,28 @@
  * the Server Side Public License v1 (SSPLv1).
  */
 
+#include <math.h>
 #include "vector_index.h"
+#include "vector_compression.h"
 #include "hybrid_reader.h"
 #include "metric_iterator.h"
 #include "query_param.h"
 #include "rdb.h"
+#include "aggregate/aggregate.h"
+#include "document.h"
+
+static size_t vecsimMemory(VecSimIndex *index) {
+  VecSimIndexInfo indexInfo = VecSimIndex_Info(index);
+  switch (indexInfo.algo)
+  {
+    case VecSimAlgo_BF:
+      return indexInfo.bfInfo.memory;
+    case VecSimAlgo_HNSWLIB:
+      return indexInfo.hnswInfo.memory;
+    default:
+      return 0;
+  }
+}
 
 static VecSimIndex *openVectorKeysDict(IndexSpec *spec, RedisModuleString *keyName,
                                              int write) {
@@ -35,19 +52,9 @@ static VecSimIndex *openVectorKeysDict(IndexSpec *spec, RedisModuleString *keyNa
 
   // create new vector data structure
   kdv = rm_calloc(1, sizeof(*kdv));
-  kdv->p = VecSimIndex_New(&fieldSpec->vectorOpts.vecSimParams);
-  VecSimIndexInfo indexInfo = VecSimIndex_Info(kdv->p);
-  switch (indexInfo.algo)
-  {
-    case VecSimAlgo_BF:
-      spec->stats.vectorIndexSize += indexInfo.bfInfo.memory;
-      break;
-    case VecSimAlgo_HNSWLIB:
-      spec->stats.vectorIndexSize += indexInfo.hnswInfo.memory;
-      break;
-    default:
-      break;
-  }
+  VecSimParams params = VecSimCompression_IndexParams(fieldSpec);
+  kdv->p = VecSimIndex_New(&params);
+  spec->stats.vectorIndexSize += vecsimMemory(kdv->p);
   dictAdd(spec->keysDict, keyName, kdv);
   kdv->dtor = (void (*)(void *))VecSimIndex_Free;
   return kdv->p;
@@ -57,6 +64,126 @@ VecSimIndex *OpenVectorIndex(IndexSpec *sp, RedisModuleString *keyName) {
   return openVectorKeysDict(sp, keyName, 1);
 }
 
+size_t VecSim_AddVectors(VecSimIndex *index, const FieldSpec *fs, const char *vecs, size_t n,
+                         size_t vecLen, t_docId id) {
+  size_t memory = 0;
+  int8_t *quantized = NULL;
+  if (FieldSpec_IsVectorCompressed(fs)) {
+    quantized = rm_malloc(fs->vectorOpts.vecSimParams.hnswParams.dim);
+  }
+  for (size_t i = 0; i < n; i++, vecs += vecLen) {
+    if (quantized) {
+      VecSimCompression_Quantize(fs, vecs, quantized);
+      memory += VecSimIndex_AddVector(index, quantized, id);
+    } else {
+      memory += VecSimIndex_AddVector(index, vecs, id);
+    }
+  }
+  rm_free(quantized);
+  return memory;
+}
+
+/*******************************************************************************************************************
+ *  Migrations
+ *******************************************************************************************************************/
+
+VecSimMigration *VecSimMigration_New(const FieldSpec *target, t_docId lastDocId) {
+  VecSimMigration *m = rm_new(VecSimMigration);
+  m->target = *target;
+  m->lastDocId = lastDocId;
+  VecSimParams params = VecSimCompression_IndexParams(target);
+  m->index = VecSimIndex_New(&params);
+  return m;
+}
+
+void VecSimMigration_Free(VecSimMigration *m) {
+  if (!m) {
+    return;
+  }
+  VecSimIndex_Free(m->index);
+  rm_free(m);
+}
+
+void VecSimMigration_AddDocument(IndexSpec *sp, Document *doc, t_docId id) {
+  for (size_t i = 0; i < doc->numFields; i++) {
+    const DocumentField *df = doc->fields + i;
+    const FieldSpec *fs = IndexSpec_GetField(sp, df->name, strlen(df->name));
+    if (!fs || !FIELD_IS(fs, INDEXFLD_T_VECTOR) || !fs->vectorOpts.migration) {
+      continue;
+    }
+    VecSimMigration *m = fs->vectorOpts.migration;
+    if (id > m->lastDocId) {
+      // Already added by the indexer
+      continue;
+    }
+
+    // The same as the vector preprocessor
+    const char *vecs;
+    size_t vecLen, n = 1;
+    switch (df->unionType) {
+      case FLD_VAR_T_RMS:
+        vecs = RedisModule_StringPtrLen(df->text, &vecLen);
+        break;
+      case FLD_VAR_T_CSTR:
+        vecs = df->strval;
+        vecLen = df->strlen;
+        break;
+      case FLD_VAR_T_BLOB_ARRAY:
+        vecs = df->blobArr;
+        vecLen = df->blobSize;
+        n = df->blobArrLen;
+        break;
+      default:
+        continue;
+    }
+    if (vecLen != m->target.vectorOpts.expBlobSize) {
+      continue;
+    }
+    // A restarted scan adds the documents it already added again, so the vectors are replaced
+    VecSimIndex_DeleteVector(m->index, id);
+    VecSim_AddVectors(m->index, &m->target, vecs, n, vecLen, id);
+  }
+}
+
+void VecSimMigration_DeleteDocument(IndexSpec *sp, t_docId id) {
+  for (size_t i = 0; i < sp->numFields; i++) {
+    const FieldSpec *fs = sp->fields + i;
+    if (FIELD_IS(fs, INDEXFLD_T_VECTOR) && fs->vectorOpts.migration) {
+      VecSimIndex_DeleteVector(fs->vectorOpts.migration->index, id);
+    }
+  }
+}
+
+void VecSimMigration_Complete(IndexSpec *sp) {
+  for (size_t i = 0; i < sp->numFields; i++) {
+    FieldSpec *fs = sp->fields + i;
+    if (!FIELD_IS(fs, INDEXFLD_T_VECTOR) || !fs->vectorOpts.migration) {
+      continue;
+    }
+    VecSimMigration *m = fs->vectorOpts.migration;
+    RedisModuleString *keyName = IndexSpec_GetFormattedKey(sp, fs, INDEXFLD_T_VECTOR);
+    KeysDictValue *kdv = dictFetchValue(sp->keysDict, keyName);
+    if (kdv) {
+      // Swap the indexes. The new index holds all the documents of the current one
+      sp->stats.vectorIndexSize += vecsimMemory(m->index);
+      sp->stats.vectorIndexSize -= vecsimMemory(kdv->p);
+      VecSimIndex_Free(kdv->p);
+      kdv->p = m->index;
+    } else {
+      // The index was never opened, so it has no documents. It is created on demand with the new
+      // parameters.
+      VecSimIndex_Free(m->index);
+    }
+    fs->vectorOpts.vecSimParams = m->target.vectorOpts.vecSimParams;
+    fs->vectorOpts.expBlobSize = m->target.vectorOpts.expBlobSize;
+    fs->vectorOpts.compression = m->target.vectorOpts.compression;
+    fs->vectorOpts.migration = NULL;
+    rm_free(m);
+    RedisModule_Log(RSDummyContext, "notice", "Vector index of field %s in index %s was replaced",
+                    fs->name, sp->name);
+  }
+}
+
 IndexIterator *createMetricIteratorFromVectorQueryResults(VecSimQueryResult_List results,
                                                           bool yields_metric) {
   size_t res_num = VecSimQueryResult_Len(results);
@@ -107,6 +234,15 @@ IndexIterator *NewVectorIterator(QueryEvalCtx *q, VectorQuery *vq, IndexIterator
       break;
   }
 
+  // The query vector of a compressed index is given in the full precision type of the field,
+  // and is quantized before running the query. The results are then reranked by the vector
+  // loader of the pipeline.
+  const FieldSpec *fs = IndexSpec_GetField(ctx->spec, vq->property, strlen(vq->property));
+  bool compressed = fs && FieldSpec_IsVectorCompressed(fs);
+  if (compressed) {
+    type = fs->vectorOpts.vecSimParams.hnswParams.type;
+  }
+
   VecSimQueryParams qParams = {0};
   switch (vq->type) {
     case VECSIM_QT_KNN: {
@@ -122,16 +258,42 @@ IndexIterator *NewVectorIterator(QueryEvalCtx *q, VectorQuery *vq, IndexIterator
                                     &qParams, queryType, q->status) != VecSim_OK)  {
         return NULL;
       }
+      // When fusing, the vector branch returns its own window of results, and the document
+      // scores are always needed by the lexical branch.
+      bool fuse = q->opts->fusion.method != FUSION_NONE;
+      // The fusion needs the distances of the vector branch before they are reranked
+      if (fuse && compressed) {
+        QueryError_SetError(q->status, QUERY_EINVAL, "FUSION is not supported for compressed vector fields");
+        return NULL;
+      }
+      KNNVectorQuery knn = vq->knn;
+      if (fuse) {
+        knn.k = q->opts->fusion.vectorWindow;
+      }
+      if (compressed) {
+        int8_t *quantized = rm_malloc(dim);
+        VecSimCompression_Quantize(fs, knn.vector, quantized);
+        knn.vector = quantized;
+        knn.vecLen = dim;
+        knn.k *= fs->vectorOpts.compression.rerankFactor;
+        type = VECSIM_SQ8_TYPE;
+      }
       HybridIteratorParams hParams = {.index = vecsim,
                                       .dim = dim,
                                       .elementType = type,
                                       .spaceMetric = metric,
-                                      .query = vq->knn,
+                                      .query = knn,
                                       .qParams = qParams,
                                       .vectorScoreField = vq->scoreField,
-                                      .ignoreDocScore = q->opts->flags & Search_IgnoreScores,
+                                      .ignoreDocScore = !fuse && (q->opts->flags & Search_IgnoreScores),
+                                      .fuseResults = fuse,
                                       .childIt = child_it,
                                       .timeout = q->sctx->timeout,
+                                      .quantized = compressed,
+                                      // The distances of a compressed index are only compared to
+                                      // the cutoff after reranking
+                                      .hasMaxDistance = !compressed && vq->maxDistance != INFINITY,
+                                      .maxDistance = vq->maxDistance,
       };
       return NewHybridVectorIterator(hParams, q->status);
     }
@@ -143,7 +305,8 @@ IndexIterator *NewVectorIterator(QueryEvalCtx *q, VectorQuery *vq, IndexIterator
                                vq->range.vecLen, (dim * VecSimType_sizeof(type)));
         return NULL;
       }
-      if (vq->range.radius < 0) {
+      // The inner product distance (1 - the inner product) may be negative
+      if (vq->range.radius < 0 && metric != VecSimMetric_IP) {
         QueryError_SetErrorFmt(q->status, QUERY_EINVAL,
                                "Error parsing vector similarity query: negative radius (%g) "
                                "given in a range query",
@@ -155,6 +318,21 @@ IndexIterator *NewVectorIterator(QueryEvalCtx *q, VectorQuery *vq, IndexIterator
         return NULL;
       }
       qParams.timeoutCtx = &(TimeoutCtx){ .timeout = q->sctx->timeout, .counter = 0 };
+      bool yields_metric = vq->scoreField != NULL;
+      if (compressed) {
+        int8_t *quantized = rm_malloc(dim);
+        VecSimCompression_Quantize(fs, vq->range.vector, quantized);
+        VecSimQueryResult_List results =
+            VecSimIndex_RangeQuery(vecsim, quantized, VecSimCompression_QuantizeRadius(fs, vq->range.radius),
+                                   &qParams, vq->range.order);
+        rm_free(quantized);
+        if (results.code == VecSim_QueryResult_TimedOut) {
+          VecSimQueryResult_Free(results);
+          QueryError_SetError(q->status, QUERY_TIMEDOUT, NULL);
+          return NULL;
+        }
+        return createMetricIteratorFromVectorQueryResults(results, yields_metric);
+      }
       VecSimQueryResult_List results =
           VecSimIndex_RangeQuery(vecsim, vq->range.vector, vq->range.radius,
                                  &qParams, vq->range.order);
@@ -163,7 +341,6 @@ IndexIterator *NewVectorIterator(QueryEvalCtx *q, VectorQuery *vq, IndexIterator
         QueryError_SetError(q->status, QUERY_TIMEDOUT, NULL);
         return NULL;
       }
-      bool yields_metric = vq->scoreField != NULL;
       return createMetricIteratorFromVectorQueryResults(results, yields_metric);
     }
   }
@@ -204,6 +381,7 @@ int VectorQuery_ParamResolve(VectorQueryParams params, size_t index, dict *param
 void VectorQuery_Free(VectorQuery *vq) {
   if (vq->property) rm_free((char *)vq->property);
   if (vq->scoreField) rm_free((char *)vq->scoreField);
+  if (vq->matchField) rm_free((char *)vq->matchField);
   switch (vq->type) {
     case VECSIM_QT_KNN: // no need to free the vector as we pointes to the query dictionary
     default:
@@ -222,6 +400,10 @@ const char *VecSimType_ToString(VecSimType type) {
   switch (type) {
     case VecSimType_FLOAT32: return VECSIM_TYPE_FLOAT32;
     case VecSimType_FLOAT64: return VECSIM_TYPE_FLOAT64;
+    case VecSimType_FLOAT16: return VECSIM_TYPE_FLOAT16;
+    case VecSimType_BFLOAT16: return VECSIM_TYPE_BFLOAT16;
+    case VecSimType_INT8: return VECSIM_TYPE_INT8;
+    case VecSimType_UINT8: return VECSIM_TYPE_UINT8;
     case VecSimType_INT32: return VECSIM_TYPE_INT32;
     case VecSimType_INT64: return VECSIM_TYPE_INT64;
   }
@@ -232,6 +414,10 @@ size_t VecSimType_sizeof(VecSimType type) {
     switch (type) {
         case VecSimType_FLOAT32: return sizeof(float);
         case VecSimType_FLOAT64: return sizeof(double);
+        case VecSimType_FLOAT16: return sizeof(uint16_t);
+        case VecSimType_BFLOAT16: return sizeof(uint16_t);
+        case VecSimType_INT8: return sizeof(int8_t);
+        case VecSimType_UINT8: return sizeof(uint8_t);
         case VecSimType_INT32: return sizeof(int32_t);
         case VecSimType_INT64: return sizeof(int64_t);
     }
@@ -285,6 +471,14 @@ static int VecSimIndex_validate_Rdb_parameters(RedisModuleIO *rdb, VecSimParams
   QueryError status = {0};
   int rv;
 
+  // The element type is saved as is, so an unknown type means the RDB was written by a newer version.
+  VecSimType type = vecsimParams->algo == VecSimAlgo_HNSWLIB ? vecsimParams->hnswParams.type
+                                                             : vecsimParams->bfParams.type;
+  if (!VecSimType_sizeof(type)) {
+    RedisModule_LogIOError(rdb, REDISMODULE_LOGLEVEL_WARNING, "ERROR: unknown vector type %u", (unsigned)type);
+    return REDISMODULE_ERR;
+  }
+
   // Checking if the loaded parameters fits the current server limits.
   rv = VecSimIndex_validate_params(ctx, vecsimParams, &status);
   if (REDISMODULE_OK != rv) {
@@ -13,6 +13,10 @@
 
 #define VECSIM_TYPE_FLOAT32 "FLOAT32"
 #define VECSIM_TYPE_FLOAT64 "FLOAT64"
+#define VECSIM_TYPE_FLOAT16 "FLOAT16"
+#define VECSIM_TYPE_BFLOAT16 "BFLOAT16"
+#define VECSIM_TYPE_INT8 "INT8"
+#define VECSIM_TYPE_UINT8 "UINT8"
 #define VECSIM_TYPE_INT32 "INT32"
 #define VECSIM_TYPE_INT64 "INT64"
 
@@ -31,9 +35,19 @@
 #define VECSIM_EPSILON "EPSILON"
 #define VECSIM_HYBRID_POLICY "HYBRID_POLICY"
 #define VECSIM_BATCH_SIZE "BATCH_SIZE"
+#define VECSIM_YIELD_MATCHED_VECTOR_AS "YIELD_MATCHED_VECTOR_AS"
+#define VECSIM_MAX_DISTANCE "MAX_DISTANCE"
 #define VECSIM_TYPE "TYPE"
 #define VECSIM_DIM "DIM"
 #define VECSIM_DISTANCE_METRIC "DISTANCE_METRIC"
+#define VECSIM_COMPRESSION "COMPRESSION"
+#define VECSIM_COMPRESSION_RANGE "COMPRESSION_RANGE"
+#define VECSIM_RERANK_FACTOR "RERANK_FACTOR"
+
+#define VECSIM_COMPRESSION_SQ8 "SQ8"
+
+#define VECSIM_DEFAULT_COMPRESSION_RANGE 1
+#define VECSIM_DEFAULT_RERANK_FACTOR 2
 
 #define VECSIM_ERR_MANDATORY(status,algorithm,arg) \
   QERR_MKBADARGS_FMT(status, "Missing mandatory parameter: cannot create %s index without specifying %s argument", algorithm, arg)
@@ -73,6 +87,8 @@ typedef struct {
 typedef struct VectorQuery {
   char *property;                     // name of field
   char *scoreField;                   // name of score field
+  char *matchField;                   // name of the matched vector position field (KNN only)
+  double maxDistance;                 // distance cutoff of the results (KNN only, INFINITY if not set)
   union {
     KNNVectorQuery knn;
     RangeVectorQuery range;
@@ -104,6 +120,39 @@ typedef enum {
 VecSimIndex *OpenVectorIndex(IndexSpec *sp,
   RedisModuleString *keyName/*, RedisModuleKey **idxKey*/);
 
+/** Add `n` vectors of `vecLen` bytes of a document to the index of the field. Returns the
+ * memory delta of the index */
+size_t VecSim_AddVectors(VecSimIndex *index, const FieldSpec *fs, const char *vecs, size_t n,
+                         size_t vecLen, t_docId id);
+
+/*
+ * FT.ALTER can modify the parameters of a vector field. A new index is built in the background by
+ * a scan of the keyspace, while the current index keeps serving queries and both are updated by
+ * new writes. Once the scan is done, the new index replaces the current one.
+ */
+typedef struct VecSimMigration {
+  FieldSpec target;  // The field with the new parameters (shares the name and path of the field)
+  VecSimIndex *index;
+  // The last document ID when the migration started. Documents indexed later on are added to the
+  // new index by the indexer, so the scan skips them.
+  t_docId lastDocId;
+} VecSimMigration;
+
+VecSimMigration *VecSimMigration_New(const FieldSpec *target, t_docId lastDocId);
+void VecSimMigration_Free(VecSimMigration *m);
+
+struct Document;
+/** Add the vectors of a loaded document to the new indexes of the migrated fields, unless they were
+ * added when the document was indexed */
+void VecSimMigration_AddDocument(IndexSpec *sp, struct Document *doc, t_docId id);
+
+/** Delete a document from the new indexes of the migrated fields */
+void VecSimMigration_DeleteDocument(IndexSpec *sp, t_docId id);
+
+/** Replace the indexes of the migrated fields with their new indexes. Assumes the spec is locked
+ * for write */
+void VecSimMigration_Complete(IndexSpec *sp);
+
 IndexIterator *NewVectorIterator(QueryEvalCtx *q, VectorQuery *vq, IndexIterator *child_it);
 
 int VectorQuery_EvalParams(dict *params, QueryNode *node, QueryError *status);
@@ -0,0 +1,80 @@
+#!/usr/bin/env python
+import sys
+import unicodedata
+
+"""
+This script generates the tables of src/normalize.c from the Unicode character
+database of the running Python: the NFKC forms of the characters of the blocks
+below which differ from the characters, and the canonical compositions of pairs
+of characters into the letters of the Latin blocks and into kana. Hangul
+syllables are composed algorithmically.
+
+This script writes to stdout; the output may be captured and redirected to
+src/normalize_table.h.
+"""
+
+MAPPED_BLOCKS = (
+    (0x00A0, 0x024F),  # Latin-1 Supplement, Latin Extended-A and B
+    (0x02B0, 0x02FF),  # Spacing Modifier Letters
+    (0x1E00, 0x1EFF),  # Latin Extended Additional
+    (0x2000, 0x209F),  # General Punctuation, Superscripts and Subscripts
+    (0x2100, 0x218F),  # Letterlike Symbols, Number Forms
+    (0x2460, 0x24FF),  # Enclosed Alphanumerics
+    (0x3000, 0x303F),  # CJK Symbols and Punctuation
+    (0x3130, 0x318F),  # Hangul Compatibility Jamo
+    (0xFB00, 0xFB06),  # Latin ligatures
+    (0xFF00, 0xFFEF),  # Halfwidth and Fullwidth Forms
+)
+
+COMPOSED_BLOCKS = (
+    (0x00C0, 0x024F),
+    (0x1E00, 0x1EFF),
+    (0x3040, 0x30FF),  # Hiragana and Katakana
+)
+
+
+def c_string(s):
+    return '"' + ''.join('\\x%02x' % b for b in s.encode('utf-8')) + '"'
+
+
+mappings = []
+for first, last in MAPPED_BLOCKS:
+    for cp in range(first, last + 1):
+        ch = chr(cp)
+        if unicodedata.category(ch) == 'Cn':
+            continue
+        nfkc = unicodedata.normalize('NFKC', ch)
+        if nfkc != ch:
+            mappings.append((cp, nfkc))
+
+compositions = []
+for first, last in COMPOSED_BLOCKS:
+    for cp in range(first, last + 1):
+        decomposition = unicodedata.decomposition(chr(cp))
+        if not decomposition or decomposition.startswith('<'):
+            continue
+        parts = [int(p, 16) for p in decomposition.split()]
+        if len(parts) == 2 and unicodedata.normalize('NFC', chr(parts[0]) + chr(parts[1])) == chr(cp):
+            compositions.append((parts[0], parts[1], cp))
+compositions.sort()
+
+expansion = max(len(nfkc.encode('utf-8')) / len(chr(cp).encode('utf-8')) for cp, nfkc in mappings)
+
+fp = sys.stdout
+fp.write('/*\n * Copyright Redis Ltd. 2016 - present\n'
+         ' * Licensed under your choice of the Redis Source Available License 2.0 (RSALv2) or\n'
+         ' * the Server Side Public License v1 (SSPLv1).\n */\n\n')
+fp.write('// Generated by srcutil/gen_normalize_table.py from Unicode %s. Do not edit.\n\n'
+         % unicodedata.unidata_version)
+fp.write('#pragma once\n\n')
+fp.write('#define NFKC_MAX_EXPANSION %d\n\n' % int(-(-expansion // 1)))
+
+fp.write('static const NFKCMapping nfkcMappings_g[] = {\n')
+for cp, nfkc in mappings:
+    fp.write('    {0x%04X, %s},\n' % (cp, c_string(nfkc)))
+fp.write('};\n\n')
+
+fp.write('static const NFKCComposition nfkcCompositions_g[] = {\n')
+for first, second, composed in compositions:
+    fp.write('    {0x%04X, 0x%04X, 0x%04X},\n' % (first, second, composed))
+fp.write('};\n')
@@ -0,0 +1,73 @@
+#include "src/date_field.h"
+
+#include "gtest/gtest.h"
+
+#include <math.h>
+#include <string.h>
+
+class DateTest : public ::testing::Test {};
+
+static bool parseDate(const char *s, double *ts) {
+  return DateField_ParseISO8601(s, strlen(s), ts);
+}
+
+TEST_F(DateTest, testParseISO8601) {
+  double ts;
+  ASSERT_TRUE(parseDate("2024-01-01", &ts));
+  ASSERT_EQ(1704067200, ts);
+  ASSERT_TRUE(parseDate("2024", &ts));
+  ASSERT_EQ(1704067200, ts);
+  ASSERT_TRUE(parseDate("2024-02", &ts));
+  ASSERT_EQ(1706745600, ts);
+  ASSERT_TRUE(parseDate("2024-02-01T12:00Z", &ts));
+  ASSERT_EQ(1706788800, ts);
+  ASSERT_TRUE(parseDate("2024-02-01 12:00:00", &ts));
+  ASSERT_EQ(1706788800, ts);
+  ASSERT_TRUE(parseDate("2024-02-01T12:00:30.5+02:00", &ts));
+  ASSERT_EQ(1706781630.5, ts);
+  ASSERT_TRUE(parseDate("2024-01-01T12:00-0530", &ts));
+  ASSERT_EQ(1704130200, ts);
+  ASSERT_TRUE(parseDate("1969-12-31T23:59:59Z", &ts));
+  ASSERT_EQ(-1, ts);
+  ASSERT_TRUE(parseDate("2024-02-29", &ts));
+
+  ASSERT_FALSE(parseDate("", &ts));
+  ASSERT_FALSE(parseDate("abc", &ts));
+  ASSERT_FALSE(parseDate("1704067200", &ts));
+  ASSERT_FALSE(parseDate("2023-02-29", &ts));
+  ASSERT_FALSE(parseDate("2024-13-01", &ts));
+  ASSERT_FALSE(parseDate("2024-01-01T25:00", &ts));
+  ASSERT_FALSE(parseDate("2024-01-01T12", &ts));
+  ASSERT_FALSE(parseDate("2024-01-01T12:00Zfoo", &ts));
+}
+
+TEST_F(DateTest, testParseBound) {
+  double ts, now = 1704067200;
+  ASSERT_TRUE(DateField_ParseBound("now", 3, now, &ts));
+  ASSERT_EQ(now, ts);
+  ASSERT_TRUE(DateField_ParseBound("now-7d", 6, now, &ts));
+  ASSERT_EQ(now - 7 * 86400, ts);
+  ASSERT_TRUE(DateField_ParseBound("NOW-1d+12h", 10, now, &ts));
+  ASSERT_EQ(now - 43200, ts);
+  ASSERT_TRUE(DateField_ParseBound("2024-01-02", 10, now, &ts));
+  ASSERT_EQ(now + 86400, ts);
+
+  ASSERT_FALSE(DateField_ParseBound("now-7", 5, now, &ts));
+  ASSERT_FALSE(DateField_ParseBound("now-7y", 6, now, &ts));
+  ASSERT_FALSE(DateField_ParseBound("now7d", 5, now, &ts));
+}
+
+TEST_F(DateTest, testParseRangeBound) {
+  double ts, now = 1704067200;
+  ASSERT_TRUE(DateField_ParseRangeBound("*", 1, 0, now, &ts));
+  ASSERT_EQ(-INFINITY, ts);
+  ASSERT_TRUE(DateField_ParseRangeBound("*", 1, 1, now, &ts));
+  ASSERT_EQ(INFINITY, ts);
+  ASSERT_TRUE(DateField_ParseRangeBound("+inf", 4, 0, now, &ts));
+  ASSERT_EQ(INFINITY, ts);
+  ASSERT_TRUE(DateField_ParseRangeBound("-INF", 4, 1, now, &ts));
+  ASSERT_EQ(-INFINITY, ts);
+  ASSERT_TRUE(DateField_ParseRangeBound("now-1d", 6, 1, now, &ts));
+  ASSERT_EQ(now - 86400, ts);
+  ASSERT_FALSE(DateField_ParseRangeBound("**", 2, 0, now, &ts));
+}
@@ -4,6 +4,9 @@
 #include "src/query.h"
 #include "src/stopwords.h"
 #include "src/ext/default.h"
+#include "src/aggregate/expr/expression.h"
+#include "src/aggregate/expr/exprast.h"
+#include "src/aggregate/reducer.h"
 
 #include "gtest/gtest.h"
 
@@ -52,9 +55,48 @@ void myFreeFunc(void *p) {
   free(p);
 }
 
+/* Repeats its string argument the given number of times */
+static int myRepeatFunc(RSExprFunctionCtx *ctx, RSExprValue *result, const RSExprValue *args,
+                        size_t nargs) {
+  if (args[0].type != RSExprValue_String || args[1].type != RSExprValue_Number) {
+    return REDISEARCH_ERR;
+  }
+  std::string s;
+  for (int i = 0; i < (int)args[1].num; ++i) {
+    s.append(args[0].str, args[0].len);
+  }
+  ctx->SetStringResult(ctx, result, s.c_str(), s.size());
+  return REDISEARCH_OK;
+}
+
+/* Concatenates the string values of a property */
+static void *myConcatNewInstance(void *privdata) {
+  return new std::string();
+}
+
+static void myConcatAdd(void *privdata, void *instance, const RSExprValue *args, size_t nargs) {
+  if (args[0].type == RSExprValue_String) {
+    static_cast<std::string *>(instance)->append(args[0].str, args[0].len);
+  }
+}
+
+static void myConcatFinalize(RSExprFunctionCtx *ctx, void *instance, RSExprValue *result) {
+  auto s = static_cast<std::string *>(instance);
+  ctx->SetStringResult(ctx, result, s->c_str(), s->size());
+}
+
+static void myConcatFreeInstance(void *privdata, void *instance) {
+  delete static_cast<std::string *>(instance);
+}
+
+static const RSReducerCallbacks myConcatCallbacks = {
+    myConcatNewInstance, myConcatAdd, myConcatFinalize, myConcatFreeInstance};
+
 #define SCORER_NAME "myScorer_" __FILE__
 #define EXPANDER_NAME "myExpander_" __FILE__
 #define EXTENSION_NAME "testung_" __FILE__
+#define FUNCTION_NAME "my_repeat"
+#define REDUCER_NAME "my_concat"
 
 /* Register the default extension */
 int myRegisterFunc(RSExtensionCtx *ctx) {
@@ -67,6 +109,16 @@ int myRegisterFunc(RSExtensionCtx *ctx) {
     return REDISEARCH_ERR;
   }
 
+  if (ctx->RegisterExpressionFunction(FUNCTION_NAME, myRepeatFunc, 2, 2, RSExprValue_String, NULL,
+                                      NULL) == REDISEARCH_ERR) {
+    return REDISEARCH_ERR;
+  }
+
+  if (ctx->RegisterReducer(REDUCER_NAME, &myConcatCallbacks, 1, RSExprValue_String, NULL, NULL,
+                           NULL) == REDISEARCH_ERR) {
+    return REDISEARCH_ERR;
+  }
+
   return REDISEARCH_OK;
 }
 
@@ -100,6 +152,131 @@ TEST_F(ExtTest, testRegistration) {
   ASSERT_TRUE(NULL == Extensions_GetScoringFunction(&scxp, ucScorer.c_str()));
 }
 
+TEST_F(ExtTest, testExpressionFunction) {
+  QueryError status = {QueryErrorCode(0)};
+  const char *e = FUNCTION_NAME "('ab', 3)";
+  RSExpr *root = ExprAST_Parse(e, strlen(e), &status);
+  ASSERT_TRUE(root != NULL) << QueryError_GetError(&status);
+
+  ExprEval eval = {0};
+  eval.err = &status;
+  eval.root = root;
+  RSValue res = RSVALUE_STATIC;
+  ASSERT_EQ(EXPR_EVAL_OK, ExprEval_Eval(&eval, &res)) << QueryError_GetError(&status);
+  size_t len;
+  const char *s = RSValue_StringPtrLen(&res, &len);
+  ASSERT_EQ(std::string("ababab"), std::string(s, len));
+  RSValue_Clear(&res);
+
+  // wrong argument types are reported by the function
+  ExprAST_Free(root);
+  e = FUNCTION_NAME "(3, 'ab')";
+  root = ExprAST_Parse(e, strlen(e), &status);
+  ASSERT_TRUE(root != NULL) << QueryError_GetError(&status);
+  eval.root = root;
+  ASSERT_EQ(EXPR_EVAL_ERR, ExprEval_Eval(&eval, &res));
+  ASSERT_STREQ("Function 'my_repeat' failed", QueryError_GetError(&status));
+  QueryError_ClearError(&status);
+  ExprAST_Free(root);
+
+  // the arguments count is validated when parsing
+  e = FUNCTION_NAME "('ab')";
+  ASSERT_TRUE(ExprAST_Parse(e, strlen(e), &status) == NULL);
+  ASSERT_STREQ("Invalid number of arguments for function 'my_repeat'", QueryError_GetError(&status));
+  QueryError_ClearError(&status);
+}
+
+TEST_F(ExtTest, testFreeExpressionFunction) {
+  QueryError status = {QueryErrorCode(0)};
+  const char *e = FUNCTION_NAME "('ab', 3)";
+
+  // the function and the reducer are unregistered along with the extensions
+  Extensions_Free();
+  ASSERT_TRUE(ExprAST_Parse(e, strlen(e), &status) == NULL);
+  QueryError_ClearError(&status);
+  ASSERT_TRUE(RDCR_GetFactory(REDUCER_NAME) == NULL);
+
+  // so it can be registered again
+  Extensions_Init();
+  ASSERT_EQ(REDISEARCH_OK, Extension_Load("testung", myRegisterFunc));
+  RSExpr *root = ExprAST_Parse(e, strlen(e), &status);
+  ASSERT_TRUE(root != NULL) << QueryError_GetError(&status);
+  ExprAST_Free(root);
+}
+
+TEST_F(ExtTest, testReducer) {
+  const ExtReducerCtx *ext = Extensions_GetReducer(REDUCER_NAME);
+  ASSERT_TRUE(ext != NULL);
+  ASSERT_TRUE(ext->mergeReducer == NULL);
+  // reducers are looked up case insensitively, like the built-in ones
+  ReducerFactory ff = RDCR_GetFactory("MY_CONCAT");
+  ASSERT_TRUE(ff != NULL);
+
+  RLookup lk = {0};
+  RLookupKey *k = RLookup_GetKey(&lk, "foo", RLOOKUP_F_OCREAT);
+  QueryError status = {QueryErrorCode(0)};
+  const char *args[] = {"@foo", "@foo"};
+  ArgsCursor ac;
+  ArgsCursor_InitCString(&ac, args, 1);
+  ReducerOptions opts = REDUCEROPTS_INIT(REDUCER_NAME, &ac, &lk, &status);
+  Reducer *r = ff(&opts);
+  ASSERT_TRUE(r != NULL) << QueryError_GetError(&status);
+
+  void *instance = r->NewInstance(r);
+  RLookupRow row = {0};
+  const char *values[] = {"ab", "cd"};
+  for (auto v : values) {
+    RLookup_WriteOwnKey(k, &row, RS_ConstStringValC((char *)v));
+    r->Add(r, instance, &row);
+    RLookupRow_Wipe(&row);
+  }
+  // missing values are passed as Null
+  r->Add(r, instance, &row);
+
+  RSValue *res = r->Finalize(r, instance);
+  size_t len;
+  const char *s = RSValue_StringPtrLen(res, &len);
+  ASSERT_EQ(std::string("abcd"), std::string(s, len));
+  RSValue_Decref(res);
+  r->FreeInstance(r, instance);
+  r->Free(r);
+
+  // the arguments count is validated when the reducer is created
+  ArgsCursor_InitCString(&ac, args, 2);
+  ASSERT_TRUE(ff(&opts) == NULL);
+  ASSERT_TRUE(QueryError_HasError(&status));
+  QueryError_ClearError(&status);
+
+  // reducers can't override built-in or previously registered ones
+  ASSERT_EQ(REDISEARCH_ERR, Extension_Load("dup", [](RSExtensionCtx *ctx) -> int {
+              return ctx->RegisterReducer("count", &myConcatCallbacks, 0, RSExprValue_Number,
+                                          NULL, NULL, NULL);
+            }));
+  ASSERT_EQ(REDISEARCH_ERR, Extension_Load("dup", [](RSExtensionCtx *ctx) -> int {
+              return ctx->RegisterReducer(REDUCER_NAME, &myConcatCallbacks, 1, RSExprValue_String,
+                                          NULL, NULL, NULL);
+            }));
+
+  // the merge reducer must exist and accept a single property
+  ASSERT_EQ(REDISEARCH_ERR, Extension_Load("merge", [](RSExtensionCtx *ctx) -> int {
+              return ctx->RegisterReducer("my_unknown_merge", &myConcatCallbacks, 1,
+                                          RSExprValue_String, "no_such_reducer", NULL, NULL);
+            }));
+  ASSERT_EQ(REDISEARCH_ERR, Extension_Load("merge", [](RSExtensionCtx *ctx) -> int {
+              return ctx->RegisterReducer("my_self_merge", &myConcatCallbacks, 2,
+                                          RSExprValue_String, "my_self_merge", NULL, NULL);
+            }));
+  ASSERT_TRUE(Extensions_GetReducer("my_unknown_merge") == NULL);
+  ASSERT_TRUE(Extensions_GetReducer("my_self_merge") == NULL);
+  ASSERT_EQ(REDISEARCH_OK, Extension_Load("merge", [](RSExtensionCtx *ctx) -> int {
+              return ctx->RegisterReducer("my_sum_merge", &myConcatCallbacks, 1,
+                                          RSExprValue_String, "sum", NULL, NULL);
+            }));
+
+  RLookupRow_Cleanup(&row);
+  RLookup_Cleanup(&lk);
+}
+
 TEST_F(ExtTest, testDynamicLoading) {
   char *errMsg = NULL;
   int rc = Extension_LoadDynamic(getExtensionPath(), &errMsg);
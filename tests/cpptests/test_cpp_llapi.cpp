@@ -14,6 +14,7 @@
 #define GEO_FIELD_NAME "geo"
 #define TAG_FIELD_NAME1 "tag1"
 #define TAG_FIELD_NAME2 "tag2"
+#define BOOLEAN_FIELD_NAME "bool"
 
 class LLApiTest : public ::testing::Test {
   virtual void SetUp() {
@@ -304,6 +305,95 @@ TEST_F(LLApiTest, testAddDocumetTagField) {
   RediSearch_DropIndex(index);
 }
 
+TEST_F(LLApiTest, testAddDocumentBooleanField) {
+  // creating the index
+  RSIndex* index = RediSearch_CreateIndex("index", NULL);
+
+  // adding boolean field to the index
+  RediSearch_CreateBooleanField(index, BOOLEAN_FIELD_NAME);
+
+  // adding documents to the index
+  RSDoc* d = RediSearch_CreateDocument(DOCID1, strlen(DOCID1), 1.0, NULL);
+  RediSearch_DocumentAddFieldCString(d, BOOLEAN_FIELD_NAME, "TRUE", RSFLDTYPE_DEFAULT);
+  RediSearch_SpecAddDocument(index, d);
+
+  d = RediSearch_CreateDocument(DOCID2, strlen(DOCID2), 1.0, NULL);
+  RediSearch_DocumentAddFieldNumber(d, BOOLEAN_FIELD_NAME, 0, RSFLDTYPE_BOOLEAN);
+  RediSearch_SpecAddDocument(index, d);
+
+  // searching on the index
+  RSQNode* qn = RediSearch_CreateTagNode(index, BOOLEAN_FIELD_NAME);
+  RediSearch_QueryNodeAddChild(qn, RediSearch_CreateTagTokenNode(index, "true"));
+  RSResultsIterator* iter = RediSearch_GetResultsIterator(qn, index);
+
+  size_t len;
+  const char* id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, DOCID1);
+  id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, NULL);
+  RediSearch_ResultsIteratorFree(iter);
+
+  // `0` is the same as `false`
+  qn = RediSearch_CreateTagNode(index, BOOLEAN_FIELD_NAME);
+  RediSearch_QueryNodeAddChild(qn, RediSearch_CreateTagTokenNode(index, "0"));
+  iter = RediSearch_GetResultsIterator(qn, index);
+
+  id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, DOCID2);
+  id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, NULL);
+  RediSearch_ResultsIteratorFree(iter);
+
+  // a value which is not a boolean fails the document
+  char* err = NULL;
+  d = RediSearch_CreateDocument("doc3", strlen("doc3"), 1.0, NULL);
+  RediSearch_DocumentAddFieldCString(d, BOOLEAN_FIELD_NAME, "maybe", RSFLDTYPE_DEFAULT);
+  ASSERT_EQ(REDISMODULE_ERR, RediSearch_IndexAddDocument(index, d, REDISEARCH_ADD_REPLACE, &err));
+  ASSERT_TRUE(err);
+  rm_free(err);
+
+  RediSearch_DropIndex(index);
+}
+
+TEST_F(LLApiTest, testMissingField) {
+  // creating the index
+  RSIndex* index = RediSearch_CreateIndex("index", NULL);
+  RediSearch_CreateTextField(index, FIELD_NAME_1);
+  RediSearch_CreateField(index, TAG_FIELD_NAME1, RSFLDTYPE_TAG, RSFLDOPT_INDEXMISSING);
+
+  // adding documents to the index, only the first one has a tag
+  RSDoc* d = RediSearch_CreateDocument(DOCID1, strlen(DOCID1), 1.0, NULL);
+  RediSearch_DocumentAddFieldCString(d, FIELD_NAME_1, "foo", RSFLDTYPE_DEFAULT);
+  RediSearch_DocumentAddFieldCString(d, TAG_FIELD_NAME1, "bar", RSFLDTYPE_DEFAULT);
+  RediSearch_SpecAddDocument(index, d);
+
+  d = RediSearch_CreateDocument(DOCID2, strlen(DOCID2), 1.0, NULL);
+  RediSearch_DocumentAddFieldCString(d, FIELD_NAME_1, "foo", RSFLDTYPE_DEFAULT);
+  RediSearch_SpecAddDocument(index, d);
+
+  RSQNode* qn = RediSearch_CreateMissingNode(index, TAG_FIELD_NAME1);
+  RSResultsIterator* iter = RediSearch_GetResultsIterator(qn, index);
+
+  size_t len;
+  const char* id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, DOCID2);
+  id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, NULL);
+  RediSearch_ResultsIteratorFree(iter);
+
+  // the same, through the query string
+  iter = RediSearch_IterateQueryWithDialect(index, "ismissing(@" TAG_FIELD_NAME1 ")",
+                                            strlen("ismissing(@" TAG_FIELD_NAME1 ")"), 2, NULL);
+  ASSERT_TRUE(iter);
+  id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, DOCID2);
+  id = (const char*)RediSearch_ResultsIteratorNext(iter, index, &len);
+  ASSERT_STREQ(id, NULL);
+  RediSearch_ResultsIteratorFree(iter);
+
+  RediSearch_DropIndex(index);
+}
+
 TEST_F(LLApiTest, testPhoneticSearch) {
   // creating the index
   RSIndex* index = RediSearch_CreateIndex("index", NULL);
@@ -9,6 +9,7 @@
 #include "gtest/gtest.h"
 
 #include <stdio.h>
+#include <math.h>
 
 #define QUERY_PARSE_CTX(ctx, qt, opts) NewQueryParseCtx(&ctx, qt, strlen(qt), &opts);
 
@@ -842,6 +843,39 @@ TEST_F(QueryTest, testFieldSpec_v2) {
   StrongRef_Release(ref);
 }
 
+TEST_F(QueryTest, testDateRange_v2) {
+  static const char *args[] = {"SCHEMA", "created", "date", "n", "numeric"};
+  QueryError err = {QUERY_OK};
+  StrongRef ref = IndexSpec_Parse("idx", args, sizeof(args) / sizeof(const char *), &err);
+  RedisSearchCtx ctx = SEARCH_CTX_STATIC(NULL, (IndexSpec *)StrongRef_Get(ref));
+  QASTCXX ast(ctx);
+  int ver = 2;
+
+  ASSERT_TRUE(ast.parse("@created:[2024-01-01 TO 2024-02-01T12:00Z]", ver)) << ast.getError();
+  QueryNode *n = ast.root;
+  ASSERT_EQ(n->type, QN_NUMERIC);
+  ASSERT_STREQ(n->nn.nf->fieldName, "created");
+  ASSERT_EQ(n->nn.nf->min, 1704067200);
+  ASSERT_EQ(n->nn.nf->max, 1706788800);
+  ASSERT_EQ(n->nn.nf->inclusiveMin, 1);
+  ASSERT_EQ(n->nn.nf->inclusiveMax, 1);
+
+  ASSERT_TRUE(ast.parse("foo @created:[ (2024-01-01 to * ] @n:[1 2]", ver)) << ast.getError();
+  n = ast.root;
+  ASSERT_EQ(n->type, QN_PHRASE);
+  ASSERT_EQ(QueryNode_NumChildren(n), 3);
+  n = n->children[1];
+  ASSERT_EQ(n->type, QN_NUMERIC);
+  ASSERT_EQ(n->nn.nf->min, 1704067200);
+  ASSERT_EQ(n->nn.nf->max, INFINITY);
+  ASSERT_EQ(n->nn.nf->inclusiveMin, 0);
+
+  ASSERT_FALSE(ast.parse("@created:[foo TO 2024]", ver));
+  ASSERT_FALSE(ast.parse("@n:[1 TO 2]", ver));
+  ASSERT_FALSE(ast.parse("@missing:[2024 TO 2025]", ver));
+  StrongRef_Release(ref);
+}
+
 TEST_F(QueryTest, testAttributes) {
   static const char *args[] = {"SCHEMA", "title", "text", "body", "text"};
   QueryError err = {QUERY_OK};
@@ -26,6 +26,49 @@ int myExpander(RSQueryExpanderCtx *ctx, RSToken *token) {
   return REDISEARCH_OK;
 }
 
+/* Returns the sum of its numeric arguments */
+static int sumFunc(RSExprFunctionCtx *ctx, RSExprValue *result, const RSExprValue *args,
+                   size_t nargs) {
+  result->type = RSExprValue_Number;
+  result->num = 0;
+  for (size_t i = 0; i < nargs; i++) {
+    if (args[i].type != RSExprValue_Number) {
+      return REDISEARCH_ERR;
+    }
+    result->num += args[i].num;
+  }
+  return REDISEARCH_OK;
+}
+
+/* A reducer summing the squares of a numeric property. The shard results are merged with SUM */
+static void *sumSquaresNewInstance(void *privdata) {
+  double *total = malloc(sizeof(*total));
+  *total = 0;
+  return total;
+}
+
+static void sumSquaresAdd(void *privdata, void *instance, const RSExprValue *args, size_t nargs) {
+  if (args[0].type == RSExprValue_Number) {
+    *(double *)instance += args[0].num * args[0].num;
+  }
+}
+
+static void sumSquaresFinalize(RSExprFunctionCtx *ctx, void *instance, RSExprValue *result) {
+  result->type = RSExprValue_Number;
+  result->num = *(double *)instance;
+}
+
+static void sumSquaresFreeInstance(void *privdata, void *instance) {
+  free(instance);
+}
+
+static const RSReducerCallbacks sumSquaresCallbacks = {
+    .NewInstance = sumSquaresNewInstance,
+    .Add = sumSquaresAdd,
+    .Finalize = sumSquaresFinalize,
+    .FreeInstance = sumSquaresFreeInstance,
+};
+
 int numFreed = 0;
 void myFreeFunc(void *p) {
   // printf("Freeing %p\n", p);
@@ -53,5 +96,15 @@ RS_ExtensionInit(RSExtensionCtx *ctx) {
     return REDISEARCH_ERR;
   }
 
+  if (ctx->RegisterExpressionFunction("example_sum", sumFunc, 1, -1, RSExprValue_Number, NULL,
+                                      NULL) == REDISEARCH_ERR) {
+    return REDISEARCH_ERR;
+  }
+
+  if (ctx->RegisterReducer("example_sumsq", &sumSquaresCallbacks, 1, RSExprValue_Number, "SUM",
+                           NULL, NULL) == REDISEARCH_ERR) {
+    return REDISEARCH_ERR;
+  }
+
   return REDISEARCH_OK;
 }
\ No newline at end of file
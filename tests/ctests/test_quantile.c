@@ -8,11 +8,13 @@
 #include "src/util/quantile.h"
 #include "src/buffer.h"
 #include "rmutil/alloc.h"
+#include "rmalloc.h"
 #include "test_util.h"
 
 #include <stdint.h>
 #include <assert.h>
 #include <stdio.h>
+#include <math.h>
 
 static FILE *fp;
 static Buffer buf;
@@ -36,6 +38,61 @@ static int testBasic() {
   return 0;
 }
 
+static int testMerge() {
+  // Exact for small streams, as long as no stream was compressed
+  QuantStream *s1 = NewQuantileStream(NULL, 0, 500);
+  QuantStream *s2 = NewQuantileStream(NULL, 0, 500);
+  for (int ii = 1; ii <= 100; ++ii) {
+    QS_Insert(ii % 2 ? s1 : s2, ii);
+  }
+  size_t len;
+  char *buf = QS_Serialize(s2, &len);
+  ASSERT(QS_Merge(s1, buf, len));
+  ASSERT_EQUAL(100, QS_GetCount(s1));
+  ASSERT_EQUAL(50, QS_Query(s1, 0.5));
+  ASSERT_EQUAL(90, QS_Query(s1, 0.9));
+
+  // Invalid buffers are rejected
+  ASSERT(!QS_Merge(s1, buf, len - 1));
+  ASSERT(!QS_Merge(s1, "abc", 3));
+  ASSERT_EQUAL(100, QS_GetCount(s1));
+  rm_free(buf);
+  QS_Free(s1);
+  QS_Free(s2);
+
+  // Merging the input in chunks keeps the rank error of a single stream
+  QuantStream *whole = NewQuantileStream(NULL, 0, 500);
+  QuantStream *merged = NewQuantileStream(NULL, 0, 500);
+  size_t chunk = numInput / 4 + 1;
+  for (size_t start = 0; start < numInput; start += chunk) {
+    QuantStream *part = NewQuantileStream(NULL, 0, 500);
+    for (size_t ii = start; ii < start + chunk && ii < numInput; ++ii) {
+      QS_Insert(whole, input[ii]);
+      QS_Insert(part, input[ii]);
+    }
+    buf = QS_Serialize(part, &len);
+    ASSERT(QS_Merge(merged, buf, len));
+    rm_free(buf);
+    QS_Free(part);
+  }
+  double quantiles[] = {0.1, 0.5, 0.9, 0.99};
+  for (size_t ii = 0; ii < sizeof(quantiles) / sizeof(quantiles[0]); ++ii) {
+    double got = QS_Query(merged, quantiles[ii]);
+    size_t below = 0, upto = 0;
+    for (size_t jj = 0; jj < numInput; ++jj) {
+      below += input[jj] < got;
+      upto += input[jj] <= got;
+    }
+    double target = quantiles[ii] * numInput, maxErr = 0.03 * numInput;
+    printf("%lf: whole=%lf merged=%lf\n", quantiles[ii], QS_Query(whole, quantiles[ii]), got);
+    ASSERT(below <= target + maxErr && upto >= target - maxErr);
+  }
+  ASSERT_EQUAL(QS_GetCount(whole), QS_GetCount(merged));
+  QS_Free(whole);
+  QS_Free(merged);
+  return 0;
+}
+
 TEST_MAIN({
   RMUTil_InitAlloc();
 
@@ -54,6 +111,7 @@ TEST_MAIN({
   input = (double *)buf.data;
 
   TESTFUNC(testBasic);
+  TESTFUNC(testMerge);
 
   Buffer_Free(&buf);
 })
\ No newline at end of file
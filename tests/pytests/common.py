@@ -20,6 +20,7 @@ from scipy import spatial
 
 BASE_RDBS_URL = 'https://s3.amazonaws.com/redismodules/redisearch-oss/rdbs/'
 VECSIM_DATA_TYPES = ['FLOAT32', 'FLOAT64']
+VECSIM_COMPACT_DATA_TYPES = ['FLOAT16', 'BFLOAT16', 'INT8', 'UINT8']
 
 
 class TimeLimit(object):
@@ -288,6 +289,15 @@ def create_np_array_typed(data, data_type='FLOAT32'):
         return np.array(data, dtype=np.float32)
     if data_type == 'FLOAT64':
         return np.array(data, dtype=np.float64)
+    if data_type == 'FLOAT16':
+        return np.array(data, dtype=np.float16)
+    if data_type == 'BFLOAT16':
+        # numpy has no bfloat16, keep the upper half of each float32 (truncating)
+        return (np.array(data, dtype=np.float32).view(np.uint32) >> 16).astype(np.uint16)
+    if data_type == 'INT8':
+        return np.array(data, dtype=np.int8)
+    if data_type == 'UINT8':
+        return np.array(data, dtype=np.uint8)
     return None
 
 def compare_lists_rec(var1, var2, delta):
@@ -871,6 +871,224 @@ def testAggregateGroup0Field(env):
                   'REDUCE', 'QUANTILE', '2', 'num', '0.5', 'AS', 'q50')
     env.assertEqual(res, [1, ['q50', '758000']])
 
+def testPercentilesMedianMode(env):
+    conn = getConnectionByEnv(env)
+    env.expect('ft.create', 'idx', 'ON', 'HASH', 'SCHEMA', 'num', 'NUMERIC', 'SORTABLE',
+               'color', 'TAG', 'SORTABLE').ok()
+    colors = ['red'] * 50 + ['green'] * 30 + ['blue'] * 21
+    for i in range(101):
+        conn.execute_command('HSET', 'doc%s' % i, 'num', i, 'color', colors[i])
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 0,
+                  'REDUCE', 'MEDIAN', '1', '@num', 'AS', 'median',
+                  'REDUCE', 'PERCENTILES', '4', '@num', '0.5', '0.9', '0.95', 'AS', 'pcts',
+                  'REDUCE', 'QUANTILE', '2', '@num', '0.95', 'AS', 'q95',
+                  'REDUCE', 'MODE', '1', '@color', 'AS', 'mode')
+    env.assertEqual(res, [1, ['median', '50', 'pcts', ['50', '90', '95'], 'q95', '95', 'mode', 'red']])
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 1, '@color',
+                  'REDUCE', 'MEDIAN', '1', '@num', 'AS', 'median',
+                  'REDUCE', 'MODE', '1', '@color', 'AS', 'mode',
+                  'SORTBY', 2, '@median', 'ASC')
+    env.assertEqual(res, [3, ['color', 'red', 'median', '24', 'mode', 'red'],
+                          ['color', 'green', 'median', '64', 'mode', 'green'],
+                          ['color', 'blue', 'median', '90', 'mode', 'blue']])
+
+    # the resolution is optional for MEDIAN and QUANTILE
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 0,
+                  'REDUCE', 'MEDIAN', '2', '@num', '100', 'AS', 'median',
+                  'REDUCE', 'QUANTILE', '3', '@num', '0.9', '100', 'AS', 'q90')
+    env.assertAlmostEqual(float(res[1][1]), 50, 3)
+    env.assertAlmostEqual(float(res[1][3]), 90, 3)
+
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'PERCENTILES', '1', '@num') \
+        .error().contains('Missing quantiles for PERCENTILES')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'PERCENTILES', '3', '@num',
+               '0.5', '2').error().contains('Percentage must be between 0.0 and 1.0')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'MODE', '2', '@num', '@color') \
+        .error()
+
+def testHistogram(env):
+    conn = getConnectionByEnv(env)
+    env.expect('ft.create', 'idx', 'ON', 'HASH', 'SCHEMA', 'price', 'NUMERIC', 'SORTABLE',
+               'ts', 'NUMERIC', 'SORTABLE', 'color', 'TAG', 'SORTABLE').ok()
+    prices = [1, 5, 9, 12, 18, 35, -3]
+    # 2024-02-28 23:30, 2024-02-29 10:00, 2024-03-01 00:00 and 2024-03-15 12:00
+    stamps = [1709163000, 1709200800, 1709251200, 1710504000, 1710504000, 1710504000, 1710504000]
+    for i, (price, ts) in enumerate(zip(prices, stamps)):
+        conn.execute_command('HSET', 'doc%s' % i, 'price', price, 'ts', ts,
+                             'color', 'red' if i % 2 else 'blue')
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 0,
+                  'REDUCE', 'HISTOGRAM', '3', '@price', 'INTERVAL', '10', 'AS', 'prices')
+    env.assertEqual(res, [1, ['prices', ['-10', '1', '0', '3', '10', '2', '30', '1']]])
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 0,
+                  'REDUCE', 'HISTOGRAM', '5', '@price', 'RANGES', '0', '10', '20', '100',
+                  'AS', 'prices')
+    env.assertEqual(res, [1, ['prices', ['0', '3', '10', '2', '20', '1']]])
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 0,
+                  'REDUCE', 'HISTOGRAM', '3', '@ts', 'DATE_INTERVAL', 'day', 'AS', 'days',
+                  'REDUCE', 'HISTOGRAM', '3', '@ts', 'DATE_INTERVAL', 'month', 'AS', 'months')
+    env.assertEqual(res, [1, ['days', ['1709078400', '1', '1709164800', '1', '1709251200', '1',
+                                       '1710460800', '4'],
+                              'months', ['1706745600', '2', '1709251200', '5']]])
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 1, '@color',
+                  'REDUCE', 'HISTOGRAM', '3', '@price', 'INTERVAL', '20', 'AS', 'prices',
+                  'SORTBY', 2, '@color', 'ASC')
+    env.assertEqual(res, [2, ['color', 'blue', 'prices', ['-20', '1', '0', '3']],
+                          ['color', 'red', 'prices', ['0', '2', '20', '1']]])
+
+    # bucket() is the APPLY counterpart of INTERVAL
+    res = env.cmd('ft.aggregate', 'idx', '*', 'APPLY', 'bucket(@price, 10)', 'AS', 'bucket',
+                  'GROUPBY', 1, '@bucket', 'REDUCE', 'COUNT', '0', 'AS', 'count',
+                  'SORTBY', 2, '@bucket', 'ASC')
+    env.assertEqual(res, [4, ['bucket', '-10', 'count', '1'], ['bucket', '0', 'count', '3'],
+                          ['bucket', '10', 'count', '2'], ['bucket', '30', 'count', '1']])
+
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'HISTOGRAM', '1', '@price') \
+        .error().contains('HISTOGRAM requires one of INTERVAL, DATE_INTERVAL or RANGES')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'HISTOGRAM', '3', '@price',
+               'INTERVAL', '0').error().contains('INTERVAL must be a positive number')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'HISTOGRAM', '3', '@ts',
+               'DATE_INTERVAL', 'week').error().contains('Unknown DATE_INTERVAL `week`')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'HISTOGRAM', '4', '@price',
+               'RANGES', '10', '0').error().contains('RANGES boundaries must be in increasing order')
+
+def testGeoReducers(env):
+    conn = getConnectionByEnv(env)
+    env.expect('ft.create', 'idx', 'ON', 'HASH', 'SCHEMA', 'location', 'GEO',
+               'color', 'TAG', 'SORTABLE').ok()
+    points = {'blue': ['2,0', '4,0'], 'red': ['10,-5', '10,5'], 'green': ['178,0', '-176,0']}
+    for color, locations in points.items():
+        for i, location in enumerate(locations):
+            conn.execute_command('HSET', '%s%d' % (color, i), 'location', location, 'color', color)
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'LOAD', 1, '@location', 'GROUPBY', 1, '@color',
+                  'REDUCE', 'GEO_BOUNDS', 1, '@location', 'AS', 'bounds',
+                  'REDUCE', 'GEO_CENTROID', 1, '@location', 'AS', 'center',
+                  'SORTBY', 2, '@color', 'ASC')
+    env.assertEqual(res, [3, ['color', 'blue', 'bounds', ['2', '0', '4', '0'], 'center', '3,0'],
+                          # the centroid is computed on the sphere, across the antimeridian
+                          ['color', 'green', 'bounds', ['-176', '0', '178', '0'], 'center', '-179,0'],
+                          ['color', 'red', 'bounds', ['10', '-5', '10', '5'], 'center', '10,0']])
+
+    res = env.cmd('ft.aggregate', 'idx', '@color:{blue|red}', 'LOAD', 1, '@location', 'GROUPBY', 0,
+                  'REDUCE', 'GEO_BOUNDS', 1, '@location', 'AS', 'bounds')
+    env.assertEqual(res, [1, ['bounds', ['2', '-5', '10', '5']]])
+
+    # groups without points have no bounds nor centroid
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 1, '@color',
+                  'REDUCE', 'GEO_BOUNDS', 1, '@color', 'AS', 'bounds',
+                  'REDUCE', 'GEO_CENTROID', 1, '@color', 'AS', 'center',
+                  'SORTBY', 2, '@color', 'ASC', 'LIMIT', 0, 1)
+    env.assertEqual(res, [3, ['color', 'blue', 'bounds', None, 'center', None]])
+
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'GEO_CENTROID', 2, '@location', '@color') \
+        .error()
+
+def testTopHits(env):
+    conn = getConnectionByEnv(env)
+    env.expect('ft.create', 'idx', 'ON', 'HASH', 'SCHEMA', 'brand', 'TAG', 'SORTABLE',
+               'name', 'TAG', 'SORTABLE', 'price', 'NUMERIC', 'SORTABLE', 'stock', 'NUMERIC').ok()
+    docs = [('acme', 'a', 10, 5), ('acme', 'b', 40, 0), ('acme', 'c', 25, 3), ('acme', 'd', 9, 1),
+            ('globex', 'e', 100, 2), ('globex', 'f', 7, 8)]
+    for i, (brand, name, price, stock) in enumerate(docs):
+        conn.execute_command('HSET', 'doc%s' % i, 'brand', brand, 'name', name, 'price', price,
+                             'stock', stock)
+
+    res = env.cmd('ft.aggregate', 'idx', '*', 'GROUPBY', 1, '@brand',
+                  'REDUCE', 'TOP_HITS', '8', '2', 'SORTBY', '2', '@price', 'DESC', 'LOAD', '1', '@name',
+                  'AS', 'top', 'SORTBY', 2, '@brand', 'ASC')
+    env.assertEqual(res, [2, ['brand', 'acme', 'top', [['price', '40', 'name', 'b'],
+                                                       ['price', '25', 'name', 'c']]],
+                          ['brand', 'globex', 'top', [['price', '100', 'name', 'e'],
+                                                      ['price', '7', 'name', 'f']]]])
+
+    # several sort keys, and a count larger than the group
+    res = env.cmd('ft.aggregate', 'idx', '*', 'APPLY', '@price > 9', 'AS', 'expensive',
+                  'GROUPBY', 0, 'REDUCE', 'TOP_HITS', '7', '10', 'SORTBY', '4', '@expensive', 'ASC',
+                  '@price', 'DESC', 'AS', 'top')
+    env.assertEqual(res, [1, ['top', [['expensive', '0', 'price', '9'], ['expensive', '0', 'price', '7'],
+                                      ['expensive', '1', 'price', '100'], ['expensive', '1', 'price', '40'],
+                                      ['expensive', '1', 'price', '25'], ['expensive', '1', 'price', '10']]]])
+
+    # strings holding numbers are compared as numbers, as they are on the coordinator
+    res = env.cmd('ft.aggregate', 'idx', '*', 'APPLY', 'format("%s", @price)', 'AS', 'p',
+                  'GROUPBY', 0, 'REDUCE', 'TOP_HITS', '5', '3', 'SORTBY', '2', '@p', 'DESC', 'AS', 'top')
+    env.assertEqual(res, [1, ['top', [['p', '100'], ['p', '40'], ['p', '25']]]])
+
+    # rows without a sort value come last
+    conn.execute_command('HSET', 'doc6', 'brand', 'globex', 'name', 'g')
+    res = env.cmd('ft.aggregate', 'idx', '@brand:{globex}', 'GROUPBY', 0,
+                  'REDUCE', 'TOP_HITS', '4', '3', 'SORTBY', '1', '@stock', 'AS', 'top')
+    env.assertEqual(res, [1, ['top', [['stock', '2'], ['stock', '8'], []]]])
+
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'TOP_HITS', '1', '3') \
+        .error().contains('TOP_HITS requires SORTBY or LOAD properties')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'TOP_HITS', '4', '0',
+               'LOAD', '1', '@name').error().contains('TOP_HITS count must be between 1 and 1000')
+    env.expect('ft.aggregate', 'idx', '*', 'GROUPBY', 0, 'REDUCE', 'TOP_HITS', '5', '3',
+               'SORTBY', '2', 'price', 'DESC').error().contains('MISSING ASC or DESC after sort field')
+
+def testFacets(env):
+    conn = getConnectionByEnv(env)
+    env.expect('ft.create', 'idx', 'ON', 'HASH', 'SCHEMA', 'name', 'TAG', 'SORTABLE',
+               'color', 'TAG', 'SORTABLE', 'size', 'TAG', 'SORTABLE',
+               'price', 'NUMERIC', 'SORTABLE').ok()
+    docs = [('a', 'red', 'S', 10), ('b', 'red', 'M', 20), ('c', 'blue', 'M', 30),
+            ('d', 'red', 'L', 40), ('e', 'green', 'S', 50)]
+    for name, color, size, price in docs:
+        conn.execute_command('HSET', 'doc:' + name, 'name', name, 'color', color, 'size', size,
+                             'price', price)
+
+    # facets see every record, regardless of the LIMIT of the main pipeline
+    res = env.cmd('ft.aggregate', 'idx', '*', 'LOAD', 1, '@name',
+                  'FACET', 'color', 14, 'GROUPBY', 1, '@color', 'REDUCE', 'COUNT', 0, 'AS', 'count',
+                  'SORTBY', 4, '@count', 'DESC', '@color', 'ASC',
+                  'FACET', 'size', 13, 'GROUPBY', 1, '@size', 'REDUCE', 'SUM', 1, '@price',
+                  'AS', 'total', 'SORTBY', 2, '@size', 'ASC',
+                  'SORTBY', 2, '@name', 'ASC', 'LIMIT', 0, 2)
+    # the facet rows are counted in the total
+    env.assertEqual(res, [11, ['name', 'a'], ['name', 'b'],
+                          ['color', 'red', 'count', '3', '__facet', 'color'],
+                          ['color', 'blue', 'count', '1', '__facet', 'color'],
+                          ['color', 'green', 'count', '1', '__facet', 'color'],
+                          ['size', 'L', 'total', '40', '__facet', 'size'],
+                          ['size', 'M', 'total', '50', '__facet', 'size'],
+                          ['size', 'S', 'total', '60', '__facet', 'size']])
+
+    # a facet may filter its records, and limit its groups
+    res = env.cmd('ft.aggregate', 'idx', '*', 'LOAD', 1, '@name',
+                  'FACET', 'cheap', 16, 'FILTER', '@price < 35',
+                  'GROUPBY', 1, '@color', 'REDUCE', 'COUNT', 0, 'AS', 'count',
+                  'SORTBY', 2, '@color', 'ASC', 'MAX', 1)
+    env.assertEqual(len(res), 7)
+    env.assertEqual(res[0], 6)
+    env.assertEqual(res[-1], ['color', 'blue', 'count', '1', '__facet', 'cheap'])
+
+    # the rows read with a cursor add up to the total
+    res, cursor = env.cmd('ft.aggregate', 'idx', '*', 'LOAD', 1, '@name',
+                          'FACET', 'color', 3, 'GROUPBY', 1, '@color', 'WITHCURSOR', 'COUNT', 4)
+    rows = res[1:]
+    while cursor:
+        res, cursor = env.cmd('ft.cursor', 'read', 'idx', cursor)
+        rows += res[1:]
+    env.assertEqual(len(rows), 8)
+
+    env.expect('ft.aggregate', 'idx', '*', 'FACET', 'p', 4, 'APPLY', '@price * 2', 'AS', 'p2') \
+        .error().contains('FACET `p` requires a GROUPBY step')
+    env.expect('ft.aggregate', 'idx', '*', 'FACET', 'p', 4, 'LIMIT', 0, 1,
+               'GROUPBY', 1, '@color').error().contains('must have a GROUPBY before SORTBY or LIMIT')
+    env.expect('ft.aggregate', 'idx', '*', 'FACET', 'c', 3, 'GROUPBY', 1, '@color',
+               'FACET', 'c', 3, 'GROUPBY', 1, '@size').error().contains('Duplicate FACET name `c`')
+    env.expect('ft.aggregate', 'idx', '*', 'FACET', 'c', 3, 'GROUPBY', 1, '@color',
+               'APPLY', '@price * 2', 'AS', 'p2', 'FACET', 's', 3, 'GROUPBY', 1, '@size') \
+        .error().contains('All FACET clauses must be adjacent')
+    env.expect('ft.search', 'idx', '*', 'FACET', 'c', 3, 'GROUPBY', 1, '@color').error()
+
 def testResultCounter(env):
     # Issue 436
     # https://github.com/RediSearch/RediSearch/issues/436
@@ -0,0 +1,133 @@
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def testFilters(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA',
+               't', 'TEXT', 'ANALYZER', 6, 'FILTER', 'LOWERCASE', 'FILTER', 'ASCIIFOLDING', 'FILTER', 'LENGTH', 2, 6,
+               'body', 'TEXT', 'ANALYZER', 4, 'CHAR_FILTER', 'HTML_STRIP', 'FILTER', 'LOWERCASE').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'Café CRÈME x', 'body', '<p class="bold">Hello</p> &amp; world')
+    conn.execute_command('HSET', 'doc2', 't', 'ÉLÉPHANT cafe', 'body', '<!-- class --> <b>bold</b>')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertEqual(index_info(env, 'idx')['attributes'][0][6:],
+                        ['WEIGHT', '1',
+                         'ANALYZER', ['FILTER', 'LOWERCASE', 'FILTER', 'ASCIIFOLDING', 'FILTER', 'LENGTH', '2', '6']])
+
+        env.expect('FT.SEARCH', 'idx', '@t:cafe', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+        env.expect('FT.SEARCH', 'idx', '@t:CAFÉ', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+        env.expect('FT.SEARCH', 'idx', '@t:creme', 'NOCONTENT').equal([1, 'doc1'])
+        # Dropped by LENGTH, both in the documents and in the queries
+        env.expect('FT.SEARCH', 'idx', '@t:x', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@t:elephant', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@t:(cafe elephant)', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+        # Prefix and fuzzy terms go through the filters which transform their characters
+        env.expect('FT.SEARCH', 'idx', '@t:CRÈ*', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:%CAFÉS%', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+
+        # The markup is not indexed
+        env.expect('FT.SEARCH', 'idx', '@body:class', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@body:amp', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@body:bold', 'NOCONTENT').equal([1, 'doc2'])
+        env.expect('FT.SEARCH', 'idx', '@body:"hello world"', 'NOCONTENT').equal([1, 'doc1'])
+
+
+def testTokenizers(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA',
+               'kw', 'TEXT', 'ANALYZER', 4, 'TOKENIZER', 'KEYWORD', 'FILTER', 'LOWERCASE',
+               'ws', 'TEXT', 'ANALYZER', 2, 'TOKENIZER', 'WHITESPACE',
+               'std', 'TEXT').ok()
+
+    conn.execute_command('HSET', 'doc1', 'kw', 'New York', 'ws', 'e-mail me', 'std', 'e-mail me')
+    conn.execute_command('HSET', 'doc2', 'kw', 'York', 'ws', 'mail', 'std', 'mail')
+
+    env.expect('FT.SEARCH', 'idx', '@kw:york', 'NOCONTENT').equal([1, 'doc2'])
+    env.expect('FT.SEARCH', 'idx', '@kw:"new york"', 'NOCONTENT').equal([0])
+    env.expect('FT.SEARCH', 'idx', '@ws:mail', 'NOCONTENT').equal([1, 'doc2'])
+    env.expect('FT.SEARCH', 'idx', '@std:mail', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+
+    # A query over fields with different analyzers matches each field with its own terms
+    env.expect('FT.SEARCH', 'idx', '@ws|std:mail', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+    res = env.cmd('FT.SEARCH', 'idx', 'mail', 'NOCONTENT')
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc2']))
+
+
+def testStem(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'LANGUAGE', 'english', 'SCHEMA',
+               'en', 'TEXT', 'ANALYZER', 4, 'FILTER', 'STOP', 'FILTER', 'STEM',
+               'fr', 'TEXT', 'ANALYZER', 4, 'FILTER', 'STEM', 'LANGUAGE', 'french').ok()
+
+    conn.execute_command('HSET', 'doc1', 'en', 'the running dogs', 'fr', 'les chanteuses')
+    conn.execute_command('HSET', 'doc2', 'en', 'runs', 'fr', 'chanteuse')
+
+    env.expect('FT.SEARCH', 'idx', '@en:dog', 'NOCONTENT').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '@en:run', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+    # Stemmed in french, even though the language of the index is english
+    env.expect('FT.SEARCH', 'idx', '@fr:chanteuses', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+    env.expect('FT.SEARCH', 'idx', '@fr:chanteuses', 'NOCONTENT', 'VERBATIM').equal([1, 'doc1'])
+
+
+def testNGram(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA',
+               't', 'TEXT', 'ANALYZER', 6, 'TOKENIZER', 'NGRAM', 2, 3, 'FILTER', 'LOWERCASE',
+               'e', 'TEXT', 'ANALYZER', 6, 'TOKENIZER', 'EDGE_NGRAM', 1, 4, 'FILTER', 'LOWERCASE').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'Hello world', 'e', 'Redis search')
+    conn.execute_command('HSET', 'doc2', 't', 'x marks the spot', 'e', 'Searching')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        # Infix queries
+        env.expect('FT.SEARCH', 'idx', '@t:ell', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:ello', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:HELLO', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:elo', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@t:hallo', 'NOCONTENT').equal([0])
+        # The grams don't span words
+        env.expect('FT.SEARCH', 'idx', '@t:lowo', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@t:(orl pot)', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@t:(arks pot)', 'NOCONTENT').equal([1, 'doc2'])
+        # Words shorter than the grams are kept whole
+        env.expect('FT.SEARCH', 'idx', '@t:x', 'NOCONTENT').equal([1, 'doc2'])
+
+        # Search as you type
+        res = env.cmd('FT.SEARCH', 'idx', '@e:s', 'NOCONTENT')
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc2']))
+        env.expect('FT.SEARCH', 'idx', '@e:re', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@e:redi', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@e:redis', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@e:edis', 'NOCONTENT').equal([0])
+        # Longer terms only match whole words
+        env.expect('FT.SEARCH', 'idx', '@e:searc', 'NOCONTENT').equal([0])
+        env.expect('FT.SEARCH', 'idx', '@e:search', 'NOCONTENT').equal([1, 'doc1'])
+
+    env.expect('FT.CREATE', 'idx2', 'NOOFFSETS', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 4, 'TOKENIZER', 'NGRAM', 2, 3).error() \
+        .contains('NGRAM tokenizer of field `t` requires term offsets')
+    env.expect('FT.CREATE', 'idx2', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 6, 'TOKENIZER', 'EDGE_NGRAM', 1, 4, 'FILTER', 'STEM').error() \
+        .contains("STEM filter can't be used with n-gram tokenizers")
+    env.expect('FT.CREATE', 'idx2', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 4, 'TOKENIZER', 'NGRAM', 3, 2).error() \
+        .contains('NGRAM tokenizer minimum is greater than its maximum')
+    env.expect('FT.CREATE', 'idx2', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 4, 'TOKENIZER', 'NGRAM', 0, 2).error()
+
+
+def testErrors(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 2, 'FILTER', 'UPPERCASE').error() \
+        .contains('Unknown analyzer filter `UPPERCASE`')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 2, 'TOKENIZER', 'LETTER').error() \
+        .contains('Unknown analyzer tokenizer `LETTER`')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 4, 'TOKENIZER', 'KEYWORD', 'TOKENIZER', 'KEYWORD').error() \
+        .contains('Analyzer has more than one tokenizer')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 4, 'FILTER', 'LENGTH', 5, 2).error() \
+        .contains('LENGTH')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 4, 'FILTER', 'STEM', 'LANGUAGE', 'klingon').error() \
+        .contains('Invalid language for STEM filter')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 3, 'FILTER', 'LOWERCASE').error()
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 2, 'FILTER', 'LOWERCASE',
+               'ANALYZER', 2, 'FILTER', 'STOP').error().contains('Duplicate ANALYZER for field `t`')
@@ -0,0 +1,122 @@
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def testBooleanHash(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'name', 'TEXT', 'active', 'BOOLEAN').ok()
+
+    conn.execute_command('HSET', 'doc1', 'name', 'foo', 'active', 'true')
+    conn.execute_command('HSET', 'doc2', 'name', 'bar', 'active', 'FALSE')
+    conn.execute_command('HSET', 'doc3', 'name', 'baz', 'active', '1')
+    conn.execute_command('HSET', 'doc4', 'name', 'qux', 'active', '0')
+
+    for dialect in [2, 3]:
+        res = env.cmd('FT.SEARCH', 'idx', '@active:true', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc3']))
+        res = env.cmd('FT.SEARCH', 'idx', '@active:false', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', '@active:TRUE foo', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(res, [1, 'doc1'])
+        res = env.cmd('FT.SEARCH', 'idx', '-@active:true', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc4']))
+        # tag syntax is supported as well
+        res = env.cmd('FT.SEARCH', 'idx', '@active:{true}', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc3']))
+        res = env.cmd('FT.SEARCH', 'idx', '@active:$val', 'NOCONTENT',
+                      'PARAMS', 2, 'val', 'false', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc4']))
+
+    env.expect('FT.SEARCH', 'idx', '@active:maybe', 'DIALECT', 2).error().contains('Invalid boolean value')
+
+    # invalid values are not indexed
+    conn.execute_command('HSET', 'doc5', 'name', 'bad', 'active', 'maybe')
+    env.assertEqual(index_info(env, 'idx')['hash_indexing_failures'], '1')
+    res = env.cmd('FT.SEARCH', 'idx', 'bad', 'NOCONTENT')
+    env.assertEqual(res, [0])
+
+
+def testBooleanJson(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA',
+               '$.active', 'AS', 'active', 'BOOLEAN',
+               '$.flags[*]', 'AS', 'flags', 'BOOLEAN').ok()
+
+    conn.execute_command('JSON.SET', 'doc1', '$', '{"active": true, "flags": [true, true]}')
+    conn.execute_command('JSON.SET', 'doc2', '$', '{"active": false, "flags": [false, true]}')
+    conn.execute_command('JSON.SET', 'doc3', '$', '{"active": null, "flags": [false, null]}')
+
+    res = env.cmd('FT.SEARCH', 'idx', '@active:true', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc1'])
+    res = env.cmd('FT.SEARCH', 'idx', '@active:false', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc2'])
+    res = env.cmd('FT.SEARCH', 'idx', '@flags:true', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc2']))
+    res = env.cmd('FT.SEARCH', 'idx', '@flags:false', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc3']))
+
+    # non boolean JSON values are not indexed
+    conn.execute_command('JSON.SET', 'doc4', '$', '{"active": "true"}')
+    env.assertEqual(index_info(env, 'idx')['hash_indexing_failures'], '1')
+
+
+def testBooleanSortby(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'n', 'NUMERIC', 'SORTABLE', 'b', 'BOOLEAN', 'SORTABLE').ok()
+
+    conn.execute_command('HSET', 'doc1', 'n', '1', 'b', 'true')
+    conn.execute_command('HSET', 'doc2', 'n', '2', 'b', 'false')
+    conn.execute_command('HSET', 'doc3', 'n', '3', 'b', 'true')
+
+    res = env.cmd('FT.SEARCH', 'idx', '*', 'NOCONTENT', 'SORTBY', 'b', 'ASC')
+    env.assertEqual(res[1], 'doc2')
+    res = env.cmd('FT.SEARCH', 'idx', '*', 'NOCONTENT', 'SORTBY', 'b', 'DESC')
+    env.assertEqual(res[3], 'doc2')
+
+    res = env.cmd('FT.AGGREGATE', 'idx', '*', 'LOAD', 1, '@n', 'SORTBY', 4, '@b', 'ASC', '@n', 'DESC')
+    env.assertEqual([to_dict(row)['n'] for row in res[1:]], ['2', '3', '1'])
+
+
+def testBooleanInfoAndExplain(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'b', 'BOOLEAN', 'SORTABLE').ok()
+
+    info = index_info(env, 'idx')
+    env.assertEqual(info['attributes'][0][:6], ['identifier', 'b', 'attribute', 'b', 'type', 'BOOLEAN'])
+    env.assertContains('SORTABLE', info['attributes'][0])
+
+    res = env.cmd('FT.EXPLAIN', 'idx', '@b:1', 'DIALECT', 2)
+    env.assertEqual(res, 'TAG:@b {\n  true\n}\n')
+
+
+def testBooleanRdb(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'b', 'BOOLEAN').ok()
+    conn.execute_command('HSET', 'doc1', 'b', 'true')
+    conn.execute_command('HSET', 'doc2', 'b', 'false')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertEqual(index_info(env, 'idx')['attributes'][0][5], 'BOOLEAN')
+        res = env.cmd('FT.SEARCH', 'idx', '@b:true', 'NOCONTENT', 'DIALECT', 2)
+        env.assertEqual(res, [1, 'doc1'])
+
+
+def testBooleanUpdateAndDelete(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'b', 'BOOLEAN').ok()
+
+    # Enough documents to span several blocks of the bitmaps
+    for i in range(3000):
+        conn.execute_command('HSET', f'doc{i}', 'b', 'true' if i % 2 else 'false')
+    env.expect('FT.SEARCH', 'idx', '@b:true', 'LIMIT', 0, 0, 'DIALECT', 2).equal([1500])
+    env.expect('FT.SEARCH', 'idx', '@b:false', 'LIMIT', 0, 0, 'DIALECT', 2).equal([1500])
+
+    conn.execute_command('HSET', 'doc1', 'b', 'false')
+    conn.execute_command('DEL', 'doc3')
+    for i in range(2000, 3000):
+        conn.execute_command('DEL', f'doc{i}')
+    env.expect('FT.SEARCH', 'idx', '@b:true', 'LIMIT', 0, 0, 'DIALECT', 2).equal([998])
+    env.expect('FT.SEARCH', 'idx', '@b:false', 'LIMIT', 0, 0, 'DIALECT', 2).equal([1001])
+    env.expect('FT.SEARCH', 'idx', '@b:true @b:false', 'LIMIT', 0, 0, 'DIALECT', 2).equal([0])
+    env.expect('FT.SEARCH', 'idx', '@b:true | @b:false', 'LIMIT', 0, 0, 'DIALECT', 2).equal([1999])
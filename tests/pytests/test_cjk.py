@@ -0,0 +1,100 @@
+# -*- coding: utf-8 -*-
+
+import os
+import tempfile
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def testJapanese(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'LANGUAGE', 'japanese', 'SCHEMA', 'txt', 'TEXT').ok()
+    conn.execute_command('HSET', 'doc1', 'txt', '私は学生です。東京都に住んでいます')
+    conn.execute_command('HSET', 'doc2', 'txt', '日本語の先生')
+
+    env.expect('FT.SEARCH', 'idx', '学生', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '東京都', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '先生', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc2'])
+    # Whole words of the dictionary are indexed, rather than their parts
+    env.expect('FT.SEARCH', 'idx', '東京', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([0])
+    # Particles and auxiliaries are not indexed
+    env.expect('FT.SEARCH', 'idx', 'です', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([0])
+    env.expect('FT.SEARCH', 'idx', 'の', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([0])
+    # Queries are segmented as well
+    env.expect('FT.SEARCH', 'idx', '東京都に住んでいます', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '日本語の', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc2'])
+
+
+def testKorean(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'LANGUAGE', 'korean', 'SCHEMA', 'txt', 'TEXT').ok()
+    conn.execute_command('HSET', 'doc1', 'txt', '서울대학교에서 공부합니다')
+    conn.execute_command('HSET', 'doc2', 'txt', '학생이 사과를 먹었다')
+    conn.execute_command('HSET', 'doc3', 'txt', 'Redis는 빠릅니다')
+
+    env.expect('FT.SEARCH', 'idx', '서울', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '대학교', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '공부', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '사과', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc2'])
+    # Particles are split from the words they follow
+    env.expect('FT.SEARCH', 'idx', '학생이', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc2'])
+    env.expect('FT.SEARCH', 'idx', '대학교에서', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '에서', 'NOCONTENT', 'LANGUAGE', 'korean').equal([0])
+    # Text of other scripts is lowercased
+    env.expect('FT.SEARCH', 'idx', 'redis', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc3'])
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.expect('FT.SEARCH', 'idx', '서울', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc1'])
+
+
+def testKoreanUnknownWords(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'LANGUAGE', 'korean', 'SCHEMA', 'txt', 'TEXT').ok()
+    # Words out of the dictionary which start with a particle
+    conn.execute_command('HSET', 'doc1', 'txt', '이야기 만두국')
+    conn.execute_command('HSET', 'doc2', 'txt', '도시락 나라들')
+    conn.execute_command('HSET', 'doc3', 'txt', '서울이야기를')
+
+    env.expect('FT.SEARCH', 'idx', '만두국', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '도시락', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc2'])
+    env.expect('FT.SEARCH', 'idx', '나라들', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc2'])
+    res = env.cmd('FT.SEARCH', 'idx', '이야기', 'NOCONTENT', 'LANGUAGE', 'korean')
+    env.assertEqual(res[0], 2)
+    env.assertEqual(sorted(res[1:]), ['doc1', 'doc3'])
+    # The particles are not split from their starts
+    env.expect('FT.SEARCH', 'idx', '야기', 'NOCONTENT', 'LANGUAGE', 'korean').equal([0])
+    env.expect('FT.SEARCH', 'idx', '두국', 'NOCONTENT', 'LANGUAGE', 'korean').equal([0])
+    env.expect('FT.SEARCH', 'idx', '시락', 'NOCONTENT', 'LANGUAGE', 'korean').equal([0])
+    env.expect('FT.SEARCH', 'idx', '라들', 'NOCONTENT', 'LANGUAGE', 'korean').equal([0])
+
+
+def testLanguageField(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'LANGUAGE_FIELD', 'lang', 'SCHEMA', 'txt', 'TEXT').ok()
+    conn.execute_command('HSET', 'doc1', 'txt', '学生です', 'lang', 'japanese')
+    conn.execute_command('HSET', 'doc2', 'txt', '학생입니다', 'lang', 'korean')
+    conn.execute_command('HSET', 'doc3', 'txt', '学生です')
+
+    env.expect('FT.SEARCH', 'idx', '学生', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', '학생', 'NOCONTENT', 'LANGUAGE', 'korean').equal([1, 'doc2'])
+    # Without a language, the sentence is a single token
+    env.expect('FT.SEARCH', 'idx', '学生です', 'NOCONTENT', 'VERBATIM').equal([1, 'doc3'])
+
+
+def testUserDict():
+    with tempfile.NamedTemporaryFile('w', suffix='.txt', delete=False) as f:
+        f.write('# Words\n東京タワー\n\n# Particles\nには STOP\n')
+    env = Env(moduleArgs='JAPANESE_DICT ' + f.name)
+    try:
+        conn = getConnectionByEnv(env)
+        env.expect('FT.CONFIG', 'GET', 'JAPANESE_DICT').equal([['JAPANESE_DICT', f.name]])
+        env.expect('FT.CREATE', 'idx', 'LANGUAGE', 'japanese', 'SCHEMA', 'txt', 'TEXT').ok()
+        conn.execute_command('HSET', 'doc1', 'txt', '東京タワーには')
+
+        env.expect('FT.SEARCH', 'idx', '東京タワー', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', 'タワー', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([0])
+        env.expect('FT.SEARCH', 'idx', 'には', 'NOCONTENT', 'LANGUAGE', 'japanese').equal([0])
+    finally:
+        os.unlink(f.name)
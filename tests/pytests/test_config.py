@@ -32,6 +32,8 @@ def testGetConfigOptions(env):
         assert env.expect('ft.config', 'get', 'WORKER_THREADS').res[0][0] == 'WORKER_THREADS'
         assert env.expect('ft.config', 'get', 'ENABLE_THREADS').res[0][0] == 'ENABLE_THREADS'
     assert env.expect('ft.config', 'get', 'FRISOINI').res[0][0] == 'FRISOINI'
+    assert env.expect('ft.config', 'get', 'JAPANESE_DICT').res[0][0] == 'JAPANESE_DICT'
+    assert env.expect('ft.config', 'get', 'KOREAN_DICT').res[0][0] == 'KOREAN_DICT'
     assert env.expect('ft.config', 'get', 'MAXSEARCHRESULTS').res[0][0] == 'MAXSEARCHRESULTS'
     assert env.expect('ft.config', 'get', 'MAXAGGREGATERESULTS').res[0][0] == 'MAXAGGREGATERESULTS'
     assert env.expect('ft.config', 'get', 'ON_TIMEOUT').res[0][0] == 'ON_TIMEOUT'
@@ -71,6 +73,8 @@ def testSetConfigOptions(env):
     if POWER_TO_THE_WORKERS:
         env.expect('ft.config', 'set', 'WORKER_THREADS', 1).equal('Not modifiable at runtime')
     env.expect('ft.config', 'set', 'FRISOINI', 1).equal('Not modifiable at runtime')
+    env.expect('ft.config', 'set', 'JAPANESE_DICT', 1).equal('Not modifiable at runtime')
+    env.expect('ft.config', 'set', 'KOREAN_DICT', 1).equal('Not modifiable at runtime')
     env.expect('ft.config', 'set', 'ON_TIMEOUT', 1).equal('Success (not an error)')
     env.expect('ft.config', 'set', 'GCSCANSIZE', 1).equal('OK')
     env.expect('ft.config', 'set', 'MIN_PHONETIC_TERM_LEN', 1).equal('OK')
@@ -112,6 +116,8 @@ def testAllConfig(env):
         env.assertEqual(res_dict['WORKER_THREADS'][0], '0')
         env.assertEqual(res_dict['ENABLE_THREADS'][0], 'false')
     env.assertEqual(res_dict['FRISOINI'][0], None)
+    env.assertEqual(res_dict['JAPANESE_DICT'][0], None)
+    env.assertEqual(res_dict['KOREAN_DICT'][0], None)
     env.assertEqual(res_dict['ON_TIMEOUT'][0], 'return')
     env.assertEqual(res_dict['GCSCANSIZE'][0], '100')
     env.assertEqual(res_dict['MIN_PHONETIC_TERM_LEN'][0], '3')
@@ -0,0 +1,138 @@
+from RLTest import Env
+from includes import *
+from common import *
+import time
+
+
+def testDateHash():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'name', 'TEXT', 'created', 'DATE').ok()
+
+    conn.execute_command('HSET', 'doc1', 'name', 'foo', 'created', '2024-01-01')
+    conn.execute_command('HSET', 'doc2', 'name', 'bar', 'created', '2024-01-15T10:30:00Z')
+    conn.execute_command('HSET', 'doc3', 'name', 'baz', 'created', '2024-02-01T12:00:00+02:00')
+    conn.execute_command('HSET', 'doc4', 'name', 'qux', 'created', '2024-03-01 08:00')
+
+    for dialect in [2, 3]:
+        res = env.cmd('FT.SEARCH', 'idx', '@created:[2024-01-01 TO 2024-02-01T12:00Z]', 'NOCONTENT',
+                      'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([3, 'doc1', 'doc2', 'doc3']))
+        # exclusive bounds
+        res = env.cmd('FT.SEARCH', 'idx', '@created:[(2024-01-01 TO (2024-02-01T10:00Z]', 'NOCONTENT',
+                      'DIALECT', dialect)
+        env.assertEqual(res, [1, 'doc2'])
+        res = env.cmd('FT.SEARCH', 'idx', '@created:[2024-02 TO *]', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc3', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', 'foo | @created:[2024-03-01 to +inf]', 'NOCONTENT',
+                      'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc4']))
+        # dates are indexed as epoch seconds
+        res = env.cmd('FT.SEARCH', 'idx', '@created:[1704067200 1704067200]', 'NOCONTENT',
+                      'DIALECT', dialect)
+        env.assertEqual(res, [1, 'doc1'])
+
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[$from TO $to]', 'NOCONTENT',
+                  'PARAMS', 4, 'from', 1706745600, 'to', 1709280000, 'DIALECT', 2)
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc3', 'doc4']))
+
+    env.expect('FT.SEARCH', 'idx', '@created:[yesterday TO now]').error().contains('Invalid date')
+    env.expect('FT.SEARCH', 'idx', '@name:[2024 TO 2025]').error().contains('to be a DATE field')
+    # date ranges require dialect 2
+    env.expect('FT.SEARCH', 'idx', '@created:[2024 TO 2025]', 'DIALECT', 1).error().contains('Syntax error')
+
+    # invalid values are not indexed
+    conn.execute_command('HSET', 'doc5', 'name', 'bad', 'created', '2024-02-30')
+    env.assertEqual(index_info(env, 'idx')['hash_indexing_failures'], '1')
+    env.expect('FT.SEARCH', 'idx', 'bad', 'NOCONTENT').equal([0])
+
+
+def testDateRelative():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'created', 'DATE').ok()
+
+    def iso(ts):
+        return time.strftime('%Y-%m-%dT%H:%M:%SZ', time.gmtime(ts))
+
+    now = time.time()
+    conn.execute_command('HSET', 'doc1', 'created', iso(now - 3600))
+    conn.execute_command('HSET', 'doc2', 'created', iso(now - 3 * 86400))
+    conn.execute_command('HSET', 'doc3', 'created', iso(now - 30 * 86400))
+
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[now-7d TO now]', 'NOCONTENT')
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc2']))
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[now-1d TO *]', 'NOCONTENT')
+    env.assertEqual(res, [1, 'doc1'])
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[* TO now-2w]', 'NOCONTENT')
+    env.assertEqual(res, [1, 'doc3'])
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[now-4w TO now-1d-12h]', 'NOCONTENT')
+    env.assertEqual(res, [1, 'doc2'])
+
+    env.expect('FT.SEARCH', 'idx', '@created:[now-7y TO now]').error().contains('Invalid date')
+
+
+def testDateJson():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA',
+               '$.created', 'AS', 'created', 'DATE',
+               '$.events[*]', 'AS', 'events', 'DATE').ok()
+
+    conn.execute_command('JSON.SET', 'doc1', '$', '{"created": "2024-01-01T00:00:00Z", "events": ["2023-06-01", "2023-07-01"]}')
+    conn.execute_command('JSON.SET', 'doc2', '$', '{"created": 1706788800, "events": ["2024-06-01"]}')
+    conn.execute_command('JSON.SET', 'doc3', '$', '{"created": null, "events": []}')
+
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[2024-01-01 TO 2024-01-31]', 'NOCONTENT')
+    env.assertEqual(res, [1, 'doc1'])
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[2024-02-01T12:00Z TO 2024-02-01T12:00Z]', 'NOCONTENT')
+    env.assertEqual(res, [1, 'doc2'])
+    res = env.cmd('FT.SEARCH', 'idx', '@events:[2023-06-15 TO 2023-12-31]', 'NOCONTENT')
+    env.assertEqual(res, [1, 'doc1'])
+    res = env.cmd('FT.SEARCH', 'idx', '@events:[2023 TO 2025]', 'NOCONTENT')
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc2']))
+
+    # non date values are not indexed
+    conn.execute_command('JSON.SET', 'doc4', '$', '{"created": "01/02/2024"}')
+    env.assertEqual(index_info(env, 'idx')['hash_indexing_failures'], '1')
+
+
+def testDateSortby():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'created', 'DATE', 'SORTABLE').ok()
+
+    conn.execute_command('HSET', 'doc1', 'created', '2024-03-01')
+    conn.execute_command('HSET', 'doc2', 'created', '2023-12-31T23:59:59Z')
+    conn.execute_command('HSET', 'doc3', 'created', '2024-01-01T01:00:00+02:00')
+
+    res = env.cmd('FT.SEARCH', 'idx', '*', 'NOCONTENT', 'SORTBY', 'created', 'ASC')
+    env.assertEqual(res, [3, 'doc3', 'doc2', 'doc1'])
+    res = env.cmd('FT.SEARCH', 'idx', '@created:[2023 TO 2025]', 'NOCONTENT', 'SORTBY', 'created', 'DESC')
+    env.assertEqual(res, [3, 'doc1', 'doc2', 'doc3'])
+
+
+def testDateInfoAndExplain():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'created', 'DATE', 'SORTABLE').ok()
+
+    info = index_info(env, 'idx')
+    env.assertEqual(info['attributes'][0][:6], ['identifier', 'created', 'attribute', 'created', 'type', 'DATE'])
+    env.assertContains('SORTABLE', info['attributes'][0])
+
+    res = env.cmd('FT.EXPLAIN', 'idx', '@created:[2024-01-01 TO (2024-02-01]')
+    env.assertEqual(res, 'NUMERIC {1704067200.000000 <= @created < 1706745600.000000}\n')
+
+
+def testDateRdb():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'created', 'DATE').ok()
+    conn.execute_command('HSET', 'doc1', 'created', '2024-01-01')
+    conn.execute_command('HSET', 'doc2', 'created', '2025-01-01')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertEqual(index_info(env, 'idx')['attributes'][0][5], 'DATE')
+        res = env.cmd('FT.SEARCH', 'idx', '@created:[2024-06-01 TO *]', 'NOCONTENT')
+        env.assertEqual(res, [1, 'doc2'])
@@ -0,0 +1,138 @@
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def testEmptyHash(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'email', 'TEXT', 'INDEXEMPTY',
+               'category', 'TAG', 'INDEXEMPTY', 'name', 'TEXT').ok()
+
+    conn.execute_command('HSET', 'doc1', 'email', 'foo@example.com', 'category', 'a', 'name', 'foo')
+    conn.execute_command('HSET', 'doc2', 'email', '', 'category', 'b', 'name', 'bar')
+    conn.execute_command('HSET', 'doc3', 'email', 'bar@example.com', 'category', '', 'name', 'baz')
+    conn.execute_command('HSET', 'doc4', 'email', '', 'category', '', 'name', 'qux')
+    conn.execute_command('HSET', 'doc5', 'name', 'quux')
+
+    for dialect in [2, 3]:
+        res = env.cmd('FT.SEARCH', 'idx', '@email:""', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', '@category:{""}', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc3', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', '@email:"" @category:{""}', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(res, [1, 'doc4'])
+        res = env.cmd('FT.SEARCH', 'idx', '-@category:{""}', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([3, 'doc1', 'doc2', 'doc5']))
+        res = env.cmd('FT.SEARCH', 'idx', '@category:{""} | @category:{a}', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([3, 'doc1', 'doc3', 'doc4']))
+
+    # updating a document changes whether its value is empty
+    conn.execute_command('HSET', 'doc2', 'email', 'baz@example.com')
+    conn.execute_command('HSET', 'doc1', 'category', '')
+    res = env.cmd('FT.SEARCH', 'idx', '@email:""', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc4'])
+    res = env.cmd('FT.SEARCH', 'idx', '@category:{""}', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([3, 'doc1', 'doc3', 'doc4']))
+
+    conn.execute_command('DEL', 'doc4')
+    env.expect('FT.SEARCH', 'idx', '@email:""', 'NOCONTENT', 'DIALECT', 2).equal([0])
+
+
+def testEmptyJson(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA',
+               '$.tag', 'AS', 'tag', 'TAG', 'INDEXEMPTY',
+               '$.tags[*]', 'AS', 'tags', 'TAG', 'INDEXEMPTY',
+               '$.text', 'AS', 'text', 'TEXT', 'INDEXEMPTY').ok()
+
+    conn.execute_command('JSON.SET', 'doc1', '$', '{"tag": "a", "tags": ["a", "b"], "text": "hello"}')
+    conn.execute_command('JSON.SET', 'doc2', '$', '{"tag": "", "tags": ["a", ""], "text": ""}')
+    conn.execute_command('JSON.SET', 'doc3', '$', '{"tag": null, "tags": [], "text": "world"}')
+
+    res = env.cmd('FT.SEARCH', 'idx', '@tag:{""}', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc2'])
+    res = env.cmd('FT.SEARCH', 'idx', '@tags:{""}', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc2'])
+    res = env.cmd('FT.SEARCH', 'idx', '@text:""', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc2'])
+
+
+def testEmptyErrors(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'num', 'NUMERIC', 'INDEXEMPTY').error() \
+        .contains('`INDEXEMPTY` is only supported for TEXT and TAG fields')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'text', 'TEXT', 'tag', 'TAG',
+               'etag', 'TAG', 'INDEXEMPTY').ok()
+
+    env.expect('FT.SEARCH', 'idx', '@text:""', 'DIALECT', 2).error() \
+        .contains("Querying empty values requires field 'text' to be a TEXT field defined with 'INDEXEMPTY'")
+    env.expect('FT.SEARCH', 'idx', '@tag:{""}', 'DIALECT', 2).error() \
+        .contains("Querying empty values requires field 'tag' to be a TAG field defined with 'INDEXEMPTY'")
+    env.expect('FT.SEARCH', 'idx', '@etag:""', 'DIALECT', 2).error() \
+        .contains("to be a TEXT field defined with 'INDEXEMPTY'")
+    env.expect('FT.SEARCH', 'idx', '@nosuchfield:{""}', 'DIALECT', 2).error() \
+        .contains('INDEXEMPTY')
+
+
+def testEmptyInfoAndExplain(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'text', 'TEXT', 'INDEXEMPTY',
+               'tag', 'TAG', 'INDEXEMPTY').ok()
+
+    info = index_info(env, 'idx')
+    env.assertContains('INDEXEMPTY', info['attributes'][0])
+    env.assertContains('INDEXEMPTY', info['attributes'][1])
+
+    res = env.cmd('FT.EXPLAIN', 'idx', '@tag:{""}', 'DIALECT', 2)
+    env.assertEqual(res, 'ISEMPTY{@tag}\n')
+    res = env.cmd('FT.EXPLAIN', 'idx', '@text:""', 'DIALECT', 2)
+    env.assertEqual(res, 'ISEMPTY{@text}\n')
+    res = env.cmd('FT.EXPLAIN', 'idx', '-@tag:{""}', 'DIALECT', 2)
+    env.assertEqual(res, 'NOT{\n  ISEMPTY{@tag}\n}\n')
+
+
+def testEmptyProfile(env):
+    env.skipOnCluster()
+    conn = getConnectionByEnv(env)
+    env.cmd('FT.CONFIG', 'SET', '_PRINT_PROFILE_CLOCK', 'false')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'text', 'TEXT', 'INDEXEMPTY',
+               'tag', 'TAG', 'INDEXEMPTY').ok()
+    conn.execute_command('HSET', 'doc1', 'text', '', 'tag', 'text')
+    conn.execute_command('HSET', 'doc2', 'text', 'tag', 'tag', '')
+
+    # the empty-docs indexes are read without a term
+    for query in ['@text:""', '@tag:{""}']:
+        res = env.cmd('FT.PROFILE', 'idx', 'SEARCH', 'QUERY', query, 'NOCONTENT', 'DIALECT', 2)
+        env.assertEqual(res[1][3], ['Iterators profile', ['Type', 'FIELD DOCS', 'Counter', 1, 'Size', 1]])
+    res = env.cmd('FT.SEARCH', 'idx', '@tag:{""}', 'HIGHLIGHT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc2', ['text', 'tag', 'tag', '']])
+
+
+def testEmptyGC(env):
+    env.skipOnCluster()
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CONFIG', 'SET', 'FORK_GC_CLEAN_THRESHOLD', 0).ok()
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'tag', 'TAG', 'INDEXEMPTY').ok()
+
+    for i in range(10):
+        conn.execute_command('HSET', 'doc%d' % i, 'tag', '')
+    env.assertEqual(int(index_info(env, 'idx')['num_records']), 10)
+
+    for i in range(5):
+        conn.execute_command('DEL', 'doc%d' % i)
+    forceInvokeGC(env, 'idx')
+
+    env.assertEqual(int(index_info(env, 'idx')['num_records']), 5)
+    res = env.cmd('FT.SEARCH', 'idx', '@tag:{""}', 'NOCONTENT', 'LIMIT', 0, 0, 'DIALECT', 2)
+    env.assertEqual(res, [5])
+
+
+def testEmptyRdb(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'tag', 'TAG', 'INDEXEMPTY').ok()
+    conn.execute_command('HSET', 'doc1', 'tag', 'a')
+    conn.execute_command('HSET', 'doc2', 'tag', '')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertContains('INDEXEMPTY', index_info(env, 'idx')['attributes'][0])
+        res = env.cmd('FT.SEARCH', 'idx', '@tag:{""}', 'NOCONTENT', 'DIALECT', 2)
+        env.assertEqual(res, [1, 'doc2'])
@@ -39,6 +39,27 @@ def testExt(env):
     res = env.execute_command('ft.search', 'idx', 'hello world', 'scorer', 'filterout_scorer')
     env.assertEqual(0, res[0])
 
+    # expression functions registered by the extension
+    res = env.cmd('ft.aggregate', 'idx', 'hello', 'LIMIT', 0, 1,
+                  'APPLY', 'example_sum(1, 2.5, 3)', 'AS', 'sum')
+    env.assertEqual(res[1], ['sum', '6.5'])
+    res = env.cmd('ft.aggregate', 'idx', 'hello', 'LOAD', 1, '@f',
+                  'APPLY', 'example_sum(strlen(@f), 1)', 'AS', 'len',
+                  'FILTER', 'example_sum(@len) > 11', 'LIMIT', 0, 0)
+    env.assertEqual(res[0], N)
+    env.expect('ft.aggregate', 'idx', 'hello', 'APPLY', 'example_sum()', 'AS', 'sum').error() \
+        .contains("Invalid number of arguments for function 'example_sum'")
+    env.expect('ft.aggregate', 'idx', 'hello', 'APPLY', "example_sum('a')", 'AS', 'sum').error() \
+        .contains("Function 'example_sum' failed")
+
+    # reducers registered by the extension
+    res = env.cmd('ft.aggregate', 'idx', 'hello', 'LOAD', 1, '@f',
+                  'APPLY', 'strlen(@f)', 'AS', 'len',
+                  'GROUPBY', 1, '@f', 'REDUCE', 'example_sumsq', 1, '@len', 'AS', 'sumsq')
+    env.assertEqual(res[1], ['f', 'hello world', 'sumsq', str(N * 11 * 11)])
+    env.expect('ft.aggregate', 'idx', 'hello', 'GROUPBY', 1, '@f',
+               'REDUCE', 'example_sumsq', 0).error().contains('example_sumsq')
+
     info = info_modules_to_dict(env)
     env.assertTrue('search_extension_load' in info['search_runtime_configurations'])
 
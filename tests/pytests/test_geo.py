@@ -127,3 +127,99 @@ def testGeoDistanceFile(env):
               'APPLY', 'geodistance(@location,-0.15036,51.50566)', 'AS', 'distance',
               'GROUPBY', '1', '@distance',
               'SORTBY', 2, '@distance', 'ASC').equal(res)
+
+def testGeoPolygon(env):
+  conn = getConnectionByEnv(env)
+  env.expect('FT.CREATE idx SCHEMA g GEO name TEXT').ok()
+  conn.execute_command('HSET', 'a', 'g', '2,1', 'name', 'foo')
+  conn.execute_command('HSET', 'b', 'g', '1,8', 'name', 'bar')
+  conn.execute_command('HSET', 'hole', 'g', '5.5,4.5', 'name', 'foo')
+  conn.execute_command('HSET', 'out', 'g', '12,5', 'name', 'foo')
+  conn.execute_command('HSET', 'far', 'g', '50,50', 'name', 'foo')
+
+  square = 'POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))'
+  res = env.cmd('FT.SEARCH', 'idx', f'@g:[within {square}]', 'NOCONTENT', 'DIALECT', 2)
+  env.assertEqual(toSortedFlatList(res), [3, 'a', 'b', 'hole'])
+
+  # points inside a hole are excluded
+  with_hole = 'POLYGON((0 0, 10 0, 10 10, 0 10, 0 0), (4 4, 6 4, 6 6, 4 6, 4 4))'
+  res = env.cmd('FT.SEARCH', 'idx', f'@g:[within {with_hole}]', 'NOCONTENT', 'DIALECT', 2)
+  env.assertEqual(toSortedFlatList(res), [2, 'a', 'b'])
+  res = env.cmd('FT.SEARCH', 'idx', '@g:[within $poly]', 'PARAMS', 2, 'poly', with_hole, 'NOCONTENT', 'DIALECT', 2)
+  env.assertEqual(toSortedFlatList(res), [2, 'a', 'b'])
+
+  # points within the bounding box of the polygon but outside of it are excluded
+  res = env.cmd('FT.SEARCH', 'idx', '@g:[within POLYGON((0 0, 10 0, 10 10, 0 0))]', 'NOCONTENT', 'DIALECT', 2)
+  env.assertEqual(toSortedFlatList(res), [2, 'a', 'hole'])
+
+  res = env.cmd('FT.SEARCH', 'idx', '@name:bar @g:[within $poly]', 'PARAMS', 2, 'poly', square, 'NOCONTENT', 'DIALECT', 2)
+  env.assertEqual(res, [1, 'b'])
+
+  world = 'POLYGON((-180 -85, 180 -85, 180 85, -180 85, -180 -85))'
+  res = env.cmd('FT.SEARCH', 'idx', f'@g:[within {world}]', 'NOCONTENT', 'DIALECT', 2)
+  env.assertEqual(toSortedFlatList(res), [5, 'a', 'b', 'far', 'hole', 'out'])
+
+  env.expect('FT.SEARCH', 'idx', f'@g:[contains {square}]', 'DIALECT', 2).error().contains('Only WITHIN queries are supported for GEO field')
+  env.expect('FT.SEARCH', 'idx', '@g:[within POLYGON((0 0, 1 1, 0 0))]', 'DIALECT', 2).error().contains('a ring must have at least 3 points')
+  env.expect('FT.SEARCH', 'idx', '@g:[within POLYGON((0 0, 1 90, 1 0, 0 0))]', 'DIALECT', 2).error().contains('is out of range')
+  env.expect('FT.SEARCH', 'idx', '@g:[within POINT(1 1)]', 'DIALECT', 2).error().contains('Only POLYGON shapes can be queried on GEO fields')
+
+def testGeoYieldDistance(env):
+  conn = getConnectionByEnv(env)
+  env.expect('FT.CREATE idx SCHEMA g GEO name TEXT').ok()
+  conn.execute_command('HSET', 'a', 'g', '1,0', 'name', 'foo')
+  conn.execute_command('HSET', 'b', 'g', '0,3', 'name', 'foo')
+  conn.execute_command('HSET', 'c', 'g', '2,0', 'name', 'bar')
+  conn.execute_command('HSET', 'far', 'g', '20,0', 'name', 'foo')
+
+  def check(res, expected):
+    env.assertEqual(res[0], len(expected))
+    env.assertEqual(res[1::2], [key for key, _ in expected])
+    for fields, (_, dist) in zip(res[2::2], expected):
+      env.assertEqual(fields[0], 'dist')
+      env.assertAlmostEqual(float(fields[1]), dist, delta=0.01)
+
+  # the distance is in the unit of the radius
+  res = env.cmd('FT.SEARCH', 'idx', '@g:[0 0 1000 km]=>{$yield_distance_as: dist}',
+                'SORTBY', 'dist', 'RETURN', 1, 'dist', 'DIALECT', 2)
+  check(res, [('a', 111.23), ('c', 222.45), ('b', 333.68)])
+  res = env.cmd('FT.SEARCH', 'idx', '@g:[0 0 1000 mi]=>{$yield_distance_as: dist}',
+                'SORTBY', 'dist', 'DESC', 'RETURN', 1, 'dist', 'DIALECT', 2)
+  check(res, [('b', 207.34), ('c', 138.23), ('a', 69.11)])
+
+  # with other clauses and parameters
+  res = env.cmd('FT.SEARCH', 'idx', '@name:foo @g:[0 0 $r km]=>{$yield_distance_as: $d}',
+                'PARAMS', 4, 'r', 1000, 'd', 'dist', 'SORTBY', 'dist', 'RETURN', 1, 'dist', 'DIALECT', 2)
+  check(res, [('a', 111.23), ('b', 333.68)])
+
+  res = env.cmd('FT.AGGREGATE', 'idx', '@g:[0 0 1000 km]=>{$yield_distance_as: dist}',
+                'SORTBY', 2, '@dist', 'DESC', 'LIMIT', 0, 1, 'DIALECT', 2)
+  env.assertAlmostEqual(float(res[1][1]), 333.68, delta=0.01)
+
+  env.expect('FT.SEARCH', 'idx', '@g:[0 0 1000 km]=>{$yield_distance_as: g}', 'DIALECT', 2) \
+     .error().contains('Property `g` already exists in schema')
+
+def testGeohashGrid(env):
+  conn = getConnectionByEnv(env)
+  env.expect('FT.CREATE idx SCHEMA g GEO').ok()
+  conn.execute_command('HSET', 'a', 'g', '-5.6,42.6')
+  conn.execute_command('HSET', 'b', 'g', '2,0')
+  conn.execute_command('HSET', 'c', 'g', '4,0')
+  conn.execute_command('HSET', 'd', 'g', '10,-5')
+
+  res = env.cmd('FT.AGGREGATE', 'idx', '@g:[-5.6 42.6 1 km]', 'LOAD', 1, '@g',
+                'APPLY', 'geohash_grid(@g, 5)', 'AS', 'cell')
+  env.assertEqual(res, [1, ['g', '-5.6,42.6', 'cell', 'ezs42']])
+
+  # clusters of points with their centers, for a map
+  res = env.cmd('FT.AGGREGATE', 'idx', '*', 'LOAD', 1, '@g',
+                'APPLY', 'geohash_grid(@g, 1)', 'AS', 'cell',
+                'GROUPBY', 1, '@cell', 'REDUCE', 'COUNT', 0, 'AS', 'count',
+                'REDUCE', 'GEO_CENTROID', 1, '@g', 'AS', 'center',
+                'SORTBY', 2, '@cell', 'ASC')
+  env.assertEqual(res, [3, ['cell', 'e', 'count', '1', 'center', '-5.6,42.6'],
+                           ['cell', 'k', 'count', '1', 'center', '10,-5'],
+                           ['cell', 's', 'count', '2', 'center', '3,0']])
+
+  env.expect('FT.AGGREGATE', 'idx', '*', 'LOAD', 1, '@g', 'APPLY', 'geohash_grid(@g, 13)', 'AS', 'cell') \
+     .error().contains('geohash_grid precision must be an integer between 1 and 12')
@@ -40,8 +40,7 @@ def testSanitySearchHashWithin(env):
   res = env.execute_command('FT.SEARCH', 'idx', '@geom:[contains POLYGON((2 2, 2 50, 50 50, 50 2, 2 2))]', 'DIALECT', 3)
   env.assertEqual(res[0], 2)
   
-  # TODO: GEOMETRY - Use params
-  #env.expect('FT.SEARCH', 'idx', '@geom:[within $POLY]', 'PARAMS', '2', 'POLY', 'POLYGON((0 0, 0 150, 150 150, 150 0, 0 0))').equal([1, 'small', expected])
+  env.expect('FT.SEARCH', 'idx', '@geom:[within $POLY]', 'PARAMS', '2', 'POLY', 'POLYGON((0 0, 0 150, 150 150, 150 0, 0 0))', 'DIALECT', 3).equal([1, 'small', expected])
 
   res = env.execute_command('FT.SEARCH', 'idx', '@geom:[within POLYGON((0 0, 0 250, 250 250, 250 0, 0 0))]', 'NOCONTENT', 'DIALECT', 3)
   env.assertEqual(toSortedFlatList(res), [2, 'large', 'small'])
@@ -56,8 +55,7 @@ def testSanitySearchJsonWithin(env):
   expected = ['$', '[{"geom":"POLYGON((1 1, 1 100, 100 100, 100 1, 1 1))"}]']
   env.expect('FT.SEARCH', 'idx', '@geom:[within POLYGON((0 0, 0 150, 150 150, 150 0, 0 0))]', 'DIALECT', 3).equal([1, 'small', expected])
   
-  # TODO: GEOMETRY - Use params
-  #env.expect('FT.SEARCH', 'idx', '@geom:[within $POLY]', 'PARAMS', '2', 'POLY', 'POLYGON((0 0, 0 150, 150 150, 150 0, 0 0))').equal([1, 'small', expected])
+  env.expect('FT.SEARCH', 'idx', '@geom:[within $POLY]', 'PARAMS', '2', 'POLY', 'POLYGON((0 0, 0 150, 150 150, 150 0, 0 0))', 'DIALECT', 3).equal([1, 'small', expected])
 
   env.expect('FT.SEARCH', 'idx', '@geom:[within POLYGON((0 0, 0 150, 150 150, 150 0, 0 0))]', 'RETURN', 1, 'geom', 'DIALECT', 3).equal([1, 'small', ['geom', json.dumps([json.loads(expected[1])[0]['geom']])]])
   res = env.execute_command('FT.SEARCH', 'idx', '@geom:[within POLYGON((0 0, 0 250, 250 250, 250 0, 0 0))]', 'NOCONTENT', 'DIALECT', 3)
@@ -230,3 +228,115 @@ def testFieldUpdate(env):
   
 
     
+
+
+def testPredicates(env):
+  ''' Test INTERSECTS, DISJOINT and DWITHIN on the different geometry types '''
+  conn = getConnectionByEnv(env)
+  env.expect('FT.CREATE', 'idx', 'SCHEMA', 'geom', 'GEOMETRY').ok()
+
+  conn.execute_command('HSET', 'point', 'geom', 'POINT(5 5)')
+  conn.execute_command('HSET', 'line', 'geom', 'LINESTRING(0 0, 20 20)')
+  conn.execute_command('HSET', 'small', 'geom', 'POLYGON((1 1, 1 3, 3 3, 3 1, 1 1))')
+  conn.execute_command('HSET', 'far', 'geom', 'POLYGON((100 100, 100 110, 110 110, 110 100, 100 100))')
+  conn.execute_command('HSET', 'multi', 'geom', 'MULTIPOLYGON(((30 30, 30 32, 32 32, 32 30, 30 30)), ((40 40, 40 42, 42 42, 42 40, 40 40)))')
+  conn.execute_command('HSET', 'coll', 'geom', 'GEOMETRYCOLLECTION(POINT(50 50), LINESTRING(60 60, 61 61))')
+  assert_index_num_docs(env, 'idx', 'geom', 6)
+
+  box = 'POLYGON((0 0, 0 10, 10 10, 10 0, 0 0))'
+  res = env.cmd('FT.SEARCH', 'idx', f'@geom:[intersects {box}]', 'NOCONTENT', 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [3, 'line', 'point', 'small'])
+  res = env.cmd('FT.SEARCH', 'idx', f'@geom:[disjoint {box}]', 'NOCONTENT', 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [3, 'coll', 'far', 'multi'])
+  res = env.cmd('FT.SEARCH', 'idx', f'@geom:[within {box}]', 'NOCONTENT', 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [2, 'point', 'small'])
+
+  # Only the second polygon of the multipolygon intersects the query
+  env.expect('FT.SEARCH', 'idx', '@geom:[intersects POLYGON((39 39, 39 41, 41 41, 41 39, 39 39))]', 'NOCONTENT', 'DIALECT', 3).equal([1, 'multi'])
+
+  # Distances are in the units of the coordinates
+  env.expect('FT.SEARCH', 'idx', '@geom:[dwithin 3 POINT(52 50)]', 'NOCONTENT', 'DIALECT', 3).equal([1, 'coll'])
+  env.expect('FT.SEARCH', 'idx', '@geom:[dwithin 1.5 POINT(52 50)]', 'NOCONTENT', 'DIALECT', 3).equal([0])
+  res = env.cmd('FT.SEARCH', 'idx', '@geom:[dwithin 0 POINT(5 5)]', 'NOCONTENT', 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [2, 'line', 'point'])
+  env.expect('FT.SEARCH', 'idx', '@geom:[dwithin $dist $shape]', 'NOCONTENT', 'PARAMS', 4, 'dist', 3, 'shape', 'POINT(52 50)', 'DIALECT', 3).equal([1, 'coll'])
+
+
+def testGeoJSON(env):
+  ''' Test GeoJSON geometries in documents and queries '''
+  conn = getConnectionByEnv(env)
+  env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA', '$.geom', 'AS', 'geom', 'GEOMETRY').ok()
+
+  conn.execute_command('JSON.SET', 'p1', '$', '{"geom": {"type": "Point", "coordinates": [5, 5]}}')
+  conn.execute_command('JSON.SET', 'p2', '$', '{"geom": {"type": "Polygon", "coordinates": [[[100, 100], [100, 110], [110, 110], [110, 100], [100, 100]]]}}')
+  conn.execute_command('JSON.SET', 'p3', '$', '{"geom": "LINESTRING(0 0, 20 20)"}')
+  conn.execute_command('JSON.SET', 'p4', '$', '{"geom": "{\\"type\\": \\"MultiPolygon\\", \\"coordinates\\": [[[[1, 1], [1, 3], [3, 3], [3, 1], [1, 1]]]]}"}')
+  assert_index_num_docs(env, 'idx', 'geom', 4)
+
+  res = env.cmd('FT.SEARCH', 'idx', '@geom:[intersects POLYGON((0 0, 0 10, 10 10, 10 0, 0 0))]', 'NOCONTENT', 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [3, 'p1', 'p3', 'p4'])
+
+  # GeoJSON query shapes are given as parameters
+  shape = json.dumps({'type': 'GeometryCollection', 'geometries': [
+    {'type': 'Point', 'coordinates': [105, 105]},
+    {'type': 'LineString', 'coordinates': [[2, 0], [2, 1.5]]}]})
+  res = env.cmd('FT.SEARCH', 'idx', '@geom:[intersects $shape]', 'NOCONTENT', 'PARAMS', 2, 'shape', shape, 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [2, 'p2', 'p4'])
+
+  # Unsupported or malformed GeoJSON objects are not indexed
+  conn.execute_command('JSON.SET', 'p5', '$', '{"geom": {"type": "MultiPoint", "coordinates": [[1, 1]]}}')
+  conn.execute_command('JSON.SET', 'p6', '$', '{"geom": {"type": "Point"}}')
+  res = env.cmd('FT.INFO', 'idx')
+  d = {res[i]: res[i + 1] for i in range(0, len(res), 2)}
+  env.assertEqual(int(d['hash_indexing_failures']), 2)
+
+
+def testPredicatesQueryError(env):
+  ''' Test errors of the geometry predicates '''
+  env.expect('FT.CREATE', 'idx', 'SCHEMA', 'geom', 'GEOMETRY').ok()
+
+  env.expect('FT.SEARCH', 'idx', '@geom:[dwithin abc POINT(1 1)]', 'DIALECT', 3).error().contains('Invalid distance `abc` for DWITHIN')
+  env.expect('FT.SEARCH', 'idx', '@geom:[dwithin -1 POINT(1 1)]', 'DIALECT', 3).error().contains('Invalid distance -1 for DWITHIN')
+  env.expect('FT.SEARCH', 'idx', '@geom:[dwithin $dist POINT(1 1)]', 'PARAMS', 2, 'dist', 'abc', 'DIALECT', 3).error().contains('Invalid numeric value')
+  env.expect('FT.SEARCH', 'idx', '@geom:[intersects MULTIPOINT((1 1), (2 2))]', 'DIALECT', 3).error().contains('Unsupported geometry type `MULTIPOINT`')
+  env.expect('FT.SEARCH', 'idx', '@geom:[intersects $shape]', 'PARAMS', 2, 'shape', '{"type": "Point"', 'DIALECT', 3).error().contains('Invalid GeoJSON')
+
+
+def testSpherical(env):
+  ''' Test geometries on the sphere with COORD_SYSTEM SPHERICAL '''
+  conn = getConnectionByEnv(env)
+  try:
+    env.cmd('FT.CREATE', 'sphere', 'SCHEMA', 'geom', 'GEOMETRY', 'COORD_SYSTEM', 'SPHERICAL')
+  except Exception as e:
+    # The module was built without S2
+    env.assertContains('COORD_SYSTEM SPHERICAL is not supported by this build', str(e))
+    env.skip()
+  env.expect('FT.CREATE', 'flat', 'SCHEMA', 'geom', 'GEOMETRY', 'COORD_SYSTEM', 'FLAT').ok()
+  env.assertEqual(index_info(env, 'sphere')['attributes'],
+                  [['identifier', 'geom', 'attribute', 'geom', 'type', 'GEOMETRY', 'COORD_SYSTEM', 'SPHERICAL']])
+  # The default coordinate system isn't reported
+  env.assertEqual(index_info(env, 'flat')['attributes'],
+                  [['identifier', 'geom', 'attribute', 'geom', 'type', 'GEOMETRY']])
+
+  conn.execute_command('HSET', 'fiji', 'geom', 'POINT(179.5 -17)')
+  conn.execute_command('HSET', 'greenwich', 'geom', 'POINT(0 -15)')
+  conn.execute_command('HSET', 'null_island', 'geom', 'POINT(0 0.001)')
+
+  # The polygon crosses the antimeridian on the sphere, and spans most longitudes on the plane
+  polygon = 'POLYGON((170 -20, -170 -20, -170 -10, 170 -10, 170 -20))'
+  env.expect('FT.SEARCH', 'sphere', f'@geom:[within {polygon}]', 'NOCONTENT', 'DIALECT', 3).equal([1, 'fiji'])
+  env.expect('FT.SEARCH', 'flat', f'@geom:[within {polygon}]', 'NOCONTENT', 'DIALECT', 3).equal([1, 'greenwich'])
+  res = env.cmd('FT.SEARCH', 'sphere', f'@geom:[disjoint {polygon}]', 'NOCONTENT', 'DIALECT', 3)
+  env.assertEqual(toSortedFlatList(res), [2, 'greenwich', 'null_island'])
+
+  # Distances on the sphere are in meters, the point is about 111 meters away
+  env.expect('FT.SEARCH', 'sphere', '@geom:[dwithin 200 POINT(0 0)]', 'NOCONTENT', 'DIALECT', 3).equal([1, 'null_island'])
+  env.expect('FT.SEARCH', 'sphere', '@geom:[dwithin 100 POINT(0 0)]', 'NOCONTENT', 'DIALECT', 3).equal([0])
+
+  # Coordinates must be valid longitudes and latitudes
+  conn.execute_command('HSET', 'invalid', 'geom', 'POINT(10 100)')
+  env.assertEqual(int(index_info(env, 'sphere')['hash_indexing_failures']), 1)
+  env.assertEqual(int(index_info(env, 'flat')['hash_indexing_failures']), 0)
+
+  env.expect('FT.CREATE', 'idx', 'SCHEMA', 'geom', 'GEOMETRY', 'COORD_SYSTEM', 'ROUND').error() \
+    .contains('COORD_SYSTEM for field `geom` must be SPHERICAL or FLAT')
@@ -67,6 +67,25 @@ def testInfoModulesBasic(env):
   # env.assertEqual(idx2Info['search_field_2'], 'identifier=T2,attribute=t2,type=TAG,SEPARATOR=","')
 
 
+def testInfoModulesBoolean(env):
+  conn = env.getConnection()
+  env.expect('FT.CREATE', 'idx', 'SCHEMA', 'b1', 'BOOLEAN', 'SORTABLE',
+                                           'b2', 'BOOLEAN', 'NOINDEX').ok()
+
+  info = info_modules_to_dict(conn)
+  fieldsInfo = info['search_fields_statistics']
+  env.assertEqual(fieldsInfo['search_fields_boolean'], 'Boolean=2,Sortable=1,NoIndex=1')
+
+
+def testInfoModulesDate(env):
+  conn = env.getConnection()
+  env.expect('FT.CREATE', 'idx', 'SCHEMA', 'd1', 'DATE', 'SORTABLE', 'd2', 'DATE').ok()
+
+  info = info_modules_to_dict(conn)
+  fieldsInfo = info['search_fields_statistics']
+  env.assertEqual(fieldsInfo['search_fields_date'], 'Date=2,Sortable=1')
+
+
 def testInfoModulesAlter(env):
   conn = env.getConnection()
   idx1 = 'idx1'
@@ -399,6 +399,44 @@ def testArrayCommands_withVector(env):
 
         conn.execute_command('FT.DROPINDEX', 'idx', 'DD')
 
+@no_msan
+def testCompactVectorTypes(env):
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    dim = 2
+
+    for data_type in VECSIM_COMPACT_DATA_TYPES:
+        conn.execute_command('FT.CREATE', 'idx', 'ON', 'JSON',
+                            'SCHEMA', '$.v', 'AS', 'vec', 'VECTOR', 'FLAT', '6', 'TYPE', data_type, 'DIM', dim, 'DISTANCE_METRIC', 'L2')
+
+        # JSON numbers are converted to the type of the field
+        env.assertOk(conn.execute_command('JSON.SET', 'doc:1', '$', '{"v":[1,3]}'))
+        env.assertOk(conn.execute_command('JSON.SET', 'doc:2', '$', '{"v":[5,7]}'))
+        waitForIndex(env, 'idx')
+        query_vec = create_np_array_typed([1, 3], data_type)
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @vec $B]', 'PARAMS', '2', 'B', query_vec.tobytes(),
+                   'RETURN', '1', '__vec_score').equal([2, 'doc:1', ['__vec_score', '0'], 'doc:2', ['__vec_score', '32']])
+
+        if 'INT8' in data_type:
+            # Integer elements must be integers within the range of the type
+            env.assertOk(conn.execute_command('JSON.SET', 'doc:1', '$', '{"v":[1.5,3]}'))
+            env.assertOk(conn.execute_command('JSON.SET', 'doc:2', '$', '{"v":[256,3]}'))
+            env.assertOk(conn.execute_command('JSON.SET', 'doc:3', '$', '{"v":[-129,3]}'))
+            waitForIndex(env, 'idx')
+            env.expect('FT.SEARCH', 'idx', '*').equal([0])
+            # Negative values are only valid for INT8
+            env.assertOk(conn.execute_command('JSON.SET', 'doc:1', '$', '{"v":[-1,3]}'))
+            waitForIndex(env, 'idx')
+            env.expect('FT.SEARCH', 'idx', '*', 'NOCONTENT').equal([1, 'doc:1'] if data_type == 'INT8' else [0])
+        else:
+            # Values out of the range of FLOAT16 become infinite, but are still indexed
+            env.assertOk(conn.execute_command('JSON.SET', 'doc:1', '$', '{"v":[1.5,100000]}'))
+            waitForIndex(env, 'idx')
+            res = conn.execute_command('FT.SEARCH', 'idx', '*', 'NOCONTENT')
+            env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc:1', 'doc:2']))
+
+        conn.execute_command('FT.DROPINDEX', 'idx', 'DD')
+
 @no_msan
 def testRootValues(env):
     # Search all JSON types as a top-level element
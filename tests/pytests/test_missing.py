@@ -0,0 +1,116 @@
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def testMissingHash(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'name', 'TEXT',
+               'tag', 'TAG', 'INDEXMISSING', 'price', 'NUMERIC', 'INDEXMISSING').ok()
+
+    conn.execute_command('HSET', 'doc1', 'name', 'foo', 'tag', 'a', 'price', 1)
+    conn.execute_command('HSET', 'doc2', 'name', 'bar', 'price', 2)
+    conn.execute_command('HSET', 'doc3', 'name', 'baz', 'tag', 'b')
+    conn.execute_command('HSET', 'doc4', 'name', 'qux')
+
+    for dialect in [2, 3]:
+        res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@tag)', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', 'ISMISSING(@price)', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc3', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@tag) ismissing(@price)', 'NOCONTENT',
+                      'DIALECT', dialect)
+        env.assertEqual(res, [1, 'doc4'])
+        res = env.cmd('FT.SEARCH', 'idx', '-ismissing(@tag)', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc1', 'doc3']))
+        res = env.cmd('FT.SEARCH', 'idx', 'foo | ismissing(@price)', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(toSortedFlatList(res), toSortedFlatList([3, 'doc1', 'doc3', 'doc4']))
+        res = env.cmd('FT.SEARCH', 'idx', '@name:bar ismissing(@tag)', 'NOCONTENT', 'DIALECT', dialect)
+        env.assertEqual(res, [1, 'doc2'])
+
+    # updating a document changes whether it is missing the field
+    conn.execute_command('HSET', 'doc2', 'tag', 'c')
+    conn.execute_command('HDEL', 'doc1', 'price')
+    res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@tag)', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc4'])
+    res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@price)', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([3, 'doc1', 'doc3', 'doc4']))
+
+    conn.execute_command('DEL', 'doc4')
+    env.expect('FT.SEARCH', 'idx', 'ismissing(@tag)', 'NOCONTENT', 'DIALECT', 2).equal([0])
+
+
+def testMissingJson(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA',
+               '$.tag', 'AS', 'tag', 'TAG', 'INDEXMISSING',
+               '$.num', 'AS', 'num', 'NUMERIC').ok()
+
+    conn.execute_command('JSON.SET', 'doc1', '$', '{"tag": "a", "num": 1}')
+    conn.execute_command('JSON.SET', 'doc2', '$', '{"tag": null, "num": 2}')
+    conn.execute_command('JSON.SET', 'doc3', '$', '{"num": 3}')
+
+    res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@tag)', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(toSortedFlatList(res), toSortedFlatList([2, 'doc2', 'doc3']))
+    res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@tag) @num:[3 3]', 'NOCONTENT', 'DIALECT', 2)
+    env.assertEqual(res, [1, 'doc3'])
+
+
+def testMissingErrors(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'name', 'TEXT', 'tag', 'TAG').ok()
+    conn.execute_command('HSET', 'doc1', 'name', 'ismissing')
+
+    env.expect('FT.SEARCH', 'idx', 'ismissing(@tag)', 'DIALECT', 2).error() \
+        .contains("'ismissing' requires field 'tag' to be defined with 'INDEXMISSING'")
+    env.expect('FT.SEARCH', 'idx', 'ismissing(@nosuchfield)', 'DIALECT', 2).error() \
+        .contains('INDEXMISSING')
+
+    # not followed by a parenthesis, `ismissing` is a regular term
+    env.expect('FT.SEARCH', 'idx', 'ismissing', 'NOCONTENT', 'DIALECT', 2).equal([1, 'doc1'])
+    env.expect('FT.SEARCH', 'idx', 'ismissing (@name:ismissing)', 'NOCONTENT', 'DIALECT', 2) \
+        .equal([1, 'doc1'])
+
+
+def testMissingInfoAndExplain(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'tag', 'TAG', 'INDEXMISSING').ok()
+
+    info = index_info(env, 'idx')
+    env.assertContains('INDEXMISSING', info['attributes'][0])
+
+    res = env.cmd('FT.EXPLAIN', 'idx', 'ismissing(@tag)', 'DIALECT', 2)
+    env.assertEqual(res, 'ISMISSING{@tag}\n')
+    res = env.cmd('FT.EXPLAIN', 'idx', '-ismissing(@tag)', 'DIALECT', 2)
+    env.assertEqual(res, 'NOT{\n  ISMISSING{@tag}\n}\n')
+
+
+def testMissingGC(env):
+    env.skipOnCluster()
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CONFIG', 'SET', 'FORK_GC_CLEAN_THRESHOLD', 0).ok()
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'price', 'NUMERIC', 'INDEXMISSING').ok()
+
+    for i in range(10):
+        conn.execute_command('HSET', 'doc%d' % i, 'name', 'foo')
+    env.assertEqual(int(index_info(env, 'idx')['num_records']), 10)
+
+    for i in range(5):
+        conn.execute_command('DEL', 'doc%d' % i)
+    forceInvokeGC(env, 'idx')
+
+    env.assertEqual(int(index_info(env, 'idx')['num_records']), 5)
+    res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@price)', 'NOCONTENT', 'LIMIT', 0, 0, 'DIALECT', 2)
+    env.assertEqual(res, [5])
+
+
+def testMissingRdb(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'tag', 'TAG', 'INDEXMISSING').ok()
+    conn.execute_command('HSET', 'doc1', 'tag', 'a')
+    conn.execute_command('HSET', 'doc2', 'name', 'foo')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertContains('INDEXMISSING', index_info(env, 'idx')['attributes'][0])
+        res = env.cmd('FT.SEARCH', 'idx', 'ismissing(@tag)', 'NOCONTENT', 'DIALECT', 2)
+        env.assertEqual(res, [1, 'doc2'])
@@ -237,3 +237,29 @@ def test_pipeline(env):
     expected_pipeline = ['Result processors profile', root, metric, sorter(), buffer_locker(), loader(), unlocker()]
     #sortby NOT SORTABLE NOCONTENT
     env.assertEqual(get_pipeline(res), expected_pipeline)
+
+def test_compressed_vector_pipeline(env):
+    if not POWER_TO_THE_WORKERS:
+        env.skip()
+    env = Env(moduleArgs='WORKER_THREADS 1 ENABLE_THREADS TRUE DEFAULT_DIALECT 2')
+    env.skipOnCluster()
+    env.cmd('FT.CONFIG', 'SET', '_PRINT_PROFILE_CLOCK', 'false')
+    conn = getConnectionByEnv(env)
+    dim = 2
+
+    env.cmd('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '10', 'TYPE', 'FLOAT32', 'DIM', dim,
+            'DISTANCE_METRIC', 'L2', 'COMPRESSION', 'SQ8', 'RERANK_FACTOR', '3')
+    for i in range(1, 5):
+        conn.execute_command('HSET', f'doc{i}', 'v', create_np_array_typed([0.25 * i] * dim).tobytes())
+    query_vec = create_np_array_typed([0.25] * dim)
+
+    ''' The candidates are reranked by the vector loader, once the spec lock is released
+        expected pipeline: root<-metric<-buffer-locker<-vector-loader<-sorter<-unlocker '''
+    expected_pipeline = ['Result processors profile', ['Type', 'Index', 'Counter', 3],
+                         ['Type', 'Metrics Applier', 'Counter', 3], ['Type', 'Buffer and Locker', 'Counter', 3],
+                         ['Type', 'Vector Loader', 'Counter', 1], ['Type', 'Sorter', 'Counter', 1],
+                         ['Type', 'Unlocker', 'Counter', 1]]
+    res = conn.execute_command('FT.PROFILE', 'idx', 'SEARCH', 'LIMITED', 'QUERY', '*=>[KNN 1 @v $b]',
+                               'PARAMS', 2, 'b', query_vec.tobytes(), 'SORTBY', '__v_score', 'NOCONTENT')
+    env.assertEqual(res[0], [1, 'doc1'])
+    env.assertEqual(get_pipeline(res), expected_pipeline)
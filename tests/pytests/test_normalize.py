@@ -0,0 +1,75 @@
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def testAsciiFold(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ASCIIFOLD', 'plain', 'TEXT').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'Café CRÈME', 'plain', 'Café')
+    conn.execute_command('HSET', 'doc2', 't', 'ÉLÉPHANT cafe', 'plain', 'cafe')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertEqual(index_info(env, 'idx')['attributes'][0][6:], ['WEIGHT', '1', 'ASCIIFOLD'])
+
+        env.expect('FT.SEARCH', 'idx', '@t:cafe', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+        env.expect('FT.SEARCH', 'idx', '@t:CAFÉ', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+        env.expect('FT.SEARCH', 'idx', '@t:creme', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:éléphant', 'NOCONTENT').equal([1, 'doc2'])
+        # Fields without the option keep the accents
+        env.expect('FT.SEARCH', 'idx', '@plain:cafe', 'NOCONTENT').equal([1, 'doc2'])
+        env.expect('FT.SEARCH', 'idx', 'cafe', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+
+
+def testNFKC(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'NFKC').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'ＲＥＤＩＳ，ｆａｓｔ')
+    conn.execute_command('HSET', 'doc2', 't', 'ﬁnance')
+    conn.execute_command('HSET', 'doc3', 't', 'café')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertEqual(index_info(env, 'idx')['attributes'][0][6:], ['WEIGHT', '1', 'NFKC'])
+
+        # Fullwidth punctuation separates the terms
+        env.expect('FT.SEARCH', 'idx', '@t:redis', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:fast', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:ＲＥＤＩＳ', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:ｒｅｄｉｓ，ｆａｓｔ', 'NOCONTENT').equal([1, 'doc1'])
+        env.expect('FT.SEARCH', 'idx', '@t:finance', 'NOCONTENT').equal([1, 'doc2'])
+        env.expect('FT.SEARCH', 'idx', '@t:café', 'NOCONTENT').equal([1, 'doc3'])
+
+
+def testBothOptions(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'NFKC', 'ASCIIFOLD').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'ＣＡＦÉ')
+    conn.execute_command('HSET', 'doc2', 't', 'café')
+
+    env.expect('FT.SEARCH', 'idx', '@t:cafe', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+    env.expect('FT.SEARCH', 'idx', '@t:ｃａｆé', 'NOCONTENT').equal([2, 'doc1', 'doc2'])
+
+
+def testHighlight(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'fold', 'TEXT', 'ASCIIFOLD', 'nfkc', 'TEXT', 'NFKC').ok()
+
+    conn.execute_command('HSET', 'doc1', 'fold', 'un Café CRÈME', 'nfkc', 'ＲＥＤＩＳ，ｆａｓｔ')
+
+    # The fragments show the original text
+    env.expect('FT.SEARCH', 'idx', '@fold:cafe', 'HIGHLIGHT', 'FIELDS', 1, 'fold', 'RETURN', 1, 'fold').equal(
+        [1, 'doc1', ['fold', 'un <b>Café</b> CRÈME']])
+    env.expect('FT.SEARCH', 'idx', '@nfkc:redis', 'HIGHLIGHT', 'FIELDS', 1, 'nfkc', 'RETURN', 1, 'nfkc').equal(
+        [1, 'doc1', ['nfkc', '<b>ＲＥＤＩＳ</b>，ｆａｓｔ']])
+
+
+def testErrors(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ASCIIFOLD', 'ANALYZER', 2, 'FILTER', 'LOWERCASE').error() \
+        .contains("ASCIIFOLD and NFKC can't be used with the ANALYZER of field `t`")
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'ANALYZER', 2, 'FILTER', 'LOWERCASE', 'NFKC').error() \
+        .contains("ASCIIFOLD and NFKC can't be used with the ANALYZER of field `t`")
@@ -210,6 +210,25 @@ def testProfileTag(env):
   actual_res = conn.execute_command('ft.profile', 'idx', 'search', 'query', '@t:{foo}', 'nocontent')
   env.assertEqual(actual_res[1][3], ['Iterators profile', ['Type', 'TAG', 'Term', 'foo', 'Counter', 2, 'Size', 2]])
 
+def testProfileMissing(env):
+  env.skipOnCluster()
+  conn = getConnectionByEnv(env)
+  env.cmd('FT.CONFIG', 'SET', '_PRINT_PROFILE_CLOCK', 'false')
+
+  env.cmd('ft.create', 'idx', 'SCHEMA', 't', 'tag', 'INDEXMISSING', 'body', 'text')
+  conn.execute_command('hset', '1', 't', 'foo')
+  conn.execute_command('hset', '2', 'body', 't')
+  conn.execute_command('hset', '3', 'body', 'bar')
+
+  # the docs index has no term
+  actual_res = conn.execute_command('ft.profile', 'idx', 'search', 'query', 'ismissing(@t)', 'nocontent',
+                                    'dialect', 2)
+  env.assertEqual(actual_res[1][3], ['Iterators profile', ['Type', 'FIELD DOCS', 'Counter', 2, 'Size', 2]])
+  # nor is the field name highlighted
+  res = conn.execute_command('ft.search', 'idx', 'ismissing(@t)', 'return', 1, 'body', 'highlight', 'dialect', 2)
+  env.assertEqual(res[0], 2)
+  env.assertFalse('<b>' in str(res))
+
 def testProfileVector(env):
   env.skipOnCluster()
   conn = getConnectionByEnv(env)
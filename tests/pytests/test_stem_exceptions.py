@@ -0,0 +1,109 @@
+from RLTest import Env
+from includes import *
+from common import *
+
+
+def search(env, *args):
+    return toSortedFlatList(env.cmd('FT.SEARCH', 'idx', *args, 'NOCONTENT'))
+
+
+def dump(env):
+    res = env.cmd('FT.STEMDUMP', 'idx')
+    return {res[i]: res[i + 1] for i in range(0, len(res), 2)}
+
+
+def testSuppress(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'news today')
+    conn.execute_command('HSET', 'doc2', 't', 'new york')
+    env.assertEqual(search(env, 'news'), toSortedFlatList([2, 'doc1', 'doc2']))
+
+    env.expect('FT.STEMADD', 'idx', 'news', 'news').equal(1)
+    waitForIndex(env, 'idx')
+    env.assertEqual(search(env, 'news'), [1, 'doc1'])
+    env.assertEqual(search(env, 'new'), [1, 'doc2'])
+    # Other terms are still stemmed
+    conn.execute_command('HSET', 'doc3', 't', 'running')
+    env.assertEqual(search(env, 'runs'), [1, 'doc3'])
+
+
+def testReplace(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT', 'plain', 'TEXT', 'NOSTEM').ok()
+    env.expect('FT.STEMADD', 'idx', 'MICE', 'Mouse', 'mouse', 'mouse').equal(2)
+
+    conn.execute_command('HSET', 'doc1', 't', 'mice', 'plain', 'mice')
+    conn.execute_command('HSET', 'doc2', 't', 'mouse', 'plain', 'mouse')
+
+    for _ in env.retry_with_rdb_reload():
+        waitForIndex(env, 'idx')
+        env.assertEqual(dump(env), {'mice': 'mouse', 'mouse': 'mouse'})
+        env.assertEqual(search(env, '@t:mice'), toSortedFlatList([2, 'doc1', 'doc2']))
+        env.assertEqual(search(env, '@t:mouse'), toSortedFlatList([2, 'doc1', 'doc2']))
+        env.assertEqual(search(env, '@t:mice', 'VERBATIM'), [1, 'doc1'])
+        # Fields which aren't stemmed are not affected
+        env.assertEqual(search(env, '@plain:mouse'), [1, 'doc2'])
+
+
+def testDel(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT').ok()
+    env.expect('FT.STEMADD', 'idx', 'news', 'news', 'mice', 'mouse').equal(2)
+    # Replacing a stem doesn't count as an addition
+    env.expect('FT.STEMADD', 'idx', 'mice', 'mice').equal(0)
+    env.assertEqual(dump(env), {'news': 'news', 'mice': 'mice'})
+
+    conn.execute_command('HSET', 'doc1', 't', 'news')
+    conn.execute_command('HSET', 'doc2', 't', 'new')
+    env.assertEqual(search(env, 'new'), [1, 'doc2'])
+
+    env.expect('FT.STEMDEL', 'idx', 'news', 'unknown').equal(1)
+    env.expect('FT.STEMDEL', 'idx', 'news').equal(0)
+    waitForIndex(env, 'idx')
+    env.assertEqual(dump(env), {'mice': 'mice'})
+    env.assertEqual(search(env, 'new'), toSortedFlatList([2, 'doc1', 'doc2']))
+
+    env.expect('FT.STEMDEL', 'idx', 'mice').equal(1)
+    env.expect('FT.STEMDUMP', 'idx').equal([])
+
+
+def testSkipInitialScan(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT').ok()
+
+    conn.execute_command('HSET', 'doc1', 't', 'news')
+    env.expect('FT.STEMADD', 'idx', 'SKIPINITIALSCAN', 'news', 'news').equal(1)
+    conn.execute_command('HSET', 'doc2', 't', 'news')
+
+    # The documents indexed before keep their stems, while queries use the new ones
+    env.assertEqual(search(env, 'new'), [1, 'doc1'])
+    env.assertEqual(search(env, 'news'), toSortedFlatList([2, 'doc1', 'doc2']))
+
+
+def testAnalyzer(env):
+    conn = getConnectionByEnv(env)
+    env.expect('FT.CREATE', 'idx', 'SCHEMA',
+               'en', 'TEXT', 'ANALYZER', 4, 'FILTER', 'LOWERCASE', 'FILTER', 'STEM',
+               'fr', 'TEXT', 'ANALYZER', 4, 'FILTER', 'STEM', 'LANGUAGE', 'french').ok()
+    env.expect('FT.STEMADD', 'idx', 'news', 'news', 'chevaux', 'cheval').equal(2)
+
+    conn.execute_command('HSET', 'doc1', 'en', 'News', 'fr', 'chevaux')
+    conn.execute_command('HSET', 'doc2', 'en', 'new', 'fr', 'cheval')
+
+    env.assertEqual(search(env, '@en:news'), [1, 'doc1'])
+    env.assertEqual(search(env, '@en:new'), [1, 'doc2'])
+    env.assertEqual(search(env, '@fr:chevaux'), toSortedFlatList([2, 'doc1', 'doc2']))
+
+
+def testErrors(env):
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 't', 'TEXT').ok()
+    env.expect('FT.STEMADD', 'idx', 'news').error().contains('wrong number of arguments')
+    env.expect('FT.STEMADD', 'idx', 'news', 'news', 'mice').error().contains('wrong number of arguments')
+    env.expect('FT.STEMADD', 'idx', 'SKIPINITIALSCAN', 'news').error().contains('wrong number of arguments')
+    env.expect('FT.STEMDEL', 'idx').error().contains('wrong number of arguments')
+    env.expect('FT.STEMDUMP', 'idx', 'news').error().contains('wrong number of arguments')
+    env.expect('FT.STEMADD', 'missing', 'news', 'news').error().contains('Unknown index name')
+    env.expect('FT.STEMDEL', 'missing', 'news').error().contains('Unknown index name')
+    env.expect('FT.STEMDUMP', 'missing').error().contains('Unknown index name')
@@ -337,7 +337,7 @@ def test_create():
     conn = getConnectionByEnv(env)
 
     # Test for INT32, INT64 as well when support for these types is added.
-    for data_type in VECSIM_DATA_TYPES:
+    for data_type in VECSIM_DATA_TYPES + VECSIM_COMPACT_DATA_TYPES:
         conn.execute_command('FT.CREATE', 'idx1', 'SCHEMA', 'v_HNSW', 'VECTOR', 'HNSW', '14', 'TYPE', data_type,
                              'DIM', '1024', 'DISTANCE_METRIC', 'COSINE', 'INITIAL_CAP', '10', 'M', '16',
                              'EF_CONSTRUCTION', '200', 'EF_RUNTIME', '10')
@@ -1121,6 +1121,207 @@ def test_wrong_vector_size():
         conn.execute_command('FLUSHALL')
 
 
+def test_compact_vector_types():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    dim = 2
+
+    for data_type in VECSIM_COMPACT_DATA_TYPES:
+        for index_type in ['FLAT', 'HNSW']:
+            env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', index_type, '6', 'TYPE', data_type,
+                       'DIM', dim, 'DISTANCE_METRIC', 'L2').ok()
+
+            # Every element takes 2 bytes for the 16 bit floats, and a single byte for the 8 bit integers
+            for i in range(1, 5):
+                vector = create_np_array_typed([i] * dim, data_type)
+                env.assertEqual(len(vector.tobytes()), dim * (1 if 'INT8' in data_type else 2))
+                conn.execute_command('HSET', f'doc{i}', 'v', vector.tobytes())
+            conn.execute_command('HSET', 'bad', 'v', create_np_array_typed([1] * dim, 'FLOAT32').tobytes())
+            assertInfoField(env, 'idx', 'num_docs', '4')
+            assertInfoField(env, 'idx', 'hash_indexing_failures', '1')
+
+            query_vec = create_np_array_typed([1] * dim, data_type)
+            expected_res = [4, 'doc1', ['__v_score', '0'], 'doc2', ['__v_score', '2'],
+                            'doc3', ['__v_score', '8'], 'doc4', ['__v_score', '18']]
+            env.expect('FT.SEARCH', 'idx', '*=>[KNN 4 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                       'RETURN', 1, '__v_score').equal(expected_res)
+            env.expect('FT.SEARCH', 'idx', '@v:[VECTOR_RANGE 2 $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                       'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal([2] + expected_res[1:5])
+
+            # The query vector must be given in the type of the field
+            env.expect('FT.SEARCH', 'idx', '*=>[KNN 4 @v $b]', 'PARAMS', 2, 'b',
+                       create_np_array_typed([1] * dim, 'FLOAT32').tobytes()).error().contains(
+                       f'query vector blob size ({dim * 4}) does not match index\'s expected size')
+
+            # The type is kept across a reload
+            env.dumpAndReload()
+            waitForIndex(env, 'idx')
+            env.expect('FT.SEARCH', 'idx', '*=>[KNN 4 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                       'RETURN', 1, '__v_score').equal(expected_res)
+
+            conn.execute_command('FLUSHALL')
+
+
+def test_sq8_compression():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    dim = 2
+
+    # Compression is only supported for HNSW over single value FLOAT32 or FLOAT64 vectors
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'FLAT', '8', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 'COMPRESSION', 'SQ8').error().contains('Bad arguments for algorithm FLAT: COMPRESSION')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '8', 'TYPE', 'FLOAT16', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 'COMPRESSION', 'SQ8').error().contains('COMPRESSION requires a FLOAT32 or FLOAT64 vector type')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '8', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 'COMPRESSION', 'PQ').error().contains('Bad arguments for vector similarity HNSW index compression')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '8', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 'RERANK_FACTOR', '3').error().contains('COMPRESSION_RANGE and RERANK_FACTOR require COMPRESSION')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '10', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 'COMPRESSION', 'SQ8', 'RERANK_FACTOR', '0').error().contains('Bad arguments for vector similarity HNSW index rerank factor')
+
+    for data_type in VECSIM_DATA_TYPES:
+        env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '10', 'TYPE', data_type, 'DIM', dim,
+                   'DISTANCE_METRIC', 'L2', 'COMPRESSION', 'SQ8', 'RERANK_FACTOR', '3', 't', 'TEXT').ok()
+        for i in range(1, 5):
+            conn.execute_command('HSET', f'doc{i}', 'v', create_np_array_typed([0.25 * i] * dim, data_type).tobytes(),
+                                 't', 'other' if i == 1 else 'hello')
+
+        # The distances are the exact ones, computed over the full precision vectors
+        query_vec = create_np_array_typed([0.25] * dim, data_type)
+        expected_res = [2, 'doc1', ['__v_score', '0'], 'doc2', ['__v_score', '0.125']]
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                   'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(expected_res)
+        env.expect('FT.SEARCH', 'idx', '@v:[VECTOR_RANGE 0.2 $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                   'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(expected_res)
+        env.expect('FT.SEARCH', 'idx', '(@t:hello)=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                   'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(
+                   [2, 'doc2', ['__v_score', '0.125'], 'doc3', ['__v_score', '0.5']])
+
+        # The query vector is given in the full precision type of the field
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', np.ones(dim, dtype=np.int8).tobytes()
+                   ).error().contains('does not match index\'s expected size')
+
+        # The candidates are reranked by the pipeline, so the vector query must be the root of the query
+        env.expect('FT.SEARCH', 'idx', '@t:other | @v:[VECTOR_RANGE 0.2 $b]', 'PARAMS', 2, 'b', query_vec.tobytes()
+                   ).error().contains('A query over a compressed vector field must be the root of the query')
+        env.expect('FT.SEARCH', 'idx', '(@t:hello)=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                   'FUSION', 1, 'RRF').error().contains('FUSION is not supported for compressed vector fields')
+
+        info = to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))
+        env.assertEqual(info['COMPRESSION'], 'SQ8')
+        env.assertEqual(info['RERANK_FACTOR'], 3)
+        env.assertGreater(info['RAW_MEMORY'], info['MEMORY'])
+        ft_info = index_info(env, 'idx')
+        env.assertGreater(float(ft_info['vector_index_raw_sz_mb']), float(ft_info['vector_index_sz_mb']))
+        env.assertContains('compression', ft_info['attributes'][0])
+
+        # The compression options are kept across a reload
+        env.dumpAndReload()
+        waitForIndex(env, 'idx')
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+                   'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(expected_res)
+        env.assertEqual(to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))['RERANK_FACTOR'], 3)
+
+        conn.execute_command('FLUSHALL')
+
+
+def test_alter_modify_vector_field():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    env.skipOnCluster()
+    conn = getConnectionByEnv(env)
+    dim = 2
+    n = 100
+
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'FLAT', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 't', 'TEXT').ok()
+    for i in range(1, n + 1):
+        conn.execute_command('HSET', f'doc{i}', 'v', create_np_array_typed([i] * dim).tobytes(), 't', 'hello')
+
+    # Only the parameters of vector fields can be modified, except for their type and dimension
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 't', 'TEXT').error().contains('Only the parameters of VECTOR fields can be modified')
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'foo', 'VECTOR', 'HNSW', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2').error().contains('Unknown field `foo`')
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'v', 'VECTOR', 'HNSW', '6', 'TYPE', 'FLOAT64', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2').error().contains('The TYPE and DIM of vector field `v` can\'t be modified')
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'v', 'VECTOR', 'HNSW', '6', 'TYPE', 'FLOAT32', 'DIM', dim + 1,
+               'DISTANCE_METRIC', 'L2').error().contains('The TYPE and DIM of vector field `v` can\'t be modified')
+    env.assertEqual(to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))['ALGORITHM'], 'FLAT')
+
+    query_vec = create_np_array_typed([0] * dim)
+    expected_res = [2, 'doc1', ['__v_score', '2'], 'doc2', ['__v_score', '8']]
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'v', 'VECTOR', 'HNSW', '10', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2', 'M', '32', 'EF_CONSTRUCTION', '100').ok()
+    # Queries are answered while the new index is built
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+               'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(expected_res)
+    # Documents written during the rebuild are added to the new index once
+    conn.execute_command('HSET', 'doc1', 'v', create_np_array_typed([1] * dim).tobytes(), 't', 'hello')
+    waitForIndex(env, 'idx')
+
+    info = to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))
+    env.assertEqual(info['ALGORITHM'], 'HNSW')
+    env.assertEqual(info['M'], 32)
+    env.assertEqual(info['EF_CONSTRUCTION'], 100)
+    env.assertEqual(info['INDEX_SIZE'], n)
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+               'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(expected_res)
+
+    # Updates and deletions reach the new index
+    conn.execute_command('DEL', 'doc1')
+    conn.execute_command('HSET', 'doc2', 'v', create_np_array_typed([n + 1] * dim).tobytes())
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+               'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(
+               [2, 'doc3', ['__v_score', '18'], 'doc4', ['__v_score', '32']])
+    env.assertEqual(to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))['INDEX_SIZE'], n - 1)
+
+    # The new parameters are kept across a reload
+    env.dumpAndReload()
+    waitForIndex(env, 'idx')
+    info = to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))
+    env.assertEqual(info['ALGORITHM'], 'HNSW')
+    env.assertEqual(info['M'], 32)
+
+    # Switch back to FLAT, on an index without documents
+    conn.execute_command('FLUSHALL')
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'HNSW', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2').ok()
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'v', 'VECTOR', 'FLAT', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'IP').ok()
+    info = to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', 'v'))
+    env.assertEqual(info['ALGORITHM'], 'FLAT')
+    env.assertEqual(info['METRIC'], 'IP')
+
+
+def test_alter_modify_two_vector_fields():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    env.skipOnCluster()
+    conn = getConnectionByEnv(env)
+    dim = 2
+    n = 100
+
+    env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA',
+               '$.a[*]', 'AS', 'a', 'VECTOR', 'FLAT', '6', 'TYPE', 'FLOAT32', 'DIM', dim, 'DISTANCE_METRIC', 'L2',
+               '$.b', 'AS', 'b', 'VECTOR', 'FLAT', '6', 'TYPE', 'FLOAT32', 'DIM', dim, 'DISTANCE_METRIC', 'L2').ok()
+    for i in range(1, n + 1):
+        conn.json().set(f'doc{i}', '$', {'a': [[i, i], [-i, -i]], 'b': [i, i]})
+
+    # The second rebuild restarts the scan, which must not add the vectors of the first field twice
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'a', 'VECTOR', 'HNSW', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2').ok()
+    env.expect('FT.ALTER', 'idx', 'SCHEMA', 'MODIFY', 'b', 'VECTOR', 'HNSW', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'L2').ok()
+    waitForIndex(env, 'idx')
+
+    for field, size in [('a', 2 * n), ('b', n)]:
+        info = to_dict(env.cmd('FT.DEBUG', 'VECSIM_INFO', 'idx', field))
+        env.assertEqual(info['ALGORITHM'], 'HNSW')
+        env.assertEqual(info['INDEX_SIZE'], size)
+    query_vec = create_np_array_typed([0] * dim)
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @a $b]', 'PARAMS', 2, 'b', query_vec.tobytes(),
+               'SORTBY', '__a_score', 'RETURN', 1, '__a_score').equal(
+               [2, 'doc1', ['__a_score', '2'], 'doc2', ['__a_score', '8']])
+
+
 def test_hybrid_query_cosine():
     env = Env(moduleArgs='DEFAULT_DIALECT 2')
     conn = getConnectionByEnv(env)
@@ -1196,6 +1397,70 @@ def test_fail_ft_aggregate():
             res.equal([0])
 
 
+def test_hybrid_fusion():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    # The lexical branch is ranked by the document score, which doesn't depend on the statistics of
+    # each shard, so a cluster returns the same results as a single shard
+    conn.execute_command('FT.CREATE', 'idx', 'SCORE_FIELD', 's', 'SCHEMA', 'v', 'VECTOR', 'FLAT', '6',
+                         'TYPE', 'FLOAT32', 'DIM', 1, 'DISTANCE_METRIC', 'L2', 't', 'TEXT')
+    for doc_id, text, value, score in [('1', 'apple apple', 5, 1), ('2', 'apple', 1, 0.5),
+                                       ('3', 'banana', 0, 1), ('4', 'banana', 9, 1)]:
+        conn.execute_command('HSET', doc_id, 't', text, 'v', np.float32([value]).tobytes(), 's', score)
+    blob = np.float32([0]).tobytes()
+    query = '(apple)=>[KNN 2 @v $b]'
+    scorer = ['SCORER', 'DOCSCORE']
+
+    # The lexical branch ranks 1 then 2, and the vector branch ranks 3 then 2. 4 is in neither branch.
+    env.expect('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'RRF', *scorer,
+               'NOCONTENT').equal([3, '2', '1', '3'])
+    res = conn.execute_command('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'RRF',
+                               *scorer, 'WITHSCORES', 'NOCONTENT')
+    env.assertAlmostEqual(float(res[2]), 2.0 / 62, 1E-9)
+    env.assertAlmostEqual(float(res[4]), 1.0 / 61, 1E-9)
+    env.assertAlmostEqual(float(res[6]), 1.0 / 61, 1E-9)
+
+    # Only the top result of each branch is fused
+    env.expect('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 4, 'RRF', 'WINDOW', 1, 1,
+               *scorer, 'NOCONTENT').equal([2, '1', '3'])
+
+    # The windows default to K, so nothing is fused with KNN 0
+    env.expect('FT.SEARCH', 'idx', '(apple)=>[KNN 0 @v $b]', 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'RRF',
+               *scorer, 'NOCONTENT').equal([0])
+    env.expect('FT.SEARCH', 'idx', '(apple)=>[KNN $k @v $b]', 'PARAMS', 4, 'b', blob, 'k', 0,
+               'FUSION', 1, 'RRF', *scorer, 'NOCONTENT').equal([0])
+
+    # Normalized within each branch, 2 is the worst of both
+    env.expect('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 3, 'LINEAR', 1, 3,
+               *scorer, 'NOCONTENT').equal([3, '3', '1', '2'])
+
+    # The vector distance is only yielded for the vector branch
+    res = conn.execute_command('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'RRF',
+                               *scorer, 'RETURN', 1, '__v_score')
+    env.assertEqual(res[1:], ['2', ['__v_score', '1'], '1', [], '3', ['__v_score', '0']])
+
+    aggregate = ['FT.AGGREGATE', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'RRF', *scorer,
+                 'LOAD', 1, '@t', 'APPLY', '@__fusion_score', 'AS', 'fused']
+    if env.isCluster():
+        env.expect(*aggregate).error().contains('FUSION is not supported by FT.AGGREGATE on a cluster')
+    else:
+        res = conn.execute_command(*aggregate)
+        env.assertEqual(res[0], 3)
+        rows = [to_dict(row) for row in res[1:]]
+        env.assertEqual([row['t'] for row in rows], ['apple', 'apple apple', 'banana'])
+        env.assertAlmostEqual(float(rows[0]['fused']), 2.0 / 62, 1E-9)
+
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]', 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'RRF').error() \
+        .contains('FUSION requires a hybrid KNN query')
+    env.expect('FT.SEARCH', 'idx', 'apple', 'FUSION', 1, 'RRF').error().contains('FUSION requires a hybrid KNN query')
+    env.expect('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 1, 'SUM').error() \
+        .contains('FUSION method must be RRF or LINEAR')
+    env.expect('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 2, 'LINEAR', 1).error() \
+        .contains('LINEAR fusion requires two non negative weights')
+    env.expect('FT.SEARCH', 'idx', query, 'PARAMS', 2, 'b', blob, 'FUSION', 3, 'RRF', 'WINDOW', 0).error() \
+        .contains('FUSION WINDOW requires two positive integers')
+
+
 def test_fail_on_v1_dialect():
     env = Env(moduleArgs='DEFAULT_DIALECT 1')
     dim = 1
@@ -1820,6 +2085,105 @@ def test_bad_index_multi_value_json():
     env.assertEqual(conn.ft('idx').info()['hash_indexing_failures'], info_type(failures))
 
 
+def test_multi_value_json_matched_vector():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    info_type = int if env.isCluster() else str
+    dim = 2
+
+    for algo in ['FLAT', 'HNSW']:
+        env.expect('FT.CREATE', 'idx', 'ON', 'JSON', 'SCHEMA',
+                   '$.sections[*]', 'AS', 'v', 'VECTOR', algo, '6', 'TYPE', 'FLOAT32', 'DIM', dim, 'DISTANCE_METRIC', 'L2',
+                   '$.t', 'AS', 't', 'TEXT').ok()
+
+        # Every section is either a single vector or an array of vectors (e.g. the embeddings of its paragraphs),
+        # and the vectors are indexed in their order in the document, skipping nulls.
+        conn.json().set('doc1', '$', {'t': 'hello', 'sections': [[[10, 10], [1, 1]], [[5, 5]]]})
+        conn.json().set('doc2', '$', {'t': 'hello', 'sections': [[[3, 3]], None, [[20, 20], None, [2, 2]]]})
+        conn.json().set('doc3', '$', {'t': 'other', 'sections': [[4, 4], [[30, 30]]]})
+        env.assertEqual(conn.ft('idx').info()['hash_indexing_failures'], info_type(0))
+
+        # Documents are ranked by their best matching vector, and yield its position
+        query_vec = create_np_array_typed([0] * dim)
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 3 @v $b]=>{$YIELD_DISTANCE_AS: dist; $YIELD_MATCHED_VECTOR_AS: match}',
+                   'PARAMS', 2, 'b', query_vec.tobytes(), 'SORTBY', 'dist', 'RETURN', 2, 'dist', 'match').equal(
+                   [3, 'doc1', ['dist', '2', 'match', '1'], 'doc2', ['dist', '8', 'match', '2'],
+                    'doc3', ['dist', '32', 'match', '0']])
+        env.expect('FT.SEARCH', 'idx', '(@t:hello)=>[KNN 2 @v $b]=>{$YIELD_DISTANCE_AS: dist; $YIELD_MATCHED_VECTOR_AS: match}',
+                   'PARAMS', 2, 'b', query_vec.tobytes(), 'SORTBY', 'dist', 'RETURN', 2, 'dist', 'match').equal(
+                   [2, 'doc1', ['dist', '2', 'match', '1'], 'doc2', ['dist', '8', 'match', '2']])
+
+        # A section is an array of vectors if its first non-null item is a vector
+        conn.json().set('doc4', '$', {'t': 'other', 'sections': [[None, [1, 0]], [3, 3]]})
+        env.assertEqual(conn.ft('idx').info()['hash_indexing_failures'], info_type(0))
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 1 @v $b]=>{$YIELD_DISTANCE_AS: dist; $YIELD_MATCHED_VECTOR_AS: match}',
+                   'PARAMS', 2, 'b', query_vec.tobytes(), 'SORTBY', 'dist', 'RETURN', 2, 'dist', 'match').equal(
+                   [1, 'doc4', ['dist', '1', 'match', '0']])
+        conn.json().set('doc5', '$', {'t': 'other', 'sections': [[[1, 1], 2]]})
+        env.assertEqual(conn.ft('idx').info()['hash_indexing_failures'], info_type(1))
+
+        env.expect('FT.SEARCH', 'idx', '@v:[VECTOR_RANGE 10 $b]=>{$YIELD_MATCHED_VECTOR_AS: match}',
+                   'PARAMS', 2, 'b', query_vec.tobytes()).error().contains('YIELD_MATCHED_VECTOR_AS is only supported for KNN queries')
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 3 @v $b]=>{$YIELD_MATCHED_VECTOR_AS: t}',
+                   'PARAMS', 2, 'b', query_vec.tobytes()).error().contains('Property `t` already exists in schema')
+
+        conn.flushall()
+
+
+def test_knn_max_distance():
+    env = Env(moduleArgs='DEFAULT_DIALECT 2')
+    conn = getConnectionByEnv(env)
+    dim = 2
+    n = 10
+
+    for algo in ['FLAT', 'HNSW']:
+        env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', algo, '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+                   'DISTANCE_METRIC', 'L2', 't', 'TEXT').ok()
+        for i in range(1, n + 1):
+            conn.execute_command('HSET', i, 'v', create_np_array_typed([i] * dim).tobytes(),
+                                 't', 'hello' if i % 2 == 0 else 'other')
+        query_vec = create_np_array_typed([0] * dim).tobytes()
+
+        # Only the results within the distance are returned, even if there are less than K
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 10 @v $b]=>{$max_distance: 20}', 'PARAMS', 2, 'b', query_vec,
+                   'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(
+                   [3, '1', ['__v_score', '2'], '2', ['__v_score', '8'], '3', ['__v_score', '18']])
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 2 @v $b]=>{$max_distance: $d}', 'PARAMS', 4, 'b', query_vec, 'd', 20,
+                   'SORTBY', '__v_score', 'RETURN', 0).equal([2, '1', '2'])
+        env.expect('FT.SEARCH', 'idx', '*=>[KNN 10 @v $b]=>{$max_distance: 1}', 'PARAMS', 2, 'b', query_vec,
+                   'RETURN', 0).equal([0])
+
+        # Hybrid queries, in every hybrid policy
+        expected_res = [2, '2', ['__v_score', '8'], '4', ['__v_score', '32']]
+        for policy in ['HYBRID_POLICY ADHOC_BF', 'HYBRID_POLICY BATCHES BATCH_SIZE 2']:
+            env.expect('FT.SEARCH', 'idx', f'(@t:hello)=>[KNN 10 @v $b {policy}]=>{{$max_distance: 50}}',
+                       'PARAMS', 2, 'b', query_vec, 'SORTBY', '__v_score', 'RETURN', 1, '__v_score').equal(expected_res)
+
+        # The cutoff is part of the explained query
+        res = env.cmd('FT.EXPLAIN', 'idx', '*=>[KNN 10 @v $b]=>{$max_distance: 20}', 'PARAMS', 2, 'b', query_vec)
+        env.assertContains('within 20 distance', res)
+
+        conn.execute_command('FLUSHALL')
+
+    env.expect('FT.CREATE', 'idx', 'SCHEMA', 'v', 'VECTOR', 'FLAT', '6', 'TYPE', 'FLOAT32', 'DIM', dim,
+               'DISTANCE_METRIC', 'IP').ok()
+    for i in range(1, n + 1):
+        conn.execute_command('HSET', i, 'v', create_np_array_typed([i] * dim).tobytes())
+    query_vec = create_np_array_typed([1] * dim).tobytes()
+
+    # The inner product distance (1 - the inner product) may be negative, both in KNN and in range queries
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 10 @v $b]=>{$max_distance: -15}', 'PARAMS', 2, 'b', query_vec,
+               'SORTBY', '__v_score', 'RETURN', 0).equal([3, '10', '9', '8'])
+    env.expect('FT.SEARCH', 'idx', '@v:[VECTOR_RANGE -15 $b]=>{$yield_distance_as: score}', 'PARAMS', 2, 'b', query_vec,
+               'SORTBY', 'score', 'RETURN', 0).equal([3, '10', '9', '8'])
+
+    # Errors
+    env.expect('FT.SEARCH', 'idx', '*=>[KNN 10 @v $b]=>{$max_distance: far}', 'PARAMS', 2, 'b', query_vec
+               ).error().contains('Invalid value (far) for `max_distance`')
+    env.expect('FT.SEARCH', 'idx', '@v:[VECTOR_RANGE 1 $b]=>{$max_distance: 1}', 'PARAMS', 2, 'b', query_vec
+               ).error().contains('MAX_DISTANCE is only supported for KNN queries')
+
+
 def test_range_query_basic():
     env = Env(moduleArgs='DEFAULT_DIALECT 2')
     conn = getConnectionByEnv(env)